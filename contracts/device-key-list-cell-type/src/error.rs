@@ -1,5 +1,6 @@
 use ckb_std::syscalls::SysError;
 
+/// Keep in sync with the catalogue in `das_core::error_catalogue`.
 #[derive(Debug, PartialEq, Clone, Copy)]
 #[repr(i8)]
 pub enum ErrorCode {
@@ -8,13 +8,14 @@ use das_core::constants::DPOINT_MAX_LIMIT;
 use das_core::contract::defult_structs::{Action, Rule};
 use das_core::error::ScriptError;
 use das_core::{code_to_error, das_assert, data_parser, debug, util as core_util};
+use das_types::constants::Action as DasAction;
 use das_types::packed::*;
 use dpoint_cell_type::error::ErrorCode;
 
 pub fn action() -> Result<Action, Box<dyn ScriptError>> {
     let config_dpoint_reader = Config::get_instance().dpoint()?;
 
-    let mut action = Action::new("burn_dp");
+    let mut action = Action::new(DasAction::BurnDP);
 
     let (input_cells, output_cells) = core_util::load_self_cells_in_inputs_and_outputs()?;
 
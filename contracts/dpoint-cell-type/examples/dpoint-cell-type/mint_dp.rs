@@ -9,14 +9,14 @@ use das_core::constants::{ScriptType, DPOINT_MAX_LIMIT};
 use das_core::contract::defult_structs::{Action, Rule};
 use das_core::error::ScriptError;
 use das_core::{code_to_error, das_assert, data_parser, util as core_util, verifiers};
-use das_types::constants::super_lock;
+use das_types::constants::{super_lock, Action as DasAction};
 use das_types::packed::*;
 use dpoint_cell_type::error::ErrorCode;
 
 pub fn action() -> Result<Action, Box<dyn ScriptError>> {
     let config_dpoint_reader = Config::get_instance().dpoint()?;
 
-    let mut action = Action::new("mint_dp");
+    let mut action = Action::new(DasAction::MintDP);
     let (input_cells, output_cells) = core_util::load_self_cells_in_inputs_and_outputs()?;
 
     let inner_input_cells = input_cells.clone();
@@ -9,6 +9,7 @@ use das_core::constants::DPOINT_MAX_LIMIT;
 use das_core::contract::defult_structs::{Action as ContractAction, Rule};
 use das_core::error::ScriptError;
 use das_core::{code_to_error, das_assert, data_parser, debug, util as core_util, verifiers};
+use das_types::constants::Action;
 use das_types::packed::*;
 use dpoint_cell_type::error::ErrorCode;
 
@@ -24,7 +25,7 @@ enum TransferType {
 pub fn action() -> Result<ContractAction, Box<dyn ScriptError>> {
     let config_dpoint_reader = Config::get_instance().dpoint()?;
 
-    let mut action = ContractAction::new("transfer_dp");
+    let mut action = ContractAction::new(Action::TransferDP);
     action.is_default = true;
 
     let (input_cells, output_cells) = core_util::load_self_cells_in_inputs_and_outputs()?;
@@ -12,6 +12,7 @@ use ckb_std::high_level;
 use das_core::config::Config;
 use das_core::constants::*;
 use das_core::error::*;
+use das_core::price_quote::PriceQuote;
 use das_core::since_util::SinceFlag;
 use das_core::{assert, code_to_error, data_parser, debug, since_util, util, verifiers, warn};
 use das_sorted_list::util as sorted_list_util;
@@ -514,18 +515,21 @@ fn verify_price_and_capacity<'a>(
         price
     );
 
-    let new_account_price_in_usd = u64::from(reader.price().new()); // x USD
-    let discount = u32::from(reader.invited_discount());
     let quote = u64::from(reader.quote()); // y CKB/USD
+    // The invited_discount has been verified to be the same as ConfigCellPrice when the inviter exists.
+    let has_inviter = u32::from(reader.invited_discount()) != 0;
 
     // Register price for 1 year in CKB = x ÷ y.
-    let register_capacity = util::calc_yearly_register_fee(new_account_price_in_usd, quote, discount)?;
     // Storage price in CKB = AccountCell base capacity + account.bytes.length
-    let storage_capacity = util::calc_account_storage_capacity(
-        config_account,
-        reader.account().as_readable().len() as u64 + 4,
+    let breakdown = PriceQuote::new(config_account, config_price, quote).register(
+        reader.account(),
         reader.owner_lock_args(),
-    );
+        1,
+        has_inviter,
+        None,
+    )?;
+    let register_capacity = breakdown.register_fee;
+    let storage_capacity = breakdown.storage_capacity;
 
     debug!("Check if PreAccountCell.capacity is enough for registration: {}(paid) <-> {}(1 year registeration fee) + {}(storage fee)",
         capacity,
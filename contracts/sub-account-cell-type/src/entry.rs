@@ -20,7 +20,8 @@ use das_types::constants::{
 };
 use das_types::packed::*;
 use das_types::prelude::{Builder, Entity};
-use simple_ast::executor::match_rule_with_account_chars;
+use simple_ast::executor::{match_price_rule_with_account_chars, match_rule_with_account_chars};
+use simple_ast::types::RuleContext;
 use witness_parser::WitnessesParserV1;
 
 use crate::sub_action::SubAction;
@@ -221,17 +222,37 @@ fn action_config_sub_account() -> Result<(), Box<dyn ScriptError>> {
                     dummy_account_chars_builder = dummy_account_chars_builder.push(AccountChar::default());
                     let dummy_account_chars = dummy_account_chars_builder.build();
                     let dummy_account = "";
+                    // The years is always >= 1 in transactions, so rules like `price / years` are valid.
+                    let dummy_context = RuleContext {
+                        years: 1,
+                        ..Default::default()
+                    };
 
-                    match_rule_with_account_chars(&rules, dummy_account_chars.as_reader(), dummy_account).map_err(
-                        |err| {
-                            warn!(
-                                "The SubAccountCell.witness.{} has some syntax error: {}",
-                                field,
-                                err.to_string()
-                            );
-                            code_to_error!(SubAccountCellErrorCode::ConfigRulesHasSyntaxError)
-                        },
-                    )?;
+                    let ret = if data_type == DataType::SubAccountPriceRule {
+                        match_price_rule_with_account_chars(
+                            &rules,
+                            dummy_account_chars.as_reader(),
+                            dummy_account,
+                            &dummy_context,
+                        )
+                        .map(|_| ())
+                    } else {
+                        match_rule_with_account_chars(
+                            &rules,
+                            dummy_account_chars.as_reader(),
+                            dummy_account,
+                            &dummy_context,
+                        )
+                        .map(|_| ())
+                    };
+                    ret.map_err(|err| {
+                        warn!(
+                            "The SubAccountCell.witness.{} has some syntax error: {}",
+                            field,
+                            err.to_string()
+                        );
+                        code_to_error!(SubAccountCellErrorCode::ConfigRulesHasSyntaxError)
+                    })?;
                 }
             } else {
                 debug!("No rules configured, skip the syntax check ...");
@@ -583,6 +604,7 @@ fn action_update_sub_account() -> Result<(), Box<dyn ScriptError>> {
         config_sub_account,
         &parent_account,
         parent_expired_at,
+        sub_account_parser.creation_count,
         &manual_mint_list_smt_root,
         &manual_renew_list_smt_root,
         &custom_preserved_rules,
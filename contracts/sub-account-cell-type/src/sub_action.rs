@@ -14,8 +14,8 @@ use das_types::packed::*;
 use das_types::prelude::{Builder, Entity};
 #[cfg(debug_assertions)]
 use das_types::prettier::Prettier;
-use simple_ast::executor::match_rule_with_account_chars;
-use simple_ast::types as ast_types;
+use simple_ast::executor::{match_price_rule_with_account_chars, match_rule_with_account_chars};
+use simple_ast::types::{self as ast_types, RuleContext};
 
 use super::approval;
 
@@ -31,6 +31,7 @@ pub struct SubAction<'a> {
     config_sub_account: ConfigCellSubAccountReader<'a>,
     parent_account: &'a [u8],
     parent_expired_at: u64,
+    minted_count: u32,
 
     // profit fields
     pub minimal_required_das_profit: u64,
@@ -61,6 +62,7 @@ impl<'a> SubAction<'a> {
         config_sub_account: ConfigCellSubAccountReader<'a>,
         parent_account: &'a [u8],
         parent_expired_at: u64,
+        minted_count: u32,
         manual_mint_list_smt_root: &'a Option<[u8; 32]>,
         manual_renew_list_smt_root: &'a Option<[u8; 32]>,
         custom_preserved_rules: &'a Option<Vec<ast_types::SubAccountRule>>,
@@ -78,6 +80,7 @@ impl<'a> SubAction<'a> {
             config_sub_account,
             parent_account,
             parent_expired_at,
+            minted_count,
             minimal_required_das_profit: 0,
             profit_total: 0,
             profit_from_manual_mint: 0,
@@ -125,6 +128,18 @@ impl<'a> SubAction<'a> {
         Ok(())
     }
 
+    fn gen_rule_context(&self, expiration_years: u64) -> Result<RuleContext<'a>, Box<dyn ScriptError>> {
+        let parent_account = core::str::from_utf8(self.parent_account)
+            .map_err(|_| code_to_error!(SubAccountCellErrorCode::BytesToStringFailed))?;
+
+        Ok(RuleContext {
+            timestamp: self.timestamp,
+            years: u32::try_from(expiration_years).unwrap_or(u32::MAX),
+            parent_account,
+            minted_count: self.minted_count,
+        })
+    }
+
     fn create(&mut self, witness: &SubAccountWitness, prev_root: &[u8]) -> Result<(), Box<dyn ScriptError>> {
         smt_verify_sub_account_is_creatable(&prev_root, &witness)?;
 
@@ -247,15 +262,17 @@ impl<'a> SubAction<'a> {
                         return Err(code_to_error!(SubAccountCellErrorCode::CustomRuleIsOff));
                     }
 
+                    let rule_context = self.gen_rule_context(expiration_years)?;
                     if let Some(rules) = self.custom_preserved_rules.as_ref() {
-                        let matched_rule = match_rule_with_account_chars(&rules, account_chars_reader, &account)
-                            .map_err(|err| {
-                                warn!(
-                                    "  witnesses[{:>2}] The config rules has syntax error: {}",
-                                    witness.index, err
-                                );
-                                code_to_error!(SubAccountCellErrorCode::ConfigRulesHasSyntaxError)
-                            })?;
+                        let matched_rule =
+                            match_rule_with_account_chars(&rules, account_chars_reader, &account, &rule_context)
+                                .map_err(|err| {
+                                    warn!(
+                                        "  witnesses[{:>2}] The config rules has syntax error: {}",
+                                        witness.index, err
+                                    );
+                                    code_to_error!(SubAccountCellErrorCode::ConfigRulesHasSyntaxError)
+                                })?;
                         if let Some(rule) = matched_rule {
                             warn!(
                                 "  witnesses[{:>2}] The new SubAccount should be preserved.(matched rule: {})",
@@ -266,26 +283,27 @@ impl<'a> SubAction<'a> {
                     }
 
                     if let Some(rules) = self.custom_price_rules.as_ref() {
-                        let matched_rule = match_rule_with_account_chars(&rules, account_chars_reader, &account)
-                            .map_err(|err| {
-                                warn!(
-                                    "  witnesses[{:>2}] The config rules has syntax error: {}",
-                                    witness.index, err
-                                );
-                                code_to_error!(SubAccountCellErrorCode::ConfigRulesHasSyntaxError)
-                            })?;
+                        let matched_rule =
+                            match_price_rule_with_account_chars(&rules, account_chars_reader, &account, &rule_context)
+                                .map_err(|err| {
+                                    warn!(
+                                        "  witnesses[{:>2}] The config rules has syntax error: {}",
+                                        witness.index, err
+                                    );
+                                    code_to_error!(SubAccountCellErrorCode::ConfigRulesHasSyntaxError)
+                                })?;
                         // let matched_rule = rules.last();
 
-                        if let Some(rule) = matched_rule {
+                        if let Some((rule, price)) = matched_rule {
                             das_assert!(
-                                rule.price >= u64::from(self.config_sub_account.new_sub_account_price()),
+                                price >= u64::from(self.config_sub_account.new_sub_account_price()),
                                 SubAccountCellErrorCode::MinimalProfitToDASNotReached,
                                 "  witnesses[{:>2}] The minimal profit to .bit should be more than {} shannon.",
                                 witness.index,
                                 u64::from(self.config_sub_account.new_sub_account_price()) * expiration_years
                             );
 
-                            let profit = util::calc_total_register_fee(rule.price, self.quote, 0, expiration_years)?;
+                            let profit = util::calc_total_register_fee(price, self.quote, 0, expiration_years)?;
                             self.profit_total += profit;
 
                             debug!(
@@ -370,24 +388,29 @@ impl<'a> SubAction<'a> {
         match (witness.edit_key.as_slice(), self.flag) {
             (b"custom_rule", SubAccountConfigFlag::CustomRule) => {
                 if self.custom_rule_flag == SubAccountCustomRuleFlag::On {
+                    let rule_context = self.gen_rule_context(expiration_years)?;
                     match self.custom_price_rules.as_ref() {
-                        Some(rules) => match match_rule_with_account_chars(&rules, account_chars_reader, &account) {
-                            Ok(Some(rule)) => {
+                        Some(rules) => match match_price_rule_with_account_chars(
+                            &rules,
+                            account_chars_reader,
+                            &account,
+                            &rule_context,
+                        ) {
+                            Ok(Some((rule, price))) => {
                                 debug!(
                                     "  witnesses[{:>2}] The account will be renewed with custom rules.",
                                     witness.index
                                 );
 
                                 das_assert!(
-                                    rule.price >= u64::from(self.config_sub_account.renew_sub_account_price()),
+                                    price >= u64::from(self.config_sub_account.renew_sub_account_price()),
                                     SubAccountCellErrorCode::MinimalProfitToDASNotReached,
                                     "  witnesses[{:>2}] The minimal profit to .bit should be more than {} shannon.",
                                     witness.index,
                                     u64::from(self.config_sub_account.renew_sub_account_price()) * expiration_years
                                 );
 
-                                let profit =
-                                    util::calc_total_register_fee(rule.price, self.quote, 0, expiration_years)?;
+                                let profit = util::calc_total_register_fee(price, self.quote, 0, expiration_years)?;
                                 self.profit_total += profit;
 
                                 debug!(
@@ -813,10 +836,7 @@ fn gen_account_from_witness<'a>(
 }
 
 fn gen_smt_key_by_account_id(account_id: &[u8]) -> [u8; 32] {
-    let mut key = [0u8; 32];
-    let key_pre = [account_id, &[0u8; 12]].concat();
-    key.copy_from_slice(&key_pre);
-    key
+    das_types::account::smt_key_from_account_id(account_id)
 }
 
 fn smt_verify_sub_account_is_in_mint_list(
@@ -9,7 +9,7 @@ use das_types::constants::*;
 use witness_parser::WitnessesParserV1;
 
 // use simple_ast::types as ast_types;
-use crate::{config_tests, uint_tests, witness_parser_tests};
+use crate::{config_tests, simple_ast_tests, uint_tests, witness_parser_tests};
 
 pub fn main() -> Result<(), Box<dyn ScriptError>> {
     debug!("====== Running test-env ======");
@@ -73,6 +73,7 @@ pub fn main() -> Result<(), Box<dyn ScriptError>> {
         "test_parse_reverse_record_witness_mixed" => {
             witness_parser_tests::reverse_record::test_parse_reverse_record_witness_mixed()?
         }
+        "perf_sub_account_rules_bytecode" => simple_ast_tests::perf_bytecode()?,
         _ => {
             warn!("Test not found: {:?}", test_name);
             return Err(code_to_error!(ErrorCode::HardCodedError));
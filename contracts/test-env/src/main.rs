@@ -13,6 +13,7 @@
 // define modules
 mod config_tests;
 mod entry;
+mod simple_ast_tests;
 mod uint_tests;
 mod witness_parser_tests;
 
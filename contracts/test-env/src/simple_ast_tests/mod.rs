@@ -0,0 +1,237 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::result::Result;
+
+use ckb_std::syscalls;
+use das_core::error::*;
+use das_core::{code_to_error, das_assert, debug, warn};
+use das_types::constants::CharSetType;
+use das_types::packed;
+use das_types::prelude::*;
+use simple_ast::error::ASTError;
+use simple_ast::types::*;
+use simple_ast::{bytecode, executor, util};
+
+fn variable(name: VarName) -> Expression {
+    Expression::Variable(VariableExpression { name })
+}
+
+fn value(value_type: ValueType, value: Value) -> Expression {
+    Expression::Value(ValueExpression { value_type, value })
+}
+
+fn operator(symbol: SymbolType, expressions: Vec<Expression>) -> Expression {
+    Expression::Operator(OperatorExpression { symbol, expressions })
+}
+
+fn function(name: FnName, arguments: Vec<Expression>) -> Expression {
+    Expression::Function(FunctionExpression { name, arguments })
+}
+
+fn rule(index: u32, price: u64, ast: Expression) -> SubAccountRule {
+    SubAccountRule {
+        index,
+        name: format!("Dummy rule {}", index),
+        note: String::from("A name that is not priced will not be automatically distributed;  name that meets more than one price rule may be automatically distributed at any one of the multiple prices it meets."),
+        price,
+        status: SubAccountRuleStatus::On,
+        ast,
+    }
+}
+
+fn to_script_error(err: ASTError) -> Box<dyn ScriptError> {
+    warn!("{}", err);
+    code_to_error!(ErrorCode::UnittestError)
+}
+
+/// The same rules as `perf_create_with_custom_rules` in the sub-account-cell-type tests.
+fn gen_preserved_rules() -> Vec<SubAccountRule> {
+    (0..2)
+        .map(|i| {
+            rule(
+                i,
+                0,
+                operator(
+                    SymbolType::And,
+                    vec![
+                        value(ValueType::Bool, Value::Bool(true)),
+                        function(
+                            FnName::InList,
+                            vec![
+                                variable(VarName::Account),
+                                value(ValueType::BinaryVec, Value::BinaryVec(vec![vec![0u8; 20]; 1001])),
+                            ],
+                        ),
+                    ],
+                ),
+            )
+        })
+        .collect()
+}
+
+fn gen_price_rules() -> Vec<SubAccountRule> {
+    let mut rules: Vec<SubAccountRule> = (0..50)
+        .map(|i| {
+            rule(
+                i,
+                0,
+                operator(
+                    SymbolType::And,
+                    vec![
+                        operator(
+                            SymbolType::Equal,
+                            vec![
+                                variable(VarName::AccountLength),
+                                value(ValueType::Uint32, Value::Uint32(147)),
+                            ],
+                        ),
+                        function(
+                            FnName::OnlyIncludeCharset,
+                            vec![
+                                variable(VarName::AccountChars),
+                                value(ValueType::CharsetType, Value::CharsetType(CharSetType::En)),
+                            ],
+                        ),
+                        function(
+                            FnName::IncludeWords,
+                            vec![
+                                variable(VarName::Account),
+                                value(
+                                    ValueType::StringVec,
+                                    Value::StringVec(vec![
+                                        String::from("test1"),
+                                        String::from("test2"),
+                                        String::from("test3"),
+                                    ]),
+                                ),
+                            ],
+                        ),
+                    ],
+                ),
+            )
+        })
+        .collect();
+    rules.push(rule(
+        50,
+        5_000_000,
+        operator(
+            SymbolType::Gt,
+            vec![
+                variable(VarName::AccountLength),
+                value(ValueType::Uint8, Value::Uint8(4)),
+            ],
+        ),
+    ));
+
+    rules
+}
+
+fn gen_accounts() -> Vec<(packed::AccountChars, String)> {
+    (0..50)
+        .map(|i| {
+            let name = format!("test{}", i);
+            let mut builder = packed::AccountChars::new_builder();
+            for char in name.chars() {
+                let charset = if char.is_ascii_digit() {
+                    CharSetType::Digit
+                } else {
+                    CharSetType::En
+                };
+                builder = builder.push(
+                    packed::AccountChar::new_builder()
+                        .char_set_name(packed::Uint32::from(charset as u32))
+                        .bytes(packed::Bytes::from(char.encode_utf8(&mut [0u8; 4]).as_bytes()))
+                        .build(),
+                );
+            }
+
+            (builder.build(), name + ".xxxxx.bit")
+        })
+        .collect()
+}
+
+/// Match the accounts by decoding the rules from molecule and walking the AST, then by the compiled bytecode, and
+/// print the cycles of both. Loading and hashing the witnesses cost the same for both, so they are not measured.
+fn perf_rules(rules: Vec<SubAccountRule>, is_price_rules: bool) -> Result<(), Box<dyn ScriptError>> {
+    let accounts = gen_accounts();
+    let context = RuleContext {
+        parent_account: "xxxxx.bit",
+        ..Default::default()
+    };
+    // The heap of a script is small, so the rules are consumed into molecule instead of being cloned.
+    let program = bytecode::compile_rules(&rules).map_err(to_script_error)?;
+    let rule_count = rules.len();
+    let rule_infos: Vec<(u32, u64)> = rules.iter().map(|rule| (rule.index, rule.price)).collect();
+    let rules_entity = util::sub_account_rules_to_mol_entity(rules).map_err(to_script_error)?;
+    let rules_bytes = rules_entity.as_slice();
+
+    let start = syscalls::current_cycles();
+    let reader = packed::SubAccountRulesReader::from_compatible_slice(rules_bytes)
+        .map_err(|_| code_to_error!(ErrorCode::UnittestError))?;
+    let decoded_rules =
+        util::mol_reader_to_sub_account_rules(String::from("rules"), reader).map_err(to_script_error)?;
+    let decoding_cycles = syscalls::current_cycles() - start;
+
+    let start = syscalls::current_cycles();
+    let mut expected = vec![];
+    for (account_chars, account) in accounts.iter() {
+        let ret = if is_price_rules {
+            executor::match_price_rule_with_account_chars(&decoded_rules, account_chars.as_reader(), account, &context)
+                .map_err(to_script_error)?
+                .map(|(rule, price)| (rule.index, price))
+        } else {
+            executor::match_rule_with_account_chars(&decoded_rules, account_chars.as_reader(), account, &context)
+                .map_err(to_script_error)?
+                .map(|rule| (rule.index, rule.price))
+        };
+        expected.push(ret);
+    }
+    let executor_cycles = syscalls::current_cycles() - start;
+
+    let start = syscalls::current_cycles();
+    let mut actual = vec![];
+    for (account_chars, account) in accounts.iter() {
+        let ret = if is_price_rules {
+            bytecode::match_price_rule_with_account_chars(&program, account_chars.as_reader(), account, &context)
+                .map_err(to_script_error)?
+        } else {
+            bytecode::match_rule_with_account_chars(&program, account_chars.as_reader(), account, &context)
+                .map_err(to_script_error)?
+                .map(|i| (i, rule_infos[i].1))
+        };
+        actual.push(ret.map(|(i, price)| (rule_infos[i].0, price)));
+    }
+    let bytecode_cycles = syscalls::current_cycles() - start;
+
+    das_assert!(
+        expected == actual,
+        ErrorCode::UnittestError,
+        "The bytecode should match the same rules as the executor."
+    );
+
+    debug!(
+        "{} rules in {} bytes of molecule and {} bytes of bytecode, matching {} accounts:",
+        rule_count,
+        rules_bytes.len(),
+        program.len(),
+        accounts.len()
+    );
+    debug!(
+        "  decoding: {} cycles, executor: {} cycles, total: {} cycles",
+        decoding_cycles,
+        executor_cycles,
+        decoding_cycles + executor_cycles
+    );
+    debug!("  bytecode: {} cycles", bytecode_cycles);
+
+    Ok(())
+}
+
+pub fn perf_bytecode() -> Result<(), Box<dyn ScriptError>> {
+    perf_rules(gen_preserved_rules(), false)?;
+    perf_rules(gen_price_rules(), true)?;
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
 use alloc::vec;
 use alloc::vec::Vec;
 use core::cell::OnceCell;
@@ -27,6 +28,9 @@ macro_rules! get_or_try_init {
     }};
 }
 
+/// The raw witnesses of ConfigCells indexed by their DataTypes, with the indexes of the witnesses in the transaction.
+pub type ConfigWitnesses = BTreeMap<DataType, (usize, Vec<u8>)>;
+
 #[derive(Debug)]
 pub struct Config {
     pub account: OnceCell<ConfigCellAccount>,
@@ -46,6 +50,9 @@ pub struct Config {
     pub preserved_account: OnceCell<Vec<u8>>,
     pub unavailable_account: OnceCell<Vec<u8>>,
     pub smt_node_white_list: OnceCell<Vec<[u8; 32]>>,
+    /// The raw witnesses of ConfigCells with their indexes, when it is some the witnesses in the transaction will
+    /// never be loaded.
+    pub witnesses: Option<ConfigWitnesses>,
 }
 
 impl Default for Config {
@@ -68,6 +75,7 @@ impl Default for Config {
             preserved_account: OnceCell::new(),
             unavailable_account: OnceCell::new(),
             smt_node_white_list: OnceCell::new(),
+            witnesses: None,
         }
     }
 }
@@ -86,7 +94,45 @@ impl Config {
         }
     }
 
+    /// Build the config from the raw witnesses of ConfigCells, so the off-chain code can reuse the same readers.
+    ///
+    /// The witnesses are not verified against the hashes in the ConfigCells like `WitnessesParserV1` does, the caller
+    /// should make sure they come from the ConfigCells it trusts.
+    pub fn from_witnesses<T: AsRef<[u8]>>(
+        witnesses: impl IntoIterator<Item = T>,
+    ) -> Result<Self, Box<dyn ScriptError>> {
+        Ok(Self {
+            witnesses: Some(parse_config_witnesses(witnesses)?),
+            ..Self::default()
+        })
+    }
+
+    /// Build the config from a JSON snapshot like `{ "witnesses": ["0x646173..."] }`, the witnesses are hex strings
+    /// with or without the `0x` prefix.
+    #[cfg(feature = "snapshot")]
+    pub fn from_snapshot_json(json: &str) -> Result<Self, Box<dyn ScriptError>> {
+        Self::from_witnesses(parse_snapshot_json(json)?)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn from_snapshot_file(path: impl AsRef<std::path::Path>) -> Result<Self, Box<dyn ScriptError>> {
+        let json = std::fs::read_to_string(path).map_err(|_| code_to_error!(ErrorCode::ConfigIsPartialMissing))?;
+        Self::from_snapshot_json(&json)
+    }
+
     fn parse_witness<T: Entity>(&self, config_id: DataType) -> Result<T, Box<dyn ScriptError>> {
+        if let Some(witnesses) = self.witnesses.as_ref() {
+            let (_, raw) = witnesses
+                .get(&config_id)
+                .ok_or_else(|| code_to_error!(ErrorCode::ConfigIsPartialMissing))?;
+            let entity = raw
+                .get((WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES)..)
+                .and_then(|data| T::from_compatible_slice(data).ok())
+                .ok_or_else(|| code_to_error!(ErrorCode::WitnessDataDecodingError))?;
+
+            return Ok(entity);
+        }
+
         let parser = WitnessesParserV1::get_instance();
         if !parser.is_inited() {
             return Err(code_to_error!(ErrorCode::WitnessNotInited));
@@ -100,6 +146,16 @@ impl Config {
     }
 
     fn parse_raw_witness(&self, config_id: DataType) -> Result<Vec<u8>, Box<dyn ScriptError>> {
+        if let Some(witnesses) = self.witnesses.as_ref() {
+            let (_, raw) = witnesses
+                .get(&config_id)
+                .ok_or_else(|| code_to_error!(ErrorCode::ConfigIsPartialMissing))?;
+            let mut raw = raw.clone();
+            raw.drain(..(WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES));
+
+            return Ok(raw);
+        }
+
         let parser = WitnessesParserV1::get_instance();
         if !parser.is_inited() {
             return Err(code_to_error!(ErrorCode::WitnessNotInited));
@@ -349,3 +405,180 @@ impl Config {
         })
     }
 }
+
+/// Index the raw witnesses of ConfigCells by their DataTypes, every DataType can only appear once.
+pub(crate) fn parse_config_witnesses<T: AsRef<[u8]>>(
+    witnesses: impl IntoIterator<Item = T>,
+) -> Result<ConfigWitnesses, Box<dyn ScriptError>> {
+    let mut map = BTreeMap::new();
+    for (i, witness) in witnesses.into_iter().enumerate() {
+        let witness = witness.as_ref();
+        das_assert!(
+            witness.get(..WITNESS_HEADER_BYTES) == Some(&WITNESS_HEADER[..]),
+            ErrorCode::WitnessDataDecodingError,
+            "witnesses[{:>2}] The witness should start with the header of DAS.",
+            i
+        );
+
+        let data_type = witness
+            .get(WITNESS_HEADER_BYTES..(WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES))
+            .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()))
+            .and_then(|value| DataType::try_from(value).ok())
+            .ok_or_else(|| code_to_error!(ErrorCode::WitnessDataTypeDecodingError))?;
+        das_assert!(
+            das_types_util::is_config_data_type(&data_type),
+            ErrorCode::WitnessDataTypeDecodingError,
+            "witnesses[{:>2}] The witness of {:?} is not a witness of ConfigCell.",
+            i,
+            data_type
+        );
+        das_assert!(
+            !map.contains_key(&data_type),
+            ErrorCode::WitnessStructureError,
+            "witnesses[{:>2}] The witness of {:?} is duplicated.",
+            i,
+            data_type
+        );
+
+        map.insert(data_type, (i, witness.to_vec()));
+    }
+
+    Ok(map)
+}
+
+/// Decode the witnesses in a JSON snapshot, see `Config::from_snapshot_json`.
+#[cfg(feature = "snapshot")]
+pub(crate) fn parse_snapshot_json(json: &str) -> Result<Vec<Vec<u8>>, Box<dyn ScriptError>> {
+    let snapshot: serde_json::Value =
+        serde_json::from_str(json).map_err(|_| code_to_error!(ErrorCode::ConfigCellWitnessDecodingError))?;
+
+    let witnesses = snapshot
+        .get("witnesses")
+        .and_then(|value| value.as_array())
+        .ok_or_else(|| code_to_error!(ErrorCode::ConfigIsPartialMissing))?
+        .iter()
+        .map(|value| {
+            value
+                .as_str()
+                .map(|value| value.strip_prefix("0x").unwrap_or(value))
+                .and_then(|value| hex::decode(value).ok())
+                .ok_or_else(|| code_to_error!(ErrorCode::ConfigCellWitnessDecodingError))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(witnesses)
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::format;
+
+    use super::*;
+
+    fn witness(data_type: DataType, entity: &[u8]) -> Vec<u8> {
+        let mut ret = WITNESS_HEADER.to_vec();
+        ret.extend((data_type as u32).to_le_bytes());
+        ret.extend(entity);
+        ret
+    }
+
+    fn error_code(ret: Result<Config, Box<dyn ScriptError>>) -> i8 {
+        ret.map(|_| ()).unwrap_err().as_i8()
+    }
+
+    #[test]
+    fn test_from_witnesses() {
+        let main = ConfigCellMain::default();
+        let mut raw = 5u32.to_le_bytes().to_vec();
+        raw.push(1);
+        let config = Config::from_witnesses([
+            witness(DataType::ConfigCellMain, main.as_slice()),
+            witness(DataType::ConfigCellRecordKeyNamespace, &raw),
+        ])
+        .unwrap();
+
+        assert_eq!(main.as_slice(), config.main().unwrap().as_slice());
+        assert_eq!(&vec![1], config.record_key_namespace().unwrap());
+        assert_eq!(
+            ErrorCode::ConfigIsPartialMissing as i8,
+            config.account().map(|_| ()).unwrap_err().as_i8()
+        );
+    }
+
+    #[test]
+    fn test_configs_from_witnesses() {
+        let main = ConfigCellMain::default();
+        let configs =
+            crate::types::Configs::from_witnesses([witness(DataType::ConfigCellMain, main.as_slice())]).unwrap();
+
+        assert_eq!(main.as_slice(), configs.main().unwrap().as_slice());
+        assert_eq!(
+            ErrorCode::ConfigIsPartialMissing as i8,
+            configs.account().map(|_| ()).unwrap_err().as_i8()
+        );
+    }
+
+    #[test]
+    fn test_from_invalid_witnesses() {
+        let main = witness(DataType::ConfigCellMain, ConfigCellMain::default().as_slice());
+        assert_eq!(
+            ErrorCode::WitnessStructureError as i8,
+            error_code(Config::from_witnesses([main.clone(), main.clone()]))
+        );
+        assert_eq!(
+            ErrorCode::WitnessDataTypeDecodingError as i8,
+            error_code(Config::from_witnesses([witness(DataType::ActionData, &[])]))
+        );
+        assert_eq!(
+            ErrorCode::WitnessDataDecodingError as i8,
+            error_code(Config::from_witnesses([&main[1..]]))
+        );
+    }
+
+    #[cfg(feature = "snapshot")]
+    #[test]
+    fn test_from_snapshot_json() {
+        let main = ConfigCellMain::default();
+        let raw = witness(DataType::ConfigCellMain, main.as_slice());
+
+        // The 0x prefix is optional.
+        for hex in [format!("0x{}", hex::encode(&raw)), hex::encode(&raw)] {
+            let json = format!(r#"{{ "witnesses": ["{}"] }}"#, hex);
+            let config = Config::from_snapshot_json(&json).unwrap();
+            assert_eq!(main.as_slice(), config.main().unwrap().as_slice());
+        }
+
+        assert_eq!(
+            ErrorCode::ConfigIsPartialMissing as i8,
+            error_code(Config::from_snapshot_json(r#"{ "cells": [] }"#))
+        );
+        assert_eq!(
+            ErrorCode::ConfigCellWitnessDecodingError as i8,
+            error_code(Config::from_snapshot_json(r#"{ "witnesses": ["0x6461g"] }"#))
+        );
+        assert_eq!(
+            ErrorCode::ConfigCellWitnessDecodingError as i8,
+            error_code(Config::from_snapshot_json("not json"))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_snapshot_file() {
+        let main = ConfigCellMain::default();
+        let raw = witness(DataType::ConfigCellMain, main.as_slice());
+        let path = std::env::temp_dir().join(format!("das-core-config-snapshot-{}.json", std::process::id()));
+        std::fs::write(&path, format!(r#"{{ "witnesses": ["0x{}"] }}"#, hex::encode(&raw))).unwrap();
+
+        let config = Config::from_snapshot_file(&path).unwrap();
+        let configs = crate::types::Configs::from_snapshot_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(main.as_slice(), config.main().unwrap().as_slice());
+        assert_eq!(main.as_slice(), configs.main().unwrap().as_slice());
+        assert_eq!(
+            ErrorCode::ConfigIsPartialMissing as i8,
+            error_code(Config::from_snapshot_file(&path))
+        );
+    }
+}
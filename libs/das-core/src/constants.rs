@@ -41,7 +41,7 @@ pub const DPOINT_MAX_LIMIT: u64 = 10_000_000 * ONE_USD;
 
 pub const RATE_BASE: u64 = 10_000;
 
-pub const ACCOUNT_SUFFIX: &str = ".bit";
+pub use das_types::constants::ACCOUNT_SUFFIX;
 pub const ACCOUNT_MAX_PRICED_LENGTH: u8 = 8;
 
 pub const CUSTOM_KEYS_NAMESPACE: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz_";
@@ -1,42 +1,169 @@
 use alloc::boxed::Box;
-use alloc::string::String;
+use alloc::format;
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
-use core::slice::SlicePattern;
+use core::cell::OnceCell;
+use core::cmp::Ordering;
 
 use ckb_std::ckb_constants::Source;
 use ckb_std::ckb_types::packed::Script;
-use ckb_std::high_level::{load_cell, QueryIter};
 use ckb_std::syscalls::SysError;
+use das_types::constants::{Action as DasAction, TypeScript};
 use das_types::packed::ActionData;
 use molecule::prelude::Entity;
 
 use super::traits::{CellWithMeta, Contract, FSMContract, Verification};
-use crate::error::ScriptError;
+use crate::error::{ErrorCode, ScriptError};
+use crate::syscall::high_level::{load_cell, load_script, QueryIter};
 use crate::witness_parser::general_witness_parser::{Meta, WithMeta};
+use crate::{util, verifiers};
+
+/// The expected cells with the current script in inputs and outputs, each variant is verified by the verifier with
+/// the same name in `verifiers::common`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CellShape {
+    /// The number of cells in inputs and outputs, like `verify_cell_number`.
+    Number(usize, usize),
+    /// The ranges of the number of cells, like `verify_cell_number_range`.
+    Range((Ordering, usize), (Ordering, usize)),
+    /// The exact positions of cells, like `verify_cell_number_and_position`.
+    Position(Vec<usize>, Vec<usize>),
+}
+
+impl CellShape {
+    fn desc(&self, cell_name: &str) -> String {
+        match self {
+            CellShape::Position(..) => format!("Verify if the number and position of {}s is correct.", cell_name),
+            _ => format!("Verify if the number of {}s is correct.", cell_name),
+        }
+    }
+
+    fn verify(&self, cell_name: &str, inputs: &[usize], outputs: &[usize]) -> Result<(), Box<dyn ScriptError>> {
+        match self {
+            CellShape::Number(expected_inputs, expected_outputs) => {
+                verifiers::common::verify_cell_number(cell_name, inputs, *expected_inputs, outputs, *expected_outputs)
+            }
+            CellShape::Range(expected_inputs, expected_outputs) => verifiers::common::verify_cell_number_range(
+                cell_name,
+                inputs,
+                *expected_inputs,
+                outputs,
+                *expected_outputs,
+            ),
+            CellShape::Position(expected_inputs, expected_outputs) => {
+                verifiers::common::verify_cell_number_and_position(
+                    cell_name,
+                    inputs,
+                    expected_inputs,
+                    outputs,
+                    expected_outputs,
+                )
+            }
+        }
+    }
+}
+
+/// Verify the cells with the current script against a `CellShape`.
+struct CellShapeRule {
+    desc: String,
+    cell_name: String,
+    shape: CellShape,
+}
+
+impl Verification for CellShapeRule {
+    fn verify(&self, contract: &mut dyn Contract) -> Result<(), Box<dyn ScriptError>> {
+        self.shape.verify(
+            &self.cell_name,
+            &contract.get_input_inner_indexes(),
+            &contract.get_output_inner_indexes(),
+        )
+    }
+
+    fn desc(&self) -> &str {
+        &self.desc
+    }
+}
+
+/// Verify that some cells with the type script exist in the source.
+struct TypeScriptRule {
+    desc: String,
+    type_script: TypeScript,
+    source: Source,
+}
+
+impl Verification for TypeScriptRule {
+    fn verify(&self, _contract: &mut dyn Contract) -> Result<(), Box<dyn ScriptError>> {
+        util::require_type_script(self.type_script, self.source, ErrorCode::InvalidTransactionStructure)
+    }
+
+    fn desc(&self) -> &str {
+        &self.desc
+    }
+}
 
+/// An action with its verifications, they are verified in the order they are added, including the declarations like
+/// `set_cell_shape` and `require_type_script`.
 pub struct Action {
-    // TODO Refactor this to an enum; otherwise, it could be seriously misleading if anyone gives two actions the same name.
-    pub name: String,
+    pub action: DasAction,
     pub is_default: bool,
+    /// Skip the pre-checks of `RegisteredActions`, like skipping `is_system_off` when initializing.
+    pub skip_pre_checks: bool,
     pub(crate) verifications: Vec<Box<dyn Verification>>,
 }
 
 impl Action {
-    pub fn new(name: impl Into<String>) -> Self {
+    pub fn new(action: DasAction) -> Self {
         Self {
-            name: name.into(),
+            action,
             is_default: false,
+            skip_pre_checks: false,
             verifications: Vec::new(),
         }
     }
 
+    pub fn name(&self) -> String {
+        self.action.to_string()
+    }
+
     pub fn set_default(&mut self) {
         self.is_default = true;
     }
 
+    pub fn set_skip_pre_checks(&mut self) {
+        self.skip_pre_checks = true;
+    }
+
+    /// Declare the shape of the cells with the current script.
+    pub fn set_cell_shape(&mut self, cell_name: impl Into<String>, shape: CellShape) {
+        let cell_name = cell_name.into();
+        self.add_verification(CellShapeRule {
+            desc: shape.desc(&cell_name),
+            cell_name,
+            shape,
+        })
+    }
+
+    /// Declare that some cells with the type script are required in the source, like the cells of other contracts in
+    /// inputs or outputs, and the cells in cell_deps.
+    pub fn require_type_script(&mut self, type_script: TypeScript, source: Source) {
+        self.add_verification(TypeScriptRule {
+            desc: format!("Verify if the {} is required in {:?}.", type_script, source),
+            type_script,
+            source,
+        })
+    }
+
     pub fn add_verification(&mut self, verification: impl Verification + 'static) {
         self.verifications.push(Box::new(verification))
     }
+
+    /// List the descriptions of all the rules in the order they are verified.
+    pub fn rules(&self) -> Vec<String> {
+        self.verifications
+            .iter()
+            .map(|verification| verification.desc().to_string())
+            .collect()
+    }
 }
 
 pub struct Rule<T> {
@@ -54,6 +181,10 @@ where
         debug!("Finished verify: {}", &self.desc);
         Ok(())
     }
+
+    fn desc(&self) -> &str {
+        &self.desc
+    }
 }
 
 impl<T> Rule<T>
@@ -77,14 +208,14 @@ where
     }
 }
 
+/// The cells are loaded when they are used for the first time, so the actions which fail early, like the ones not
+/// supported, do not load any cell.
 pub struct MyContract {
     pub registered_actions: Vec<Action>,
     pub action_data: ActionData,
     pub this_script: Script,
-    pub input_inner_cells: Vec<CellWithMeta>,
-    pub input_outer_cells: Vec<CellWithMeta>,
-    pub output_inner_cells: Vec<CellWithMeta>,
-    pub output_outer_cells: Vec<CellWithMeta>,
+    input_cells: OnceCell<(Vec<CellWithMeta>, Vec<CellWithMeta>)>,
+    output_cells: OnceCell<(Vec<CellWithMeta>, Vec<CellWithMeta>)>,
 }
 
 // #[derive(Clone, Debug)]
@@ -128,26 +259,33 @@ impl FSMContract for MyContract {
 
 impl MyContract {
     pub fn new(action_data: ActionData) -> Result<Self, Box<dyn ScriptError>> {
-        fn load_cell_with_meta(index: usize, source: Source) -> Result<CellWithMeta, SysError> {
-            load_cell(index, source).map(|cell| WithMeta::new(cell, Meta { index, source }))
-        }
-        let this_script = ckb_std::high_level::load_script()?;
-        let (input_inner_cells, input_outer_cells): (Vec<_>, Vec<_>) =
-            QueryIter::new(load_cell_with_meta, Source::Input)
-                .partition(|cell| cell.type_().as_slice() == this_script.as_slice());
-        let (output_inner_cells, output_outer_cells): (Vec<_>, Vec<_>) =
-            QueryIter::new(load_cell_with_meta, Source::Output)
-                .partition(|cell| cell.type_().as_slice() == this_script.as_slice());
+        let this_script = load_script()?;
         Ok(Self {
             registered_actions: Vec::new(),
             action_data,
             this_script,
-            input_inner_cells,
-            input_outer_cells,
-            output_inner_cells,
-            output_outer_cells,
+            input_cells: OnceCell::new(),
+            output_cells: OnceCell::new(),
         })
     }
+
+    /// Load the cells in the source and partition them into the ones with the current script and the others.
+    fn load_cells(&self, source: Source) -> (Vec<CellWithMeta>, Vec<CellWithMeta>) {
+        fn load_cell_with_meta(index: usize, source: Source) -> Result<CellWithMeta, SysError> {
+            load_cell(index, source).map(|cell| WithMeta::new(cell, Meta { index, source }))
+        }
+
+        QueryIter::new(load_cell_with_meta, source)
+            .partition(|cell| cell.type_().as_slice() == self.this_script.as_slice())
+    }
+
+    fn input_cells(&self) -> &(Vec<CellWithMeta>, Vec<CellWithMeta>) {
+        self.input_cells.get_or_init(|| self.load_cells(Source::Input))
+    }
+
+    fn output_cells(&self) -> &(Vec<CellWithMeta>, Vec<CellWithMeta>) {
+        self.output_cells.get_or_init(|| self.load_cells(Source::Output))
+    }
 }
 
 // pub trait GetCellWitness {
@@ -166,19 +304,19 @@ impl MyContract {
 
 impl Contract for MyContract {
     fn get_input_inner_cells(&self) -> &Vec<CellWithMeta> {
-        &self.input_inner_cells
+        &self.input_cells().0
     }
 
     fn get_input_outer_cells(&self) -> &Vec<CellWithMeta> {
-        &self.input_outer_cells
+        &self.input_cells().1
     }
 
     fn get_output_inner_cells(&self) -> &Vec<CellWithMeta> {
-        &self.output_inner_cells
+        &self.output_cells().0
     }
 
     fn get_output_outer_cells(&self) -> &Vec<CellWithMeta> {
-        &self.output_outer_cells
+        &self.output_cells().1
     }
 
     fn get_this_script(&self) -> &Script {
@@ -193,6 +331,7 @@ impl Contract for MyContract {
 #[derive(Default)]
 pub struct RegisteredActions {
     registered_actions: Vec<Action>,
+    pre_checks: Vec<Box<dyn Verification>>,
 }
 
 impl RegisteredActions {
@@ -200,24 +339,303 @@ impl RegisteredActions {
         self.registered_actions.push(action)
     }
 
-    pub fn get_active_action(&mut self, action_data: &ActionData) -> Option<Action> {
-        let mut default_action = None;
-        while let Some(action) = self.registered_actions.pop() {
-            if action.name.as_bytes() == action_data.action().raw_data().as_slice() {
-                debug!("==== Route to action {} ====", action.name);
-                return Some(action);
-            }
+    /// Add a verification which runs before every action, unless the action skips pre-checks.
+    pub fn add_pre_check(&mut self, verification: impl Verification + 'static) {
+        self.pre_checks.push(Box::new(verification))
+    }
 
-            if action.is_default {
-                default_action = Some(action)
-            }
+    pub fn get_active_action(&self, action_data: &ActionData) -> Option<&Action> {
+        let action = action_data.action().raw_data();
+        self.take_action(DasAction::new(core::str::from_utf8(&action).unwrap_or_default()))
+    }
+
+    /// Find the action to run, the registered actions are kept, so `rules` still lists all of them.
+    ///
+    /// If the same action is registered more than once, the last one is taken. If no action matches, the first
+    /// default action is taken.
+    pub fn take_action(&self, action: DasAction) -> Option<&Action> {
+        if let Some(registered_action) = self.registered_actions.iter().rev().find(|val| val.action == action) {
+            debug!("==== Route to action {} ====", registered_action.name());
+            return Some(registered_action);
         }
 
         // TODO Change action to explicit declaration
-        if let Some(_action) = default_action.as_ref() {
-            debug!("==== Route to action {} ====", _action.name);
+        let default_action = self.registered_actions.iter().find(|val| val.is_default);
+        if let Some(_action) = default_action {
+            debug!("==== Route to action {} ====", _action.name());
         }
 
         default_action
     }
+
+    /// Route to the action, then run the pre-checks and the action against the contract.
+    ///
+    /// The pre-checks also run when no action matches, so the errors like `SystemOff` take precedence over
+    /// `ActionNotSupported`.
+    pub fn run<T: FSMContract>(&self, action: DasAction, contract: &mut T) -> Result<(), Box<dyn ScriptError>> {
+        let active_action = self.take_action(action);
+        if !active_action.map(|val| val.skip_pre_checks).unwrap_or(false) {
+            for verification in self.pre_checks.iter() {
+                verification.verify(contract)?;
+            }
+        }
+
+        let active_action = active_action.ok_or_else(|| code_to_error!(ErrorCode::ActionNotSupported))?;
+        contract.run_against_action(active_action)
+    }
+
+    /// List the rules of every registered action, including the pre-checks.
+    pub fn rules(&self) -> Vec<(DasAction, Vec<String>)> {
+        self.registered_actions
+            .iter()
+            .map(|action| {
+                let mut rules = Vec::new();
+                if !action.skip_pre_checks {
+                    rules.extend(self.pre_checks.iter().map(|val| val.desc().to_string()));
+                }
+                rules.extend(action.rules());
+                (action.action, rules)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec;
+
+    use ckb_std::ckb_types::packed::CellOutput;
+
+    use super::*;
+    use crate::error::AccountCellErrorCode;
+
+    struct FakeContract {
+        action_data: ActionData,
+        this_script: Script,
+        input_inner_cells: Vec<CellWithMeta>,
+        output_inner_cells: Vec<CellWithMeta>,
+        outer_cells: Vec<CellWithMeta>,
+    }
+
+    impl FakeContract {
+        fn new(inputs: &[usize], outputs: &[usize]) -> Self {
+            fn cells(indexes: &[usize], source: Source) -> Vec<CellWithMeta> {
+                indexes
+                    .iter()
+                    .map(|index| WithMeta::new(CellOutput::default(), Meta { index: *index, source }))
+                    .collect()
+            }
+
+            FakeContract {
+                action_data: ActionData::default(),
+                this_script: Script::default(),
+                input_inner_cells: cells(inputs, Source::Input),
+                output_inner_cells: cells(outputs, Source::Output),
+                outer_cells: Vec::new(),
+            }
+        }
+    }
+
+    impl Contract for FakeContract {
+        fn get_input_inner_cells(&self) -> &Vec<CellWithMeta> {
+            &self.input_inner_cells
+        }
+
+        fn get_input_outer_cells(&self) -> &Vec<CellWithMeta> {
+            &self.outer_cells
+        }
+
+        fn get_output_inner_cells(&self) -> &Vec<CellWithMeta> {
+            &self.output_inner_cells
+        }
+
+        fn get_output_outer_cells(&self) -> &Vec<CellWithMeta> {
+            &self.outer_cells
+        }
+
+        fn get_this_script(&self) -> &Script {
+            &self.this_script
+        }
+    }
+
+    impl FSMContract for FakeContract {
+        fn get_action_data(&self) -> &ActionData {
+            &self.action_data
+        }
+    }
+
+    fn action(action: DasAction, error: Option<ErrorCode>) -> Action {
+        let mut action = Action::new(action);
+        action.add_verification(Rule::new(
+            format!("Verify {}.", action.name()),
+            move |_contract: &mut dyn Contract| match error {
+                Some(error) => Err(code_to_error!(error)),
+                None => Ok(()),
+            },
+        ));
+        action
+    }
+
+    fn registered_actions(pre_check_error: Option<ErrorCode>) -> RegisteredActions {
+        let mut actions = RegisteredActions::default();
+        actions.add_pre_check(Rule::new(
+            "Verify if the DAS system is on.",
+            move |_contract: &mut dyn Contract| match pre_check_error {
+                Some(error) => Err(code_to_error!(error)),
+                None => Ok(()),
+            },
+        ));
+
+        let mut init = action(DasAction::InitAccountChain, None);
+        init.set_skip_pre_checks();
+        actions.register_action(init);
+        actions.register_action(action(DasAction::TransferAccount, None));
+        actions.register_action(action(
+            DasAction::EditManager,
+            Some(ErrorCode::InvalidTransactionStructure),
+        ));
+
+        actions
+    }
+
+    #[test]
+    fn test_register_and_take_action() {
+        let mut actions = registered_actions(None);
+        assert_eq!(
+            DasAction::TransferAccount,
+            actions.take_action(DasAction::TransferAccount).unwrap().action
+        );
+        core::assert!(actions.take_action(DasAction::RenewAccount).is_none());
+
+        // The actions are kept after taken.
+        assert_eq!(
+            DasAction::TransferAccount,
+            actions.take_action(DasAction::TransferAccount).unwrap().action
+        );
+        assert_eq!(3, actions.rules().len());
+
+        // The last registered action wins, and the first default action is taken when no action matches.
+        let mut default_action = action(DasAction::RenewAccount, None);
+        default_action.set_default();
+        actions.register_action(default_action);
+        actions.register_action(action(DasAction::TransferAccount, Some(ErrorCode::ActionNotSupported)));
+        assert_eq!(
+            1,
+            actions
+                .take_action(DasAction::TransferAccount)
+                .unwrap()
+                .verifications
+                .len()
+        );
+        assert_eq!(
+            DasAction::RenewAccount,
+            actions.take_action(DasAction::BuyAccount).unwrap().action
+        );
+    }
+
+    #[test]
+    fn test_run() {
+        let actions = registered_actions(None);
+        let mut contract = FakeContract::new(&[0], &[0]);
+        core::assert!(actions.run(DasAction::TransferAccount, &mut contract).is_ok());
+        assert_eq!(
+            ErrorCode::InvalidTransactionStructure as i8,
+            actions.run(DasAction::EditManager, &mut contract).unwrap_err().as_i8()
+        );
+        assert_eq!(
+            ErrorCode::ActionNotSupported as i8,
+            actions.run(DasAction::RenewAccount, &mut contract).unwrap_err().as_i8()
+        );
+
+        // The pre-checks take precedence over ActionNotSupported, unless the action skips them.
+        let actions = registered_actions(Some(ErrorCode::SystemOff));
+        assert_eq!(
+            ErrorCode::SystemOff as i8,
+            actions
+                .run(DasAction::TransferAccount, &mut contract)
+                .unwrap_err()
+                .as_i8()
+        );
+        assert_eq!(
+            ErrorCode::SystemOff as i8,
+            actions.run(DasAction::RenewAccount, &mut contract).unwrap_err().as_i8()
+        );
+        core::assert!(actions.run(DasAction::InitAccountChain, &mut contract).is_ok());
+    }
+
+    #[test]
+    fn test_cell_shape() {
+        let mut action = Action::new(DasAction::RecycleExpiredAccount);
+        action.set_cell_shape("AccountCell", CellShape::Position(vec![0, 1], vec![0]));
+        let mut actions = RegisteredActions::default();
+        actions.register_action(action);
+
+        for (inputs, outputs, is_ok) in [
+            (vec![0, 1], vec![0], true),
+            (vec![0], vec![0], false),
+            (vec![0, 2], vec![0], false),
+            (vec![0, 1], vec![1], false),
+        ] {
+            let mut contract = FakeContract::new(&inputs, &outputs);
+            assert_eq!(
+                is_ok,
+                actions.run(DasAction::RecycleExpiredAccount, &mut contract).is_ok(),
+                "The shape of {:?} {:?} is verified wrongly.",
+                inputs,
+                outputs
+            );
+        }
+
+        let mut contract = FakeContract::new(&[0, 1], &[0]);
+        core::assert!(CellShape::Number(2, 1).verify("AccountCell", &[0, 1], &[0]).is_ok());
+        core::assert!(CellShape::Number(1, 1).verify("AccountCell", &[0, 1], &[0]).is_err());
+        core::assert!(CellShape::Range((Ordering::Greater, 1), (Ordering::Equal, 1))
+            .verify("AccountCell", &[0, 1], &[0])
+            .is_ok());
+
+        // The verifications run in the order they are added.
+        let mut action = Action::new(DasAction::TransferAccount);
+        action.add_verification(Rule::new(
+            "Verify if the transaction is signed by the required role.",
+            |_contract: &mut dyn Contract| Err(code_to_error!(AccountCellErrorCode::AccountCellPermissionDenied)),
+        ));
+        action.set_cell_shape("AccountCell", CellShape::Number(1, 1));
+        assert_eq!(
+            AccountCellErrorCode::AccountCellPermissionDenied as i8,
+            contract.run_against_action(&action).unwrap_err().as_i8()
+        );
+    }
+
+    #[test]
+    fn test_rules() {
+        let mut actions = registered_actions(None);
+        let mut action = Action::new(DasAction::ConfirmProposal);
+        action.set_cell_shape("AccountCell", CellShape::Position(vec![0], vec![0]));
+        action.require_type_script(TypeScript::ProposalCellType, Source::Input);
+        actions.register_action(action);
+
+        let rules = actions.rules();
+        assert_eq!(
+            vec![
+                DasAction::InitAccountChain,
+                DasAction::TransferAccount,
+                DasAction::EditManager,
+                DasAction::ConfirmProposal
+            ],
+            rules.iter().map(|(action, _)| *action).collect::<Vec<_>>()
+        );
+        assert_eq!(vec![String::from("Verify init_account_chain.")], rules[0].1);
+        assert_eq!(
+            vec![
+                String::from("Verify if the DAS system is on."),
+                String::from("Verify if the number and position of AccountCells is correct."),
+                format!(
+                    "Verify if the {} is required in {:?}.",
+                    TypeScript::ProposalCellType,
+                    Source::Input
+                ),
+            ],
+            rules[3].1
+        );
+    }
 }
@@ -12,6 +12,10 @@ pub type CellWithMeta = WithMeta<CellOutput>;
 
 pub trait Verification {
     fn verify(&self, contract: &mut dyn Contract) -> Result<(), Box<dyn ScriptError>>;
+
+    fn desc(&self) -> &str {
+        ""
+    }
 }
 
 pub trait Contract {
@@ -20,6 +24,14 @@ pub trait Contract {
     fn get_output_inner_cells(&self) -> &Vec<CellWithMeta>;
     fn get_output_outer_cells(&self) -> &Vec<CellWithMeta>;
     fn get_this_script(&self) -> &Script;
+
+    fn get_input_inner_indexes(&self) -> Vec<usize> {
+        self.get_input_inner_cells().iter().map(|cell| cell.meta.index).collect()
+    }
+
+    fn get_output_inner_indexes(&self) -> Vec<usize> {
+        self.get_output_inner_cells().iter().map(|cell| cell.meta.index).collect()
+    }
 }
 
 pub trait FSMContract: Contract + Sized {
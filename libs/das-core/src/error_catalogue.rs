@@ -0,0 +1,1678 @@
+//! The catalogue of all the exit codes of the DAS contracts.
+//!
+//! All the contracts share the `i8` exit code space with the following rules:
+//!
+//! - 1 ~ 49 and the negative codes are global, they have the same meaning in every contract, this is checked at
+//!   compile time;
+//! - 50 ~ 126 are local to the contracts which have their own error enums, like `AccountCellErrorCode`, the rest of
+//!   the contracts use the shared errors of `ErrorCode` in this range.
+//!
+//! So an exit code can only be explained together with the script which returned it, that is what `lookup` does. The
+//! codes are not renumbered, because they are part of the interface of the deployed contracts. Instead a local code
+//! may have more than one candidate when a contract returns errors of several enums, like `53` of the
+//! account-cell-type which is either `AccountCellPermissionDenied` or `ErrorCode::WitnessVersionOrTypeInvalid`.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use das_types::constants::TypeScript;
+
+use crate::error::{
+    AccountCellErrorCode, ErrorCode, PreAccountCellErrorCode, ReverseRecordRootCellErrorCode, SubAccountCellErrorCode,
+};
+
+/// The first exit code which is local to the contracts.
+pub const CONTRACT_LOCAL_CODE_START: i8 = 50;
+
+/// The global codes have the same meaning in every contract.
+pub const fn is_global_code(code: i8) -> bool {
+    code < CONTRACT_LOCAL_CODE_START
+}
+
+/// The error enums, every error in the catalogue belongs to one of them.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ErrorScope {
+    Common,
+    AccountCell,
+    PreAccountCell,
+    ReverseRecordRootCell,
+    SubAccountCell,
+    DPointCell,
+    DeviceKeyListCell,
+}
+
+impl ErrorScope {
+    /// The path of the error enum.
+    pub const fn enum_path(&self) -> &'static str {
+        match self {
+            ErrorScope::Common => "das_core::error::ErrorCode",
+            ErrorScope::AccountCell => "das_core::error::AccountCellErrorCode",
+            ErrorScope::PreAccountCell => "das_core::error::PreAccountCellErrorCode",
+            ErrorScope::ReverseRecordRootCell => "das_core::error::ReverseRecordRootCellErrorCode",
+            ErrorScope::SubAccountCell => "das_core::error::SubAccountCellErrorCode",
+            ErrorScope::DPointCell => "dpoint_cell_type::error::ErrorCode",
+            ErrorScope::DeviceKeyListCell => "device_key_list_cell_type::error::ErrorCode",
+        }
+    }
+
+    pub const fn entries(&self) -> &'static [ErrorEntry] {
+        match self {
+            ErrorScope::Common => COMMON_ERRORS,
+            ErrorScope::AccountCell => ACCOUNT_CELL_ERRORS,
+            ErrorScope::PreAccountCell => PRE_ACCOUNT_CELL_ERRORS,
+            ErrorScope::ReverseRecordRootCell => REVERSE_RECORD_ROOT_CELL_ERRORS,
+            ErrorScope::SubAccountCell => SUB_ACCOUNT_CELL_ERRORS,
+            ErrorScope::DPointCell => DPOINT_CELL_ERRORS,
+            ErrorScope::DeviceKeyListCell => DEVICE_KEY_LIST_CELL_ERRORS,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ErrorEntry {
+    pub code: i8,
+    pub scope: ErrorScope,
+    pub name: &'static str,
+    pub description: &'static str,
+    /// The most likely cause of the error, it is written for the users and the developers of wallets.
+    pub cause: &'static str,
+}
+
+impl ErrorEntry {
+    const fn new(code: i8, scope: ErrorScope, name: &'static str, text: (&'static str, &'static str)) -> Self {
+        ErrorEntry {
+            code,
+            scope,
+            name,
+            description: text.0,
+            cause: text.1,
+        }
+    }
+
+    /// Convert the entry back to the error enum, the errors of the contracts which define their own error enums are
+    /// not available in das-core.
+    pub fn to_typed(&self) -> Option<TypedError> {
+        let typed = match self.scope {
+            ErrorScope::Common => TypedError::Common(ErrorCode::from_code(self.code)?),
+            ErrorScope::AccountCell => TypedError::AccountCell(AccountCellErrorCode::from_code(self.code)?),
+            ErrorScope::PreAccountCell => TypedError::PreAccountCell(PreAccountCellErrorCode::from_code(self.code)?),
+            ErrorScope::ReverseRecordRootCell => {
+                TypedError::ReverseRecordRootCell(ReverseRecordRootCellErrorCode::from_code(self.code)?)
+            }
+            ErrorScope::SubAccountCell => TypedError::SubAccountCell(SubAccountCellErrorCode::from_code(self.code)?),
+            ErrorScope::DPointCell | ErrorScope::DeviceKeyListCell => return None,
+        };
+
+        Some(typed)
+    }
+}
+
+impl fmt::Display for ErrorEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}({}): {}", self.name, self.code, self.description)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TypedError {
+    Common(ErrorCode),
+    AccountCell(AccountCellErrorCode),
+    PreAccountCell(PreAccountCellErrorCode),
+    ReverseRecordRootCell(ReverseRecordRootCellErrorCode),
+    SubAccountCell(SubAccountCellErrorCode),
+}
+
+/// The error enums which a contract may return, the former ones are more likely when they share an exit code.
+pub const fn contract_scopes(script: TypeScript) -> &'static [ErrorScope] {
+    match script {
+        // The errors of SubAccountCellErrorCode are also returned when the AccountCell is operating its sub-accounts.
+        TypeScript::AccountCellType => &[ErrorScope::AccountCell, ErrorScope::SubAccountCell, ErrorScope::Common],
+        TypeScript::EIP712Lib => &[ErrorScope::AccountCell, ErrorScope::Common],
+        TypeScript::PreAccountCellType => &[ErrorScope::PreAccountCell, ErrorScope::Common],
+        TypeScript::ReverseRecordRootCellType => &[ErrorScope::ReverseRecordRootCell, ErrorScope::Common],
+        TypeScript::SubAccountCellType => &[ErrorScope::SubAccountCell, ErrorScope::Common],
+        TypeScript::DPointCellType => &[ErrorScope::DPointCell, ErrorScope::Common],
+        TypeScript::DeviceKeyListCellType => &[ErrorScope::DeviceKeyListCell, ErrorScope::Common],
+        _ => &[ErrorScope::Common],
+    }
+}
+
+/// Explain the exit code returned by the script, like `-37` of the account-cell-type.
+///
+/// Every error enum of the script which uses the code gives a candidate, in the order of `contract_scopes`. A global
+/// code always has one candidate, and `check_codes` makes sure the candidates of a local code come from different
+/// enums.
+pub fn lookup(script: TypeScript, code: i8) -> Vec<&'static ErrorEntry> {
+    let mut candidates: Vec<&'static ErrorEntry> = contract_scopes(script)
+        .iter()
+        .filter_map(|scope| scope.entries().iter().find(|entry| entry.code == code))
+        .collect();
+    if is_global_code(code) {
+        candidates.truncate(1);
+    }
+
+    candidates
+}
+
+/// All the contracts in the catalogue.
+pub const CONTRACTS: &[TypeScript] = &[
+    TypeScript::AccountCellType,
+    TypeScript::AccountSaleCellType,
+    TypeScript::ApplyRegisterCellType,
+    TypeScript::BalanceCellType,
+    TypeScript::ConfigCellType,
+    TypeScript::IncomeCellType,
+    TypeScript::OfferCellType,
+    TypeScript::PreAccountCellType,
+    TypeScript::ProposalCellType,
+    TypeScript::ReverseRecordCellType,
+    TypeScript::SubAccountCellType,
+    TypeScript::ReverseRecordRootCellType,
+    TypeScript::DPointCellType,
+    TypeScript::EIP712Lib,
+    TypeScript::DeviceKeyListCellType,
+];
+
+/// Export the catalogue as a JSON array, every exit code of every contract appears with all the candidates `lookup`
+/// returns.
+#[cfg(feature = "std")]
+pub fn to_json() -> serde_json::Value {
+    use alloc::string::ToString;
+
+    let mut items = Vec::new();
+    for script in CONTRACTS.iter().copied() {
+        let mut codes = Vec::new();
+        for entry in contract_scopes(script).iter().flat_map(|scope| scope.entries()) {
+            if is_global_code(entry.code) && codes.contains(&entry.code) {
+                continue;
+            }
+            codes.push(entry.code);
+            items.push(serde_json::json!({
+                "code": entry.code,
+                "contract": script.to_string(),
+                "enum": entry.scope.enum_path(),
+                "name": entry.name,
+                "description": entry.description,
+                "cause": entry.cause,
+            }));
+        }
+    }
+
+    serde_json::Value::Array(items)
+}
+
+/// Build the catalogue of an error enum of das-core, the match in `entry` makes sure every variant is catalogued.
+macro_rules! catalogue {
+    ($table:ident, $scope:expr, $enum:ident { $($variant:ident => $text:expr,)* }) => {
+        pub const $table: &[ErrorEntry] = &[
+            $(ErrorEntry::new($enum::$variant as i8, $scope, stringify!($variant), $text),)*
+        ];
+
+        impl $enum {
+            pub const fn entry(self) -> ErrorEntry {
+                match self {
+                    $($enum::$variant => ErrorEntry::new($enum::$variant as i8, $scope, stringify!($variant), $text),)*
+                }
+            }
+
+            pub const fn from_code(code: i8) -> Option<Self> {
+                $(
+                    if code == $enum::$variant as i8 {
+                        return Some($enum::$variant);
+                    }
+                )*
+                None
+            }
+        }
+    };
+    ($table:ident, $scope:expr, { $($code:literal $variant:ident => $text:expr,)* }) => {
+        pub const $table: &[ErrorEntry] = &[$(ErrorEntry::new($code, $scope, stringify!($variant), $text),)*];
+    };
+}
+
+// The errors of syscalls and the global errors which every error enum reserves.
+const INDEX_OUT_OF_BOUND: (&str, &str) = (
+    "A syscall loaded a cell, witness or header with an index out of bound.",
+    "The transaction is missing some cells or witnesses which the contract expects.",
+);
+const ITEM_MISSING: (&str, &str) = (
+    "A syscall loaded a field which does not exist.",
+    "Some cell is missing its type script or some header is missing from the header_deps.",
+);
+const LENGTH_NOT_ENOUGH: (&str, &str) = (
+    "A syscall was called with a buffer which is too small.",
+    "This is a bug of the contract, please report it.",
+);
+const ENCODING: (&str, &str) = (
+    "A syscall loaded data which can not be decoded.",
+    "Some cell or witness in the transaction is malformed.",
+);
+const INCOME_CELL_CONSOLIDATE_CONDITION_NOT_SATISFIED: (&str, &str) = (
+    "The IncomeCells can not be consolidated yet.",
+    "There are not enough IncomeCells or records to consolidate, try again later.",
+);
+const ACCOUNT_CELL_MISSING_PREV_ACCOUNT: (&str, &str) = (
+    "The AccountCell of the previous account is missing.",
+    "The proposal does not include the AccountCell which should be before the new account.",
+);
+const ACCOUNT_CELL_THROTTLE: (&str, &str) = (
+    "The AccountCell was updated too frequently.",
+    "The account has been updated recently, wait for a while and try again.",
+);
+const ACCOUNT_CELL_IN_EXPIRATION_GRACE_PERIOD: (&str, &str) = (
+    "The account is in the grace period after expiration.",
+    "The account has expired, renew it before any other operation.",
+);
+const SUB_ACCOUNT_NORMAL_CELL_LOCK_LIMIT: (&str, &str) = (
+    "Cells with the das-lock of a sub-account can not be created or spent by this transaction.",
+    "The transaction tries to transfer CKB or cells to or from the address of a sub-account, which is not allowed.",
+);
+const SYSTEM_OFF: (&str, &str) = (
+    "The system is off.",
+    "The DAS contracts are temporarily suspended for maintenance, try again later.",
+);
+
+catalogue!(COMMON_ERRORS, ErrorScope::Common, ErrorCode {
+    IndexOutOfBound => INDEX_OUT_OF_BOUND,
+    ItemMissing => ITEM_MISSING,
+    LengthNotEnough => LENGTH_NOT_ENOUGH,
+    Encoding => ENCODING,
+    HardCodedError => (
+        "A hard-coded constant does not match the chain.",
+        "The contract is deployed with the constants of another network.",
+    ),
+    InvalidTransactionStructure => (
+        "The cells of the transaction are not in the expected number or order.",
+        "The transaction is built incorrectly, some cells are missing, redundant or in the wrong position.",
+    ),
+    InvalidCellData => (
+        "The data of some cell is invalid.",
+        "The outputs data of the transaction does not match the format of the cell.",
+    ),
+    InitDayHasPassed => (
+        "The initialization day of the system has passed.",
+        "The initialization action can only be used on the first day.",
+    ),
+    OracleCellIsRequired => (
+        "The OracleCell is required in the cell_deps.",
+        "The transaction is missing the cell_dep of the time, height or quote OracleCell.",
+    ),
+    OracleCellDataDecodingError => (
+        "The data of the OracleCell can not be decoded.",
+        "The wrong cell is used as the OracleCell in the cell_deps.",
+    ),
+    ConfigTypeIsUndefined => (
+        "The type of the ConfigCell is undefined.",
+        "The ConfigCell is newer than the contract, the contract may need an upgrade.",
+    ),
+    ConfigIsPartialMissing => (
+        "Some ConfigCell required by the contract is missing.",
+        "The transaction is missing some ConfigCell in the cell_deps.",
+    ),
+    ConfigCellIsRequired => (
+        "The ConfigCell is required.",
+        "The transaction is missing some ConfigCell in the cell_deps.",
+    ),
+    ConfigCellWitnessIsCorrupted => (
+        "The witness of the ConfigCell does not match its data hash.",
+        "The witness of the ConfigCell is outdated or modified, rebuild the transaction with the latest config.",
+    ),
+    ConfigCellWitnessDecodingError => (
+        "The witness of the ConfigCell can not be decoded.",
+        "The witness of the ConfigCell is malformed.",
+    ),
+    TxFeeSpentError => (
+        "The transaction spends more fee than allowed from the cells of DAS.",
+        "The capacity of some output cell is less than expected, the fee should be paid by the user's own cells.",
+    ),
+    DasLockArgsInvalid => (
+        "The args of the das-lock are invalid.",
+        "The address used in the transaction is not a valid DAS address.",
+    ),
+    CellLockCanNotBeModified => (
+        "The lock of the cell can not be modified.",
+        "The action does not allow changing the owner of the cell.",
+    ),
+    CellTypeCanNotBeModified => (
+        "The type of the cell can not be modified.",
+        "The output cell uses a different type script from the input cell.",
+    ),
+    CellDataCanNotBeModified => (
+        "The data of the cell can not be modified.",
+        "The action does not allow changing the data of the cell.",
+    ),
+    CellCapacityMustReduced => (
+        "The capacity of the cell must be reduced.",
+        "The output cell has the same or more capacity than the input cell.",
+    ),
+    CellCapacityMustIncreased => (
+        "The capacity of the cell must be increased.",
+        "The output cell has the same or less capacity than the input cell.",
+    ),
+    CellCapacityMustBeConsistent => (
+        "The capacity of the cell must not change.",
+        "The output cell has a different capacity from the input cell.",
+    ),
+    CellsMustHaveSameOrderAndNumber => (
+        "The cells in the inputs and outputs must have the same order and number.",
+        "The transaction is built incorrectly, some cells are missing or in the wrong position.",
+    ),
+    ActionNotSupported => (
+        "The action is not supported by the contract.",
+        "The action in the witness is unknown to this contract, the wallet may be outdated.",
+    ),
+    ParamsDecodingError => (
+        "The params of the action can not be decoded.",
+        "The action witness is malformed.",
+    ),
+    SuperLockIsRequired => (
+        "The super lock is required.",
+        "This action can only be performed by the administrators of DAS.",
+    ),
+    AlwaysSuccessLockIsRequired => (
+        "The always-success lock is required.",
+        "The cell should be locked by the always-success lock.",
+    ),
+    SignallLockIsRequired => (
+        "The secp256k1-blake160 lock is required.",
+        "The cell should be locked by a normal CKB address.",
+    ),
+    DataTypeUpgradeRequired => (
+        "The data type of the witness needs to be upgraded.",
+        "The witness uses an old version of the data structure, rebuild the transaction with the latest version.",
+    ),
+    NarrowMixerTypeFailed => (
+        "The entity of the witness can not be converted to the expected version.",
+        "The witness uses a version of the data structure which is not supported here.",
+    ),
+    ChangeError => (
+        "The change of the transaction is incorrect.",
+        "The change cell is missing or has the wrong lock or capacity.",
+    ),
+    AccountStillCanNotBeRegister => (
+        "The account can not be registered yet.",
+        "The account is not open for registration yet.",
+    ),
+    AccountIsPreserved => (
+        "The account is preserved.",
+        "The account is in the preserved list and can not be registered by the public.",
+    ),
+    AccountIsUnAvailable => (
+        "The account is unavailable.",
+        "The account is in the unavailable list and can not be registered.",
+    ),
+    AccountIdIsInvalid => (
+        "The account ID does not match the account.",
+        "The account ID in the cell is not the hash of the account.",
+    ),
+    WitnessNotInited => (
+        "The witnesses are not parsed yet.",
+        "This is a bug of the contract, please report it.",
+    ),
+    WitnessStructureError => (
+        "The witnesses of DAS are not in the expected structure.",
+        "The witnesses of the transaction are built incorrectly.",
+    ),
+    WitnessDataTypeDecodingError => (
+        "The data type of the witness can not be decoded.",
+        "Some witness of DAS is malformed.",
+    ),
+    WitnessReadingError => (
+        "The witness can not be loaded.",
+        "The transaction is missing some witness.",
+    ),
+    WitnessActionDecodingError => (
+        "The action witness can not be decoded.",
+        "The action witness is missing or malformed.",
+    ),
+    WitnessDataParseLengthHeaderFailed => (
+        "The length header of the witness can not be parsed.",
+        "Some witness of DAS is malformed.",
+    ),
+    WitnessDataReadDataBodyFailed => (
+        "The body of the witness can not be read.",
+        "Some witness of DAS is truncated.",
+    ),
+    WitnessDataDecodingError => (
+        "The witness can not be decoded.",
+        "Some witness of DAS is malformed.",
+    ),
+    WitnessDataHashOrTypeMissMatch => (
+        "The witness does not match the hash or type of its cell.",
+        "The witness of some cell is outdated or modified.",
+    ),
+    WitnessDataIndexMissMatch => (
+        "The witness does not match the index of its cell.",
+        "The witness points to a different cell, the order of the cells or witnesses may be wrong.",
+    ),
+    WitnessEntityDecodingError => (
+        "The entity in the witness can not be decoded.",
+        "Some witness of DAS is malformed.",
+    ),
+    WitnessEmpty => (
+        "The witness is empty.",
+        "The transaction is missing some witness.",
+    ),
+    WitnessArgsInvalid => (
+        "The WitnessArgs is invalid.",
+        "The witness of the lock is malformed.",
+    ),
+    WitnessArgsDecodingError => (
+        "The WitnessArgs can not be decoded.",
+        "The witness of the lock is malformed.",
+    ),
+    WitnessVersionOrTypeInvalid => (
+        "The version or type of the witness is invalid.",
+        "The witness is built for another version of the contract.",
+    ),
+    WitnessVersionUndefined => (
+        "The version of the witness is undefined.",
+        "The witness is newer than the contract.",
+    ),
+    SMTWhiteListTheLockIsNotFound => (
+        "The lock is not in the whitelist.",
+        "The sender is not allowed to update the SMT.",
+    ),
+    SMTNewRootMismatch => (
+        "The new root of the SMT does not match.",
+        "The SMT proof is generated from outdated data, rebuild the transaction.",
+    ),
+    SMTProofVerifyFailed => (
+        "The proof of the SMT can not be verified.",
+        "The SMT proof is generated from outdated data, rebuild the transaction.",
+    ),
+    SignMethodUnsupported => (
+        "The sign method is not supported.",
+        "The address type is not supported by this action.",
+    ),
+    WitnessCannotBeVerified => (
+        "The witness can not be verified.",
+        "The signature in the witness is invalid.",
+    ),
+    ApplyRegisterNeedWaitLonger => (
+        "The ApplyRegisterCell is too new.",
+        "The registration needs to wait for a few more blocks after applying.",
+    ),
+    ApplyRegisterHasTimeout => (
+        "The ApplyRegisterCell has timed out.",
+        "The application is too old, apply for the account again.",
+    ),
+    ApplyLockMustBeUnique => (
+        "The lock of the ApplyRegisterCell must be unique.",
+        "Multiple ApplyRegisterCells are created with the same lock.",
+    ),
+    ApplyRegisterSinceMismatch => (
+        "The since of the ApplyRegisterCell is incorrect.",
+        "The since of the input ApplyRegisterCell is not set as required.",
+    ),
+    ApplyRegisterRefundCapacityError => (
+        "The refund of the ApplyRegisterCell is incorrect.",
+        "The capacity refunded to the applicant is less than expected.",
+    ),
+    CharSetIsConflict => (
+        "The account mixes characters of conflicting char sets.",
+        "The account uses characters from languages which can not be mixed.",
+    ),
+    CharSetIsUndefined => (
+        "The char set of the account is undefined.",
+        "The account contains characters which are not supported.",
+    ),
+    AccountCharIsInvalid => (
+        "Some character of the account is invalid.",
+        "The account contains characters which are not supported.",
+    ),
+    AccountIsTooShort => (
+        "The account is too short.",
+        "The account has fewer characters than allowed.",
+    ),
+    AccountIsTooLong => (
+        "The account is too long.",
+        "The account has more characters than allowed.",
+    ),
+    ProposalSliceIsNotSorted => (
+        "The slice of the proposal is not sorted.",
+        "The accounts in the proposal are not in order.",
+    ),
+    ProposalSliceIsDiscontinuity => (
+        "The slice of the proposal is discontinuous.",
+        "Some account between the accounts in the proposal is missing.",
+    ),
+    ProposalSliceRelatedCellNotFound => (
+        "The cell related to the proposal is not found.",
+        "Some AccountCell or PreAccountCell in the proposal is missing from the transaction.",
+    ),
+    ProposalSliceRelatedCellMissing => (
+        "The cell related to the proposal is missing.",
+        "Some AccountCell or PreAccountCell in the proposal is missing from the transaction.",
+    ),
+    ProposalCellTypeError => (
+        "The type of the item in the proposal is incorrect.",
+        "The proposal is built from outdated cells.",
+    ),
+    ProposalCellAccountIdError => (
+        "The account ID of the item in the proposal is incorrect.",
+        "The proposal is built from outdated cells.",
+    ),
+    ProposalCellNextError => (
+        "The next account of the item in the proposal is incorrect.",
+        "The proposal is built from outdated cells.",
+    ),
+    ProposalFieldCanNotBeModified => (
+        "The field of the ProposalCell can not be modified.",
+        "The transaction modifies some protected field of the ProposalCell.",
+    ),
+    ProposalWitnessCanNotBeModified => (
+        "The witness of the ProposalCell can not be modified.",
+        "The transaction modifies the witness of the ProposalCell.",
+    ),
+    ProposalConfirmNewAccountCellDataError => (
+        "The data of the new AccountCell is incorrect.",
+        "The AccountCell created by the proposal does not match the PreAccountCell.",
+    ),
+    ProposalConfirmNewAccountCellCapacityError => (
+        "The capacity of the new AccountCell is incorrect.",
+        "The AccountCell created by the proposal does not have the expected capacity.",
+    ),
+    ProposalConfirmNewAccountWitnessError => (
+        "The witness of the new AccountCell is incorrect.",
+        "The AccountCell created by the proposal does not match the PreAccountCell.",
+    ),
+    ProposalConfirmPreAccountCellExpired => (
+        "The PreAccountCell has expired.",
+        "The PreAccountCell is too old to be confirmed.",
+    ),
+    ProposalConfirmNeedWaitLonger => (
+        "The proposal can not be confirmed yet.",
+        "The proposal needs to wait for a few more blocks before confirmation.",
+    ),
+    ProposalConfirmInitialRecordsMismatch => (
+        "The initial records of the new AccountCell do not match.",
+        "The records of the new AccountCell differ from the ones in the PreAccountCell.",
+    ),
+    ProposalConfirmAccountLockArgsIsInvalid => (
+        "The lock args of the new AccountCell are invalid.",
+        "The owner of the new AccountCell differs from the one in the PreAccountCell.",
+    ),
+    ProposalConfirmRefundError => (
+        "The refund of the proposal is incorrect.",
+        "The capacity refunded to the registrant is less than expected.",
+    ),
+    ProposalSlicesCanNotBeEmpty => (
+        "The slices of the proposal can not be empty.",
+        "The proposal contains no account.",
+    ),
+    ProposalSliceNotEndCorrectly => (
+        "The slice of the proposal does not end correctly.",
+        "The last item of the slice is not linked to the next existing account.",
+    ),
+    ProposalSliceMustStartWithAccountCell => (
+        "The slice of the proposal must start with an AccountCell.",
+        "The first item of the slice is not an existing account.",
+    ),
+    ProposalSliceMustContainMoreThanOneElement => (
+        "The slice of the proposal must contain more than one item.",
+        "The slice contains only the existing account.",
+    ),
+    ProposalSliceItemMustBeUniqueAccount => (
+        "The items of the proposal must be unique accounts.",
+        "The same account appears more than once in the proposal.",
+    ),
+    ProposalRecycleNeedWaitLonger => (
+        "The ProposalCell can not be recycled yet.",
+        "The proposal needs to wait for a few more blocks before recycling.",
+    ),
+    ProposalRecycleRefundAmountError => (
+        "The refund of the recycled ProposalCell is incorrect.",
+        "The capacity refunded to the proposer is less than expected.",
+    ),
+    PrevProposalItemNotFound => (
+        "The previous item of the proposal is not found.",
+        "The proposal extends a ProposalCell which is missing from the transaction.",
+    ),
+    IncomeCellConsolidateConditionNotSatisfied => INCOME_CELL_CONSOLIDATE_CONDITION_NOT_SATISFIED,
+    IncomeCellConsolidateError => (
+        "The IncomeCells are consolidated incorrectly.",
+        "The records of the output IncomeCells do not match the input IncomeCells.",
+    ),
+    IncomeCellConsolidateWaste => (
+        "The consolidation of IncomeCells wastes capacity.",
+        "The output IncomeCells could be merged into fewer cells.",
+    ),
+    IncomeCellTransferError => (
+        "The transfer from the IncomeCells is incorrect.",
+        "The capacity transferred to some creditor does not match the records.",
+    ),
+    IncomeCellCapacityError => (
+        "The capacity of the IncomeCell is incorrect.",
+        "The capacity of the IncomeCell does not match the sum of its records.",
+    ),
+    IncomeCellProfitMismatch => (
+        "The profit in the IncomeCell does not match.",
+        "The profit recorded in the IncomeCell differs from the expected profit.",
+    ),
+    EIP712SerializationError => (
+        "The transaction can not be serialized to EIP-712 typed data.",
+        "The transaction contains something which can not be displayed to the signer.",
+    ),
+    EIP712SematicError => (
+        "The EIP-712 typed data does not match the transaction.",
+        "The typed data signed by the wallet differs from the transaction.",
+    ),
+    EIP712DecodingWitnessArgsError => (
+        "The WitnessArgs of the EIP-712 signature can not be decoded.",
+        "The witness of the das-lock is malformed.",
+    ),
+    EIP712SignatureError => (
+        "The EIP-712 signature is invalid.",
+        "The signature is not signed by the owner or the manager of the cells.",
+    ),
+    BalanceCellFoundSomeOutputsLackOfType => (
+        "Some output with das-lock is lacking the balance-cell-type.",
+        "CKB sent to a DAS address should be in a BalanceCell with the balance-cell-type.",
+    ),
+    BalanceCellCanNotBeSpent => (
+        "The BalanceCell can not be spent.",
+        "The BalanceCell can not be spent by this action.",
+    ),
+    AccountSaleCellCapacityError => (
+        "The capacity of the AccountSaleCell is incorrect.",
+        "The AccountSaleCell does not have the required capacity.",
+    ),
+    AccountSaleCellRefundError => (
+        "The refund of the AccountSaleCell is incorrect.",
+        "The capacity refunded to the seller is less than expected.",
+    ),
+    AccountSaleCellAccountIdInvalid => (
+        "The account ID of the AccountSaleCell is invalid.",
+        "The AccountSaleCell does not belong to the AccountCell in the transaction.",
+    ),
+    AccountSaleCellStartedAtInvalid => (
+        "The started_at of the AccountSaleCell is invalid.",
+        "The started_at is not the current timestamp.",
+    ),
+    AccountSaleCellPriceTooSmall => (
+        "The price of the account is too small.",
+        "The price is lower than the minimum price for sale.",
+    ),
+    AccountSaleCellDescriptionTooLarge => (
+        "The description of the sale is too large.",
+        "Shorten the description of the sale.",
+    ),
+    AccountSaleCellNewOwnerError => (
+        "The new owner of the account is incorrect.",
+        "The AccountCell is not transferred to the buyer.",
+    ),
+    AccountSaleCellNotPayEnough => (
+        "The buyer does not pay enough.",
+        "The payment is less than the price of the account.",
+    ),
+    AccountSaleCellProfitError => (
+        "The profit of the sale is incorrect.",
+        "The profit paid to the seller, inviter, channel or DAS does not match.",
+    ),
+    AccountSaleCellProfitRateError => (
+        "The profit rate of the sale is incorrect.",
+        "The profit rate in the AccountSaleCell is out of range.",
+    ),
+    OfferCellCapacityError => (
+        "The capacity of the OfferCell is incorrect.",
+        "The OfferCell does not hold the offered price.",
+    ),
+    OfferCellLockError => (
+        "The lock of the OfferCell is incorrect.",
+        "The OfferCell is not locked by the buyer.",
+    ),
+    OfferCellMessageTooLong => (
+        "The message of the offer is too long.",
+        "Shorten the message of the offer.",
+    ),
+    OfferCellNewOwnerError => (
+        "The new owner of the account is incorrect.",
+        "The AccountCell is not transferred to the buyer of the offer.",
+    ),
+    OfferCellFieldCanNotModified => (
+        "The field of the OfferCell can not be modified.",
+        "The transaction modifies some protected field of the OfferCell.",
+    ),
+    OfferCellAccountMismatch => (
+        "The account of the OfferCell does not match.",
+        "The offer is made for another account.",
+    ),
+    UpgradeForWitnessIsRequired => (
+        "The witness needs to be upgraded.",
+        "The cell uses an old version of witness, upgrade it first.",
+    ),
+    UpgradeDefaultValueOfNewFieldIsError => (
+        "The default value of the new field is incorrect.",
+        "The upgraded witness does not use the default value for the new field.",
+    ),
+    CrossChainLockError => (
+        "The account can not be locked for cross chain.",
+        "The account is not in a status which allows locking for cross chain.",
+    ),
+    CrossChainUnlockError => (
+        "The account can not be unlocked from cross chain.",
+        "The account is not locked for cross chain or the unlock is not signed correctly.",
+    ),
+    OverflowError => (
+        "An arithmetic operation overflowed.",
+        "Some amount in the transaction is too large.",
+    ),
+    UnittestError => (
+        "An error for the unit tests only.",
+        "This should never happen on chain.",
+    ),
+    SystemOff => SYSTEM_OFF,
+});
+
+catalogue!(ACCOUNT_CELL_ERRORS, ErrorScope::AccountCell, AccountCellErrorCode {
+    IndexOutOfBound => INDEX_OUT_OF_BOUND,
+    ItemMissing => ITEM_MISSING,
+    LengthNotEnough => LENGTH_NOT_ENOUGH,
+    Encoding => ENCODING,
+    IncomeCellConsolidateConditionNotSatisfied => INCOME_CELL_CONSOLIDATE_CONDITION_NOT_SATISFIED,
+    AccountCellMissingPrevAccount => ACCOUNT_CELL_MISSING_PREV_ACCOUNT,
+    AccountCellThrottle => ACCOUNT_CELL_THROTTLE,
+    AccountCellInExpirationGracePeriod => ACCOUNT_CELL_IN_EXPIRATION_GRACE_PERIOD,
+    SubAccountNormalCellLockLimit => SUB_ACCOUNT_NORMAL_CELL_LOCK_LIMIT,
+    SystemOff => SYSTEM_OFF,
+    WitnessParsingError => (
+        "The witness of the AccountCell can not be parsed.",
+        "The witness of the AccountCell is malformed.",
+    ),
+    AccountCellNextUpdateError => (
+        "The next of the AccountCell can not be updated.",
+        "Only the proposal may change the next account.",
+    ),
+    AccountCellIdNotMatch => (
+        "The account ID of the AccountCell does not match.",
+        "The input and output AccountCells belong to different accounts.",
+    ),
+    AccountCellPermissionDenied => (
+        "The signer has no permission for this action.",
+        "Owner actions must be signed by the owner, manager actions by the manager.",
+    ),
+    AccountCellOwnerLockShouldNotBeModified => (
+        "The owner of the AccountCell can not be modified.",
+        "Only the transfer action may change the owner.",
+    ),
+    AccountCellOwnerLockShouldBeModified => (
+        "The owner of the AccountCell should be modified.",
+        "The transfer action does not change the owner.",
+    ),
+    AccountCellManagerLockShouldBeModified => (
+        "The manager of the AccountCell should be modified.",
+        "The edit manager action does not change the manager.",
+    ),
+    AccountCellDataNotConsistent => (
+        "The data of the AccountCell is not consistent.",
+        "The transaction modifies some field of the AccountCell which the action does not allow.",
+    ),
+    AccountCellProtectFieldIsModified => (
+        "The protected field of the AccountCell is modified.",
+        "The transaction modifies some protected field of the AccountCell.",
+    ),
+    AccountCellNoMoreFee => (
+        "The AccountCell can not pay more fee.",
+        "The AccountCell has spent its fee allowance, pay the fee with another cell.",
+    ),
+    AccountCellRenewDurationMustLongerThanYear => (
+        "The account must be renewed for at least one year.",
+        "Renew the account for one year or longer.",
+    ),
+    AccountCellRenewDurationBiggerThanPayed => (
+        "The renew duration is longer than paid.",
+        "The payment does not cover the renew duration.",
+    ),
+    AccountCellRecycleCapacityError => (
+        "The capacity of the recycled AccountCell is incorrect.",
+        "The capacity refunded to the owner is less than expected.",
+    ),
+    AccountCellChangeCapacityError => (
+        "The capacity of the AccountCell changes incorrectly.",
+        "The AccountCell pays more fee or takes more capacity than allowed.",
+    ),
+    AccountCellRecordKeyInvalid => (
+        "The key of some record is invalid.",
+        "The record key is not in the list of supported keys.",
+    ),
+    AccountCellRecordSizeTooLarge => (
+        "The records are too large.",
+        "Remove some records or shorten their values.",
+    ),
+    AccountCellRecordNotEmpty => (
+        "The records of the AccountCell are not empty.",
+        "The records should be cleared by this action.",
+    ),
+    AccountCellStatusLocked => (
+        "The AccountCell is in a locked status.",
+        "The account is on sale, in an auction, approved to someone or locked for cross chain.",
+    ),
+    AccountCellIsNotExpired => (
+        "The account is not expired.",
+        "The account can only be recycled or auctioned after expiration.",
+    ),
+    AccountCellInExpirationAuctionConfirmationPeriod => (
+        "The account is in the confirmation period of the expiration auction.",
+        "The account is waiting for the confirmation of the auction, try again later.",
+    ),
+    AccountCellInExpirationAuctionPeriod => (
+        "The account is in the expiration auction period.",
+        "The expired account can only be bid on during the auction.",
+    ),
+    AccountCellHasExpired => (
+        "The account has expired.",
+        "Renew the account before any other operation.",
+    ),
+    AccountCellStillCanNotRecycle => (
+        "The account can not be recycled yet.",
+        "The account is still in the grace or auction period.",
+    ),
+    AccountHasNearGracePeriod => (
+        "The account is near the grace period.",
+        "The account expires soon, renew it before this operation.",
+    ),
+    ApprovalExist => (
+        "An approval already exists.",
+        "Revoke or fulfill the existing approval first.",
+    ),
+    ApprovalActionUndefined => (
+        "The action of the approval is undefined.",
+        "The approval was created by a newer version of the contract.",
+    ),
+    ApprovalParamsPlatformLockInvalid => (
+        "The platform lock of the approval is invalid.",
+        "The platform lock is not a valid das-lock.",
+    ),
+    ApprovalParamsProtectedUntilInvalid => (
+        "The protected_until of the approval is invalid.",
+        "The protected_until is out of the allowed range.",
+    ),
+    ApprovalParamsSealedUntilInvalid => (
+        "The sealed_until of the approval is invalid.",
+        "The sealed_until is out of the allowed range.",
+    ),
+    ApprovalParamsDelayCountRemainInvalid => (
+        "The delay_count_remain of the approval is invalid.",
+        "The delay_count_remain is out of the allowed range.",
+    ),
+    ApprovalParamsToLockInvalid => (
+        "The to_lock of the approval is invalid.",
+        "The receiver of the approval is not a valid das-lock.",
+    ),
+    ApprovalParamsCanNotBeChanged => (
+        "The params of the approval can not be changed.",
+        "Only the sealed_until may be delayed.",
+    ),
+    ApprovalParamsDelayCountNotEnough => (
+        "The approval can not be delayed anymore.",
+        "The delay count of the approval has run out.",
+    ),
+    ApprovalParamsDelayCountDecrementError => (
+        "The delay count of the approval is decremented incorrectly.",
+        "The delay count should be decremented by one.",
+    ),
+    ApprovalParamsSealedUntilIncrementError => (
+        "The sealed_until of the approval is increased incorrectly.",
+        "The new sealed_until should be later than the old one.",
+    ),
+    ApprovalNotRevoked => (
+        "The approval is not revoked.",
+        "The approval should be removed by this action.",
+    ),
+    ApprovalInProtectionPeriod => (
+        "The approval is in the protection period.",
+        "The approval can not be revoked until protected_until.",
+    ),
+    ApprovalFulfillError => (
+        "The approval is fulfilled incorrectly.",
+        "The account is not transferred to the to_lock of the approval.",
+    ),
+    AccountCellBidPriceTooLow => (
+        "The bid price is too low.",
+        "The payment is less than the current price of the auction.",
+    ),
+});
+
+catalogue!(PRE_ACCOUNT_CELL_ERRORS, ErrorScope::PreAccountCell, PreAccountCellErrorCode {
+    IndexOutOfBound => INDEX_OUT_OF_BOUND,
+    ItemMissing => ITEM_MISSING,
+    LengthNotEnough => LENGTH_NOT_ENOUGH,
+    Encoding => ENCODING,
+    IncomeCellConsolidateConditionNotSatisfied => INCOME_CELL_CONSOLIDATE_CONDITION_NOT_SATISFIED,
+    AccountCellMissingPrevAccount => ACCOUNT_CELL_MISSING_PREV_ACCOUNT,
+    AccountCellThrottle => ACCOUNT_CELL_THROTTLE,
+    AccountCellInExpirationGracePeriod => ACCOUNT_CELL_IN_EXPIRATION_GRACE_PERIOD,
+    SubAccountNormalCellLockLimit => SUB_ACCOUNT_NORMAL_CELL_LOCK_LIMIT,
+    SystemOff => SYSTEM_OFF,
+    ApplyHashMismatch => (
+        "The hash in the ApplyRegisterCell does not match.",
+        "The PreAccountCell is for a different account or owner than the application.",
+    ),
+    ApplySinceMismatch => (
+        "The since of the ApplyRegisterCell is incorrect.",
+        "The application needs to wait for a few more blocks.",
+    ),
+    AccountIdIsInvalid => (
+        "The account ID of the PreAccountCell is invalid.",
+        "The account ID is not the hash of the account.",
+    ),
+    AccountAlreadyExistOrProofInvalid => (
+        "The account already exists or its proof is invalid.",
+        "The account has been registered by someone else.",
+    ),
+    CreateAtIsInvalid => (
+        "The created_at of the PreAccountCell is invalid.",
+        "The created_at is not the current timestamp.",
+    ),
+    PriceIsInvalid => (
+        "The price of the PreAccountCell is invalid.",
+        "The price does not match the price config of the account length.",
+    ),
+    CharSetIsUndefined => (
+        "The char set of the account is undefined.",
+        "The account contains characters which are not supported.",
+    ),
+    CKBIsInsufficient => (
+        "The PreAccountCell does not hold enough CKB.",
+        "The payment does not cover the registration fee and storage.",
+    ),
+    QuoteIsInvalid => (
+        "The quote of the PreAccountCell is invalid.",
+        "The quote differs from the current quote of the OracleCell.",
+    ),
+    OwnerLockArgsIsInvalid => (
+        "The owner lock args of the PreAccountCell are invalid.",
+        "The owner address is not a valid DAS address.",
+    ),
+    RefundLockMustBeUnique => (
+        "The refund lock must be unique.",
+        "The PreAccountCells are refunded to different locks.",
+    ),
+    RefundCapacityError => (
+        "The refund of the PreAccountCell is incorrect.",
+        "The capacity refunded to the registrant is less than expected.",
+    ),
+    SinceMismatch => (
+        "The since of the PreAccountCell is incorrect.",
+        "The PreAccountCell can not be refunded yet.",
+    ),
+    InviterIdShouldBeEmpty => (
+        "The inviter ID should be empty.",
+        "The inviter ID is set without an inviter lock.",
+    ),
+    InviterIdIsInvalid => (
+        "The inviter ID is invalid.",
+        "The inviter account does not exist.",
+    ),
+    InviteeDiscountShouldBeEmpty => (
+        "The invitee discount should be empty.",
+        "The discount is only available with an inviter.",
+    ),
+    InviteeDiscountIsInvalid => (
+        "The invitee discount is invalid.",
+        "The discount differs from the one in the config.",
+    ),
+});
+
+catalogue!(REVERSE_RECORD_ROOT_CELL_ERRORS, ErrorScope::ReverseRecordRootCell, ReverseRecordRootCellErrorCode {
+    IndexOutOfBound => INDEX_OUT_OF_BOUND,
+    ItemMissing => ITEM_MISSING,
+    LengthNotEnough => LENGTH_NOT_ENOUGH,
+    Encoding => ENCODING,
+    IncomeCellConsolidateConditionNotSatisfied => INCOME_CELL_CONSOLIDATE_CONDITION_NOT_SATISFIED,
+    AccountCellMissingPrevAccount => ACCOUNT_CELL_MISSING_PREV_ACCOUNT,
+    AccountCellThrottle => ACCOUNT_CELL_THROTTLE,
+    AccountCellInExpirationGracePeriod => ACCOUNT_CELL_IN_EXPIRATION_GRACE_PERIOD,
+    SubAccountNormalCellLockLimit => SUB_ACCOUNT_NORMAL_CELL_LOCK_LIMIT,
+    SystemOff => SYSTEM_OFF,
+    InitialCapacityError => (
+        "The capacity of the new ReverseRecordRootCell is incorrect.",
+        "The ReverseRecordRootCell does not have the required capacity.",
+    ),
+    InitialOutputsDataError => (
+        "The data of the new ReverseRecordRootCell is incorrect.",
+        "The ReverseRecordRootCell should be created with the root of an empty SMT.",
+    ),
+    SignatureVerifyError => (
+        "The signature of the reverse record can not be verified.",
+        "The reverse record is not signed by the owner of the address.",
+    ),
+});
+
+catalogue!(SUB_ACCOUNT_CELL_ERRORS, ErrorScope::SubAccountCell, SubAccountCellErrorCode {
+    IndexOutOfBound => INDEX_OUT_OF_BOUND,
+    ItemMissing => ITEM_MISSING,
+    LengthNotEnough => LENGTH_NOT_ENOUGH,
+    Encoding => ENCODING,
+    IncomeCellConsolidateConditionNotSatisfied => INCOME_CELL_CONSOLIDATE_CONDITION_NOT_SATISFIED,
+    AccountCellMissingPrevAccount => ACCOUNT_CELL_MISSING_PREV_ACCOUNT,
+    AccountCellThrottle => ACCOUNT_CELL_THROTTLE,
+    AccountCellInExpirationGracePeriod => ACCOUNT_CELL_IN_EXPIRATION_GRACE_PERIOD,
+    SubAccountNormalCellLockLimit => SUB_ACCOUNT_NORMAL_CELL_LOCK_LIMIT,
+    SystemOff => SYSTEM_OFF,
+    SubAccountFeatureNotEnabled => (
+        "The sub-account feature is not enabled.",
+        "Enable the sub-account feature of the parent account first.",
+    ),
+    ConfigManualInvalid => (
+        "The manual mint config is invalid.",
+        "The manual mint list in the SubAccountCell is malformed.",
+    ),
+    ConfigCustomRuleInvalid => (
+        "The custom rule config is invalid.",
+        "The custom price rules in the SubAccountCell are malformed.",
+    ),
+    ConfigFlagInvalid => (
+        "The flag of the SubAccountCell is invalid.",
+        "The flag is not one of the supported values.",
+    ),
+    ConfigRulesHashMismatch => (
+        "The hash of the rules does not match.",
+        "The rules in the witness differ from the ones in the SubAccountCell.",
+    ),
+    ConfigRulesHasSyntaxError => (
+        "The rules have a syntax error.",
+        "The price or preserved rules can not be parsed.",
+    ),
+    ConfigRulesPriceError => (
+        "The price in the rules is invalid.",
+        "The price in the rules is lower than the minimum price.",
+    ),
+    WitnessParsingError => (
+        "The witness of the sub-account can not be parsed.",
+        "The witness of the sub-account is malformed.",
+    ),
+    WitnessEditKeyInvalid => (
+        "The edit key of the sub-account witness is invalid.",
+        "The edit key is not supported by the action.",
+    ),
+    WitnessEditValueError => (
+        "The edit value of the sub-account witness is invalid.",
+        "The edit value does not match the edit key.",
+    ),
+    WitnessSignMintIsRequired => (
+        "The mint signature is required.",
+        "The manually minted sub-accounts need the signature of the parent account.",
+    ),
+    WitnessVersionMismatched => (
+        "The version of the sub-account witness does not match.",
+        "The witness is built for another version of the contract.",
+    ),
+    WitnessUpgradeNeeded => (
+        "The sub-account witness needs to be upgraded.",
+        "The witness uses an old version of the data structure.",
+    ),
+    CanNotMint => (
+        "The sub-account can not be minted.",
+        "The sub-account is not allowed by the mint rules of the parent account.",
+    ),
+    ProofInManualSignRenewListMissing => (
+        "The proof of the manual renew list is missing.",
+        "The sub-account is not in the manual renew list.",
+    ),
+    AccountIsPreserved => (
+        "The sub-account is preserved.",
+        "The sub-account is preserved by the parent account.",
+    ),
+    AccountHasNoPrice => (
+        "The sub-account has no price.",
+        "No price rule of the parent account matches the sub-account.",
+    ),
+    BytesToStringFailed => (
+        "The bytes can not be converted to a string.",
+        "The account is not valid UTF-8.",
+    ),
+    MinimalProfitToDASNotReached => (
+        "The minimal profit to DAS is not reached.",
+        "The price of the sub-account is lower than the minimum.",
+    ),
+    ExpirationYearsTooShort => (
+        "The registration years are too short.",
+        "The sub-account must be registered for at least one year.",
+    ),
+    ExpirationToleranceReached => (
+        "The expiration tolerance is reached.",
+        "The expired_at is later than allowed.",
+    ),
+    SenderCapacityOverCost => (
+        "The sender spends too much capacity.",
+        "The sender pays more than the cost of the sub-accounts.",
+    ),
+    ProfitManagerLockIsRequired => (
+        "The profit manager lock is required.",
+        "The profit can only be collected by the profit manager.",
+    ),
+    ProfitMustBeCollected => (
+        "The profit must be collected.",
+        "The transaction does not collect the profit of the SubAccountCell.",
+    ),
+    ProfitIsEmpty => (
+        "The profit is empty.",
+        "There is no profit to collect.",
+    ),
+    CustomRuleIsOff => (
+        "The custom rule is off.",
+        "The parent account does not allow minting with the custom rules.",
+    ),
+    NewExpiredAtIsRequired => (
+        "The new expired_at is required.",
+        "The renew witness does not set the new expired_at.",
+    ),
+    AccountHasNearGracePeriod => (
+        "The sub-account is near the grace period.",
+        "The sub-account expires soon, renew it before this operation.",
+    ),
+    AccountHasInGracePeriod => (
+        "The sub-account is in the grace period.",
+        "The sub-account has expired, renew it before any other operation.",
+    ),
+    AccountHasExpired => (
+        "The sub-account has expired.",
+        "Renew the sub-account before any other operation.",
+    ),
+    AccountStillCanNotBeRecycled => (
+        "The sub-account can not be recycled yet.",
+        "The sub-account is still in the grace period.",
+    ),
+    SomeCellWithDasLockMayBeAbused => (
+        "Some cell with the das-lock may be abused.",
+        "The transaction spends cells of the user which it does not need.",
+    ),
+    MultipleSignRolesIsNotAllowed => (
+        "Multiple sign roles are not allowed.",
+        "The witnesses of the transaction are signed by both the owner and the manager.",
+    ),
+    ManualRenewListIsRequired => (
+        "The manual renew list is required.",
+        "The parent account has no manual renew list.",
+    ),
+    ManualRenewProofIsRequired => (
+        "The proof of the manual renew list is required.",
+        "The renew witness has no proof of the manual renew list.",
+    ),
+    ManualRenewProofIsInvalid => (
+        "The proof of the manual renew list is invalid.",
+        "The proof is generated from an outdated manual renew list.",
+    ),
+    EditKeyMismatch => (
+        "The edit key does not match the action.",
+        "The witness edits something which the action does not allow.",
+    ),
+    ApprovalExist => (
+        "An approval already exists.",
+        "Revoke or fulfill the existing approval first.",
+    ),
+    ApprovalActionUndefined => (
+        "The action of the approval is undefined.",
+        "The approval was created by a newer version of the contract.",
+    ),
+    ApprovalParamsPlatformLockInvalid => (
+        "The platform lock of the approval is invalid.",
+        "The platform lock is not a valid das-lock.",
+    ),
+    ApprovalParamsProtectedUntilInvalid => (
+        "The protected_until of the approval is invalid.",
+        "The protected_until is out of the allowed range.",
+    ),
+    ApprovalParamsSealedUntilInvalid => (
+        "The sealed_until of the approval is invalid.",
+        "The sealed_until is out of the allowed range.",
+    ),
+    ApprovalParamsDelayCountRemainInvalid => (
+        "The delay_count_remain of the approval is invalid.",
+        "The delay_count_remain is out of the allowed range.",
+    ),
+    ApprovalParamsToLockInvalid => (
+        "The to_lock of the approval is invalid.",
+        "The receiver of the approval is not a valid das-lock.",
+    ),
+    ApprovalParamsCanNotBeChanged => (
+        "The params of the approval can not be changed.",
+        "Only the sealed_until may be delayed.",
+    ),
+    ApprovalParamsDelayCountNotEnough => (
+        "The approval can not be delayed anymore.",
+        "The delay count of the approval has run out.",
+    ),
+    ApprovalParamsDelayCountDecrementError => (
+        "The delay count of the approval is decremented incorrectly.",
+        "The delay count should be decremented by one.",
+    ),
+    ApprovalParamsSealedUntilIncrementError => (
+        "The sealed_until of the approval is increased incorrectly.",
+        "The new sealed_until should be later than the old one.",
+    ),
+    ApprovalNotRevoked => (
+        "The approval is not revoked.",
+        "The approval should be removed by this action.",
+    ),
+    ApprovalInProtectionPeriod => (
+        "The approval is in the protection period.",
+        "The approval can not be revoked until protected_until.",
+    ),
+    ApprovalFulfillError => (
+        "The approval is fulfilled incorrectly.",
+        "The sub-account is not transferred to the to_lock of the approval.",
+    ),
+    AccountStatusError => (
+        "The status of the sub-account is incorrect.",
+        "The sub-account is in a status which does not allow this action.",
+    ),
+    SignExpiredAtTooLarge => (
+        "The expired_at of the signature is too large.",
+        "The signature is valid for longer than allowed.",
+    ),
+    SignExpiredAtReached => (
+        "The signature has expired.",
+        "Sign the transaction again.",
+    ),
+    SignError => (
+        "The signature is invalid.",
+        "The witness is not signed by the owner or the manager.",
+    ),
+    SubAccountRenewSignIsNotAllowed => (
+        "The renew signature is not allowed.",
+        "The sub-account can not be renewed with a signature of the parent account.",
+    ),
+    SubAccountWitnessMismatched => (
+        "The sub-account witness does not match.",
+        "The witness differs from the sub-account in the SMT.",
+    ),
+    SubAccountRulesToWitnessFailed => (
+        "The rules can not be converted to the witness.",
+        "The rules are too large or malformed.",
+    ),
+    SubAccountSignMintExpiredAtTooLarge => (
+        "The expired_at of the mint signature is too large.",
+        "The mint signature is valid for longer than allowed.",
+    ),
+    SubAccountSignMintExpiredAtReached => (
+        "The mint signature has expired.",
+        "Sign the mint list again.",
+    ),
+    SubAccountSignMintSignatureRequired => (
+        "The mint signature is required.",
+        "The manually minted sub-accounts need the signature of the parent account.",
+    ),
+    SubAccountCellCapacityError => (
+        "The capacity of the SubAccountCell is incorrect.",
+        "The capacity of the SubAccountCell does not match the fees paid.",
+    ),
+    SubAccountCellAccountIdError => (
+        "The account ID of the SubAccountCell is incorrect.",
+        "The SubAccountCell does not belong to the parent account.",
+    ),
+    SubAccountCellConsistencyError => (
+        "The SubAccountCell is not consistent.",
+        "The transaction modifies some field of the SubAccountCell which the action does not allow.",
+    ),
+    SubAccountInitialValueError => (
+        "The initial value of the sub-account is incorrect.",
+        "The new sub-account is not created with the default values.",
+    ),
+    SubAccountSigVerifyError => (
+        "The signature of the sub-account can not be verified.",
+        "The witness is not signed by the owner or the manager of the sub-account.",
+    ),
+    SubAccountFieldNotEditable => (
+        "The field of the sub-account is not editable.",
+        "The action does not allow editing this field.",
+    ),
+    SubAccountEditLockError => (
+        "The new lock of the sub-account is invalid.",
+        "The new owner or manager is not a valid das-lock.",
+    ),
+    SubAccountJoinBetaError => (
+        "The parent account has not joined the beta.",
+        "The sub-account feature is in beta for this parent account.",
+    ),
+    SubAccountProfitError => (
+        "The profit of the sub-accounts is incorrect.",
+        "The profit paid to the parent account or DAS does not match.",
+    ),
+    SubAccountCustomScriptEmpty => (
+        "The custom script is empty.",
+        "The parent account has no custom script.",
+    ),
+    SubAccountCustomScriptError => (
+        "The custom script failed.",
+        "The custom script of the parent account rejected the transaction.",
+    ),
+    SubAccountCollectProfitError => (
+        "The profit is collected incorrectly.",
+        "The collected profit does not match the profit in the SubAccountCell.",
+    ),
+    SubAccountBalanceManagerError => (
+        "The balance is managed incorrectly.",
+        "The capacity of the SubAccountCell is changed by someone other than the owner.",
+    ),
+});
+
+// The error enum of dpoint-cell-type lives in its own crate which can not depend on das-core, keep this in sync with
+// `contracts/dpoint-cell-type/src/error.rs`.
+catalogue!(DPOINT_CELL_ERRORS, ErrorScope::DPointCell, {
+    1 IndexOutOfBound => INDEX_OUT_OF_BOUND,
+    2 ItemMissing => ITEM_MISSING,
+    3 LengthNotEnough => LENGTH_NOT_ENOUGH,
+    4 Encoding => ENCODING,
+    6 InvalidTransactionStructure => (
+        "The cells of the transaction are not in the expected number or order.",
+        "The transaction is built incorrectly, some cells are missing, redundant or in the wrong position.",
+    ),
+    27 ActionNotSupported => (
+        "The action is not supported by the contract.",
+        "The action in the witness is unknown to this contract, the wallet may be outdated.",
+    ),
+    50 SuperLockIsRequired => (
+        "The super lock is required.",
+        "This action can only be performed by the administrators of DAS.",
+    ),
+    51 WhitelistLockIsRequired => (
+        "The whitelist lock is required.",
+        "The DPoint can only be transferred by the locks in the whitelist.",
+    ),
+    52 InitialCapacityError => (
+        "The capacity of the new DPointCell is incorrect.",
+        "The DPointCell does not have the required capacity.",
+    ),
+    53 InitialDataError => (
+        "The data of the new DPointCell is incorrect.",
+        "The DPointCell is created with invalid data.",
+    ),
+    54 InitialOwnerError => (
+        "The owner of the new DPointCell is incorrect.",
+        "The DPointCell is not locked by a valid das-lock.",
+    ),
+    55 TheValueFieldIsMissing => (
+        "The value of the DPointCell is missing.",
+        "The data of the DPointCell is too short.",
+    ),
+    56 TheTotalDPointCanNotChange => (
+        "The total DPoint can not change.",
+        "The transfer creates or destroys DPoint.",
+    ),
+    57 TheDPointShouldDecreased => (
+        "The DPoint should be decreased.",
+        "The burn does not decrease the DPoint.",
+    ),
+    58 OnlyOneUserIsAllowed => (
+        "Only one user is allowed.",
+        "The DPointCells in the inputs belong to different users.",
+    ),
+    59 CanNotSpendAnyFee => (
+        "The DPointCell can not pay any fee.",
+        "Pay the fee with another cell.",
+    ),
+    60 ThereShouldBeSomeDPointCellInInput => (
+        "There should be some DPointCell in the inputs.",
+        "The transaction has no DPointCell to spend.",
+    ),
+    61 CapacityRecycleError => (
+        "The capacity of the DPointCell is recycled incorrectly.",
+        "The capacity is not refunded to the expected lock.",
+    ),
+});
+
+// The error enum of device-key-list-cell-type lives in its own crate which can not depend on das-core, keep this in
+// sync with `contracts/device-key-list-cell-type/src/error.rs`.
+catalogue!(DEVICE_KEY_LIST_CELL_ERRORS, ErrorScope::DeviceKeyListCell, {
+    1 IndexOutOfBound => INDEX_OUT_OF_BOUND,
+    2 ItemMissing => ITEM_MISSING,
+    3 LengthNotEnough => LENGTH_NOT_ENOUGH,
+    4 Encoding => ENCODING,
+    50 FoundKeyListInInput => (
+        "A DeviceKeyListCell is found in the inputs.",
+        "The create action can not spend an existing DeviceKeyListCell.",
+    ),
+    51 WitnessArgsInvalid => (
+        "The WitnessArgs is invalid.",
+        "The witness of the DeviceKeyListCell is malformed.",
+    ),
+    52 NoKeyListInOutput => (
+        "There is no DeviceKeyListCell in the outputs.",
+        "The transaction does not create or update the DeviceKeyListCell.",
+    ),
+    53 LockArgLengthIncorrect => (
+        "The length of the lock args is incorrect.",
+        "The lock of the DeviceKeyListCell is not a valid das-lock.",
+    ),
+    54 InvalidLock => (
+        "The lock is invalid.",
+        "The lock of the DeviceKeyListCell does not match its key list.",
+    ),
+    55 KeyListParseError => (
+        "The key list can not be parsed.",
+        "The data of the DeviceKeyListCell is malformed.",
+    ),
+    56 InvalidTransactionStructure => (
+        "The cells of the transaction are not in the expected number or order.",
+        "The transaction is built incorrectly, some cells are missing, redundant or in the wrong position.",
+    ),
+    57 KeyListNumberIncorrect => (
+        "The number of keys is incorrect.",
+        "The key list is empty or has more keys than allowed.",
+    ),
+    58 UpdateParamsInvalid => (
+        "The params of the update are invalid.",
+        "The update does not add or remove exactly one key.",
+    ),
+    59 DestroyParamsInvalid => (
+        "The params of the destroy are invalid.",
+        "The refund lock of the destroy is invalid.",
+    ),
+    60 CapacityNotEnough => (
+        "The capacity is not enough.",
+        "The DeviceKeyListCell does not have the required capacity.",
+    ),
+    61 MustUseDasLock => (
+        "The das-lock must be used.",
+        "The cell should be locked by a das-lock.",
+    ),
+    62 InconsistentBalanceCellLocks => (
+        "The locks of the BalanceCells are inconsistent.",
+        "The BalanceCells in the transaction belong to different users.",
+    ),
+    63 CapacityReduceTooMuch => (
+        "The capacity is reduced too much.",
+        "The DeviceKeyListCell pays more fee than allowed.",
+    ),
+    64 DuplicatedKeys => (
+        "There are duplicated keys.",
+        "The key to add is already in the key list.",
+    ),
+    65 ActionNotSupported => (
+        "The action is not supported by the contract.",
+        "The action in the witness is unknown to this contract, the wallet may be outdated.",
+    ),
+    66 VerificationError => (
+        "The data can not be verified.",
+        "Some cell or witness of the DeviceKeyListCell is malformed.",
+    ),
+});
+
+const ALL_ERRORS: &[&[ErrorEntry]] = &[
+    COMMON_ERRORS,
+    ACCOUNT_CELL_ERRORS,
+    PRE_ACCOUNT_CELL_ERRORS,
+    REVERSE_RECORD_ROOT_CELL_ERRORS,
+    SUB_ACCOUNT_CELL_ERRORS,
+    DPOINT_CELL_ERRORS,
+    DEVICE_KEY_LIST_CELL_ERRORS,
+];
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+/// Check the rules of `lookup`:
+///
+/// - no error enum uses a code twice, so every enum gives at most one candidate of a code;
+/// - the global codes have the same name in every error enum, so they have only one candidate;
+/// - the local codes are in `CONTRACT_LOCAL_CODE_START..=126`, since 127 is reserved.
+const fn check_codes(tables: &[&[ErrorEntry]]) {
+    let mut i = 0;
+    while i < tables.len() {
+        let mut j = 0;
+        while j < tables[i].len() {
+            let entry = &tables[i][j];
+            if entry.code == i8::MAX {
+                panic!("The exit code 127 is reserved.");
+            }
+
+            let mut k = j + 1;
+            while k < tables[i].len() {
+                if tables[i][k].code == entry.code {
+                    panic!("An exit code is used twice in the same error enum.");
+                }
+                k += 1;
+            }
+
+            if is_global_code(entry.code) {
+                let mut m = i + 1;
+                while m < tables.len() {
+                    let mut n = 0;
+                    while n < tables[m].len() {
+                        if tables[m][n].code == entry.code && !str_eq(tables[m][n].name, entry.name) {
+                            panic!("A global exit code has different meanings in different error enums.");
+                        }
+                        n += 1;
+                    }
+                    m += 1;
+                }
+            }
+
+            j += 1;
+        }
+        i += 1;
+    }
+}
+
+const _: () = check_codes(ALL_ERRORS);
+
+#[cfg(test)]
+mod test {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn test_lookup() {
+        let candidates = lookup(TypeScript::AccountCellType, -37);
+        assert_eq!(1, candidates.len());
+        assert_eq!(ErrorScope::AccountCell, candidates[0].scope);
+        assert_eq!("SubAccountNormalCellLockLimit", candidates[0].name);
+        assert_eq!(
+            Some(TypedError::AccountCell(
+                AccountCellErrorCode::SubAccountNormalCellLockLimit
+            )),
+            candidates[0].to_typed()
+        );
+
+        // A local code has a candidate from every error enum of the contract which uses it.
+        let typed = |script, code| {
+            lookup(script, code)
+                .iter()
+                .map(|entry| entry.to_typed().unwrap())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            vec![
+                TypedError::AccountCell(AccountCellErrorCode::AccountCellPermissionDenied),
+                TypedError::SubAccountCell(SubAccountCellErrorCode::ConfigFlagInvalid),
+                TypedError::Common(ErrorCode::WitnessVersionOrTypeInvalid),
+            ],
+            typed(TypeScript::AccountCellType, 53)
+        );
+        assert_eq!(
+            vec![
+                TypedError::SubAccountCell(SubAccountCellErrorCode::SubAccountCellCapacityError),
+                TypedError::Common(ErrorCode::ProposalConfirmRefundError),
+            ],
+            typed(TypeScript::AccountCellType, 111)
+        );
+        assert_eq!(
+            vec![TypedError::Common(ErrorCode::ProposalConfirmRefundError)],
+            typed(TypeScript::ProposalCellType, 111)
+        );
+        for code in CONTRACT_LOCAL_CODE_START..i8::MAX {
+            let candidates = lookup(TypeScript::AccountCellType, code);
+            for (i, entry) in candidates.iter().enumerate() {
+                core::assert!(candidates[i + 1..].iter().all(|item| item.scope != entry.scope));
+            }
+        }
+
+        let candidates = lookup(TypeScript::DPointCellType, 56);
+        assert_eq!(
+            vec!["TheTotalDPointCanNotChange", "SMTNewRootMismatch"],
+            candidates.iter().map(|entry| entry.name).collect::<Vec<_>>()
+        );
+        assert_eq!(None, candidates[0].to_typed());
+
+        core::assert!(lookup(TypeScript::ApplyRegisterCellType, 127).is_empty());
+    }
+
+    #[test]
+    fn test_entry() {
+        for entry in ALL_ERRORS.iter().flat_map(|entries| entries.iter()) {
+            core::assert!(!entry.description.is_empty() && !entry.cause.is_empty());
+            if let Some(typed) = entry.to_typed() {
+                let expected = match typed {
+                    TypedError::Common(code) => code.entry(),
+                    TypedError::AccountCell(code) => code.entry(),
+                    TypedError::PreAccountCell(code) => code.entry(),
+                    TypedError::ReverseRecordRootCell(code) => code.entry(),
+                    TypedError::SubAccountCell(code) => code.entry(),
+                };
+                assert_eq!(&expected, entry);
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_to_json() {
+        use alloc::string::ToString;
+
+        let json = to_json();
+        let items = json.as_array().unwrap();
+        let find = |script: TypeScript, code: i8| {
+            items
+                .iter()
+                .filter(|item| item["contract"] == script.to_string() && item["code"] == code)
+                .map(|item| item["name"].as_str().unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        // The same candidates as lookup, and a global code appears only once in every contract.
+        for (script, code) in [
+            (TypeScript::AccountCellType, 53),
+            (TypeScript::AccountCellType, 111),
+            (TypeScript::DPointCellType, 56),
+            (TypeScript::ProposalCellType, ErrorCode::ItemMissing as i8),
+        ] {
+            assert_eq!(
+                lookup(script, code).iter().map(|entry| entry.name).collect::<Vec<_>>(),
+                find(script, code)
+            );
+        }
+        for item in items {
+            core::assert!(["code", "contract", "enum", "name", "description", "cause"]
+                .iter()
+                .all(|key| !item[*key].is_null()));
+        }
+    }
+}
@@ -2,11 +2,12 @@
 #![allow(incomplete_features)]
 #![feature(generic_const_exprs)]
 #![feature(min_specialization)]
-#![feature(slice_pattern)]
 #![feature(once_cell_try)]
 #![no_std]
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 #[macro_use]
 pub mod macros;
@@ -16,10 +17,13 @@ pub mod constants;
 pub mod contract;
 pub mod data_parser;
 pub mod error;
+pub mod error_catalogue;
 pub mod helpers;
 pub mod inspect;
+pub mod price_quote;
 pub mod sign_util;
 pub mod since_util;
+pub mod syscall;
 pub mod traits;
 pub mod types;
 pub mod util;
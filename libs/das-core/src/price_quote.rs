@@ -0,0 +1,285 @@
+use alloc::boxed::Box;
+
+use das_types::packed::{AccountCharsReader, BytesReader, ConfigCellAccountReader, ConfigCellPriceReader};
+use primitive_types::U256;
+
+use super::constants::ONE_CKB;
+use super::error::*;
+use super::util;
+
+/// The itemized costs of registering an account, all the capacities are in shannon and the prices are in USD with
+/// 6 decimals like `ConfigCellPrice`.
+///
+/// The `total` is the capacity paid in CKB, the `premium_price` and `bid_price` of the dutch auction are not included,
+/// because an expired account is bid in DPoint which is also in USD with 6 decimals, see
+/// `verifiers::account_cell::verify_account_in_auction`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PriceBreakdown {
+    pub length_in_price: u8,
+    pub yearly_price: u64,
+    pub years: u64,
+    pub discount_rate: u32,
+    pub register_fee: u64,
+    pub discount: u64,
+    pub storage_capacity: u64,
+    pub premium_price: u64,
+    /// The lowest DPoint to bid the expired account for 1 year, it is some only in the dutch auction.
+    pub bid_price: Option<u64>,
+    pub total: u64,
+}
+
+/// The single source of the registration fee math, `pre-account-cell-type` verifies the capacity of PreAccountCells
+/// with it and the clients can quote with the same configs.
+pub struct PriceQuote<'a> {
+    config_account: ConfigCellAccountReader<'a>,
+    config_price: ConfigCellPriceReader<'a>,
+    quote: u64,
+}
+
+impl<'a> PriceQuote<'a> {
+    /// The `quote` is the price of CKB in USD with 6 decimals, like the one in the QuoteCell.
+    pub fn new(
+        config_account: ConfigCellAccountReader<'a>,
+        config_price: ConfigCellPriceReader<'a>,
+        quote: u64,
+    ) -> Self {
+        PriceQuote {
+            config_account,
+            config_price,
+            quote,
+        }
+    }
+
+    /// Quote the registration of the account for the given years.
+    ///
+    /// The `auction_elapsed` is the seconds since the dutch auction of an expired account started, the premium of the
+    /// auction is only charged when it is some, and it is clamped to the `expiration_auction_period` of
+    /// ConfigCellAccount.
+    pub fn register(
+        &self,
+        account: AccountCharsReader,
+        owner_lock_args: BytesReader,
+        years: u64,
+        has_inviter: bool,
+        auction_elapsed: Option<u64>,
+    ) -> Result<PriceBreakdown, Box<dyn ScriptError>> {
+        let length_in_price = util::get_length_in_price(account.len() as u64);
+        let yearly_price = self.yearly_price(length_in_price)?;
+        let discount_rate = self.discount_rate(has_inviter);
+        let register_fee = util::calc_total_register_fee(yearly_price, self.quote, discount_rate, years)?;
+        let discount = util::calc_total_register_fee(yearly_price, self.quote, 0, years)? - register_fee;
+
+        // The account is stored with 4 bytes of length in the AccountCell.
+        let storage_capacity = util::calc_account_storage_capacity(
+            self.config_account,
+            account.as_readable().len() as u64 + 4,
+            owner_lock_args,
+        );
+
+        let (premium_price, bid_price) = match auction_elapsed {
+            Some(elapsed) => {
+                let elapsed = elapsed.min(u32::from(self.config_account.expiration_auction_period()) as u64);
+                let premium_price = util::calculate_dutch_auction_premium(
+                    elapsed,
+                    u32::from(self.config_account.expiration_auction_start_premiums()),
+                );
+
+                // The same as the bid_account action of account-cell-type, the storage is paid in USD too.
+                let bid_price = U256::from(storage_capacity) * U256::from(self.quote) / U256::from(ONE_CKB)
+                    + U256::from(yearly_price)
+                    + U256::from(premium_price);
+                (premium_price, Some(to_u64(bid_price)?))
+            }
+            None => (0, None),
+        };
+
+        let total = to_u64(U256::from(register_fee) + U256::from(storage_capacity))?;
+
+        Ok(PriceBreakdown {
+            length_in_price,
+            yearly_price,
+            years,
+            discount_rate,
+            register_fee,
+            discount,
+            storage_capacity,
+            premium_price,
+            bid_price,
+            total,
+        })
+    }
+
+    /// Calculate the seconds of registration the `paid` capacity can afford, the storage capacity should not be
+    /// included in the `paid`.
+    pub fn duration_from_paid(
+        &self,
+        account: AccountCharsReader,
+        paid: u64,
+        has_inviter: bool,
+    ) -> Result<u64, Box<dyn ScriptError>> {
+        let length_in_price = util::get_length_in_price(account.len() as u64);
+        let yearly_price = self.yearly_price(length_in_price)?;
+
+        util::calc_duration_from_paid(paid, yearly_price, self.quote, self.discount_rate(has_inviter))
+    }
+
+    fn yearly_price(&self, length_in_price: u8) -> Result<u64, Box<dyn ScriptError>> {
+        let prices = self.config_price.prices();
+        let price = prices
+            .iter()
+            .find(|item| u8::from(item.length()) == length_in_price)
+            .ok_or_else(|| code_to_error!(ErrorCode::ItemMissing))?;
+
+        Ok(u64::from(price.new()))
+    }
+
+    fn discount_rate(&self, has_inviter: bool) -> u32 {
+        if has_inviter {
+            u32::from(self.config_price.discount().invited_discount())
+        } else {
+            0
+        }
+    }
+}
+
+fn to_u64(value: U256) -> Result<u64, Box<dyn ScriptError>> {
+    if value > U256::from(u64::MAX) {
+        return Err(code_to_error!(ErrorCode::OverflowError));
+    }
+
+    Ok(value.as_u64())
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec;
+
+    use das_types::packed::*;
+    use das_types::prelude::*;
+
+    use super::*;
+    use crate::constants::{DAYS_OF_YEAR, DAY_SEC};
+
+    const QUOTE: u64 = 2_000; // 0.002 USD/CKB
+
+    fn configs() -> (ConfigCellAccount, ConfigCellPrice) {
+        let config_account = ConfigCellAccount::new_builder()
+            .basic_capacity(Uint64::from(206 * ONE_CKB))
+            .prepared_fee_capacity(Uint64::from(ONE_CKB))
+            .expiration_auction_period(Uint32::from(27 * DAY_SEC as u32))
+            .expiration_auction_start_premiums(Uint32::from(100))
+            .build();
+        let config_price = ConfigCellPrice::new_builder()
+            .discount(
+                DiscountConfig::new_builder()
+                    .invited_discount(Uint32::from(500))
+                    .build(),
+            )
+            .prices(
+                PriceConfigList::new_builder()
+                    .push(
+                        PriceConfig::new_builder()
+                            .length(Uint8::from(8))
+                            .new(Uint64::from(5_000_000))
+                            .renew(Uint64::from(5_000_000))
+                            .build(),
+                    )
+                    .build(),
+            )
+            .build();
+
+        (config_account, config_price)
+    }
+
+    fn account(length: usize) -> AccountChars {
+        let mut builder = AccountChars::new_builder();
+        for _ in 0..length {
+            builder = builder.push(
+                AccountChar::new_builder()
+                    .char_set_name(Uint32::from(2))
+                    .bytes(Bytes::from(vec![b'a']))
+                    .build(),
+            );
+        }
+        builder.build()
+    }
+
+    #[test]
+    fn test_register() {
+        let (config_account, config_price) = configs();
+        let quote = PriceQuote::new(config_account.as_reader(), config_price.as_reader(), QUOTE);
+        let account = account(10);
+        let owner_lock_args = Bytes::from(vec![3; 42]);
+
+        let breakdown = quote
+            .register(account.as_reader(), owner_lock_args.as_reader(), 2, true, None)
+            .unwrap();
+        assert_eq!(8, breakdown.length_in_price);
+        assert_eq!(5_000_000, breakdown.yearly_price);
+        assert_eq!(
+            util::calc_yearly_register_fee(5_000_000, QUOTE, 500).unwrap() * 2,
+            breakdown.register_fee
+        );
+        assert_eq!(
+            util::calc_yearly_register_fee(5_000_000, QUOTE, 0).unwrap() * 2 - breakdown.register_fee,
+            breakdown.discount
+        );
+        assert_eq!((206 + 1 + 14) * ONE_CKB, breakdown.storage_capacity);
+        assert_eq!((0, None), (breakdown.premium_price, breakdown.bid_price));
+        assert_eq!(breakdown.register_fee + breakdown.storage_capacity, breakdown.total);
+
+        // The premium is in USD, and the elapsed time is clamped to the end of the auction.
+        let breakdown = quote
+            .register(account.as_reader(), owner_lock_args.as_reader(), 1, false, Some(0))
+            .unwrap();
+        assert_eq!(100_000_000, breakdown.premium_price);
+        assert_eq!(
+            Some(breakdown.storage_capacity * QUOTE / ONE_CKB + 5_000_000 + 100_000_000),
+            breakdown.bid_price
+        );
+        assert_eq!(breakdown.register_fee + breakdown.storage_capacity, breakdown.total);
+
+        let end = util::calculate_dutch_auction_premium(27 * DAY_SEC, 100);
+        let breakdown = quote
+            .register(
+                account.as_reader(),
+                owner_lock_args.as_reader(),
+                1,
+                false,
+                Some(u64::MAX),
+            )
+            .unwrap();
+        assert_eq!(end, breakdown.premium_price);
+
+        core::assert!(quote
+            .register(
+                AccountChars::default().as_reader(),
+                owner_lock_args.as_reader(),
+                1,
+                false,
+                None
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_duration_from_paid() {
+        let (config_account, config_price) = configs();
+        let quote = PriceQuote::new(config_account.as_reader(), config_price.as_reader(), QUOTE);
+        let account = account(8);
+
+        let yearly_fee = util::calc_yearly_register_fee(5_000_000, QUOTE, 500).unwrap();
+        assert_eq!(
+            DAYS_OF_YEAR * DAY_SEC * 2,
+            quote
+                .duration_from_paid(account.as_reader(), yearly_fee * 2, true)
+                .unwrap()
+        );
+        assert_eq!(
+            util::calc_duration_from_paid(yearly_fee, 5_000_000, QUOTE, 0).unwrap(),
+            quote
+                .duration_from_paid(account.as_reader(), yearly_fee, false)
+                .unwrap()
+        );
+    }
+}
@@ -103,17 +103,17 @@ mod test {
         let mut since = 0b00000000_11111110_11111110_11111111_11111111_11111111_11111111_11111111;
         since = set_relative_flag(since, SinceFlag::Absolute);
 
-        assert!(is_not_set!(since, 63));
+        core::assert!(is_not_set!(since, 63));
 
         let mut since = 0b00000000_11111110_11111110_11111111_11111111_11111111_11111111_11111111;
         since = set_relative_flag(since, SinceFlag::Relative);
 
-        assert!(is_set!(since, 63));
+        core::assert!(is_set!(since, 63));
 
         let mut since = 0b10000000_11111110_11111110_11111111_11111111_11111111_11111111_11111111;
         since = set_relative_flag(since, SinceFlag::Relative);
 
-        assert!(is_set!(since, 63));
+        core::assert!(is_set!(since, 63));
     }
 
     #[test]
@@ -146,47 +146,47 @@ mod test {
         let mut since = 0b00000000_11111110_11111110_11111111_11111111_11111111_11111111_11111111;
         since = set_metric_flag(since, SinceFlag::Height);
 
-        assert!(is_not_set!(since, 62) && is_not_set!(since, 61));
+        core::assert!(is_not_set!(since, 62) && is_not_set!(since, 61));
 
         let mut since = 0b01000000_11111110_11111110_11111111_11111111_11111111_11111111_11111111;
         since = set_metric_flag(since, SinceFlag::Height);
 
-        assert!(is_not_set!(since, 62) && is_not_set!(since, 61));
+        core::assert!(is_not_set!(since, 62) && is_not_set!(since, 61));
 
         let mut since = 0b00100000_11111110_11111110_11111111_11111111_11111111_11111111_11111111;
         since = set_metric_flag(since, SinceFlag::Height);
 
-        assert!(is_not_set!(since, 62) && is_not_set!(since, 61));
+        core::assert!(is_not_set!(since, 62) && is_not_set!(since, 61));
 
         let mut since = 0b00000000_11111110_11111110_11111111_11111111_11111111_11111111_11111111;
         since = set_metric_flag(since, SinceFlag::Timestamp);
 
-        assert!(is_set!(since, 62) && is_not_set!(since, 61));
+        core::assert!(is_set!(since, 62) && is_not_set!(since, 61));
 
         let mut since = 0b00100000_11111110_11111110_11111111_11111111_11111111_11111111_11111111;
         since = set_metric_flag(since, SinceFlag::Timestamp);
 
-        assert!(is_set!(since, 62) && is_not_set!(since, 61));
+        core::assert!(is_set!(since, 62) && is_not_set!(since, 61));
 
         let mut since = 0b01100000_11111110_11111110_11111111_11111111_11111111_11111111_11111111;
         since = set_metric_flag(since, SinceFlag::Timestamp);
 
-        assert!(is_set!(since, 62) && is_not_set!(since, 61));
+        core::assert!(is_set!(since, 62) && is_not_set!(since, 61));
 
         let mut since = 0b00000000_11111110_11111110_11111111_11111111_11111111_11111111_11111111;
         since = set_metric_flag(since, SinceFlag::Epoch);
 
-        assert!(is_not_set!(since, 62) && is_set!(since, 61));
+        core::assert!(is_not_set!(since, 62) && is_set!(since, 61));
 
         let mut since = 0b01000000_11111110_11111110_11111111_11111111_11111111_11111111_11111111;
         since = set_metric_flag(since, SinceFlag::Epoch);
 
-        assert!(is_not_set!(since, 62) && is_set!(since, 61));
+        core::assert!(is_not_set!(since, 62) && is_set!(since, 61));
 
         let mut since = 0b01100000_11111110_11111110_11111111_11111111_11111111_11111111_11111111;
         since = set_metric_flag(since, SinceFlag::Epoch);
 
-        assert!(is_not_set!(since, 62) && is_set!(since, 61));
+        core::assert!(is_not_set!(since, 62) && is_set!(since, 61));
     }
 
     #[test]
@@ -0,0 +1,125 @@
+//! The same functions as `ckb_std::high_level`, but load from the current backend.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use ckb_std::ckb_constants::{CellField, Source};
+use ckb_std::ckb_types::packed::{CellOutput, CellOutputReader, Header, HeaderReader, Script, ScriptReader};
+use ckb_std::ckb_types::prelude::*;
+use ckb_std::error::SysError;
+pub use ckb_std::high_level::{exec_cell, QueryIter};
+
+use super::syscalls;
+
+const BUF_SIZE: usize = 1024;
+
+fn load_data<F: Fn(&mut [u8], usize) -> Result<usize, SysError>>(syscall: F) -> Result<Vec<u8>, SysError> {
+    let mut buf = [0u8; BUF_SIZE];
+    match syscall(&mut buf, 0) {
+        Ok(len) => Ok(buf[..len].to_vec()),
+        Err(SysError::LengthNotEnough(actual_size)) => {
+            let mut data = vec![0; actual_size];
+            let loaded_len = buf.len();
+            data[..loaded_len].copy_from_slice(&buf);
+            let len = syscall(&mut data[loaded_len..], loaded_len)?;
+            debug_assert_eq!(len + loaded_len, actual_size);
+            Ok(data)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+fn load_u64_field(index: usize, source: Source, field: CellField) -> Result<u64, SysError> {
+    let mut buf = [0u8; 8];
+    let len = syscalls::load_cell_by_field(&mut buf, 0, index, source, field)?;
+    debug_assert_eq!(len, buf.len());
+    Ok(u64::from_le_bytes(buf))
+}
+
+fn load_script_field(index: usize, source: Source, field: CellField) -> Result<Script, SysError> {
+    let data = load_data(|buf, offset| syscalls::load_cell_by_field(buf, offset, index, source, field))?;
+
+    match ScriptReader::verify(&data, false) {
+        Ok(()) => Ok(Script::new_unchecked(data.into())),
+        Err(_err) => Err(SysError::Encoding),
+    }
+}
+
+pub fn load_cell(index: usize, source: Source) -> Result<CellOutput, SysError> {
+    let data = load_data(|buf, offset| syscalls::load_cell(buf, offset, index, source))?;
+
+    match CellOutputReader::verify(&data, false) {
+        Ok(()) => Ok(CellOutput::new_unchecked(data.into())),
+        Err(_err) => Err(SysError::Encoding),
+    }
+}
+
+pub fn load_header(index: usize, source: Source) -> Result<Header, SysError> {
+    let data = load_data(|buf, offset| syscalls::load_header(buf, offset, index, source))?;
+
+    match HeaderReader::verify(&data, false) {
+        Ok(()) => Ok(Header::new_unchecked(data.into())),
+        Err(_err) => Err(SysError::Encoding),
+    }
+}
+
+pub fn load_cell_capacity(index: usize, source: Source) -> Result<u64, SysError> {
+    load_u64_field(index, source, CellField::Capacity)
+}
+
+pub fn load_cell_occupied_capacity(index: usize, source: Source) -> Result<u64, SysError> {
+    load_u64_field(index, source, CellField::OccupiedCapacity)
+}
+
+pub fn load_cell_data_hash(index: usize, source: Source) -> Result<[u8; 32], SysError> {
+    let mut buf = [0u8; 32];
+    let len = syscalls::load_cell_by_field(&mut buf, 0, index, source, CellField::DataHash)?;
+    debug_assert_eq!(len, buf.len());
+    Ok(buf)
+}
+
+pub fn load_cell_lock_hash(index: usize, source: Source) -> Result<[u8; 32], SysError> {
+    let mut buf = [0u8; 32];
+    let len = syscalls::load_cell_by_field(&mut buf, 0, index, source, CellField::LockHash)?;
+    debug_assert_eq!(len, buf.len());
+    Ok(buf)
+}
+
+/// Return None if the cell has no type script.
+pub fn load_cell_type_hash(index: usize, source: Source) -> Result<Option<[u8; 32]>, SysError> {
+    let mut buf = [0u8; 32];
+    match syscalls::load_cell_by_field(&mut buf, 0, index, source, CellField::TypeHash) {
+        Ok(len) => {
+            debug_assert_eq!(len, buf.len());
+            Ok(Some(buf))
+        }
+        Err(SysError::ItemMissing) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+pub fn load_cell_lock(index: usize, source: Source) -> Result<Script, SysError> {
+    load_script_field(index, source, CellField::Lock)
+}
+
+/// Return None if the cell has no type script.
+pub fn load_cell_type(index: usize, source: Source) -> Result<Option<Script>, SysError> {
+    match load_script_field(index, source, CellField::Type) {
+        Ok(script) => Ok(Some(script)),
+        Err(SysError::ItemMissing) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+pub fn load_cell_data(index: usize, source: Source) -> Result<Vec<u8>, SysError> {
+    load_data(|buf, offset| syscalls::load_cell_data(buf, offset, index, source))
+}
+
+pub fn load_script() -> Result<Script, SysError> {
+    let data = load_data(syscalls::load_script)?;
+
+    match ScriptReader::verify(&data, false) {
+        Ok(()) => Ok(Script::new_unchecked(data.into())),
+        Err(_err) => Err(SysError::Encoding),
+    }
+}
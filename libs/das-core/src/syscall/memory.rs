@@ -0,0 +1,304 @@
+use alloc::vec::Vec;
+
+use ckb_std::ckb_constants::{CellField, Source};
+use ckb_std::ckb_types::packed::{CellOutput, Header, Script};
+use ckb_std::ckb_types::prelude::*;
+use ckb_std::error::SysError;
+
+use super::Syscalls;
+use crate::constants::{ScriptType, ONE_CKB};
+use crate::util::blake2b_256;
+
+#[derive(Debug, Clone, Default)]
+pub struct MemoryCell {
+    pub output: CellOutput,
+    pub data: Vec<u8>,
+    /// The header of the block which contains the cell, it is required by `load_header` with `Source::Input` or
+    /// `Source::CellDep`.
+    pub header: Option<Header>,
+}
+
+impl MemoryCell {
+    pub fn new(output: CellOutput, data: Vec<u8>) -> Self {
+        MemoryCell {
+            output,
+            data,
+            header: None,
+        }
+    }
+
+    pub fn with_header(mut self, header: Header) -> Self {
+        self.header = Some(header);
+        self
+    }
+
+    fn occupied_capacity(&self) -> u64 {
+        fn script_size(script: &Script) -> u64 {
+            // code_hash + hash_type + args
+            32 + 1 + script.args().raw_data().len() as u64
+        }
+
+        let mut size = 8 + script_size(&self.output.lock()) + self.data.len() as u64;
+        if let Some(type_) = self.output.type_().to_opt() {
+            size += script_size(&type_);
+        }
+
+        size * ONE_CKB
+    }
+}
+
+/// An in-memory transaction which implements the syscalls like CKB-VM does when running `script`.
+///
+/// The group sources are the cells whose lock hash or type hash equals the hash of `script`, depending on
+/// `script_type`.
+#[derive(Debug, Clone)]
+pub struct MemoryTransaction {
+    pub script: Script,
+    pub script_type: ScriptType,
+    pub inputs: Vec<MemoryCell>,
+    pub outputs: Vec<MemoryCell>,
+    pub cell_deps: Vec<MemoryCell>,
+    pub header_deps: Vec<Header>,
+    pub witnesses: Vec<Vec<u8>>,
+}
+
+impl MemoryTransaction {
+    pub fn new(script: Script, script_type: ScriptType) -> Self {
+        MemoryTransaction {
+            script,
+            script_type,
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            cell_deps: Vec::new(),
+            header_deps: Vec::new(),
+            witnesses: Vec::new(),
+        }
+    }
+
+    /// The indexes of the cells in the script group.
+    fn group_indexes(&self, cells: &[MemoryCell]) -> Vec<usize> {
+        let script_hash = blake2b_256(self.script.as_slice());
+        cells
+            .iter()
+            .enumerate()
+            .filter(|(_, cell)| match self.script_type {
+                ScriptType::Lock => blake2b_256(cell.output.lock().as_slice()) == script_hash,
+                ScriptType::Type => match cell.output.type_().to_opt() {
+                    Some(type_) => blake2b_256(type_.as_slice()) == script_hash,
+                    None => false,
+                },
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Resolve the index of a source to the index in inputs, outputs or cell_deps.
+    fn resolve(&self, index: usize, source: Source) -> Result<(usize, &[MemoryCell]), SysError> {
+        let (index, cells) = match source {
+            Source::Input => (index, self.inputs.as_slice()),
+            Source::Output => (index, self.outputs.as_slice()),
+            Source::CellDep => (index, self.cell_deps.as_slice()),
+            Source::HeaderDep => return Err(SysError::IndexOutOfBound),
+            Source::GroupInput => match self.group_indexes(&self.inputs).get(index) {
+                Some(i) => (*i, self.inputs.as_slice()),
+                None => return Err(SysError::IndexOutOfBound),
+            },
+            Source::GroupOutput => match self.group_indexes(&self.outputs).get(index) {
+                Some(i) => (*i, self.outputs.as_slice()),
+                None => return Err(SysError::IndexOutOfBound),
+            },
+        };
+
+        if index < cells.len() {
+            Ok((index, cells))
+        } else {
+            Err(SysError::IndexOutOfBound)
+        }
+    }
+
+    fn cell(&self, index: usize, source: Source) -> Result<&MemoryCell, SysError> {
+        let (index, cells) = self.resolve(index, source)?;
+        Ok(&cells[index])
+    }
+}
+
+/// Copy the data from `offset` into `buf` like CKB-VM, the length of the rest of the data is returned.
+fn store_data(buf: &mut [u8], offset: usize, data: &[u8]) -> Result<usize, SysError> {
+    let data = data.get(offset..).unwrap_or_default();
+    let len = buf.len().min(data.len());
+    buf[..len].copy_from_slice(&data[..len]);
+
+    if data.len() > buf.len() {
+        Err(SysError::LengthNotEnough(data.len()))
+    } else {
+        Ok(data.len())
+    }
+}
+
+impl Syscalls for MemoryTransaction {
+    fn load_cell(&self, buf: &mut [u8], offset: usize, index: usize, source: Source) -> Result<usize, SysError> {
+        let cell = self.cell(index, source)?;
+        store_data(buf, offset, cell.output.as_slice())
+    }
+
+    fn load_cell_by_field(
+        &self,
+        buf: &mut [u8],
+        offset: usize,
+        index: usize,
+        source: Source,
+        field: CellField,
+    ) -> Result<usize, SysError> {
+        let cell = self.cell(index, source)?;
+        match field {
+            CellField::Capacity => store_data(buf, offset, cell.output.capacity().as_slice()),
+            CellField::DataHash => {
+                // The data hash of empty data is zero in CKB.
+                let hash = if cell.data.is_empty() {
+                    [0u8; 32]
+                } else {
+                    blake2b_256(&cell.data)
+                };
+                store_data(buf, offset, &hash)
+            }
+            CellField::Lock => store_data(buf, offset, cell.output.lock().as_slice()),
+            CellField::LockHash => store_data(buf, offset, &blake2b_256(cell.output.lock().as_slice())),
+            CellField::Type => match cell.output.type_().to_opt() {
+                Some(type_) => store_data(buf, offset, type_.as_slice()),
+                None => Err(SysError::ItemMissing),
+            },
+            CellField::TypeHash => match cell.output.type_().to_opt() {
+                Some(type_) => store_data(buf, offset, &blake2b_256(type_.as_slice())),
+                None => Err(SysError::ItemMissing),
+            },
+            CellField::OccupiedCapacity => store_data(buf, offset, &cell.occupied_capacity().to_le_bytes()),
+        }
+    }
+
+    fn load_cell_data(&self, buf: &mut [u8], offset: usize, index: usize, source: Source) -> Result<usize, SysError> {
+        let cell = self.cell(index, source)?;
+        store_data(buf, offset, &cell.data)
+    }
+
+    fn load_header(&self, buf: &mut [u8], offset: usize, index: usize, source: Source) -> Result<usize, SysError> {
+        let header = match source {
+            Source::HeaderDep => self.header_deps.get(index).ok_or(SysError::IndexOutOfBound)?,
+            Source::Output | Source::GroupOutput => return Err(SysError::IndexOutOfBound),
+            _ => {
+                let header = self.cell(index, source)?.header.as_ref().ok_or(SysError::ItemMissing)?;
+                // CKB-VM only loads the headers of cells which are also in the header_deps.
+                if !self.header_deps.iter().any(|item| item.as_slice() == header.as_slice()) {
+                    return Err(SysError::ItemMissing);
+                }
+                header
+            }
+        };
+
+        store_data(buf, offset, header.as_slice())
+    }
+
+    fn load_witness(&self, buf: &mut [u8], offset: usize, index: usize, source: Source) -> Result<usize, SysError> {
+        let index = match source {
+            Source::Input | Source::Output => index,
+            Source::GroupInput | Source::GroupOutput => self.resolve(index, source)?.0,
+            Source::CellDep | Source::HeaderDep => return Err(SysError::IndexOutOfBound),
+        };
+        let witness = self.witnesses.get(index).ok_or(SysError::IndexOutOfBound)?;
+
+        store_data(buf, offset, witness)
+    }
+
+    fn load_script(&self, buf: &mut [u8], offset: usize) -> Result<usize, SysError> {
+        store_data(buf, offset, self.script.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::boxed::Box;
+    use alloc::vec;
+
+    use ckb_std::ckb_types::packed::ScriptOpt;
+
+    use super::*;
+    use crate::syscall::{high_level, reset_backend, set_backend, syscalls};
+    use crate::verifiers::common::{verify_cell_number_and_position, verify_tx_fee_spent_correctly};
+
+    fn script(args: &[u8]) -> Script {
+        Script::new_builder().args(args.pack()).build()
+    }
+
+    fn cell(capacity: u64, lock: Script, type_: Option<Script>, data: Vec<u8>) -> MemoryCell {
+        let output = CellOutput::new_builder()
+            .capacity(capacity.pack())
+            .lock(lock)
+            .type_(ScriptOpt::new_builder().set(type_).build())
+            .build();
+        MemoryCell::new(output, data)
+    }
+
+    fn transaction() -> MemoryTransaction {
+        let mut tx = MemoryTransaction::new(script(b"type"), ScriptType::Type);
+        tx.inputs = vec![
+            cell(1000 * ONE_CKB, script(b"owner"), None, vec![]),
+            cell(200 * ONE_CKB, script(b"owner"), Some(script(b"type")), vec![1; 2000]),
+        ];
+        tx.outputs = vec![cell(
+            200 * ONE_CKB - 10_000,
+            script(b"owner"),
+            Some(script(b"type")),
+            vec![2; 10],
+        )];
+        tx.witnesses = vec![vec![0], vec![1]];
+        tx
+    }
+
+    #[test]
+    fn test_memory_transaction() {
+        set_backend(Box::new(transaction()));
+
+        assert_eq!(vec![1; 2000], high_level::load_cell_data(1, Source::Input).unwrap());
+        let mut buf = [0u8; 5];
+        assert_eq!(
+            Err(SysError::LengthNotEnough(10)),
+            syscalls::load_cell_data(&mut buf, 1990, 1, Source::Input)
+        );
+        assert_eq!(Ok(10), syscalls::load_cell_data(&mut [0u8; 10], 1990, 1, Source::Input));
+
+        assert_eq!(None, high_level::load_cell_type_hash(0, Source::Input).unwrap());
+        assert_eq!(
+            Some(blake2b_256(script(b"type").as_slice())),
+            high_level::load_cell_type_hash(0, Source::GroupInput).unwrap()
+        );
+        assert_eq!(
+            Err(SysError::IndexOutOfBound),
+            high_level::load_cell_capacity(1, Source::GroupInput)
+        );
+        assert_eq!(
+            (8 + 38 + 37 + 10) * ONE_CKB,
+            high_level::load_cell_occupied_capacity(0, Source::Output).unwrap()
+        );
+
+        let mut buf = [0u8; 1];
+        syscalls::load_witness(&mut buf, 0, 0, Source::GroupInput).unwrap();
+        assert_eq!([1], buf);
+        assert_eq!(
+            Err(SysError::ItemMissing),
+            syscalls::load_header(&mut buf, 0, 0, Source::Input)
+        );
+
+        reset_backend();
+    }
+
+    #[test]
+    fn test_run_verifiers() {
+        set_backend(Box::new(transaction()));
+
+        core::assert!(verify_cell_number_and_position("TestCell", &[1], &[1], &[0], &[0]).is_ok());
+        core::assert!(verify_cell_number_and_position("TestCell", &[1], &[0], &[0], &[0]).is_err());
+        core::assert!(verify_tx_fee_spent_correctly("TestCell", 1, 0, 10_000, 100 * ONE_CKB).is_ok());
+        core::assert!(verify_tx_fee_spent_correctly("TestCell", 1, 0, 9_999, 100 * ONE_CKB).is_err());
+
+        reset_backend();
+    }
+}
@@ -0,0 +1,127 @@
+//! The layer between the verifiers and the syscalls of CKB-VM.
+//!
+//! `syscalls` and `high_level` are drop-in replacements of the modules with the same names in ckb-std, they load from
+//! CKB-VM by default. Setting a `MemoryTransaction` as the backend lets the verifiers run natively against an in-memory
+//! transaction, with the same results as on chain, it is only available with the `std` feature.
+
+use alloc::boxed::Box;
+
+use ckb_std::ckb_constants::{CellField, Source};
+use ckb_std::error::SysError;
+
+pub mod high_level;
+#[cfg(feature = "std")]
+pub mod memory;
+pub mod syscalls;
+
+#[cfg(feature = "std")]
+pub use memory::{MemoryCell, MemoryTransaction};
+
+/// The raw partial loading syscalls, they follow the protocol of CKB-VM: fill `buf` with the data from `offset`, return
+/// the length of the rest of the data, or `SysError::LengthNotEnough` with that length if `buf` is too small.
+pub trait Syscalls {
+    fn load_cell(&self, buf: &mut [u8], offset: usize, index: usize, source: Source) -> Result<usize, SysError>;
+
+    fn load_cell_by_field(
+        &self,
+        buf: &mut [u8],
+        offset: usize,
+        index: usize,
+        source: Source,
+        field: CellField,
+    ) -> Result<usize, SysError>;
+
+    fn load_cell_data(&self, buf: &mut [u8], offset: usize, index: usize, source: Source) -> Result<usize, SysError>;
+
+    fn load_header(&self, buf: &mut [u8], offset: usize, index: usize, source: Source) -> Result<usize, SysError>;
+
+    fn load_witness(&self, buf: &mut [u8], offset: usize, index: usize, source: Source) -> Result<usize, SysError>;
+
+    fn load_script(&self, buf: &mut [u8], offset: usize) -> Result<usize, SysError>;
+}
+
+/// The default backend which calls CKB-VM.
+pub struct CkbVm;
+
+impl Syscalls for CkbVm {
+    fn load_cell(&self, buf: &mut [u8], offset: usize, index: usize, source: Source) -> Result<usize, SysError> {
+        ckb_std::syscalls::load_cell(buf, offset, index, source)
+    }
+
+    fn load_cell_by_field(
+        &self,
+        buf: &mut [u8],
+        offset: usize,
+        index: usize,
+        source: Source,
+        field: CellField,
+    ) -> Result<usize, SysError> {
+        ckb_std::syscalls::load_cell_by_field(buf, offset, index, source, field)
+    }
+
+    fn load_cell_data(&self, buf: &mut [u8], offset: usize, index: usize, source: Source) -> Result<usize, SysError> {
+        ckb_std::syscalls::load_cell_data(buf, offset, index, source)
+    }
+
+    fn load_header(&self, buf: &mut [u8], offset: usize, index: usize, source: Source) -> Result<usize, SysError> {
+        ckb_std::syscalls::load_header(buf, offset, index, source)
+    }
+
+    fn load_witness(&self, buf: &mut [u8], offset: usize, index: usize, source: Source) -> Result<usize, SysError> {
+        ckb_std::syscalls::load_witness(buf, offset, index, source)
+    }
+
+    fn load_script(&self, buf: &mut [u8], offset: usize) -> Result<usize, SysError> {
+        ckb_std::syscalls::load_script(buf, offset)
+    }
+}
+
+// The tests run in parallel threads, so every thread has its own backend with std.
+#[cfg(feature = "std")]
+std::thread_local! {
+    static BACKEND: core::cell::RefCell<Option<Box<dyn Syscalls>>> = core::cell::RefCell::new(None);
+}
+
+// CKB-VM runs a script in a single thread, so a plain static is enough without std.
+#[cfg(not(feature = "std"))]
+static mut BACKEND: Option<Box<dyn Syscalls>> = None;
+
+/// Replace the backend of all the syscalls, the verifiers will load everything from it since then.
+pub fn set_backend(backend: Box<dyn Syscalls>) {
+    replace_backend(Some(backend));
+}
+
+/// Restore the default `CkbVm` backend.
+pub fn reset_backend() {
+    replace_backend(None);
+}
+
+#[cfg(feature = "std")]
+fn replace_backend(backend: Option<Box<dyn Syscalls>>) {
+    BACKEND.with(|val| *val.borrow_mut() = backend);
+}
+
+#[cfg(not(feature = "std"))]
+fn replace_backend(backend: Option<Box<dyn Syscalls>>) {
+    unsafe {
+        *core::ptr::addr_of_mut!(BACKEND) = backend;
+    }
+}
+
+#[cfg(feature = "std")]
+fn with_backend<T>(f: impl FnOnce(&dyn Syscalls) -> T) -> T {
+    BACKEND.with(|val| match val.borrow().as_deref() {
+        Some(backend) => f(backend),
+        None => f(&CkbVm),
+    })
+}
+
+#[cfg(not(feature = "std"))]
+fn with_backend<T>(f: impl FnOnce(&dyn Syscalls) -> T) -> T {
+    unsafe {
+        match (*core::ptr::addr_of!(BACKEND)).as_deref() {
+            Some(backend) => f(backend),
+            None => f(&CkbVm),
+        }
+    }
+}
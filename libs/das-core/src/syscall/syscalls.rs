@@ -0,0 +1,36 @@
+//! The same functions as `ckb_std::syscalls`, but load from the current backend.
+
+use ckb_std::ckb_constants::{CellField, Source};
+use ckb_std::error::SysError;
+
+use super::with_backend;
+
+pub fn load_cell(buf: &mut [u8], offset: usize, index: usize, source: Source) -> Result<usize, SysError> {
+    with_backend(|backend| backend.load_cell(buf, offset, index, source))
+}
+
+pub fn load_cell_by_field(
+    buf: &mut [u8],
+    offset: usize,
+    index: usize,
+    source: Source,
+    field: CellField,
+) -> Result<usize, SysError> {
+    with_backend(|backend| backend.load_cell_by_field(buf, offset, index, source, field))
+}
+
+pub fn load_cell_data(buf: &mut [u8], offset: usize, index: usize, source: Source) -> Result<usize, SysError> {
+    with_backend(|backend| backend.load_cell_data(buf, offset, index, source))
+}
+
+pub fn load_header(buf: &mut [u8], offset: usize, index: usize, source: Source) -> Result<usize, SysError> {
+    with_backend(|backend| backend.load_header(buf, offset, index, source))
+}
+
+pub fn load_witness(buf: &mut [u8], offset: usize, index: usize, source: Source) -> Result<usize, SysError> {
+    with_backend(|backend| backend.load_witness(buf, offset, index, source))
+}
+
+pub fn load_script(buf: &mut [u8], offset: usize) -> Result<usize, SysError> {
+    with_backend(|backend| backend.load_script(buf, offset))
+}
@@ -13,14 +13,14 @@ use das_types::prelude::Entity;
 use das_types::util as das_types_util;
 
 use super::error::*;
-use super::{assert, code_to_error, debug, util, warn};
+use super::{assert, code_to_error, config, debug, util, warn};
 
 macro_rules! get_or_try_init {
     ( $self:expr, $property:ident, $entity_type:ty, $data_type:expr ) => {{
         $self
             .$property
             .get_or_try_init(|| {
-                let (i, raw) = Configs::parse_witness(&$self.config_witnesses, $data_type)?;
+                let (i, raw) = $self.parse_witness($data_type)?;
                 let entity = <$entity_type>::from_compatible_slice(&raw).map_err(|e| {
                     warn!("witnesses[{:>2}] Decoding {:?} failed: {}", i, $data_type, e);
                     ErrorCode::ConfigCellWitnessDecodingError
@@ -50,6 +50,8 @@ pub struct LockScriptTypeIdTable {
 #[derive(Debug)]
 pub struct Configs {
     config_witnesses: BTreeMap<u32, (usize, [u8; 32])>,
+    // The raw witnesses of ConfigCells, when it is some the witnesses in the transaction will never be loaded.
+    witnesses: Option<config::ConfigWitnesses>,
     pub account: OnceCell<ConfigCellAccount>,
     pub apply: OnceCell<ConfigCellApply>,
     pub char_set: Vec<OnceCell<CharSet>>,
@@ -74,6 +76,7 @@ impl Configs {
     pub fn new(config_witnesses: BTreeMap<u32, (usize, [u8; 32])>) -> Self {
         Configs {
             config_witnesses,
+            witnesses: None,
             account: OnceCell::new(),
             apply: OnceCell::new(),
             char_set: vec![OnceCell::new(); CHAR_SET_LENGTH],
@@ -95,18 +98,49 @@ impl Configs {
         }
     }
 
-    fn parse_witness(
-        config_witnesses: &BTreeMap<u32, (usize, [u8; 32])>,
-        data_type: DataType,
-    ) -> Result<(usize, Vec<u8>), Box<dyn ScriptError>> {
-        let &(i, expected_hash) = config_witnesses.get(&(data_type as u32)).ok_or_else(|| {
+    /// Build the configs from the raw witnesses of ConfigCells, like `Config::from_witnesses` does.
+    pub fn from_witnesses<T: AsRef<[u8]>>(
+        witnesses: impl IntoIterator<Item = T>,
+    ) -> Result<Self, Box<dyn ScriptError>> {
+        let witnesses = config::parse_config_witnesses(witnesses)?;
+        let config_witnesses = witnesses
+            .iter()
+            .map(|(data_type, (i, raw))| {
+                let hash = util::blake2b_256(&raw[(WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES)..]);
+                (*data_type as u32, (*i, hash))
+            })
+            .collect();
+
+        Ok(Configs {
+            witnesses: Some(witnesses),
+            ..Configs::new(config_witnesses)
+        })
+    }
+
+    /// Build the configs from a JSON snapshot, like `Config::from_snapshot_json` does.
+    #[cfg(feature = "snapshot")]
+    pub fn from_snapshot_json(json: &str) -> Result<Self, Box<dyn ScriptError>> {
+        Self::from_witnesses(config::parse_snapshot_json(json)?)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn from_snapshot_file(path: impl AsRef<std::path::Path>) -> Result<Self, Box<dyn ScriptError>> {
+        let json = std::fs::read_to_string(path).map_err(|_| code_to_error!(ErrorCode::ConfigIsPartialMissing))?;
+        Self::from_snapshot_json(&json)
+    }
+
+    fn parse_witness(&self, data_type: DataType) -> Result<(usize, Vec<u8>), Box<dyn ScriptError>> {
+        let &(i, expected_hash) = self.config_witnesses.get(&(data_type as u32)).ok_or_else(|| {
             warn!("Can not find {:?} in Configs.config_witnesses, this means the WitnessesParser did not find the witness", data_type);
             ErrorCode::ConfigIsPartialMissing
         })?;
 
         debug!("witnesses[{:>2}] Parsing it as {:?} ...", i, data_type);
 
-        let raw = util::load_das_witnesses(i)?;
+        let raw = match self.witnesses.as_ref().and_then(|witnesses| witnesses.get(&data_type)) {
+            Some((_, raw)) => raw.clone(),
+            None => util::load_das_witnesses(i)?,
+        };
         let entity = raw
             .get((WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES)..)
             .ok_or(ErrorCode::ConfigCellWitnessDecodingError)?;
@@ -185,7 +219,7 @@ impl Configs {
     pub fn record_key_namespace(&self) -> Result<&Vec<u8>, Box<dyn ScriptError>> {
         self.record_key_namespace.get_or_try_init(|| {
             let data_type = DataType::ConfigCellRecordKeyNamespace;
-            let (i, raw) = Self::parse_witness(&self.config_witnesses, data_type)?;
+            let (i, raw) = self.parse_witness(data_type)?;
             let data = match raw.get(WITNESS_LENGTH_BYTES..) {
                 Some(data) => data.to_vec(),
                 None => {
@@ -200,7 +234,7 @@ impl Configs {
 
     pub fn preserved_account(&self, data_type: DataType) -> Result<&Vec<u8>, Box<dyn ScriptError>> {
         self.preserved_account.get_or_try_init(|| {
-            let (i, raw) = Self::parse_witness(&self.config_witnesses, data_type)?;
+            let (i, raw) = self.parse_witness(data_type)?;
             let data = match raw.get(WITNESS_LENGTH_BYTES..) {
                 Some(data) => data.to_vec(),
                 None => {
@@ -217,7 +251,7 @@ impl Configs {
     pub fn unavailable_account(&self) -> Result<&Vec<u8>, Box<dyn ScriptError>> {
         self.unavailable_account.get_or_try_init(|| {
             let data_type = DataType::ConfigCellUnAvailableAccount;
-            let (i, raw) = Self::parse_witness(&self.config_witnesses, data_type)?;
+            let (i, raw) = self.parse_witness(data_type)?;
             let data = match raw.get(WITNESS_LENGTH_BYTES..) {
                 Some(data) => data.to_vec(),
                 None => {
@@ -233,7 +267,7 @@ impl Configs {
     pub fn sub_account_beta_list(&self) -> Result<&Vec<u8>, Box<dyn ScriptError>> {
         self.unavailable_account.get_or_try_init(|| {
             let data_type = DataType::ConfigCellSubAccountBetaList;
-            let (i, raw) = Self::parse_witness(&self.config_witnesses, data_type)?;
+            let (i, raw) = self.parse_witness(data_type)?;
             let data = match raw.get(WITNESS_LENGTH_BYTES..) {
                 Some(data) => data.to_vec(),
                 None => {
@@ -257,7 +291,7 @@ impl Configs {
                     }
                 };
                 let data_type = das_types_util::char_set_to_data_type(char_set_type);
-                let (i, raw) = Self::parse_witness(&self.config_witnesses, data_type)?;
+                let (i, raw) = self.parse_witness(data_type)?;
                 let length = match raw.get(..WITNESS_LENGTH_BYTES) {
                     Some(length_bytes) => {
                         let mut tmp = [0u8; 4];
@@ -295,7 +329,7 @@ impl Configs {
     pub fn smt_node_white_list(&self) -> Result<&Vec<[u8; 32]>, Box<dyn ScriptError>> {
         self.smt_node_white_list.get_or_try_init(|| {
             let data_type = DataType::ConfigCellSMTNodeWhitelist;
-            let (i, raw) = Self::parse_witness(&self.config_witnesses, data_type)?;
+            let (i, raw) = self.parse_witness(data_type)?;
             let data = match raw.get(WITNESS_LENGTH_BYTES..) {
                 Some(data) => {
                     let mut ret = vec![];
@@ -5,7 +5,6 @@ use alloc::vec::Vec;
 use core::convert::TryFrom;
 
 use ckb_std::ckb_constants::Source;
-use ckb_std::high_level;
 use das_types::constants::{das_lock, *};
 use das_types::mixer::AccountCellDataReaderMixer;
 use das_types::packed::*;
@@ -14,6 +13,7 @@ use das_types::util as types_util;
 use crate::config::Config;
 use crate::constants::*;
 use crate::error::*;
+use crate::syscall::high_level;
 #[cfg(debug_assertions)]
 use crate::util::print_dp;
 use crate::util::{blake2b_256, find_cells_by_script};
@@ -93,7 +93,7 @@ pub fn verify_account_in_auction(
     let expired_at = data_parser::account_cell::get_expired_at(data.as_slice());
     let expiration_grace_period = u32::from(config.expiration_grace_period()) as u64;
     let expiration_auction_period = u32::from(config.expiration_auction_period()) as u64;
-    let expiration_auction_start_premium = u32::from(config.expiration_auction_start_premiums()) as u64;
+    let expiration_auction_start_premium = u32::from(config.expiration_auction_start_premiums());
 
     if current_timestamp > expired_at {
         let duration_after_expired = current_timestamp - expired_at;
@@ -2,13 +2,13 @@ use alloc::boxed::Box;
 use alloc::vec::Vec;
 
 use ckb_std::ckb_constants::Source;
-use ckb_std::high_level;
 use das_types::constants::{das_lock, DasLockType};
 use das_types::packed as das_packed;
 use das_types::prelude::{Builder, Entity, Reader};
 
 use crate::constants::*;
 use crate::error::*;
+use crate::syscall::high_level;
 use crate::util::{self};
 use crate::{code_to_error, data_parser, warn};
 
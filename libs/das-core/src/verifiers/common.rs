@@ -4,7 +4,6 @@ use alloc::vec::Vec;
 use core::cmp::Ordering;
 
 use ckb_std::ckb_constants::Source;
-use ckb_std::high_level;
 use das_types::constants::wallet_lock;
 use das_types::packed;
 use sparse_merkle_tree::ckb_smt::SMTBuilder;
@@ -12,6 +11,7 @@ use sparse_merkle_tree::H256;
 
 use crate::constants::{CellField, ScriptType};
 use crate::error::*;
+use crate::syscall::high_level;
 use crate::util;
 
 pub fn verify_cell_dep_number(
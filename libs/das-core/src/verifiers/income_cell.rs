@@ -2,7 +2,6 @@ use alloc::boxed::Box;
 use core::cmp::Ordering;
 
 use ckb_std::ckb_constants::Source;
-use ckb_std::high_level;
 use das_map::map::Map;
 use das_map::util as map_util;
 use das_types::packed::*;
@@ -11,6 +10,7 @@ use das_types::prelude::*;
 use crate::config::Config;
 use crate::constants::ScriptType;
 use crate::error::*;
+use crate::syscall::high_level;
 use crate::{assert, code_to_error, debug, util, warn};
 
 pub fn verify_newly_created(
@@ -2,13 +2,13 @@ use alloc::boxed::Box;
 
 use ckb_std::ckb_constants::Source;
 use ckb_std::ckb_types::packed as ckb_packed;
-use ckb_std::high_level;
 use ckb_std::syscalls::SysError;
 use das_types::constants::always_success_lock;
 use das_types::packed::*;
 
 use crate::constants::*;
 use crate::error::*;
+use crate::syscall::high_level;
 use crate::util::{self, find_cells_by_script};
 use crate::{assert, code_to_error, warn};
 
@@ -4,9 +4,9 @@ use alloc::vec::Vec;
 use core::ops::Index;
 
 use ckb_std::ckb_constants::Source;
-use ckb_std::high_level;
 use das_dynamic_libs::error::Error as DasDynamicLibError;
 use das_dynamic_libs::sign_lib::SignLib;
+use das_types::account;
 use das_types::constants::{das_lock, *};
 use das_types::mixer::SubAccountReaderMixer;
 use das_types::packed::*;
@@ -15,6 +15,7 @@ use das_types::prettier::Prettier;
 
 use crate::constants::*;
 use crate::error::*;
+use crate::syscall::high_level;
 use crate::witness_parser::sub_account::*;
 use crate::witness_parser::webauthn_signature::WebAuthnSignature;
 use crate::{data_parser, util, verifiers};
@@ -159,19 +160,16 @@ pub fn verify_suffix_with_parent_account<'a>(
         sub_account_index
     );
 
-    let mut expected_suffix = b".".to_vec();
-    expected_suffix.extend(parent_account);
-
     let suffix = sub_account_reader.suffix().raw_data();
 
     das_assert!(
-        expected_suffix == suffix,
+        account::is_suffix_of_parent(suffix, parent_account),
         SubAccountCellErrorCode::SubAccountInitialValueError,
-        "  witnesses[{:>2}] The witness.sub_account.suffix of {} should come from the parent account.(expected: {:?}, current: {:?})",
+        "  witnesses[{:>2}] The witness.sub_account.suffix of {} should come from the parent account.(expected: .{}, current: {})",
         sub_account_index,
         util::get_sub_account_name_from_reader(&sub_account_reader),
-        String::from_utf8(expected_suffix),
-        String::from_utf8(suffix.to_vec())
+        String::from_utf8_lossy(parent_account),
+        String::from_utf8_lossy(suffix)
     );
 
     Ok(())
@@ -10,16 +10,17 @@ use core::ops::Deref;
 
 use ckb_std::ckb_constants::Source;
 use ckb_std::ckb_types::packed::{CellOutput, Script};
-use ckb_std::high_level::{
-    load_cell, load_cell_data, load_cell_lock, load_cell_lock_hash, load_cell_type, load_cell_type_hash, QueryIter,
-};
-use ckb_std::syscalls::{load_witness, SysError};
+use ckb_std::error::SysError;
 use das_types::constants::{WITNESS_HEADER, WITNESS_HEADER_BYTES, WITNESS_TYPE_BYTES};
 use das_types::packed::{ConfigList, Data, DataEntity};
 use molecule::bytes::Bytes;
 use molecule::prelude::Entity;
 
 use crate::error::{ErrorCode, ScriptError};
+use crate::syscall::high_level::{
+    load_cell, load_cell_data, load_cell_lock, load_cell_lock_hash, load_cell_type, load_cell_type_hash, QueryIter,
+};
+use crate::syscall::syscalls::{self, load_witness};
 use crate::traits::{Blake2BHash, GetDataType};
 // use crate::util::find_only_cell_by_type_id;
 
@@ -173,7 +174,7 @@ impl CellIndexer {
             });
 
             let mut data = [0; 32];
-            let res = ckb_std::syscalls::load_cell_data(&mut data, 0, item.meta.index, item.meta.source);
+            let res = syscalls::load_cell_data(&mut data, 0, item.meta.index, item.meta.source);
             match res {
                 Ok(_) | Err(SysError::LengthNotEnough(_)) => {
                     self.by_hash
@@ -691,7 +692,7 @@ impl GeneralWitnessParser {
         loop {
             // Only load first 7 bytes to identify the corresponding witness type
             let res = match load_witness(&mut buf, 0, i, Source::Input) {
-                Err(ckb_std::syscalls::SysError::IndexOutOfBound) => break,
+                Err(SysError::IndexOutOfBound) => break,
                 Ok(actual_size) => Witness::Loaded(WithMeta {
                     item: CompleteWitness {
                         buf: buf[..actual_size].to_owned(),
@@ -702,7 +703,7 @@ impl GeneralWitnessParser {
                         source: Source::Input,
                     },
                 }),
-                Err(ckb_std::syscalls::SysError::LengthNotEnough(actual_size)) => Witness::Loading(WithMeta {
+                Err(SysError::LengthNotEnough(actual_size)) => Witness::Loading(WithMeta {
                     item: PartialWitness {
                         buf: buf.to_vec(),
                         actual_size,
@@ -766,7 +767,7 @@ impl GeneralWitnessParser {
         meta: &Meta,
     ) -> Result<ParsedWithHash<T>, Box<dyn ScriptError>> {
         let mut data = [0; 32];
-        let res = ckb_std::syscalls::load_cell_data(&mut data, 0, meta.index, meta.source)?;
+        let res = syscalls::load_cell_data(&mut data, 0, meta.index, meta.source)?;
         if res != 32 {
             return Err(code_to_error!(ErrorCode::InvalidCellData));
         }
@@ -8,7 +8,6 @@ use core::str::FromStr;
 
 use ckb_std::ckb_constants::Source;
 use ckb_std::error::SysError;
-use ckb_std::syscalls::{self};
 use das_types::constants::*;
 use das_types::packed::{ConfigCellMainReader, DeviceKeyListCellData};
 use das_types::prelude::Entity;
@@ -17,6 +16,7 @@ use super::super::error::*;
 use super::super::util;
 use super::device_key_list::get_device_key_list_cell_deps;
 use super::lv_parser::*;
+use crate::syscall::syscalls;
 use crate::traits::Blake2BHash;
 use crate::util::load_das_witnesses;
 
@@ -8,7 +8,6 @@ use core::str::FromStr;
 
 use ckb_std::ckb_constants::Source;
 use ckb_std::error::SysError;
-use ckb_std::syscalls;
 use das_types::constants::*;
 use das_types::mixer::SubAccountMixer;
 use das_types::packed::*;
@@ -20,6 +19,7 @@ use simple_ast::{types as ast_types, util as ast_util};
 use super::super::error::*;
 use super::super::{data_parser, util};
 use super::device_key_list::get_device_key_list_cell_deps;
+use crate::syscall::syscalls;
 use crate::traits::Blake2BHash;
 use crate::util::load_das_witnesses;
 
@@ -93,6 +93,8 @@ pub struct SubAccountWitnessesParser {
     pub contains_edition: bool,
     pub contains_renew: bool,
     pub contains_recycle: bool,
+    /// The count of sub-account witnesses with the create action.
+    pub creation_count: u32,
     pub mint_sign_index: Option<usize>,
     pub renew_sign_index: Option<usize>,
     pub price_rule_indexes: Vec<usize>,
@@ -110,6 +112,7 @@ impl SubAccountWitnessesParser {
         let mut contains_edition = false;
         let mut contains_renew = false;
         let mut contains_recycle = false;
+        let mut creation_count = 0;
         let mut mint_sign_index = None;
         let mut renew_sign_index = None;
         let mut price_rule_indexes = Vec::new();
@@ -181,6 +184,7 @@ impl SubAccountWitnessesParser {
                             ];
                             if action == SubAccountAction::Create.to_string() {
                                 contains_creation = true;
+                                creation_count += 1;
                             } else if edit_like_actions.contains(&action) {
                                 contains_edition = true;
                             } else if action == SubAccountAction::Renew.to_string() {
@@ -239,6 +243,7 @@ impl SubAccountWitnessesParser {
             contains_edition,
             contains_renew,
             contains_recycle,
+            creation_count,
             mint_sign_index,
             renew_sign_index,
             price_rule_indexes,
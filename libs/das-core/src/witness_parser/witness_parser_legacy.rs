@@ -5,7 +5,6 @@ use core::convert::{TryFrom, TryInto};
 
 use ckb_std::ckb_constants::Source;
 use ckb_std::error::SysError;
-use ckb_std::syscalls;
 use das_types::constants::{
     always_success_lock, config_cell_type, das_lock, multisign_lock, signhash_lock, DataType, TypeScript,
     WITNESS_HEADER, WITNESS_HEADER_BYTES, WITNESS_LENGTH_BYTES, WITNESS_TYPE_BYTES,
@@ -18,6 +17,7 @@ use super::super::constants::*;
 use super::super::error::*;
 use super::super::types::{Configs, LockScriptTypeIdTable};
 use super::super::util;
+use crate::syscall::syscalls;
 use crate::util::load_data;
 
 #[derive(Debug)]
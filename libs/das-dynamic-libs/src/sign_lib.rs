@@ -1,6 +1,11 @@
 use alloc::string::ToString;
 use alloc::vec::Vec;
 
+// The C implementation of dynamic loading only exists on RISC-V, host builds fall back to the Rust implementation which
+// shares the same Symbol type, so das-core can be built and tested on the host.
+#[cfg(not(target_arch = "riscv64"))]
+use ckb_std::dynamic_loading::Symbol;
+#[cfg(target_arch = "riscv64")]
 use ckb_std::dynamic_loading_c_impl::Symbol;
 use das_types::constants::{DasLockType, SubAccountAction};
 use das_types::packed::*;
@@ -20,6 +20,8 @@ fn main() {
 
     println!("cargo:rerun-if-changed={}", dotenv_path.as_path().display());
 
+    generate_presets(dotenv_path.parent().expect("The .env file should be in a directory."));
+
     match dotenvy::from_path_iter(dotenv_path.as_path()) {
         Ok(dotenv_iter) => {
             println!("cargo:warning=✅ {} loaded", dotenv_path.as_path().display());
@@ -48,6 +50,30 @@ fn main() {
     }
 }
 
+/// Generate `presets.rs` in OUT_DIR for `NetworkProfile::preset`, it embeds the `.env.*` files beside the `.env` file,
+/// the missing ones are `None`.
+fn generate_presets(dir: &Path) {
+    let mut code = String::new();
+    for network in ["mainnet", "testnet2", "testnet3", "dev"] {
+        let path = dir.join(format!(".env.{}", network));
+        println!("cargo:rerun-if-changed={}", path.display());
+
+        let value = if path.is_file() {
+            format!("Some(include_str!({:?}))", path.display().to_string())
+        } else {
+            String::from("None")
+        };
+        code.push_str(&format!(
+            "const PRESET_{}: Option<&str> = {};\n",
+            network.to_uppercase(),
+            value
+        ));
+    }
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").expect("$OUT_DIR is required to generate the presets."));
+    fs::write(out_dir.join("presets.rs"), code).expect("Writing presets.rs failed.");
+}
+
 /// Searches for `filename` in `directory` and parent directories until found or root is reached.
 /// Copy and slightly modified from the dotenvy crate.
 pub fn find(directory: &Path, filename: &Path) -> Result<PathBuf, io::Error> {
@@ -0,0 +1,50 @@
+//! Compare the schemas with an old version of them, and check the coverage of mixer traits.
+//!
+//! Usage: cargo run -p das-types --example check_schema -- <old_schemas_dir> [new_schemas_dir]
+
+use std::path::Path;
+use std::{env, fs, process};
+
+use das_types::schema::{check_compatibility, check_mixers, Schemas, IGNORED_MIXER_TYPES};
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let old_dir = match args.first() {
+        Some(val) => Path::new(val).to_path_buf(),
+        None => {
+            eprintln!("Usage: check_schema <old_schemas_dir> [new_schemas_dir]");
+            process::exit(2);
+        }
+    };
+    let new_dir = args
+        .get(1)
+        .map(|val| Path::new(val).to_path_buf())
+        .unwrap_or_else(|| root.join("schemas"));
+
+    let load = |dir: &Path| {
+        Schemas::from_dir(dir).unwrap_or_else(|err| {
+            eprintln!("Parse schemas failed: {}", err);
+            process::exit(2);
+        })
+    };
+    let old = load(&old_dir);
+    let new = load(&new_dir);
+
+    let mut failed = false;
+    for err in check_compatibility(&old, &new) {
+        println!("incompatible: {}", err);
+        failed = true;
+    }
+
+    let mixer_source = fs::read_to_string(root.join("rust/src/mixer.rs")).expect("Expect mixer.rs to be readable.");
+    for err in check_mixers(&new, &mixer_source, &IGNORED_MIXER_TYPES) {
+        println!("mixer: {}", err);
+        failed = true;
+    }
+
+    if failed {
+        process::exit(1);
+    }
+    println!("Schemas are compatible.");
+}
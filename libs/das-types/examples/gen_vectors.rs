@@ -0,0 +1,308 @@
+//! Generate the conformance test vectors for the bindings in other languages.
+//!
+//! Usage: cargo run -p das-types --example gen_vectors
+//!
+//! Every vector is a witness carrying a version of the cell data in `Data.new`, the `decoded` is the entity in the
+//! schema of that version and the `latest` is the entity upgraded to the latest version by `upgrade.rs`. The SubAccount
+//! is not stored in `Data`, so its vectors are the witnesses of editing a sub-account, the version is the
+//! `old_sub_account_version` of the witness.
+
+use std::fs;
+use std::path::Path;
+
+use das_types::constants::{DataType, Source};
+use das_types::packed::*;
+use das_types::prelude::*;
+use das_types::upgrade::Upgrade;
+use das_types::util::{wrap_data_witness_v4, wrap_sub_account_witness, EntityWrapper};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+fn script(args: &str) -> Value {
+    json!({
+        "code_hash": "0x9376c3b5811942960a846691e16e477cf43d7c7fa654067c9948dfcd09a32137",
+        "hash_type": 1,
+        "args": args,
+    })
+}
+
+fn account_cell_data() -> Value {
+    json!({
+        "id": "0x9d6e5f2b56ed9e1b4e86d0dbd9c6b0b9d2e5a7f1",
+        "account": [
+            { "char_set_name": 2, "bytes": "0x64" },
+            { "char_set_name": 2, "bytes": "0x61" },
+            { "char_set_name": 2, "bytes": "0x73" },
+            { "char_set_name": 1, "bytes": "0x30" },
+        ],
+        "registered_at": 1_611_200_000u64,
+        "updated_at": 1_611_300_000u64,
+        "last_transfer_account_at": 1_611_400_000u64,
+        "last_edit_manager_at": 1_611_500_000u64,
+        "last_edit_records_at": 1_611_600_000u64,
+        "status": 0,
+        "records": [{
+            "record_type": "0x61646472657373",
+            "record_key": "0x3630",
+            "record_label": "0x",
+            "record_value": "0x9d6e5f2b56ed9e1b4e86d0dbd9c6b0b9d2e5a7f1",
+            "record_ttl": 300,
+        }],
+        "enable_sub_account": 1,
+        "renew_sub_account_price": 100_000_000u64,
+        "approval": { "action": "0x7472616e73666572", "params": "0x00" },
+    })
+}
+
+fn pre_account_cell_data() -> Value {
+    json!({
+        "account": [
+            { "char_set_name": 2, "bytes": "0x64" },
+            { "char_set_name": 2, "bytes": "0x61" },
+            { "char_set_name": 2, "bytes": "0x73" },
+            { "char_set_name": 1, "bytes": "0x30" },
+        ],
+        "refund_lock": script("0x01"),
+        "owner_lock_args": "0x050000000000000000000000000000000000001111050000000000000000000000000000000000001111",
+        "inviter_id": "0x",
+        "inviter_lock": null,
+        "channel_lock": script("0x02"),
+        "price": { "length": 4, "new": 5_000_000u64, "renew": 5_000_000u64 },
+        "quote": 1000,
+        "invited_discount": 0,
+        "created_at": 1_611_200_000u64,
+        "initial_records": [{
+            "record_type": "0x61646472657373",
+            "record_key": "0x3630",
+            "record_label": "0x",
+            "record_value": "0x9d6e5f2b56ed9e1b4e86d0dbd9c6b0b9d2e5a7f1",
+            "record_ttl": 300,
+        }],
+        "initial_cross_chain": { "checked": 1, "coin_type": 60, "chain_id": 1 },
+    })
+}
+
+fn sub_account() -> Value {
+    json!({
+        "lock": script("0x050000000000000000000000000000000000001111050000000000000000000000000000000000001111"),
+        "id": "0x8a9f3e0b6c0d2e5a7f19d6e5f2b56ed9e1b4e86d",
+        "account": [
+            { "char_set_name": 2, "bytes": "0x61" },
+            { "char_set_name": 2, "bytes": "0x6c" },
+            { "char_set_name": 2, "bytes": "0x69" },
+            { "char_set_name": 2, "bytes": "0x63" },
+            { "char_set_name": 2, "bytes": "0x65" },
+        ],
+        "suffix": "0x2e626974",
+        "registered_at": 1_611_200_000u64,
+        "expired_at": 1_642_736_000u64,
+        "status": 0,
+        "records": [{
+            "record_type": "0x61646472657373",
+            "record_key": "0x3630",
+            "record_label": "0x",
+            "record_value": "0x9d6e5f2b56ed9e1b4e86d0dbd9c6b0b9d2e5a7f1",
+            "record_ttl": 300,
+        }],
+        "nonce": 3,
+        "enable_sub_account": 1,
+        "renew_sub_account_price": 100_000_000u64,
+        "approval": { "action": "0x7472616e73666572", "params": "0x00" },
+    })
+}
+
+fn account_sale_cell_data() -> Value {
+    json!({
+        "account_id": "0x9d6e5f2b56ed9e1b4e86d0dbd9c6b0b9d2e5a7f1",
+        "account": "0x64617330312e626974",
+        "price": 20_000_000_000u64,
+        "description": "0x666f722073616c65",
+        "started_at": 1_611_200_000u64,
+        "buyer_inviter_profit_rate": 100,
+    })
+}
+
+/// Build a version of the entity from the sample containing the fields of all versions.
+fn omit<T: DeserializeOwned>(sample: &Value, fields: &[&str]) -> T {
+    let mut value = sample.clone();
+    for field in fields {
+        value.as_object_mut().unwrap().remove(*field);
+    }
+    serde_json::from_value(value).expect("The sample should match the schema.")
+}
+
+fn vector<T: Entity + Serialize, L: Serialize>(
+    data_type: DataType,
+    version: u32,
+    schema: &str,
+    entity: T,
+    wrapper: EntityWrapper,
+    latest: L,
+) -> Value {
+    let witness = wrap_data_witness_v4(data_type, version, 0, wrapper, Source::Output);
+    json!({
+        "name": format!("{}_v{}", data_type, version),
+        "data_type": data_type as u32,
+        "version": version,
+        "schema": schema,
+        "witness": format!("0x{}", hex::encode(witness)),
+        "entity": format!("0x{}", hex::encode(entity.as_slice())),
+        "decoded": serde_json::to_value(&entity).unwrap(),
+        "latest": serde_json::to_value(&latest).unwrap(),
+    })
+}
+
+fn sub_account_vector<T: Entity + Serialize, L: Serialize>(version: u32, schema: &str, entity: T, latest: L) -> Value {
+    let mut payload = Vec::new();
+    for field in [
+        &3u32.to_le_bytes()[..],
+        b"edit",
+        &[0; 65],
+        &[0],
+        &1_611_300_000u64.to_le_bytes(),
+        &[0; 32],
+        &[],
+        &version.to_le_bytes(),
+        &version.to_le_bytes(),
+        entity.as_slice(),
+        b"records",
+        &[],
+    ] {
+        payload.extend((field.len() as u32).to_le_bytes());
+        payload.extend(field);
+    }
+
+    let witness = wrap_sub_account_witness(DataType::SubAccount, payload);
+    json!({
+        "name": format!("{}_v{}", DataType::SubAccount, version),
+        "data_type": DataType::SubAccount as u32,
+        "version": version,
+        "schema": schema,
+        "witness": format!("0x{}", hex::encode(witness)),
+        "entity": format!("0x{}", hex::encode(entity.as_slice())),
+        "decoded": serde_json::to_value(&entity).unwrap(),
+        "latest": serde_json::to_value(&latest).unwrap(),
+    })
+}
+
+fn main() {
+    let account = account_cell_data();
+    let pre_account = pre_account_cell_data();
+    let v1: AccountCellDataV1 = omit(
+        &account,
+        &[
+            "last_transfer_account_at",
+            "last_edit_manager_at",
+            "last_edit_records_at",
+            "enable_sub_account",
+            "renew_sub_account_price",
+            "approval",
+        ],
+    );
+    let v2: AccountCellDataV2 = omit(
+        &account,
+        &[
+            "updated_at",
+            "enable_sub_account",
+            "renew_sub_account_price",
+            "approval",
+        ],
+    );
+    let v3: AccountCellDataV3 = omit(&account, &["updated_at", "approval"]);
+    let v4: AccountCellData = omit(&account, &["updated_at"]);
+    let pre_v1: PreAccountCellDataV1 = omit(&pre_account, &["initial_records", "initial_cross_chain"]);
+    let pre_v2: PreAccountCellDataV2 = omit(&pre_account, &["initial_cross_chain"]);
+    let pre_v3: PreAccountCellData = omit(&pre_account, &[]);
+    let sub_account_v1: SubAccountV1 = omit(&sub_account(), &["approval"]);
+    let sub_account_v2: SubAccount = omit(&sub_account(), &[]);
+    let sale_v1: AccountSaleCellDataV1 = omit(&account_sale_cell_data(), &["buyer_inviter_profit_rate"]);
+    let sale_v2: AccountSaleCellData = omit(&account_sale_cell_data(), &[]);
+
+    let vectors = vec![
+        vector(
+            DataType::AccountCellData,
+            1,
+            "AccountCellDataV1",
+            v1.clone(),
+            EntityWrapper::AccountCellDataV1(v1.clone()),
+            v1.upgrade_to_latest(),
+        ),
+        vector(
+            DataType::AccountCellData,
+            2,
+            "AccountCellDataV2",
+            v2.clone(),
+            EntityWrapper::AccountCellDataV2(v2.clone()),
+            v2.upgrade_to_latest(),
+        ),
+        vector(
+            DataType::AccountCellData,
+            3,
+            "AccountCellDataV3",
+            v3.clone(),
+            EntityWrapper::AccountCellDataV3(v3.clone()),
+            v3.upgrade_to_latest(),
+        ),
+        vector(
+            DataType::AccountCellData,
+            4,
+            "AccountCellData",
+            v4.clone(),
+            EntityWrapper::AccountCellData(v4.clone()),
+            v4,
+        ),
+        vector(
+            DataType::PreAccountCellData,
+            1,
+            "PreAccountCellDataV1",
+            pre_v1.clone(),
+            EntityWrapper::PreAccountCellDataV1(pre_v1.clone()),
+            pre_v1.upgrade_to_latest(),
+        ),
+        vector(
+            DataType::PreAccountCellData,
+            2,
+            "PreAccountCellDataV2",
+            pre_v2.clone(),
+            EntityWrapper::PreAccountCellDataV2(pre_v2.clone()),
+            pre_v2.upgrade_to_latest(),
+        ),
+        vector(
+            DataType::PreAccountCellData,
+            3,
+            "PreAccountCellData",
+            pre_v3.clone(),
+            EntityWrapper::PreAccountCellData(pre_v3.clone()),
+            pre_v3,
+        ),
+        sub_account_vector(
+            1,
+            "SubAccountV1",
+            sub_account_v1.clone(),
+            sub_account_v1.upgrade_to_latest(),
+        ),
+        sub_account_vector(2, "SubAccount", sub_account_v2.clone(), sub_account_v2),
+        vector(
+            DataType::AccountSaleCellData,
+            1,
+            "AccountSaleCellDataV1",
+            sale_v1.clone(),
+            EntityWrapper::AccountSaleCellDataV1(sale_v1.clone()),
+            sale_v1.upgrade_to_latest(),
+        ),
+        vector(
+            DataType::AccountSaleCellData,
+            2,
+            "AccountSaleCellData",
+            sale_v2.clone(),
+            EntityWrapper::AccountSaleCellData(sale_v2.clone()),
+            sale_v2,
+        ),
+    ];
+
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("vectors/cell_data.json");
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, serde_json::to_string_pretty(&vectors).unwrap() + "\n").unwrap();
+    println!("Write {} vectors to {}", vectors.len(), path.display());
+}
@@ -1691,6 +1691,205 @@ func (s *ScriptOpt) AsBuilder() ScriptOptBuilder {
 }
             
 
+type ScriptsBuilder struct {
+    inner []Script
+}
+    
+
+func (s *ScriptsBuilder) Build() Scripts {
+    itemCount := len(s.inner)
+
+    b := new(bytes.Buffer)
+
+    // Empty dyn vector, just return size's bytes
+    if itemCount == 0 {
+        b.Write(packNumber(Number(HeaderSizeUint)))
+        return Scripts{inner: b.Bytes()}
+    }
+
+    // Calculate first offset then loop for rest items offsets
+    totalSize := HeaderSizeUint * uint32(itemCount+1)
+    offsets := make([]uint32, 0, itemCount)
+    offsets = append(offsets, totalSize)
+    for i := 1; i < itemCount; i++ {
+        totalSize += uint32(len(s.inner[i-1].AsSlice()))
+        offsets = append(offsets, offsets[i-1]+uint32(len(s.inner[i-1].AsSlice())))
+    }
+    totalSize += uint32(len(s.inner[itemCount-1].AsSlice()))
+
+    b.Write(packNumber(Number(totalSize)))
+
+    for i := 0; i < itemCount; i++ {
+        b.Write(packNumber(Number(offsets[i])))
+    }
+
+    for i := 0; i < itemCount; i++ {
+        b.Write(s.inner[i].AsSlice())
+    }
+
+    return Scripts{inner: b.Bytes()}
+}
+          
+
+func (s *ScriptsBuilder) Set(v []Script) *ScriptsBuilder {
+    s.inner = v
+    return s
+}
+func (s *ScriptsBuilder) Push(v Script) *ScriptsBuilder {
+    s.inner = append(s.inner, v)
+    return s
+}
+func (s *ScriptsBuilder) Extend(iter []Script) *ScriptsBuilder {
+    for i:=0; i < len(iter); i++ {
+        s.inner = append(s.inner, iter[i])
+    }
+    return s
+}
+func (s *ScriptsBuilder) Replace(index uint, v Script) *Script {
+    if uint(len(s.inner)) > index {
+        a := s.inner[index]
+        s.inner[index] = v
+        return &a
+    }
+    return nil
+}
+    
+
+func NewScriptsBuilder() *ScriptsBuilder {
+	return &ScriptsBuilder{ []Script{} }
+}
+        
+
+type Scripts struct {
+    inner []byte
+}
+        
+
+func ScriptsFromSliceUnchecked(slice []byte) *Scripts {
+    return &Scripts{inner: slice}
+}
+func (s *Scripts) AsSlice() []byte {
+    return s.inner
+}
+            
+
+func ScriptsDefault() Scripts {
+    return *ScriptsFromSliceUnchecked([]byte{ 4,0,0,0 })
+}
+            
+
+func ScriptsFromSlice(slice []byte, compatible bool) (*Scripts, error) {
+    sliceLen := len(slice)
+
+    if uint32(sliceLen) < HeaderSizeUint {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "Scripts", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    totalSize := unpackNumber(slice)
+    if Number(sliceLen) != totalSize {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "Scripts", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if uint32(sliceLen) == HeaderSizeUint {
+        return &Scripts{inner: slice}, nil
+    }
+
+    if uint32(sliceLen) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "Scripts", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    offsetFirst := unpackNumber(slice[HeaderSizeUint:])
+    if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "Scripts", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if sliceLen < int(offsetFirst) {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "Scripts", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        return nil, errors.New(errMsg)
+    }
+    itemCount := uint32(offsetFirst)/HeaderSizeUint - 1
+
+    offsets := make([]uint32, itemCount)
+
+    for i := 0; i < int(itemCount); i++ {
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    }
+
+    offsets = append(offsets, uint32(totalSize))
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            errMsg := strings.Join([]string{"OffsetsNotMatch", "Scripts"}, " ")
+            return nil, errors.New(errMsg)
+        }
+    }
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 {
+            start := offsets[i-1]
+            end := offsets[i]
+            _, err := ScriptFromSlice(slice[start:end], compatible)
+
+            if err != nil {
+                return nil, err
+            }
+        }
+    }
+
+    return &Scripts{inner: slice}, nil
+}
+            
+
+func (s *Scripts) TotalSize() uint {
+    return uint(unpackNumber(s.inner))
+}
+func (s *Scripts) ItemCount() uint {
+    var number uint = 0
+    if uint32(s.TotalSize()) == HeaderSizeUint {
+        return number
+    }
+    number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
+    return number
+}
+func (s *Scripts) Len() uint {
+    return s.ItemCount()
+}
+func (s *Scripts) IsEmpty() bool {
+    return s.Len() == 0
+}
+// if *Script is nil, index is out of bounds
+func (s *Scripts) Get(index uint) *Script {
+    var b *Script
+    if index < s.Len() {
+        start_index := uint(HeaderSizeUint) * (1 + index)
+        start := unpackNumber(s.inner[start_index:])
+
+        if index == s.Len()-1 {
+            b = ScriptFromSliceUnchecked(s.inner[start:])
+        } else {
+            end_index := start_index + uint(HeaderSizeUint)
+            end := unpackNumber(s.inner[end_index:])
+            b = ScriptFromSliceUnchecked(s.inner[start:end])
+        }
+    }
+    return b
+}
+            
+
+func (s *Scripts) AsBuilder() ScriptsBuilder {
+    size := s.ItemCount()
+    t := NewScriptsBuilder()
+    for i:=uint(0); i < size; i++ {
+        t.Push(*s.Get(i))
+    }
+    return *t
+}
+        
+
 type OutPointBuilder struct {
     tx_hash Hash
 index Uint32
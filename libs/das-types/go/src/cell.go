@@ -273,7 +273,7 @@ func (s *ConfigCellMain) AsSlice() []byte {
             
 
 func ConfigCellMainDefault() ConfigCellMain {
-    return *ConfigCellMainFromSliceUnchecked([]byte{ 45,4,0,0,20,0,0,0,21,0,0,0,17,2,0,0,45,3,0,0,0,252,1,0,0,60,0,0,0,92,0,0,0,124,0,0,0,156,0,0,0,188,0,0,0,220,0,0,0,252,0,0,0,28,1,0,0,60,1,0,0,92,1,0,0,124,1,0,0,156,1,0,0,188,1,0,0,220,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,28,1,0,0,32,0,0,0,68,0,0,0,104,0,0,0,140,0,0,0,176,0,0,0,212,0,0,0,248,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,0,0,32,0,0,0,64,0,0,0,96,0,0,0,128,0,0,0,160,0,0,0,192,0,0,0,224,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+    return *ConfigCellMainFromSliceUnchecked([]byte{ 81,4,0,0,20,0,0,0,21,0,0,0,53,2,0,0,81,3,0,0,0,32,2,0,0,64,0,0,0,96,0,0,0,128,0,0,0,160,0,0,0,192,0,0,0,224,0,0,0,0,1,0,0,32,1,0,0,64,1,0,0,96,1,0,0,128,1,0,0,160,1,0,0,192,1,0,0,224,1,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,28,1,0,0,32,0,0,0,68,0,0,0,104,0,0,0,140,0,0,0,176,0,0,0,212,0,0,0,248,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,0,0,32,0,0,0,64,0,0,0,96,0,0,0,128,0,0,0,160,0,0,0,192,0,0,0,224,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
 }
             
 
@@ -437,14 +437,15 @@ sub_account_cell Hash
 eip712_lib Hash
 reverse_record_root_cell Hash
 key_list_config_cell Hash
+dpoint_cell Hash
 }
         
 
 func (s *TypeIdTableBuilder) Build() TypeIdTable {
     b := new(bytes.Buffer)
 
-    totalSize := HeaderSizeUint * (14 + 1)
-    offsets := make([]uint32, 0, 14)
+    totalSize := HeaderSizeUint * (15 + 1)
+    offsets := make([]uint32, 0, 15)
 
     offsets = append(offsets, totalSize)
 totalSize += uint32(len(s.account_cell.AsSlice()))
@@ -474,6 +475,8 @@ offsets = append(offsets, totalSize)
 totalSize += uint32(len(s.reverse_record_root_cell.AsSlice()))
 offsets = append(offsets, totalSize)
 totalSize += uint32(len(s.key_list_config_cell.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.dpoint_cell.AsSlice()))
 
     b.Write(packNumber(Number(totalSize)))
 
@@ -495,6 +498,7 @@ b.Write(s.sub_account_cell.AsSlice())
 b.Write(s.eip712_lib.AsSlice())
 b.Write(s.reverse_record_root_cell.AsSlice())
 b.Write(s.key_list_config_cell.AsSlice())
+b.Write(s.dpoint_cell.AsSlice())
     return TypeIdTable{inner: b.Bytes()}
 }
                 
@@ -583,8 +587,14 @@ func (s *TypeIdTableBuilder) KeyListConfigCell(v Hash) *TypeIdTableBuilder {
 }
             
 
+func (s *TypeIdTableBuilder) DpointCell(v Hash) *TypeIdTableBuilder {
+    s.dpoint_cell = v
+    return s
+}
+            
+
 func NewTypeIdTableBuilder() *TypeIdTableBuilder {
-	return &TypeIdTableBuilder{ account_cell: HashDefault(),apply_register_cell: HashDefault(),balance_cell: HashDefault(),income_cell: HashDefault(),pre_account_cell: HashDefault(),proposal_cell: HashDefault(),account_sale_cell: HashDefault(),account_auction_cell: HashDefault(),offer_cell: HashDefault(),reverse_record_cell: HashDefault(),sub_account_cell: HashDefault(),eip712_lib: HashDefault(),reverse_record_root_cell: HashDefault(),key_list_config_cell: HashDefault() }
+	return &TypeIdTableBuilder{ account_cell: HashDefault(),apply_register_cell: HashDefault(),balance_cell: HashDefault(),income_cell: HashDefault(),pre_account_cell: HashDefault(),proposal_cell: HashDefault(),account_sale_cell: HashDefault(),account_auction_cell: HashDefault(),offer_cell: HashDefault(),reverse_record_cell: HashDefault(),sub_account_cell: HashDefault(),eip712_lib: HashDefault(),reverse_record_root_cell: HashDefault(),key_list_config_cell: HashDefault(),dpoint_cell: HashDefault() }
 }
     
 
@@ -602,7 +612,7 @@ func (s *TypeIdTable) AsSlice() []byte {
             
 
 func TypeIdTableDefault() TypeIdTable {
-    return *TypeIdTableFromSliceUnchecked([]byte{ 252,1,0,0,60,0,0,0,92,0,0,0,124,0,0,0,156,0,0,0,188,0,0,0,220,0,0,0,252,0,0,0,28,1,0,0,60,1,0,0,92,1,0,0,124,1,0,0,156,1,0,0,188,1,0,0,220,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+    return *TypeIdTableFromSliceUnchecked([]byte{ 32,2,0,0,64,0,0,0,96,0,0,0,128,0,0,0,160,0,0,0,192,0,0,0,224,0,0,0,0,1,0,0,32,1,0,0,64,1,0,0,96,1,0,0,128,1,0,0,160,1,0,0,192,1,0,0,224,1,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
 }
             
 
@@ -636,9 +646,9 @@ func TypeIdTableFromSlice(slice []byte, compatible bool) (*TypeIdTable, error) {
     }
 
     fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
-    if fieldCount < 14 {
+    if fieldCount < 15 {
         return nil, errors.New("FieldCountNotMatch")
-    } else if !compatible && fieldCount > 14 {
+    } else if !compatible && fieldCount > 15 {
         return nil, errors.New("FieldCountNotMatch")
     }
 
@@ -741,6 +751,12 @@ if err != nil {
 }
                 
 
+_, err = HashFromSlice(slice[offsets[14]:offsets[15]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
     return &TypeIdTable{inner: slice}, nil
 }
             
@@ -763,11 +779,11 @@ func (s *TypeIdTable) IsEmpty() bool {
     return s.Len() == 0
 }
 func (s *TypeIdTable) CountExtraFields() uint {
-    return s.FieldCount() - 14
+    return s.FieldCount() - 15
 }
 
 func (s *TypeIdTable) HasExtraFields() bool {
-    return 14 != s.FieldCount()
+    return 15 != s.FieldCount()
 }
             
 
@@ -863,10 +879,17 @@ func (s *TypeIdTable) ReverseRecordRootCell() *Hash {
                
 
 func (s *TypeIdTable) KeyListConfigCell() *Hash {
-    var ret *Hash
     start := unpackNumber(s.inner[56:])
+    end := unpackNumber(s.inner[60:])
+    return HashFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TypeIdTable) DpointCell() *Hash {
+    var ret *Hash
+    start := unpackNumber(s.inner[60:])
     if s.HasExtraFields() {
-        end := unpackNumber(s.inner[60:])
+        end := unpackNumber(s.inner[64:])
         ret = HashFromSliceUnchecked(s.inner[start:end])
     } else {
         ret = HashFromSliceUnchecked(s.inner[start:])
@@ -876,7 +899,7 @@ func (s *TypeIdTable) KeyListConfigCell() *Hash {
                         
 
 func (s *TypeIdTable) AsBuilder() TypeIdTableBuilder {
-    ret := NewTypeIdTableBuilder().AccountCell(*s.AccountCell()).ApplyRegisterCell(*s.ApplyRegisterCell()).BalanceCell(*s.BalanceCell()).IncomeCell(*s.IncomeCell()).PreAccountCell(*s.PreAccountCell()).ProposalCell(*s.ProposalCell()).AccountSaleCell(*s.AccountSaleCell()).AccountAuctionCell(*s.AccountAuctionCell()).OfferCell(*s.OfferCell()).ReverseRecordCell(*s.ReverseRecordCell()).SubAccountCell(*s.SubAccountCell()).Eip712Lib(*s.Eip712Lib()).ReverseRecordRootCell(*s.ReverseRecordRootCell()).KeyListConfigCell(*s.KeyListConfigCell())
+    ret := NewTypeIdTableBuilder().AccountCell(*s.AccountCell()).ApplyRegisterCell(*s.ApplyRegisterCell()).BalanceCell(*s.BalanceCell()).IncomeCell(*s.IncomeCell()).PreAccountCell(*s.PreAccountCell()).ProposalCell(*s.ProposalCell()).AccountSaleCell(*s.AccountSaleCell()).AccountAuctionCell(*s.AccountAuctionCell()).OfferCell(*s.OfferCell()).ReverseRecordCell(*s.ReverseRecordCell()).SubAccountCell(*s.SubAccountCell()).Eip712Lib(*s.Eip712Lib()).ReverseRecordRootCell(*s.ReverseRecordRootCell()).KeyListConfigCell(*s.KeyListConfigCell()).DpointCell(*s.DpointCell())
     return *ret
 }
         
@@ -1492,14 +1515,17 @@ transfer_account_throttle Uint32
 edit_manager_throttle Uint32
 edit_records_throttle Uint32
 common_throttle Uint32
+expiration_auction_period Uint32
+expiration_deliver_period Uint32
+expiration_auction_start_premiums Uint32
 }
         
 
 func (s *ConfigCellAccountBuilder) Build() ConfigCellAccount {
     b := new(bytes.Buffer)
 
-    totalSize := HeaderSizeUint * (14 + 1)
-    offsets := make([]uint32, 0, 14)
+    totalSize := HeaderSizeUint * (17 + 1)
+    offsets := make([]uint32, 0, 17)
 
     offsets = append(offsets, totalSize)
 totalSize += uint32(len(s.max_length.AsSlice()))
@@ -1529,6 +1555,12 @@ offsets = append(offsets, totalSize)
 totalSize += uint32(len(s.edit_records_throttle.AsSlice()))
 offsets = append(offsets, totalSize)
 totalSize += uint32(len(s.common_throttle.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.expiration_auction_period.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.expiration_deliver_period.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.expiration_auction_start_premiums.AsSlice()))
 
     b.Write(packNumber(Number(totalSize)))
 
@@ -1550,6 +1582,9 @@ b.Write(s.transfer_account_throttle.AsSlice())
 b.Write(s.edit_manager_throttle.AsSlice())
 b.Write(s.edit_records_throttle.AsSlice())
 b.Write(s.common_throttle.AsSlice())
+b.Write(s.expiration_auction_period.AsSlice())
+b.Write(s.expiration_deliver_period.AsSlice())
+b.Write(s.expiration_auction_start_premiums.AsSlice())
     return ConfigCellAccount{inner: b.Bytes()}
 }
                 
@@ -1638,8 +1673,26 @@ func (s *ConfigCellAccountBuilder) CommonThrottle(v Uint32) *ConfigCellAccountBu
 }
             
 
+func (s *ConfigCellAccountBuilder) ExpirationAuctionPeriod(v Uint32) *ConfigCellAccountBuilder {
+    s.expiration_auction_period = v
+    return s
+}
+            
+
+func (s *ConfigCellAccountBuilder) ExpirationDeliverPeriod(v Uint32) *ConfigCellAccountBuilder {
+    s.expiration_deliver_period = v
+    return s
+}
+            
+
+func (s *ConfigCellAccountBuilder) ExpirationAuctionStartPremiums(v Uint32) *ConfigCellAccountBuilder {
+    s.expiration_auction_start_premiums = v
+    return s
+}
+            
+
 func NewConfigCellAccountBuilder() *ConfigCellAccountBuilder {
-	return &ConfigCellAccountBuilder{ max_length: Uint32Default(),basic_capacity: Uint64Default(),prepared_fee_capacity: Uint64Default(),expiration_grace_period: Uint32Default(),record_min_ttl: Uint32Default(),record_size_limit: Uint32Default(),transfer_account_fee: Uint64Default(),edit_manager_fee: Uint64Default(),edit_records_fee: Uint64Default(),common_fee: Uint64Default(),transfer_account_throttle: Uint32Default(),edit_manager_throttle: Uint32Default(),edit_records_throttle: Uint32Default(),common_throttle: Uint32Default() }
+	return &ConfigCellAccountBuilder{ max_length: Uint32Default(),basic_capacity: Uint64Default(),prepared_fee_capacity: Uint64Default(),expiration_grace_period: Uint32Default(),record_min_ttl: Uint32Default(),record_size_limit: Uint32Default(),transfer_account_fee: Uint64Default(),edit_manager_fee: Uint64Default(),edit_records_fee: Uint64Default(),common_fee: Uint64Default(),transfer_account_throttle: Uint32Default(),edit_manager_throttle: Uint32Default(),edit_records_throttle: Uint32Default(),common_throttle: Uint32Default(),expiration_auction_period: Uint32Default(),expiration_deliver_period: Uint32Default(),expiration_auction_start_premiums: Uint32Default() }
 }
     
 
@@ -1657,7 +1710,7 @@ func (s *ConfigCellAccount) AsSlice() []byte {
             
 
 func ConfigCellAccountDefault() ConfigCellAccount {
-    return *ConfigCellAccountFromSliceUnchecked([]byte{ 140,0,0,0,60,0,0,0,64,0,0,0,72,0,0,0,80,0,0,0,84,0,0,0,88,0,0,0,92,0,0,0,100,0,0,0,108,0,0,0,116,0,0,0,124,0,0,0,128,0,0,0,132,0,0,0,136,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+    return *ConfigCellAccountFromSliceUnchecked([]byte{ 164,0,0,0,72,0,0,0,76,0,0,0,84,0,0,0,92,0,0,0,96,0,0,0,100,0,0,0,104,0,0,0,112,0,0,0,120,0,0,0,128,0,0,0,136,0,0,0,140,0,0,0,144,0,0,0,148,0,0,0,152,0,0,0,156,0,0,0,160,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
 }
             
 
@@ -1691,9 +1744,9 @@ func ConfigCellAccountFromSlice(slice []byte, compatible bool) (*ConfigCellAccou
     }
 
     fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
-    if fieldCount < 14 {
+    if fieldCount < 17 {
         return nil, errors.New("FieldCountNotMatch")
-    } else if !compatible && fieldCount > 14 {
+    } else if !compatible && fieldCount > 17 {
         return nil, errors.New("FieldCountNotMatch")
     }
 
@@ -1796,6 +1849,24 @@ if err != nil {
 }
                 
 
+_, err = Uint32FromSlice(slice[offsets[14]:offsets[15]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint32FromSlice(slice[offsets[15]:offsets[16]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint32FromSlice(slice[offsets[16]:offsets[17]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
     return &ConfigCellAccount{inner: slice}, nil
 }
             
@@ -1818,11 +1889,11 @@ func (s *ConfigCellAccount) IsEmpty() bool {
     return s.Len() == 0
 }
 func (s *ConfigCellAccount) CountExtraFields() uint {
-    return s.FieldCount() - 14
+    return s.FieldCount() - 17
 }
 
 func (s *ConfigCellAccount) HasExtraFields() bool {
-    return 14 != s.FieldCount()
+    return 17 != s.FieldCount()
 }
             
 
@@ -1918,10 +1989,31 @@ func (s *ConfigCellAccount) EditRecordsThrottle() *Uint32 {
                
 
 func (s *ConfigCellAccount) CommonThrottle() *Uint32 {
-    var ret *Uint32
     start := unpackNumber(s.inner[56:])
+    end := unpackNumber(s.inner[60:])
+    return Uint32FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellAccount) ExpirationAuctionPeriod() *Uint32 {
+    start := unpackNumber(s.inner[60:])
+    end := unpackNumber(s.inner[64:])
+    return Uint32FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellAccount) ExpirationDeliverPeriod() *Uint32 {
+    start := unpackNumber(s.inner[64:])
+    end := unpackNumber(s.inner[68:])
+    return Uint32FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellAccount) ExpirationAuctionStartPremiums() *Uint32 {
+    var ret *Uint32
+    start := unpackNumber(s.inner[68:])
     if s.HasExtraFields() {
-        end := unpackNumber(s.inner[60:])
+        end := unpackNumber(s.inner[72:])
         ret = Uint32FromSliceUnchecked(s.inner[start:end])
     } else {
         ret = Uint32FromSliceUnchecked(s.inner[start:])
@@ -1931,7 +2023,7 @@ func (s *ConfigCellAccount) CommonThrottle() *Uint32 {
                         
 
 func (s *ConfigCellAccount) AsBuilder() ConfigCellAccountBuilder {
-    ret := NewConfigCellAccountBuilder().MaxLength(*s.MaxLength()).BasicCapacity(*s.BasicCapacity()).PreparedFeeCapacity(*s.PreparedFeeCapacity()).ExpirationGracePeriod(*s.ExpirationGracePeriod()).RecordMinTtl(*s.RecordMinTtl()).RecordSizeLimit(*s.RecordSizeLimit()).TransferAccountFee(*s.TransferAccountFee()).EditManagerFee(*s.EditManagerFee()).EditRecordsFee(*s.EditRecordsFee()).CommonFee(*s.CommonFee()).TransferAccountThrottle(*s.TransferAccountThrottle()).EditManagerThrottle(*s.EditManagerThrottle()).EditRecordsThrottle(*s.EditRecordsThrottle()).CommonThrottle(*s.CommonThrottle())
+    ret := NewConfigCellAccountBuilder().MaxLength(*s.MaxLength()).BasicCapacity(*s.BasicCapacity()).PreparedFeeCapacity(*s.PreparedFeeCapacity()).ExpirationGracePeriod(*s.ExpirationGracePeriod()).RecordMinTtl(*s.RecordMinTtl()).RecordSizeLimit(*s.RecordSizeLimit()).TransferAccountFee(*s.TransferAccountFee()).EditManagerFee(*s.EditManagerFee()).EditRecordsFee(*s.EditRecordsFee()).CommonFee(*s.CommonFee()).TransferAccountThrottle(*s.TransferAccountThrottle()).EditManagerThrottle(*s.EditManagerThrottle()).EditRecordsThrottle(*s.EditRecordsThrottle()).CommonThrottle(*s.CommonThrottle()).ExpirationAuctionPeriod(*s.ExpirationAuctionPeriod()).ExpirationDeliverPeriod(*s.ExpirationDeliverPeriod()).ExpirationAuctionStartPremiums(*s.ExpirationAuctionStartPremiums())
     return *ret
 }
         
@@ -5235,14 +5327,16 @@ income_cell_type ContractStatus
 reverse_record_cell_type ContractStatus
 reverse_record_root_cell_type ContractStatus
 eip712_lib ContractStatus
+key_list_config_cell_type ContractStatus
+dpoint_cell_type ContractStatus
 }
         
 
 func (s *ConfigCellSystemStatusBuilder) Build() ConfigCellSystemStatus {
     b := new(bytes.Buffer)
 
-    totalSize := HeaderSizeUint * (13 + 1)
-    offsets := make([]uint32, 0, 13)
+    totalSize := HeaderSizeUint * (15 + 1)
+    offsets := make([]uint32, 0, 15)
 
     offsets = append(offsets, totalSize)
 totalSize += uint32(len(s.apply_register_cell_type.AsSlice()))
@@ -5270,6 +5364,10 @@ offsets = append(offsets, totalSize)
 totalSize += uint32(len(s.reverse_record_root_cell_type.AsSlice()))
 offsets = append(offsets, totalSize)
 totalSize += uint32(len(s.eip712_lib.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.key_list_config_cell_type.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.dpoint_cell_type.AsSlice()))
 
     b.Write(packNumber(Number(totalSize)))
 
@@ -5290,6 +5388,8 @@ b.Write(s.income_cell_type.AsSlice())
 b.Write(s.reverse_record_cell_type.AsSlice())
 b.Write(s.reverse_record_root_cell_type.AsSlice())
 b.Write(s.eip712_lib.AsSlice())
+b.Write(s.key_list_config_cell_type.AsSlice())
+b.Write(s.dpoint_cell_type.AsSlice())
     return ConfigCellSystemStatus{inner: b.Bytes()}
 }
                 
@@ -5372,8 +5472,20 @@ func (s *ConfigCellSystemStatusBuilder) Eip712Lib(v ContractStatus) *ConfigCellS
 }
             
 
+func (s *ConfigCellSystemStatusBuilder) KeyListConfigCellType(v ContractStatus) *ConfigCellSystemStatusBuilder {
+    s.key_list_config_cell_type = v
+    return s
+}
+            
+
+func (s *ConfigCellSystemStatusBuilder) DpointCellType(v ContractStatus) *ConfigCellSystemStatusBuilder {
+    s.dpoint_cell_type = v
+    return s
+}
+            
+
 func NewConfigCellSystemStatusBuilder() *ConfigCellSystemStatusBuilder {
-	return &ConfigCellSystemStatusBuilder{ apply_register_cell_type: ContractStatusDefault(),pre_account_cell_type: ContractStatusDefault(),proposal_cell_type: ContractStatusDefault(),config_cell_type: ContractStatusDefault(),account_cell_type: ContractStatusDefault(),account_sale_cell_type: ContractStatusDefault(),sub_account_cell_type: ContractStatusDefault(),offer_cell_type: ContractStatusDefault(),balance_cell_type: ContractStatusDefault(),income_cell_type: ContractStatusDefault(),reverse_record_cell_type: ContractStatusDefault(),reverse_record_root_cell_type: ContractStatusDefault(),eip712_lib: ContractStatusDefault() }
+	return &ConfigCellSystemStatusBuilder{ apply_register_cell_type: ContractStatusDefault(),pre_account_cell_type: ContractStatusDefault(),proposal_cell_type: ContractStatusDefault(),config_cell_type: ContractStatusDefault(),account_cell_type: ContractStatusDefault(),account_sale_cell_type: ContractStatusDefault(),sub_account_cell_type: ContractStatusDefault(),offer_cell_type: ContractStatusDefault(),balance_cell_type: ContractStatusDefault(),income_cell_type: ContractStatusDefault(),reverse_record_cell_type: ContractStatusDefault(),reverse_record_root_cell_type: ContractStatusDefault(),eip712_lib: ContractStatusDefault(),key_list_config_cell_type: ContractStatusDefault(),dpoint_cell_type: ContractStatusDefault() }
 }
     
 
@@ -5391,7 +5503,7 @@ func (s *ConfigCellSystemStatus) AsSlice() []byte {
             
 
 func ConfigCellSystemStatusDefault() ConfigCellSystemStatus {
-    return *ConfigCellSystemStatusFromSliceUnchecked([]byte{ 21,1,0,0,56,0,0,0,73,0,0,0,90,0,0,0,107,0,0,0,124,0,0,0,141,0,0,0,158,0,0,0,175,0,0,0,192,0,0,0,209,0,0,0,226,0,0,0,243,0,0,0,4,1,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0 })
+    return *ConfigCellSystemStatusFromSliceUnchecked([]byte{ 63,1,0,0,64,0,0,0,81,0,0,0,98,0,0,0,115,0,0,0,132,0,0,0,149,0,0,0,166,0,0,0,183,0,0,0,200,0,0,0,217,0,0,0,234,0,0,0,251,0,0,0,12,1,0,0,29,1,0,0,46,1,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0 })
 }
             
 
@@ -5425,9 +5537,9 @@ func ConfigCellSystemStatusFromSlice(slice []byte, compatible bool) (*ConfigCell
     }
 
     fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
-    if fieldCount < 13 {
+    if fieldCount < 15 {
         return nil, errors.New("FieldCountNotMatch")
-    } else if !compatible && fieldCount > 13 {
+    } else if !compatible && fieldCount > 15 {
         return nil, errors.New("FieldCountNotMatch")
     }
 
@@ -5524,6 +5636,18 @@ if err != nil {
 }
                 
 
+_, err = ContractStatusFromSlice(slice[offsets[13]:offsets[14]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = ContractStatusFromSlice(slice[offsets[14]:offsets[15]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
     return &ConfigCellSystemStatus{inner: slice}, nil
 }
             
@@ -5546,11 +5670,11 @@ func (s *ConfigCellSystemStatus) IsEmpty() bool {
     return s.Len() == 0
 }
 func (s *ConfigCellSystemStatus) CountExtraFields() uint {
-    return s.FieldCount() - 13
+    return s.FieldCount() - 15
 }
 
 func (s *ConfigCellSystemStatus) HasExtraFields() bool {
-    return 13 != s.FieldCount()
+    return 15 != s.FieldCount()
 }
             
 
@@ -5639,10 +5763,24 @@ func (s *ConfigCellSystemStatus) ReverseRecordRootCellType() *ContractStatus {
                
 
 func (s *ConfigCellSystemStatus) Eip712Lib() *ContractStatus {
-    var ret *ContractStatus
     start := unpackNumber(s.inner[52:])
+    end := unpackNumber(s.inner[56:])
+    return ContractStatusFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellSystemStatus) KeyListConfigCellType() *ContractStatus {
+    start := unpackNumber(s.inner[56:])
+    end := unpackNumber(s.inner[60:])
+    return ContractStatusFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellSystemStatus) DpointCellType() *ContractStatus {
+    var ret *ContractStatus
+    start := unpackNumber(s.inner[60:])
     if s.HasExtraFields() {
-        end := unpackNumber(s.inner[56:])
+        end := unpackNumber(s.inner[64:])
         ret = ContractStatusFromSliceUnchecked(s.inner[start:end])
     } else {
         ret = ContractStatusFromSliceUnchecked(s.inner[start:])
@@ -5652,7 +5790,7 @@ func (s *ConfigCellSystemStatus) Eip712Lib() *ContractStatus {
                         
 
 func (s *ConfigCellSystemStatus) AsBuilder() ConfigCellSystemStatusBuilder {
-    ret := NewConfigCellSystemStatusBuilder().ApplyRegisterCellType(*s.ApplyRegisterCellType()).PreAccountCellType(*s.PreAccountCellType()).ProposalCellType(*s.ProposalCellType()).ConfigCellType(*s.ConfigCellType()).AccountCellType(*s.AccountCellType()).AccountSaleCellType(*s.AccountSaleCellType()).SubAccountCellType(*s.SubAccountCellType()).OfferCellType(*s.OfferCellType()).BalanceCellType(*s.BalanceCellType()).IncomeCellType(*s.IncomeCellType()).ReverseRecordCellType(*s.ReverseRecordCellType()).ReverseRecordRootCellType(*s.ReverseRecordRootCellType()).Eip712Lib(*s.Eip712Lib())
+    ret := NewConfigCellSystemStatusBuilder().ApplyRegisterCellType(*s.ApplyRegisterCellType()).PreAccountCellType(*s.PreAccountCellType()).ProposalCellType(*s.ProposalCellType()).ConfigCellType(*s.ConfigCellType()).AccountCellType(*s.AccountCellType()).AccountSaleCellType(*s.AccountSaleCellType()).SubAccountCellType(*s.SubAccountCellType()).OfferCellType(*s.OfferCellType()).BalanceCellType(*s.BalanceCellType()).IncomeCellType(*s.IncomeCellType()).ReverseRecordCellType(*s.ReverseRecordCellType()).ReverseRecordRootCellType(*s.ReverseRecordRootCellType()).Eip712Lib(*s.Eip712Lib()).KeyListConfigCellType(*s.KeyListConfigCellType()).DpointCellType(*s.DpointCellType())
     return *ret
 }
         
@@ -5840,25 +5978,28 @@ func (s *ContractStatus) AsBuilder() ContractStatusBuilder {
 }
         
 
-type ProposalCellDataBuilder struct {
-    proposer_lock Script
-created_at_height Uint64
-slices SliceList
+type ConfigCellDPointBuilder struct {
+    basic_capacity Uint64
+prepared_fee_capacity Uint64
+transfer_whitelist Scripts
+capacity_recycle_whitelist Scripts
 }
         
 
-func (s *ProposalCellDataBuilder) Build() ProposalCellData {
+func (s *ConfigCellDPointBuilder) Build() ConfigCellDPoint {
     b := new(bytes.Buffer)
 
-    totalSize := HeaderSizeUint * (3 + 1)
-    offsets := make([]uint32, 0, 3)
+    totalSize := HeaderSizeUint * (4 + 1)
+    offsets := make([]uint32, 0, 4)
 
     offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.proposer_lock.AsSlice()))
+totalSize += uint32(len(s.basic_capacity.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.created_at_height.AsSlice()))
+totalSize += uint32(len(s.prepared_fee_capacity.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.slices.AsSlice()))
+totalSize += uint32(len(s.transfer_whitelist.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.capacity_recycle_whitelist.AsSlice()))
 
     b.Write(packNumber(Number(totalSize)))
 
@@ -5866,87 +6007,94 @@ totalSize += uint32(len(s.slices.AsSlice()))
         b.Write(packNumber(Number(offsets[i])))
     }
 
-    b.Write(s.proposer_lock.AsSlice())
-b.Write(s.created_at_height.AsSlice())
-b.Write(s.slices.AsSlice())
-    return ProposalCellData{inner: b.Bytes()}
+    b.Write(s.basic_capacity.AsSlice())
+b.Write(s.prepared_fee_capacity.AsSlice())
+b.Write(s.transfer_whitelist.AsSlice())
+b.Write(s.capacity_recycle_whitelist.AsSlice())
+    return ConfigCellDPoint{inner: b.Bytes()}
 }
                 
 
-func (s *ProposalCellDataBuilder) ProposerLock(v Script) *ProposalCellDataBuilder {
-    s.proposer_lock = v
+func (s *ConfigCellDPointBuilder) BasicCapacity(v Uint64) *ConfigCellDPointBuilder {
+    s.basic_capacity = v
     return s
 }
             
 
-func (s *ProposalCellDataBuilder) CreatedAtHeight(v Uint64) *ProposalCellDataBuilder {
-    s.created_at_height = v
+func (s *ConfigCellDPointBuilder) PreparedFeeCapacity(v Uint64) *ConfigCellDPointBuilder {
+    s.prepared_fee_capacity = v
     return s
 }
             
 
-func (s *ProposalCellDataBuilder) Slices(v SliceList) *ProposalCellDataBuilder {
-    s.slices = v
+func (s *ConfigCellDPointBuilder) TransferWhitelist(v Scripts) *ConfigCellDPointBuilder {
+    s.transfer_whitelist = v
     return s
 }
             
 
-func NewProposalCellDataBuilder() *ProposalCellDataBuilder {
-	return &ProposalCellDataBuilder{ proposer_lock: ScriptDefault(),created_at_height: Uint64Default(),slices: SliceListDefault() }
+func (s *ConfigCellDPointBuilder) CapacityRecycleWhitelist(v Scripts) *ConfigCellDPointBuilder {
+    s.capacity_recycle_whitelist = v
+    return s
+}
+            
+
+func NewConfigCellDPointBuilder() *ConfigCellDPointBuilder {
+	return &ConfigCellDPointBuilder{ basic_capacity: Uint64Default(),prepared_fee_capacity: Uint64Default(),transfer_whitelist: ScriptsDefault(),capacity_recycle_whitelist: ScriptsDefault() }
 }
     
 
-type ProposalCellData struct {
+type ConfigCellDPoint struct {
     inner []byte
 }
         
 
-func ProposalCellDataFromSliceUnchecked(slice []byte) *ProposalCellData {
-    return &ProposalCellData{inner: slice}
+func ConfigCellDPointFromSliceUnchecked(slice []byte) *ConfigCellDPoint {
+    return &ConfigCellDPoint{inner: slice}
 }
-func (s *ProposalCellData) AsSlice() []byte {
+func (s *ConfigCellDPoint) AsSlice() []byte {
     return s.inner
 }
             
 
-func ProposalCellDataDefault() ProposalCellData {
-    return *ProposalCellDataFromSliceUnchecked([]byte{ 81,0,0,0,16,0,0,0,69,0,0,0,77,0,0,0,53,0,0,0,16,0,0,0,48,0,0,0,49,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0 })
+func ConfigCellDPointDefault() ConfigCellDPoint {
+    return *ConfigCellDPointFromSliceUnchecked([]byte{ 44,0,0,0,20,0,0,0,28,0,0,0,36,0,0,0,40,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0,4,0,0,0 })
 }
             
 
-func ProposalCellDataFromSlice(slice []byte, compatible bool) (*ProposalCellData, error) {
+func ConfigCellDPointFromSlice(slice []byte, compatible bool) (*ConfigCellDPoint, error) {
     sliceLen := len(slice)
     if uint32(sliceLen) < HeaderSizeUint {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "ProposalCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellDPoint", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
         return nil, errors.New(errMsg)
     }
 
     totalSize := unpackNumber(slice)
     if Number(sliceLen) != totalSize {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ProposalCellData", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellDPoint", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if uint32(sliceLen) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ProposalCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellDPoint", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     offsetFirst := unpackNumber(slice[HeaderSizeUint:])
     if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"OffsetsNotMatch", "ProposalCellData", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "ConfigCellDPoint", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if sliceLen < int(offsetFirst) {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "ProposalCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellDPoint", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
         return nil, errors.New(errMsg)
     }
 
     fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
-    if fieldCount < 3 {
+    if fieldCount < 4 {
         return nil, errors.New("FieldCountNotMatch")
-    } else if !compatible && fieldCount > 3 {
+    } else if !compatible && fieldCount > 4 {
         return nil, errors.New("FieldCountNotMatch")
     }
 
@@ -5965,7 +6113,7 @@ func ProposalCellDataFromSlice(slice []byte, compatible bool) (*ProposalCellData
 
     var err error
     
-_, err = ScriptFromSlice(slice[offsets[0]:offsets[1]], compatible)
+_, err = Uint64FromSlice(slice[offsets[0]:offsets[1]], compatible)
 if err != nil {
     return nil, err
 }
@@ -5977,20 +6125,26 @@ if err != nil {
 }
                 
 
-_, err = SliceListFromSlice(slice[offsets[2]:offsets[3]], compatible)
+_, err = ScriptsFromSlice(slice[offsets[2]:offsets[3]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-    return &ProposalCellData{inner: slice}, nil
+_, err = ScriptsFromSlice(slice[offsets[3]:offsets[4]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+    return &ConfigCellDPoint{inner: slice}, nil
 }
             
 
-func (s *ProposalCellData) TotalSize() uint {
+func (s *ConfigCellDPoint) TotalSize() uint {
     return uint(unpackNumber(s.inner))
 }
-func (s *ProposalCellData) FieldCount() uint {
+func (s *ConfigCellDPoint) FieldCount() uint {
     var number uint = 0
     if uint32(s.TotalSize()) == HeaderSizeUint {
         return number
@@ -5998,104 +6152,317 @@ func (s *ProposalCellData) FieldCount() uint {
     number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
     return number
 }
-func (s *ProposalCellData) Len() uint {
+func (s *ConfigCellDPoint) Len() uint {
     return s.FieldCount()
 }
-func (s *ProposalCellData) IsEmpty() bool {
+func (s *ConfigCellDPoint) IsEmpty() bool {
     return s.Len() == 0
 }
-func (s *ProposalCellData) CountExtraFields() uint {
-    return s.FieldCount() - 3
+func (s *ConfigCellDPoint) CountExtraFields() uint {
+    return s.FieldCount() - 4
 }
 
-func (s *ProposalCellData) HasExtraFields() bool {
-    return 3 != s.FieldCount()
+func (s *ConfigCellDPoint) HasExtraFields() bool {
+    return 4 != s.FieldCount()
 }
             
 
-func (s *ProposalCellData) ProposerLock() *Script {
+func (s *ConfigCellDPoint) BasicCapacity() *Uint64 {
     start := unpackNumber(s.inner[4:])
     end := unpackNumber(s.inner[8:])
-    return ScriptFromSliceUnchecked(s.inner[start:end])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ProposalCellData) CreatedAtHeight() *Uint64 {
+func (s *ConfigCellDPoint) PreparedFeeCapacity() *Uint64 {
     start := unpackNumber(s.inner[8:])
     end := unpackNumber(s.inner[12:])
     return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ProposalCellData) Slices() *SliceList {
-    var ret *SliceList
+func (s *ConfigCellDPoint) TransferWhitelist() *Scripts {
     start := unpackNumber(s.inner[12:])
+    end := unpackNumber(s.inner[16:])
+    return ScriptsFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellDPoint) CapacityRecycleWhitelist() *Scripts {
+    var ret *Scripts
+    start := unpackNumber(s.inner[16:])
     if s.HasExtraFields() {
-        end := unpackNumber(s.inner[16:])
-        ret = SliceListFromSliceUnchecked(s.inner[start:end])
+        end := unpackNumber(s.inner[20:])
+        ret = ScriptsFromSliceUnchecked(s.inner[start:end])
     } else {
-        ret = SliceListFromSliceUnchecked(s.inner[start:])
+        ret = ScriptsFromSliceUnchecked(s.inner[start:])
     }
     return ret
 }
                         
 
-func (s *ProposalCellData) AsBuilder() ProposalCellDataBuilder {
-    ret := NewProposalCellDataBuilder().ProposerLock(*s.ProposerLock()).CreatedAtHeight(*s.CreatedAtHeight()).Slices(*s.Slices())
+func (s *ConfigCellDPoint) AsBuilder() ConfigCellDPointBuilder {
+    ret := NewConfigCellDPointBuilder().BasicCapacity(*s.BasicCapacity()).PreparedFeeCapacity(*s.PreparedFeeCapacity()).TransferWhitelist(*s.TransferWhitelist()).CapacityRecycleWhitelist(*s.CapacityRecycleWhitelist())
     return *ret
 }
         
 
-type SliceListBuilder struct {
-    inner []SL
+type ProposalCellDataBuilder struct {
+    proposer_lock Script
+created_at_height Uint64
+slices SliceList
 }
-    
-
-func (s *SliceListBuilder) Build() SliceList {
-    itemCount := len(s.inner)
+        
 
+func (s *ProposalCellDataBuilder) Build() ProposalCellData {
     b := new(bytes.Buffer)
 
-    // Empty dyn vector, just return size's bytes
-    if itemCount == 0 {
-        b.Write(packNumber(Number(HeaderSizeUint)))
-        return SliceList{inner: b.Bytes()}
-    }
+    totalSize := HeaderSizeUint * (3 + 1)
+    offsets := make([]uint32, 0, 3)
 
-    // Calculate first offset then loop for rest items offsets
-    totalSize := HeaderSizeUint * uint32(itemCount+1)
-    offsets := make([]uint32, 0, itemCount)
     offsets = append(offsets, totalSize)
-    for i := 1; i < itemCount; i++ {
-        totalSize += uint32(len(s.inner[i-1].AsSlice()))
-        offsets = append(offsets, offsets[i-1]+uint32(len(s.inner[i-1].AsSlice())))
-    }
-    totalSize += uint32(len(s.inner[itemCount-1].AsSlice()))
+totalSize += uint32(len(s.proposer_lock.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.created_at_height.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.slices.AsSlice()))
 
     b.Write(packNumber(Number(totalSize)))
 
-    for i := 0; i < itemCount; i++ {
+    for i := 0; i < len(offsets); i++ {
         b.Write(packNumber(Number(offsets[i])))
     }
 
-    for i := 0; i < itemCount; i++ {
-        b.Write(s.inner[i].AsSlice())
-    }
-
-    return SliceList{inner: b.Bytes()}
+    b.Write(s.proposer_lock.AsSlice())
+b.Write(s.created_at_height.AsSlice())
+b.Write(s.slices.AsSlice())
+    return ProposalCellData{inner: b.Bytes()}
 }
-          
+                
 
-func (s *SliceListBuilder) Set(v []SL) *SliceListBuilder {
-    s.inner = v
+func (s *ProposalCellDataBuilder) ProposerLock(v Script) *ProposalCellDataBuilder {
+    s.proposer_lock = v
     return s
 }
-func (s *SliceListBuilder) Push(v SL) *SliceListBuilder {
-    s.inner = append(s.inner, v)
+            
+
+func (s *ProposalCellDataBuilder) CreatedAtHeight(v Uint64) *ProposalCellDataBuilder {
+    s.created_at_height = v
     return s
 }
-func (s *SliceListBuilder) Extend(iter []SL) *SliceListBuilder {
-    for i:=0; i < len(iter); i++ {
+            
+
+func (s *ProposalCellDataBuilder) Slices(v SliceList) *ProposalCellDataBuilder {
+    s.slices = v
+    return s
+}
+            
+
+func NewProposalCellDataBuilder() *ProposalCellDataBuilder {
+	return &ProposalCellDataBuilder{ proposer_lock: ScriptDefault(),created_at_height: Uint64Default(),slices: SliceListDefault() }
+}
+    
+
+type ProposalCellData struct {
+    inner []byte
+}
+        
+
+func ProposalCellDataFromSliceUnchecked(slice []byte) *ProposalCellData {
+    return &ProposalCellData{inner: slice}
+}
+func (s *ProposalCellData) AsSlice() []byte {
+    return s.inner
+}
+            
+
+func ProposalCellDataDefault() ProposalCellData {
+    return *ProposalCellDataFromSliceUnchecked([]byte{ 81,0,0,0,16,0,0,0,69,0,0,0,77,0,0,0,53,0,0,0,16,0,0,0,48,0,0,0,49,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0 })
+}
+            
+
+func ProposalCellDataFromSlice(slice []byte, compatible bool) (*ProposalCellData, error) {
+    sliceLen := len(slice)
+    if uint32(sliceLen) < HeaderSizeUint {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ProposalCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    totalSize := unpackNumber(slice)
+    if Number(sliceLen) != totalSize {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ProposalCellData", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if uint32(sliceLen) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ProposalCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    offsetFirst := unpackNumber(slice[HeaderSizeUint:])
+    if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "ProposalCellData", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if sliceLen < int(offsetFirst) {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ProposalCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
+    if fieldCount < 3 {
+        return nil, errors.New("FieldCountNotMatch")
+    } else if !compatible && fieldCount > 3 {
+        return nil, errors.New("FieldCountNotMatch")
+    }
+
+    offsets := make([]uint32, fieldCount)
+
+    for i := 0; i < int(fieldCount); i++ {
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    }
+    offsets = append(offsets, uint32(totalSize))
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            return nil, errors.New("OffsetsNotMatch")
+        }
+    }
+
+    var err error
+    
+_, err = ScriptFromSlice(slice[offsets[0]:offsets[1]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint64FromSlice(slice[offsets[1]:offsets[2]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = SliceListFromSlice(slice[offsets[2]:offsets[3]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+    return &ProposalCellData{inner: slice}, nil
+}
+            
+
+func (s *ProposalCellData) TotalSize() uint {
+    return uint(unpackNumber(s.inner))
+}
+func (s *ProposalCellData) FieldCount() uint {
+    var number uint = 0
+    if uint32(s.TotalSize()) == HeaderSizeUint {
+        return number
+    }
+    number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
+    return number
+}
+func (s *ProposalCellData) Len() uint {
+    return s.FieldCount()
+}
+func (s *ProposalCellData) IsEmpty() bool {
+    return s.Len() == 0
+}
+func (s *ProposalCellData) CountExtraFields() uint {
+    return s.FieldCount() - 3
+}
+
+func (s *ProposalCellData) HasExtraFields() bool {
+    return 3 != s.FieldCount()
+}
+            
+
+func (s *ProposalCellData) ProposerLock() *Script {
+    start := unpackNumber(s.inner[4:])
+    end := unpackNumber(s.inner[8:])
+    return ScriptFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ProposalCellData) CreatedAtHeight() *Uint64 {
+    start := unpackNumber(s.inner[8:])
+    end := unpackNumber(s.inner[12:])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ProposalCellData) Slices() *SliceList {
+    var ret *SliceList
+    start := unpackNumber(s.inner[12:])
+    if s.HasExtraFields() {
+        end := unpackNumber(s.inner[16:])
+        ret = SliceListFromSliceUnchecked(s.inner[start:end])
+    } else {
+        ret = SliceListFromSliceUnchecked(s.inner[start:])
+    }
+    return ret
+}
+                        
+
+func (s *ProposalCellData) AsBuilder() ProposalCellDataBuilder {
+    ret := NewProposalCellDataBuilder().ProposerLock(*s.ProposerLock()).CreatedAtHeight(*s.CreatedAtHeight()).Slices(*s.Slices())
+    return *ret
+}
+        
+
+type SliceListBuilder struct {
+    inner []SL
+}
+    
+
+func (s *SliceListBuilder) Build() SliceList {
+    itemCount := len(s.inner)
+
+    b := new(bytes.Buffer)
+
+    // Empty dyn vector, just return size's bytes
+    if itemCount == 0 {
+        b.Write(packNumber(Number(HeaderSizeUint)))
+        return SliceList{inner: b.Bytes()}
+    }
+
+    // Calculate first offset then loop for rest items offsets
+    totalSize := HeaderSizeUint * uint32(itemCount+1)
+    offsets := make([]uint32, 0, itemCount)
+    offsets = append(offsets, totalSize)
+    for i := 1; i < itemCount; i++ {
+        totalSize += uint32(len(s.inner[i-1].AsSlice()))
+        offsets = append(offsets, offsets[i-1]+uint32(len(s.inner[i-1].AsSlice())))
+    }
+    totalSize += uint32(len(s.inner[itemCount-1].AsSlice()))
+
+    b.Write(packNumber(Number(totalSize)))
+
+    for i := 0; i < itemCount; i++ {
+        b.Write(packNumber(Number(offsets[i])))
+    }
+
+    for i := 0; i < itemCount; i++ {
+        b.Write(s.inner[i].AsSlice())
+    }
+
+    return SliceList{inner: b.Bytes()}
+}
+          
+
+func (s *SliceListBuilder) Set(v []SL) *SliceListBuilder {
+    s.inner = v
+    return s
+}
+func (s *SliceListBuilder) Push(v SL) *SliceListBuilder {
+    s.inner = append(s.inner, v)
+    return s
+}
+func (s *SliceListBuilder) Extend(iter []SL) *SliceListBuilder {
+    for i:=0; i < len(iter); i++ {
         s.inner = append(s.inner, iter[i])
     }
     return s
@@ -7215,7 +7582,7 @@ func (s *IncomeRecord) AsBuilder() IncomeRecordBuilder {
 }
         
 
-type AccountCellDataV2Builder struct {
+type AccountCellDataBuilder struct {
     id AccountId
 account AccountChars
 registered_at Uint64
@@ -7224,14 +7591,17 @@ last_edit_manager_at Uint64
 last_edit_records_at Uint64
 status Uint8
 records Records
+enable_sub_account Uint8
+renew_sub_account_price Uint64
+approval AccountApproval
 }
         
 
-func (s *AccountCellDataV2Builder) Build() AccountCellDataV2 {
+func (s *AccountCellDataBuilder) Build() AccountCellData {
     b := new(bytes.Buffer)
 
-    totalSize := HeaderSizeUint * (8 + 1)
-    offsets := make([]uint32, 0, 8)
+    totalSize := HeaderSizeUint * (11 + 1)
+    offsets := make([]uint32, 0, 11)
 
     offsets = append(offsets, totalSize)
 totalSize += uint32(len(s.id.AsSlice()))
@@ -7249,6 +7619,12 @@ offsets = append(offsets, totalSize)
 totalSize += uint32(len(s.status.AsSlice()))
 offsets = append(offsets, totalSize)
 totalSize += uint32(len(s.records.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.enable_sub_account.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.renew_sub_account_price.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.approval.AsSlice()))
 
     b.Write(packNumber(Number(totalSize)))
 
@@ -7264,114 +7640,135 @@ b.Write(s.last_edit_manager_at.AsSlice())
 b.Write(s.last_edit_records_at.AsSlice())
 b.Write(s.status.AsSlice())
 b.Write(s.records.AsSlice())
-    return AccountCellDataV2{inner: b.Bytes()}
+b.Write(s.enable_sub_account.AsSlice())
+b.Write(s.renew_sub_account_price.AsSlice())
+b.Write(s.approval.AsSlice())
+    return AccountCellData{inner: b.Bytes()}
 }
                 
 
-func (s *AccountCellDataV2Builder) Id(v AccountId) *AccountCellDataV2Builder {
+func (s *AccountCellDataBuilder) Id(v AccountId) *AccountCellDataBuilder {
     s.id = v
     return s
 }
             
 
-func (s *AccountCellDataV2Builder) Account(v AccountChars) *AccountCellDataV2Builder {
+func (s *AccountCellDataBuilder) Account(v AccountChars) *AccountCellDataBuilder {
     s.account = v
     return s
 }
             
 
-func (s *AccountCellDataV2Builder) RegisteredAt(v Uint64) *AccountCellDataV2Builder {
+func (s *AccountCellDataBuilder) RegisteredAt(v Uint64) *AccountCellDataBuilder {
     s.registered_at = v
     return s
 }
             
 
-func (s *AccountCellDataV2Builder) LastTransferAccountAt(v Uint64) *AccountCellDataV2Builder {
+func (s *AccountCellDataBuilder) LastTransferAccountAt(v Uint64) *AccountCellDataBuilder {
     s.last_transfer_account_at = v
     return s
 }
             
 
-func (s *AccountCellDataV2Builder) LastEditManagerAt(v Uint64) *AccountCellDataV2Builder {
+func (s *AccountCellDataBuilder) LastEditManagerAt(v Uint64) *AccountCellDataBuilder {
     s.last_edit_manager_at = v
     return s
 }
             
 
-func (s *AccountCellDataV2Builder) LastEditRecordsAt(v Uint64) *AccountCellDataV2Builder {
+func (s *AccountCellDataBuilder) LastEditRecordsAt(v Uint64) *AccountCellDataBuilder {
     s.last_edit_records_at = v
     return s
 }
             
 
-func (s *AccountCellDataV2Builder) Status(v Uint8) *AccountCellDataV2Builder {
+func (s *AccountCellDataBuilder) Status(v Uint8) *AccountCellDataBuilder {
     s.status = v
     return s
 }
             
 
-func (s *AccountCellDataV2Builder) Records(v Records) *AccountCellDataV2Builder {
+func (s *AccountCellDataBuilder) Records(v Records) *AccountCellDataBuilder {
     s.records = v
     return s
 }
             
 
-func NewAccountCellDataV2Builder() *AccountCellDataV2Builder {
-	return &AccountCellDataV2Builder{ id: AccountIdDefault(),account: AccountCharsDefault(),registered_at: Uint64Default(),last_transfer_account_at: Uint64Default(),last_edit_manager_at: Uint64Default(),last_edit_records_at: Uint64Default(),status: Uint8Default(),records: RecordsDefault() }
+func (s *AccountCellDataBuilder) EnableSubAccount(v Uint8) *AccountCellDataBuilder {
+    s.enable_sub_account = v
+    return s
+}
+            
+
+func (s *AccountCellDataBuilder) RenewSubAccountPrice(v Uint64) *AccountCellDataBuilder {
+    s.renew_sub_account_price = v
+    return s
+}
+            
+
+func (s *AccountCellDataBuilder) Approval(v AccountApproval) *AccountCellDataBuilder {
+    s.approval = v
+    return s
+}
+            
+
+func NewAccountCellDataBuilder() *AccountCellDataBuilder {
+	return &AccountCellDataBuilder{ id: AccountIdDefault(),account: AccountCharsDefault(),registered_at: Uint64Default(),last_transfer_account_at: Uint64Default(),last_edit_manager_at: Uint64Default(),last_edit_records_at: Uint64Default(),status: Uint8Default(),records: RecordsDefault(),enable_sub_account: Uint8Default(),renew_sub_account_price: Uint64Default(),approval: AccountApprovalDefault() }
 }
     
 
-type AccountCellDataV2 struct {
+type AccountCellData struct {
     inner []byte
 }
         
 
-func AccountCellDataV2FromSliceUnchecked(slice []byte) *AccountCellDataV2 {
-    return &AccountCellDataV2{inner: slice}
+func AccountCellDataFromSliceUnchecked(slice []byte) *AccountCellData {
+    return &AccountCellData{inner: slice}
 }
-func (s *AccountCellDataV2) AsSlice() []byte {
+func (s *AccountCellData) AsSlice() []byte {
     return s.inner
 }
             
 
-func AccountCellDataV2Default() AccountCellDataV2 {
-    return *AccountCellDataV2FromSliceUnchecked([]byte{ 97,0,0,0,36,0,0,0,56,0,0,0,60,0,0,0,68,0,0,0,76,0,0,0,84,0,0,0,92,0,0,0,93,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0 })
+func AccountCellDataDefault() AccountCellData {
+    return *AccountCellDataFromSliceUnchecked([]byte{ 138,0,0,0,48,0,0,0,68,0,0,0,72,0,0,0,80,0,0,0,88,0,0,0,96,0,0,0,104,0,0,0,105,0,0,0,109,0,0,0,110,0,0,0,118,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,0,20,0,0,0,12,0,0,0,16,0,0,0,0,0,0,0,0,0,0,0 })
 }
             
 
-func AccountCellDataV2FromSlice(slice []byte, compatible bool) (*AccountCellDataV2, error) {
+func AccountCellDataFromSlice(slice []byte, compatible bool) (*AccountCellData, error) {
     sliceLen := len(slice)
     if uint32(sliceLen) < HeaderSizeUint {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "AccountCellDataV2", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "AccountCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
         return nil, errors.New(errMsg)
     }
 
     totalSize := unpackNumber(slice)
     if Number(sliceLen) != totalSize {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "AccountCellDataV2", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "AccountCellData", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if uint32(sliceLen) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "AccountCellDataV2", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "AccountCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     offsetFirst := unpackNumber(slice[HeaderSizeUint:])
     if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"OffsetsNotMatch", "AccountCellDataV2", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "AccountCellData", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if sliceLen < int(offsetFirst) {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "AccountCellDataV2", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "AccountCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
         return nil, errors.New(errMsg)
     }
 
     fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
-    if fieldCount < 8 {
+    if fieldCount < 11 {
         return nil, errors.New("FieldCountNotMatch")
-    } else if !compatible && fieldCount > 8 {
+    } else if !compatible && fieldCount > 11 {
         return nil, errors.New("FieldCountNotMatch")
     }
 
@@ -7438,14 +7835,32 @@ if err != nil {
 }
                 
 
-    return &AccountCellDataV2{inner: slice}, nil
+_, err = Uint8FromSlice(slice[offsets[8]:offsets[9]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint64FromSlice(slice[offsets[9]:offsets[10]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = AccountApprovalFromSlice(slice[offsets[10]:offsets[11]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+    return &AccountCellData{inner: slice}, nil
 }
             
 
-func (s *AccountCellDataV2) TotalSize() uint {
+func (s *AccountCellData) TotalSize() uint {
     return uint(unpackNumber(s.inner))
 }
-func (s *AccountCellDataV2) FieldCount() uint {
+func (s *AccountCellData) FieldCount() uint {
     var number uint = 0
     if uint32(s.TotalSize()) == HeaderSizeUint {
         return number
@@ -7453,575 +7868,229 @@ func (s *AccountCellDataV2) FieldCount() uint {
     number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
     return number
 }
-func (s *AccountCellDataV2) Len() uint {
+func (s *AccountCellData) Len() uint {
     return s.FieldCount()
 }
-func (s *AccountCellDataV2) IsEmpty() bool {
+func (s *AccountCellData) IsEmpty() bool {
     return s.Len() == 0
 }
-func (s *AccountCellDataV2) CountExtraFields() uint {
-    return s.FieldCount() - 8
+func (s *AccountCellData) CountExtraFields() uint {
+    return s.FieldCount() - 11
 }
 
-func (s *AccountCellDataV2) HasExtraFields() bool {
-    return 8 != s.FieldCount()
+func (s *AccountCellData) HasExtraFields() bool {
+    return 11 != s.FieldCount()
 }
             
 
-func (s *AccountCellDataV2) Id() *AccountId {
+func (s *AccountCellData) Id() *AccountId {
     start := unpackNumber(s.inner[4:])
     end := unpackNumber(s.inner[8:])
     return AccountIdFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *AccountCellDataV2) Account() *AccountChars {
+func (s *AccountCellData) Account() *AccountChars {
     start := unpackNumber(s.inner[8:])
     end := unpackNumber(s.inner[12:])
     return AccountCharsFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *AccountCellDataV2) RegisteredAt() *Uint64 {
+func (s *AccountCellData) RegisteredAt() *Uint64 {
     start := unpackNumber(s.inner[12:])
     end := unpackNumber(s.inner[16:])
     return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *AccountCellDataV2) LastTransferAccountAt() *Uint64 {
+func (s *AccountCellData) LastTransferAccountAt() *Uint64 {
     start := unpackNumber(s.inner[16:])
     end := unpackNumber(s.inner[20:])
     return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *AccountCellDataV2) LastEditManagerAt() *Uint64 {
+func (s *AccountCellData) LastEditManagerAt() *Uint64 {
     start := unpackNumber(s.inner[20:])
     end := unpackNumber(s.inner[24:])
     return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *AccountCellDataV2) LastEditRecordsAt() *Uint64 {
+func (s *AccountCellData) LastEditRecordsAt() *Uint64 {
     start := unpackNumber(s.inner[24:])
     end := unpackNumber(s.inner[28:])
     return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *AccountCellDataV2) Status() *Uint8 {
+func (s *AccountCellData) Status() *Uint8 {
     start := unpackNumber(s.inner[28:])
     end := unpackNumber(s.inner[32:])
     return Uint8FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *AccountCellDataV2) Records() *Records {
-    var ret *Records
+func (s *AccountCellData) Records() *Records {
     start := unpackNumber(s.inner[32:])
+    end := unpackNumber(s.inner[36:])
+    return RecordsFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *AccountCellData) EnableSubAccount() *Uint8 {
+    start := unpackNumber(s.inner[36:])
+    end := unpackNumber(s.inner[40:])
+    return Uint8FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *AccountCellData) RenewSubAccountPrice() *Uint64 {
+    start := unpackNumber(s.inner[40:])
+    end := unpackNumber(s.inner[44:])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *AccountCellData) Approval() *AccountApproval {
+    var ret *AccountApproval
+    start := unpackNumber(s.inner[44:])
     if s.HasExtraFields() {
-        end := unpackNumber(s.inner[36:])
-        ret = RecordsFromSliceUnchecked(s.inner[start:end])
+        end := unpackNumber(s.inner[48:])
+        ret = AccountApprovalFromSliceUnchecked(s.inner[start:end])
     } else {
-        ret = RecordsFromSliceUnchecked(s.inner[start:])
+        ret = AccountApprovalFromSliceUnchecked(s.inner[start:])
     }
     return ret
 }
                         
 
-func (s *AccountCellDataV2) AsBuilder() AccountCellDataV2Builder {
-    ret := NewAccountCellDataV2Builder().Id(*s.Id()).Account(*s.Account()).RegisteredAt(*s.RegisteredAt()).LastTransferAccountAt(*s.LastTransferAccountAt()).LastEditManagerAt(*s.LastEditManagerAt()).LastEditRecordsAt(*s.LastEditRecordsAt()).Status(*s.Status()).Records(*s.Records())
+func (s *AccountCellData) AsBuilder() AccountCellDataBuilder {
+    ret := NewAccountCellDataBuilder().Id(*s.Id()).Account(*s.Account()).RegisteredAt(*s.RegisteredAt()).LastTransferAccountAt(*s.LastTransferAccountAt()).LastEditManagerAt(*s.LastEditManagerAt()).LastEditRecordsAt(*s.LastEditRecordsAt()).Status(*s.Status()).Records(*s.Records()).EnableSubAccount(*s.EnableSubAccount()).RenewSubAccountPrice(*s.RenewSubAccountPrice()).Approval(*s.Approval())
     return *ret
 }
         
 
-type AccountCellDataBuilder struct {
-    id AccountId
-account AccountChars
-registered_at Uint64
-last_transfer_account_at Uint64
-last_edit_manager_at Uint64
-last_edit_records_at Uint64
-status Uint8
-records Records
-enable_sub_account Uint8
-renew_sub_account_price Uint64
+type AccountIdBuilder struct {
+	inner [20]Byte
 }
-        
-
-func (s *AccountCellDataBuilder) Build() AccountCellData {
-    b := new(bytes.Buffer)
-
-    totalSize := HeaderSizeUint * (10 + 1)
-    offsets := make([]uint32, 0, 10)
 
-    offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.id.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.account.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.registered_at.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.last_transfer_account_at.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.last_edit_manager_at.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.last_edit_records_at.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.status.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.records.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.enable_sub_account.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.renew_sub_account_price.AsSlice()))
+func NewAccountIdBuilder() *AccountIdBuilder {
+	return &AccountIdBuilder{inner: [20]Byte{ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault()}}
+}
 
-    b.Write(packNumber(Number(totalSize)))
+func (s *AccountIdBuilder) Build() AccountId {
+	b := new(bytes.Buffer)
+	len := len(s.inner)
+	for i := 0; i < len; i++ {
+		b.Write(s.inner[i].AsSlice())
+	}
+	return AccountId{inner: b.Bytes()}
+}
+        
 
-    for i := 0; i < len(offsets); i++ {
-        b.Write(packNumber(Number(offsets[i])))
-    }
+func (s *AccountIdBuilder) Set(v [20]Byte) *AccountIdBuilder {
+	s.inner = v
+	return s
+}
+        
 
-    b.Write(s.id.AsSlice())
-b.Write(s.account.AsSlice())
-b.Write(s.registered_at.AsSlice())
-b.Write(s.last_transfer_account_at.AsSlice())
-b.Write(s.last_edit_manager_at.AsSlice())
-b.Write(s.last_edit_records_at.AsSlice())
-b.Write(s.status.AsSlice())
-b.Write(s.records.AsSlice())
-b.Write(s.enable_sub_account.AsSlice())
-b.Write(s.renew_sub_account_price.AsSlice())
-    return AccountCellData{inner: b.Bytes()}
+func (s *AccountIdBuilder) Nth0(v Byte) *AccountIdBuilder {
+	s.inner[0] = v
+	return s
 }
                 
 
-func (s *AccountCellDataBuilder) Id(v AccountId) *AccountCellDataBuilder {
-    s.id = v
-    return s
+func (s *AccountIdBuilder) Nth1(v Byte) *AccountIdBuilder {
+	s.inner[1] = v
+	return s
 }
-            
+                
 
-func (s *AccountCellDataBuilder) Account(v AccountChars) *AccountCellDataBuilder {
-    s.account = v
-    return s
+func (s *AccountIdBuilder) Nth2(v Byte) *AccountIdBuilder {
+	s.inner[2] = v
+	return s
 }
-            
+                
 
-func (s *AccountCellDataBuilder) RegisteredAt(v Uint64) *AccountCellDataBuilder {
-    s.registered_at = v
-    return s
+func (s *AccountIdBuilder) Nth3(v Byte) *AccountIdBuilder {
+	s.inner[3] = v
+	return s
 }
-            
+                
 
-func (s *AccountCellDataBuilder) LastTransferAccountAt(v Uint64) *AccountCellDataBuilder {
-    s.last_transfer_account_at = v
-    return s
+func (s *AccountIdBuilder) Nth4(v Byte) *AccountIdBuilder {
+	s.inner[4] = v
+	return s
 }
-            
+                
 
-func (s *AccountCellDataBuilder) LastEditManagerAt(v Uint64) *AccountCellDataBuilder {
-    s.last_edit_manager_at = v
-    return s
+func (s *AccountIdBuilder) Nth5(v Byte) *AccountIdBuilder {
+	s.inner[5] = v
+	return s
 }
-            
+                
 
-func (s *AccountCellDataBuilder) LastEditRecordsAt(v Uint64) *AccountCellDataBuilder {
-    s.last_edit_records_at = v
-    return s
+func (s *AccountIdBuilder) Nth6(v Byte) *AccountIdBuilder {
+	s.inner[6] = v
+	return s
 }
-            
+                
 
-func (s *AccountCellDataBuilder) Status(v Uint8) *AccountCellDataBuilder {
-    s.status = v
-    return s
+func (s *AccountIdBuilder) Nth7(v Byte) *AccountIdBuilder {
+	s.inner[7] = v
+	return s
 }
-            
+                
 
-func (s *AccountCellDataBuilder) Records(v Records) *AccountCellDataBuilder {
-    s.records = v
-    return s
+func (s *AccountIdBuilder) Nth8(v Byte) *AccountIdBuilder {
+	s.inner[8] = v
+	return s
 }
-            
+                
 
-func (s *AccountCellDataBuilder) EnableSubAccount(v Uint8) *AccountCellDataBuilder {
-    s.enable_sub_account = v
-    return s
+func (s *AccountIdBuilder) Nth9(v Byte) *AccountIdBuilder {
+	s.inner[9] = v
+	return s
 }
-            
+                
 
-func (s *AccountCellDataBuilder) RenewSubAccountPrice(v Uint64) *AccountCellDataBuilder {
-    s.renew_sub_account_price = v
-    return s
+func (s *AccountIdBuilder) Nth10(v Byte) *AccountIdBuilder {
+	s.inner[10] = v
+	return s
 }
-            
+                
 
-func NewAccountCellDataBuilder() *AccountCellDataBuilder {
-	return &AccountCellDataBuilder{ id: AccountIdDefault(),account: AccountCharsDefault(),registered_at: Uint64Default(),last_transfer_account_at: Uint64Default(),last_edit_manager_at: Uint64Default(),last_edit_records_at: Uint64Default(),status: Uint8Default(),records: RecordsDefault(),enable_sub_account: Uint8Default(),renew_sub_account_price: Uint64Default() }
+func (s *AccountIdBuilder) Nth11(v Byte) *AccountIdBuilder {
+	s.inner[11] = v
+	return s
 }
-    
+                
 
-type AccountCellData struct {
-    inner []byte
+func (s *AccountIdBuilder) Nth12(v Byte) *AccountIdBuilder {
+	s.inner[12] = v
+	return s
 }
-        
+                
 
-func AccountCellDataFromSliceUnchecked(slice []byte) *AccountCellData {
-    return &AccountCellData{inner: slice}
+func (s *AccountIdBuilder) Nth13(v Byte) *AccountIdBuilder {
+	s.inner[13] = v
+	return s
 }
-func (s *AccountCellData) AsSlice() []byte {
-    return s.inner
+                
+
+func (s *AccountIdBuilder) Nth14(v Byte) *AccountIdBuilder {
+	s.inner[14] = v
+	return s
 }
-            
+                
 
-func AccountCellDataDefault() AccountCellData {
-    return *AccountCellDataFromSliceUnchecked([]byte{ 114,0,0,0,44,0,0,0,64,0,0,0,68,0,0,0,76,0,0,0,84,0,0,0,92,0,0,0,100,0,0,0,101,0,0,0,105,0,0,0,106,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,0 })
+func (s *AccountIdBuilder) Nth15(v Byte) *AccountIdBuilder {
+	s.inner[15] = v
+	return s
 }
-            
-
-func AccountCellDataFromSlice(slice []byte, compatible bool) (*AccountCellData, error) {
-    sliceLen := len(slice)
-    if uint32(sliceLen) < HeaderSizeUint {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "AccountCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
-        return nil, errors.New(errMsg)
-    }
-
-    totalSize := unpackNumber(slice)
-    if Number(sliceLen) != totalSize {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "AccountCellData", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
-        return nil, errors.New(errMsg)
-    }
-
-    if uint32(sliceLen) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "AccountCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
-        return nil, errors.New(errMsg)
-    }
-
-    offsetFirst := unpackNumber(slice[HeaderSizeUint:])
-    if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"OffsetsNotMatch", "AccountCellData", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
-        return nil, errors.New(errMsg)
-    }
-
-    if sliceLen < int(offsetFirst) {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "AccountCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
-        return nil, errors.New(errMsg)
-    }
-
-    fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
-    if fieldCount < 10 {
-        return nil, errors.New("FieldCountNotMatch")
-    } else if !compatible && fieldCount > 10 {
-        return nil, errors.New("FieldCountNotMatch")
-    }
-
-    offsets := make([]uint32, fieldCount)
-
-    for i := 0; i < int(fieldCount); i++ {
-        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
-    }
-    offsets = append(offsets, uint32(totalSize))
-
-    for i := 0; i < len(offsets); i++ {
-        if i&1 != 0 && offsets[i-1] > offsets[i] {
-            return nil, errors.New("OffsetsNotMatch")
-        }
-    }
-
-    var err error
-    
-_, err = AccountIdFromSlice(slice[offsets[0]:offsets[1]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = AccountCharsFromSlice(slice[offsets[1]:offsets[2]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = Uint64FromSlice(slice[offsets[2]:offsets[3]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = Uint64FromSlice(slice[offsets[3]:offsets[4]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = Uint64FromSlice(slice[offsets[4]:offsets[5]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = Uint64FromSlice(slice[offsets[5]:offsets[6]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = Uint8FromSlice(slice[offsets[6]:offsets[7]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = RecordsFromSlice(slice[offsets[7]:offsets[8]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = Uint8FromSlice(slice[offsets[8]:offsets[9]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = Uint64FromSlice(slice[offsets[9]:offsets[10]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-    return &AccountCellData{inner: slice}, nil
-}
-            
-
-func (s *AccountCellData) TotalSize() uint {
-    return uint(unpackNumber(s.inner))
-}
-func (s *AccountCellData) FieldCount() uint {
-    var number uint = 0
-    if uint32(s.TotalSize()) == HeaderSizeUint {
-        return number
-    }
-    number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
-    return number
-}
-func (s *AccountCellData) Len() uint {
-    return s.FieldCount()
-}
-func (s *AccountCellData) IsEmpty() bool {
-    return s.Len() == 0
-}
-func (s *AccountCellData) CountExtraFields() uint {
-    return s.FieldCount() - 10
-}
-
-func (s *AccountCellData) HasExtraFields() bool {
-    return 10 != s.FieldCount()
-}
-            
-
-func (s *AccountCellData) Id() *AccountId {
-    start := unpackNumber(s.inner[4:])
-    end := unpackNumber(s.inner[8:])
-    return AccountIdFromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *AccountCellData) Account() *AccountChars {
-    start := unpackNumber(s.inner[8:])
-    end := unpackNumber(s.inner[12:])
-    return AccountCharsFromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *AccountCellData) RegisteredAt() *Uint64 {
-    start := unpackNumber(s.inner[12:])
-    end := unpackNumber(s.inner[16:])
-    return Uint64FromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *AccountCellData) LastTransferAccountAt() *Uint64 {
-    start := unpackNumber(s.inner[16:])
-    end := unpackNumber(s.inner[20:])
-    return Uint64FromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *AccountCellData) LastEditManagerAt() *Uint64 {
-    start := unpackNumber(s.inner[20:])
-    end := unpackNumber(s.inner[24:])
-    return Uint64FromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *AccountCellData) LastEditRecordsAt() *Uint64 {
-    start := unpackNumber(s.inner[24:])
-    end := unpackNumber(s.inner[28:])
-    return Uint64FromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *AccountCellData) Status() *Uint8 {
-    start := unpackNumber(s.inner[28:])
-    end := unpackNumber(s.inner[32:])
-    return Uint8FromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *AccountCellData) Records() *Records {
-    start := unpackNumber(s.inner[32:])
-    end := unpackNumber(s.inner[36:])
-    return RecordsFromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *AccountCellData) EnableSubAccount() *Uint8 {
-    start := unpackNumber(s.inner[36:])
-    end := unpackNumber(s.inner[40:])
-    return Uint8FromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *AccountCellData) RenewSubAccountPrice() *Uint64 {
-    var ret *Uint64
-    start := unpackNumber(s.inner[40:])
-    if s.HasExtraFields() {
-        end := unpackNumber(s.inner[44:])
-        ret = Uint64FromSliceUnchecked(s.inner[start:end])
-    } else {
-        ret = Uint64FromSliceUnchecked(s.inner[start:])
-    }
-    return ret
-}
-                        
-
-func (s *AccountCellData) AsBuilder() AccountCellDataBuilder {
-    ret := NewAccountCellDataBuilder().Id(*s.Id()).Account(*s.Account()).RegisteredAt(*s.RegisteredAt()).LastTransferAccountAt(*s.LastTransferAccountAt()).LastEditManagerAt(*s.LastEditManagerAt()).LastEditRecordsAt(*s.LastEditRecordsAt()).Status(*s.Status()).Records(*s.Records()).EnableSubAccount(*s.EnableSubAccount()).RenewSubAccountPrice(*s.RenewSubAccountPrice())
-    return *ret
-}
-        
-
-type AccountIdBuilder struct {
-	inner [20]Byte
-}
-
-func NewAccountIdBuilder() *AccountIdBuilder {
-	return &AccountIdBuilder{inner: [20]Byte{ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault(),ByteDefault()}}
-}
-
-func (s *AccountIdBuilder) Build() AccountId {
-	b := new(bytes.Buffer)
-	len := len(s.inner)
-	for i := 0; i < len; i++ {
-		b.Write(s.inner[i].AsSlice())
-	}
-	return AccountId{inner: b.Bytes()}
-}
-        
-
-func (s *AccountIdBuilder) Set(v [20]Byte) *AccountIdBuilder {
-	s.inner = v
-	return s
-}
-        
-
-func (s *AccountIdBuilder) Nth0(v Byte) *AccountIdBuilder {
-	s.inner[0] = v
-	return s
-}
-                
-
-func (s *AccountIdBuilder) Nth1(v Byte) *AccountIdBuilder {
-	s.inner[1] = v
-	return s
-}
-                
-
-func (s *AccountIdBuilder) Nth2(v Byte) *AccountIdBuilder {
-	s.inner[2] = v
-	return s
-}
-                
-
-func (s *AccountIdBuilder) Nth3(v Byte) *AccountIdBuilder {
-	s.inner[3] = v
-	return s
-}
-                
-
-func (s *AccountIdBuilder) Nth4(v Byte) *AccountIdBuilder {
-	s.inner[4] = v
-	return s
-}
-                
-
-func (s *AccountIdBuilder) Nth5(v Byte) *AccountIdBuilder {
-	s.inner[5] = v
-	return s
-}
-                
-
-func (s *AccountIdBuilder) Nth6(v Byte) *AccountIdBuilder {
-	s.inner[6] = v
-	return s
-}
-                
-
-func (s *AccountIdBuilder) Nth7(v Byte) *AccountIdBuilder {
-	s.inner[7] = v
-	return s
-}
-                
-
-func (s *AccountIdBuilder) Nth8(v Byte) *AccountIdBuilder {
-	s.inner[8] = v
-	return s
-}
-                
-
-func (s *AccountIdBuilder) Nth9(v Byte) *AccountIdBuilder {
-	s.inner[9] = v
-	return s
-}
-                
-
-func (s *AccountIdBuilder) Nth10(v Byte) *AccountIdBuilder {
-	s.inner[10] = v
-	return s
-}
-                
-
-func (s *AccountIdBuilder) Nth11(v Byte) *AccountIdBuilder {
-	s.inner[11] = v
-	return s
-}
-                
-
-func (s *AccountIdBuilder) Nth12(v Byte) *AccountIdBuilder {
-	s.inner[12] = v
-	return s
-}
-                
-
-func (s *AccountIdBuilder) Nth13(v Byte) *AccountIdBuilder {
-	s.inner[13] = v
-	return s
-}
-                
-
-func (s *AccountIdBuilder) Nth14(v Byte) *AccountIdBuilder {
-	s.inner[14] = v
-	return s
-}
-                
-
-func (s *AccountIdBuilder) Nth15(v Byte) *AccountIdBuilder {
-	s.inner[15] = v
-	return s
-}
-                
+                
 
 func (s *AccountIdBuilder) Nth16(v Byte) *AccountIdBuilder {
 	s.inner[16] = v
@@ -8677,31 +8746,22 @@ func (s *Records) AsBuilder() RecordsBuilder {
 }
         
 
-type AccountSaleCellDataV1Builder struct {
-    account_id AccountId
-account Bytes
-price Uint64
-description Bytes
-started_at Uint64
+type AccountApprovalBuilder struct {
+    action Bytes
+params Bytes
 }
         
 
-func (s *AccountSaleCellDataV1Builder) Build() AccountSaleCellDataV1 {
+func (s *AccountApprovalBuilder) Build() AccountApproval {
     b := new(bytes.Buffer)
 
-    totalSize := HeaderSizeUint * (5 + 1)
-    offsets := make([]uint32, 0, 5)
+    totalSize := HeaderSizeUint * (2 + 1)
+    offsets := make([]uint32, 0, 2)
 
     offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.account_id.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.account.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.price.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.description.AsSlice()))
+totalSize += uint32(len(s.action.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.started_at.AsSlice()))
+totalSize += uint32(len(s.params.AsSlice()))
 
     b.Write(packNumber(Number(totalSize)))
 
@@ -8709,101 +8769,80 @@ totalSize += uint32(len(s.started_at.AsSlice()))
         b.Write(packNumber(Number(offsets[i])))
     }
 
-    b.Write(s.account_id.AsSlice())
-b.Write(s.account.AsSlice())
-b.Write(s.price.AsSlice())
-b.Write(s.description.AsSlice())
-b.Write(s.started_at.AsSlice())
-    return AccountSaleCellDataV1{inner: b.Bytes()}
+    b.Write(s.action.AsSlice())
+b.Write(s.params.AsSlice())
+    return AccountApproval{inner: b.Bytes()}
 }
                 
 
-func (s *AccountSaleCellDataV1Builder) AccountId(v AccountId) *AccountSaleCellDataV1Builder {
-    s.account_id = v
-    return s
-}
-            
-
-func (s *AccountSaleCellDataV1Builder) Account(v Bytes) *AccountSaleCellDataV1Builder {
-    s.account = v
-    return s
-}
-            
-
-func (s *AccountSaleCellDataV1Builder) Price(v Uint64) *AccountSaleCellDataV1Builder {
-    s.price = v
-    return s
-}
-            
-
-func (s *AccountSaleCellDataV1Builder) Description(v Bytes) *AccountSaleCellDataV1Builder {
-    s.description = v
+func (s *AccountApprovalBuilder) Action(v Bytes) *AccountApprovalBuilder {
+    s.action = v
     return s
 }
             
 
-func (s *AccountSaleCellDataV1Builder) StartedAt(v Uint64) *AccountSaleCellDataV1Builder {
-    s.started_at = v
+func (s *AccountApprovalBuilder) Params(v Bytes) *AccountApprovalBuilder {
+    s.params = v
     return s
 }
             
 
-func NewAccountSaleCellDataV1Builder() *AccountSaleCellDataV1Builder {
-	return &AccountSaleCellDataV1Builder{ account_id: AccountIdDefault(),account: BytesDefault(),price: Uint64Default(),description: BytesDefault(),started_at: Uint64Default() }
+func NewAccountApprovalBuilder() *AccountApprovalBuilder {
+	return &AccountApprovalBuilder{ action: BytesDefault(),params: BytesDefault() }
 }
     
 
-type AccountSaleCellDataV1 struct {
+type AccountApproval struct {
     inner []byte
 }
         
 
-func AccountSaleCellDataV1FromSliceUnchecked(slice []byte) *AccountSaleCellDataV1 {
-    return &AccountSaleCellDataV1{inner: slice}
+func AccountApprovalFromSliceUnchecked(slice []byte) *AccountApproval {
+    return &AccountApproval{inner: slice}
 }
-func (s *AccountSaleCellDataV1) AsSlice() []byte {
+func (s *AccountApproval) AsSlice() []byte {
     return s.inner
 }
             
 
-func AccountSaleCellDataV1Default() AccountSaleCellDataV1 {
-    return *AccountSaleCellDataV1FromSliceUnchecked([]byte{ 68,0,0,0,24,0,0,0,44,0,0,0,48,0,0,0,56,0,0,0,60,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+func AccountApprovalDefault() AccountApproval {
+    return *AccountApprovalFromSliceUnchecked([]byte{ 20,0,0,0,12,0,0,0,16,0,0,0,0,0,0,0,0,0,0,0 })
 }
             
 
-func AccountSaleCellDataV1FromSlice(slice []byte, compatible bool) (*AccountSaleCellDataV1, error) {
+func AccountApprovalFromSlice(slice []byte, compatible bool) (*AccountApproval, error) {
     sliceLen := len(slice)
     if uint32(sliceLen) < HeaderSizeUint {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "AccountSaleCellDataV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "AccountApproval", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
         return nil, errors.New(errMsg)
     }
 
     totalSize := unpackNumber(slice)
     if Number(sliceLen) != totalSize {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "AccountSaleCellDataV1", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "AccountApproval", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if uint32(sliceLen) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "AccountSaleCellDataV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "AccountApproval", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     offsetFirst := unpackNumber(slice[HeaderSizeUint:])
     if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"OffsetsNotMatch", "AccountSaleCellDataV1", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "AccountApproval", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if sliceLen < int(offsetFirst) {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "AccountSaleCellDataV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "AccountApproval", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
         return nil, errors.New(errMsg)
     }
 
     fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
-    if fieldCount < 5 {
+    if fieldCount < 2 {
         return nil, errors.New("FieldCountNotMatch")
-    } else if !compatible && fieldCount > 5 {
+    } else if !compatible && fieldCount > 2 {
         return nil, errors.New("FieldCountNotMatch")
     }
 
@@ -8822,7 +8861,7 @@ func AccountSaleCellDataV1FromSlice(slice []byte, compatible bool) (*AccountSale
 
     var err error
     
-_, err = AccountIdFromSlice(slice[offsets[0]:offsets[1]], compatible)
+_, err = BytesFromSlice(slice[offsets[0]:offsets[1]], compatible)
 if err != nil {
     return nil, err
 }
@@ -8834,32 +8873,14 @@ if err != nil {
 }
                 
 
-_, err = Uint64FromSlice(slice[offsets[2]:offsets[3]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = BytesFromSlice(slice[offsets[3]:offsets[4]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = Uint64FromSlice(slice[offsets[4]:offsets[5]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-    return &AccountSaleCellDataV1{inner: slice}, nil
+    return &AccountApproval{inner: slice}, nil
 }
             
 
-func (s *AccountSaleCellDataV1) TotalSize() uint {
+func (s *AccountApproval) TotalSize() uint {
     return uint(unpackNumber(s.inner))
 }
-func (s *AccountSaleCellDataV1) FieldCount() uint {
+func (s *AccountApproval) FieldCount() uint {
     var number uint = 0
     if uint32(s.TotalSize()) == HeaderSizeUint {
         return number
@@ -8867,96 +8888,72 @@ func (s *AccountSaleCellDataV1) FieldCount() uint {
     number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
     return number
 }
-func (s *AccountSaleCellDataV1) Len() uint {
+func (s *AccountApproval) Len() uint {
     return s.FieldCount()
 }
-func (s *AccountSaleCellDataV1) IsEmpty() bool {
+func (s *AccountApproval) IsEmpty() bool {
     return s.Len() == 0
 }
-func (s *AccountSaleCellDataV1) CountExtraFields() uint {
-    return s.FieldCount() - 5
+func (s *AccountApproval) CountExtraFields() uint {
+    return s.FieldCount() - 2
 }
 
-func (s *AccountSaleCellDataV1) HasExtraFields() bool {
-    return 5 != s.FieldCount()
+func (s *AccountApproval) HasExtraFields() bool {
+    return 2 != s.FieldCount()
 }
             
 
-func (s *AccountSaleCellDataV1) AccountId() *AccountId {
+func (s *AccountApproval) Action() *Bytes {
     start := unpackNumber(s.inner[4:])
     end := unpackNumber(s.inner[8:])
-    return AccountIdFromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *AccountSaleCellDataV1) Account() *Bytes {
-    start := unpackNumber(s.inner[8:])
-    end := unpackNumber(s.inner[12:])
     return BytesFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *AccountSaleCellDataV1) Price() *Uint64 {
-    start := unpackNumber(s.inner[12:])
-    end := unpackNumber(s.inner[16:])
-    return Uint64FromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *AccountSaleCellDataV1) Description() *Bytes {
-    start := unpackNumber(s.inner[16:])
-    end := unpackNumber(s.inner[20:])
-    return BytesFromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *AccountSaleCellDataV1) StartedAt() *Uint64 {
-    var ret *Uint64
-    start := unpackNumber(s.inner[20:])
+func (s *AccountApproval) Params() *Bytes {
+    var ret *Bytes
+    start := unpackNumber(s.inner[8:])
     if s.HasExtraFields() {
-        end := unpackNumber(s.inner[24:])
-        ret = Uint64FromSliceUnchecked(s.inner[start:end])
+        end := unpackNumber(s.inner[12:])
+        ret = BytesFromSliceUnchecked(s.inner[start:end])
     } else {
-        ret = Uint64FromSliceUnchecked(s.inner[start:])
+        ret = BytesFromSliceUnchecked(s.inner[start:])
     }
     return ret
 }
                         
 
-func (s *AccountSaleCellDataV1) AsBuilder() AccountSaleCellDataV1Builder {
-    ret := NewAccountSaleCellDataV1Builder().AccountId(*s.AccountId()).Account(*s.Account()).Price(*s.Price()).Description(*s.Description()).StartedAt(*s.StartedAt())
-    return *ret
-}
-        
-
-type AccountSaleCellDataBuilder struct {
-    account_id AccountId
-account Bytes
-price Uint64
-description Bytes
-started_at Uint64
-buyer_inviter_profit_rate Uint32
+func (s *AccountApproval) AsBuilder() AccountApprovalBuilder {
+    ret := NewAccountApprovalBuilder().Action(*s.Action()).Params(*s.Params())
+    return *ret
 }
         
 
-func (s *AccountSaleCellDataBuilder) Build() AccountSaleCellData {
+type AccountApprovalTransferBuilder struct {
+    platform_lock Script
+protected_until Uint64
+sealed_until Uint64
+delay_count_remain Uint8
+to_lock Script
+}
+        
+
+func (s *AccountApprovalTransferBuilder) Build() AccountApprovalTransfer {
     b := new(bytes.Buffer)
 
-    totalSize := HeaderSizeUint * (6 + 1)
-    offsets := make([]uint32, 0, 6)
+    totalSize := HeaderSizeUint * (5 + 1)
+    offsets := make([]uint32, 0, 5)
 
     offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.account_id.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.account.AsSlice()))
+totalSize += uint32(len(s.platform_lock.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.price.AsSlice()))
+totalSize += uint32(len(s.protected_until.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.description.AsSlice()))
+totalSize += uint32(len(s.sealed_until.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.started_at.AsSlice()))
+totalSize += uint32(len(s.delay_count_remain.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.buyer_inviter_profit_rate.AsSlice()))
+totalSize += uint32(len(s.to_lock.AsSlice()))
 
     b.Write(packNumber(Number(totalSize)))
 
@@ -8964,108 +8961,101 @@ totalSize += uint32(len(s.buyer_inviter_profit_rate.AsSlice()))
         b.Write(packNumber(Number(offsets[i])))
     }
 
-    b.Write(s.account_id.AsSlice())
-b.Write(s.account.AsSlice())
-b.Write(s.price.AsSlice())
-b.Write(s.description.AsSlice())
-b.Write(s.started_at.AsSlice())
-b.Write(s.buyer_inviter_profit_rate.AsSlice())
-    return AccountSaleCellData{inner: b.Bytes()}
+    b.Write(s.platform_lock.AsSlice())
+b.Write(s.protected_until.AsSlice())
+b.Write(s.sealed_until.AsSlice())
+b.Write(s.delay_count_remain.AsSlice())
+b.Write(s.to_lock.AsSlice())
+    return AccountApprovalTransfer{inner: b.Bytes()}
 }
                 
 
-func (s *AccountSaleCellDataBuilder) AccountId(v AccountId) *AccountSaleCellDataBuilder {
-    s.account_id = v
-    return s
-}
-            
-
-func (s *AccountSaleCellDataBuilder) Account(v Bytes) *AccountSaleCellDataBuilder {
-    s.account = v
+func (s *AccountApprovalTransferBuilder) PlatformLock(v Script) *AccountApprovalTransferBuilder {
+    s.platform_lock = v
     return s
 }
             
 
-func (s *AccountSaleCellDataBuilder) Price(v Uint64) *AccountSaleCellDataBuilder {
-    s.price = v
+func (s *AccountApprovalTransferBuilder) ProtectedUntil(v Uint64) *AccountApprovalTransferBuilder {
+    s.protected_until = v
     return s
 }
             
 
-func (s *AccountSaleCellDataBuilder) Description(v Bytes) *AccountSaleCellDataBuilder {
-    s.description = v
+func (s *AccountApprovalTransferBuilder) SealedUntil(v Uint64) *AccountApprovalTransferBuilder {
+    s.sealed_until = v
     return s
 }
             
 
-func (s *AccountSaleCellDataBuilder) StartedAt(v Uint64) *AccountSaleCellDataBuilder {
-    s.started_at = v
+func (s *AccountApprovalTransferBuilder) DelayCountRemain(v Uint8) *AccountApprovalTransferBuilder {
+    s.delay_count_remain = v
     return s
 }
             
 
-func (s *AccountSaleCellDataBuilder) BuyerInviterProfitRate(v Uint32) *AccountSaleCellDataBuilder {
-    s.buyer_inviter_profit_rate = v
+func (s *AccountApprovalTransferBuilder) ToLock(v Script) *AccountApprovalTransferBuilder {
+    s.to_lock = v
     return s
 }
             
 
-func NewAccountSaleCellDataBuilder() *AccountSaleCellDataBuilder {
-	return &AccountSaleCellDataBuilder{ account_id: AccountIdDefault(),account: BytesDefault(),price: Uint64Default(),description: BytesDefault(),started_at: Uint64Default(),buyer_inviter_profit_rate: Uint32Default() }
+func NewAccountApprovalTransferBuilder() *AccountApprovalTransferBuilder {
+	return &AccountApprovalTransferBuilder{ platform_lock: ScriptDefault(),protected_until: Uint64Default(),sealed_until: Uint64Default(),delay_count_remain: Uint8Default(),to_lock: ScriptDefault() }
 }
     
 
-type AccountSaleCellData struct {
+type AccountApprovalTransfer struct {
     inner []byte
 }
         
 
-func AccountSaleCellDataFromSliceUnchecked(slice []byte) *AccountSaleCellData {
-    return &AccountSaleCellData{inner: slice}
+func AccountApprovalTransferFromSliceUnchecked(slice []byte) *AccountApprovalTransfer {
+    return &AccountApprovalTransfer{inner: slice}
 }
-func (s *AccountSaleCellData) AsSlice() []byte {
+func (s *AccountApprovalTransfer) AsSlice() []byte {
     return s.inner
 }
             
 
-func AccountSaleCellDataDefault() AccountSaleCellData {
-    return *AccountSaleCellDataFromSliceUnchecked([]byte{ 76,0,0,0,28,0,0,0,48,0,0,0,52,0,0,0,60,0,0,0,64,0,0,0,72,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+func AccountApprovalTransferDefault() AccountApprovalTransfer {
+    return *AccountApprovalTransferFromSliceUnchecked([]byte{ 147,0,0,0,24,0,0,0,77,0,0,0,85,0,0,0,93,0,0,0,94,0,0,0,53,0,0,0,16,0,0,0,48,0,0,0,49,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,53,0,0,0,16,0,0,0,48,0,0,0,49,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
 }
             
 
-func AccountSaleCellDataFromSlice(slice []byte, compatible bool) (*AccountSaleCellData, error) {
+func AccountApprovalTransferFromSlice(slice []byte, compatible bool) (*AccountApprovalTransfer, error) {
     sliceLen := len(slice)
     if uint32(sliceLen) < HeaderSizeUint {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "AccountSaleCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "AccountApprovalTransfer", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
         return nil, errors.New(errMsg)
     }
 
     totalSize := unpackNumber(slice)
     if Number(sliceLen) != totalSize {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "AccountSaleCellData", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "AccountApprovalTransfer", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if uint32(sliceLen) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "AccountSaleCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "AccountApprovalTransfer", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     offsetFirst := unpackNumber(slice[HeaderSizeUint:])
     if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"OffsetsNotMatch", "AccountSaleCellData", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "AccountApprovalTransfer", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if sliceLen < int(offsetFirst) {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "AccountSaleCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "AccountApprovalTransfer", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
         return nil, errors.New(errMsg)
     }
 
     fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
-    if fieldCount < 6 {
+    if fieldCount < 5 {
         return nil, errors.New("FieldCountNotMatch")
-    } else if !compatible && fieldCount > 6 {
+    } else if !compatible && fieldCount > 5 {
         return nil, errors.New("FieldCountNotMatch")
     }
 
@@ -9084,13 +9074,13 @@ func AccountSaleCellDataFromSlice(slice []byte, compatible bool) (*AccountSaleCe
 
     var err error
     
-_, err = AccountIdFromSlice(slice[offsets[0]:offsets[1]], compatible)
+_, err = ScriptFromSlice(slice[offsets[0]:offsets[1]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = BytesFromSlice(slice[offsets[1]:offsets[2]], compatible)
+_, err = Uint64FromSlice(slice[offsets[1]:offsets[2]], compatible)
 if err != nil {
     return nil, err
 }
@@ -9102,32 +9092,26 @@ if err != nil {
 }
                 
 
-_, err = BytesFromSlice(slice[offsets[3]:offsets[4]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = Uint64FromSlice(slice[offsets[4]:offsets[5]], compatible)
+_, err = Uint8FromSlice(slice[offsets[3]:offsets[4]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = Uint32FromSlice(slice[offsets[5]:offsets[6]], compatible)
+_, err = ScriptFromSlice(slice[offsets[4]:offsets[5]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-    return &AccountSaleCellData{inner: slice}, nil
+    return &AccountApprovalTransfer{inner: slice}, nil
 }
             
 
-func (s *AccountSaleCellData) TotalSize() uint {
+func (s *AccountApprovalTransfer) TotalSize() uint {
     return uint(unpackNumber(s.inner))
 }
-func (s *AccountSaleCellData) FieldCount() uint {
+func (s *AccountApprovalTransfer) FieldCount() uint {
     var number uint = 0
     if uint32(s.TotalSize()) == HeaderSizeUint {
         return number
@@ -9135,115 +9119,93 @@ func (s *AccountSaleCellData) FieldCount() uint {
     number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
     return number
 }
-func (s *AccountSaleCellData) Len() uint {
+func (s *AccountApprovalTransfer) Len() uint {
     return s.FieldCount()
 }
-func (s *AccountSaleCellData) IsEmpty() bool {
+func (s *AccountApprovalTransfer) IsEmpty() bool {
     return s.Len() == 0
 }
-func (s *AccountSaleCellData) CountExtraFields() uint {
-    return s.FieldCount() - 6
+func (s *AccountApprovalTransfer) CountExtraFields() uint {
+    return s.FieldCount() - 5
 }
 
-func (s *AccountSaleCellData) HasExtraFields() bool {
-    return 6 != s.FieldCount()
+func (s *AccountApprovalTransfer) HasExtraFields() bool {
+    return 5 != s.FieldCount()
 }
             
 
-func (s *AccountSaleCellData) AccountId() *AccountId {
+func (s *AccountApprovalTransfer) PlatformLock() *Script {
     start := unpackNumber(s.inner[4:])
     end := unpackNumber(s.inner[8:])
-    return AccountIdFromSliceUnchecked(s.inner[start:end])
+    return ScriptFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *AccountSaleCellData) Account() *Bytes {
+func (s *AccountApprovalTransfer) ProtectedUntil() *Uint64 {
     start := unpackNumber(s.inner[8:])
     end := unpackNumber(s.inner[12:])
-    return BytesFromSliceUnchecked(s.inner[start:end])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *AccountSaleCellData) Price() *Uint64 {
+func (s *AccountApprovalTransfer) SealedUntil() *Uint64 {
     start := unpackNumber(s.inner[12:])
     end := unpackNumber(s.inner[16:])
     return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *AccountSaleCellData) Description() *Bytes {
+func (s *AccountApprovalTransfer) DelayCountRemain() *Uint8 {
     start := unpackNumber(s.inner[16:])
     end := unpackNumber(s.inner[20:])
-    return BytesFromSliceUnchecked(s.inner[start:end])
+    return Uint8FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *AccountSaleCellData) StartedAt() *Uint64 {
+func (s *AccountApprovalTransfer) ToLock() *Script {
+    var ret *Script
     start := unpackNumber(s.inner[20:])
-    end := unpackNumber(s.inner[24:])
-    return Uint64FromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *AccountSaleCellData) BuyerInviterProfitRate() *Uint32 {
-    var ret *Uint32
-    start := unpackNumber(s.inner[24:])
     if s.HasExtraFields() {
-        end := unpackNumber(s.inner[28:])
-        ret = Uint32FromSliceUnchecked(s.inner[start:end])
+        end := unpackNumber(s.inner[24:])
+        ret = ScriptFromSliceUnchecked(s.inner[start:end])
     } else {
-        ret = Uint32FromSliceUnchecked(s.inner[start:])
+        ret = ScriptFromSliceUnchecked(s.inner[start:])
     }
     return ret
 }
                         
 
-func (s *AccountSaleCellData) AsBuilder() AccountSaleCellDataBuilder {
-    ret := NewAccountSaleCellDataBuilder().AccountId(*s.AccountId()).Account(*s.Account()).Price(*s.Price()).Description(*s.Description()).StartedAt(*s.StartedAt()).BuyerInviterProfitRate(*s.BuyerInviterProfitRate())
+func (s *AccountApprovalTransfer) AsBuilder() AccountApprovalTransferBuilder {
+    ret := NewAccountApprovalTransferBuilder().PlatformLock(*s.PlatformLock()).ProtectedUntil(*s.ProtectedUntil()).SealedUntil(*s.SealedUntil()).DelayCountRemain(*s.DelayCountRemain()).ToLock(*s.ToLock())
     return *ret
 }
         
 
-type AccountAuctionCellDataBuilder struct {
+type AccountSaleCellDataV1Builder struct {
     account_id AccountId
 account Bytes
+price Uint64
 description Bytes
-opening_price Uint64
-increment_rate_each_bid Uint32
 started_at Uint64
-ended_at Uint64
-current_bidder_lock Script
-current_bid_price Uint64
-prev_bidder_profit_rate Uint32
 }
         
 
-func (s *AccountAuctionCellDataBuilder) Build() AccountAuctionCellData {
+func (s *AccountSaleCellDataV1Builder) Build() AccountSaleCellDataV1 {
     b := new(bytes.Buffer)
 
-    totalSize := HeaderSizeUint * (10 + 1)
-    offsets := make([]uint32, 0, 10)
+    totalSize := HeaderSizeUint * (5 + 1)
+    offsets := make([]uint32, 0, 5)
 
     offsets = append(offsets, totalSize)
 totalSize += uint32(len(s.account_id.AsSlice()))
 offsets = append(offsets, totalSize)
 totalSize += uint32(len(s.account.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.description.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.opening_price.AsSlice()))
+totalSize += uint32(len(s.price.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.increment_rate_each_bid.AsSlice()))
+totalSize += uint32(len(s.description.AsSlice()))
 offsets = append(offsets, totalSize)
 totalSize += uint32(len(s.started_at.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.ended_at.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.current_bidder_lock.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.current_bid_price.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.prev_bidder_profit_rate.AsSlice()))
 
     b.Write(packNumber(Number(totalSize)))
 
@@ -9253,220 +9215,155 @@ totalSize += uint32(len(s.prev_bidder_profit_rate.AsSlice()))
 
     b.Write(s.account_id.AsSlice())
 b.Write(s.account.AsSlice())
+b.Write(s.price.AsSlice())
 b.Write(s.description.AsSlice())
-b.Write(s.opening_price.AsSlice())
-b.Write(s.increment_rate_each_bid.AsSlice())
 b.Write(s.started_at.AsSlice())
-b.Write(s.ended_at.AsSlice())
-b.Write(s.current_bidder_lock.AsSlice())
-b.Write(s.current_bid_price.AsSlice())
-b.Write(s.prev_bidder_profit_rate.AsSlice())
-    return AccountAuctionCellData{inner: b.Bytes()}
+    return AccountSaleCellDataV1{inner: b.Bytes()}
 }
                 
 
-func (s *AccountAuctionCellDataBuilder) AccountId(v AccountId) *AccountAuctionCellDataBuilder {
+func (s *AccountSaleCellDataV1Builder) AccountId(v AccountId) *AccountSaleCellDataV1Builder {
     s.account_id = v
     return s
 }
             
 
-func (s *AccountAuctionCellDataBuilder) Account(v Bytes) *AccountAuctionCellDataBuilder {
+func (s *AccountSaleCellDataV1Builder) Account(v Bytes) *AccountSaleCellDataV1Builder {
     s.account = v
     return s
 }
             
 
-func (s *AccountAuctionCellDataBuilder) Description(v Bytes) *AccountAuctionCellDataBuilder {
-    s.description = v
-    return s
-}
-            
-
-func (s *AccountAuctionCellDataBuilder) OpeningPrice(v Uint64) *AccountAuctionCellDataBuilder {
-    s.opening_price = v
+func (s *AccountSaleCellDataV1Builder) Price(v Uint64) *AccountSaleCellDataV1Builder {
+    s.price = v
     return s
 }
             
 
-func (s *AccountAuctionCellDataBuilder) IncrementRateEachBid(v Uint32) *AccountAuctionCellDataBuilder {
-    s.increment_rate_each_bid = v
+func (s *AccountSaleCellDataV1Builder) Description(v Bytes) *AccountSaleCellDataV1Builder {
+    s.description = v
     return s
 }
             
 
-func (s *AccountAuctionCellDataBuilder) StartedAt(v Uint64) *AccountAuctionCellDataBuilder {
+func (s *AccountSaleCellDataV1Builder) StartedAt(v Uint64) *AccountSaleCellDataV1Builder {
     s.started_at = v
     return s
 }
             
 
-func (s *AccountAuctionCellDataBuilder) EndedAt(v Uint64) *AccountAuctionCellDataBuilder {
-    s.ended_at = v
-    return s
-}
-            
-
-func (s *AccountAuctionCellDataBuilder) CurrentBidderLock(v Script) *AccountAuctionCellDataBuilder {
-    s.current_bidder_lock = v
-    return s
-}
-            
-
-func (s *AccountAuctionCellDataBuilder) CurrentBidPrice(v Uint64) *AccountAuctionCellDataBuilder {
-    s.current_bid_price = v
-    return s
-}
-            
-
-func (s *AccountAuctionCellDataBuilder) PrevBidderProfitRate(v Uint32) *AccountAuctionCellDataBuilder {
-    s.prev_bidder_profit_rate = v
-    return s
-}
-            
-
-func NewAccountAuctionCellDataBuilder() *AccountAuctionCellDataBuilder {
-	return &AccountAuctionCellDataBuilder{ account_id: AccountIdDefault(),account: BytesDefault(),description: BytesDefault(),opening_price: Uint64Default(),increment_rate_each_bid: Uint32Default(),started_at: Uint64Default(),ended_at: Uint64Default(),current_bidder_lock: ScriptDefault(),current_bid_price: Uint64Default(),prev_bidder_profit_rate: Uint32Default() }
+func NewAccountSaleCellDataV1Builder() *AccountSaleCellDataV1Builder {
+	return &AccountSaleCellDataV1Builder{ account_id: AccountIdDefault(),account: BytesDefault(),price: Uint64Default(),description: BytesDefault(),started_at: Uint64Default() }
 }
     
 
-type AccountAuctionCellData struct {
+type AccountSaleCellDataV1 struct {
     inner []byte
 }
         
 
-func AccountAuctionCellDataFromSliceUnchecked(slice []byte) *AccountAuctionCellData {
-    return &AccountAuctionCellData{inner: slice}
+func AccountSaleCellDataV1FromSliceUnchecked(slice []byte) *AccountSaleCellDataV1 {
+    return &AccountSaleCellDataV1{inner: slice}
 }
-func (s *AccountAuctionCellData) AsSlice() []byte {
+func (s *AccountSaleCellDataV1) AsSlice() []byte {
     return s.inner
 }
             
 
-func AccountAuctionCellDataDefault() AccountAuctionCellData {
-    return *AccountAuctionCellDataFromSliceUnchecked([]byte{ 165,0,0,0,44,0,0,0,64,0,0,0,68,0,0,0,72,0,0,0,80,0,0,0,84,0,0,0,92,0,0,0,100,0,0,0,153,0,0,0,161,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,53,0,0,0,16,0,0,0,48,0,0,0,49,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+func AccountSaleCellDataV1Default() AccountSaleCellDataV1 {
+    return *AccountSaleCellDataV1FromSliceUnchecked([]byte{ 68,0,0,0,24,0,0,0,44,0,0,0,48,0,0,0,56,0,0,0,60,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
 }
             
 
-func AccountAuctionCellDataFromSlice(slice []byte, compatible bool) (*AccountAuctionCellData, error) {
+func AccountSaleCellDataV1FromSlice(slice []byte, compatible bool) (*AccountSaleCellDataV1, error) {
     sliceLen := len(slice)
     if uint32(sliceLen) < HeaderSizeUint {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "AccountAuctionCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "AccountSaleCellDataV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
         return nil, errors.New(errMsg)
     }
 
     totalSize := unpackNumber(slice)
     if Number(sliceLen) != totalSize {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "AccountAuctionCellData", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "AccountSaleCellDataV1", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if uint32(sliceLen) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "AccountAuctionCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "AccountSaleCellDataV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     offsetFirst := unpackNumber(slice[HeaderSizeUint:])
     if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"OffsetsNotMatch", "AccountAuctionCellData", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "AccountSaleCellDataV1", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if sliceLen < int(offsetFirst) {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "AccountAuctionCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "AccountSaleCellDataV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
         return nil, errors.New(errMsg)
     }
 
     fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
-    if fieldCount < 10 {
+    if fieldCount < 5 {
         return nil, errors.New("FieldCountNotMatch")
-    } else if !compatible && fieldCount > 10 {
+    } else if !compatible && fieldCount > 5 {
         return nil, errors.New("FieldCountNotMatch")
     }
 
-    offsets := make([]uint32, fieldCount)
-
-    for i := 0; i < int(fieldCount); i++ {
-        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
-    }
-    offsets = append(offsets, uint32(totalSize))
-
-    for i := 0; i < len(offsets); i++ {
-        if i&1 != 0 && offsets[i-1] > offsets[i] {
-            return nil, errors.New("OffsetsNotMatch")
-        }
-    }
-
-    var err error
-    
-_, err = AccountIdFromSlice(slice[offsets[0]:offsets[1]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = BytesFromSlice(slice[offsets[1]:offsets[2]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = BytesFromSlice(slice[offsets[2]:offsets[3]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = Uint64FromSlice(slice[offsets[3]:offsets[4]], compatible)
-if err != nil {
-    return nil, err
-}
-                
+    offsets := make([]uint32, fieldCount)
 
-_, err = Uint32FromSlice(slice[offsets[4]:offsets[5]], compatible)
-if err != nil {
-    return nil, err
-}
-                
+    for i := 0; i < int(fieldCount); i++ {
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    }
+    offsets = append(offsets, uint32(totalSize))
 
-_, err = Uint64FromSlice(slice[offsets[5]:offsets[6]], compatible)
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            return nil, errors.New("OffsetsNotMatch")
+        }
+    }
+
+    var err error
+    
+_, err = AccountIdFromSlice(slice[offsets[0]:offsets[1]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = Uint64FromSlice(slice[offsets[6]:offsets[7]], compatible)
+_, err = BytesFromSlice(slice[offsets[1]:offsets[2]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = ScriptFromSlice(slice[offsets[7]:offsets[8]], compatible)
+_, err = Uint64FromSlice(slice[offsets[2]:offsets[3]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = Uint64FromSlice(slice[offsets[8]:offsets[9]], compatible)
+_, err = BytesFromSlice(slice[offsets[3]:offsets[4]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = Uint32FromSlice(slice[offsets[9]:offsets[10]], compatible)
+_, err = Uint64FromSlice(slice[offsets[4]:offsets[5]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-    return &AccountAuctionCellData{inner: slice}, nil
+    return &AccountSaleCellDataV1{inner: slice}, nil
 }
             
 
-func (s *AccountAuctionCellData) TotalSize() uint {
+func (s *AccountSaleCellDataV1) TotalSize() uint {
     return uint(unpackNumber(s.inner))
 }
-func (s *AccountAuctionCellData) FieldCount() uint {
+func (s *AccountSaleCellDataV1) FieldCount() uint {
     var number uint = 0
     if uint32(s.TotalSize()) == HeaderSizeUint {
         return number
@@ -9474,143 +9371,96 @@ func (s *AccountAuctionCellData) FieldCount() uint {
     number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
     return number
 }
-func (s *AccountAuctionCellData) Len() uint {
+func (s *AccountSaleCellDataV1) Len() uint {
     return s.FieldCount()
 }
-func (s *AccountAuctionCellData) IsEmpty() bool {
+func (s *AccountSaleCellDataV1) IsEmpty() bool {
     return s.Len() == 0
 }
-func (s *AccountAuctionCellData) CountExtraFields() uint {
-    return s.FieldCount() - 10
+func (s *AccountSaleCellDataV1) CountExtraFields() uint {
+    return s.FieldCount() - 5
 }
 
-func (s *AccountAuctionCellData) HasExtraFields() bool {
-    return 10 != s.FieldCount()
+func (s *AccountSaleCellDataV1) HasExtraFields() bool {
+    return 5 != s.FieldCount()
 }
             
 
-func (s *AccountAuctionCellData) AccountId() *AccountId {
+func (s *AccountSaleCellDataV1) AccountId() *AccountId {
     start := unpackNumber(s.inner[4:])
     end := unpackNumber(s.inner[8:])
     return AccountIdFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *AccountAuctionCellData) Account() *Bytes {
+func (s *AccountSaleCellDataV1) Account() *Bytes {
     start := unpackNumber(s.inner[8:])
     end := unpackNumber(s.inner[12:])
     return BytesFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *AccountAuctionCellData) Description() *Bytes {
+func (s *AccountSaleCellDataV1) Price() *Uint64 {
     start := unpackNumber(s.inner[12:])
     end := unpackNumber(s.inner[16:])
-    return BytesFromSliceUnchecked(s.inner[start:end])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *AccountAuctionCellData) OpeningPrice() *Uint64 {
+func (s *AccountSaleCellDataV1) Description() *Bytes {
     start := unpackNumber(s.inner[16:])
     end := unpackNumber(s.inner[20:])
-    return Uint64FromSliceUnchecked(s.inner[start:end])
+    return BytesFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *AccountAuctionCellData) IncrementRateEachBid() *Uint32 {
+func (s *AccountSaleCellDataV1) StartedAt() *Uint64 {
+    var ret *Uint64
     start := unpackNumber(s.inner[20:])
-    end := unpackNumber(s.inner[24:])
-    return Uint32FromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *AccountAuctionCellData) StartedAt() *Uint64 {
-    start := unpackNumber(s.inner[24:])
-    end := unpackNumber(s.inner[28:])
-    return Uint64FromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *AccountAuctionCellData) EndedAt() *Uint64 {
-    start := unpackNumber(s.inner[28:])
-    end := unpackNumber(s.inner[32:])
-    return Uint64FromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *AccountAuctionCellData) CurrentBidderLock() *Script {
-    start := unpackNumber(s.inner[32:])
-    end := unpackNumber(s.inner[36:])
-    return ScriptFromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *AccountAuctionCellData) CurrentBidPrice() *Uint64 {
-    start := unpackNumber(s.inner[36:])
-    end := unpackNumber(s.inner[40:])
-    return Uint64FromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *AccountAuctionCellData) PrevBidderProfitRate() *Uint32 {
-    var ret *Uint32
-    start := unpackNumber(s.inner[40:])
     if s.HasExtraFields() {
-        end := unpackNumber(s.inner[44:])
-        ret = Uint32FromSliceUnchecked(s.inner[start:end])
+        end := unpackNumber(s.inner[24:])
+        ret = Uint64FromSliceUnchecked(s.inner[start:end])
     } else {
-        ret = Uint32FromSliceUnchecked(s.inner[start:])
+        ret = Uint64FromSliceUnchecked(s.inner[start:])
     }
     return ret
 }
                         
 
-func (s *AccountAuctionCellData) AsBuilder() AccountAuctionCellDataBuilder {
-    ret := NewAccountAuctionCellDataBuilder().AccountId(*s.AccountId()).Account(*s.Account()).Description(*s.Description()).OpeningPrice(*s.OpeningPrice()).IncrementRateEachBid(*s.IncrementRateEachBid()).StartedAt(*s.StartedAt()).EndedAt(*s.EndedAt()).CurrentBidderLock(*s.CurrentBidderLock()).CurrentBidPrice(*s.CurrentBidPrice()).PrevBidderProfitRate(*s.PrevBidderProfitRate())
+func (s *AccountSaleCellDataV1) AsBuilder() AccountSaleCellDataV1Builder {
+    ret := NewAccountSaleCellDataV1Builder().AccountId(*s.AccountId()).Account(*s.Account()).Price(*s.Price()).Description(*s.Description()).StartedAt(*s.StartedAt())
     return *ret
 }
         
 
-type PreAccountCellDataV1Builder struct {
-    account AccountChars
-refund_lock Script
-owner_lock_args Bytes
-inviter_id Bytes
-inviter_lock ScriptOpt
-channel_lock ScriptOpt
-price PriceConfig
-quote Uint64
-invited_discount Uint32
-created_at Uint64
+type AccountSaleCellDataBuilder struct {
+    account_id AccountId
+account Bytes
+price Uint64
+description Bytes
+started_at Uint64
+buyer_inviter_profit_rate Uint32
 }
         
 
-func (s *PreAccountCellDataV1Builder) Build() PreAccountCellDataV1 {
+func (s *AccountSaleCellDataBuilder) Build() AccountSaleCellData {
     b := new(bytes.Buffer)
 
-    totalSize := HeaderSizeUint * (10 + 1)
-    offsets := make([]uint32, 0, 10)
+    totalSize := HeaderSizeUint * (6 + 1)
+    offsets := make([]uint32, 0, 6)
 
     offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.account.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.refund_lock.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.owner_lock_args.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.inviter_id.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.inviter_lock.AsSlice()))
+totalSize += uint32(len(s.account_id.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.channel_lock.AsSlice()))
+totalSize += uint32(len(s.account.AsSlice()))
 offsets = append(offsets, totalSize)
 totalSize += uint32(len(s.price.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.quote.AsSlice()))
+totalSize += uint32(len(s.description.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.invited_discount.AsSlice()))
+totalSize += uint32(len(s.started_at.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.created_at.AsSlice()))
+totalSize += uint32(len(s.buyer_inviter_profit_rate.AsSlice()))
 
     b.Write(packNumber(Number(totalSize)))
 
@@ -9618,222 +9468,170 @@ totalSize += uint32(len(s.created_at.AsSlice()))
         b.Write(packNumber(Number(offsets[i])))
     }
 
-    b.Write(s.account.AsSlice())
-b.Write(s.refund_lock.AsSlice())
-b.Write(s.owner_lock_args.AsSlice())
-b.Write(s.inviter_id.AsSlice())
-b.Write(s.inviter_lock.AsSlice())
-b.Write(s.channel_lock.AsSlice())
+    b.Write(s.account_id.AsSlice())
+b.Write(s.account.AsSlice())
 b.Write(s.price.AsSlice())
-b.Write(s.quote.AsSlice())
-b.Write(s.invited_discount.AsSlice())
-b.Write(s.created_at.AsSlice())
-    return PreAccountCellDataV1{inner: b.Bytes()}
+b.Write(s.description.AsSlice())
+b.Write(s.started_at.AsSlice())
+b.Write(s.buyer_inviter_profit_rate.AsSlice())
+    return AccountSaleCellData{inner: b.Bytes()}
 }
                 
 
-func (s *PreAccountCellDataV1Builder) Account(v AccountChars) *PreAccountCellDataV1Builder {
-    s.account = v
-    return s
-}
-            
-
-func (s *PreAccountCellDataV1Builder) RefundLock(v Script) *PreAccountCellDataV1Builder {
-    s.refund_lock = v
-    return s
-}
-            
-
-func (s *PreAccountCellDataV1Builder) OwnerLockArgs(v Bytes) *PreAccountCellDataV1Builder {
-    s.owner_lock_args = v
-    return s
-}
-            
-
-func (s *PreAccountCellDataV1Builder) InviterId(v Bytes) *PreAccountCellDataV1Builder {
-    s.inviter_id = v
-    return s
-}
-            
-
-func (s *PreAccountCellDataV1Builder) InviterLock(v ScriptOpt) *PreAccountCellDataV1Builder {
-    s.inviter_lock = v
+func (s *AccountSaleCellDataBuilder) AccountId(v AccountId) *AccountSaleCellDataBuilder {
+    s.account_id = v
     return s
 }
             
 
-func (s *PreAccountCellDataV1Builder) ChannelLock(v ScriptOpt) *PreAccountCellDataV1Builder {
-    s.channel_lock = v
+func (s *AccountSaleCellDataBuilder) Account(v Bytes) *AccountSaleCellDataBuilder {
+    s.account = v
     return s
 }
             
 
-func (s *PreAccountCellDataV1Builder) Price(v PriceConfig) *PreAccountCellDataV1Builder {
+func (s *AccountSaleCellDataBuilder) Price(v Uint64) *AccountSaleCellDataBuilder {
     s.price = v
     return s
 }
             
 
-func (s *PreAccountCellDataV1Builder) Quote(v Uint64) *PreAccountCellDataV1Builder {
-    s.quote = v
+func (s *AccountSaleCellDataBuilder) Description(v Bytes) *AccountSaleCellDataBuilder {
+    s.description = v
     return s
 }
             
 
-func (s *PreAccountCellDataV1Builder) InvitedDiscount(v Uint32) *PreAccountCellDataV1Builder {
-    s.invited_discount = v
+func (s *AccountSaleCellDataBuilder) StartedAt(v Uint64) *AccountSaleCellDataBuilder {
+    s.started_at = v
     return s
 }
             
 
-func (s *PreAccountCellDataV1Builder) CreatedAt(v Uint64) *PreAccountCellDataV1Builder {
-    s.created_at = v
+func (s *AccountSaleCellDataBuilder) BuyerInviterProfitRate(v Uint32) *AccountSaleCellDataBuilder {
+    s.buyer_inviter_profit_rate = v
     return s
 }
             
 
-func NewPreAccountCellDataV1Builder() *PreAccountCellDataV1Builder {
-	return &PreAccountCellDataV1Builder{ account: AccountCharsDefault(),refund_lock: ScriptDefault(),owner_lock_args: BytesDefault(),inviter_id: BytesDefault(),inviter_lock: ScriptOptDefault(),channel_lock: ScriptOptDefault(),price: PriceConfigDefault(),quote: Uint64Default(),invited_discount: Uint32Default(),created_at: Uint64Default() }
+func NewAccountSaleCellDataBuilder() *AccountSaleCellDataBuilder {
+	return &AccountSaleCellDataBuilder{ account_id: AccountIdDefault(),account: BytesDefault(),price: Uint64Default(),description: BytesDefault(),started_at: Uint64Default(),buyer_inviter_profit_rate: Uint32Default() }
 }
     
 
-type PreAccountCellDataV1 struct {
+type AccountSaleCellData struct {
     inner []byte
 }
         
 
-func PreAccountCellDataV1FromSliceUnchecked(slice []byte) *PreAccountCellDataV1 {
-    return &PreAccountCellDataV1{inner: slice}
+func AccountSaleCellDataFromSliceUnchecked(slice []byte) *AccountSaleCellData {
+    return &AccountSaleCellData{inner: slice}
 }
-func (s *PreAccountCellDataV1) AsSlice() []byte {
+func (s *AccountSaleCellData) AsSlice() []byte {
     return s.inner
 }
             
 
-func PreAccountCellDataV1Default() PreAccountCellDataV1 {
-    return *PreAccountCellDataV1FromSliceUnchecked([]byte{ 162,0,0,0,44,0,0,0,48,0,0,0,101,0,0,0,105,0,0,0,109,0,0,0,109,0,0,0,109,0,0,0,142,0,0,0,150,0,0,0,154,0,0,0,4,0,0,0,53,0,0,0,16,0,0,0,48,0,0,0,49,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,33,0,0,0,16,0,0,0,17,0,0,0,25,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+func AccountSaleCellDataDefault() AccountSaleCellData {
+    return *AccountSaleCellDataFromSliceUnchecked([]byte{ 76,0,0,0,28,0,0,0,48,0,0,0,52,0,0,0,60,0,0,0,64,0,0,0,72,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
 }
             
 
-func PreAccountCellDataV1FromSlice(slice []byte, compatible bool) (*PreAccountCellDataV1, error) {
+func AccountSaleCellDataFromSlice(slice []byte, compatible bool) (*AccountSaleCellData, error) {
     sliceLen := len(slice)
     if uint32(sliceLen) < HeaderSizeUint {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "PreAccountCellDataV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "AccountSaleCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
         return nil, errors.New(errMsg)
     }
 
     totalSize := unpackNumber(slice)
     if Number(sliceLen) != totalSize {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "PreAccountCellDataV1", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "AccountSaleCellData", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if uint32(sliceLen) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "PreAccountCellDataV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "AccountSaleCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     offsetFirst := unpackNumber(slice[HeaderSizeUint:])
     if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"OffsetsNotMatch", "PreAccountCellDataV1", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "AccountSaleCellData", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if sliceLen < int(offsetFirst) {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "PreAccountCellDataV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "AccountSaleCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
         return nil, errors.New(errMsg)
     }
 
     fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
-    if fieldCount < 10 {
+    if fieldCount < 6 {
         return nil, errors.New("FieldCountNotMatch")
-    } else if !compatible && fieldCount > 10 {
+    } else if !compatible && fieldCount > 6 {
         return nil, errors.New("FieldCountNotMatch")
     }
 
     offsets := make([]uint32, fieldCount)
 
     for i := 0; i < int(fieldCount); i++ {
-        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
-    }
-    offsets = append(offsets, uint32(totalSize))
-
-    for i := 0; i < len(offsets); i++ {
-        if i&1 != 0 && offsets[i-1] > offsets[i] {
-            return nil, errors.New("OffsetsNotMatch")
-        }
-    }
-
-    var err error
-    
-_, err = AccountCharsFromSlice(slice[offsets[0]:offsets[1]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = ScriptFromSlice(slice[offsets[1]:offsets[2]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = BytesFromSlice(slice[offsets[2]:offsets[3]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = BytesFromSlice(slice[offsets[3]:offsets[4]], compatible)
-if err != nil {
-    return nil, err
-}
-                
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    }
+    offsets = append(offsets, uint32(totalSize))
 
-_, err = ScriptOptFromSlice(slice[offsets[4]:offsets[5]], compatible)
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            return nil, errors.New("OffsetsNotMatch")
+        }
+    }
+
+    var err error
+    
+_, err = AccountIdFromSlice(slice[offsets[0]:offsets[1]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = ScriptOptFromSlice(slice[offsets[5]:offsets[6]], compatible)
+_, err = BytesFromSlice(slice[offsets[1]:offsets[2]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = PriceConfigFromSlice(slice[offsets[6]:offsets[7]], compatible)
+_, err = Uint64FromSlice(slice[offsets[2]:offsets[3]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = Uint64FromSlice(slice[offsets[7]:offsets[8]], compatible)
+_, err = BytesFromSlice(slice[offsets[3]:offsets[4]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = Uint32FromSlice(slice[offsets[8]:offsets[9]], compatible)
+_, err = Uint64FromSlice(slice[offsets[4]:offsets[5]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = Uint64FromSlice(slice[offsets[9]:offsets[10]], compatible)
+_, err = Uint32FromSlice(slice[offsets[5]:offsets[6]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-    return &PreAccountCellDataV1{inner: slice}, nil
+    return &AccountSaleCellData{inner: slice}, nil
 }
             
 
-func (s *PreAccountCellDataV1) TotalSize() uint {
+func (s *AccountSaleCellData) TotalSize() uint {
     return uint(unpackNumber(s.inner))
 }
-func (s *PreAccountCellDataV1) FieldCount() uint {
+func (s *AccountSaleCellData) FieldCount() uint {
     var number uint = 0
     if uint32(s.TotalSize()) == HeaderSizeUint {
         return number
@@ -9841,146 +9639,115 @@ func (s *PreAccountCellDataV1) FieldCount() uint {
     number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
     return number
 }
-func (s *PreAccountCellDataV1) Len() uint {
+func (s *AccountSaleCellData) Len() uint {
     return s.FieldCount()
 }
-func (s *PreAccountCellDataV1) IsEmpty() bool {
+func (s *AccountSaleCellData) IsEmpty() bool {
     return s.Len() == 0
 }
-func (s *PreAccountCellDataV1) CountExtraFields() uint {
-    return s.FieldCount() - 10
+func (s *AccountSaleCellData) CountExtraFields() uint {
+    return s.FieldCount() - 6
 }
 
-func (s *PreAccountCellDataV1) HasExtraFields() bool {
-    return 10 != s.FieldCount()
+func (s *AccountSaleCellData) HasExtraFields() bool {
+    return 6 != s.FieldCount()
 }
             
 
-func (s *PreAccountCellDataV1) Account() *AccountChars {
+func (s *AccountSaleCellData) AccountId() *AccountId {
     start := unpackNumber(s.inner[4:])
     end := unpackNumber(s.inner[8:])
-    return AccountCharsFromSliceUnchecked(s.inner[start:end])
+    return AccountIdFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *PreAccountCellDataV1) RefundLock() *Script {
+func (s *AccountSaleCellData) Account() *Bytes {
     start := unpackNumber(s.inner[8:])
     end := unpackNumber(s.inner[12:])
-    return ScriptFromSliceUnchecked(s.inner[start:end])
+    return BytesFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *PreAccountCellDataV1) OwnerLockArgs() *Bytes {
+func (s *AccountSaleCellData) Price() *Uint64 {
     start := unpackNumber(s.inner[12:])
     end := unpackNumber(s.inner[16:])
-    return BytesFromSliceUnchecked(s.inner[start:end])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *PreAccountCellDataV1) InviterId() *Bytes {
+func (s *AccountSaleCellData) Description() *Bytes {
     start := unpackNumber(s.inner[16:])
     end := unpackNumber(s.inner[20:])
     return BytesFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *PreAccountCellDataV1) InviterLock() *ScriptOpt {
+func (s *AccountSaleCellData) StartedAt() *Uint64 {
     start := unpackNumber(s.inner[20:])
     end := unpackNumber(s.inner[24:])
-    return ScriptOptFromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *PreAccountCellDataV1) ChannelLock() *ScriptOpt {
-    start := unpackNumber(s.inner[24:])
-    end := unpackNumber(s.inner[28:])
-    return ScriptOptFromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *PreAccountCellDataV1) Price() *PriceConfig {
-    start := unpackNumber(s.inner[28:])
-    end := unpackNumber(s.inner[32:])
-    return PriceConfigFromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *PreAccountCellDataV1) Quote() *Uint64 {
-    start := unpackNumber(s.inner[32:])
-    end := unpackNumber(s.inner[36:])
     return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *PreAccountCellDataV1) InvitedDiscount() *Uint32 {
-    start := unpackNumber(s.inner[36:])
-    end := unpackNumber(s.inner[40:])
-    return Uint32FromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *PreAccountCellDataV1) CreatedAt() *Uint64 {
-    var ret *Uint64
-    start := unpackNumber(s.inner[40:])
+func (s *AccountSaleCellData) BuyerInviterProfitRate() *Uint32 {
+    var ret *Uint32
+    start := unpackNumber(s.inner[24:])
     if s.HasExtraFields() {
-        end := unpackNumber(s.inner[44:])
-        ret = Uint64FromSliceUnchecked(s.inner[start:end])
+        end := unpackNumber(s.inner[28:])
+        ret = Uint32FromSliceUnchecked(s.inner[start:end])
     } else {
-        ret = Uint64FromSliceUnchecked(s.inner[start:])
+        ret = Uint32FromSliceUnchecked(s.inner[start:])
     }
     return ret
 }
                         
 
-func (s *PreAccountCellDataV1) AsBuilder() PreAccountCellDataV1Builder {
-    ret := NewPreAccountCellDataV1Builder().Account(*s.Account()).RefundLock(*s.RefundLock()).OwnerLockArgs(*s.OwnerLockArgs()).InviterId(*s.InviterId()).InviterLock(*s.InviterLock()).ChannelLock(*s.ChannelLock()).Price(*s.Price()).Quote(*s.Quote()).InvitedDiscount(*s.InvitedDiscount()).CreatedAt(*s.CreatedAt())
+func (s *AccountSaleCellData) AsBuilder() AccountSaleCellDataBuilder {
+    ret := NewAccountSaleCellDataBuilder().AccountId(*s.AccountId()).Account(*s.Account()).Price(*s.Price()).Description(*s.Description()).StartedAt(*s.StartedAt()).BuyerInviterProfitRate(*s.BuyerInviterProfitRate())
     return *ret
 }
         
 
-type PreAccountCellDataV2Builder struct {
-    account AccountChars
-refund_lock Script
-owner_lock_args Bytes
-inviter_id Bytes
-inviter_lock ScriptOpt
-channel_lock ScriptOpt
-price PriceConfig
-quote Uint64
-invited_discount Uint32
-created_at Uint64
-initial_records Records
+type AccountAuctionCellDataBuilder struct {
+    account_id AccountId
+account Bytes
+description Bytes
+opening_price Uint64
+increment_rate_each_bid Uint32
+started_at Uint64
+ended_at Uint64
+current_bidder_lock Script
+current_bid_price Uint64
+prev_bidder_profit_rate Uint32
 }
         
 
-func (s *PreAccountCellDataV2Builder) Build() PreAccountCellDataV2 {
+func (s *AccountAuctionCellDataBuilder) Build() AccountAuctionCellData {
     b := new(bytes.Buffer)
 
-    totalSize := HeaderSizeUint * (11 + 1)
-    offsets := make([]uint32, 0, 11)
+    totalSize := HeaderSizeUint * (10 + 1)
+    offsets := make([]uint32, 0, 10)
 
     offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.account.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.refund_lock.AsSlice()))
+totalSize += uint32(len(s.account_id.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.owner_lock_args.AsSlice()))
+totalSize += uint32(len(s.account.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.inviter_id.AsSlice()))
+totalSize += uint32(len(s.description.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.inviter_lock.AsSlice()))
+totalSize += uint32(len(s.opening_price.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.channel_lock.AsSlice()))
+totalSize += uint32(len(s.increment_rate_each_bid.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.price.AsSlice()))
+totalSize += uint32(len(s.started_at.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.quote.AsSlice()))
+totalSize += uint32(len(s.ended_at.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.invited_discount.AsSlice()))
+totalSize += uint32(len(s.current_bidder_lock.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.created_at.AsSlice()))
+totalSize += uint32(len(s.current_bid_price.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.initial_records.AsSlice()))
+totalSize += uint32(len(s.prev_bidder_profit_rate.AsSlice()))
 
     b.Write(packNumber(Number(totalSize)))
 
@@ -9988,143 +9755,136 @@ totalSize += uint32(len(s.initial_records.AsSlice()))
         b.Write(packNumber(Number(offsets[i])))
     }
 
-    b.Write(s.account.AsSlice())
-b.Write(s.refund_lock.AsSlice())
-b.Write(s.owner_lock_args.AsSlice())
-b.Write(s.inviter_id.AsSlice())
-b.Write(s.inviter_lock.AsSlice())
-b.Write(s.channel_lock.AsSlice())
-b.Write(s.price.AsSlice())
-b.Write(s.quote.AsSlice())
-b.Write(s.invited_discount.AsSlice())
-b.Write(s.created_at.AsSlice())
-b.Write(s.initial_records.AsSlice())
-    return PreAccountCellDataV2{inner: b.Bytes()}
+    b.Write(s.account_id.AsSlice())
+b.Write(s.account.AsSlice())
+b.Write(s.description.AsSlice())
+b.Write(s.opening_price.AsSlice())
+b.Write(s.increment_rate_each_bid.AsSlice())
+b.Write(s.started_at.AsSlice())
+b.Write(s.ended_at.AsSlice())
+b.Write(s.current_bidder_lock.AsSlice())
+b.Write(s.current_bid_price.AsSlice())
+b.Write(s.prev_bidder_profit_rate.AsSlice())
+    return AccountAuctionCellData{inner: b.Bytes()}
 }
                 
 
-func (s *PreAccountCellDataV2Builder) Account(v AccountChars) *PreAccountCellDataV2Builder {
-    s.account = v
-    return s
-}
-            
-
-func (s *PreAccountCellDataV2Builder) RefundLock(v Script) *PreAccountCellDataV2Builder {
-    s.refund_lock = v
+func (s *AccountAuctionCellDataBuilder) AccountId(v AccountId) *AccountAuctionCellDataBuilder {
+    s.account_id = v
     return s
 }
             
 
-func (s *PreAccountCellDataV2Builder) OwnerLockArgs(v Bytes) *PreAccountCellDataV2Builder {
-    s.owner_lock_args = v
+func (s *AccountAuctionCellDataBuilder) Account(v Bytes) *AccountAuctionCellDataBuilder {
+    s.account = v
     return s
 }
             
 
-func (s *PreAccountCellDataV2Builder) InviterId(v Bytes) *PreAccountCellDataV2Builder {
-    s.inviter_id = v
+func (s *AccountAuctionCellDataBuilder) Description(v Bytes) *AccountAuctionCellDataBuilder {
+    s.description = v
     return s
 }
             
 
-func (s *PreAccountCellDataV2Builder) InviterLock(v ScriptOpt) *PreAccountCellDataV2Builder {
-    s.inviter_lock = v
+func (s *AccountAuctionCellDataBuilder) OpeningPrice(v Uint64) *AccountAuctionCellDataBuilder {
+    s.opening_price = v
     return s
 }
             
 
-func (s *PreAccountCellDataV2Builder) ChannelLock(v ScriptOpt) *PreAccountCellDataV2Builder {
-    s.channel_lock = v
+func (s *AccountAuctionCellDataBuilder) IncrementRateEachBid(v Uint32) *AccountAuctionCellDataBuilder {
+    s.increment_rate_each_bid = v
     return s
 }
             
 
-func (s *PreAccountCellDataV2Builder) Price(v PriceConfig) *PreAccountCellDataV2Builder {
-    s.price = v
+func (s *AccountAuctionCellDataBuilder) StartedAt(v Uint64) *AccountAuctionCellDataBuilder {
+    s.started_at = v
     return s
 }
             
 
-func (s *PreAccountCellDataV2Builder) Quote(v Uint64) *PreAccountCellDataV2Builder {
-    s.quote = v
+func (s *AccountAuctionCellDataBuilder) EndedAt(v Uint64) *AccountAuctionCellDataBuilder {
+    s.ended_at = v
     return s
 }
             
 
-func (s *PreAccountCellDataV2Builder) InvitedDiscount(v Uint32) *PreAccountCellDataV2Builder {
-    s.invited_discount = v
+func (s *AccountAuctionCellDataBuilder) CurrentBidderLock(v Script) *AccountAuctionCellDataBuilder {
+    s.current_bidder_lock = v
     return s
 }
             
 
-func (s *PreAccountCellDataV2Builder) CreatedAt(v Uint64) *PreAccountCellDataV2Builder {
-    s.created_at = v
+func (s *AccountAuctionCellDataBuilder) CurrentBidPrice(v Uint64) *AccountAuctionCellDataBuilder {
+    s.current_bid_price = v
     return s
 }
             
 
-func (s *PreAccountCellDataV2Builder) InitialRecords(v Records) *PreAccountCellDataV2Builder {
-    s.initial_records = v
+func (s *AccountAuctionCellDataBuilder) PrevBidderProfitRate(v Uint32) *AccountAuctionCellDataBuilder {
+    s.prev_bidder_profit_rate = v
     return s
 }
             
 
-func NewPreAccountCellDataV2Builder() *PreAccountCellDataV2Builder {
-	return &PreAccountCellDataV2Builder{ account: AccountCharsDefault(),refund_lock: ScriptDefault(),owner_lock_args: BytesDefault(),inviter_id: BytesDefault(),inviter_lock: ScriptOptDefault(),channel_lock: ScriptOptDefault(),price: PriceConfigDefault(),quote: Uint64Default(),invited_discount: Uint32Default(),created_at: Uint64Default(),initial_records: RecordsDefault() }
+func NewAccountAuctionCellDataBuilder() *AccountAuctionCellDataBuilder {
+	return &AccountAuctionCellDataBuilder{ account_id: AccountIdDefault(),account: BytesDefault(),description: BytesDefault(),opening_price: Uint64Default(),increment_rate_each_bid: Uint32Default(),started_at: Uint64Default(),ended_at: Uint64Default(),current_bidder_lock: ScriptDefault(),current_bid_price: Uint64Default(),prev_bidder_profit_rate: Uint32Default() }
 }
     
 
-type PreAccountCellDataV2 struct {
+type AccountAuctionCellData struct {
     inner []byte
 }
         
 
-func PreAccountCellDataV2FromSliceUnchecked(slice []byte) *PreAccountCellDataV2 {
-    return &PreAccountCellDataV2{inner: slice}
+func AccountAuctionCellDataFromSliceUnchecked(slice []byte) *AccountAuctionCellData {
+    return &AccountAuctionCellData{inner: slice}
 }
-func (s *PreAccountCellDataV2) AsSlice() []byte {
+func (s *AccountAuctionCellData) AsSlice() []byte {
     return s.inner
 }
             
 
-func PreAccountCellDataV2Default() PreAccountCellDataV2 {
-    return *PreAccountCellDataV2FromSliceUnchecked([]byte{ 170,0,0,0,48,0,0,0,52,0,0,0,105,0,0,0,109,0,0,0,113,0,0,0,113,0,0,0,113,0,0,0,146,0,0,0,154,0,0,0,158,0,0,0,166,0,0,0,4,0,0,0,53,0,0,0,16,0,0,0,48,0,0,0,49,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,33,0,0,0,16,0,0,0,17,0,0,0,25,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0 })
+func AccountAuctionCellDataDefault() AccountAuctionCellData {
+    return *AccountAuctionCellDataFromSliceUnchecked([]byte{ 165,0,0,0,44,0,0,0,64,0,0,0,68,0,0,0,72,0,0,0,80,0,0,0,84,0,0,0,92,0,0,0,100,0,0,0,153,0,0,0,161,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,53,0,0,0,16,0,0,0,48,0,0,0,49,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
 }
             
 
-func PreAccountCellDataV2FromSlice(slice []byte, compatible bool) (*PreAccountCellDataV2, error) {
+func AccountAuctionCellDataFromSlice(slice []byte, compatible bool) (*AccountAuctionCellData, error) {
     sliceLen := len(slice)
     if uint32(sliceLen) < HeaderSizeUint {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "PreAccountCellDataV2", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "AccountAuctionCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
         return nil, errors.New(errMsg)
     }
 
     totalSize := unpackNumber(slice)
     if Number(sliceLen) != totalSize {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "PreAccountCellDataV2", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "AccountAuctionCellData", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if uint32(sliceLen) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "PreAccountCellDataV2", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "AccountAuctionCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     offsetFirst := unpackNumber(slice[HeaderSizeUint:])
     if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"OffsetsNotMatch", "PreAccountCellDataV2", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "AccountAuctionCellData", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if sliceLen < int(offsetFirst) {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "PreAccountCellDataV2", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "AccountAuctionCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
         return nil, errors.New(errMsg)
     }
 
     fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
-    if fieldCount < 11 {
+    if fieldCount < 10 {
         return nil, errors.New("FieldCountNotMatch")
-    } else if !compatible && fieldCount > 11 {
+    } else if !compatible && fieldCount > 10 {
         return nil, errors.New("FieldCountNotMatch")
     }
 
@@ -10143,13 +9903,13 @@ func PreAccountCellDataV2FromSlice(slice []byte, compatible bool) (*PreAccountCe
 
     var err error
     
-_, err = AccountCharsFromSlice(slice[offsets[0]:offsets[1]], compatible)
+_, err = AccountIdFromSlice(slice[offsets[0]:offsets[1]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = ScriptFromSlice(slice[offsets[1]:offsets[2]], compatible)
+_, err = BytesFromSlice(slice[offsets[1]:offsets[2]], compatible)
 if err != nil {
     return nil, err
 }
@@ -10161,62 +9921,56 @@ if err != nil {
 }
                 
 
-_, err = BytesFromSlice(slice[offsets[3]:offsets[4]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = ScriptOptFromSlice(slice[offsets[4]:offsets[5]], compatible)
+_, err = Uint64FromSlice(slice[offsets[3]:offsets[4]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = ScriptOptFromSlice(slice[offsets[5]:offsets[6]], compatible)
+_, err = Uint32FromSlice(slice[offsets[4]:offsets[5]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = PriceConfigFromSlice(slice[offsets[6]:offsets[7]], compatible)
+_, err = Uint64FromSlice(slice[offsets[5]:offsets[6]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = Uint64FromSlice(slice[offsets[7]:offsets[8]], compatible)
+_, err = Uint64FromSlice(slice[offsets[6]:offsets[7]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = Uint32FromSlice(slice[offsets[8]:offsets[9]], compatible)
+_, err = ScriptFromSlice(slice[offsets[7]:offsets[8]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = Uint64FromSlice(slice[offsets[9]:offsets[10]], compatible)
+_, err = Uint64FromSlice(slice[offsets[8]:offsets[9]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = RecordsFromSlice(slice[offsets[10]:offsets[11]], compatible)
+_, err = Uint32FromSlice(slice[offsets[9]:offsets[10]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-    return &PreAccountCellDataV2{inner: slice}, nil
+    return &AccountAuctionCellData{inner: slice}, nil
 }
             
 
-func (s *PreAccountCellDataV2) TotalSize() uint {
+func (s *AccountAuctionCellData) TotalSize() uint {
     return uint(unpackNumber(s.inner))
 }
-func (s *PreAccountCellDataV2) FieldCount() uint {
+func (s *AccountAuctionCellData) FieldCount() uint {
     var number uint = 0
     if uint32(s.TotalSize()) == HeaderSizeUint {
         return number
@@ -10224,106 +9978,99 @@ func (s *PreAccountCellDataV2) FieldCount() uint {
     number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
     return number
 }
-func (s *PreAccountCellDataV2) Len() uint {
+func (s *AccountAuctionCellData) Len() uint {
     return s.FieldCount()
 }
-func (s *PreAccountCellDataV2) IsEmpty() bool {
+func (s *AccountAuctionCellData) IsEmpty() bool {
     return s.Len() == 0
 }
-func (s *PreAccountCellDataV2) CountExtraFields() uint {
-    return s.FieldCount() - 11
+func (s *AccountAuctionCellData) CountExtraFields() uint {
+    return s.FieldCount() - 10
 }
 
-func (s *PreAccountCellDataV2) HasExtraFields() bool {
-    return 11 != s.FieldCount()
+func (s *AccountAuctionCellData) HasExtraFields() bool {
+    return 10 != s.FieldCount()
 }
             
 
-func (s *PreAccountCellDataV2) Account() *AccountChars {
+func (s *AccountAuctionCellData) AccountId() *AccountId {
     start := unpackNumber(s.inner[4:])
     end := unpackNumber(s.inner[8:])
-    return AccountCharsFromSliceUnchecked(s.inner[start:end])
+    return AccountIdFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *PreAccountCellDataV2) RefundLock() *Script {
+func (s *AccountAuctionCellData) Account() *Bytes {
     start := unpackNumber(s.inner[8:])
     end := unpackNumber(s.inner[12:])
-    return ScriptFromSliceUnchecked(s.inner[start:end])
+    return BytesFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *PreAccountCellDataV2) OwnerLockArgs() *Bytes {
+func (s *AccountAuctionCellData) Description() *Bytes {
     start := unpackNumber(s.inner[12:])
     end := unpackNumber(s.inner[16:])
     return BytesFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *PreAccountCellDataV2) InviterId() *Bytes {
+func (s *AccountAuctionCellData) OpeningPrice() *Uint64 {
     start := unpackNumber(s.inner[16:])
     end := unpackNumber(s.inner[20:])
-    return BytesFromSliceUnchecked(s.inner[start:end])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *PreAccountCellDataV2) InviterLock() *ScriptOpt {
+func (s *AccountAuctionCellData) IncrementRateEachBid() *Uint32 {
     start := unpackNumber(s.inner[20:])
     end := unpackNumber(s.inner[24:])
-    return ScriptOptFromSliceUnchecked(s.inner[start:end])
+    return Uint32FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *PreAccountCellDataV2) ChannelLock() *ScriptOpt {
+func (s *AccountAuctionCellData) StartedAt() *Uint64 {
     start := unpackNumber(s.inner[24:])
     end := unpackNumber(s.inner[28:])
-    return ScriptOptFromSliceUnchecked(s.inner[start:end])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *PreAccountCellDataV2) Price() *PriceConfig {
+func (s *AccountAuctionCellData) EndedAt() *Uint64 {
     start := unpackNumber(s.inner[28:])
     end := unpackNumber(s.inner[32:])
-    return PriceConfigFromSliceUnchecked(s.inner[start:end])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *PreAccountCellDataV2) Quote() *Uint64 {
+func (s *AccountAuctionCellData) CurrentBidderLock() *Script {
     start := unpackNumber(s.inner[32:])
     end := unpackNumber(s.inner[36:])
-    return Uint64FromSliceUnchecked(s.inner[start:end])
+    return ScriptFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *PreAccountCellDataV2) InvitedDiscount() *Uint32 {
+func (s *AccountAuctionCellData) CurrentBidPrice() *Uint64 {
     start := unpackNumber(s.inner[36:])
     end := unpackNumber(s.inner[40:])
-    return Uint32FromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *PreAccountCellDataV2) CreatedAt() *Uint64 {
-    start := unpackNumber(s.inner[40:])
-    end := unpackNumber(s.inner[44:])
     return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *PreAccountCellDataV2) InitialRecords() *Records {
-    var ret *Records
-    start := unpackNumber(s.inner[44:])
+func (s *AccountAuctionCellData) PrevBidderProfitRate() *Uint32 {
+    var ret *Uint32
+    start := unpackNumber(s.inner[40:])
     if s.HasExtraFields() {
-        end := unpackNumber(s.inner[48:])
-        ret = RecordsFromSliceUnchecked(s.inner[start:end])
+        end := unpackNumber(s.inner[44:])
+        ret = Uint32FromSliceUnchecked(s.inner[start:end])
     } else {
-        ret = RecordsFromSliceUnchecked(s.inner[start:])
+        ret = Uint32FromSliceUnchecked(s.inner[start:])
     }
     return ret
 }
                         
 
-func (s *PreAccountCellDataV2) AsBuilder() PreAccountCellDataV2Builder {
-    ret := NewPreAccountCellDataV2Builder().Account(*s.Account()).RefundLock(*s.RefundLock()).OwnerLockArgs(*s.OwnerLockArgs()).InviterId(*s.InviterId()).InviterLock(*s.InviterLock()).ChannelLock(*s.ChannelLock()).Price(*s.Price()).Quote(*s.Quote()).InvitedDiscount(*s.InvitedDiscount()).CreatedAt(*s.CreatedAt()).InitialRecords(*s.InitialRecords())
+func (s *AccountAuctionCellData) AsBuilder() AccountAuctionCellDataBuilder {
+    ret := NewAccountAuctionCellDataBuilder().AccountId(*s.AccountId()).Account(*s.Account()).Description(*s.Description()).OpeningPrice(*s.OpeningPrice()).IncrementRateEachBid(*s.IncrementRateEachBid()).StartedAt(*s.StartedAt()).EndedAt(*s.EndedAt()).CurrentBidderLock(*s.CurrentBidderLock()).CurrentBidPrice(*s.CurrentBidPrice()).PrevBidderProfitRate(*s.PrevBidderProfitRate())
     return *ret
 }
         
@@ -11259,28 +11006,259 @@ func AccountCharFromSlice(slice []byte, compatible bool) (*AccountChar, error) {
         }
     }
 
-    var err error
-    
-_, err = Uint32FromSlice(slice[offsets[0]:offsets[1]], compatible)
+    var err error
+    
+_, err = Uint32FromSlice(slice[offsets[0]:offsets[1]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = BytesFromSlice(slice[offsets[1]:offsets[2]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+    return &AccountChar{inner: slice}, nil
+}
+            
+
+func (s *AccountChar) TotalSize() uint {
+    return uint(unpackNumber(s.inner))
+}
+func (s *AccountChar) FieldCount() uint {
+    var number uint = 0
+    if uint32(s.TotalSize()) == HeaderSizeUint {
+        return number
+    }
+    number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
+    return number
+}
+func (s *AccountChar) Len() uint {
+    return s.FieldCount()
+}
+func (s *AccountChar) IsEmpty() bool {
+    return s.Len() == 0
+}
+func (s *AccountChar) CountExtraFields() uint {
+    return s.FieldCount() - 2
+}
+
+func (s *AccountChar) HasExtraFields() bool {
+    return 2 != s.FieldCount()
+}
+            
+
+func (s *AccountChar) CharSetName() *Uint32 {
+    start := unpackNumber(s.inner[4:])
+    end := unpackNumber(s.inner[8:])
+    return Uint32FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *AccountChar) Bytes() *Bytes {
+    var ret *Bytes
+    start := unpackNumber(s.inner[8:])
+    if s.HasExtraFields() {
+        end := unpackNumber(s.inner[12:])
+        ret = BytesFromSliceUnchecked(s.inner[start:end])
+    } else {
+        ret = BytesFromSliceUnchecked(s.inner[start:])
+    }
+    return ret
+}
+                        
+
+func (s *AccountChar) AsBuilder() AccountCharBuilder {
+    ret := NewAccountCharBuilder().CharSetName(*s.CharSetName()).Bytes(*s.Bytes())
+    return *ret
+}
+        
+
+type OfferCellDataBuilder struct {
+    account Bytes
+price Uint64
+message Bytes
+inviter_lock Script
+channel_lock Script
+}
+        
+
+func (s *OfferCellDataBuilder) Build() OfferCellData {
+    b := new(bytes.Buffer)
+
+    totalSize := HeaderSizeUint * (5 + 1)
+    offsets := make([]uint32, 0, 5)
+
+    offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.account.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.price.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.message.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.inviter_lock.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.channel_lock.AsSlice()))
+
+    b.Write(packNumber(Number(totalSize)))
+
+    for i := 0; i < len(offsets); i++ {
+        b.Write(packNumber(Number(offsets[i])))
+    }
+
+    b.Write(s.account.AsSlice())
+b.Write(s.price.AsSlice())
+b.Write(s.message.AsSlice())
+b.Write(s.inviter_lock.AsSlice())
+b.Write(s.channel_lock.AsSlice())
+    return OfferCellData{inner: b.Bytes()}
+}
+                
+
+func (s *OfferCellDataBuilder) Account(v Bytes) *OfferCellDataBuilder {
+    s.account = v
+    return s
+}
+            
+
+func (s *OfferCellDataBuilder) Price(v Uint64) *OfferCellDataBuilder {
+    s.price = v
+    return s
+}
+            
+
+func (s *OfferCellDataBuilder) Message(v Bytes) *OfferCellDataBuilder {
+    s.message = v
+    return s
+}
+            
+
+func (s *OfferCellDataBuilder) InviterLock(v Script) *OfferCellDataBuilder {
+    s.inviter_lock = v
+    return s
+}
+            
+
+func (s *OfferCellDataBuilder) ChannelLock(v Script) *OfferCellDataBuilder {
+    s.channel_lock = v
+    return s
+}
+            
+
+func NewOfferCellDataBuilder() *OfferCellDataBuilder {
+	return &OfferCellDataBuilder{ account: BytesDefault(),price: Uint64Default(),message: BytesDefault(),inviter_lock: ScriptDefault(),channel_lock: ScriptDefault() }
+}
+    
+
+type OfferCellData struct {
+    inner []byte
+}
+        
+
+func OfferCellDataFromSliceUnchecked(slice []byte) *OfferCellData {
+    return &OfferCellData{inner: slice}
+}
+func (s *OfferCellData) AsSlice() []byte {
+    return s.inner
+}
+            
+
+func OfferCellDataDefault() OfferCellData {
+    return *OfferCellDataFromSliceUnchecked([]byte{ 146,0,0,0,24,0,0,0,28,0,0,0,36,0,0,0,40,0,0,0,93,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,53,0,0,0,16,0,0,0,48,0,0,0,49,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,53,0,0,0,16,0,0,0,48,0,0,0,49,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+}
+            
+
+func OfferCellDataFromSlice(slice []byte, compatible bool) (*OfferCellData, error) {
+    sliceLen := len(slice)
+    if uint32(sliceLen) < HeaderSizeUint {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "OfferCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    totalSize := unpackNumber(slice)
+    if Number(sliceLen) != totalSize {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "OfferCellData", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if uint32(sliceLen) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "OfferCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    offsetFirst := unpackNumber(slice[HeaderSizeUint:])
+    if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "OfferCellData", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if sliceLen < int(offsetFirst) {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "OfferCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
+    if fieldCount < 5 {
+        return nil, errors.New("FieldCountNotMatch")
+    } else if !compatible && fieldCount > 5 {
+        return nil, errors.New("FieldCountNotMatch")
+    }
+
+    offsets := make([]uint32, fieldCount)
+
+    for i := 0; i < int(fieldCount); i++ {
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    }
+    offsets = append(offsets, uint32(totalSize))
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            return nil, errors.New("OffsetsNotMatch")
+        }
+    }
+
+    var err error
+    
+_, err = BytesFromSlice(slice[offsets[0]:offsets[1]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint64FromSlice(slice[offsets[1]:offsets[2]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = BytesFromSlice(slice[offsets[2]:offsets[3]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = ScriptFromSlice(slice[offsets[3]:offsets[4]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = BytesFromSlice(slice[offsets[1]:offsets[2]], compatible)
+_, err = ScriptFromSlice(slice[offsets[4]:offsets[5]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-    return &AccountChar{inner: slice}, nil
+    return &OfferCellData{inner: slice}, nil
 }
             
 
-func (s *AccountChar) TotalSize() uint {
+func (s *OfferCellData) TotalSize() uint {
     return uint(unpackNumber(s.inner))
 }
-func (s *AccountChar) FieldCount() uint {
+func (s *OfferCellData) FieldCount() uint {
     var number uint = 0
     if uint32(s.TotalSize()) == HeaderSizeUint {
         return number
@@ -11288,72 +11266,114 @@ func (s *AccountChar) FieldCount() uint {
     number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
     return number
 }
-func (s *AccountChar) Len() uint {
+func (s *OfferCellData) Len() uint {
     return s.FieldCount()
 }
-func (s *AccountChar) IsEmpty() bool {
+func (s *OfferCellData) IsEmpty() bool {
     return s.Len() == 0
 }
-func (s *AccountChar) CountExtraFields() uint {
-    return s.FieldCount() - 2
+func (s *OfferCellData) CountExtraFields() uint {
+    return s.FieldCount() - 5
 }
 
-func (s *AccountChar) HasExtraFields() bool {
-    return 2 != s.FieldCount()
+func (s *OfferCellData) HasExtraFields() bool {
+    return 5 != s.FieldCount()
 }
             
 
-func (s *AccountChar) CharSetName() *Uint32 {
+func (s *OfferCellData) Account() *Bytes {
     start := unpackNumber(s.inner[4:])
     end := unpackNumber(s.inner[8:])
-    return Uint32FromSliceUnchecked(s.inner[start:end])
+    return BytesFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *AccountChar) Bytes() *Bytes {
-    var ret *Bytes
+func (s *OfferCellData) Price() *Uint64 {
     start := unpackNumber(s.inner[8:])
+    end := unpackNumber(s.inner[12:])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *OfferCellData) Message() *Bytes {
+    start := unpackNumber(s.inner[12:])
+    end := unpackNumber(s.inner[16:])
+    return BytesFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *OfferCellData) InviterLock() *Script {
+    start := unpackNumber(s.inner[16:])
+    end := unpackNumber(s.inner[20:])
+    return ScriptFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *OfferCellData) ChannelLock() *Script {
+    var ret *Script
+    start := unpackNumber(s.inner[20:])
     if s.HasExtraFields() {
-        end := unpackNumber(s.inner[12:])
-        ret = BytesFromSliceUnchecked(s.inner[start:end])
+        end := unpackNumber(s.inner[24:])
+        ret = ScriptFromSliceUnchecked(s.inner[start:end])
     } else {
-        ret = BytesFromSliceUnchecked(s.inner[start:])
+        ret = ScriptFromSliceUnchecked(s.inner[start:])
     }
     return ret
 }
                         
 
-func (s *AccountChar) AsBuilder() AccountCharBuilder {
-    ret := NewAccountCharBuilder().CharSetName(*s.CharSetName()).Bytes(*s.Bytes())
+func (s *OfferCellData) AsBuilder() OfferCellDataBuilder {
+    ret := NewOfferCellDataBuilder().Account(*s.Account()).Price(*s.Price()).Message(*s.Message()).InviterLock(*s.InviterLock()).ChannelLock(*s.ChannelLock())
     return *ret
 }
         
 
-type OfferCellDataBuilder struct {
-    account Bytes
-price Uint64
-message Bytes
-inviter_lock Script
-channel_lock Script
+type SubAccountBuilder struct {
+    lock Script
+id AccountId
+account AccountChars
+suffix Bytes
+registered_at Uint64
+expired_at Uint64
+status Uint8
+records Records
+nonce Uint64
+enable_sub_account Uint8
+renew_sub_account_price Uint64
+approval AccountApproval
 }
         
 
-func (s *OfferCellDataBuilder) Build() OfferCellData {
+func (s *SubAccountBuilder) Build() SubAccount {
     b := new(bytes.Buffer)
 
-    totalSize := HeaderSizeUint * (5 + 1)
-    offsets := make([]uint32, 0, 5)
+    totalSize := HeaderSizeUint * (12 + 1)
+    offsets := make([]uint32, 0, 12)
 
     offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.lock.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.id.AsSlice()))
+offsets = append(offsets, totalSize)
 totalSize += uint32(len(s.account.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.price.AsSlice()))
+totalSize += uint32(len(s.suffix.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.message.AsSlice()))
+totalSize += uint32(len(s.registered_at.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.inviter_lock.AsSlice()))
+totalSize += uint32(len(s.expired_at.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.channel_lock.AsSlice()))
+totalSize += uint32(len(s.status.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.records.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.nonce.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.enable_sub_account.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.renew_sub_account_price.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.approval.AsSlice()))
 
     b.Write(packNumber(Number(totalSize)))
 
@@ -11361,157 +11381,248 @@ totalSize += uint32(len(s.channel_lock.AsSlice()))
         b.Write(packNumber(Number(offsets[i])))
     }
 
-    b.Write(s.account.AsSlice())
-b.Write(s.price.AsSlice())
-b.Write(s.message.AsSlice())
-b.Write(s.inviter_lock.AsSlice())
-b.Write(s.channel_lock.AsSlice())
-    return OfferCellData{inner: b.Bytes()}
+    b.Write(s.lock.AsSlice())
+b.Write(s.id.AsSlice())
+b.Write(s.account.AsSlice())
+b.Write(s.suffix.AsSlice())
+b.Write(s.registered_at.AsSlice())
+b.Write(s.expired_at.AsSlice())
+b.Write(s.status.AsSlice())
+b.Write(s.records.AsSlice())
+b.Write(s.nonce.AsSlice())
+b.Write(s.enable_sub_account.AsSlice())
+b.Write(s.renew_sub_account_price.AsSlice())
+b.Write(s.approval.AsSlice())
+    return SubAccount{inner: b.Bytes()}
 }
                 
 
-func (s *OfferCellDataBuilder) Account(v Bytes) *OfferCellDataBuilder {
+func (s *SubAccountBuilder) Lock(v Script) *SubAccountBuilder {
+    s.lock = v
+    return s
+}
+            
+
+func (s *SubAccountBuilder) Id(v AccountId) *SubAccountBuilder {
+    s.id = v
+    return s
+}
+            
+
+func (s *SubAccountBuilder) Account(v AccountChars) *SubAccountBuilder {
     s.account = v
     return s
 }
             
 
-func (s *OfferCellDataBuilder) Price(v Uint64) *OfferCellDataBuilder {
-    s.price = v
+func (s *SubAccountBuilder) Suffix(v Bytes) *SubAccountBuilder {
+    s.suffix = v
     return s
 }
             
 
-func (s *OfferCellDataBuilder) Message(v Bytes) *OfferCellDataBuilder {
-    s.message = v
+func (s *SubAccountBuilder) RegisteredAt(v Uint64) *SubAccountBuilder {
+    s.registered_at = v
     return s
 }
             
 
-func (s *OfferCellDataBuilder) InviterLock(v Script) *OfferCellDataBuilder {
-    s.inviter_lock = v
+func (s *SubAccountBuilder) ExpiredAt(v Uint64) *SubAccountBuilder {
+    s.expired_at = v
     return s
 }
             
 
-func (s *OfferCellDataBuilder) ChannelLock(v Script) *OfferCellDataBuilder {
-    s.channel_lock = v
+func (s *SubAccountBuilder) Status(v Uint8) *SubAccountBuilder {
+    s.status = v
     return s
 }
             
 
-func NewOfferCellDataBuilder() *OfferCellDataBuilder {
-	return &OfferCellDataBuilder{ account: BytesDefault(),price: Uint64Default(),message: BytesDefault(),inviter_lock: ScriptDefault(),channel_lock: ScriptDefault() }
+func (s *SubAccountBuilder) Records(v Records) *SubAccountBuilder {
+    s.records = v
+    return s
+}
+            
+
+func (s *SubAccountBuilder) Nonce(v Uint64) *SubAccountBuilder {
+    s.nonce = v
+    return s
+}
+            
+
+func (s *SubAccountBuilder) EnableSubAccount(v Uint8) *SubAccountBuilder {
+    s.enable_sub_account = v
+    return s
+}
+            
+
+func (s *SubAccountBuilder) RenewSubAccountPrice(v Uint64) *SubAccountBuilder {
+    s.renew_sub_account_price = v
+    return s
+}
+            
+
+func (s *SubAccountBuilder) Approval(v AccountApproval) *SubAccountBuilder {
+    s.approval = v
+    return s
+}
+            
+
+func NewSubAccountBuilder() *SubAccountBuilder {
+	return &SubAccountBuilder{ lock: ScriptDefault(),id: AccountIdDefault(),account: AccountCharsDefault(),suffix: BytesDefault(),registered_at: Uint64Default(),expired_at: Uint64Default(),status: Uint8Default(),records: RecordsDefault(),nonce: Uint64Default(),enable_sub_account: Uint8Default(),renew_sub_account_price: Uint64Default(),approval: AccountApprovalDefault() }
 }
     
 
-type OfferCellData struct {
+type SubAccount struct {
     inner []byte
 }
         
 
-func OfferCellDataFromSliceUnchecked(slice []byte) *OfferCellData {
-    return &OfferCellData{inner: slice}
+func SubAccountFromSliceUnchecked(slice []byte) *SubAccount {
+    return &SubAccount{inner: slice}
 }
-func (s *OfferCellData) AsSlice() []byte {
+func (s *SubAccount) AsSlice() []byte {
     return s.inner
 }
             
 
-func OfferCellDataDefault() OfferCellData {
-    return *OfferCellDataFromSliceUnchecked([]byte{ 146,0,0,0,24,0,0,0,28,0,0,0,36,0,0,0,40,0,0,0,93,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,53,0,0,0,16,0,0,0,48,0,0,0,49,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,53,0,0,0,16,0,0,0,48,0,0,0,49,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+func SubAccountDefault() SubAccount {
+    return *SubAccountFromSliceUnchecked([]byte{ 191,0,0,0,52,0,0,0,105,0,0,0,125,0,0,0,129,0,0,0,133,0,0,0,141,0,0,0,149,0,0,0,150,0,0,0,154,0,0,0,162,0,0,0,163,0,0,0,171,0,0,0,53,0,0,0,16,0,0,0,48,0,0,0,49,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,20,0,0,0,12,0,0,0,16,0,0,0,0,0,0,0,0,0,0,0 })
 }
             
 
-func OfferCellDataFromSlice(slice []byte, compatible bool) (*OfferCellData, error) {
+func SubAccountFromSlice(slice []byte, compatible bool) (*SubAccount, error) {
     sliceLen := len(slice)
     if uint32(sliceLen) < HeaderSizeUint {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "OfferCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "SubAccount", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
         return nil, errors.New(errMsg)
     }
 
     totalSize := unpackNumber(slice)
     if Number(sliceLen) != totalSize {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "OfferCellData", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "SubAccount", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if uint32(sliceLen) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "OfferCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "SubAccount", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     offsetFirst := unpackNumber(slice[HeaderSizeUint:])
     if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"OffsetsNotMatch", "OfferCellData", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "SubAccount", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if sliceLen < int(offsetFirst) {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "OfferCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "SubAccount", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
         return nil, errors.New(errMsg)
     }
 
     fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
-    if fieldCount < 5 {
+    if fieldCount < 12 {
         return nil, errors.New("FieldCountNotMatch")
-    } else if !compatible && fieldCount > 5 {
+    } else if !compatible && fieldCount > 12 {
         return nil, errors.New("FieldCountNotMatch")
     }
 
     offsets := make([]uint32, fieldCount)
 
-    for i := 0; i < int(fieldCount); i++ {
-        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
-    }
-    offsets = append(offsets, uint32(totalSize))
+    for i := 0; i < int(fieldCount); i++ {
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    }
+    offsets = append(offsets, uint32(totalSize))
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            return nil, errors.New("OffsetsNotMatch")
+        }
+    }
+
+    var err error
+    
+_, err = ScriptFromSlice(slice[offsets[0]:offsets[1]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = AccountIdFromSlice(slice[offsets[1]:offsets[2]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = AccountCharsFromSlice(slice[offsets[2]:offsets[3]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = BytesFromSlice(slice[offsets[3]:offsets[4]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint64FromSlice(slice[offsets[4]:offsets[5]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint64FromSlice(slice[offsets[5]:offsets[6]], compatible)
+if err != nil {
+    return nil, err
+}
+                
 
-    for i := 0; i < len(offsets); i++ {
-        if i&1 != 0 && offsets[i-1] > offsets[i] {
-            return nil, errors.New("OffsetsNotMatch")
-        }
-    }
+_, err = Uint8FromSlice(slice[offsets[6]:offsets[7]], compatible)
+if err != nil {
+    return nil, err
+}
+                
 
-    var err error
-    
-_, err = BytesFromSlice(slice[offsets[0]:offsets[1]], compatible)
+_, err = RecordsFromSlice(slice[offsets[7]:offsets[8]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = Uint64FromSlice(slice[offsets[1]:offsets[2]], compatible)
+_, err = Uint64FromSlice(slice[offsets[8]:offsets[9]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = BytesFromSlice(slice[offsets[2]:offsets[3]], compatible)
+_, err = Uint8FromSlice(slice[offsets[9]:offsets[10]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = ScriptFromSlice(slice[offsets[3]:offsets[4]], compatible)
+_, err = Uint64FromSlice(slice[offsets[10]:offsets[11]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = ScriptFromSlice(slice[offsets[4]:offsets[5]], compatible)
+_, err = AccountApprovalFromSlice(slice[offsets[11]:offsets[12]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-    return &OfferCellData{inner: slice}, nil
+    return &SubAccount{inner: slice}, nil
 }
             
 
-func (s *OfferCellData) TotalSize() uint {
+func (s *SubAccount) TotalSize() uint {
     return uint(unpackNumber(s.inner))
 }
-func (s *OfferCellData) FieldCount() uint {
+func (s *SubAccount) FieldCount() uint {
     var number uint = 0
     if uint32(s.TotalSize()) == HeaderSizeUint {
         return number
@@ -11519,111 +11630,145 @@ func (s *OfferCellData) FieldCount() uint {
     number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
     return number
 }
-func (s *OfferCellData) Len() uint {
+func (s *SubAccount) Len() uint {
     return s.FieldCount()
 }
-func (s *OfferCellData) IsEmpty() bool {
+func (s *SubAccount) IsEmpty() bool {
     return s.Len() == 0
 }
-func (s *OfferCellData) CountExtraFields() uint {
-    return s.FieldCount() - 5
+func (s *SubAccount) CountExtraFields() uint {
+    return s.FieldCount() - 12
 }
 
-func (s *OfferCellData) HasExtraFields() bool {
-    return 5 != s.FieldCount()
+func (s *SubAccount) HasExtraFields() bool {
+    return 12 != s.FieldCount()
 }
             
 
-func (s *OfferCellData) Account() *Bytes {
+func (s *SubAccount) Lock() *Script {
     start := unpackNumber(s.inner[4:])
     end := unpackNumber(s.inner[8:])
-    return BytesFromSliceUnchecked(s.inner[start:end])
+    return ScriptFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *OfferCellData) Price() *Uint64 {
+func (s *SubAccount) Id() *AccountId {
     start := unpackNumber(s.inner[8:])
     end := unpackNumber(s.inner[12:])
-    return Uint64FromSliceUnchecked(s.inner[start:end])
+    return AccountIdFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *OfferCellData) Message() *Bytes {
+func (s *SubAccount) Account() *AccountChars {
     start := unpackNumber(s.inner[12:])
     end := unpackNumber(s.inner[16:])
-    return BytesFromSliceUnchecked(s.inner[start:end])
+    return AccountCharsFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *OfferCellData) InviterLock() *Script {
+func (s *SubAccount) Suffix() *Bytes {
     start := unpackNumber(s.inner[16:])
     end := unpackNumber(s.inner[20:])
-    return ScriptFromSliceUnchecked(s.inner[start:end])
+    return BytesFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *OfferCellData) ChannelLock() *Script {
-    var ret *Script
+func (s *SubAccount) RegisteredAt() *Uint64 {
     start := unpackNumber(s.inner[20:])
+    end := unpackNumber(s.inner[24:])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *SubAccount) ExpiredAt() *Uint64 {
+    start := unpackNumber(s.inner[24:])
+    end := unpackNumber(s.inner[28:])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *SubAccount) Status() *Uint8 {
+    start := unpackNumber(s.inner[28:])
+    end := unpackNumber(s.inner[32:])
+    return Uint8FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *SubAccount) Records() *Records {
+    start := unpackNumber(s.inner[32:])
+    end := unpackNumber(s.inner[36:])
+    return RecordsFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *SubAccount) Nonce() *Uint64 {
+    start := unpackNumber(s.inner[36:])
+    end := unpackNumber(s.inner[40:])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *SubAccount) EnableSubAccount() *Uint8 {
+    start := unpackNumber(s.inner[40:])
+    end := unpackNumber(s.inner[44:])
+    return Uint8FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *SubAccount) RenewSubAccountPrice() *Uint64 {
+    start := unpackNumber(s.inner[44:])
+    end := unpackNumber(s.inner[48:])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *SubAccount) Approval() *AccountApproval {
+    var ret *AccountApproval
+    start := unpackNumber(s.inner[48:])
     if s.HasExtraFields() {
-        end := unpackNumber(s.inner[24:])
-        ret = ScriptFromSliceUnchecked(s.inner[start:end])
+        end := unpackNumber(s.inner[52:])
+        ret = AccountApprovalFromSliceUnchecked(s.inner[start:end])
     } else {
-        ret = ScriptFromSliceUnchecked(s.inner[start:])
+        ret = AccountApprovalFromSliceUnchecked(s.inner[start:])
     }
     return ret
 }
                         
 
-func (s *OfferCellData) AsBuilder() OfferCellDataBuilder {
-    ret := NewOfferCellDataBuilder().Account(*s.Account()).Price(*s.Price()).Message(*s.Message()).InviterLock(*s.InviterLock()).ChannelLock(*s.ChannelLock())
+func (s *SubAccount) AsBuilder() SubAccountBuilder {
+    ret := NewSubAccountBuilder().Lock(*s.Lock()).Id(*s.Id()).Account(*s.Account()).Suffix(*s.Suffix()).RegisteredAt(*s.RegisteredAt()).ExpiredAt(*s.ExpiredAt()).Status(*s.Status()).Records(*s.Records()).Nonce(*s.Nonce()).EnableSubAccount(*s.EnableSubAccount()).RenewSubAccountPrice(*s.RenewSubAccountPrice()).Approval(*s.Approval())
     return *ret
 }
         
 
-type SubAccountBuilder struct {
-    lock Script
-id AccountId
-account AccountChars
-suffix Bytes
-registered_at Uint64
-expired_at Uint64
+type SubAccountRuleBuilder struct {
+    index Uint32
+name Bytes
+note Bytes
+price Uint64
+ast ASTExpression
 status Uint8
-records Records
-nonce Uint64
-enable_sub_account Uint8
-renew_sub_account_price Uint64
 }
         
 
-func (s *SubAccountBuilder) Build() SubAccount {
+func (s *SubAccountRuleBuilder) Build() SubAccountRule {
     b := new(bytes.Buffer)
 
-    totalSize := HeaderSizeUint * (11 + 1)
-    offsets := make([]uint32, 0, 11)
+    totalSize := HeaderSizeUint * (6 + 1)
+    offsets := make([]uint32, 0, 6)
 
     offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.lock.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.id.AsSlice()))
+totalSize += uint32(len(s.index.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.account.AsSlice()))
+totalSize += uint32(len(s.name.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.suffix.AsSlice()))
+totalSize += uint32(len(s.note.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.registered_at.AsSlice()))
+totalSize += uint32(len(s.price.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.expired_at.AsSlice()))
+totalSize += uint32(len(s.ast.AsSlice()))
 offsets = append(offsets, totalSize)
 totalSize += uint32(len(s.status.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.records.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.nonce.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.enable_sub_account.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.renew_sub_account_price.AsSlice()))
 
     b.Write(packNumber(Number(totalSize)))
 
@@ -11631,143 +11776,108 @@ totalSize += uint32(len(s.renew_sub_account_price.AsSlice()))
         b.Write(packNumber(Number(offsets[i])))
     }
 
-    b.Write(s.lock.AsSlice())
-b.Write(s.id.AsSlice())
-b.Write(s.account.AsSlice())
-b.Write(s.suffix.AsSlice())
-b.Write(s.registered_at.AsSlice())
-b.Write(s.expired_at.AsSlice())
+    b.Write(s.index.AsSlice())
+b.Write(s.name.AsSlice())
+b.Write(s.note.AsSlice())
+b.Write(s.price.AsSlice())
+b.Write(s.ast.AsSlice())
 b.Write(s.status.AsSlice())
-b.Write(s.records.AsSlice())
-b.Write(s.nonce.AsSlice())
-b.Write(s.enable_sub_account.AsSlice())
-b.Write(s.renew_sub_account_price.AsSlice())
-    return SubAccount{inner: b.Bytes()}
+    return SubAccountRule{inner: b.Bytes()}
 }
                 
 
-func (s *SubAccountBuilder) Lock(v Script) *SubAccountBuilder {
-    s.lock = v
-    return s
-}
-            
-
-func (s *SubAccountBuilder) Id(v AccountId) *SubAccountBuilder {
-    s.id = v
+func (s *SubAccountRuleBuilder) Index(v Uint32) *SubAccountRuleBuilder {
+    s.index = v
     return s
 }
             
 
-func (s *SubAccountBuilder) Account(v AccountChars) *SubAccountBuilder {
-    s.account = v
+func (s *SubAccountRuleBuilder) Name(v Bytes) *SubAccountRuleBuilder {
+    s.name = v
     return s
 }
             
 
-func (s *SubAccountBuilder) Suffix(v Bytes) *SubAccountBuilder {
-    s.suffix = v
+func (s *SubAccountRuleBuilder) Note(v Bytes) *SubAccountRuleBuilder {
+    s.note = v
     return s
 }
             
 
-func (s *SubAccountBuilder) RegisteredAt(v Uint64) *SubAccountBuilder {
-    s.registered_at = v
+func (s *SubAccountRuleBuilder) Price(v Uint64) *SubAccountRuleBuilder {
+    s.price = v
     return s
 }
             
 
-func (s *SubAccountBuilder) ExpiredAt(v Uint64) *SubAccountBuilder {
-    s.expired_at = v
+func (s *SubAccountRuleBuilder) Ast(v ASTExpression) *SubAccountRuleBuilder {
+    s.ast = v
     return s
 }
             
 
-func (s *SubAccountBuilder) Status(v Uint8) *SubAccountBuilder {
+func (s *SubAccountRuleBuilder) Status(v Uint8) *SubAccountRuleBuilder {
     s.status = v
     return s
 }
             
 
-func (s *SubAccountBuilder) Records(v Records) *SubAccountBuilder {
-    s.records = v
-    return s
-}
-            
-
-func (s *SubAccountBuilder) Nonce(v Uint64) *SubAccountBuilder {
-    s.nonce = v
-    return s
-}
-            
-
-func (s *SubAccountBuilder) EnableSubAccount(v Uint8) *SubAccountBuilder {
-    s.enable_sub_account = v
-    return s
-}
-            
-
-func (s *SubAccountBuilder) RenewSubAccountPrice(v Uint64) *SubAccountBuilder {
-    s.renew_sub_account_price = v
-    return s
-}
-            
-
-func NewSubAccountBuilder() *SubAccountBuilder {
-	return &SubAccountBuilder{ lock: ScriptDefault(),id: AccountIdDefault(),account: AccountCharsDefault(),suffix: BytesDefault(),registered_at: Uint64Default(),expired_at: Uint64Default(),status: Uint8Default(),records: RecordsDefault(),nonce: Uint64Default(),enable_sub_account: Uint8Default(),renew_sub_account_price: Uint64Default() }
+func NewSubAccountRuleBuilder() *SubAccountRuleBuilder {
+	return &SubAccountRuleBuilder{ index: Uint32Default(),name: BytesDefault(),note: BytesDefault(),price: Uint64Default(),ast: ASTExpressionDefault(),status: Uint8Default() }
 }
     
 
-type SubAccount struct {
+type SubAccountRule struct {
     inner []byte
 }
         
 
-func SubAccountFromSliceUnchecked(slice []byte) *SubAccount {
-    return &SubAccount{inner: slice}
+func SubAccountRuleFromSliceUnchecked(slice []byte) *SubAccountRule {
+    return &SubAccountRule{inner: slice}
 }
-func (s *SubAccount) AsSlice() []byte {
+func (s *SubAccountRule) AsSlice() []byte {
     return s.inner
 }
             
 
-func SubAccountDefault() SubAccount {
-    return *SubAccountFromSliceUnchecked([]byte{ 167,0,0,0,48,0,0,0,101,0,0,0,121,0,0,0,125,0,0,0,129,0,0,0,137,0,0,0,145,0,0,0,146,0,0,0,150,0,0,0,158,0,0,0,159,0,0,0,53,0,0,0,16,0,0,0,48,0,0,0,49,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+func SubAccountRuleDefault() SubAccountRule {
+    return *SubAccountRuleFromSliceUnchecked([]byte{ 66,0,0,0,28,0,0,0,32,0,0,0,36,0,0,0,40,0,0,0,48,0,0,0,65,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,0 })
 }
             
 
-func SubAccountFromSlice(slice []byte, compatible bool) (*SubAccount, error) {
+func SubAccountRuleFromSlice(slice []byte, compatible bool) (*SubAccountRule, error) {
     sliceLen := len(slice)
     if uint32(sliceLen) < HeaderSizeUint {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "SubAccount", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "SubAccountRule", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
         return nil, errors.New(errMsg)
     }
 
     totalSize := unpackNumber(slice)
     if Number(sliceLen) != totalSize {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "SubAccount", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "SubAccountRule", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if uint32(sliceLen) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "SubAccount", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "SubAccountRule", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     offsetFirst := unpackNumber(slice[HeaderSizeUint:])
     if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"OffsetsNotMatch", "SubAccount", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "SubAccountRule", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if sliceLen < int(offsetFirst) {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "SubAccount", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "SubAccountRule", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
         return nil, errors.New(errMsg)
     }
 
     fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
-    if fieldCount < 11 {
+    if fieldCount < 6 {
         return nil, errors.New("FieldCountNotMatch")
-    } else if !compatible && fieldCount > 11 {
+    } else if !compatible && fieldCount > 6 {
         return nil, errors.New("FieldCountNotMatch")
     }
 
@@ -11786,80 +11896,50 @@ func SubAccountFromSlice(slice []byte, compatible bool) (*SubAccount, error) {
 
     var err error
     
-_, err = ScriptFromSlice(slice[offsets[0]:offsets[1]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = AccountIdFromSlice(slice[offsets[1]:offsets[2]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = AccountCharsFromSlice(slice[offsets[2]:offsets[3]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = BytesFromSlice(slice[offsets[3]:offsets[4]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = Uint64FromSlice(slice[offsets[4]:offsets[5]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = Uint64FromSlice(slice[offsets[5]:offsets[6]], compatible)
+_, err = Uint32FromSlice(slice[offsets[0]:offsets[1]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = Uint8FromSlice(slice[offsets[6]:offsets[7]], compatible)
+_, err = BytesFromSlice(slice[offsets[1]:offsets[2]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = RecordsFromSlice(slice[offsets[7]:offsets[8]], compatible)
+_, err = BytesFromSlice(slice[offsets[2]:offsets[3]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = Uint64FromSlice(slice[offsets[8]:offsets[9]], compatible)
+_, err = Uint64FromSlice(slice[offsets[3]:offsets[4]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = Uint8FromSlice(slice[offsets[9]:offsets[10]], compatible)
+_, err = ASTExpressionFromSlice(slice[offsets[4]:offsets[5]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = Uint64FromSlice(slice[offsets[10]:offsets[11]], compatible)
+_, err = Uint8FromSlice(slice[offsets[5]:offsets[6]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-    return &SubAccount{inner: slice}, nil
+    return &SubAccountRule{inner: slice}, nil
 }
             
 
-func (s *SubAccount) TotalSize() uint {
+func (s *SubAccountRule) TotalSize() uint {
     return uint(unpackNumber(s.inner))
 }
-func (s *SubAccount) FieldCount() uint {
+func (s *SubAccountRule) FieldCount() uint {
     var number uint = 0
     if uint32(s.TotalSize()) == HeaderSizeUint {
         return number
@@ -11867,138 +11947,290 @@ func (s *SubAccount) FieldCount() uint {
     number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
     return number
 }
-func (s *SubAccount) Len() uint {
+func (s *SubAccountRule) Len() uint {
     return s.FieldCount()
 }
-func (s *SubAccount) IsEmpty() bool {
+func (s *SubAccountRule) IsEmpty() bool {
     return s.Len() == 0
 }
-func (s *SubAccount) CountExtraFields() uint {
-    return s.FieldCount() - 11
+func (s *SubAccountRule) CountExtraFields() uint {
+    return s.FieldCount() - 6
 }
 
-func (s *SubAccount) HasExtraFields() bool {
-    return 11 != s.FieldCount()
+func (s *SubAccountRule) HasExtraFields() bool {
+    return 6 != s.FieldCount()
 }
             
 
-func (s *SubAccount) Lock() *Script {
+func (s *SubAccountRule) Index() *Uint32 {
     start := unpackNumber(s.inner[4:])
     end := unpackNumber(s.inner[8:])
-    return ScriptFromSliceUnchecked(s.inner[start:end])
+    return Uint32FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *SubAccount) Id() *AccountId {
+func (s *SubAccountRule) Name() *Bytes {
     start := unpackNumber(s.inner[8:])
     end := unpackNumber(s.inner[12:])
-    return AccountIdFromSliceUnchecked(s.inner[start:end])
+    return BytesFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *SubAccount) Account() *AccountChars {
+func (s *SubAccountRule) Note() *Bytes {
     start := unpackNumber(s.inner[12:])
     end := unpackNumber(s.inner[16:])
-    return AccountCharsFromSliceUnchecked(s.inner[start:end])
+    return BytesFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *SubAccount) Suffix() *Bytes {
+func (s *SubAccountRule) Price() *Uint64 {
     start := unpackNumber(s.inner[16:])
     end := unpackNumber(s.inner[20:])
-    return BytesFromSliceUnchecked(s.inner[start:end])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *SubAccount) RegisteredAt() *Uint64 {
+func (s *SubAccountRule) Ast() *ASTExpression {
     start := unpackNumber(s.inner[20:])
     end := unpackNumber(s.inner[24:])
-    return Uint64FromSliceUnchecked(s.inner[start:end])
+    return ASTExpressionFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *SubAccount) ExpiredAt() *Uint64 {
-    start := unpackNumber(s.inner[24:])
-    end := unpackNumber(s.inner[28:])
-    return Uint64FromSliceUnchecked(s.inner[start:end])
+func (s *SubAccountRule) Status() *Uint8 {
+    var ret *Uint8
+    start := unpackNumber(s.inner[24:])
+    if s.HasExtraFields() {
+        end := unpackNumber(s.inner[28:])
+        ret = Uint8FromSliceUnchecked(s.inner[start:end])
+    } else {
+        ret = Uint8FromSliceUnchecked(s.inner[start:])
+    }
+    return ret
+}
+                        
+
+func (s *SubAccountRule) AsBuilder() SubAccountRuleBuilder {
+    ret := NewSubAccountRuleBuilder().Index(*s.Index()).Name(*s.Name()).Note(*s.Note()).Price(*s.Price()).Ast(*s.Ast()).Status(*s.Status())
+    return *ret
+}
+        
+
+type SubAccountRulesBuilder struct {
+    inner []SubAccountRule
+}
+    
+
+func (s *SubAccountRulesBuilder) Build() SubAccountRules {
+    itemCount := len(s.inner)
+
+    b := new(bytes.Buffer)
+
+    // Empty dyn vector, just return size's bytes
+    if itemCount == 0 {
+        b.Write(packNumber(Number(HeaderSizeUint)))
+        return SubAccountRules{inner: b.Bytes()}
+    }
+
+    // Calculate first offset then loop for rest items offsets
+    totalSize := HeaderSizeUint * uint32(itemCount+1)
+    offsets := make([]uint32, 0, itemCount)
+    offsets = append(offsets, totalSize)
+    for i := 1; i < itemCount; i++ {
+        totalSize += uint32(len(s.inner[i-1].AsSlice()))
+        offsets = append(offsets, offsets[i-1]+uint32(len(s.inner[i-1].AsSlice())))
+    }
+    totalSize += uint32(len(s.inner[itemCount-1].AsSlice()))
+
+    b.Write(packNumber(Number(totalSize)))
+
+    for i := 0; i < itemCount; i++ {
+        b.Write(packNumber(Number(offsets[i])))
+    }
+
+    for i := 0; i < itemCount; i++ {
+        b.Write(s.inner[i].AsSlice())
+    }
+
+    return SubAccountRules{inner: b.Bytes()}
+}
+          
+
+func (s *SubAccountRulesBuilder) Set(v []SubAccountRule) *SubAccountRulesBuilder {
+    s.inner = v
+    return s
+}
+func (s *SubAccountRulesBuilder) Push(v SubAccountRule) *SubAccountRulesBuilder {
+    s.inner = append(s.inner, v)
+    return s
+}
+func (s *SubAccountRulesBuilder) Extend(iter []SubAccountRule) *SubAccountRulesBuilder {
+    for i:=0; i < len(iter); i++ {
+        s.inner = append(s.inner, iter[i])
+    }
+    return s
+}
+func (s *SubAccountRulesBuilder) Replace(index uint, v SubAccountRule) *SubAccountRule {
+    if uint(len(s.inner)) > index {
+        a := s.inner[index]
+        s.inner[index] = v
+        return &a
+    }
+    return nil
+}
+    
+
+func NewSubAccountRulesBuilder() *SubAccountRulesBuilder {
+	return &SubAccountRulesBuilder{ []SubAccountRule{} }
+}
+        
+
+type SubAccountRules struct {
+    inner []byte
+}
+        
+
+func SubAccountRulesFromSliceUnchecked(slice []byte) *SubAccountRules {
+    return &SubAccountRules{inner: slice}
+}
+func (s *SubAccountRules) AsSlice() []byte {
+    return s.inner
+}
+            
+
+func SubAccountRulesDefault() SubAccountRules {
+    return *SubAccountRulesFromSliceUnchecked([]byte{ 4,0,0,0 })
+}
+            
+
+func SubAccountRulesFromSlice(slice []byte, compatible bool) (*SubAccountRules, error) {
+    sliceLen := len(slice)
+
+    if uint32(sliceLen) < HeaderSizeUint {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "SubAccountRules", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    totalSize := unpackNumber(slice)
+    if Number(sliceLen) != totalSize {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "SubAccountRules", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if uint32(sliceLen) == HeaderSizeUint {
+        return &SubAccountRules{inner: slice}, nil
+    }
+
+    if uint32(sliceLen) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "SubAccountRules", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    offsetFirst := unpackNumber(slice[HeaderSizeUint:])
+    if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "SubAccountRules", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if sliceLen < int(offsetFirst) {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "SubAccountRules", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        return nil, errors.New(errMsg)
+    }
+    itemCount := uint32(offsetFirst)/HeaderSizeUint - 1
+
+    offsets := make([]uint32, itemCount)
+
+    for i := 0; i < int(itemCount); i++ {
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    }
+
+    offsets = append(offsets, uint32(totalSize))
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            errMsg := strings.Join([]string{"OffsetsNotMatch", "SubAccountRules"}, " ")
+            return nil, errors.New(errMsg)
+        }
+    }
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 {
+            start := offsets[i-1]
+            end := offsets[i]
+            _, err := SubAccountRuleFromSlice(slice[start:end], compatible)
+
+            if err != nil {
+                return nil, err
+            }
+        }
+    }
+
+    return &SubAccountRules{inner: slice}, nil
 }
-               
+            
 
-func (s *SubAccount) Status() *Uint8 {
-    start := unpackNumber(s.inner[28:])
-    end := unpackNumber(s.inner[32:])
-    return Uint8FromSliceUnchecked(s.inner[start:end])
+func (s *SubAccountRules) TotalSize() uint {
+    return uint(unpackNumber(s.inner))
 }
-               
-
-func (s *SubAccount) Records() *Records {
-    start := unpackNumber(s.inner[32:])
-    end := unpackNumber(s.inner[36:])
-    return RecordsFromSliceUnchecked(s.inner[start:end])
+func (s *SubAccountRules) ItemCount() uint {
+    var number uint = 0
+    if uint32(s.TotalSize()) == HeaderSizeUint {
+        return number
+    }
+    number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
+    return number
 }
-               
-
-func (s *SubAccount) Nonce() *Uint64 {
-    start := unpackNumber(s.inner[36:])
-    end := unpackNumber(s.inner[40:])
-    return Uint64FromSliceUnchecked(s.inner[start:end])
+func (s *SubAccountRules) Len() uint {
+    return s.ItemCount()
 }
-               
-
-func (s *SubAccount) EnableSubAccount() *Uint8 {
-    start := unpackNumber(s.inner[40:])
-    end := unpackNumber(s.inner[44:])
-    return Uint8FromSliceUnchecked(s.inner[start:end])
+func (s *SubAccountRules) IsEmpty() bool {
+    return s.Len() == 0
 }
-               
+// if *SubAccountRule is nil, index is out of bounds
+func (s *SubAccountRules) Get(index uint) *SubAccountRule {
+    var b *SubAccountRule
+    if index < s.Len() {
+        start_index := uint(HeaderSizeUint) * (1 + index)
+        start := unpackNumber(s.inner[start_index:])
 
-func (s *SubAccount) RenewSubAccountPrice() *Uint64 {
-    var ret *Uint64
-    start := unpackNumber(s.inner[44:])
-    if s.HasExtraFields() {
-        end := unpackNumber(s.inner[48:])
-        ret = Uint64FromSliceUnchecked(s.inner[start:end])
-    } else {
-        ret = Uint64FromSliceUnchecked(s.inner[start:])
+        if index == s.Len()-1 {
+            b = SubAccountRuleFromSliceUnchecked(s.inner[start:])
+        } else {
+            end_index := start_index + uint(HeaderSizeUint)
+            end := unpackNumber(s.inner[end_index:])
+            b = SubAccountRuleFromSliceUnchecked(s.inner[start:end])
+        }
     }
-    return ret
+    return b
 }
-                        
+            
 
-func (s *SubAccount) AsBuilder() SubAccountBuilder {
-    ret := NewSubAccountBuilder().Lock(*s.Lock()).Id(*s.Id()).Account(*s.Account()).Suffix(*s.Suffix()).RegisteredAt(*s.RegisteredAt()).ExpiredAt(*s.ExpiredAt()).Status(*s.Status()).Records(*s.Records()).Nonce(*s.Nonce()).EnableSubAccount(*s.EnableSubAccount()).RenewSubAccountPrice(*s.RenewSubAccountPrice())
-    return *ret
+func (s *SubAccountRules) AsBuilder() SubAccountRulesBuilder {
+    size := s.ItemCount()
+    t := NewSubAccountRulesBuilder()
+    for i:=uint(0); i < size; i++ {
+        t.Push(*s.Get(i))
+    }
+    return *t
 }
         
 
-type SubAccountRuleBuilder struct {
-    index Uint32
-name Bytes
-note Bytes
-price Uint64
-ast ASTExpression
-status Uint8
+type ASTExpressionBuilder struct {
+    expression_type Byte
+expression Bytes
 }
         
 
-func (s *SubAccountRuleBuilder) Build() SubAccountRule {
+func (s *ASTExpressionBuilder) Build() ASTExpression {
     b := new(bytes.Buffer)
 
-    totalSize := HeaderSizeUint * (6 + 1)
-    offsets := make([]uint32, 0, 6)
+    totalSize := HeaderSizeUint * (2 + 1)
+    offsets := make([]uint32, 0, 2)
 
     offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.index.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.name.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.note.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.price.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.ast.AsSlice()))
+totalSize += uint32(len(s.expression_type.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.status.AsSlice()))
+totalSize += uint32(len(s.expression.AsSlice()))
 
     b.Write(packNumber(Number(totalSize)))
 
@@ -12006,108 +12238,80 @@ totalSize += uint32(len(s.status.AsSlice()))
         b.Write(packNumber(Number(offsets[i])))
     }
 
-    b.Write(s.index.AsSlice())
-b.Write(s.name.AsSlice())
-b.Write(s.note.AsSlice())
-b.Write(s.price.AsSlice())
-b.Write(s.ast.AsSlice())
-b.Write(s.status.AsSlice())
-    return SubAccountRule{inner: b.Bytes()}
+    b.Write(s.expression_type.AsSlice())
+b.Write(s.expression.AsSlice())
+    return ASTExpression{inner: b.Bytes()}
 }
                 
 
-func (s *SubAccountRuleBuilder) Index(v Uint32) *SubAccountRuleBuilder {
-    s.index = v
-    return s
-}
-            
-
-func (s *SubAccountRuleBuilder) Name(v Bytes) *SubAccountRuleBuilder {
-    s.name = v
-    return s
-}
-            
-
-func (s *SubAccountRuleBuilder) Note(v Bytes) *SubAccountRuleBuilder {
-    s.note = v
-    return s
-}
-            
-
-func (s *SubAccountRuleBuilder) Price(v Uint64) *SubAccountRuleBuilder {
-    s.price = v
-    return s
-}
-            
-
-func (s *SubAccountRuleBuilder) Ast(v ASTExpression) *SubAccountRuleBuilder {
-    s.ast = v
+func (s *ASTExpressionBuilder) ExpressionType(v Byte) *ASTExpressionBuilder {
+    s.expression_type = v
     return s
 }
             
 
-func (s *SubAccountRuleBuilder) Status(v Uint8) *SubAccountRuleBuilder {
-    s.status = v
+func (s *ASTExpressionBuilder) Expression(v Bytes) *ASTExpressionBuilder {
+    s.expression = v
     return s
 }
             
 
-func NewSubAccountRuleBuilder() *SubAccountRuleBuilder {
-	return &SubAccountRuleBuilder{ index: Uint32Default(),name: BytesDefault(),note: BytesDefault(),price: Uint64Default(),ast: ASTExpressionDefault(),status: Uint8Default() }
+func NewASTExpressionBuilder() *ASTExpressionBuilder {
+	return &ASTExpressionBuilder{ expression_type: ByteDefault(),expression: BytesDefault() }
 }
     
 
-type SubAccountRule struct {
+type ASTExpression struct {
     inner []byte
 }
         
 
-func SubAccountRuleFromSliceUnchecked(slice []byte) *SubAccountRule {
-    return &SubAccountRule{inner: slice}
+func ASTExpressionFromSliceUnchecked(slice []byte) *ASTExpression {
+    return &ASTExpression{inner: slice}
 }
-func (s *SubAccountRule) AsSlice() []byte {
+func (s *ASTExpression) AsSlice() []byte {
     return s.inner
 }
             
 
-func SubAccountRuleDefault() SubAccountRule {
-    return *SubAccountRuleFromSliceUnchecked([]byte{ 66,0,0,0,28,0,0,0,32,0,0,0,36,0,0,0,40,0,0,0,48,0,0,0,65,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,0 })
+func ASTExpressionDefault() ASTExpression {
+    return *ASTExpressionFromSliceUnchecked([]byte{ 17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0 })
 }
             
 
-func SubAccountRuleFromSlice(slice []byte, compatible bool) (*SubAccountRule, error) {
+func ASTExpressionFromSlice(slice []byte, compatible bool) (*ASTExpression, error) {
     sliceLen := len(slice)
     if uint32(sliceLen) < HeaderSizeUint {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "SubAccountRule", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ASTExpression", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
         return nil, errors.New(errMsg)
     }
 
     totalSize := unpackNumber(slice)
     if Number(sliceLen) != totalSize {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "SubAccountRule", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ASTExpression", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if uint32(sliceLen) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "SubAccountRule", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ASTExpression", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     offsetFirst := unpackNumber(slice[HeaderSizeUint:])
     if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"OffsetsNotMatch", "SubAccountRule", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "ASTExpression", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if sliceLen < int(offsetFirst) {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "SubAccountRule", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ASTExpression", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
         return nil, errors.New(errMsg)
     }
 
     fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
-    if fieldCount < 6 {
+    if fieldCount < 2 {
         return nil, errors.New("FieldCountNotMatch")
-    } else if !compatible && fieldCount > 6 {
+    } else if !compatible && fieldCount > 2 {
         return nil, errors.New("FieldCountNotMatch")
     }
 
@@ -12126,7 +12330,7 @@ func SubAccountRuleFromSlice(slice []byte, compatible bool) (*SubAccountRule, er
 
     var err error
     
-_, err = Uint32FromSlice(slice[offsets[0]:offsets[1]], compatible)
+_, err = ByteFromSlice(slice[offsets[0]:offsets[1]], compatible)
 if err != nil {
     return nil, err
 }
@@ -12138,38 +12342,14 @@ if err != nil {
 }
                 
 
-_, err = BytesFromSlice(slice[offsets[2]:offsets[3]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = Uint64FromSlice(slice[offsets[3]:offsets[4]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = ASTExpressionFromSlice(slice[offsets[4]:offsets[5]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = Uint8FromSlice(slice[offsets[5]:offsets[6]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-    return &SubAccountRule{inner: slice}, nil
+    return &ASTExpression{inner: slice}, nil
 }
             
 
-func (s *SubAccountRule) TotalSize() uint {
+func (s *ASTExpression) TotalSize() uint {
     return uint(unpackNumber(s.inner))
 }
-func (s *SubAccountRule) FieldCount() uint {
+func (s *ASTExpression) FieldCount() uint {
     var number uint = 0
     if uint32(s.TotalSize()) == HeaderSizeUint {
         return number
@@ -12177,81 +12357,53 @@ func (s *SubAccountRule) FieldCount() uint {
     number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
     return number
 }
-func (s *SubAccountRule) Len() uint {
+func (s *ASTExpression) Len() uint {
     return s.FieldCount()
 }
-func (s *SubAccountRule) IsEmpty() bool {
+func (s *ASTExpression) IsEmpty() bool {
     return s.Len() == 0
 }
-func (s *SubAccountRule) CountExtraFields() uint {
-    return s.FieldCount() - 6
+func (s *ASTExpression) CountExtraFields() uint {
+    return s.FieldCount() - 2
 }
 
-func (s *SubAccountRule) HasExtraFields() bool {
-    return 6 != s.FieldCount()
+func (s *ASTExpression) HasExtraFields() bool {
+    return 2 != s.FieldCount()
 }
             
 
-func (s *SubAccountRule) Index() *Uint32 {
+func (s *ASTExpression) ExpressionType() *Byte {
     start := unpackNumber(s.inner[4:])
     end := unpackNumber(s.inner[8:])
-    return Uint32FromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *SubAccountRule) Name() *Bytes {
-    start := unpackNumber(s.inner[8:])
-    end := unpackNumber(s.inner[12:])
-    return BytesFromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *SubAccountRule) Note() *Bytes {
-    start := unpackNumber(s.inner[12:])
-    end := unpackNumber(s.inner[16:])
-    return BytesFromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *SubAccountRule) Price() *Uint64 {
-    start := unpackNumber(s.inner[16:])
-    end := unpackNumber(s.inner[20:])
-    return Uint64FromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *SubAccountRule) Ast() *ASTExpression {
-    start := unpackNumber(s.inner[20:])
-    end := unpackNumber(s.inner[24:])
-    return ASTExpressionFromSliceUnchecked(s.inner[start:end])
+    return ByteFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *SubAccountRule) Status() *Uint8 {
-    var ret *Uint8
-    start := unpackNumber(s.inner[24:])
+func (s *ASTExpression) Expression() *Bytes {
+    var ret *Bytes
+    start := unpackNumber(s.inner[8:])
     if s.HasExtraFields() {
-        end := unpackNumber(s.inner[28:])
-        ret = Uint8FromSliceUnchecked(s.inner[start:end])
+        end := unpackNumber(s.inner[12:])
+        ret = BytesFromSliceUnchecked(s.inner[start:end])
     } else {
-        ret = Uint8FromSliceUnchecked(s.inner[start:])
+        ret = BytesFromSliceUnchecked(s.inner[start:])
     }
     return ret
 }
                         
 
-func (s *SubAccountRule) AsBuilder() SubAccountRuleBuilder {
-    ret := NewSubAccountRuleBuilder().Index(*s.Index()).Name(*s.Name()).Note(*s.Note()).Price(*s.Price()).Ast(*s.Ast()).Status(*s.Status())
+func (s *ASTExpression) AsBuilder() ASTExpressionBuilder {
+    ret := NewASTExpressionBuilder().ExpressionType(*s.ExpressionType()).Expression(*s.Expression())
     return *ret
 }
         
 
-type SubAccountRulesBuilder struct {
-    inner []SubAccountRule
+type ASTExpressionsBuilder struct {
+    inner []ASTExpression
 }
     
 
-func (s *SubAccountRulesBuilder) Build() SubAccountRules {
+func (s *ASTExpressionsBuilder) Build() ASTExpressions {
     itemCount := len(s.inner)
 
     b := new(bytes.Buffer)
@@ -12259,7 +12411,7 @@ func (s *SubAccountRulesBuilder) Build() SubAccountRules {
     // Empty dyn vector, just return size's bytes
     if itemCount == 0 {
         b.Write(packNumber(Number(HeaderSizeUint)))
-        return SubAccountRules{inner: b.Bytes()}
+        return ASTExpressions{inner: b.Bytes()}
     }
 
     // Calculate first offset then loop for rest items offsets
@@ -12282,25 +12434,25 @@ func (s *SubAccountRulesBuilder) Build() SubAccountRules {
         b.Write(s.inner[i].AsSlice())
     }
 
-    return SubAccountRules{inner: b.Bytes()}
+    return ASTExpressions{inner: b.Bytes()}
 }
           
 
-func (s *SubAccountRulesBuilder) Set(v []SubAccountRule) *SubAccountRulesBuilder {
+func (s *ASTExpressionsBuilder) Set(v []ASTExpression) *ASTExpressionsBuilder {
     s.inner = v
     return s
 }
-func (s *SubAccountRulesBuilder) Push(v SubAccountRule) *SubAccountRulesBuilder {
+func (s *ASTExpressionsBuilder) Push(v ASTExpression) *ASTExpressionsBuilder {
     s.inner = append(s.inner, v)
     return s
 }
-func (s *SubAccountRulesBuilder) Extend(iter []SubAccountRule) *SubAccountRulesBuilder {
+func (s *ASTExpressionsBuilder) Extend(iter []ASTExpression) *ASTExpressionsBuilder {
     for i:=0; i < len(iter); i++ {
         s.inner = append(s.inner, iter[i])
     }
     return s
 }
-func (s *SubAccountRulesBuilder) Replace(index uint, v SubAccountRule) *SubAccountRule {
+func (s *ASTExpressionsBuilder) Replace(index uint, v ASTExpression) *ASTExpression {
     if uint(len(s.inner)) > index {
         a := s.inner[index]
         s.inner[index] = v
@@ -12310,60 +12462,60 @@ func (s *SubAccountRulesBuilder) Replace(index uint, v SubAccountRule) *SubAccou
 }
     
 
-func NewSubAccountRulesBuilder() *SubAccountRulesBuilder {
-	return &SubAccountRulesBuilder{ []SubAccountRule{} }
+func NewASTExpressionsBuilder() *ASTExpressionsBuilder {
+	return &ASTExpressionsBuilder{ []ASTExpression{} }
 }
         
 
-type SubAccountRules struct {
+type ASTExpressions struct {
     inner []byte
 }
         
 
-func SubAccountRulesFromSliceUnchecked(slice []byte) *SubAccountRules {
-    return &SubAccountRules{inner: slice}
+func ASTExpressionsFromSliceUnchecked(slice []byte) *ASTExpressions {
+    return &ASTExpressions{inner: slice}
 }
-func (s *SubAccountRules) AsSlice() []byte {
+func (s *ASTExpressions) AsSlice() []byte {
     return s.inner
 }
             
 
-func SubAccountRulesDefault() SubAccountRules {
-    return *SubAccountRulesFromSliceUnchecked([]byte{ 4,0,0,0 })
+func ASTExpressionsDefault() ASTExpressions {
+    return *ASTExpressionsFromSliceUnchecked([]byte{ 4,0,0,0 })
 }
             
 
-func SubAccountRulesFromSlice(slice []byte, compatible bool) (*SubAccountRules, error) {
+func ASTExpressionsFromSlice(slice []byte, compatible bool) (*ASTExpressions, error) {
     sliceLen := len(slice)
 
     if uint32(sliceLen) < HeaderSizeUint {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "SubAccountRules", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ASTExpressions", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
         return nil, errors.New(errMsg)
     }
 
     totalSize := unpackNumber(slice)
     if Number(sliceLen) != totalSize {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "SubAccountRules", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ASTExpressions", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if uint32(sliceLen) == HeaderSizeUint {
-        return &SubAccountRules{inner: slice}, nil
+        return &ASTExpressions{inner: slice}, nil
     }
 
     if uint32(sliceLen) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "SubAccountRules", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ASTExpressions", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     offsetFirst := unpackNumber(slice[HeaderSizeUint:])
     if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"OffsetsNotMatch", "SubAccountRules", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "ASTExpressions", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if sliceLen < int(offsetFirst) {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "SubAccountRules", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ASTExpressions", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
         return nil, errors.New(errMsg)
     }
     itemCount := uint32(offsetFirst)/HeaderSizeUint - 1
@@ -12378,7 +12530,7 @@ func SubAccountRulesFromSlice(slice []byte, compatible bool) (*SubAccountRules,
 
     for i := 0; i < len(offsets); i++ {
         if i&1 != 0 && offsets[i-1] > offsets[i] {
-            errMsg := strings.Join([]string{"OffsetsNotMatch", "SubAccountRules"}, " ")
+            errMsg := strings.Join([]string{"OffsetsNotMatch", "ASTExpressions"}, " ")
             return nil, errors.New(errMsg)
         }
     }
@@ -12387,7 +12539,7 @@ func SubAccountRulesFromSlice(slice []byte, compatible bool) (*SubAccountRules,
         if i&1 != 0 {
             start := offsets[i-1]
             end := offsets[i]
-            _, err := SubAccountRuleFromSlice(slice[start:end], compatible)
+            _, err := ASTExpressionFromSlice(slice[start:end], compatible)
 
             if err != nil {
                 return nil, err
@@ -12395,14 +12547,14 @@ func SubAccountRulesFromSlice(slice []byte, compatible bool) (*SubAccountRules,
         }
     }
 
-    return &SubAccountRules{inner: slice}, nil
+    return &ASTExpressions{inner: slice}, nil
 }
             
 
-func (s *SubAccountRules) TotalSize() uint {
+func (s *ASTExpressions) TotalSize() uint {
     return uint(unpackNumber(s.inner))
 }
-func (s *SubAccountRules) ItemCount() uint {
+func (s *ASTExpressions) ItemCount() uint {
     var number uint = 0
     if uint32(s.TotalSize()) == HeaderSizeUint {
         return number
@@ -12410,57 +12562,240 @@ func (s *SubAccountRules) ItemCount() uint {
     number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
     return number
 }
-func (s *SubAccountRules) Len() uint {
+func (s *ASTExpressions) Len() uint {
     return s.ItemCount()
 }
-func (s *SubAccountRules) IsEmpty() bool {
+func (s *ASTExpressions) IsEmpty() bool {
+    return s.Len() == 0
+}
+// if *ASTExpression is nil, index is out of bounds
+func (s *ASTExpressions) Get(index uint) *ASTExpression {
+    var b *ASTExpression
+    if index < s.Len() {
+        start_index := uint(HeaderSizeUint) * (1 + index)
+        start := unpackNumber(s.inner[start_index:])
+
+        if index == s.Len()-1 {
+            b = ASTExpressionFromSliceUnchecked(s.inner[start:])
+        } else {
+            end_index := start_index + uint(HeaderSizeUint)
+            end := unpackNumber(s.inner[end_index:])
+            b = ASTExpressionFromSliceUnchecked(s.inner[start:end])
+        }
+    }
+    return b
+}
+            
+
+func (s *ASTExpressions) AsBuilder() ASTExpressionsBuilder {
+    size := s.ItemCount()
+    t := NewASTExpressionsBuilder()
+    for i:=uint(0); i < size; i++ {
+        t.Push(*s.Get(i))
+    }
+    return *t
+}
+        
+
+type ASTOperatorBuilder struct {
+    symbol Byte
+expressions ASTExpressions
+}
+        
+
+func (s *ASTOperatorBuilder) Build() ASTOperator {
+    b := new(bytes.Buffer)
+
+    totalSize := HeaderSizeUint * (2 + 1)
+    offsets := make([]uint32, 0, 2)
+
+    offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.symbol.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.expressions.AsSlice()))
+
+    b.Write(packNumber(Number(totalSize)))
+
+    for i := 0; i < len(offsets); i++ {
+        b.Write(packNumber(Number(offsets[i])))
+    }
+
+    b.Write(s.symbol.AsSlice())
+b.Write(s.expressions.AsSlice())
+    return ASTOperator{inner: b.Bytes()}
+}
+                
+
+func (s *ASTOperatorBuilder) Symbol(v Byte) *ASTOperatorBuilder {
+    s.symbol = v
+    return s
+}
+            
+
+func (s *ASTOperatorBuilder) Expressions(v ASTExpressions) *ASTOperatorBuilder {
+    s.expressions = v
+    return s
+}
+            
+
+func NewASTOperatorBuilder() *ASTOperatorBuilder {
+	return &ASTOperatorBuilder{ symbol: ByteDefault(),expressions: ASTExpressionsDefault() }
+}
+    
+
+type ASTOperator struct {
+    inner []byte
+}
+        
+
+func ASTOperatorFromSliceUnchecked(slice []byte) *ASTOperator {
+    return &ASTOperator{inner: slice}
+}
+func (s *ASTOperator) AsSlice() []byte {
+    return s.inner
+}
+            
+
+func ASTOperatorDefault() ASTOperator {
+    return *ASTOperatorFromSliceUnchecked([]byte{ 17,0,0,0,12,0,0,0,13,0,0,0,0,4,0,0,0 })
+}
+            
+
+func ASTOperatorFromSlice(slice []byte, compatible bool) (*ASTOperator, error) {
+    sliceLen := len(slice)
+    if uint32(sliceLen) < HeaderSizeUint {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ASTOperator", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    totalSize := unpackNumber(slice)
+    if Number(sliceLen) != totalSize {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ASTOperator", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if uint32(sliceLen) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ASTOperator", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    offsetFirst := unpackNumber(slice[HeaderSizeUint:])
+    if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "ASTOperator", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if sliceLen < int(offsetFirst) {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ASTOperator", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
+    if fieldCount < 2 {
+        return nil, errors.New("FieldCountNotMatch")
+    } else if !compatible && fieldCount > 2 {
+        return nil, errors.New("FieldCountNotMatch")
+    }
+
+    offsets := make([]uint32, fieldCount)
+
+    for i := 0; i < int(fieldCount); i++ {
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    }
+    offsets = append(offsets, uint32(totalSize))
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            return nil, errors.New("OffsetsNotMatch")
+        }
+    }
+
+    var err error
+    
+_, err = ByteFromSlice(slice[offsets[0]:offsets[1]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = ASTExpressionsFromSlice(slice[offsets[1]:offsets[2]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+    return &ASTOperator{inner: slice}, nil
+}
+            
+
+func (s *ASTOperator) TotalSize() uint {
+    return uint(unpackNumber(s.inner))
+}
+func (s *ASTOperator) FieldCount() uint {
+    var number uint = 0
+    if uint32(s.TotalSize()) == HeaderSizeUint {
+        return number
+    }
+    number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
+    return number
+}
+func (s *ASTOperator) Len() uint {
+    return s.FieldCount()
+}
+func (s *ASTOperator) IsEmpty() bool {
     return s.Len() == 0
 }
-// if *SubAccountRule is nil, index is out of bounds
-func (s *SubAccountRules) Get(index uint) *SubAccountRule {
-    var b *SubAccountRule
-    if index < s.Len() {
-        start_index := uint(HeaderSizeUint) * (1 + index)
-        start := unpackNumber(s.inner[start_index:])
+func (s *ASTOperator) CountExtraFields() uint {
+    return s.FieldCount() - 2
+}
 
-        if index == s.Len()-1 {
-            b = SubAccountRuleFromSliceUnchecked(s.inner[start:])
-        } else {
-            end_index := start_index + uint(HeaderSizeUint)
-            end := unpackNumber(s.inner[end_index:])
-            b = SubAccountRuleFromSliceUnchecked(s.inner[start:end])
-        }
-    }
-    return b
+func (s *ASTOperator) HasExtraFields() bool {
+    return 2 != s.FieldCount()
 }
             
 
-func (s *SubAccountRules) AsBuilder() SubAccountRulesBuilder {
-    size := s.ItemCount()
-    t := NewSubAccountRulesBuilder()
-    for i:=uint(0); i < size; i++ {
-        t.Push(*s.Get(i))
+func (s *ASTOperator) Symbol() *Byte {
+    start := unpackNumber(s.inner[4:])
+    end := unpackNumber(s.inner[8:])
+    return ByteFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ASTOperator) Expressions() *ASTExpressions {
+    var ret *ASTExpressions
+    start := unpackNumber(s.inner[8:])
+    if s.HasExtraFields() {
+        end := unpackNumber(s.inner[12:])
+        ret = ASTExpressionsFromSliceUnchecked(s.inner[start:end])
+    } else {
+        ret = ASTExpressionsFromSliceUnchecked(s.inner[start:])
     }
-    return *t
+    return ret
+}
+                        
+
+func (s *ASTOperator) AsBuilder() ASTOperatorBuilder {
+    ret := NewASTOperatorBuilder().Symbol(*s.Symbol()).Expressions(*s.Expressions())
+    return *ret
 }
         
 
-type ASTExpressionBuilder struct {
-    expression_type Byte
-expression Bytes
+type ASTFunctionBuilder struct {
+    name Byte
+arguments ASTExpressions
 }
         
 
-func (s *ASTExpressionBuilder) Build() ASTExpression {
+func (s *ASTFunctionBuilder) Build() ASTFunction {
     b := new(bytes.Buffer)
 
     totalSize := HeaderSizeUint * (2 + 1)
     offsets := make([]uint32, 0, 2)
 
     offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.expression_type.AsSlice()))
+totalSize += uint32(len(s.name.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.expression.AsSlice()))
+totalSize += uint32(len(s.arguments.AsSlice()))
 
     b.Write(packNumber(Number(totalSize)))
 
@@ -12468,73 +12803,73 @@ totalSize += uint32(len(s.expression.AsSlice()))
         b.Write(packNumber(Number(offsets[i])))
     }
 
-    b.Write(s.expression_type.AsSlice())
-b.Write(s.expression.AsSlice())
-    return ASTExpression{inner: b.Bytes()}
+    b.Write(s.name.AsSlice())
+b.Write(s.arguments.AsSlice())
+    return ASTFunction{inner: b.Bytes()}
 }
                 
 
-func (s *ASTExpressionBuilder) ExpressionType(v Byte) *ASTExpressionBuilder {
-    s.expression_type = v
+func (s *ASTFunctionBuilder) Name(v Byte) *ASTFunctionBuilder {
+    s.name = v
     return s
 }
             
 
-func (s *ASTExpressionBuilder) Expression(v Bytes) *ASTExpressionBuilder {
-    s.expression = v
+func (s *ASTFunctionBuilder) Arguments(v ASTExpressions) *ASTFunctionBuilder {
+    s.arguments = v
     return s
 }
             
 
-func NewASTExpressionBuilder() *ASTExpressionBuilder {
-	return &ASTExpressionBuilder{ expression_type: ByteDefault(),expression: BytesDefault() }
+func NewASTFunctionBuilder() *ASTFunctionBuilder {
+	return &ASTFunctionBuilder{ name: ByteDefault(),arguments: ASTExpressionsDefault() }
 }
     
 
-type ASTExpression struct {
+type ASTFunction struct {
     inner []byte
 }
         
 
-func ASTExpressionFromSliceUnchecked(slice []byte) *ASTExpression {
-    return &ASTExpression{inner: slice}
+func ASTFunctionFromSliceUnchecked(slice []byte) *ASTFunction {
+    return &ASTFunction{inner: slice}
 }
-func (s *ASTExpression) AsSlice() []byte {
+func (s *ASTFunction) AsSlice() []byte {
     return s.inner
 }
             
 
-func ASTExpressionDefault() ASTExpression {
-    return *ASTExpressionFromSliceUnchecked([]byte{ 17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0 })
+func ASTFunctionDefault() ASTFunction {
+    return *ASTFunctionFromSliceUnchecked([]byte{ 17,0,0,0,12,0,0,0,13,0,0,0,0,4,0,0,0 })
 }
             
 
-func ASTExpressionFromSlice(slice []byte, compatible bool) (*ASTExpression, error) {
+func ASTFunctionFromSlice(slice []byte, compatible bool) (*ASTFunction, error) {
     sliceLen := len(slice)
     if uint32(sliceLen) < HeaderSizeUint {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "ASTExpression", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ASTFunction", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
         return nil, errors.New(errMsg)
     }
 
     totalSize := unpackNumber(slice)
     if Number(sliceLen) != totalSize {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ASTExpression", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ASTFunction", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if uint32(sliceLen) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ASTExpression", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ASTFunction", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     offsetFirst := unpackNumber(slice[HeaderSizeUint:])
     if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"OffsetsNotMatch", "ASTExpression", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "ASTFunction", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if sliceLen < int(offsetFirst) {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "ASTExpression", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ASTFunction", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
         return nil, errors.New(errMsg)
     }
 
@@ -12566,20 +12901,20 @@ if err != nil {
 }
                 
 
-_, err = BytesFromSlice(slice[offsets[1]:offsets[2]], compatible)
+_, err = ASTExpressionsFromSlice(slice[offsets[1]:offsets[2]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-    return &ASTExpression{inner: slice}, nil
+    return &ASTFunction{inner: slice}, nil
 }
             
 
-func (s *ASTExpression) TotalSize() uint {
+func (s *ASTFunction) TotalSize() uint {
     return uint(unpackNumber(s.inner))
 }
-func (s *ASTExpression) FieldCount() uint {
+func (s *ASTFunction) FieldCount() uint {
     var number uint = 0
     if uint32(s.TotalSize()) == HeaderSizeUint {
         return number
@@ -12587,204 +12922,166 @@ func (s *ASTExpression) FieldCount() uint {
     number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
     return number
 }
-func (s *ASTExpression) Len() uint {
+func (s *ASTFunction) Len() uint {
     return s.FieldCount()
 }
-func (s *ASTExpression) IsEmpty() bool {
+func (s *ASTFunction) IsEmpty() bool {
     return s.Len() == 0
 }
-func (s *ASTExpression) CountExtraFields() uint {
+func (s *ASTFunction) CountExtraFields() uint {
     return s.FieldCount() - 2
 }
 
-func (s *ASTExpression) HasExtraFields() bool {
+func (s *ASTFunction) HasExtraFields() bool {
     return 2 != s.FieldCount()
 }
             
 
-func (s *ASTExpression) ExpressionType() *Byte {
+func (s *ASTFunction) Name() *Byte {
     start := unpackNumber(s.inner[4:])
     end := unpackNumber(s.inner[8:])
     return ByteFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ASTExpression) Expression() *Bytes {
-    var ret *Bytes
+func (s *ASTFunction) Arguments() *ASTExpressions {
+    var ret *ASTExpressions
     start := unpackNumber(s.inner[8:])
     if s.HasExtraFields() {
         end := unpackNumber(s.inner[12:])
-        ret = BytesFromSliceUnchecked(s.inner[start:end])
+        ret = ASTExpressionsFromSliceUnchecked(s.inner[start:end])
     } else {
-        ret = BytesFromSliceUnchecked(s.inner[start:])
+        ret = ASTExpressionsFromSliceUnchecked(s.inner[start:])
     }
     return ret
 }
                         
 
-func (s *ASTExpression) AsBuilder() ASTExpressionBuilder {
-    ret := NewASTExpressionBuilder().ExpressionType(*s.ExpressionType()).Expression(*s.Expression())
+func (s *ASTFunction) AsBuilder() ASTFunctionBuilder {
+    ret := NewASTFunctionBuilder().Name(*s.Name()).Arguments(*s.Arguments())
     return *ret
 }
         
 
-type ASTExpressionsBuilder struct {
-    inner []ASTExpression
+type ASTVariableBuilder struct {
+    name Byte
 }
-    
-
-func (s *ASTExpressionsBuilder) Build() ASTExpressions {
-    itemCount := len(s.inner)
+        
 
+func (s *ASTVariableBuilder) Build() ASTVariable {
     b := new(bytes.Buffer)
 
-    // Empty dyn vector, just return size's bytes
-    if itemCount == 0 {
-        b.Write(packNumber(Number(HeaderSizeUint)))
-        return ASTExpressions{inner: b.Bytes()}
-    }
+    totalSize := HeaderSizeUint * (1 + 1)
+    offsets := make([]uint32, 0, 1)
 
-    // Calculate first offset then loop for rest items offsets
-    totalSize := HeaderSizeUint * uint32(itemCount+1)
-    offsets := make([]uint32, 0, itemCount)
     offsets = append(offsets, totalSize)
-    for i := 1; i < itemCount; i++ {
-        totalSize += uint32(len(s.inner[i-1].AsSlice()))
-        offsets = append(offsets, offsets[i-1]+uint32(len(s.inner[i-1].AsSlice())))
-    }
-    totalSize += uint32(len(s.inner[itemCount-1].AsSlice()))
+totalSize += uint32(len(s.name.AsSlice()))
 
     b.Write(packNumber(Number(totalSize)))
 
-    for i := 0; i < itemCount; i++ {
+    for i := 0; i < len(offsets); i++ {
         b.Write(packNumber(Number(offsets[i])))
     }
 
-    for i := 0; i < itemCount; i++ {
-        b.Write(s.inner[i].AsSlice())
-    }
-
-    return ASTExpressions{inner: b.Bytes()}
+    b.Write(s.name.AsSlice())
+    return ASTVariable{inner: b.Bytes()}
 }
-          
+                
 
-func (s *ASTExpressionsBuilder) Set(v []ASTExpression) *ASTExpressionsBuilder {
-    s.inner = v
-    return s
-}
-func (s *ASTExpressionsBuilder) Push(v ASTExpression) *ASTExpressionsBuilder {
-    s.inner = append(s.inner, v)
-    return s
-}
-func (s *ASTExpressionsBuilder) Extend(iter []ASTExpression) *ASTExpressionsBuilder {
-    for i:=0; i < len(iter); i++ {
-        s.inner = append(s.inner, iter[i])
-    }
+func (s *ASTVariableBuilder) Name(v Byte) *ASTVariableBuilder {
+    s.name = v
     return s
 }
-func (s *ASTExpressionsBuilder) Replace(index uint, v ASTExpression) *ASTExpression {
-    if uint(len(s.inner)) > index {
-        a := s.inner[index]
-        s.inner[index] = v
-        return &a
-    }
-    return nil
-}
-    
+            
 
-func NewASTExpressionsBuilder() *ASTExpressionsBuilder {
-	return &ASTExpressionsBuilder{ []ASTExpression{} }
+func NewASTVariableBuilder() *ASTVariableBuilder {
+	return &ASTVariableBuilder{ name: ByteDefault() }
 }
-        
+    
 
-type ASTExpressions struct {
+type ASTVariable struct {
     inner []byte
 }
         
 
-func ASTExpressionsFromSliceUnchecked(slice []byte) *ASTExpressions {
-    return &ASTExpressions{inner: slice}
+func ASTVariableFromSliceUnchecked(slice []byte) *ASTVariable {
+    return &ASTVariable{inner: slice}
 }
-func (s *ASTExpressions) AsSlice() []byte {
+func (s *ASTVariable) AsSlice() []byte {
     return s.inner
 }
             
 
-func ASTExpressionsDefault() ASTExpressions {
-    return *ASTExpressionsFromSliceUnchecked([]byte{ 4,0,0,0 })
+func ASTVariableDefault() ASTVariable {
+    return *ASTVariableFromSliceUnchecked([]byte{ 9,0,0,0,8,0,0,0,0 })
 }
             
 
-func ASTExpressionsFromSlice(slice []byte, compatible bool) (*ASTExpressions, error) {
+func ASTVariableFromSlice(slice []byte, compatible bool) (*ASTVariable, error) {
     sliceLen := len(slice)
-
     if uint32(sliceLen) < HeaderSizeUint {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "ASTExpressions", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ASTVariable", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
         return nil, errors.New(errMsg)
     }
 
     totalSize := unpackNumber(slice)
     if Number(sliceLen) != totalSize {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ASTExpressions", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ASTVariable", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
         return nil, errors.New(errMsg)
     }
 
-    if uint32(sliceLen) == HeaderSizeUint {
-        return &ASTExpressions{inner: slice}, nil
-    }
-
     if uint32(sliceLen) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ASTExpressions", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ASTVariable", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     offsetFirst := unpackNumber(slice[HeaderSizeUint:])
     if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"OffsetsNotMatch", "ASTExpressions", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "ASTVariable", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if sliceLen < int(offsetFirst) {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "ASTExpressions", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ASTVariable", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
         return nil, errors.New(errMsg)
     }
-    itemCount := uint32(offsetFirst)/HeaderSizeUint - 1
-
-    offsets := make([]uint32, itemCount)
 
-    for i := 0; i < int(itemCount); i++ {
-        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
+    if fieldCount < 1 {
+        return nil, errors.New("FieldCountNotMatch")
+    } else if !compatible && fieldCount > 1 {
+        return nil, errors.New("FieldCountNotMatch")
     }
 
-    offsets = append(offsets, uint32(totalSize))
+    offsets := make([]uint32, fieldCount)
 
-    for i := 0; i < len(offsets); i++ {
-        if i&1 != 0 && offsets[i-1] > offsets[i] {
-            errMsg := strings.Join([]string{"OffsetsNotMatch", "ASTExpressions"}, " ")
-            return nil, errors.New(errMsg)
-        }
+    for i := 0; i < int(fieldCount); i++ {
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
     }
+    offsets = append(offsets, uint32(totalSize))
 
     for i := 0; i < len(offsets); i++ {
-        if i&1 != 0 {
-            start := offsets[i-1]
-            end := offsets[i]
-            _, err := ASTExpressionFromSlice(slice[start:end], compatible)
-
-            if err != nil {
-                return nil, err
-            }
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            return nil, errors.New("OffsetsNotMatch")
         }
     }
 
-    return &ASTExpressions{inner: slice}, nil
+    var err error
+    
+_, err = ByteFromSlice(slice[offsets[0]:offsets[1]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+    return &ASTVariable{inner: slice}, nil
 }
             
 
-func (s *ASTExpressions) TotalSize() uint {
+func (s *ASTVariable) TotalSize() uint {
     return uint(unpackNumber(s.inner))
 }
-func (s *ASTExpressions) ItemCount() uint {
+func (s *ASTVariable) FieldCount() uint {
     var number uint = 0
     if uint32(s.TotalSize()) == HeaderSizeUint {
         return number
@@ -12792,57 +13089,56 @@ func (s *ASTExpressions) ItemCount() uint {
     number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
     return number
 }
-func (s *ASTExpressions) Len() uint {
-    return s.ItemCount()
+func (s *ASTVariable) Len() uint {
+    return s.FieldCount()
 }
-func (s *ASTExpressions) IsEmpty() bool {
+func (s *ASTVariable) IsEmpty() bool {
     return s.Len() == 0
 }
-// if *ASTExpression is nil, index is out of bounds
-func (s *ASTExpressions) Get(index uint) *ASTExpression {
-    var b *ASTExpression
-    if index < s.Len() {
-        start_index := uint(HeaderSizeUint) * (1 + index)
-        start := unpackNumber(s.inner[start_index:])
+func (s *ASTVariable) CountExtraFields() uint {
+    return s.FieldCount() - 1
+}
 
-        if index == s.Len()-1 {
-            b = ASTExpressionFromSliceUnchecked(s.inner[start:])
-        } else {
-            end_index := start_index + uint(HeaderSizeUint)
-            end := unpackNumber(s.inner[end_index:])
-            b = ASTExpressionFromSliceUnchecked(s.inner[start:end])
-        }
-    }
-    return b
+func (s *ASTVariable) HasExtraFields() bool {
+    return 1 != s.FieldCount()
 }
             
 
-func (s *ASTExpressions) AsBuilder() ASTExpressionsBuilder {
-    size := s.ItemCount()
-    t := NewASTExpressionsBuilder()
-    for i:=uint(0); i < size; i++ {
-        t.Push(*s.Get(i))
+func (s *ASTVariable) Name() *Byte {
+    var ret *Byte
+    start := unpackNumber(s.inner[4:])
+    if s.HasExtraFields() {
+        end := unpackNumber(s.inner[8:])
+        ret = ByteFromSliceUnchecked(s.inner[start:end])
+    } else {
+        ret = ByteFromSliceUnchecked(s.inner[start:])
     }
-    return *t
+    return ret
+}
+                        
+
+func (s *ASTVariable) AsBuilder() ASTVariableBuilder {
+    ret := NewASTVariableBuilder().Name(*s.Name())
+    return *ret
 }
         
 
-type ASTOperatorBuilder struct {
-    symbol Byte
-expressions ASTExpressions
+type ASTValueBuilder struct {
+    value_type Byte
+value Bytes
 }
         
 
-func (s *ASTOperatorBuilder) Build() ASTOperator {
+func (s *ASTValueBuilder) Build() ASTValue {
     b := new(bytes.Buffer)
 
     totalSize := HeaderSizeUint * (2 + 1)
     offsets := make([]uint32, 0, 2)
 
     offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.symbol.AsSlice()))
+totalSize += uint32(len(s.value_type.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.expressions.AsSlice()))
+totalSize += uint32(len(s.value.AsSlice()))
 
     b.Write(packNumber(Number(totalSize)))
 
@@ -12850,73 +13146,73 @@ totalSize += uint32(len(s.expressions.AsSlice()))
         b.Write(packNumber(Number(offsets[i])))
     }
 
-    b.Write(s.symbol.AsSlice())
-b.Write(s.expressions.AsSlice())
-    return ASTOperator{inner: b.Bytes()}
+    b.Write(s.value_type.AsSlice())
+b.Write(s.value.AsSlice())
+    return ASTValue{inner: b.Bytes()}
 }
                 
 
-func (s *ASTOperatorBuilder) Symbol(v Byte) *ASTOperatorBuilder {
-    s.symbol = v
+func (s *ASTValueBuilder) ValueType(v Byte) *ASTValueBuilder {
+    s.value_type = v
     return s
 }
             
 
-func (s *ASTOperatorBuilder) Expressions(v ASTExpressions) *ASTOperatorBuilder {
-    s.expressions = v
+func (s *ASTValueBuilder) Value(v Bytes) *ASTValueBuilder {
+    s.value = v
     return s
 }
             
 
-func NewASTOperatorBuilder() *ASTOperatorBuilder {
-	return &ASTOperatorBuilder{ symbol: ByteDefault(),expressions: ASTExpressionsDefault() }
+func NewASTValueBuilder() *ASTValueBuilder {
+	return &ASTValueBuilder{ value_type: ByteDefault(),value: BytesDefault() }
 }
     
 
-type ASTOperator struct {
+type ASTValue struct {
     inner []byte
 }
         
 
-func ASTOperatorFromSliceUnchecked(slice []byte) *ASTOperator {
-    return &ASTOperator{inner: slice}
+func ASTValueFromSliceUnchecked(slice []byte) *ASTValue {
+    return &ASTValue{inner: slice}
 }
-func (s *ASTOperator) AsSlice() []byte {
+func (s *ASTValue) AsSlice() []byte {
     return s.inner
 }
             
 
-func ASTOperatorDefault() ASTOperator {
-    return *ASTOperatorFromSliceUnchecked([]byte{ 17,0,0,0,12,0,0,0,13,0,0,0,0,4,0,0,0 })
+func ASTValueDefault() ASTValue {
+    return *ASTValueFromSliceUnchecked([]byte{ 17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0 })
 }
             
 
-func ASTOperatorFromSlice(slice []byte, compatible bool) (*ASTOperator, error) {
+func ASTValueFromSlice(slice []byte, compatible bool) (*ASTValue, error) {
     sliceLen := len(slice)
     if uint32(sliceLen) < HeaderSizeUint {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "ASTOperator", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ASTValue", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
         return nil, errors.New(errMsg)
     }
 
     totalSize := unpackNumber(slice)
     if Number(sliceLen) != totalSize {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ASTOperator", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ASTValue", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if uint32(sliceLen) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ASTOperator", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ASTValue", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     offsetFirst := unpackNumber(slice[HeaderSizeUint:])
     if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"OffsetsNotMatch", "ASTOperator", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "ASTValue", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if sliceLen < int(offsetFirst) {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "ASTOperator", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ASTValue", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
         return nil, errors.New(errMsg)
     }
 
@@ -12948,20 +13244,20 @@ if err != nil {
 }
                 
 
-_, err = ASTExpressionsFromSlice(slice[offsets[1]:offsets[2]], compatible)
+_, err = BytesFromSlice(slice[offsets[1]:offsets[2]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-    return &ASTOperator{inner: slice}, nil
+    return &ASTValue{inner: slice}, nil
 }
             
 
-func (s *ASTOperator) TotalSize() uint {
+func (s *ASTValue) TotalSize() uint {
     return uint(unpackNumber(s.inner))
 }
-func (s *ASTOperator) FieldCount() uint {
+func (s *ASTValue) FieldCount() uint {
     var number uint = 0
     if uint32(s.TotalSize()) == HeaderSizeUint {
         return number
@@ -12969,243 +13265,295 @@ func (s *ASTOperator) FieldCount() uint {
     number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
     return number
 }
-func (s *ASTOperator) Len() uint {
+func (s *ASTValue) Len() uint {
     return s.FieldCount()
 }
-func (s *ASTOperator) IsEmpty() bool {
+func (s *ASTValue) IsEmpty() bool {
     return s.Len() == 0
 }
-func (s *ASTOperator) CountExtraFields() uint {
+func (s *ASTValue) CountExtraFields() uint {
     return s.FieldCount() - 2
 }
 
-func (s *ASTOperator) HasExtraFields() bool {
+func (s *ASTValue) HasExtraFields() bool {
     return 2 != s.FieldCount()
 }
             
 
-func (s *ASTOperator) Symbol() *Byte {
+func (s *ASTValue) ValueType() *Byte {
     start := unpackNumber(s.inner[4:])
     end := unpackNumber(s.inner[8:])
     return ByteFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ASTOperator) Expressions() *ASTExpressions {
-    var ret *ASTExpressions
+func (s *ASTValue) Value() *Bytes {
+    var ret *Bytes
     start := unpackNumber(s.inner[8:])
     if s.HasExtraFields() {
         end := unpackNumber(s.inner[12:])
-        ret = ASTExpressionsFromSliceUnchecked(s.inner[start:end])
+        ret = BytesFromSliceUnchecked(s.inner[start:end])
     } else {
-        ret = ASTExpressionsFromSliceUnchecked(s.inner[start:])
+        ret = BytesFromSliceUnchecked(s.inner[start:])
     }
     return ret
 }
                         
 
-func (s *ASTOperator) AsBuilder() ASTOperatorBuilder {
-    ret := NewASTOperatorBuilder().Symbol(*s.Symbol()).Expressions(*s.Expressions())
+func (s *ASTValue) AsBuilder() ASTValueBuilder {
+    ret := NewASTValueBuilder().ValueType(*s.ValueType()).Value(*s.Value())
     return *ret
 }
         
 
-type ASTFunctionBuilder struct {
-    name Byte
-arguments ASTExpressions
+type DeviceKeyBuilder struct {
+    main_alg_id Uint8
+sub_alg_id Uint8
+cid Byte10
+pubkey Byte10
 }
         
 
-func (s *ASTFunctionBuilder) Build() ASTFunction {
+func (s *DeviceKeyBuilder) Build() DeviceKey {
     b := new(bytes.Buffer)
+    b.Write(s.main_alg_id.AsSlice())
+b.Write(s.sub_alg_id.AsSlice())
+b.Write(s.cid.AsSlice())
+b.Write(s.pubkey.AsSlice())
+    return DeviceKey{inner: b.Bytes()}
+}
+            
 
-    totalSize := HeaderSizeUint * (2 + 1)
-    offsets := make([]uint32, 0, 2)
+func (s *DeviceKeyBuilder) MainAlgId(v Uint8) *DeviceKeyBuilder {
+    s.main_alg_id = v
+    return s
+}
+            
+
+func (s *DeviceKeyBuilder) SubAlgId(v Uint8) *DeviceKeyBuilder {
+    s.sub_alg_id = v
+    return s
+}
+            
+
+func (s *DeviceKeyBuilder) Cid(v Byte10) *DeviceKeyBuilder {
+    s.cid = v
+    return s
+}
+            
+
+func (s *DeviceKeyBuilder) Pubkey(v Byte10) *DeviceKeyBuilder {
+    s.pubkey = v
+    return s
+}
+            
+
+func NewDeviceKeyBuilder() *DeviceKeyBuilder {
+	return &DeviceKeyBuilder{ main_alg_id: Uint8Default(),sub_alg_id: Uint8Default(),cid: Byte10Default(),pubkey: Byte10Default() }
+}
+    
+
+type DeviceKey struct {
+    inner []byte
+}
+        
+
+func DeviceKeyFromSliceUnchecked(slice []byte) *DeviceKey {
+    return &DeviceKey{inner: slice}
+}
+func (s *DeviceKey) AsSlice() []byte {
+    return s.inner
+}
+            
+
+func DeviceKeyDefault() DeviceKey {
+    return *DeviceKeyFromSliceUnchecked([]byte{ 0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+}
+            
+
+func DeviceKeyFromSlice(slice []byte, _compatible bool) (*DeviceKey, error) {
+    sliceLen := len(slice)
+    if sliceLen != 22 {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "DeviceKey", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(22)}, " ")
+        return nil, errors.New(errMsg)
+    }
+    return &DeviceKey{inner: slice}, nil
+}
+        
+
+func (s *DeviceKey) MainAlgId() *Uint8 {
+    ret := Uint8FromSliceUnchecked(s.inner[0:1])
+    return ret
+}
+                
+
+func (s *DeviceKey) SubAlgId() *Uint8 {
+    ret := Uint8FromSliceUnchecked(s.inner[1:2])
+    return ret
+}
+                
+
+func (s *DeviceKey) Cid() *Byte10 {
+    ret := Byte10FromSliceUnchecked(s.inner[2:12])
+    return ret
+}
+                
+
+func (s *DeviceKey) Pubkey() *Byte10 {
+    ret := Byte10FromSliceUnchecked(s.inner[12:22])
+    return ret
+}
+                
+
+func (s *DeviceKey) AsBuilder() DeviceKeyBuilder {
+    ret := NewDeviceKeyBuilder().MainAlgId(*s.MainAlgId()).SubAlgId(*s.SubAlgId()).Cid(*s.Cid()).Pubkey(*s.Pubkey())
+    return *ret
+}
+        
+
+type DeviceKeyListBuilder struct {
+    inner []DeviceKey
+}
+    
 
-    offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.name.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.arguments.AsSlice()))
+func (s *DeviceKeyListBuilder) Build() DeviceKeyList {
+    size := packNumber(Number(len(s.inner)))
 
-    b.Write(packNumber(Number(totalSize)))
+    b := new(bytes.Buffer)
 
-    for i := 0; i < len(offsets); i++ {
-        b.Write(packNumber(Number(offsets[i])))
+    b.Write(size)
+    len := len(s.inner)
+    for i := 0; i < len; i++ {
+        b.Write(s.inner[i].AsSlice())
     }
 
-    b.Write(s.name.AsSlice())
-b.Write(s.arguments.AsSlice())
-    return ASTFunction{inner: b.Bytes()}
-}
-                
+    sb := DeviceKeyList{inner: b.Bytes()}
 
-func (s *ASTFunctionBuilder) Name(v Byte) *ASTFunctionBuilder {
-    s.name = v
-    return s
+    return sb
 }
             
 
-func (s *ASTFunctionBuilder) Arguments(v ASTExpressions) *ASTFunctionBuilder {
-    s.arguments = v
+func (s *DeviceKeyListBuilder) Set(v []DeviceKey) *DeviceKeyListBuilder {
+    s.inner = v
     return s
 }
-            
-
-func NewASTFunctionBuilder() *ASTFunctionBuilder {
-	return &ASTFunctionBuilder{ name: ByteDefault(),arguments: ASTExpressionsDefault() }
+func (s *DeviceKeyListBuilder) Push(v DeviceKey) *DeviceKeyListBuilder {
+    s.inner = append(s.inner, v)
+    return s
+}
+func (s *DeviceKeyListBuilder) Extend(iter []DeviceKey) *DeviceKeyListBuilder {
+    for i:=0; i < len(iter); i++ {
+        s.inner = append(s.inner, iter[i])
+    }
+    return s
+}
+func (s *DeviceKeyListBuilder) Replace(index uint, v DeviceKey) *DeviceKey {
+    if uint(len(s.inner)) > index {
+        a := s.inner[index]
+        s.inner[index] = v
+        return &a
+    }
+    return nil
 }
     
 
-type ASTFunction struct {
+func NewDeviceKeyListBuilder() *DeviceKeyListBuilder {
+	return &DeviceKeyListBuilder{ []DeviceKey{} }
+}
+        
+
+type DeviceKeyList struct {
     inner []byte
 }
         
 
-func ASTFunctionFromSliceUnchecked(slice []byte) *ASTFunction {
-    return &ASTFunction{inner: slice}
+func DeviceKeyListFromSliceUnchecked(slice []byte) *DeviceKeyList {
+    return &DeviceKeyList{inner: slice}
 }
-func (s *ASTFunction) AsSlice() []byte {
+func (s *DeviceKeyList) AsSlice() []byte {
     return s.inner
 }
             
 
-func ASTFunctionDefault() ASTFunction {
-    return *ASTFunctionFromSliceUnchecked([]byte{ 17,0,0,0,12,0,0,0,13,0,0,0,0,4,0,0,0 })
+func DeviceKeyListDefault() DeviceKeyList {
+    return *DeviceKeyListFromSliceUnchecked([]byte{ 0,0,0,0 })
 }
             
 
-func ASTFunctionFromSlice(slice []byte, compatible bool) (*ASTFunction, error) {
+func DeviceKeyListFromSlice(slice []byte, _compatible bool) (*DeviceKeyList, error) {
     sliceLen := len(slice)
-    if uint32(sliceLen) < HeaderSizeUint {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "ASTFunction", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
-        return nil, errors.New(errMsg)
-    }
-
-    totalSize := unpackNumber(slice)
-    if Number(sliceLen) != totalSize {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ASTFunction", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
-        return nil, errors.New(errMsg)
-    }
-
-    if uint32(sliceLen) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ASTFunction", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+    if sliceLen < int(HeaderSizeUint) {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "DeviceKeyList", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
         return nil, errors.New(errMsg)
     }
-
-    offsetFirst := unpackNumber(slice[HeaderSizeUint:])
-    if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"OffsetsNotMatch", "ASTFunction", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
-        return nil, errors.New(errMsg)
+    itemCount := unpackNumber(slice)
+    if itemCount == 0 {
+        if sliceLen != int(HeaderSizeUint) {
+            errMsg := strings.Join([]string{"TotalSizeNotMatch", "DeviceKeyList", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(HeaderSizeUint))}, " ")
+            return nil, errors.New(errMsg)
+        }
+        return &DeviceKeyList{inner: slice}, nil
     }
-
-    if sliceLen < int(offsetFirst) {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "ASTFunction", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+    totalSize := int(HeaderSizeUint) + int(22*itemCount)
+    if sliceLen != totalSize {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "DeviceKeyList", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
         return nil, errors.New(errMsg)
     }
-
-    fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
-    if fieldCount < 2 {
-        return nil, errors.New("FieldCountNotMatch")
-    } else if !compatible && fieldCount > 2 {
-        return nil, errors.New("FieldCountNotMatch")
-    }
-
-    offsets := make([]uint32, fieldCount)
-
-    for i := 0; i < int(fieldCount); i++ {
-        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
-    }
-    offsets = append(offsets, uint32(totalSize))
-
-    for i := 0; i < len(offsets); i++ {
-        if i&1 != 0 && offsets[i-1] > offsets[i] {
-            return nil, errors.New("OffsetsNotMatch")
-        }
-    }
-
-    var err error
-    
-_, err = ByteFromSlice(slice[offsets[0]:offsets[1]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = ASTExpressionsFromSlice(slice[offsets[1]:offsets[2]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-    return &ASTFunction{inner: slice}, nil
+    return &DeviceKeyList{inner: slice}, nil
 }
             
 
-func (s *ASTFunction) TotalSize() uint {
-    return uint(unpackNumber(s.inner))
+func (s *DeviceKeyList) TotalSize() uint {
+    return uint(HeaderSizeUint) + 22 * s.ItemCount()
 }
-func (s *ASTFunction) FieldCount() uint {
-    var number uint = 0
-    if uint32(s.TotalSize()) == HeaderSizeUint {
-        return number
-    }
-    number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
+func (s *DeviceKeyList) ItemCount() uint {
+    number := uint(unpackNumber(s.inner))
     return number
 }
-func (s *ASTFunction) Len() uint {
-    return s.FieldCount()
+func (s *DeviceKeyList) Len() uint {
+    return s.ItemCount()
 }
-func (s *ASTFunction) IsEmpty() bool {
+func (s *DeviceKeyList) IsEmpty() bool {
     return s.Len() == 0
 }
-func (s *ASTFunction) CountExtraFields() uint {
-    return s.FieldCount() - 2
-}
-
-func (s *ASTFunction) HasExtraFields() bool {
-    return 2 != s.FieldCount()
-}
-            
-
-func (s *ASTFunction) Name() *Byte {
-    start := unpackNumber(s.inner[4:])
-    end := unpackNumber(s.inner[8:])
-    return ByteFromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *ASTFunction) Arguments() *ASTExpressions {
-    var ret *ASTExpressions
-    start := unpackNumber(s.inner[8:])
-    if s.HasExtraFields() {
-        end := unpackNumber(s.inner[12:])
-        ret = ASTExpressionsFromSliceUnchecked(s.inner[start:end])
-    } else {
-        ret = ASTExpressionsFromSliceUnchecked(s.inner[start:])
+// if *DeviceKey is nil, index is out of bounds
+func (s *DeviceKeyList) Get(index uint) *DeviceKey {
+    var re *DeviceKey
+    if index < s.Len() {
+        start := uint(HeaderSizeUint) + 22*index
+        end := start + 22
+        re = DeviceKeyFromSliceUnchecked(s.inner[start:end])
     }
-    return ret
+    return re
 }
-                        
+        
 
-func (s *ASTFunction) AsBuilder() ASTFunctionBuilder {
-    ret := NewASTFunctionBuilder().Name(*s.Name()).Arguments(*s.Arguments())
-    return *ret
+func (s *DeviceKeyList) AsBuilder() DeviceKeyListBuilder {
+    size := s.ItemCount()
+    t := NewDeviceKeyListBuilder()
+    for i:=uint(0); i < size; i++ {
+        t.Push(*s.Get(i))
+    }
+    return *t
 }
         
 
-type ASTVariableBuilder struct {
-    name Byte
+type DeviceKeyListCellDataBuilder struct {
+    keys DeviceKeyList
+refund_lock Script
 }
         
 
-func (s *ASTVariableBuilder) Build() ASTVariable {
+func (s *DeviceKeyListCellDataBuilder) Build() DeviceKeyListCellData {
     b := new(bytes.Buffer)
 
-    totalSize := HeaderSizeUint * (1 + 1)
-    offsets := make([]uint32, 0, 1)
+    totalSize := HeaderSizeUint * (2 + 1)
+    offsets := make([]uint32, 0, 2)
 
     offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.name.AsSlice()))
+totalSize += uint32(len(s.keys.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.refund_lock.AsSlice()))
 
     b.Write(packNumber(Number(totalSize)))
 
@@ -13213,73 +13561,80 @@ totalSize += uint32(len(s.name.AsSlice()))
         b.Write(packNumber(Number(offsets[i])))
     }
 
-    b.Write(s.name.AsSlice())
-    return ASTVariable{inner: b.Bytes()}
+    b.Write(s.keys.AsSlice())
+b.Write(s.refund_lock.AsSlice())
+    return DeviceKeyListCellData{inner: b.Bytes()}
 }
                 
 
-func (s *ASTVariableBuilder) Name(v Byte) *ASTVariableBuilder {
-    s.name = v
+func (s *DeviceKeyListCellDataBuilder) Keys(v DeviceKeyList) *DeviceKeyListCellDataBuilder {
+    s.keys = v
     return s
 }
             
 
-func NewASTVariableBuilder() *ASTVariableBuilder {
-	return &ASTVariableBuilder{ name: ByteDefault() }
+func (s *DeviceKeyListCellDataBuilder) RefundLock(v Script) *DeviceKeyListCellDataBuilder {
+    s.refund_lock = v
+    return s
+}
+            
+
+func NewDeviceKeyListCellDataBuilder() *DeviceKeyListCellDataBuilder {
+	return &DeviceKeyListCellDataBuilder{ keys: DeviceKeyListDefault(),refund_lock: ScriptDefault() }
 }
     
 
-type ASTVariable struct {
+type DeviceKeyListCellData struct {
     inner []byte
 }
         
 
-func ASTVariableFromSliceUnchecked(slice []byte) *ASTVariable {
-    return &ASTVariable{inner: slice}
+func DeviceKeyListCellDataFromSliceUnchecked(slice []byte) *DeviceKeyListCellData {
+    return &DeviceKeyListCellData{inner: slice}
 }
-func (s *ASTVariable) AsSlice() []byte {
+func (s *DeviceKeyListCellData) AsSlice() []byte {
     return s.inner
 }
             
 
-func ASTVariableDefault() ASTVariable {
-    return *ASTVariableFromSliceUnchecked([]byte{ 9,0,0,0,8,0,0,0,0 })
+func DeviceKeyListCellDataDefault() DeviceKeyListCellData {
+    return *DeviceKeyListCellDataFromSliceUnchecked([]byte{ 69,0,0,0,12,0,0,0,16,0,0,0,0,0,0,0,53,0,0,0,16,0,0,0,48,0,0,0,49,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
 }
             
 
-func ASTVariableFromSlice(slice []byte, compatible bool) (*ASTVariable, error) {
+func DeviceKeyListCellDataFromSlice(slice []byte, compatible bool) (*DeviceKeyListCellData, error) {
     sliceLen := len(slice)
     if uint32(sliceLen) < HeaderSizeUint {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "ASTVariable", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "DeviceKeyListCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
         return nil, errors.New(errMsg)
     }
 
     totalSize := unpackNumber(slice)
     if Number(sliceLen) != totalSize {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ASTVariable", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "DeviceKeyListCellData", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if uint32(sliceLen) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ASTVariable", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "DeviceKeyListCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     offsetFirst := unpackNumber(slice[HeaderSizeUint:])
     if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"OffsetsNotMatch", "ASTVariable", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "DeviceKeyListCellData", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if sliceLen < int(offsetFirst) {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "ASTVariable", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "DeviceKeyListCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
         return nil, errors.New(errMsg)
     }
 
     fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
-    if fieldCount < 1 {
+    if fieldCount < 2 {
         return nil, errors.New("FieldCountNotMatch")
-    } else if !compatible && fieldCount > 1 {
+    } else if !compatible && fieldCount > 2 {
         return nil, errors.New("FieldCountNotMatch")
     }
 
@@ -13298,20 +13653,26 @@ func ASTVariableFromSlice(slice []byte, compatible bool) (*ASTVariable, error) {
 
     var err error
     
-_, err = ByteFromSlice(slice[offsets[0]:offsets[1]], compatible)
+_, err = DeviceKeyListFromSlice(slice[offsets[0]:offsets[1]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-    return &ASTVariable{inner: slice}, nil
+_, err = ScriptFromSlice(slice[offsets[1]:offsets[2]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+    return &DeviceKeyListCellData{inner: slice}, nil
 }
             
 
-func (s *ASTVariable) TotalSize() uint {
+func (s *DeviceKeyListCellData) TotalSize() uint {
     return uint(unpackNumber(s.inner))
 }
-func (s *ASTVariable) FieldCount() uint {
+func (s *DeviceKeyListCellData) FieldCount() uint {
     var number uint = 0
     if uint32(s.TotalSize()) == HeaderSizeUint {
         return number
@@ -13319,54 +13680,61 @@ func (s *ASTVariable) FieldCount() uint {
     number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
     return number
 }
-func (s *ASTVariable) Len() uint {
+func (s *DeviceKeyListCellData) Len() uint {
     return s.FieldCount()
 }
-func (s *ASTVariable) IsEmpty() bool {
+func (s *DeviceKeyListCellData) IsEmpty() bool {
     return s.Len() == 0
 }
-func (s *ASTVariable) CountExtraFields() uint {
-    return s.FieldCount() - 1
+func (s *DeviceKeyListCellData) CountExtraFields() uint {
+    return s.FieldCount() - 2
 }
 
-func (s *ASTVariable) HasExtraFields() bool {
-    return 1 != s.FieldCount()
+func (s *DeviceKeyListCellData) HasExtraFields() bool {
+    return 2 != s.FieldCount()
 }
             
 
-func (s *ASTVariable) Name() *Byte {
-    var ret *Byte
+func (s *DeviceKeyListCellData) Keys() *DeviceKeyList {
     start := unpackNumber(s.inner[4:])
+    end := unpackNumber(s.inner[8:])
+    return DeviceKeyListFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *DeviceKeyListCellData) RefundLock() *Script {
+    var ret *Script
+    start := unpackNumber(s.inner[8:])
     if s.HasExtraFields() {
-        end := unpackNumber(s.inner[8:])
-        ret = ByteFromSliceUnchecked(s.inner[start:end])
+        end := unpackNumber(s.inner[12:])
+        ret = ScriptFromSliceUnchecked(s.inner[start:end])
     } else {
-        ret = ByteFromSliceUnchecked(s.inner[start:])
+        ret = ScriptFromSliceUnchecked(s.inner[start:])
     }
     return ret
 }
                         
 
-func (s *ASTVariable) AsBuilder() ASTVariableBuilder {
-    ret := NewASTVariableBuilder().Name(*s.Name())
+func (s *DeviceKeyListCellData) AsBuilder() DeviceKeyListCellDataBuilder {
+    ret := NewDeviceKeyListCellDataBuilder().Keys(*s.Keys()).RefundLock(*s.RefundLock())
     return *ret
 }
         
 
-type ASTValueBuilder struct {
-    value_type Byte
+type MapEntryBuilder struct {
+    key Bytes
 value Bytes
 }
         
 
-func (s *ASTValueBuilder) Build() ASTValue {
+func (s *MapEntryBuilder) Build() MapEntry {
     b := new(bytes.Buffer)
 
     totalSize := HeaderSizeUint * (2 + 1)
     offsets := make([]uint32, 0, 2)
 
     offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.value_type.AsSlice()))
+totalSize += uint32(len(s.key.AsSlice()))
 offsets = append(offsets, totalSize)
 totalSize += uint32(len(s.value.AsSlice()))
 
@@ -13376,73 +13744,73 @@ totalSize += uint32(len(s.value.AsSlice()))
         b.Write(packNumber(Number(offsets[i])))
     }
 
-    b.Write(s.value_type.AsSlice())
+    b.Write(s.key.AsSlice())
 b.Write(s.value.AsSlice())
-    return ASTValue{inner: b.Bytes()}
+    return MapEntry{inner: b.Bytes()}
 }
                 
 
-func (s *ASTValueBuilder) ValueType(v Byte) *ASTValueBuilder {
-    s.value_type = v
+func (s *MapEntryBuilder) Key(v Bytes) *MapEntryBuilder {
+    s.key = v
     return s
 }
             
 
-func (s *ASTValueBuilder) Value(v Bytes) *ASTValueBuilder {
+func (s *MapEntryBuilder) Value(v Bytes) *MapEntryBuilder {
     s.value = v
     return s
 }
             
 
-func NewASTValueBuilder() *ASTValueBuilder {
-	return &ASTValueBuilder{ value_type: ByteDefault(),value: BytesDefault() }
+func NewMapEntryBuilder() *MapEntryBuilder {
+	return &MapEntryBuilder{ key: BytesDefault(),value: BytesDefault() }
 }
     
 
-type ASTValue struct {
+type MapEntry struct {
     inner []byte
 }
         
 
-func ASTValueFromSliceUnchecked(slice []byte) *ASTValue {
-    return &ASTValue{inner: slice}
+func MapEntryFromSliceUnchecked(slice []byte) *MapEntry {
+    return &MapEntry{inner: slice}
 }
-func (s *ASTValue) AsSlice() []byte {
+func (s *MapEntry) AsSlice() []byte {
     return s.inner
 }
             
 
-func ASTValueDefault() ASTValue {
-    return *ASTValueFromSliceUnchecked([]byte{ 17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0 })
+func MapEntryDefault() MapEntry {
+    return *MapEntryFromSliceUnchecked([]byte{ 20,0,0,0,12,0,0,0,16,0,0,0,0,0,0,0,0,0,0,0 })
 }
             
 
-func ASTValueFromSlice(slice []byte, compatible bool) (*ASTValue, error) {
+func MapEntryFromSlice(slice []byte, compatible bool) (*MapEntry, error) {
     sliceLen := len(slice)
     if uint32(sliceLen) < HeaderSizeUint {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "ASTValue", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "MapEntry", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
         return nil, errors.New(errMsg)
     }
 
     totalSize := unpackNumber(slice)
     if Number(sliceLen) != totalSize {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ASTValue", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "MapEntry", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if uint32(sliceLen) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ASTValue", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "MapEntry", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     offsetFirst := unpackNumber(slice[HeaderSizeUint:])
     if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"OffsetsNotMatch", "ASTValue", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "MapEntry", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if sliceLen < int(offsetFirst) {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "ASTValue", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "MapEntry", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
         return nil, errors.New(errMsg)
     }
 
@@ -13468,7 +13836,7 @@ func ASTValueFromSlice(slice []byte, compatible bool) (*ASTValue, error) {
 
     var err error
     
-_, err = ByteFromSlice(slice[offsets[0]:offsets[1]], compatible)
+_, err = BytesFromSlice(slice[offsets[0]:offsets[1]], compatible)
 if err != nil {
     return nil, err
 }
@@ -13480,14 +13848,14 @@ if err != nil {
 }
                 
 
-    return &ASTValue{inner: slice}, nil
+    return &MapEntry{inner: slice}, nil
 }
             
 
-func (s *ASTValue) TotalSize() uint {
+func (s *MapEntry) TotalSize() uint {
     return uint(unpackNumber(s.inner))
 }
-func (s *ASTValue) FieldCount() uint {
+func (s *MapEntry) FieldCount() uint {
     var number uint = 0
     if uint32(s.TotalSize()) == HeaderSizeUint {
         return number
@@ -13495,29 +13863,29 @@ func (s *ASTValue) FieldCount() uint {
     number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
     return number
 }
-func (s *ASTValue) Len() uint {
+func (s *MapEntry) Len() uint {
     return s.FieldCount()
 }
-func (s *ASTValue) IsEmpty() bool {
+func (s *MapEntry) IsEmpty() bool {
     return s.Len() == 0
 }
-func (s *ASTValue) CountExtraFields() uint {
+func (s *MapEntry) CountExtraFields() uint {
     return s.FieldCount() - 2
 }
 
-func (s *ASTValue) HasExtraFields() bool {
+func (s *MapEntry) HasExtraFields() bool {
     return 2 != s.FieldCount()
 }
             
 
-func (s *ASTValue) ValueType() *Byte {
+func (s *MapEntry) Key() *Bytes {
     start := unpackNumber(s.inner[4:])
     end := unpackNumber(s.inner[8:])
-    return ByteFromSliceUnchecked(s.inner[start:end])
+    return BytesFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ASTValue) Value() *Bytes {
+func (s *MapEntry) Value() *Bytes {
     var ret *Bytes
     start := unpackNumber(s.inner[8:])
     if s.HasExtraFields() {
@@ -13530,154 +13898,67 @@ func (s *ASTValue) Value() *Bytes {
 }
                         
 
-func (s *ASTValue) AsBuilder() ASTValueBuilder {
-    ret := NewASTValueBuilder().ValueType(*s.ValueType()).Value(*s.Value())
+func (s *MapEntry) AsBuilder() MapEntryBuilder {
+    ret := NewMapEntryBuilder().Key(*s.Key()).Value(*s.Value())
     return *ret
 }
         
 
-type DeviceKeyBuilder struct {
-    main_alg_id Uint8
-sub_alg_id Uint8
-cid Byte10
-pubkey Byte10
-}
-        
-
-func (s *DeviceKeyBuilder) Build() DeviceKey {
-    b := new(bytes.Buffer)
-    b.Write(s.main_alg_id.AsSlice())
-b.Write(s.sub_alg_id.AsSlice())
-b.Write(s.cid.AsSlice())
-b.Write(s.pubkey.AsSlice())
-    return DeviceKey{inner: b.Bytes()}
-}
-            
-
-func (s *DeviceKeyBuilder) MainAlgId(v Uint8) *DeviceKeyBuilder {
-    s.main_alg_id = v
-    return s
-}
-            
-
-func (s *DeviceKeyBuilder) SubAlgId(v Uint8) *DeviceKeyBuilder {
-    s.sub_alg_id = v
-    return s
-}
-            
-
-func (s *DeviceKeyBuilder) Cid(v Byte10) *DeviceKeyBuilder {
-    s.cid = v
-    return s
-}
-            
-
-func (s *DeviceKeyBuilder) Pubkey(v Byte10) *DeviceKeyBuilder {
-    s.pubkey = v
-    return s
-}
-            
-
-func NewDeviceKeyBuilder() *DeviceKeyBuilder {
-	return &DeviceKeyBuilder{ main_alg_id: Uint8Default(),sub_alg_id: Uint8Default(),cid: Byte10Default(),pubkey: Byte10Default() }
+type ConfigListBuilder struct {
+    inner []MapEntry
 }
     
 
-type DeviceKey struct {
-    inner []byte
-}
-        
-
-func DeviceKeyFromSliceUnchecked(slice []byte) *DeviceKey {
-    return &DeviceKey{inner: slice}
-}
-func (s *DeviceKey) AsSlice() []byte {
-    return s.inner
-}
-            
+func (s *ConfigListBuilder) Build() ConfigList {
+    itemCount := len(s.inner)
 
-func DeviceKeyDefault() DeviceKey {
-    return *DeviceKeyFromSliceUnchecked([]byte{ 0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
-}
-            
+    b := new(bytes.Buffer)
 
-func DeviceKeyFromSlice(slice []byte, _compatible bool) (*DeviceKey, error) {
-    sliceLen := len(slice)
-    if sliceLen != 22 {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "DeviceKey", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(22)}, " ")
-        return nil, errors.New(errMsg)
+    // Empty dyn vector, just return size's bytes
+    if itemCount == 0 {
+        b.Write(packNumber(Number(HeaderSizeUint)))
+        return ConfigList{inner: b.Bytes()}
     }
-    return &DeviceKey{inner: slice}, nil
-}
-        
-
-func (s *DeviceKey) MainAlgId() *Uint8 {
-    ret := Uint8FromSliceUnchecked(s.inner[0:1])
-    return ret
-}
-                
-
-func (s *DeviceKey) SubAlgId() *Uint8 {
-    ret := Uint8FromSliceUnchecked(s.inner[1:2])
-    return ret
-}
-                
-
-func (s *DeviceKey) Cid() *Byte10 {
-    ret := Byte10FromSliceUnchecked(s.inner[2:12])
-    return ret
-}
-                
-
-func (s *DeviceKey) Pubkey() *Byte10 {
-    ret := Byte10FromSliceUnchecked(s.inner[12:22])
-    return ret
-}
-                
-
-func (s *DeviceKey) AsBuilder() DeviceKeyBuilder {
-    ret := NewDeviceKeyBuilder().MainAlgId(*s.MainAlgId()).SubAlgId(*s.SubAlgId()).Cid(*s.Cid()).Pubkey(*s.Pubkey())
-    return *ret
-}
-        
 
-type DeviceKeyListBuilder struct {
-    inner []DeviceKey
-}
-    
+    // Calculate first offset then loop for rest items offsets
+    totalSize := HeaderSizeUint * uint32(itemCount+1)
+    offsets := make([]uint32, 0, itemCount)
+    offsets = append(offsets, totalSize)
+    for i := 1; i < itemCount; i++ {
+        totalSize += uint32(len(s.inner[i-1].AsSlice()))
+        offsets = append(offsets, offsets[i-1]+uint32(len(s.inner[i-1].AsSlice())))
+    }
+    totalSize += uint32(len(s.inner[itemCount-1].AsSlice()))
 
-func (s *DeviceKeyListBuilder) Build() DeviceKeyList {
-    size := packNumber(Number(len(s.inner)))
+    b.Write(packNumber(Number(totalSize)))
 
-    b := new(bytes.Buffer)
+    for i := 0; i < itemCount; i++ {
+        b.Write(packNumber(Number(offsets[i])))
+    }
 
-    b.Write(size)
-    len := len(s.inner)
-    for i := 0; i < len; i++ {
+    for i := 0; i < itemCount; i++ {
         b.Write(s.inner[i].AsSlice())
     }
 
-    sb := DeviceKeyList{inner: b.Bytes()}
-
-    return sb
+    return ConfigList{inner: b.Bytes()}
 }
-            
+          
 
-func (s *DeviceKeyListBuilder) Set(v []DeviceKey) *DeviceKeyListBuilder {
+func (s *ConfigListBuilder) Set(v []MapEntry) *ConfigListBuilder {
     s.inner = v
     return s
 }
-func (s *DeviceKeyListBuilder) Push(v DeviceKey) *DeviceKeyListBuilder {
+func (s *ConfigListBuilder) Push(v MapEntry) *ConfigListBuilder {
     s.inner = append(s.inner, v)
     return s
 }
-func (s *DeviceKeyListBuilder) Extend(iter []DeviceKey) *DeviceKeyListBuilder {
+func (s *ConfigListBuilder) Extend(iter []MapEntry) *ConfigListBuilder {
     for i:=0; i < len(iter); i++ {
         s.inner = append(s.inner, iter[i])
     }
     return s
 }
-func (s *DeviceKeyListBuilder) Replace(index uint, v DeviceKey) *DeviceKey {
+func (s *ConfigListBuilder) Replace(index uint, v MapEntry) *MapEntry {
     if uint(len(s.inner)) > index {
         a := s.inner[index]
         s.inner[index] = v
@@ -13687,80 +13968,134 @@ func (s *DeviceKeyListBuilder) Replace(index uint, v DeviceKey) *DeviceKey {
 }
     
 
-func NewDeviceKeyListBuilder() *DeviceKeyListBuilder {
-	return &DeviceKeyListBuilder{ []DeviceKey{} }
+func NewConfigListBuilder() *ConfigListBuilder {
+	return &ConfigListBuilder{ []MapEntry{} }
 }
         
 
-type DeviceKeyList struct {
+type ConfigList struct {
     inner []byte
 }
         
 
-func DeviceKeyListFromSliceUnchecked(slice []byte) *DeviceKeyList {
-    return &DeviceKeyList{inner: slice}
+func ConfigListFromSliceUnchecked(slice []byte) *ConfigList {
+    return &ConfigList{inner: slice}
 }
-func (s *DeviceKeyList) AsSlice() []byte {
+func (s *ConfigList) AsSlice() []byte {
     return s.inner
 }
             
 
-func DeviceKeyListDefault() DeviceKeyList {
-    return *DeviceKeyListFromSliceUnchecked([]byte{ 0,0,0,0 })
-}
-            
+func ConfigListDefault() ConfigList {
+    return *ConfigListFromSliceUnchecked([]byte{ 4,0,0,0 })
+}
+            
+
+func ConfigListFromSlice(slice []byte, compatible bool) (*ConfigList, error) {
+    sliceLen := len(slice)
+
+    if uint32(sliceLen) < HeaderSizeUint {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigList", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    totalSize := unpackNumber(slice)
+    if Number(sliceLen) != totalSize {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigList", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if uint32(sliceLen) == HeaderSizeUint {
+        return &ConfigList{inner: slice}, nil
+    }
+
+    if uint32(sliceLen) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigList", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    offsetFirst := unpackNumber(slice[HeaderSizeUint:])
+    if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "ConfigList", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
 
-func DeviceKeyListFromSlice(slice []byte, _compatible bool) (*DeviceKeyList, error) {
-    sliceLen := len(slice)
-    if sliceLen < int(HeaderSizeUint) {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "DeviceKeyList", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+    if sliceLen < int(offsetFirst) {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigList", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
         return nil, errors.New(errMsg)
     }
-    itemCount := unpackNumber(slice)
-    if itemCount == 0 {
-        if sliceLen != int(HeaderSizeUint) {
-            errMsg := strings.Join([]string{"TotalSizeNotMatch", "DeviceKeyList", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(HeaderSizeUint))}, " ")
+    itemCount := uint32(offsetFirst)/HeaderSizeUint - 1
+
+    offsets := make([]uint32, itemCount)
+
+    for i := 0; i < int(itemCount); i++ {
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    }
+
+    offsets = append(offsets, uint32(totalSize))
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            errMsg := strings.Join([]string{"OffsetsNotMatch", "ConfigList"}, " ")
             return nil, errors.New(errMsg)
         }
-        return &DeviceKeyList{inner: slice}, nil
     }
-    totalSize := int(HeaderSizeUint) + int(22*itemCount)
-    if sliceLen != totalSize {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "DeviceKeyList", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
-        return nil, errors.New(errMsg)
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 {
+            start := offsets[i-1]
+            end := offsets[i]
+            _, err := MapEntryFromSlice(slice[start:end], compatible)
+
+            if err != nil {
+                return nil, err
+            }
+        }
     }
-    return &DeviceKeyList{inner: slice}, nil
+
+    return &ConfigList{inner: slice}, nil
 }
             
 
-func (s *DeviceKeyList) TotalSize() uint {
-    return uint(HeaderSizeUint) + 22 * s.ItemCount()
+func (s *ConfigList) TotalSize() uint {
+    return uint(unpackNumber(s.inner))
 }
-func (s *DeviceKeyList) ItemCount() uint {
-    number := uint(unpackNumber(s.inner))
+func (s *ConfigList) ItemCount() uint {
+    var number uint = 0
+    if uint32(s.TotalSize()) == HeaderSizeUint {
+        return number
+    }
+    number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
     return number
 }
-func (s *DeviceKeyList) Len() uint {
+func (s *ConfigList) Len() uint {
     return s.ItemCount()
 }
-func (s *DeviceKeyList) IsEmpty() bool {
+func (s *ConfigList) IsEmpty() bool {
     return s.Len() == 0
 }
-// if *DeviceKey is nil, index is out of bounds
-func (s *DeviceKeyList) Get(index uint) *DeviceKey {
-    var re *DeviceKey
+// if *MapEntry is nil, index is out of bounds
+func (s *ConfigList) Get(index uint) *MapEntry {
+    var b *MapEntry
     if index < s.Len() {
-        start := uint(HeaderSizeUint) + 22*index
-        end := start + 22
-        re = DeviceKeyFromSliceUnchecked(s.inner[start:end])
+        start_index := uint(HeaderSizeUint) * (1 + index)
+        start := unpackNumber(s.inner[start_index:])
+
+        if index == s.Len()-1 {
+            b = MapEntryFromSliceUnchecked(s.inner[start:])
+        } else {
+            end_index := start_index + uint(HeaderSizeUint)
+            end := unpackNumber(s.inner[end_index:])
+            b = MapEntryFromSliceUnchecked(s.inner[start:end])
+        }
     }
-    return re
+    return b
 }
-        
+            
 
-func (s *DeviceKeyList) AsBuilder() DeviceKeyListBuilder {
+func (s *ConfigList) AsBuilder() ConfigListBuilder {
     size := s.ItemCount()
-    t := NewDeviceKeyListBuilder()
+    t := NewConfigListBuilder()
     for i:=uint(0); i < size; i++ {
         t.Push(*s.Get(i))
     }
@@ -13768,22 +14103,22 @@ func (s *DeviceKeyList) AsBuilder() DeviceKeyListBuilder {
 }
         
 
-type DeviceKeyListCellDataBuilder struct {
-    keys DeviceKeyList
-refund_lock Script
+type ConfigBuilder struct {
+    status Uint8
+configs ConfigList
 }
         
 
-func (s *DeviceKeyListCellDataBuilder) Build() DeviceKeyListCellData {
+func (s *ConfigBuilder) Build() Config {
     b := new(bytes.Buffer)
 
     totalSize := HeaderSizeUint * (2 + 1)
     offsets := make([]uint32, 0, 2)
 
     offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.keys.AsSlice()))
+totalSize += uint32(len(s.status.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.refund_lock.AsSlice()))
+totalSize += uint32(len(s.configs.AsSlice()))
 
     b.Write(packNumber(Number(totalSize)))
 
@@ -13791,73 +14126,73 @@ totalSize += uint32(len(s.refund_lock.AsSlice()))
         b.Write(packNumber(Number(offsets[i])))
     }
 
-    b.Write(s.keys.AsSlice())
-b.Write(s.refund_lock.AsSlice())
-    return DeviceKeyListCellData{inner: b.Bytes()}
+    b.Write(s.status.AsSlice())
+b.Write(s.configs.AsSlice())
+    return Config{inner: b.Bytes()}
 }
                 
 
-func (s *DeviceKeyListCellDataBuilder) Keys(v DeviceKeyList) *DeviceKeyListCellDataBuilder {
-    s.keys = v
+func (s *ConfigBuilder) Status(v Uint8) *ConfigBuilder {
+    s.status = v
     return s
 }
             
 
-func (s *DeviceKeyListCellDataBuilder) RefundLock(v Script) *DeviceKeyListCellDataBuilder {
-    s.refund_lock = v
+func (s *ConfigBuilder) Configs(v ConfigList) *ConfigBuilder {
+    s.configs = v
     return s
 }
             
 
-func NewDeviceKeyListCellDataBuilder() *DeviceKeyListCellDataBuilder {
-	return &DeviceKeyListCellDataBuilder{ keys: DeviceKeyListDefault(),refund_lock: ScriptDefault() }
+func NewConfigBuilder() *ConfigBuilder {
+	return &ConfigBuilder{ status: Uint8Default(),configs: ConfigListDefault() }
 }
     
 
-type DeviceKeyListCellData struct {
+type Config struct {
     inner []byte
 }
         
 
-func DeviceKeyListCellDataFromSliceUnchecked(slice []byte) *DeviceKeyListCellData {
-    return &DeviceKeyListCellData{inner: slice}
+func ConfigFromSliceUnchecked(slice []byte) *Config {
+    return &Config{inner: slice}
 }
-func (s *DeviceKeyListCellData) AsSlice() []byte {
+func (s *Config) AsSlice() []byte {
     return s.inner
 }
             
 
-func DeviceKeyListCellDataDefault() DeviceKeyListCellData {
-    return *DeviceKeyListCellDataFromSliceUnchecked([]byte{ 69,0,0,0,12,0,0,0,16,0,0,0,0,0,0,0,53,0,0,0,16,0,0,0,48,0,0,0,49,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+func ConfigDefault() Config {
+    return *ConfigFromSliceUnchecked([]byte{ 17,0,0,0,12,0,0,0,13,0,0,0,0,4,0,0,0 })
 }
             
 
-func DeviceKeyListCellDataFromSlice(slice []byte, compatible bool) (*DeviceKeyListCellData, error) {
+func ConfigFromSlice(slice []byte, compatible bool) (*Config, error) {
     sliceLen := len(slice)
     if uint32(sliceLen) < HeaderSizeUint {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "DeviceKeyListCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "Config", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
         return nil, errors.New(errMsg)
     }
 
     totalSize := unpackNumber(slice)
     if Number(sliceLen) != totalSize {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "DeviceKeyListCellData", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "Config", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if uint32(sliceLen) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "DeviceKeyListCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "Config", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     offsetFirst := unpackNumber(slice[HeaderSizeUint:])
     if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"OffsetsNotMatch", "DeviceKeyListCellData", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "Config", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if sliceLen < int(offsetFirst) {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "DeviceKeyListCellData", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "Config", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
         return nil, errors.New(errMsg)
     }
 
@@ -13883,26 +14218,26 @@ func DeviceKeyListCellDataFromSlice(slice []byte, compatible bool) (*DeviceKeyLi
 
     var err error
     
-_, err = DeviceKeyListFromSlice(slice[offsets[0]:offsets[1]], compatible)
+_, err = Uint8FromSlice(slice[offsets[0]:offsets[1]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = ScriptFromSlice(slice[offsets[1]:offsets[2]], compatible)
+_, err = ConfigListFromSlice(slice[offsets[1]:offsets[2]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-    return &DeviceKeyListCellData{inner: slice}, nil
+    return &Config{inner: slice}, nil
 }
             
 
-func (s *DeviceKeyListCellData) TotalSize() uint {
+func (s *Config) TotalSize() uint {
     return uint(unpackNumber(s.inner))
 }
-func (s *DeviceKeyListCellData) FieldCount() uint {
+func (s *Config) FieldCount() uint {
     var number uint = 0
     if uint32(s.TotalSize()) == HeaderSizeUint {
         return number
@@ -13910,43 +14245,203 @@ func (s *DeviceKeyListCellData) FieldCount() uint {
     number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
     return number
 }
-func (s *DeviceKeyListCellData) Len() uint {
+func (s *Config) Len() uint {
     return s.FieldCount()
 }
-func (s *DeviceKeyListCellData) IsEmpty() bool {
+func (s *Config) IsEmpty() bool {
     return s.Len() == 0
 }
-func (s *DeviceKeyListCellData) CountExtraFields() uint {
+func (s *Config) CountExtraFields() uint {
     return s.FieldCount() - 2
 }
 
-func (s *DeviceKeyListCellData) HasExtraFields() bool {
+func (s *Config) HasExtraFields() bool {
     return 2 != s.FieldCount()
 }
             
 
-func (s *DeviceKeyListCellData) Keys() *DeviceKeyList {
+func (s *Config) Status() *Uint8 {
     start := unpackNumber(s.inner[4:])
     end := unpackNumber(s.inner[8:])
-    return DeviceKeyListFromSliceUnchecked(s.inner[start:end])
+    return Uint8FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *DeviceKeyListCellData) RefundLock() *Script {
-    var ret *Script
+func (s *Config) Configs() *ConfigList {
+    var ret *ConfigList
     start := unpackNumber(s.inner[8:])
     if s.HasExtraFields() {
         end := unpackNumber(s.inner[12:])
-        ret = ScriptFromSliceUnchecked(s.inner[start:end])
+        ret = ConfigListFromSliceUnchecked(s.inner[start:end])
     } else {
-        ret = ScriptFromSliceUnchecked(s.inner[start:])
+        ret = ConfigListFromSliceUnchecked(s.inner[start:])
     }
     return ret
 }
                         
 
-func (s *DeviceKeyListCellData) AsBuilder() DeviceKeyListCellDataBuilder {
-    ret := NewDeviceKeyListCellDataBuilder().Keys(*s.Keys()).RefundLock(*s.RefundLock())
+func (s *Config) AsBuilder() ConfigBuilder {
+    ret := NewConfigBuilder().Status(*s.Status()).Configs(*s.Configs())
+    return *ret
+}
+        
+
+type OrderInfoBuilder struct {
+    memo Bytes
+}
+        
+
+func (s *OrderInfoBuilder) Build() OrderInfo {
+    b := new(bytes.Buffer)
+
+    totalSize := HeaderSizeUint * (1 + 1)
+    offsets := make([]uint32, 0, 1)
+
+    offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.memo.AsSlice()))
+
+    b.Write(packNumber(Number(totalSize)))
+
+    for i := 0; i < len(offsets); i++ {
+        b.Write(packNumber(Number(offsets[i])))
+    }
+
+    b.Write(s.memo.AsSlice())
+    return OrderInfo{inner: b.Bytes()}
+}
+                
+
+func (s *OrderInfoBuilder) Memo(v Bytes) *OrderInfoBuilder {
+    s.memo = v
+    return s
+}
+            
+
+func NewOrderInfoBuilder() *OrderInfoBuilder {
+	return &OrderInfoBuilder{ memo: BytesDefault() }
+}
+    
+
+type OrderInfo struct {
+    inner []byte
+}
+        
+
+func OrderInfoFromSliceUnchecked(slice []byte) *OrderInfo {
+    return &OrderInfo{inner: slice}
+}
+func (s *OrderInfo) AsSlice() []byte {
+    return s.inner
+}
+            
+
+func OrderInfoDefault() OrderInfo {
+    return *OrderInfoFromSliceUnchecked([]byte{ 12,0,0,0,8,0,0,0,0,0,0,0 })
+}
+            
+
+func OrderInfoFromSlice(slice []byte, compatible bool) (*OrderInfo, error) {
+    sliceLen := len(slice)
+    if uint32(sliceLen) < HeaderSizeUint {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "OrderInfo", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    totalSize := unpackNumber(slice)
+    if Number(sliceLen) != totalSize {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "OrderInfo", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if uint32(sliceLen) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "OrderInfo", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    offsetFirst := unpackNumber(slice[HeaderSizeUint:])
+    if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "OrderInfo", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if sliceLen < int(offsetFirst) {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "OrderInfo", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
+    if fieldCount < 1 {
+        return nil, errors.New("FieldCountNotMatch")
+    } else if !compatible && fieldCount > 1 {
+        return nil, errors.New("FieldCountNotMatch")
+    }
+
+    offsets := make([]uint32, fieldCount)
+
+    for i := 0; i < int(fieldCount); i++ {
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    }
+    offsets = append(offsets, uint32(totalSize))
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            return nil, errors.New("OffsetsNotMatch")
+        }
+    }
+
+    var err error
+    
+_, err = BytesFromSlice(slice[offsets[0]:offsets[1]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+    return &OrderInfo{inner: slice}, nil
+}
+            
+
+func (s *OrderInfo) TotalSize() uint {
+    return uint(unpackNumber(s.inner))
+}
+func (s *OrderInfo) FieldCount() uint {
+    var number uint = 0
+    if uint32(s.TotalSize()) == HeaderSizeUint {
+        return number
+    }
+    number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
+    return number
+}
+func (s *OrderInfo) Len() uint {
+    return s.FieldCount()
+}
+func (s *OrderInfo) IsEmpty() bool {
+    return s.Len() == 0
+}
+func (s *OrderInfo) CountExtraFields() uint {
+    return s.FieldCount() - 1
+}
+
+func (s *OrderInfo) HasExtraFields() bool {
+    return 1 != s.FieldCount()
+}
+            
+
+func (s *OrderInfo) Memo() *Bytes {
+    var ret *Bytes
+    start := unpackNumber(s.inner[4:])
+    if s.HasExtraFields() {
+        end := unpackNumber(s.inner[8:])
+        ret = BytesFromSliceUnchecked(s.inner[start:end])
+    } else {
+        ret = BytesFromSliceUnchecked(s.inner[start:])
+    }
+    return ret
+}
+                        
+
+func (s *OrderInfo) AsBuilder() OrderInfoBuilder {
+    ret := NewOrderInfoBuilder().Memo(*s.Memo())
     return *ret
 }
         
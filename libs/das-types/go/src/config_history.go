@@ -3355,6 +3355,212 @@ func (s *ConfigCellMainV7) AsBuilder() ConfigCellMainV7Builder {
 }
         
 
+type ConfigCellMainV8Builder struct {
+    status Uint8
+type_id_table TypeIdTableV6
+das_lock_out_point_table DasLockOutPointTable
+}
+        
+
+func (s *ConfigCellMainV8Builder) Build() ConfigCellMainV8 {
+    b := new(bytes.Buffer)
+
+    totalSize := HeaderSizeUint * (3 + 1)
+    offsets := make([]uint32, 0, 3)
+
+    offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.status.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.type_id_table.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.das_lock_out_point_table.AsSlice()))
+
+    b.Write(packNumber(Number(totalSize)))
+
+    for i := 0; i < len(offsets); i++ {
+        b.Write(packNumber(Number(offsets[i])))
+    }
+
+    b.Write(s.status.AsSlice())
+b.Write(s.type_id_table.AsSlice())
+b.Write(s.das_lock_out_point_table.AsSlice())
+    return ConfigCellMainV8{inner: b.Bytes()}
+}
+                
+
+func (s *ConfigCellMainV8Builder) Status(v Uint8) *ConfigCellMainV8Builder {
+    s.status = v
+    return s
+}
+            
+
+func (s *ConfigCellMainV8Builder) TypeIdTable(v TypeIdTableV6) *ConfigCellMainV8Builder {
+    s.type_id_table = v
+    return s
+}
+            
+
+func (s *ConfigCellMainV8Builder) DasLockOutPointTable(v DasLockOutPointTable) *ConfigCellMainV8Builder {
+    s.das_lock_out_point_table = v
+    return s
+}
+            
+
+func NewConfigCellMainV8Builder() *ConfigCellMainV8Builder {
+	return &ConfigCellMainV8Builder{ status: Uint8Default(),type_id_table: TypeIdTableV6Default(),das_lock_out_point_table: DasLockOutPointTableDefault() }
+}
+    
+
+type ConfigCellMainV8 struct {
+    inner []byte
+}
+        
+
+func ConfigCellMainV8FromSliceUnchecked(slice []byte) *ConfigCellMainV8 {
+    return &ConfigCellMainV8{inner: slice}
+}
+func (s *ConfigCellMainV8) AsSlice() []byte {
+    return s.inner
+}
+            
+
+func ConfigCellMainV8Default() ConfigCellMainV8 {
+    return *ConfigCellMainV8FromSliceUnchecked([]byte{ 225,2,0,0,16,0,0,0,17,0,0,0,197,1,0,0,0,180,1,0,0,52,0,0,0,84,0,0,0,116,0,0,0,148,0,0,0,180,0,0,0,212,0,0,0,244,0,0,0,20,1,0,0,52,1,0,0,84,1,0,0,116,1,0,0,148,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,28,1,0,0,32,0,0,0,68,0,0,0,104,0,0,0,140,0,0,0,176,0,0,0,212,0,0,0,248,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+}
+            
+
+func ConfigCellMainV8FromSlice(slice []byte, compatible bool) (*ConfigCellMainV8, error) {
+    sliceLen := len(slice)
+    if uint32(sliceLen) < HeaderSizeUint {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellMainV8", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    totalSize := unpackNumber(slice)
+    if Number(sliceLen) != totalSize {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellMainV8", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if uint32(sliceLen) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellMainV8", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    offsetFirst := unpackNumber(slice[HeaderSizeUint:])
+    if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "ConfigCellMainV8", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if sliceLen < int(offsetFirst) {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellMainV8", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
+    if fieldCount < 3 {
+        return nil, errors.New("FieldCountNotMatch")
+    } else if !compatible && fieldCount > 3 {
+        return nil, errors.New("FieldCountNotMatch")
+    }
+
+    offsets := make([]uint32, fieldCount)
+
+    for i := 0; i < int(fieldCount); i++ {
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    }
+    offsets = append(offsets, uint32(totalSize))
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            return nil, errors.New("OffsetsNotMatch")
+        }
+    }
+
+    var err error
+    
+_, err = Uint8FromSlice(slice[offsets[0]:offsets[1]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = TypeIdTableV6FromSlice(slice[offsets[1]:offsets[2]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = DasLockOutPointTableFromSlice(slice[offsets[2]:offsets[3]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+    return &ConfigCellMainV8{inner: slice}, nil
+}
+            
+
+func (s *ConfigCellMainV8) TotalSize() uint {
+    return uint(unpackNumber(s.inner))
+}
+func (s *ConfigCellMainV8) FieldCount() uint {
+    var number uint = 0
+    if uint32(s.TotalSize()) == HeaderSizeUint {
+        return number
+    }
+    number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
+    return number
+}
+func (s *ConfigCellMainV8) Len() uint {
+    return s.FieldCount()
+}
+func (s *ConfigCellMainV8) IsEmpty() bool {
+    return s.Len() == 0
+}
+func (s *ConfigCellMainV8) CountExtraFields() uint {
+    return s.FieldCount() - 3
+}
+
+func (s *ConfigCellMainV8) HasExtraFields() bool {
+    return 3 != s.FieldCount()
+}
+            
+
+func (s *ConfigCellMainV8) Status() *Uint8 {
+    start := unpackNumber(s.inner[4:])
+    end := unpackNumber(s.inner[8:])
+    return Uint8FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellMainV8) TypeIdTable() *TypeIdTableV6 {
+    start := unpackNumber(s.inner[8:])
+    end := unpackNumber(s.inner[12:])
+    return TypeIdTableV6FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellMainV8) DasLockOutPointTable() *DasLockOutPointTable {
+    var ret *DasLockOutPointTable
+    start := unpackNumber(s.inner[12:])
+    if s.HasExtraFields() {
+        end := unpackNumber(s.inner[16:])
+        ret = DasLockOutPointTableFromSliceUnchecked(s.inner[start:end])
+    } else {
+        ret = DasLockOutPointTableFromSliceUnchecked(s.inner[start:])
+    }
+    return ret
+}
+                        
+
+func (s *ConfigCellMainV8) AsBuilder() ConfigCellMainV8Builder {
+    ret := NewConfigCellMainV8Builder().Status(*s.Status()).TypeIdTable(*s.TypeIdTable()).DasLockOutPointTable(*s.DasLockOutPointTable())
+    return *ret
+}
+        
+
 type TypeIdTableV6Builder struct {
     account_cell Hash
 apply_register_cell Hash
@@ -3768,52 +3974,25 @@ func (s *TypeIdTableV6) AsBuilder() TypeIdTableV6Builder {
 }
         
 
-type ConfigCellAccountV1Builder struct {
-    max_length Uint32
-basic_capacity Uint64
-prepared_fee_capacity Uint64
-expiration_grace_period Uint32
-record_min_ttl Uint32
-record_size_limit Uint32
-transfer_account_fee Uint64
-edit_manager_fee Uint64
-edit_records_fee Uint64
-transfer_account_throttle Uint32
-edit_manager_throttle Uint32
-edit_records_throttle Uint32
+type ConfigCellMainV9Builder struct {
+    status Uint8
+type_id_table TypeIdTableV7
+das_lock_out_point_table DasLockOutPointTable
 }
         
 
-func (s *ConfigCellAccountV1Builder) Build() ConfigCellAccountV1 {
+func (s *ConfigCellMainV9Builder) Build() ConfigCellMainV9 {
     b := new(bytes.Buffer)
 
-    totalSize := HeaderSizeUint * (12 + 1)
-    offsets := make([]uint32, 0, 12)
+    totalSize := HeaderSizeUint * (3 + 1)
+    offsets := make([]uint32, 0, 3)
 
     offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.max_length.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.basic_capacity.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.prepared_fee_capacity.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.expiration_grace_period.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.record_min_ttl.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.record_size_limit.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.transfer_account_fee.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.edit_manager_fee.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.edit_records_fee.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.transfer_account_throttle.AsSlice()))
+totalSize += uint32(len(s.status.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.edit_manager_throttle.AsSlice()))
+totalSize += uint32(len(s.type_id_table.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.edit_records_throttle.AsSlice()))
+totalSize += uint32(len(s.das_lock_out_point_table.AsSlice()))
 
     b.Write(packNumber(Number(totalSize)))
 
@@ -3821,55 +4000,1641 @@ totalSize += uint32(len(s.edit_records_throttle.AsSlice()))
         b.Write(packNumber(Number(offsets[i])))
     }
 
-    b.Write(s.max_length.AsSlice())
-b.Write(s.basic_capacity.AsSlice())
-b.Write(s.prepared_fee_capacity.AsSlice())
-b.Write(s.expiration_grace_period.AsSlice())
-b.Write(s.record_min_ttl.AsSlice())
-b.Write(s.record_size_limit.AsSlice())
-b.Write(s.transfer_account_fee.AsSlice())
-b.Write(s.edit_manager_fee.AsSlice())
-b.Write(s.edit_records_fee.AsSlice())
-b.Write(s.transfer_account_throttle.AsSlice())
-b.Write(s.edit_manager_throttle.AsSlice())
-b.Write(s.edit_records_throttle.AsSlice())
-    return ConfigCellAccountV1{inner: b.Bytes()}
+    b.Write(s.status.AsSlice())
+b.Write(s.type_id_table.AsSlice())
+b.Write(s.das_lock_out_point_table.AsSlice())
+    return ConfigCellMainV9{inner: b.Bytes()}
 }
                 
 
-func (s *ConfigCellAccountV1Builder) MaxLength(v Uint32) *ConfigCellAccountV1Builder {
-    s.max_length = v
+func (s *ConfigCellMainV9Builder) Status(v Uint8) *ConfigCellMainV9Builder {
+    s.status = v
     return s
 }
             
 
-func (s *ConfigCellAccountV1Builder) BasicCapacity(v Uint64) *ConfigCellAccountV1Builder {
-    s.basic_capacity = v
+func (s *ConfigCellMainV9Builder) TypeIdTable(v TypeIdTableV7) *ConfigCellMainV9Builder {
+    s.type_id_table = v
     return s
 }
             
 
-func (s *ConfigCellAccountV1Builder) PreparedFeeCapacity(v Uint64) *ConfigCellAccountV1Builder {
-    s.prepared_fee_capacity = v
+func (s *ConfigCellMainV9Builder) DasLockOutPointTable(v DasLockOutPointTable) *ConfigCellMainV9Builder {
+    s.das_lock_out_point_table = v
     return s
 }
             
 
-func (s *ConfigCellAccountV1Builder) ExpirationGracePeriod(v Uint32) *ConfigCellAccountV1Builder {
-    s.expiration_grace_period = v
-    return s
+func NewConfigCellMainV9Builder() *ConfigCellMainV9Builder {
+	return &ConfigCellMainV9Builder{ status: Uint8Default(),type_id_table: TypeIdTableV7Default(),das_lock_out_point_table: DasLockOutPointTableDefault() }
 }
-            
+    
 
-func (s *ConfigCellAccountV1Builder) RecordMinTtl(v Uint32) *ConfigCellAccountV1Builder {
-    s.record_min_ttl = v
-    return s
+type ConfigCellMainV9 struct {
+    inner []byte
 }
-            
+        
 
-func (s *ConfigCellAccountV1Builder) RecordSizeLimit(v Uint32) *ConfigCellAccountV1Builder {
-    s.record_size_limit = v
-    return s
+func ConfigCellMainV9FromSliceUnchecked(slice []byte) *ConfigCellMainV9 {
+    return &ConfigCellMainV9{inner: slice}
+}
+func (s *ConfigCellMainV9) AsSlice() []byte {
+    return s.inner
+}
+            
+
+func ConfigCellMainV9Default() ConfigCellMainV9 {
+    return *ConfigCellMainV9FromSliceUnchecked([]byte{ 5,3,0,0,16,0,0,0,17,0,0,0,233,1,0,0,0,216,1,0,0,56,0,0,0,88,0,0,0,120,0,0,0,152,0,0,0,184,0,0,0,216,0,0,0,248,0,0,0,24,1,0,0,56,1,0,0,88,1,0,0,120,1,0,0,152,1,0,0,184,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,28,1,0,0,32,0,0,0,68,0,0,0,104,0,0,0,140,0,0,0,176,0,0,0,212,0,0,0,248,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+}
+            
+
+func ConfigCellMainV9FromSlice(slice []byte, compatible bool) (*ConfigCellMainV9, error) {
+    sliceLen := len(slice)
+    if uint32(sliceLen) < HeaderSizeUint {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellMainV9", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    totalSize := unpackNumber(slice)
+    if Number(sliceLen) != totalSize {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellMainV9", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if uint32(sliceLen) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellMainV9", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    offsetFirst := unpackNumber(slice[HeaderSizeUint:])
+    if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "ConfigCellMainV9", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if sliceLen < int(offsetFirst) {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellMainV9", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
+    if fieldCount < 3 {
+        return nil, errors.New("FieldCountNotMatch")
+    } else if !compatible && fieldCount > 3 {
+        return nil, errors.New("FieldCountNotMatch")
+    }
+
+    offsets := make([]uint32, fieldCount)
+
+    for i := 0; i < int(fieldCount); i++ {
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    }
+    offsets = append(offsets, uint32(totalSize))
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            return nil, errors.New("OffsetsNotMatch")
+        }
+    }
+
+    var err error
+    
+_, err = Uint8FromSlice(slice[offsets[0]:offsets[1]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = TypeIdTableV7FromSlice(slice[offsets[1]:offsets[2]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = DasLockOutPointTableFromSlice(slice[offsets[2]:offsets[3]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+    return &ConfigCellMainV9{inner: slice}, nil
+}
+            
+
+func (s *ConfigCellMainV9) TotalSize() uint {
+    return uint(unpackNumber(s.inner))
+}
+func (s *ConfigCellMainV9) FieldCount() uint {
+    var number uint = 0
+    if uint32(s.TotalSize()) == HeaderSizeUint {
+        return number
+    }
+    number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
+    return number
+}
+func (s *ConfigCellMainV9) Len() uint {
+    return s.FieldCount()
+}
+func (s *ConfigCellMainV9) IsEmpty() bool {
+    return s.Len() == 0
+}
+func (s *ConfigCellMainV9) CountExtraFields() uint {
+    return s.FieldCount() - 3
+}
+
+func (s *ConfigCellMainV9) HasExtraFields() bool {
+    return 3 != s.FieldCount()
+}
+            
+
+func (s *ConfigCellMainV9) Status() *Uint8 {
+    start := unpackNumber(s.inner[4:])
+    end := unpackNumber(s.inner[8:])
+    return Uint8FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellMainV9) TypeIdTable() *TypeIdTableV7 {
+    start := unpackNumber(s.inner[8:])
+    end := unpackNumber(s.inner[12:])
+    return TypeIdTableV7FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellMainV9) DasLockOutPointTable() *DasLockOutPointTable {
+    var ret *DasLockOutPointTable
+    start := unpackNumber(s.inner[12:])
+    if s.HasExtraFields() {
+        end := unpackNumber(s.inner[16:])
+        ret = DasLockOutPointTableFromSliceUnchecked(s.inner[start:end])
+    } else {
+        ret = DasLockOutPointTableFromSliceUnchecked(s.inner[start:])
+    }
+    return ret
+}
+                        
+
+func (s *ConfigCellMainV9) AsBuilder() ConfigCellMainV9Builder {
+    ret := NewConfigCellMainV9Builder().Status(*s.Status()).TypeIdTable(*s.TypeIdTable()).DasLockOutPointTable(*s.DasLockOutPointTable())
+    return *ret
+}
+        
+
+type TypeIdTableV7Builder struct {
+    account_cell Hash
+apply_register_cell Hash
+balance_cell Hash
+income_cell Hash
+pre_account_cell Hash
+proposal_cell Hash
+account_sale_cell Hash
+account_auction_cell Hash
+offer_cell Hash
+reverse_record_cell Hash
+sub_account_cell Hash
+eip712_lib Hash
+reverse_record_root_cell Hash
+}
+        
+
+func (s *TypeIdTableV7Builder) Build() TypeIdTableV7 {
+    b := new(bytes.Buffer)
+
+    totalSize := HeaderSizeUint * (13 + 1)
+    offsets := make([]uint32, 0, 13)
+
+    offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.account_cell.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.apply_register_cell.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.balance_cell.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.income_cell.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.pre_account_cell.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.proposal_cell.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.account_sale_cell.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.account_auction_cell.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.offer_cell.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.reverse_record_cell.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.sub_account_cell.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.eip712_lib.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.reverse_record_root_cell.AsSlice()))
+
+    b.Write(packNumber(Number(totalSize)))
+
+    for i := 0; i < len(offsets); i++ {
+        b.Write(packNumber(Number(offsets[i])))
+    }
+
+    b.Write(s.account_cell.AsSlice())
+b.Write(s.apply_register_cell.AsSlice())
+b.Write(s.balance_cell.AsSlice())
+b.Write(s.income_cell.AsSlice())
+b.Write(s.pre_account_cell.AsSlice())
+b.Write(s.proposal_cell.AsSlice())
+b.Write(s.account_sale_cell.AsSlice())
+b.Write(s.account_auction_cell.AsSlice())
+b.Write(s.offer_cell.AsSlice())
+b.Write(s.reverse_record_cell.AsSlice())
+b.Write(s.sub_account_cell.AsSlice())
+b.Write(s.eip712_lib.AsSlice())
+b.Write(s.reverse_record_root_cell.AsSlice())
+    return TypeIdTableV7{inner: b.Bytes()}
+}
+                
+
+func (s *TypeIdTableV7Builder) AccountCell(v Hash) *TypeIdTableV7Builder {
+    s.account_cell = v
+    return s
+}
+            
+
+func (s *TypeIdTableV7Builder) ApplyRegisterCell(v Hash) *TypeIdTableV7Builder {
+    s.apply_register_cell = v
+    return s
+}
+            
+
+func (s *TypeIdTableV7Builder) BalanceCell(v Hash) *TypeIdTableV7Builder {
+    s.balance_cell = v
+    return s
+}
+            
+
+func (s *TypeIdTableV7Builder) IncomeCell(v Hash) *TypeIdTableV7Builder {
+    s.income_cell = v
+    return s
+}
+            
+
+func (s *TypeIdTableV7Builder) PreAccountCell(v Hash) *TypeIdTableV7Builder {
+    s.pre_account_cell = v
+    return s
+}
+            
+
+func (s *TypeIdTableV7Builder) ProposalCell(v Hash) *TypeIdTableV7Builder {
+    s.proposal_cell = v
+    return s
+}
+            
+
+func (s *TypeIdTableV7Builder) AccountSaleCell(v Hash) *TypeIdTableV7Builder {
+    s.account_sale_cell = v
+    return s
+}
+            
+
+func (s *TypeIdTableV7Builder) AccountAuctionCell(v Hash) *TypeIdTableV7Builder {
+    s.account_auction_cell = v
+    return s
+}
+            
+
+func (s *TypeIdTableV7Builder) OfferCell(v Hash) *TypeIdTableV7Builder {
+    s.offer_cell = v
+    return s
+}
+            
+
+func (s *TypeIdTableV7Builder) ReverseRecordCell(v Hash) *TypeIdTableV7Builder {
+    s.reverse_record_cell = v
+    return s
+}
+            
+
+func (s *TypeIdTableV7Builder) SubAccountCell(v Hash) *TypeIdTableV7Builder {
+    s.sub_account_cell = v
+    return s
+}
+            
+
+func (s *TypeIdTableV7Builder) Eip712Lib(v Hash) *TypeIdTableV7Builder {
+    s.eip712_lib = v
+    return s
+}
+            
+
+func (s *TypeIdTableV7Builder) ReverseRecordRootCell(v Hash) *TypeIdTableV7Builder {
+    s.reverse_record_root_cell = v
+    return s
+}
+            
+
+func NewTypeIdTableV7Builder() *TypeIdTableV7Builder {
+	return &TypeIdTableV7Builder{ account_cell: HashDefault(),apply_register_cell: HashDefault(),balance_cell: HashDefault(),income_cell: HashDefault(),pre_account_cell: HashDefault(),proposal_cell: HashDefault(),account_sale_cell: HashDefault(),account_auction_cell: HashDefault(),offer_cell: HashDefault(),reverse_record_cell: HashDefault(),sub_account_cell: HashDefault(),eip712_lib: HashDefault(),reverse_record_root_cell: HashDefault() }
+}
+    
+
+type TypeIdTableV7 struct {
+    inner []byte
+}
+        
+
+func TypeIdTableV7FromSliceUnchecked(slice []byte) *TypeIdTableV7 {
+    return &TypeIdTableV7{inner: slice}
+}
+func (s *TypeIdTableV7) AsSlice() []byte {
+    return s.inner
+}
+            
+
+func TypeIdTableV7Default() TypeIdTableV7 {
+    return *TypeIdTableV7FromSliceUnchecked([]byte{ 216,1,0,0,56,0,0,0,88,0,0,0,120,0,0,0,152,0,0,0,184,0,0,0,216,0,0,0,248,0,0,0,24,1,0,0,56,1,0,0,88,1,0,0,120,1,0,0,152,1,0,0,184,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+}
+            
+
+func TypeIdTableV7FromSlice(slice []byte, compatible bool) (*TypeIdTableV7, error) {
+    sliceLen := len(slice)
+    if uint32(sliceLen) < HeaderSizeUint {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "TypeIdTableV7", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    totalSize := unpackNumber(slice)
+    if Number(sliceLen) != totalSize {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "TypeIdTableV7", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if uint32(sliceLen) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "TypeIdTableV7", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    offsetFirst := unpackNumber(slice[HeaderSizeUint:])
+    if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "TypeIdTableV7", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if sliceLen < int(offsetFirst) {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "TypeIdTableV7", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
+    if fieldCount < 13 {
+        return nil, errors.New("FieldCountNotMatch")
+    } else if !compatible && fieldCount > 13 {
+        return nil, errors.New("FieldCountNotMatch")
+    }
+
+    offsets := make([]uint32, fieldCount)
+
+    for i := 0; i < int(fieldCount); i++ {
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    }
+    offsets = append(offsets, uint32(totalSize))
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            return nil, errors.New("OffsetsNotMatch")
+        }
+    }
+
+    var err error
+    
+_, err = HashFromSlice(slice[offsets[0]:offsets[1]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = HashFromSlice(slice[offsets[1]:offsets[2]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = HashFromSlice(slice[offsets[2]:offsets[3]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = HashFromSlice(slice[offsets[3]:offsets[4]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = HashFromSlice(slice[offsets[4]:offsets[5]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = HashFromSlice(slice[offsets[5]:offsets[6]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = HashFromSlice(slice[offsets[6]:offsets[7]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = HashFromSlice(slice[offsets[7]:offsets[8]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = HashFromSlice(slice[offsets[8]:offsets[9]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = HashFromSlice(slice[offsets[9]:offsets[10]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = HashFromSlice(slice[offsets[10]:offsets[11]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = HashFromSlice(slice[offsets[11]:offsets[12]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = HashFromSlice(slice[offsets[12]:offsets[13]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+    return &TypeIdTableV7{inner: slice}, nil
+}
+            
+
+func (s *TypeIdTableV7) TotalSize() uint {
+    return uint(unpackNumber(s.inner))
+}
+func (s *TypeIdTableV7) FieldCount() uint {
+    var number uint = 0
+    if uint32(s.TotalSize()) == HeaderSizeUint {
+        return number
+    }
+    number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
+    return number
+}
+func (s *TypeIdTableV7) Len() uint {
+    return s.FieldCount()
+}
+func (s *TypeIdTableV7) IsEmpty() bool {
+    return s.Len() == 0
+}
+func (s *TypeIdTableV7) CountExtraFields() uint {
+    return s.FieldCount() - 13
+}
+
+func (s *TypeIdTableV7) HasExtraFields() bool {
+    return 13 != s.FieldCount()
+}
+            
+
+func (s *TypeIdTableV7) AccountCell() *Hash {
+    start := unpackNumber(s.inner[4:])
+    end := unpackNumber(s.inner[8:])
+    return HashFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TypeIdTableV7) ApplyRegisterCell() *Hash {
+    start := unpackNumber(s.inner[8:])
+    end := unpackNumber(s.inner[12:])
+    return HashFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TypeIdTableV7) BalanceCell() *Hash {
+    start := unpackNumber(s.inner[12:])
+    end := unpackNumber(s.inner[16:])
+    return HashFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TypeIdTableV7) IncomeCell() *Hash {
+    start := unpackNumber(s.inner[16:])
+    end := unpackNumber(s.inner[20:])
+    return HashFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TypeIdTableV7) PreAccountCell() *Hash {
+    start := unpackNumber(s.inner[20:])
+    end := unpackNumber(s.inner[24:])
+    return HashFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TypeIdTableV7) ProposalCell() *Hash {
+    start := unpackNumber(s.inner[24:])
+    end := unpackNumber(s.inner[28:])
+    return HashFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TypeIdTableV7) AccountSaleCell() *Hash {
+    start := unpackNumber(s.inner[28:])
+    end := unpackNumber(s.inner[32:])
+    return HashFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TypeIdTableV7) AccountAuctionCell() *Hash {
+    start := unpackNumber(s.inner[32:])
+    end := unpackNumber(s.inner[36:])
+    return HashFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TypeIdTableV7) OfferCell() *Hash {
+    start := unpackNumber(s.inner[36:])
+    end := unpackNumber(s.inner[40:])
+    return HashFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TypeIdTableV7) ReverseRecordCell() *Hash {
+    start := unpackNumber(s.inner[40:])
+    end := unpackNumber(s.inner[44:])
+    return HashFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TypeIdTableV7) SubAccountCell() *Hash {
+    start := unpackNumber(s.inner[44:])
+    end := unpackNumber(s.inner[48:])
+    return HashFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TypeIdTableV7) Eip712Lib() *Hash {
+    start := unpackNumber(s.inner[48:])
+    end := unpackNumber(s.inner[52:])
+    return HashFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TypeIdTableV7) ReverseRecordRootCell() *Hash {
+    var ret *Hash
+    start := unpackNumber(s.inner[52:])
+    if s.HasExtraFields() {
+        end := unpackNumber(s.inner[56:])
+        ret = HashFromSliceUnchecked(s.inner[start:end])
+    } else {
+        ret = HashFromSliceUnchecked(s.inner[start:])
+    }
+    return ret
+}
+                        
+
+func (s *TypeIdTableV7) AsBuilder() TypeIdTableV7Builder {
+    ret := NewTypeIdTableV7Builder().AccountCell(*s.AccountCell()).ApplyRegisterCell(*s.ApplyRegisterCell()).BalanceCell(*s.BalanceCell()).IncomeCell(*s.IncomeCell()).PreAccountCell(*s.PreAccountCell()).ProposalCell(*s.ProposalCell()).AccountSaleCell(*s.AccountSaleCell()).AccountAuctionCell(*s.AccountAuctionCell()).OfferCell(*s.OfferCell()).ReverseRecordCell(*s.ReverseRecordCell()).SubAccountCell(*s.SubAccountCell()).Eip712Lib(*s.Eip712Lib()).ReverseRecordRootCell(*s.ReverseRecordRootCell())
+    return *ret
+}
+        
+
+type ConfigCellMainV10Builder struct {
+    status Uint8
+type_id_table TypeIdTableV7
+das_lock_out_point_table DasLockOutPointTable
+das_lock_type_id_table DasLockTypeIdTable
+}
+        
+
+func (s *ConfigCellMainV10Builder) Build() ConfigCellMainV10 {
+    b := new(bytes.Buffer)
+
+    totalSize := HeaderSizeUint * (4 + 1)
+    offsets := make([]uint32, 0, 4)
+
+    offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.status.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.type_id_table.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.das_lock_out_point_table.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.das_lock_type_id_table.AsSlice()))
+
+    b.Write(packNumber(Number(totalSize)))
+
+    for i := 0; i < len(offsets); i++ {
+        b.Write(packNumber(Number(offsets[i])))
+    }
+
+    b.Write(s.status.AsSlice())
+b.Write(s.type_id_table.AsSlice())
+b.Write(s.das_lock_out_point_table.AsSlice())
+b.Write(s.das_lock_type_id_table.AsSlice())
+    return ConfigCellMainV10{inner: b.Bytes()}
+}
+                
+
+func (s *ConfigCellMainV10Builder) Status(v Uint8) *ConfigCellMainV10Builder {
+    s.status = v
+    return s
+}
+            
+
+func (s *ConfigCellMainV10Builder) TypeIdTable(v TypeIdTableV7) *ConfigCellMainV10Builder {
+    s.type_id_table = v
+    return s
+}
+            
+
+func (s *ConfigCellMainV10Builder) DasLockOutPointTable(v DasLockOutPointTable) *ConfigCellMainV10Builder {
+    s.das_lock_out_point_table = v
+    return s
+}
+            
+
+func (s *ConfigCellMainV10Builder) DasLockTypeIdTable(v DasLockTypeIdTable) *ConfigCellMainV10Builder {
+    s.das_lock_type_id_table = v
+    return s
+}
+            
+
+func NewConfigCellMainV10Builder() *ConfigCellMainV10Builder {
+	return &ConfigCellMainV10Builder{ status: Uint8Default(),type_id_table: TypeIdTableV7Default(),das_lock_out_point_table: DasLockOutPointTableDefault(),das_lock_type_id_table: DasLockTypeIdTableDefault() }
+}
+    
+
+type ConfigCellMainV10 struct {
+    inner []byte
+}
+        
+
+func ConfigCellMainV10FromSliceUnchecked(slice []byte) *ConfigCellMainV10 {
+    return &ConfigCellMainV10{inner: slice}
+}
+func (s *ConfigCellMainV10) AsSlice() []byte {
+    return s.inner
+}
+            
+
+func ConfigCellMainV10Default() ConfigCellMainV10 {
+    return *ConfigCellMainV10FromSliceUnchecked([]byte{ 9,4,0,0,20,0,0,0,21,0,0,0,237,1,0,0,9,3,0,0,0,216,1,0,0,56,0,0,0,88,0,0,0,120,0,0,0,152,0,0,0,184,0,0,0,216,0,0,0,248,0,0,0,24,1,0,0,56,1,0,0,88,1,0,0,120,1,0,0,152,1,0,0,184,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,28,1,0,0,32,0,0,0,68,0,0,0,104,0,0,0,140,0,0,0,176,0,0,0,212,0,0,0,248,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,0,0,32,0,0,0,64,0,0,0,96,0,0,0,128,0,0,0,160,0,0,0,192,0,0,0,224,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+}
+            
+
+func ConfigCellMainV10FromSlice(slice []byte, compatible bool) (*ConfigCellMainV10, error) {
+    sliceLen := len(slice)
+    if uint32(sliceLen) < HeaderSizeUint {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellMainV10", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    totalSize := unpackNumber(slice)
+    if Number(sliceLen) != totalSize {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellMainV10", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if uint32(sliceLen) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellMainV10", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    offsetFirst := unpackNumber(slice[HeaderSizeUint:])
+    if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "ConfigCellMainV10", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if sliceLen < int(offsetFirst) {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellMainV10", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
+    if fieldCount < 4 {
+        return nil, errors.New("FieldCountNotMatch")
+    } else if !compatible && fieldCount > 4 {
+        return nil, errors.New("FieldCountNotMatch")
+    }
+
+    offsets := make([]uint32, fieldCount)
+
+    for i := 0; i < int(fieldCount); i++ {
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    }
+    offsets = append(offsets, uint32(totalSize))
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            return nil, errors.New("OffsetsNotMatch")
+        }
+    }
+
+    var err error
+    
+_, err = Uint8FromSlice(slice[offsets[0]:offsets[1]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = TypeIdTableV7FromSlice(slice[offsets[1]:offsets[2]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = DasLockOutPointTableFromSlice(slice[offsets[2]:offsets[3]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = DasLockTypeIdTableFromSlice(slice[offsets[3]:offsets[4]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+    return &ConfigCellMainV10{inner: slice}, nil
+}
+            
+
+func (s *ConfigCellMainV10) TotalSize() uint {
+    return uint(unpackNumber(s.inner))
+}
+func (s *ConfigCellMainV10) FieldCount() uint {
+    var number uint = 0
+    if uint32(s.TotalSize()) == HeaderSizeUint {
+        return number
+    }
+    number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
+    return number
+}
+func (s *ConfigCellMainV10) Len() uint {
+    return s.FieldCount()
+}
+func (s *ConfigCellMainV10) IsEmpty() bool {
+    return s.Len() == 0
+}
+func (s *ConfigCellMainV10) CountExtraFields() uint {
+    return s.FieldCount() - 4
+}
+
+func (s *ConfigCellMainV10) HasExtraFields() bool {
+    return 4 != s.FieldCount()
+}
+            
+
+func (s *ConfigCellMainV10) Status() *Uint8 {
+    start := unpackNumber(s.inner[4:])
+    end := unpackNumber(s.inner[8:])
+    return Uint8FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellMainV10) TypeIdTable() *TypeIdTableV7 {
+    start := unpackNumber(s.inner[8:])
+    end := unpackNumber(s.inner[12:])
+    return TypeIdTableV7FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellMainV10) DasLockOutPointTable() *DasLockOutPointTable {
+    start := unpackNumber(s.inner[12:])
+    end := unpackNumber(s.inner[16:])
+    return DasLockOutPointTableFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellMainV10) DasLockTypeIdTable() *DasLockTypeIdTable {
+    var ret *DasLockTypeIdTable
+    start := unpackNumber(s.inner[16:])
+    if s.HasExtraFields() {
+        end := unpackNumber(s.inner[20:])
+        ret = DasLockTypeIdTableFromSliceUnchecked(s.inner[start:end])
+    } else {
+        ret = DasLockTypeIdTableFromSliceUnchecked(s.inner[start:])
+    }
+    return ret
+}
+                        
+
+func (s *ConfigCellMainV10) AsBuilder() ConfigCellMainV10Builder {
+    ret := NewConfigCellMainV10Builder().Status(*s.Status()).TypeIdTable(*s.TypeIdTable()).DasLockOutPointTable(*s.DasLockOutPointTable()).DasLockTypeIdTable(*s.DasLockTypeIdTable())
+    return *ret
+}
+        
+
+type ConfigCellMainV11Builder struct {
+    status Uint8
+type_id_table TypeIdTableV8
+das_lock_out_point_table DasLockOutPointTable
+das_lock_type_id_table DasLockTypeIdTable
+}
+        
+
+func (s *ConfigCellMainV11Builder) Build() ConfigCellMainV11 {
+    b := new(bytes.Buffer)
+
+    totalSize := HeaderSizeUint * (4 + 1)
+    offsets := make([]uint32, 0, 4)
+
+    offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.status.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.type_id_table.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.das_lock_out_point_table.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.das_lock_type_id_table.AsSlice()))
+
+    b.Write(packNumber(Number(totalSize)))
+
+    for i := 0; i < len(offsets); i++ {
+        b.Write(packNumber(Number(offsets[i])))
+    }
+
+    b.Write(s.status.AsSlice())
+b.Write(s.type_id_table.AsSlice())
+b.Write(s.das_lock_out_point_table.AsSlice())
+b.Write(s.das_lock_type_id_table.AsSlice())
+    return ConfigCellMainV11{inner: b.Bytes()}
+}
+                
+
+func (s *ConfigCellMainV11Builder) Status(v Uint8) *ConfigCellMainV11Builder {
+    s.status = v
+    return s
+}
+            
+
+func (s *ConfigCellMainV11Builder) TypeIdTable(v TypeIdTableV8) *ConfigCellMainV11Builder {
+    s.type_id_table = v
+    return s
+}
+            
+
+func (s *ConfigCellMainV11Builder) DasLockOutPointTable(v DasLockOutPointTable) *ConfigCellMainV11Builder {
+    s.das_lock_out_point_table = v
+    return s
+}
+            
+
+func (s *ConfigCellMainV11Builder) DasLockTypeIdTable(v DasLockTypeIdTable) *ConfigCellMainV11Builder {
+    s.das_lock_type_id_table = v
+    return s
+}
+            
+
+func NewConfigCellMainV11Builder() *ConfigCellMainV11Builder {
+	return &ConfigCellMainV11Builder{ status: Uint8Default(),type_id_table: TypeIdTableV8Default(),das_lock_out_point_table: DasLockOutPointTableDefault(),das_lock_type_id_table: DasLockTypeIdTableDefault() }
+}
+    
+
+type ConfigCellMainV11 struct {
+    inner []byte
+}
+        
+
+func ConfigCellMainV11FromSliceUnchecked(slice []byte) *ConfigCellMainV11 {
+    return &ConfigCellMainV11{inner: slice}
+}
+func (s *ConfigCellMainV11) AsSlice() []byte {
+    return s.inner
+}
+            
+
+func ConfigCellMainV11Default() ConfigCellMainV11 {
+    return *ConfigCellMainV11FromSliceUnchecked([]byte{ 45,4,0,0,20,0,0,0,21,0,0,0,17,2,0,0,45,3,0,0,0,252,1,0,0,60,0,0,0,92,0,0,0,124,0,0,0,156,0,0,0,188,0,0,0,220,0,0,0,252,0,0,0,28,1,0,0,60,1,0,0,92,1,0,0,124,1,0,0,156,1,0,0,188,1,0,0,220,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,28,1,0,0,32,0,0,0,68,0,0,0,104,0,0,0,140,0,0,0,176,0,0,0,212,0,0,0,248,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,0,0,32,0,0,0,64,0,0,0,96,0,0,0,128,0,0,0,160,0,0,0,192,0,0,0,224,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+}
+            
+
+func ConfigCellMainV11FromSlice(slice []byte, compatible bool) (*ConfigCellMainV11, error) {
+    sliceLen := len(slice)
+    if uint32(sliceLen) < HeaderSizeUint {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellMainV11", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    totalSize := unpackNumber(slice)
+    if Number(sliceLen) != totalSize {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellMainV11", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if uint32(sliceLen) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellMainV11", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    offsetFirst := unpackNumber(slice[HeaderSizeUint:])
+    if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "ConfigCellMainV11", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if sliceLen < int(offsetFirst) {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellMainV11", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
+    if fieldCount < 4 {
+        return nil, errors.New("FieldCountNotMatch")
+    } else if !compatible && fieldCount > 4 {
+        return nil, errors.New("FieldCountNotMatch")
+    }
+
+    offsets := make([]uint32, fieldCount)
+
+    for i := 0; i < int(fieldCount); i++ {
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    }
+    offsets = append(offsets, uint32(totalSize))
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            return nil, errors.New("OffsetsNotMatch")
+        }
+    }
+
+    var err error
+    
+_, err = Uint8FromSlice(slice[offsets[0]:offsets[1]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = TypeIdTableV8FromSlice(slice[offsets[1]:offsets[2]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = DasLockOutPointTableFromSlice(slice[offsets[2]:offsets[3]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = DasLockTypeIdTableFromSlice(slice[offsets[3]:offsets[4]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+    return &ConfigCellMainV11{inner: slice}, nil
+}
+            
+
+func (s *ConfigCellMainV11) TotalSize() uint {
+    return uint(unpackNumber(s.inner))
+}
+func (s *ConfigCellMainV11) FieldCount() uint {
+    var number uint = 0
+    if uint32(s.TotalSize()) == HeaderSizeUint {
+        return number
+    }
+    number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
+    return number
+}
+func (s *ConfigCellMainV11) Len() uint {
+    return s.FieldCount()
+}
+func (s *ConfigCellMainV11) IsEmpty() bool {
+    return s.Len() == 0
+}
+func (s *ConfigCellMainV11) CountExtraFields() uint {
+    return s.FieldCount() - 4
+}
+
+func (s *ConfigCellMainV11) HasExtraFields() bool {
+    return 4 != s.FieldCount()
+}
+            
+
+func (s *ConfigCellMainV11) Status() *Uint8 {
+    start := unpackNumber(s.inner[4:])
+    end := unpackNumber(s.inner[8:])
+    return Uint8FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellMainV11) TypeIdTable() *TypeIdTableV8 {
+    start := unpackNumber(s.inner[8:])
+    end := unpackNumber(s.inner[12:])
+    return TypeIdTableV8FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellMainV11) DasLockOutPointTable() *DasLockOutPointTable {
+    start := unpackNumber(s.inner[12:])
+    end := unpackNumber(s.inner[16:])
+    return DasLockOutPointTableFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellMainV11) DasLockTypeIdTable() *DasLockTypeIdTable {
+    var ret *DasLockTypeIdTable
+    start := unpackNumber(s.inner[16:])
+    if s.HasExtraFields() {
+        end := unpackNumber(s.inner[20:])
+        ret = DasLockTypeIdTableFromSliceUnchecked(s.inner[start:end])
+    } else {
+        ret = DasLockTypeIdTableFromSliceUnchecked(s.inner[start:])
+    }
+    return ret
+}
+                        
+
+func (s *ConfigCellMainV11) AsBuilder() ConfigCellMainV11Builder {
+    ret := NewConfigCellMainV11Builder().Status(*s.Status()).TypeIdTable(*s.TypeIdTable()).DasLockOutPointTable(*s.DasLockOutPointTable()).DasLockTypeIdTable(*s.DasLockTypeIdTable())
+    return *ret
+}
+        
+
+type TypeIdTableV8Builder struct {
+    account_cell Hash
+apply_register_cell Hash
+balance_cell Hash
+income_cell Hash
+pre_account_cell Hash
+proposal_cell Hash
+account_sale_cell Hash
+account_auction_cell Hash
+offer_cell Hash
+reverse_record_cell Hash
+sub_account_cell Hash
+eip712_lib Hash
+reverse_record_root_cell Hash
+key_list_config_cell Hash
+}
+        
+
+func (s *TypeIdTableV8Builder) Build() TypeIdTableV8 {
+    b := new(bytes.Buffer)
+
+    totalSize := HeaderSizeUint * (14 + 1)
+    offsets := make([]uint32, 0, 14)
+
+    offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.account_cell.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.apply_register_cell.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.balance_cell.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.income_cell.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.pre_account_cell.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.proposal_cell.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.account_sale_cell.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.account_auction_cell.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.offer_cell.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.reverse_record_cell.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.sub_account_cell.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.eip712_lib.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.reverse_record_root_cell.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.key_list_config_cell.AsSlice()))
+
+    b.Write(packNumber(Number(totalSize)))
+
+    for i := 0; i < len(offsets); i++ {
+        b.Write(packNumber(Number(offsets[i])))
+    }
+
+    b.Write(s.account_cell.AsSlice())
+b.Write(s.apply_register_cell.AsSlice())
+b.Write(s.balance_cell.AsSlice())
+b.Write(s.income_cell.AsSlice())
+b.Write(s.pre_account_cell.AsSlice())
+b.Write(s.proposal_cell.AsSlice())
+b.Write(s.account_sale_cell.AsSlice())
+b.Write(s.account_auction_cell.AsSlice())
+b.Write(s.offer_cell.AsSlice())
+b.Write(s.reverse_record_cell.AsSlice())
+b.Write(s.sub_account_cell.AsSlice())
+b.Write(s.eip712_lib.AsSlice())
+b.Write(s.reverse_record_root_cell.AsSlice())
+b.Write(s.key_list_config_cell.AsSlice())
+    return TypeIdTableV8{inner: b.Bytes()}
+}
+                
+
+func (s *TypeIdTableV8Builder) AccountCell(v Hash) *TypeIdTableV8Builder {
+    s.account_cell = v
+    return s
+}
+            
+
+func (s *TypeIdTableV8Builder) ApplyRegisterCell(v Hash) *TypeIdTableV8Builder {
+    s.apply_register_cell = v
+    return s
+}
+            
+
+func (s *TypeIdTableV8Builder) BalanceCell(v Hash) *TypeIdTableV8Builder {
+    s.balance_cell = v
+    return s
+}
+            
+
+func (s *TypeIdTableV8Builder) IncomeCell(v Hash) *TypeIdTableV8Builder {
+    s.income_cell = v
+    return s
+}
+            
+
+func (s *TypeIdTableV8Builder) PreAccountCell(v Hash) *TypeIdTableV8Builder {
+    s.pre_account_cell = v
+    return s
+}
+            
+
+func (s *TypeIdTableV8Builder) ProposalCell(v Hash) *TypeIdTableV8Builder {
+    s.proposal_cell = v
+    return s
+}
+            
+
+func (s *TypeIdTableV8Builder) AccountSaleCell(v Hash) *TypeIdTableV8Builder {
+    s.account_sale_cell = v
+    return s
+}
+            
+
+func (s *TypeIdTableV8Builder) AccountAuctionCell(v Hash) *TypeIdTableV8Builder {
+    s.account_auction_cell = v
+    return s
+}
+            
+
+func (s *TypeIdTableV8Builder) OfferCell(v Hash) *TypeIdTableV8Builder {
+    s.offer_cell = v
+    return s
+}
+            
+
+func (s *TypeIdTableV8Builder) ReverseRecordCell(v Hash) *TypeIdTableV8Builder {
+    s.reverse_record_cell = v
+    return s
+}
+            
+
+func (s *TypeIdTableV8Builder) SubAccountCell(v Hash) *TypeIdTableV8Builder {
+    s.sub_account_cell = v
+    return s
+}
+            
+
+func (s *TypeIdTableV8Builder) Eip712Lib(v Hash) *TypeIdTableV8Builder {
+    s.eip712_lib = v
+    return s
+}
+            
+
+func (s *TypeIdTableV8Builder) ReverseRecordRootCell(v Hash) *TypeIdTableV8Builder {
+    s.reverse_record_root_cell = v
+    return s
+}
+            
+
+func (s *TypeIdTableV8Builder) KeyListConfigCell(v Hash) *TypeIdTableV8Builder {
+    s.key_list_config_cell = v
+    return s
+}
+            
+
+func NewTypeIdTableV8Builder() *TypeIdTableV8Builder {
+	return &TypeIdTableV8Builder{ account_cell: HashDefault(),apply_register_cell: HashDefault(),balance_cell: HashDefault(),income_cell: HashDefault(),pre_account_cell: HashDefault(),proposal_cell: HashDefault(),account_sale_cell: HashDefault(),account_auction_cell: HashDefault(),offer_cell: HashDefault(),reverse_record_cell: HashDefault(),sub_account_cell: HashDefault(),eip712_lib: HashDefault(),reverse_record_root_cell: HashDefault(),key_list_config_cell: HashDefault() }
+}
+    
+
+type TypeIdTableV8 struct {
+    inner []byte
+}
+        
+
+func TypeIdTableV8FromSliceUnchecked(slice []byte) *TypeIdTableV8 {
+    return &TypeIdTableV8{inner: slice}
+}
+func (s *TypeIdTableV8) AsSlice() []byte {
+    return s.inner
+}
+            
+
+func TypeIdTableV8Default() TypeIdTableV8 {
+    return *TypeIdTableV8FromSliceUnchecked([]byte{ 252,1,0,0,60,0,0,0,92,0,0,0,124,0,0,0,156,0,0,0,188,0,0,0,220,0,0,0,252,0,0,0,28,1,0,0,60,1,0,0,92,1,0,0,124,1,0,0,156,1,0,0,188,1,0,0,220,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+}
+            
+
+func TypeIdTableV8FromSlice(slice []byte, compatible bool) (*TypeIdTableV8, error) {
+    sliceLen := len(slice)
+    if uint32(sliceLen) < HeaderSizeUint {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "TypeIdTableV8", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    totalSize := unpackNumber(slice)
+    if Number(sliceLen) != totalSize {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "TypeIdTableV8", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if uint32(sliceLen) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "TypeIdTableV8", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    offsetFirst := unpackNumber(slice[HeaderSizeUint:])
+    if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "TypeIdTableV8", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if sliceLen < int(offsetFirst) {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "TypeIdTableV8", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
+    if fieldCount < 14 {
+        return nil, errors.New("FieldCountNotMatch")
+    } else if !compatible && fieldCount > 14 {
+        return nil, errors.New("FieldCountNotMatch")
+    }
+
+    offsets := make([]uint32, fieldCount)
+
+    for i := 0; i < int(fieldCount); i++ {
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    }
+    offsets = append(offsets, uint32(totalSize))
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            return nil, errors.New("OffsetsNotMatch")
+        }
+    }
+
+    var err error
+    
+_, err = HashFromSlice(slice[offsets[0]:offsets[1]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = HashFromSlice(slice[offsets[1]:offsets[2]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = HashFromSlice(slice[offsets[2]:offsets[3]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = HashFromSlice(slice[offsets[3]:offsets[4]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = HashFromSlice(slice[offsets[4]:offsets[5]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = HashFromSlice(slice[offsets[5]:offsets[6]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = HashFromSlice(slice[offsets[6]:offsets[7]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = HashFromSlice(slice[offsets[7]:offsets[8]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = HashFromSlice(slice[offsets[8]:offsets[9]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = HashFromSlice(slice[offsets[9]:offsets[10]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = HashFromSlice(slice[offsets[10]:offsets[11]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = HashFromSlice(slice[offsets[11]:offsets[12]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = HashFromSlice(slice[offsets[12]:offsets[13]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = HashFromSlice(slice[offsets[13]:offsets[14]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+    return &TypeIdTableV8{inner: slice}, nil
+}
+            
+
+func (s *TypeIdTableV8) TotalSize() uint {
+    return uint(unpackNumber(s.inner))
+}
+func (s *TypeIdTableV8) FieldCount() uint {
+    var number uint = 0
+    if uint32(s.TotalSize()) == HeaderSizeUint {
+        return number
+    }
+    number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
+    return number
+}
+func (s *TypeIdTableV8) Len() uint {
+    return s.FieldCount()
+}
+func (s *TypeIdTableV8) IsEmpty() bool {
+    return s.Len() == 0
+}
+func (s *TypeIdTableV8) CountExtraFields() uint {
+    return s.FieldCount() - 14
+}
+
+func (s *TypeIdTableV8) HasExtraFields() bool {
+    return 14 != s.FieldCount()
+}
+            
+
+func (s *TypeIdTableV8) AccountCell() *Hash {
+    start := unpackNumber(s.inner[4:])
+    end := unpackNumber(s.inner[8:])
+    return HashFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TypeIdTableV8) ApplyRegisterCell() *Hash {
+    start := unpackNumber(s.inner[8:])
+    end := unpackNumber(s.inner[12:])
+    return HashFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TypeIdTableV8) BalanceCell() *Hash {
+    start := unpackNumber(s.inner[12:])
+    end := unpackNumber(s.inner[16:])
+    return HashFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TypeIdTableV8) IncomeCell() *Hash {
+    start := unpackNumber(s.inner[16:])
+    end := unpackNumber(s.inner[20:])
+    return HashFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TypeIdTableV8) PreAccountCell() *Hash {
+    start := unpackNumber(s.inner[20:])
+    end := unpackNumber(s.inner[24:])
+    return HashFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TypeIdTableV8) ProposalCell() *Hash {
+    start := unpackNumber(s.inner[24:])
+    end := unpackNumber(s.inner[28:])
+    return HashFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TypeIdTableV8) AccountSaleCell() *Hash {
+    start := unpackNumber(s.inner[28:])
+    end := unpackNumber(s.inner[32:])
+    return HashFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TypeIdTableV8) AccountAuctionCell() *Hash {
+    start := unpackNumber(s.inner[32:])
+    end := unpackNumber(s.inner[36:])
+    return HashFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TypeIdTableV8) OfferCell() *Hash {
+    start := unpackNumber(s.inner[36:])
+    end := unpackNumber(s.inner[40:])
+    return HashFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TypeIdTableV8) ReverseRecordCell() *Hash {
+    start := unpackNumber(s.inner[40:])
+    end := unpackNumber(s.inner[44:])
+    return HashFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TypeIdTableV8) SubAccountCell() *Hash {
+    start := unpackNumber(s.inner[44:])
+    end := unpackNumber(s.inner[48:])
+    return HashFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TypeIdTableV8) Eip712Lib() *Hash {
+    start := unpackNumber(s.inner[48:])
+    end := unpackNumber(s.inner[52:])
+    return HashFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TypeIdTableV8) ReverseRecordRootCell() *Hash {
+    start := unpackNumber(s.inner[52:])
+    end := unpackNumber(s.inner[56:])
+    return HashFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TypeIdTableV8) KeyListConfigCell() *Hash {
+    var ret *Hash
+    start := unpackNumber(s.inner[56:])
+    if s.HasExtraFields() {
+        end := unpackNumber(s.inner[60:])
+        ret = HashFromSliceUnchecked(s.inner[start:end])
+    } else {
+        ret = HashFromSliceUnchecked(s.inner[start:])
+    }
+    return ret
+}
+                        
+
+func (s *TypeIdTableV8) AsBuilder() TypeIdTableV8Builder {
+    ret := NewTypeIdTableV8Builder().AccountCell(*s.AccountCell()).ApplyRegisterCell(*s.ApplyRegisterCell()).BalanceCell(*s.BalanceCell()).IncomeCell(*s.IncomeCell()).PreAccountCell(*s.PreAccountCell()).ProposalCell(*s.ProposalCell()).AccountSaleCell(*s.AccountSaleCell()).AccountAuctionCell(*s.AccountAuctionCell()).OfferCell(*s.OfferCell()).ReverseRecordCell(*s.ReverseRecordCell()).SubAccountCell(*s.SubAccountCell()).Eip712Lib(*s.Eip712Lib()).ReverseRecordRootCell(*s.ReverseRecordRootCell()).KeyListConfigCell(*s.KeyListConfigCell())
+    return *ret
+}
+        
+
+type ConfigCellAccountV1Builder struct {
+    max_length Uint32
+basic_capacity Uint64
+prepared_fee_capacity Uint64
+expiration_grace_period Uint32
+record_min_ttl Uint32
+record_size_limit Uint32
+transfer_account_fee Uint64
+edit_manager_fee Uint64
+edit_records_fee Uint64
+transfer_account_throttle Uint32
+edit_manager_throttle Uint32
+edit_records_throttle Uint32
+}
+        
+
+func (s *ConfigCellAccountV1Builder) Build() ConfigCellAccountV1 {
+    b := new(bytes.Buffer)
+
+    totalSize := HeaderSizeUint * (12 + 1)
+    offsets := make([]uint32, 0, 12)
+
+    offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.max_length.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.basic_capacity.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.prepared_fee_capacity.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.expiration_grace_period.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.record_min_ttl.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.record_size_limit.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.transfer_account_fee.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.edit_manager_fee.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.edit_records_fee.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.transfer_account_throttle.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.edit_manager_throttle.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.edit_records_throttle.AsSlice()))
+
+    b.Write(packNumber(Number(totalSize)))
+
+    for i := 0; i < len(offsets); i++ {
+        b.Write(packNumber(Number(offsets[i])))
+    }
+
+    b.Write(s.max_length.AsSlice())
+b.Write(s.basic_capacity.AsSlice())
+b.Write(s.prepared_fee_capacity.AsSlice())
+b.Write(s.expiration_grace_period.AsSlice())
+b.Write(s.record_min_ttl.AsSlice())
+b.Write(s.record_size_limit.AsSlice())
+b.Write(s.transfer_account_fee.AsSlice())
+b.Write(s.edit_manager_fee.AsSlice())
+b.Write(s.edit_records_fee.AsSlice())
+b.Write(s.transfer_account_throttle.AsSlice())
+b.Write(s.edit_manager_throttle.AsSlice())
+b.Write(s.edit_records_throttle.AsSlice())
+    return ConfigCellAccountV1{inner: b.Bytes()}
+}
+                
+
+func (s *ConfigCellAccountV1Builder) MaxLength(v Uint32) *ConfigCellAccountV1Builder {
+    s.max_length = v
+    return s
+}
+            
+
+func (s *ConfigCellAccountV1Builder) BasicCapacity(v Uint64) *ConfigCellAccountV1Builder {
+    s.basic_capacity = v
+    return s
+}
+            
+
+func (s *ConfigCellAccountV1Builder) PreparedFeeCapacity(v Uint64) *ConfigCellAccountV1Builder {
+    s.prepared_fee_capacity = v
+    return s
+}
+            
+
+func (s *ConfigCellAccountV1Builder) ExpirationGracePeriod(v Uint32) *ConfigCellAccountV1Builder {
+    s.expiration_grace_period = v
+    return s
+}
+            
+
+func (s *ConfigCellAccountV1Builder) RecordMinTtl(v Uint32) *ConfigCellAccountV1Builder {
+    s.record_min_ttl = v
+    return s
+}
+            
+
+func (s *ConfigCellAccountV1Builder) RecordSizeLimit(v Uint32) *ConfigCellAccountV1Builder {
+    s.record_size_limit = v
+    return s
 }
             
 
@@ -3879,92 +5644,515 @@ func (s *ConfigCellAccountV1Builder) TransferAccountFee(v Uint64) *ConfigCellAcc
 }
             
 
-func (s *ConfigCellAccountV1Builder) EditManagerFee(v Uint64) *ConfigCellAccountV1Builder {
+func (s *ConfigCellAccountV1Builder) EditManagerFee(v Uint64) *ConfigCellAccountV1Builder {
+    s.edit_manager_fee = v
+    return s
+}
+            
+
+func (s *ConfigCellAccountV1Builder) EditRecordsFee(v Uint64) *ConfigCellAccountV1Builder {
+    s.edit_records_fee = v
+    return s
+}
+            
+
+func (s *ConfigCellAccountV1Builder) TransferAccountThrottle(v Uint32) *ConfigCellAccountV1Builder {
+    s.transfer_account_throttle = v
+    return s
+}
+            
+
+func (s *ConfigCellAccountV1Builder) EditManagerThrottle(v Uint32) *ConfigCellAccountV1Builder {
+    s.edit_manager_throttle = v
+    return s
+}
+            
+
+func (s *ConfigCellAccountV1Builder) EditRecordsThrottle(v Uint32) *ConfigCellAccountV1Builder {
+    s.edit_records_throttle = v
+    return s
+}
+            
+
+func NewConfigCellAccountV1Builder() *ConfigCellAccountV1Builder {
+	return &ConfigCellAccountV1Builder{ max_length: Uint32Default(),basic_capacity: Uint64Default(),prepared_fee_capacity: Uint64Default(),expiration_grace_period: Uint32Default(),record_min_ttl: Uint32Default(),record_size_limit: Uint32Default(),transfer_account_fee: Uint64Default(),edit_manager_fee: Uint64Default(),edit_records_fee: Uint64Default(),transfer_account_throttle: Uint32Default(),edit_manager_throttle: Uint32Default(),edit_records_throttle: Uint32Default() }
+}
+    
+
+type ConfigCellAccountV1 struct {
+    inner []byte
+}
+        
+
+func ConfigCellAccountV1FromSliceUnchecked(slice []byte) *ConfigCellAccountV1 {
+    return &ConfigCellAccountV1{inner: slice}
+}
+func (s *ConfigCellAccountV1) AsSlice() []byte {
+    return s.inner
+}
+            
+
+func ConfigCellAccountV1Default() ConfigCellAccountV1 {
+    return *ConfigCellAccountV1FromSliceUnchecked([]byte{ 120,0,0,0,52,0,0,0,56,0,0,0,64,0,0,0,72,0,0,0,76,0,0,0,80,0,0,0,84,0,0,0,92,0,0,0,100,0,0,0,108,0,0,0,112,0,0,0,116,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+}
+            
+
+func ConfigCellAccountV1FromSlice(slice []byte, compatible bool) (*ConfigCellAccountV1, error) {
+    sliceLen := len(slice)
+    if uint32(sliceLen) < HeaderSizeUint {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellAccountV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    totalSize := unpackNumber(slice)
+    if Number(sliceLen) != totalSize {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellAccountV1", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if uint32(sliceLen) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellAccountV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    offsetFirst := unpackNumber(slice[HeaderSizeUint:])
+    if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "ConfigCellAccountV1", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if sliceLen < int(offsetFirst) {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellAccountV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
+    if fieldCount < 12 {
+        return nil, errors.New("FieldCountNotMatch")
+    } else if !compatible && fieldCount > 12 {
+        return nil, errors.New("FieldCountNotMatch")
+    }
+
+    offsets := make([]uint32, fieldCount)
+
+    for i := 0; i < int(fieldCount); i++ {
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    }
+    offsets = append(offsets, uint32(totalSize))
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            return nil, errors.New("OffsetsNotMatch")
+        }
+    }
+
+    var err error
+    
+_, err = Uint32FromSlice(slice[offsets[0]:offsets[1]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint64FromSlice(slice[offsets[1]:offsets[2]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint64FromSlice(slice[offsets[2]:offsets[3]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint32FromSlice(slice[offsets[3]:offsets[4]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint32FromSlice(slice[offsets[4]:offsets[5]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint32FromSlice(slice[offsets[5]:offsets[6]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint64FromSlice(slice[offsets[6]:offsets[7]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint64FromSlice(slice[offsets[7]:offsets[8]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint64FromSlice(slice[offsets[8]:offsets[9]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint32FromSlice(slice[offsets[9]:offsets[10]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint32FromSlice(slice[offsets[10]:offsets[11]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint32FromSlice(slice[offsets[11]:offsets[12]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+    return &ConfigCellAccountV1{inner: slice}, nil
+}
+            
+
+func (s *ConfigCellAccountV1) TotalSize() uint {
+    return uint(unpackNumber(s.inner))
+}
+func (s *ConfigCellAccountV1) FieldCount() uint {
+    var number uint = 0
+    if uint32(s.TotalSize()) == HeaderSizeUint {
+        return number
+    }
+    number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
+    return number
+}
+func (s *ConfigCellAccountV1) Len() uint {
+    return s.FieldCount()
+}
+func (s *ConfigCellAccountV1) IsEmpty() bool {
+    return s.Len() == 0
+}
+func (s *ConfigCellAccountV1) CountExtraFields() uint {
+    return s.FieldCount() - 12
+}
+
+func (s *ConfigCellAccountV1) HasExtraFields() bool {
+    return 12 != s.FieldCount()
+}
+            
+
+func (s *ConfigCellAccountV1) MaxLength() *Uint32 {
+    start := unpackNumber(s.inner[4:])
+    end := unpackNumber(s.inner[8:])
+    return Uint32FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellAccountV1) BasicCapacity() *Uint64 {
+    start := unpackNumber(s.inner[8:])
+    end := unpackNumber(s.inner[12:])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellAccountV1) PreparedFeeCapacity() *Uint64 {
+    start := unpackNumber(s.inner[12:])
+    end := unpackNumber(s.inner[16:])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellAccountV1) ExpirationGracePeriod() *Uint32 {
+    start := unpackNumber(s.inner[16:])
+    end := unpackNumber(s.inner[20:])
+    return Uint32FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellAccountV1) RecordMinTtl() *Uint32 {
+    start := unpackNumber(s.inner[20:])
+    end := unpackNumber(s.inner[24:])
+    return Uint32FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellAccountV1) RecordSizeLimit() *Uint32 {
+    start := unpackNumber(s.inner[24:])
+    end := unpackNumber(s.inner[28:])
+    return Uint32FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellAccountV1) TransferAccountFee() *Uint64 {
+    start := unpackNumber(s.inner[28:])
+    end := unpackNumber(s.inner[32:])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellAccountV1) EditManagerFee() *Uint64 {
+    start := unpackNumber(s.inner[32:])
+    end := unpackNumber(s.inner[36:])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellAccountV1) EditRecordsFee() *Uint64 {
+    start := unpackNumber(s.inner[36:])
+    end := unpackNumber(s.inner[40:])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellAccountV1) TransferAccountThrottle() *Uint32 {
+    start := unpackNumber(s.inner[40:])
+    end := unpackNumber(s.inner[44:])
+    return Uint32FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellAccountV1) EditManagerThrottle() *Uint32 {
+    start := unpackNumber(s.inner[44:])
+    end := unpackNumber(s.inner[48:])
+    return Uint32FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellAccountV1) EditRecordsThrottle() *Uint32 {
+    var ret *Uint32
+    start := unpackNumber(s.inner[48:])
+    if s.HasExtraFields() {
+        end := unpackNumber(s.inner[52:])
+        ret = Uint32FromSliceUnchecked(s.inner[start:end])
+    } else {
+        ret = Uint32FromSliceUnchecked(s.inner[start:])
+    }
+    return ret
+}
+                        
+
+func (s *ConfigCellAccountV1) AsBuilder() ConfigCellAccountV1Builder {
+    ret := NewConfigCellAccountV1Builder().MaxLength(*s.MaxLength()).BasicCapacity(*s.BasicCapacity()).PreparedFeeCapacity(*s.PreparedFeeCapacity()).ExpirationGracePeriod(*s.ExpirationGracePeriod()).RecordMinTtl(*s.RecordMinTtl()).RecordSizeLimit(*s.RecordSizeLimit()).TransferAccountFee(*s.TransferAccountFee()).EditManagerFee(*s.EditManagerFee()).EditRecordsFee(*s.EditRecordsFee()).TransferAccountThrottle(*s.TransferAccountThrottle()).EditManagerThrottle(*s.EditManagerThrottle()).EditRecordsThrottle(*s.EditRecordsThrottle())
+    return *ret
+}
+        
+
+type ConfigCellAccountV2Builder struct {
+    max_length Uint32
+basic_capacity Uint64
+prepared_fee_capacity Uint64
+expiration_grace_period Uint32
+record_min_ttl Uint32
+record_size_limit Uint32
+transfer_account_fee Uint64
+edit_manager_fee Uint64
+edit_records_fee Uint64
+transfer_account_throttle Uint32
+edit_manager_throttle Uint32
+edit_records_throttle Uint32
+common_throttle Uint32
+}
+        
+
+func (s *ConfigCellAccountV2Builder) Build() ConfigCellAccountV2 {
+    b := new(bytes.Buffer)
+
+    totalSize := HeaderSizeUint * (13 + 1)
+    offsets := make([]uint32, 0, 13)
+
+    offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.max_length.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.basic_capacity.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.prepared_fee_capacity.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.expiration_grace_period.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.record_min_ttl.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.record_size_limit.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.transfer_account_fee.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.edit_manager_fee.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.edit_records_fee.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.transfer_account_throttle.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.edit_manager_throttle.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.edit_records_throttle.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.common_throttle.AsSlice()))
+
+    b.Write(packNumber(Number(totalSize)))
+
+    for i := 0; i < len(offsets); i++ {
+        b.Write(packNumber(Number(offsets[i])))
+    }
+
+    b.Write(s.max_length.AsSlice())
+b.Write(s.basic_capacity.AsSlice())
+b.Write(s.prepared_fee_capacity.AsSlice())
+b.Write(s.expiration_grace_period.AsSlice())
+b.Write(s.record_min_ttl.AsSlice())
+b.Write(s.record_size_limit.AsSlice())
+b.Write(s.transfer_account_fee.AsSlice())
+b.Write(s.edit_manager_fee.AsSlice())
+b.Write(s.edit_records_fee.AsSlice())
+b.Write(s.transfer_account_throttle.AsSlice())
+b.Write(s.edit_manager_throttle.AsSlice())
+b.Write(s.edit_records_throttle.AsSlice())
+b.Write(s.common_throttle.AsSlice())
+    return ConfigCellAccountV2{inner: b.Bytes()}
+}
+                
+
+func (s *ConfigCellAccountV2Builder) MaxLength(v Uint32) *ConfigCellAccountV2Builder {
+    s.max_length = v
+    return s
+}
+            
+
+func (s *ConfigCellAccountV2Builder) BasicCapacity(v Uint64) *ConfigCellAccountV2Builder {
+    s.basic_capacity = v
+    return s
+}
+            
+
+func (s *ConfigCellAccountV2Builder) PreparedFeeCapacity(v Uint64) *ConfigCellAccountV2Builder {
+    s.prepared_fee_capacity = v
+    return s
+}
+            
+
+func (s *ConfigCellAccountV2Builder) ExpirationGracePeriod(v Uint32) *ConfigCellAccountV2Builder {
+    s.expiration_grace_period = v
+    return s
+}
+            
+
+func (s *ConfigCellAccountV2Builder) RecordMinTtl(v Uint32) *ConfigCellAccountV2Builder {
+    s.record_min_ttl = v
+    return s
+}
+            
+
+func (s *ConfigCellAccountV2Builder) RecordSizeLimit(v Uint32) *ConfigCellAccountV2Builder {
+    s.record_size_limit = v
+    return s
+}
+            
+
+func (s *ConfigCellAccountV2Builder) TransferAccountFee(v Uint64) *ConfigCellAccountV2Builder {
+    s.transfer_account_fee = v
+    return s
+}
+            
+
+func (s *ConfigCellAccountV2Builder) EditManagerFee(v Uint64) *ConfigCellAccountV2Builder {
     s.edit_manager_fee = v
     return s
 }
             
 
-func (s *ConfigCellAccountV1Builder) EditRecordsFee(v Uint64) *ConfigCellAccountV1Builder {
+func (s *ConfigCellAccountV2Builder) EditRecordsFee(v Uint64) *ConfigCellAccountV2Builder {
     s.edit_records_fee = v
     return s
 }
             
 
-func (s *ConfigCellAccountV1Builder) TransferAccountThrottle(v Uint32) *ConfigCellAccountV1Builder {
+func (s *ConfigCellAccountV2Builder) TransferAccountThrottle(v Uint32) *ConfigCellAccountV2Builder {
     s.transfer_account_throttle = v
     return s
 }
             
 
-func (s *ConfigCellAccountV1Builder) EditManagerThrottle(v Uint32) *ConfigCellAccountV1Builder {
+func (s *ConfigCellAccountV2Builder) EditManagerThrottle(v Uint32) *ConfigCellAccountV2Builder {
     s.edit_manager_throttle = v
     return s
 }
             
 
-func (s *ConfigCellAccountV1Builder) EditRecordsThrottle(v Uint32) *ConfigCellAccountV1Builder {
+func (s *ConfigCellAccountV2Builder) EditRecordsThrottle(v Uint32) *ConfigCellAccountV2Builder {
     s.edit_records_throttle = v
     return s
 }
             
 
-func NewConfigCellAccountV1Builder() *ConfigCellAccountV1Builder {
-	return &ConfigCellAccountV1Builder{ max_length: Uint32Default(),basic_capacity: Uint64Default(),prepared_fee_capacity: Uint64Default(),expiration_grace_period: Uint32Default(),record_min_ttl: Uint32Default(),record_size_limit: Uint32Default(),transfer_account_fee: Uint64Default(),edit_manager_fee: Uint64Default(),edit_records_fee: Uint64Default(),transfer_account_throttle: Uint32Default(),edit_manager_throttle: Uint32Default(),edit_records_throttle: Uint32Default() }
+func (s *ConfigCellAccountV2Builder) CommonThrottle(v Uint32) *ConfigCellAccountV2Builder {
+    s.common_throttle = v
+    return s
+}
+            
+
+func NewConfigCellAccountV2Builder() *ConfigCellAccountV2Builder {
+	return &ConfigCellAccountV2Builder{ max_length: Uint32Default(),basic_capacity: Uint64Default(),prepared_fee_capacity: Uint64Default(),expiration_grace_period: Uint32Default(),record_min_ttl: Uint32Default(),record_size_limit: Uint32Default(),transfer_account_fee: Uint64Default(),edit_manager_fee: Uint64Default(),edit_records_fee: Uint64Default(),transfer_account_throttle: Uint32Default(),edit_manager_throttle: Uint32Default(),edit_records_throttle: Uint32Default(),common_throttle: Uint32Default() }
 }
     
 
-type ConfigCellAccountV1 struct {
+type ConfigCellAccountV2 struct {
     inner []byte
 }
         
 
-func ConfigCellAccountV1FromSliceUnchecked(slice []byte) *ConfigCellAccountV1 {
-    return &ConfigCellAccountV1{inner: slice}
+func ConfigCellAccountV2FromSliceUnchecked(slice []byte) *ConfigCellAccountV2 {
+    return &ConfigCellAccountV2{inner: slice}
 }
-func (s *ConfigCellAccountV1) AsSlice() []byte {
+func (s *ConfigCellAccountV2) AsSlice() []byte {
     return s.inner
 }
             
 
-func ConfigCellAccountV1Default() ConfigCellAccountV1 {
-    return *ConfigCellAccountV1FromSliceUnchecked([]byte{ 120,0,0,0,52,0,0,0,56,0,0,0,64,0,0,0,72,0,0,0,76,0,0,0,80,0,0,0,84,0,0,0,92,0,0,0,100,0,0,0,108,0,0,0,112,0,0,0,116,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+func ConfigCellAccountV2Default() ConfigCellAccountV2 {
+    return *ConfigCellAccountV2FromSliceUnchecked([]byte{ 128,0,0,0,56,0,0,0,60,0,0,0,68,0,0,0,76,0,0,0,80,0,0,0,84,0,0,0,88,0,0,0,96,0,0,0,104,0,0,0,112,0,0,0,116,0,0,0,120,0,0,0,124,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
 }
             
 
-func ConfigCellAccountV1FromSlice(slice []byte, compatible bool) (*ConfigCellAccountV1, error) {
+func ConfigCellAccountV2FromSlice(slice []byte, compatible bool) (*ConfigCellAccountV2, error) {
     sliceLen := len(slice)
     if uint32(sliceLen) < HeaderSizeUint {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellAccountV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellAccountV2", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
         return nil, errors.New(errMsg)
     }
 
     totalSize := unpackNumber(slice)
     if Number(sliceLen) != totalSize {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellAccountV1", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellAccountV2", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if uint32(sliceLen) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellAccountV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellAccountV2", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     offsetFirst := unpackNumber(slice[HeaderSizeUint:])
     if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"OffsetsNotMatch", "ConfigCellAccountV1", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "ConfigCellAccountV2", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if sliceLen < int(offsetFirst) {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellAccountV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellAccountV2", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
         return nil, errors.New(errMsg)
     }
 
     fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
-    if fieldCount < 12 {
+    if fieldCount < 13 {
         return nil, errors.New("FieldCountNotMatch")
-    } else if !compatible && fieldCount > 12 {
+    } else if !compatible && fieldCount > 13 {
         return nil, errors.New("FieldCountNotMatch")
     }
 
@@ -4055,14 +6243,20 @@ if err != nil {
 }
                 
 
-    return &ConfigCellAccountV1{inner: slice}, nil
+_, err = Uint32FromSlice(slice[offsets[12]:offsets[13]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+    return &ConfigCellAccountV2{inner: slice}, nil
 }
             
 
-func (s *ConfigCellAccountV1) TotalSize() uint {
+func (s *ConfigCellAccountV2) TotalSize() uint {
     return uint(unpackNumber(s.inner))
 }
-func (s *ConfigCellAccountV1) FieldCount() uint {
+func (s *ConfigCellAccountV2) FieldCount() uint {
     var number uint = 0
     if uint32(s.TotalSize()) == HeaderSizeUint {
         return number
@@ -4070,103 +6264,110 @@ func (s *ConfigCellAccountV1) FieldCount() uint {
     number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
     return number
 }
-func (s *ConfigCellAccountV1) Len() uint {
+func (s *ConfigCellAccountV2) Len() uint {
     return s.FieldCount()
 }
-func (s *ConfigCellAccountV1) IsEmpty() bool {
+func (s *ConfigCellAccountV2) IsEmpty() bool {
     return s.Len() == 0
 }
-func (s *ConfigCellAccountV1) CountExtraFields() uint {
-    return s.FieldCount() - 12
+func (s *ConfigCellAccountV2) CountExtraFields() uint {
+    return s.FieldCount() - 13
 }
 
-func (s *ConfigCellAccountV1) HasExtraFields() bool {
-    return 12 != s.FieldCount()
+func (s *ConfigCellAccountV2) HasExtraFields() bool {
+    return 13 != s.FieldCount()
 }
             
 
-func (s *ConfigCellAccountV1) MaxLength() *Uint32 {
+func (s *ConfigCellAccountV2) MaxLength() *Uint32 {
     start := unpackNumber(s.inner[4:])
     end := unpackNumber(s.inner[8:])
     return Uint32FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ConfigCellAccountV1) BasicCapacity() *Uint64 {
+func (s *ConfigCellAccountV2) BasicCapacity() *Uint64 {
     start := unpackNumber(s.inner[8:])
     end := unpackNumber(s.inner[12:])
     return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ConfigCellAccountV1) PreparedFeeCapacity() *Uint64 {
+func (s *ConfigCellAccountV2) PreparedFeeCapacity() *Uint64 {
     start := unpackNumber(s.inner[12:])
     end := unpackNumber(s.inner[16:])
     return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ConfigCellAccountV1) ExpirationGracePeriod() *Uint32 {
+func (s *ConfigCellAccountV2) ExpirationGracePeriod() *Uint32 {
     start := unpackNumber(s.inner[16:])
     end := unpackNumber(s.inner[20:])
     return Uint32FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ConfigCellAccountV1) RecordMinTtl() *Uint32 {
+func (s *ConfigCellAccountV2) RecordMinTtl() *Uint32 {
     start := unpackNumber(s.inner[20:])
     end := unpackNumber(s.inner[24:])
     return Uint32FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ConfigCellAccountV1) RecordSizeLimit() *Uint32 {
+func (s *ConfigCellAccountV2) RecordSizeLimit() *Uint32 {
     start := unpackNumber(s.inner[24:])
     end := unpackNumber(s.inner[28:])
     return Uint32FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ConfigCellAccountV1) TransferAccountFee() *Uint64 {
+func (s *ConfigCellAccountV2) TransferAccountFee() *Uint64 {
     start := unpackNumber(s.inner[28:])
     end := unpackNumber(s.inner[32:])
     return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ConfigCellAccountV1) EditManagerFee() *Uint64 {
+func (s *ConfigCellAccountV2) EditManagerFee() *Uint64 {
     start := unpackNumber(s.inner[32:])
     end := unpackNumber(s.inner[36:])
     return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ConfigCellAccountV1) EditRecordsFee() *Uint64 {
+func (s *ConfigCellAccountV2) EditRecordsFee() *Uint64 {
     start := unpackNumber(s.inner[36:])
     end := unpackNumber(s.inner[40:])
     return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ConfigCellAccountV1) TransferAccountThrottle() *Uint32 {
+func (s *ConfigCellAccountV2) TransferAccountThrottle() *Uint32 {
     start := unpackNumber(s.inner[40:])
     end := unpackNumber(s.inner[44:])
     return Uint32FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ConfigCellAccountV1) EditManagerThrottle() *Uint32 {
+func (s *ConfigCellAccountV2) EditManagerThrottle() *Uint32 {
     start := unpackNumber(s.inner[44:])
     end := unpackNumber(s.inner[48:])
     return Uint32FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ConfigCellAccountV1) EditRecordsThrottle() *Uint32 {
-    var ret *Uint32
+func (s *ConfigCellAccountV2) EditRecordsThrottle() *Uint32 {
     start := unpackNumber(s.inner[48:])
+    end := unpackNumber(s.inner[52:])
+    return Uint32FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellAccountV2) CommonThrottle() *Uint32 {
+    var ret *Uint32
+    start := unpackNumber(s.inner[52:])
     if s.HasExtraFields() {
-        end := unpackNumber(s.inner[52:])
+        end := unpackNumber(s.inner[56:])
         ret = Uint32FromSliceUnchecked(s.inner[start:end])
     } else {
         ret = Uint32FromSliceUnchecked(s.inner[start:])
@@ -4175,8 +6376,8 @@ func (s *ConfigCellAccountV1) EditRecordsThrottle() *Uint32 {
 }
                         
 
-func (s *ConfigCellAccountV1) AsBuilder() ConfigCellAccountV1Builder {
-    ret := NewConfigCellAccountV1Builder().MaxLength(*s.MaxLength()).BasicCapacity(*s.BasicCapacity()).PreparedFeeCapacity(*s.PreparedFeeCapacity()).ExpirationGracePeriod(*s.ExpirationGracePeriod()).RecordMinTtl(*s.RecordMinTtl()).RecordSizeLimit(*s.RecordSizeLimit()).TransferAccountFee(*s.TransferAccountFee()).EditManagerFee(*s.EditManagerFee()).EditRecordsFee(*s.EditRecordsFee()).TransferAccountThrottle(*s.TransferAccountThrottle()).EditManagerThrottle(*s.EditManagerThrottle()).EditRecordsThrottle(*s.EditRecordsThrottle())
+func (s *ConfigCellAccountV2) AsBuilder() ConfigCellAccountV2Builder {
+    ret := NewConfigCellAccountV2Builder().MaxLength(*s.MaxLength()).BasicCapacity(*s.BasicCapacity()).PreparedFeeCapacity(*s.PreparedFeeCapacity()).ExpirationGracePeriod(*s.ExpirationGracePeriod()).RecordMinTtl(*s.RecordMinTtl()).RecordSizeLimit(*s.RecordSizeLimit()).TransferAccountFee(*s.TransferAccountFee()).EditManagerFee(*s.EditManagerFee()).EditRecordsFee(*s.EditRecordsFee()).TransferAccountThrottle(*s.TransferAccountThrottle()).EditManagerThrottle(*s.EditManagerThrottle()).EditRecordsThrottle(*s.EditRecordsThrottle()).CommonThrottle(*s.CommonThrottle())
     return *ret
 }
         
@@ -4805,62 +7006,318 @@ func (s *ConfigCellSecondaryMarketV1Builder) SaleDescriptionBytesLimit(v Uint32)
 }
             
 
-func NewConfigCellSecondaryMarketV1Builder() *ConfigCellSecondaryMarketV1Builder {
-	return &ConfigCellSecondaryMarketV1Builder{ min_sale_price: Uint64Default(),sale_expiration_limit: Uint64Default(),sale_description_bytes_limit: Uint32Default() }
+func NewConfigCellSecondaryMarketV1Builder() *ConfigCellSecondaryMarketV1Builder {
+	return &ConfigCellSecondaryMarketV1Builder{ min_sale_price: Uint64Default(),sale_expiration_limit: Uint64Default(),sale_description_bytes_limit: Uint32Default() }
+}
+    
+
+type ConfigCellSecondaryMarketV1 struct {
+    inner []byte
+}
+        
+
+func ConfigCellSecondaryMarketV1FromSliceUnchecked(slice []byte) *ConfigCellSecondaryMarketV1 {
+    return &ConfigCellSecondaryMarketV1{inner: slice}
+}
+func (s *ConfigCellSecondaryMarketV1) AsSlice() []byte {
+    return s.inner
+}
+            
+
+func ConfigCellSecondaryMarketV1Default() ConfigCellSecondaryMarketV1 {
+    return *ConfigCellSecondaryMarketV1FromSliceUnchecked([]byte{ 36,0,0,0,16,0,0,0,24,0,0,0,32,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+}
+            
+
+func ConfigCellSecondaryMarketV1FromSlice(slice []byte, compatible bool) (*ConfigCellSecondaryMarketV1, error) {
+    sliceLen := len(slice)
+    if uint32(sliceLen) < HeaderSizeUint {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellSecondaryMarketV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    totalSize := unpackNumber(slice)
+    if Number(sliceLen) != totalSize {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellSecondaryMarketV1", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if uint32(sliceLen) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellSecondaryMarketV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    offsetFirst := unpackNumber(slice[HeaderSizeUint:])
+    if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "ConfigCellSecondaryMarketV1", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if sliceLen < int(offsetFirst) {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellSecondaryMarketV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
+    if fieldCount < 3 {
+        return nil, errors.New("FieldCountNotMatch")
+    } else if !compatible && fieldCount > 3 {
+        return nil, errors.New("FieldCountNotMatch")
+    }
+
+    offsets := make([]uint32, fieldCount)
+
+    for i := 0; i < int(fieldCount); i++ {
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    }
+    offsets = append(offsets, uint32(totalSize))
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            return nil, errors.New("OffsetsNotMatch")
+        }
+    }
+
+    var err error
+    
+_, err = Uint64FromSlice(slice[offsets[0]:offsets[1]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint64FromSlice(slice[offsets[1]:offsets[2]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint32FromSlice(slice[offsets[2]:offsets[3]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+    return &ConfigCellSecondaryMarketV1{inner: slice}, nil
+}
+            
+
+func (s *ConfigCellSecondaryMarketV1) TotalSize() uint {
+    return uint(unpackNumber(s.inner))
+}
+func (s *ConfigCellSecondaryMarketV1) FieldCount() uint {
+    var number uint = 0
+    if uint32(s.TotalSize()) == HeaderSizeUint {
+        return number
+    }
+    number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
+    return number
+}
+func (s *ConfigCellSecondaryMarketV1) Len() uint {
+    return s.FieldCount()
+}
+func (s *ConfigCellSecondaryMarketV1) IsEmpty() bool {
+    return s.Len() == 0
+}
+func (s *ConfigCellSecondaryMarketV1) CountExtraFields() uint {
+    return s.FieldCount() - 3
+}
+
+func (s *ConfigCellSecondaryMarketV1) HasExtraFields() bool {
+    return 3 != s.FieldCount()
+}
+            
+
+func (s *ConfigCellSecondaryMarketV1) MinSalePrice() *Uint64 {
+    start := unpackNumber(s.inner[4:])
+    end := unpackNumber(s.inner[8:])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellSecondaryMarketV1) SaleExpirationLimit() *Uint64 {
+    start := unpackNumber(s.inner[8:])
+    end := unpackNumber(s.inner[12:])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellSecondaryMarketV1) SaleDescriptionBytesLimit() *Uint32 {
+    var ret *Uint32
+    start := unpackNumber(s.inner[12:])
+    if s.HasExtraFields() {
+        end := unpackNumber(s.inner[16:])
+        ret = Uint32FromSliceUnchecked(s.inner[start:end])
+    } else {
+        ret = Uint32FromSliceUnchecked(s.inner[start:])
+    }
+    return ret
+}
+                        
+
+func (s *ConfigCellSecondaryMarketV1) AsBuilder() ConfigCellSecondaryMarketV1Builder {
+    ret := NewConfigCellSecondaryMarketV1Builder().MinSalePrice(*s.MinSalePrice()).SaleExpirationLimit(*s.SaleExpirationLimit()).SaleDescriptionBytesLimit(*s.SaleDescriptionBytesLimit())
+    return *ret
+}
+        
+
+type ConfigCellSecondaryMarketV2Builder struct {
+    sale_min_price Uint64
+sale_expiration_limit Uint32
+sale_description_bytes_limit Uint32
+auction_max_extendable_duration Uint32
+auction_duration_increment_each_bid Uint32
+auction_min_opening_price Uint64
+auction_min_increment_rate_each_bid Uint32
+auction_description_bytes_limit Uint32
+}
+        
+
+func (s *ConfigCellSecondaryMarketV2Builder) Build() ConfigCellSecondaryMarketV2 {
+    b := new(bytes.Buffer)
+
+    totalSize := HeaderSizeUint * (8 + 1)
+    offsets := make([]uint32, 0, 8)
+
+    offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.sale_min_price.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.sale_expiration_limit.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.sale_description_bytes_limit.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.auction_max_extendable_duration.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.auction_duration_increment_each_bid.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.auction_min_opening_price.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.auction_min_increment_rate_each_bid.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.auction_description_bytes_limit.AsSlice()))
+
+    b.Write(packNumber(Number(totalSize)))
+
+    for i := 0; i < len(offsets); i++ {
+        b.Write(packNumber(Number(offsets[i])))
+    }
+
+    b.Write(s.sale_min_price.AsSlice())
+b.Write(s.sale_expiration_limit.AsSlice())
+b.Write(s.sale_description_bytes_limit.AsSlice())
+b.Write(s.auction_max_extendable_duration.AsSlice())
+b.Write(s.auction_duration_increment_each_bid.AsSlice())
+b.Write(s.auction_min_opening_price.AsSlice())
+b.Write(s.auction_min_increment_rate_each_bid.AsSlice())
+b.Write(s.auction_description_bytes_limit.AsSlice())
+    return ConfigCellSecondaryMarketV2{inner: b.Bytes()}
+}
+                
+
+func (s *ConfigCellSecondaryMarketV2Builder) SaleMinPrice(v Uint64) *ConfigCellSecondaryMarketV2Builder {
+    s.sale_min_price = v
+    return s
+}
+            
+
+func (s *ConfigCellSecondaryMarketV2Builder) SaleExpirationLimit(v Uint32) *ConfigCellSecondaryMarketV2Builder {
+    s.sale_expiration_limit = v
+    return s
+}
+            
+
+func (s *ConfigCellSecondaryMarketV2Builder) SaleDescriptionBytesLimit(v Uint32) *ConfigCellSecondaryMarketV2Builder {
+    s.sale_description_bytes_limit = v
+    return s
+}
+            
+
+func (s *ConfigCellSecondaryMarketV2Builder) AuctionMaxExtendableDuration(v Uint32) *ConfigCellSecondaryMarketV2Builder {
+    s.auction_max_extendable_duration = v
+    return s
+}
+            
+
+func (s *ConfigCellSecondaryMarketV2Builder) AuctionDurationIncrementEachBid(v Uint32) *ConfigCellSecondaryMarketV2Builder {
+    s.auction_duration_increment_each_bid = v
+    return s
+}
+            
+
+func (s *ConfigCellSecondaryMarketV2Builder) AuctionMinOpeningPrice(v Uint64) *ConfigCellSecondaryMarketV2Builder {
+    s.auction_min_opening_price = v
+    return s
+}
+            
+
+func (s *ConfigCellSecondaryMarketV2Builder) AuctionMinIncrementRateEachBid(v Uint32) *ConfigCellSecondaryMarketV2Builder {
+    s.auction_min_increment_rate_each_bid = v
+    return s
+}
+            
+
+func (s *ConfigCellSecondaryMarketV2Builder) AuctionDescriptionBytesLimit(v Uint32) *ConfigCellSecondaryMarketV2Builder {
+    s.auction_description_bytes_limit = v
+    return s
+}
+            
+
+func NewConfigCellSecondaryMarketV2Builder() *ConfigCellSecondaryMarketV2Builder {
+	return &ConfigCellSecondaryMarketV2Builder{ sale_min_price: Uint64Default(),sale_expiration_limit: Uint32Default(),sale_description_bytes_limit: Uint32Default(),auction_max_extendable_duration: Uint32Default(),auction_duration_increment_each_bid: Uint32Default(),auction_min_opening_price: Uint64Default(),auction_min_increment_rate_each_bid: Uint32Default(),auction_description_bytes_limit: Uint32Default() }
 }
     
 
-type ConfigCellSecondaryMarketV1 struct {
+type ConfigCellSecondaryMarketV2 struct {
     inner []byte
 }
         
 
-func ConfigCellSecondaryMarketV1FromSliceUnchecked(slice []byte) *ConfigCellSecondaryMarketV1 {
-    return &ConfigCellSecondaryMarketV1{inner: slice}
+func ConfigCellSecondaryMarketV2FromSliceUnchecked(slice []byte) *ConfigCellSecondaryMarketV2 {
+    return &ConfigCellSecondaryMarketV2{inner: slice}
 }
-func (s *ConfigCellSecondaryMarketV1) AsSlice() []byte {
+func (s *ConfigCellSecondaryMarketV2) AsSlice() []byte {
     return s.inner
 }
             
 
-func ConfigCellSecondaryMarketV1Default() ConfigCellSecondaryMarketV1 {
-    return *ConfigCellSecondaryMarketV1FromSliceUnchecked([]byte{ 36,0,0,0,16,0,0,0,24,0,0,0,32,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+func ConfigCellSecondaryMarketV2Default() ConfigCellSecondaryMarketV2 {
+    return *ConfigCellSecondaryMarketV2FromSliceUnchecked([]byte{ 76,0,0,0,36,0,0,0,44,0,0,0,48,0,0,0,52,0,0,0,56,0,0,0,60,0,0,0,68,0,0,0,72,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
 }
             
 
-func ConfigCellSecondaryMarketV1FromSlice(slice []byte, compatible bool) (*ConfigCellSecondaryMarketV1, error) {
+func ConfigCellSecondaryMarketV2FromSlice(slice []byte, compatible bool) (*ConfigCellSecondaryMarketV2, error) {
     sliceLen := len(slice)
     if uint32(sliceLen) < HeaderSizeUint {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellSecondaryMarketV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellSecondaryMarketV2", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
         return nil, errors.New(errMsg)
     }
 
     totalSize := unpackNumber(slice)
     if Number(sliceLen) != totalSize {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellSecondaryMarketV1", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellSecondaryMarketV2", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if uint32(sliceLen) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellSecondaryMarketV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellSecondaryMarketV2", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     offsetFirst := unpackNumber(slice[HeaderSizeUint:])
     if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"OffsetsNotMatch", "ConfigCellSecondaryMarketV1", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "ConfigCellSecondaryMarketV2", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if sliceLen < int(offsetFirst) {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellSecondaryMarketV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellSecondaryMarketV2", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
         return nil, errors.New(errMsg)
     }
 
     fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
-    if fieldCount < 3 {
+    if fieldCount < 8 {
         return nil, errors.New("FieldCountNotMatch")
-    } else if !compatible && fieldCount > 3 {
+    } else if !compatible && fieldCount > 8 {
         return nil, errors.New("FieldCountNotMatch")
     }
 
@@ -4885,7 +7342,7 @@ if err != nil {
 }
                 
 
-_, err = Uint64FromSlice(slice[offsets[1]:offsets[2]], compatible)
+_, err = Uint32FromSlice(slice[offsets[1]:offsets[2]], compatible)
 if err != nil {
     return nil, err
 }
@@ -4897,14 +7354,44 @@ if err != nil {
 }
                 
 
-    return &ConfigCellSecondaryMarketV1{inner: slice}, nil
+_, err = Uint32FromSlice(slice[offsets[3]:offsets[4]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint32FromSlice(slice[offsets[4]:offsets[5]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint64FromSlice(slice[offsets[5]:offsets[6]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint32FromSlice(slice[offsets[6]:offsets[7]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint32FromSlice(slice[offsets[7]:offsets[8]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+    return &ConfigCellSecondaryMarketV2{inner: slice}, nil
 }
             
 
-func (s *ConfigCellSecondaryMarketV1) TotalSize() uint {
+func (s *ConfigCellSecondaryMarketV2) TotalSize() uint {
     return uint(unpackNumber(s.inner))
 }
-func (s *ConfigCellSecondaryMarketV1) FieldCount() uint {
+func (s *ConfigCellSecondaryMarketV2) FieldCount() uint {
     var number uint = 0
     if uint32(s.TotalSize()) == HeaderSizeUint {
         return number
@@ -4912,40 +7399,75 @@ func (s *ConfigCellSecondaryMarketV1) FieldCount() uint {
     number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
     return number
 }
-func (s *ConfigCellSecondaryMarketV1) Len() uint {
+func (s *ConfigCellSecondaryMarketV2) Len() uint {
     return s.FieldCount()
 }
-func (s *ConfigCellSecondaryMarketV1) IsEmpty() bool {
+func (s *ConfigCellSecondaryMarketV2) IsEmpty() bool {
     return s.Len() == 0
 }
-func (s *ConfigCellSecondaryMarketV1) CountExtraFields() uint {
-    return s.FieldCount() - 3
+func (s *ConfigCellSecondaryMarketV2) CountExtraFields() uint {
+    return s.FieldCount() - 8
 }
 
-func (s *ConfigCellSecondaryMarketV1) HasExtraFields() bool {
-    return 3 != s.FieldCount()
+func (s *ConfigCellSecondaryMarketV2) HasExtraFields() bool {
+    return 8 != s.FieldCount()
 }
             
 
-func (s *ConfigCellSecondaryMarketV1) MinSalePrice() *Uint64 {
+func (s *ConfigCellSecondaryMarketV2) SaleMinPrice() *Uint64 {
     start := unpackNumber(s.inner[4:])
     end := unpackNumber(s.inner[8:])
     return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ConfigCellSecondaryMarketV1) SaleExpirationLimit() *Uint64 {
+func (s *ConfigCellSecondaryMarketV2) SaleExpirationLimit() *Uint32 {
     start := unpackNumber(s.inner[8:])
     end := unpackNumber(s.inner[12:])
+    return Uint32FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellSecondaryMarketV2) SaleDescriptionBytesLimit() *Uint32 {
+    start := unpackNumber(s.inner[12:])
+    end := unpackNumber(s.inner[16:])
+    return Uint32FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellSecondaryMarketV2) AuctionMaxExtendableDuration() *Uint32 {
+    start := unpackNumber(s.inner[16:])
+    end := unpackNumber(s.inner[20:])
+    return Uint32FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellSecondaryMarketV2) AuctionDurationIncrementEachBid() *Uint32 {
+    start := unpackNumber(s.inner[20:])
+    end := unpackNumber(s.inner[24:])
+    return Uint32FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellSecondaryMarketV2) AuctionMinOpeningPrice() *Uint64 {
+    start := unpackNumber(s.inner[24:])
+    end := unpackNumber(s.inner[28:])
     return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ConfigCellSecondaryMarketV1) SaleDescriptionBytesLimit() *Uint32 {
+func (s *ConfigCellSecondaryMarketV2) AuctionMinIncrementRateEachBid() *Uint32 {
+    start := unpackNumber(s.inner[28:])
+    end := unpackNumber(s.inner[32:])
+    return Uint32FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellSecondaryMarketV2) AuctionDescriptionBytesLimit() *Uint32 {
     var ret *Uint32
-    start := unpackNumber(s.inner[12:])
+    start := unpackNumber(s.inner[32:])
     if s.HasExtraFields() {
-        end := unpackNumber(s.inner[16:])
+        end := unpackNumber(s.inner[36:])
         ret = Uint32FromSliceUnchecked(s.inner[start:end])
     } else {
         ret = Uint32FromSliceUnchecked(s.inner[start:])
@@ -4954,46 +7476,49 @@ func (s *ConfigCellSecondaryMarketV1) SaleDescriptionBytesLimit() *Uint32 {
 }
                         
 
-func (s *ConfigCellSecondaryMarketV1) AsBuilder() ConfigCellSecondaryMarketV1Builder {
-    ret := NewConfigCellSecondaryMarketV1Builder().MinSalePrice(*s.MinSalePrice()).SaleExpirationLimit(*s.SaleExpirationLimit()).SaleDescriptionBytesLimit(*s.SaleDescriptionBytesLimit())
+func (s *ConfigCellSecondaryMarketV2) AsBuilder() ConfigCellSecondaryMarketV2Builder {
+    ret := NewConfigCellSecondaryMarketV2Builder().SaleMinPrice(*s.SaleMinPrice()).SaleExpirationLimit(*s.SaleExpirationLimit()).SaleDescriptionBytesLimit(*s.SaleDescriptionBytesLimit()).AuctionMaxExtendableDuration(*s.AuctionMaxExtendableDuration()).AuctionDurationIncrementEachBid(*s.AuctionDurationIncrementEachBid()).AuctionMinOpeningPrice(*s.AuctionMinOpeningPrice()).AuctionMinIncrementRateEachBid(*s.AuctionMinIncrementRateEachBid()).AuctionDescriptionBytesLimit(*s.AuctionDescriptionBytesLimit())
     return *ret
 }
         
 
-type ConfigCellSecondaryMarketV2Builder struct {
-    sale_min_price Uint64
-sale_expiration_limit Uint32
-sale_description_bytes_limit Uint32
-auction_max_extendable_duration Uint32
-auction_duration_increment_each_bid Uint32
-auction_min_opening_price Uint64
-auction_min_increment_rate_each_bid Uint32
-auction_description_bytes_limit Uint32
+type ConfigCellSubAccountV1Builder struct {
+    basic_capacity Uint64
+prepared_fee_capacity Uint64
+new_sub_account_price Uint64
+renew_sub_account_price Uint64
+common_fee Uint64
+create_fee Uint64
+edit_fee Uint64
+renew_fee Uint64
+recycle_fee Uint64
 }
         
 
-func (s *ConfigCellSecondaryMarketV2Builder) Build() ConfigCellSecondaryMarketV2 {
+func (s *ConfigCellSubAccountV1Builder) Build() ConfigCellSubAccountV1 {
     b := new(bytes.Buffer)
 
-    totalSize := HeaderSizeUint * (8 + 1)
-    offsets := make([]uint32, 0, 8)
+    totalSize := HeaderSizeUint * (9 + 1)
+    offsets := make([]uint32, 0, 9)
 
     offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.sale_min_price.AsSlice()))
+totalSize += uint32(len(s.basic_capacity.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.sale_expiration_limit.AsSlice()))
+totalSize += uint32(len(s.prepared_fee_capacity.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.sale_description_bytes_limit.AsSlice()))
+totalSize += uint32(len(s.new_sub_account_price.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.auction_max_extendable_duration.AsSlice()))
+totalSize += uint32(len(s.renew_sub_account_price.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.auction_duration_increment_each_bid.AsSlice()))
+totalSize += uint32(len(s.common_fee.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.auction_min_opening_price.AsSlice()))
+totalSize += uint32(len(s.create_fee.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.auction_min_increment_rate_each_bid.AsSlice()))
+totalSize += uint32(len(s.edit_fee.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.auction_description_bytes_limit.AsSlice()))
+totalSize += uint32(len(s.renew_fee.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.recycle_fee.AsSlice()))
 
     b.Write(packNumber(Number(totalSize)))
 
@@ -5001,122 +7526,129 @@ totalSize += uint32(len(s.auction_description_bytes_limit.AsSlice()))
         b.Write(packNumber(Number(offsets[i])))
     }
 
-    b.Write(s.sale_min_price.AsSlice())
-b.Write(s.sale_expiration_limit.AsSlice())
-b.Write(s.sale_description_bytes_limit.AsSlice())
-b.Write(s.auction_max_extendable_duration.AsSlice())
-b.Write(s.auction_duration_increment_each_bid.AsSlice())
-b.Write(s.auction_min_opening_price.AsSlice())
-b.Write(s.auction_min_increment_rate_each_bid.AsSlice())
-b.Write(s.auction_description_bytes_limit.AsSlice())
-    return ConfigCellSecondaryMarketV2{inner: b.Bytes()}
+    b.Write(s.basic_capacity.AsSlice())
+b.Write(s.prepared_fee_capacity.AsSlice())
+b.Write(s.new_sub_account_price.AsSlice())
+b.Write(s.renew_sub_account_price.AsSlice())
+b.Write(s.common_fee.AsSlice())
+b.Write(s.create_fee.AsSlice())
+b.Write(s.edit_fee.AsSlice())
+b.Write(s.renew_fee.AsSlice())
+b.Write(s.recycle_fee.AsSlice())
+    return ConfigCellSubAccountV1{inner: b.Bytes()}
 }
                 
 
-func (s *ConfigCellSecondaryMarketV2Builder) SaleMinPrice(v Uint64) *ConfigCellSecondaryMarketV2Builder {
-    s.sale_min_price = v
+func (s *ConfigCellSubAccountV1Builder) BasicCapacity(v Uint64) *ConfigCellSubAccountV1Builder {
+    s.basic_capacity = v
     return s
 }
             
 
-func (s *ConfigCellSecondaryMarketV2Builder) SaleExpirationLimit(v Uint32) *ConfigCellSecondaryMarketV2Builder {
-    s.sale_expiration_limit = v
+func (s *ConfigCellSubAccountV1Builder) PreparedFeeCapacity(v Uint64) *ConfigCellSubAccountV1Builder {
+    s.prepared_fee_capacity = v
     return s
 }
             
 
-func (s *ConfigCellSecondaryMarketV2Builder) SaleDescriptionBytesLimit(v Uint32) *ConfigCellSecondaryMarketV2Builder {
-    s.sale_description_bytes_limit = v
+func (s *ConfigCellSubAccountV1Builder) NewSubAccountPrice(v Uint64) *ConfigCellSubAccountV1Builder {
+    s.new_sub_account_price = v
     return s
 }
             
 
-func (s *ConfigCellSecondaryMarketV2Builder) AuctionMaxExtendableDuration(v Uint32) *ConfigCellSecondaryMarketV2Builder {
-    s.auction_max_extendable_duration = v
+func (s *ConfigCellSubAccountV1Builder) RenewSubAccountPrice(v Uint64) *ConfigCellSubAccountV1Builder {
+    s.renew_sub_account_price = v
     return s
 }
             
 
-func (s *ConfigCellSecondaryMarketV2Builder) AuctionDurationIncrementEachBid(v Uint32) *ConfigCellSecondaryMarketV2Builder {
-    s.auction_duration_increment_each_bid = v
+func (s *ConfigCellSubAccountV1Builder) CommonFee(v Uint64) *ConfigCellSubAccountV1Builder {
+    s.common_fee = v
     return s
 }
             
 
-func (s *ConfigCellSecondaryMarketV2Builder) AuctionMinOpeningPrice(v Uint64) *ConfigCellSecondaryMarketV2Builder {
-    s.auction_min_opening_price = v
+func (s *ConfigCellSubAccountV1Builder) CreateFee(v Uint64) *ConfigCellSubAccountV1Builder {
+    s.create_fee = v
     return s
 }
             
 
-func (s *ConfigCellSecondaryMarketV2Builder) AuctionMinIncrementRateEachBid(v Uint32) *ConfigCellSecondaryMarketV2Builder {
-    s.auction_min_increment_rate_each_bid = v
+func (s *ConfigCellSubAccountV1Builder) EditFee(v Uint64) *ConfigCellSubAccountV1Builder {
+    s.edit_fee = v
     return s
 }
             
 
-func (s *ConfigCellSecondaryMarketV2Builder) AuctionDescriptionBytesLimit(v Uint32) *ConfigCellSecondaryMarketV2Builder {
-    s.auction_description_bytes_limit = v
+func (s *ConfigCellSubAccountV1Builder) RenewFee(v Uint64) *ConfigCellSubAccountV1Builder {
+    s.renew_fee = v
     return s
 }
             
 
-func NewConfigCellSecondaryMarketV2Builder() *ConfigCellSecondaryMarketV2Builder {
-	return &ConfigCellSecondaryMarketV2Builder{ sale_min_price: Uint64Default(),sale_expiration_limit: Uint32Default(),sale_description_bytes_limit: Uint32Default(),auction_max_extendable_duration: Uint32Default(),auction_duration_increment_each_bid: Uint32Default(),auction_min_opening_price: Uint64Default(),auction_min_increment_rate_each_bid: Uint32Default(),auction_description_bytes_limit: Uint32Default() }
+func (s *ConfigCellSubAccountV1Builder) RecycleFee(v Uint64) *ConfigCellSubAccountV1Builder {
+    s.recycle_fee = v
+    return s
+}
+            
+
+func NewConfigCellSubAccountV1Builder() *ConfigCellSubAccountV1Builder {
+	return &ConfigCellSubAccountV1Builder{ basic_capacity: Uint64Default(),prepared_fee_capacity: Uint64Default(),new_sub_account_price: Uint64Default(),renew_sub_account_price: Uint64Default(),common_fee: Uint64Default(),create_fee: Uint64Default(),edit_fee: Uint64Default(),renew_fee: Uint64Default(),recycle_fee: Uint64Default() }
 }
     
 
-type ConfigCellSecondaryMarketV2 struct {
+type ConfigCellSubAccountV1 struct {
     inner []byte
 }
         
 
-func ConfigCellSecondaryMarketV2FromSliceUnchecked(slice []byte) *ConfigCellSecondaryMarketV2 {
-    return &ConfigCellSecondaryMarketV2{inner: slice}
+func ConfigCellSubAccountV1FromSliceUnchecked(slice []byte) *ConfigCellSubAccountV1 {
+    return &ConfigCellSubAccountV1{inner: slice}
 }
-func (s *ConfigCellSecondaryMarketV2) AsSlice() []byte {
+func (s *ConfigCellSubAccountV1) AsSlice() []byte {
     return s.inner
 }
             
 
-func ConfigCellSecondaryMarketV2Default() ConfigCellSecondaryMarketV2 {
-    return *ConfigCellSecondaryMarketV2FromSliceUnchecked([]byte{ 76,0,0,0,36,0,0,0,44,0,0,0,48,0,0,0,52,0,0,0,56,0,0,0,60,0,0,0,68,0,0,0,72,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+func ConfigCellSubAccountV1Default() ConfigCellSubAccountV1 {
+    return *ConfigCellSubAccountV1FromSliceUnchecked([]byte{ 112,0,0,0,40,0,0,0,48,0,0,0,56,0,0,0,64,0,0,0,72,0,0,0,80,0,0,0,88,0,0,0,96,0,0,0,104,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
 }
             
 
-func ConfigCellSecondaryMarketV2FromSlice(slice []byte, compatible bool) (*ConfigCellSecondaryMarketV2, error) {
+func ConfigCellSubAccountV1FromSlice(slice []byte, compatible bool) (*ConfigCellSubAccountV1, error) {
     sliceLen := len(slice)
     if uint32(sliceLen) < HeaderSizeUint {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellSecondaryMarketV2", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellSubAccountV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
         return nil, errors.New(errMsg)
     }
 
     totalSize := unpackNumber(slice)
     if Number(sliceLen) != totalSize {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellSecondaryMarketV2", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellSubAccountV1", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if uint32(sliceLen) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellSecondaryMarketV2", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellSubAccountV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     offsetFirst := unpackNumber(slice[HeaderSizeUint:])
     if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"OffsetsNotMatch", "ConfigCellSecondaryMarketV2", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "ConfigCellSubAccountV1", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if sliceLen < int(offsetFirst) {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellSecondaryMarketV2", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellSubAccountV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
         return nil, errors.New(errMsg)
     }
 
     fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
-    if fieldCount < 8 {
+    if fieldCount < 9 {
         return nil, errors.New("FieldCountNotMatch")
-    } else if !compatible && fieldCount > 8 {
+    } else if !compatible && fieldCount > 9 {
         return nil, errors.New("FieldCountNotMatch")
     }
 
@@ -5141,25 +7673,25 @@ if err != nil {
 }
                 
 
-_, err = Uint32FromSlice(slice[offsets[1]:offsets[2]], compatible)
+_, err = Uint64FromSlice(slice[offsets[1]:offsets[2]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = Uint32FromSlice(slice[offsets[2]:offsets[3]], compatible)
+_, err = Uint64FromSlice(slice[offsets[2]:offsets[3]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = Uint32FromSlice(slice[offsets[3]:offsets[4]], compatible)
+_, err = Uint64FromSlice(slice[offsets[3]:offsets[4]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = Uint32FromSlice(slice[offsets[4]:offsets[5]], compatible)
+_, err = Uint64FromSlice(slice[offsets[4]:offsets[5]], compatible)
 if err != nil {
     return nil, err
 }
@@ -5171,26 +7703,32 @@ if err != nil {
 }
                 
 
-_, err = Uint32FromSlice(slice[offsets[6]:offsets[7]], compatible)
+_, err = Uint64FromSlice(slice[offsets[6]:offsets[7]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = Uint32FromSlice(slice[offsets[7]:offsets[8]], compatible)
+_, err = Uint64FromSlice(slice[offsets[7]:offsets[8]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-    return &ConfigCellSecondaryMarketV2{inner: slice}, nil
+_, err = Uint64FromSlice(slice[offsets[8]:offsets[9]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+    return &ConfigCellSubAccountV1{inner: slice}, nil
 }
             
 
-func (s *ConfigCellSecondaryMarketV2) TotalSize() uint {
+func (s *ConfigCellSubAccountV1) TotalSize() uint {
     return uint(unpackNumber(s.inner))
 }
-func (s *ConfigCellSecondaryMarketV2) FieldCount() uint {
+func (s *ConfigCellSubAccountV1) FieldCount() uint {
     var number uint = 0
     if uint32(s.TotalSize()) == HeaderSizeUint {
         return number
@@ -5198,126 +7736,145 @@ func (s *ConfigCellSecondaryMarketV2) FieldCount() uint {
     number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
     return number
 }
-func (s *ConfigCellSecondaryMarketV2) Len() uint {
+func (s *ConfigCellSubAccountV1) Len() uint {
     return s.FieldCount()
 }
-func (s *ConfigCellSecondaryMarketV2) IsEmpty() bool {
+func (s *ConfigCellSubAccountV1) IsEmpty() bool {
     return s.Len() == 0
 }
-func (s *ConfigCellSecondaryMarketV2) CountExtraFields() uint {
-    return s.FieldCount() - 8
+func (s *ConfigCellSubAccountV1) CountExtraFields() uint {
+    return s.FieldCount() - 9
 }
 
-func (s *ConfigCellSecondaryMarketV2) HasExtraFields() bool {
-    return 8 != s.FieldCount()
+func (s *ConfigCellSubAccountV1) HasExtraFields() bool {
+    return 9 != s.FieldCount()
 }
             
 
-func (s *ConfigCellSecondaryMarketV2) SaleMinPrice() *Uint64 {
+func (s *ConfigCellSubAccountV1) BasicCapacity() *Uint64 {
     start := unpackNumber(s.inner[4:])
     end := unpackNumber(s.inner[8:])
     return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ConfigCellSecondaryMarketV2) SaleExpirationLimit() *Uint32 {
+func (s *ConfigCellSubAccountV1) PreparedFeeCapacity() *Uint64 {
     start := unpackNumber(s.inner[8:])
     end := unpackNumber(s.inner[12:])
-    return Uint32FromSliceUnchecked(s.inner[start:end])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ConfigCellSecondaryMarketV2) SaleDescriptionBytesLimit() *Uint32 {
+func (s *ConfigCellSubAccountV1) NewSubAccountPrice() *Uint64 {
     start := unpackNumber(s.inner[12:])
     end := unpackNumber(s.inner[16:])
-    return Uint32FromSliceUnchecked(s.inner[start:end])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ConfigCellSecondaryMarketV2) AuctionMaxExtendableDuration() *Uint32 {
+func (s *ConfigCellSubAccountV1) RenewSubAccountPrice() *Uint64 {
     start := unpackNumber(s.inner[16:])
     end := unpackNumber(s.inner[20:])
-    return Uint32FromSliceUnchecked(s.inner[start:end])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ConfigCellSecondaryMarketV2) AuctionDurationIncrementEachBid() *Uint32 {
+func (s *ConfigCellSubAccountV1) CommonFee() *Uint64 {
     start := unpackNumber(s.inner[20:])
     end := unpackNumber(s.inner[24:])
-    return Uint32FromSliceUnchecked(s.inner[start:end])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ConfigCellSecondaryMarketV2) AuctionMinOpeningPrice() *Uint64 {
+func (s *ConfigCellSubAccountV1) CreateFee() *Uint64 {
     start := unpackNumber(s.inner[24:])
     end := unpackNumber(s.inner[28:])
     return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ConfigCellSecondaryMarketV2) AuctionMinIncrementRateEachBid() *Uint32 {
+func (s *ConfigCellSubAccountV1) EditFee() *Uint64 {
     start := unpackNumber(s.inner[28:])
     end := unpackNumber(s.inner[32:])
-    return Uint32FromSliceUnchecked(s.inner[start:end])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ConfigCellSecondaryMarketV2) AuctionDescriptionBytesLimit() *Uint32 {
-    var ret *Uint32
+func (s *ConfigCellSubAccountV1) RenewFee() *Uint64 {
     start := unpackNumber(s.inner[32:])
+    end := unpackNumber(s.inner[36:])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellSubAccountV1) RecycleFee() *Uint64 {
+    var ret *Uint64
+    start := unpackNumber(s.inner[36:])
     if s.HasExtraFields() {
-        end := unpackNumber(s.inner[36:])
-        ret = Uint32FromSliceUnchecked(s.inner[start:end])
+        end := unpackNumber(s.inner[40:])
+        ret = Uint64FromSliceUnchecked(s.inner[start:end])
     } else {
-        ret = Uint32FromSliceUnchecked(s.inner[start:])
+        ret = Uint64FromSliceUnchecked(s.inner[start:])
     }
     return ret
 }
                         
 
-func (s *ConfigCellSecondaryMarketV2) AsBuilder() ConfigCellSecondaryMarketV2Builder {
-    ret := NewConfigCellSecondaryMarketV2Builder().SaleMinPrice(*s.SaleMinPrice()).SaleExpirationLimit(*s.SaleExpirationLimit()).SaleDescriptionBytesLimit(*s.SaleDescriptionBytesLimit()).AuctionMaxExtendableDuration(*s.AuctionMaxExtendableDuration()).AuctionDurationIncrementEachBid(*s.AuctionDurationIncrementEachBid()).AuctionMinOpeningPrice(*s.AuctionMinOpeningPrice()).AuctionMinIncrementRateEachBid(*s.AuctionMinIncrementRateEachBid()).AuctionDescriptionBytesLimit(*s.AuctionDescriptionBytesLimit())
+func (s *ConfigCellSubAccountV1) AsBuilder() ConfigCellSubAccountV1Builder {
+    ret := NewConfigCellSubAccountV1Builder().BasicCapacity(*s.BasicCapacity()).PreparedFeeCapacity(*s.PreparedFeeCapacity()).NewSubAccountPrice(*s.NewSubAccountPrice()).RenewSubAccountPrice(*s.RenewSubAccountPrice()).CommonFee(*s.CommonFee()).CreateFee(*s.CreateFee()).EditFee(*s.EditFee()).RenewFee(*s.RenewFee()).RecycleFee(*s.RecycleFee())
     return *ret
 }
         
 
-type ConfigCellSubAccountV1Builder struct {
-    basic_capacity Uint64
-prepared_fee_capacity Uint64
-new_sub_account_price Uint64
-renew_sub_account_price Uint64
-common_fee Uint64
-create_fee Uint64
-edit_fee Uint64
-renew_fee Uint64
-recycle_fee Uint64
+type ConfigCellSystemStatusV1Builder struct {
+    apply_register_cell_type ContractStatus
+pre_account_cell_type ContractStatus
+proposal_cell_type ContractStatus
+config_cell_type ContractStatus
+account_cell_type ContractStatus
+account_sale_cell_type ContractStatus
+sub_account_cell_type ContractStatus
+offer_cell_type ContractStatus
+balance_cell_type ContractStatus
+income_cell_type ContractStatus
+reverse_record_cell_type ContractStatus
+reverse_record_root_cell_type ContractStatus
+eip712_lib ContractStatus
 }
         
 
-func (s *ConfigCellSubAccountV1Builder) Build() ConfigCellSubAccountV1 {
+func (s *ConfigCellSystemStatusV1Builder) Build() ConfigCellSystemStatusV1 {
     b := new(bytes.Buffer)
 
-    totalSize := HeaderSizeUint * (9 + 1)
-    offsets := make([]uint32, 0, 9)
+    totalSize := HeaderSizeUint * (13 + 1)
+    offsets := make([]uint32, 0, 13)
 
     offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.basic_capacity.AsSlice()))
+totalSize += uint32(len(s.apply_register_cell_type.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.prepared_fee_capacity.AsSlice()))
+totalSize += uint32(len(s.pre_account_cell_type.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.new_sub_account_price.AsSlice()))
+totalSize += uint32(len(s.proposal_cell_type.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.renew_sub_account_price.AsSlice()))
+totalSize += uint32(len(s.config_cell_type.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.common_fee.AsSlice()))
+totalSize += uint32(len(s.account_cell_type.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.create_fee.AsSlice()))
+totalSize += uint32(len(s.account_sale_cell_type.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.edit_fee.AsSlice()))
+totalSize += uint32(len(s.sub_account_cell_type.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.renew_fee.AsSlice()))
+totalSize += uint32(len(s.offer_cell_type.AsSlice()))
 offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.recycle_fee.AsSlice()))
+totalSize += uint32(len(s.balance_cell_type.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.income_cell_type.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.reverse_record_cell_type.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.reverse_record_root_cell_type.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.eip712_lib.AsSlice()))
 
     b.Write(packNumber(Number(totalSize)))
 
@@ -5325,129 +7882,157 @@ totalSize += uint32(len(s.recycle_fee.AsSlice()))
         b.Write(packNumber(Number(offsets[i])))
     }
 
-    b.Write(s.basic_capacity.AsSlice())
-b.Write(s.prepared_fee_capacity.AsSlice())
-b.Write(s.new_sub_account_price.AsSlice())
-b.Write(s.renew_sub_account_price.AsSlice())
-b.Write(s.common_fee.AsSlice())
-b.Write(s.create_fee.AsSlice())
-b.Write(s.edit_fee.AsSlice())
-b.Write(s.renew_fee.AsSlice())
-b.Write(s.recycle_fee.AsSlice())
-    return ConfigCellSubAccountV1{inner: b.Bytes()}
+    b.Write(s.apply_register_cell_type.AsSlice())
+b.Write(s.pre_account_cell_type.AsSlice())
+b.Write(s.proposal_cell_type.AsSlice())
+b.Write(s.config_cell_type.AsSlice())
+b.Write(s.account_cell_type.AsSlice())
+b.Write(s.account_sale_cell_type.AsSlice())
+b.Write(s.sub_account_cell_type.AsSlice())
+b.Write(s.offer_cell_type.AsSlice())
+b.Write(s.balance_cell_type.AsSlice())
+b.Write(s.income_cell_type.AsSlice())
+b.Write(s.reverse_record_cell_type.AsSlice())
+b.Write(s.reverse_record_root_cell_type.AsSlice())
+b.Write(s.eip712_lib.AsSlice())
+    return ConfigCellSystemStatusV1{inner: b.Bytes()}
 }
                 
 
-func (s *ConfigCellSubAccountV1Builder) BasicCapacity(v Uint64) *ConfigCellSubAccountV1Builder {
-    s.basic_capacity = v
+func (s *ConfigCellSystemStatusV1Builder) ApplyRegisterCellType(v ContractStatus) *ConfigCellSystemStatusV1Builder {
+    s.apply_register_cell_type = v
     return s
 }
             
 
-func (s *ConfigCellSubAccountV1Builder) PreparedFeeCapacity(v Uint64) *ConfigCellSubAccountV1Builder {
-    s.prepared_fee_capacity = v
+func (s *ConfigCellSystemStatusV1Builder) PreAccountCellType(v ContractStatus) *ConfigCellSystemStatusV1Builder {
+    s.pre_account_cell_type = v
     return s
 }
             
 
-func (s *ConfigCellSubAccountV1Builder) NewSubAccountPrice(v Uint64) *ConfigCellSubAccountV1Builder {
-    s.new_sub_account_price = v
+func (s *ConfigCellSystemStatusV1Builder) ProposalCellType(v ContractStatus) *ConfigCellSystemStatusV1Builder {
+    s.proposal_cell_type = v
     return s
 }
             
 
-func (s *ConfigCellSubAccountV1Builder) RenewSubAccountPrice(v Uint64) *ConfigCellSubAccountV1Builder {
-    s.renew_sub_account_price = v
+func (s *ConfigCellSystemStatusV1Builder) ConfigCellType(v ContractStatus) *ConfigCellSystemStatusV1Builder {
+    s.config_cell_type = v
     return s
 }
             
 
-func (s *ConfigCellSubAccountV1Builder) CommonFee(v Uint64) *ConfigCellSubAccountV1Builder {
-    s.common_fee = v
+func (s *ConfigCellSystemStatusV1Builder) AccountCellType(v ContractStatus) *ConfigCellSystemStatusV1Builder {
+    s.account_cell_type = v
     return s
 }
             
 
-func (s *ConfigCellSubAccountV1Builder) CreateFee(v Uint64) *ConfigCellSubAccountV1Builder {
-    s.create_fee = v
+func (s *ConfigCellSystemStatusV1Builder) AccountSaleCellType(v ContractStatus) *ConfigCellSystemStatusV1Builder {
+    s.account_sale_cell_type = v
     return s
 }
             
 
-func (s *ConfigCellSubAccountV1Builder) EditFee(v Uint64) *ConfigCellSubAccountV1Builder {
-    s.edit_fee = v
+func (s *ConfigCellSystemStatusV1Builder) SubAccountCellType(v ContractStatus) *ConfigCellSystemStatusV1Builder {
+    s.sub_account_cell_type = v
     return s
 }
             
 
-func (s *ConfigCellSubAccountV1Builder) RenewFee(v Uint64) *ConfigCellSubAccountV1Builder {
-    s.renew_fee = v
+func (s *ConfigCellSystemStatusV1Builder) OfferCellType(v ContractStatus) *ConfigCellSystemStatusV1Builder {
+    s.offer_cell_type = v
     return s
 }
             
 
-func (s *ConfigCellSubAccountV1Builder) RecycleFee(v Uint64) *ConfigCellSubAccountV1Builder {
-    s.recycle_fee = v
+func (s *ConfigCellSystemStatusV1Builder) BalanceCellType(v ContractStatus) *ConfigCellSystemStatusV1Builder {
+    s.balance_cell_type = v
     return s
 }
             
 
-func NewConfigCellSubAccountV1Builder() *ConfigCellSubAccountV1Builder {
-	return &ConfigCellSubAccountV1Builder{ basic_capacity: Uint64Default(),prepared_fee_capacity: Uint64Default(),new_sub_account_price: Uint64Default(),renew_sub_account_price: Uint64Default(),common_fee: Uint64Default(),create_fee: Uint64Default(),edit_fee: Uint64Default(),renew_fee: Uint64Default(),recycle_fee: Uint64Default() }
+func (s *ConfigCellSystemStatusV1Builder) IncomeCellType(v ContractStatus) *ConfigCellSystemStatusV1Builder {
+    s.income_cell_type = v
+    return s
+}
+            
+
+func (s *ConfigCellSystemStatusV1Builder) ReverseRecordCellType(v ContractStatus) *ConfigCellSystemStatusV1Builder {
+    s.reverse_record_cell_type = v
+    return s
+}
+            
+
+func (s *ConfigCellSystemStatusV1Builder) ReverseRecordRootCellType(v ContractStatus) *ConfigCellSystemStatusV1Builder {
+    s.reverse_record_root_cell_type = v
+    return s
+}
+            
+
+func (s *ConfigCellSystemStatusV1Builder) Eip712Lib(v ContractStatus) *ConfigCellSystemStatusV1Builder {
+    s.eip712_lib = v
+    return s
+}
+            
+
+func NewConfigCellSystemStatusV1Builder() *ConfigCellSystemStatusV1Builder {
+	return &ConfigCellSystemStatusV1Builder{ apply_register_cell_type: ContractStatusDefault(),pre_account_cell_type: ContractStatusDefault(),proposal_cell_type: ContractStatusDefault(),config_cell_type: ContractStatusDefault(),account_cell_type: ContractStatusDefault(),account_sale_cell_type: ContractStatusDefault(),sub_account_cell_type: ContractStatusDefault(),offer_cell_type: ContractStatusDefault(),balance_cell_type: ContractStatusDefault(),income_cell_type: ContractStatusDefault(),reverse_record_cell_type: ContractStatusDefault(),reverse_record_root_cell_type: ContractStatusDefault(),eip712_lib: ContractStatusDefault() }
 }
     
 
-type ConfigCellSubAccountV1 struct {
+type ConfigCellSystemStatusV1 struct {
     inner []byte
 }
         
 
-func ConfigCellSubAccountV1FromSliceUnchecked(slice []byte) *ConfigCellSubAccountV1 {
-    return &ConfigCellSubAccountV1{inner: slice}
+func ConfigCellSystemStatusV1FromSliceUnchecked(slice []byte) *ConfigCellSystemStatusV1 {
+    return &ConfigCellSystemStatusV1{inner: slice}
 }
-func (s *ConfigCellSubAccountV1) AsSlice() []byte {
+func (s *ConfigCellSystemStatusV1) AsSlice() []byte {
     return s.inner
 }
             
 
-func ConfigCellSubAccountV1Default() ConfigCellSubAccountV1 {
-    return *ConfigCellSubAccountV1FromSliceUnchecked([]byte{ 112,0,0,0,40,0,0,0,48,0,0,0,56,0,0,0,64,0,0,0,72,0,0,0,80,0,0,0,88,0,0,0,96,0,0,0,104,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+func ConfigCellSystemStatusV1Default() ConfigCellSystemStatusV1 {
+    return *ConfigCellSystemStatusV1FromSliceUnchecked([]byte{ 21,1,0,0,56,0,0,0,73,0,0,0,90,0,0,0,107,0,0,0,124,0,0,0,141,0,0,0,158,0,0,0,175,0,0,0,192,0,0,0,209,0,0,0,226,0,0,0,243,0,0,0,4,1,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0,17,0,0,0,12,0,0,0,13,0,0,0,0,0,0,0,0 })
 }
             
 
-func ConfigCellSubAccountV1FromSlice(slice []byte, compatible bool) (*ConfigCellSubAccountV1, error) {
+func ConfigCellSystemStatusV1FromSlice(slice []byte, compatible bool) (*ConfigCellSystemStatusV1, error) {
     sliceLen := len(slice)
     if uint32(sliceLen) < HeaderSizeUint {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellSubAccountV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellSystemStatusV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
         return nil, errors.New(errMsg)
     }
 
     totalSize := unpackNumber(slice)
     if Number(sliceLen) != totalSize {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellSubAccountV1", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellSystemStatusV1", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if uint32(sliceLen) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellSubAccountV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ConfigCellSystemStatusV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     offsetFirst := unpackNumber(slice[HeaderSizeUint:])
     if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"OffsetsNotMatch", "ConfigCellSubAccountV1", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "ConfigCellSystemStatusV1", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if sliceLen < int(offsetFirst) {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellSubAccountV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ConfigCellSystemStatusV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
         return nil, errors.New(errMsg)
     }
 
     fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
-    if fieldCount < 9 {
+    if fieldCount < 13 {
         return nil, errors.New("FieldCountNotMatch")
-    } else if !compatible && fieldCount > 9 {
+    } else if !compatible && fieldCount > 13 {
         return nil, errors.New("FieldCountNotMatch")
     }
 
@@ -5466,68 +8051,92 @@ func ConfigCellSubAccountV1FromSlice(slice []byte, compatible bool) (*ConfigCell
 
     var err error
     
-_, err = Uint64FromSlice(slice[offsets[0]:offsets[1]], compatible)
+_, err = ContractStatusFromSlice(slice[offsets[0]:offsets[1]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = Uint64FromSlice(slice[offsets[1]:offsets[2]], compatible)
+_, err = ContractStatusFromSlice(slice[offsets[1]:offsets[2]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = Uint64FromSlice(slice[offsets[2]:offsets[3]], compatible)
+_, err = ContractStatusFromSlice(slice[offsets[2]:offsets[3]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = Uint64FromSlice(slice[offsets[3]:offsets[4]], compatible)
+_, err = ContractStatusFromSlice(slice[offsets[3]:offsets[4]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = Uint64FromSlice(slice[offsets[4]:offsets[5]], compatible)
+_, err = ContractStatusFromSlice(slice[offsets[4]:offsets[5]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = Uint64FromSlice(slice[offsets[5]:offsets[6]], compatible)
+_, err = ContractStatusFromSlice(slice[offsets[5]:offsets[6]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = Uint64FromSlice(slice[offsets[6]:offsets[7]], compatible)
+_, err = ContractStatusFromSlice(slice[offsets[6]:offsets[7]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = Uint64FromSlice(slice[offsets[7]:offsets[8]], compatible)
+_, err = ContractStatusFromSlice(slice[offsets[7]:offsets[8]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = Uint64FromSlice(slice[offsets[8]:offsets[9]], compatible)
+_, err = ContractStatusFromSlice(slice[offsets[8]:offsets[9]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-    return &ConfigCellSubAccountV1{inner: slice}, nil
+_, err = ContractStatusFromSlice(slice[offsets[9]:offsets[10]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = ContractStatusFromSlice(slice[offsets[10]:offsets[11]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = ContractStatusFromSlice(slice[offsets[11]:offsets[12]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = ContractStatusFromSlice(slice[offsets[12]:offsets[13]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+    return &ConfigCellSystemStatusV1{inner: slice}, nil
 }
             
 
-func (s *ConfigCellSubAccountV1) TotalSize() uint {
+func (s *ConfigCellSystemStatusV1) TotalSize() uint {
     return uint(unpackNumber(s.inner))
 }
-func (s *ConfigCellSubAccountV1) FieldCount() uint {
+func (s *ConfigCellSystemStatusV1) FieldCount() uint {
     var number uint = 0
     if uint32(s.TotalSize()) == HeaderSizeUint {
         return number
@@ -5535,92 +8144,120 @@ func (s *ConfigCellSubAccountV1) FieldCount() uint {
     number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
     return number
 }
-func (s *ConfigCellSubAccountV1) Len() uint {
+func (s *ConfigCellSystemStatusV1) Len() uint {
     return s.FieldCount()
 }
-func (s *ConfigCellSubAccountV1) IsEmpty() bool {
+func (s *ConfigCellSystemStatusV1) IsEmpty() bool {
     return s.Len() == 0
 }
-func (s *ConfigCellSubAccountV1) CountExtraFields() uint {
-    return s.FieldCount() - 9
+func (s *ConfigCellSystemStatusV1) CountExtraFields() uint {
+    return s.FieldCount() - 13
 }
 
-func (s *ConfigCellSubAccountV1) HasExtraFields() bool {
-    return 9 != s.FieldCount()
+func (s *ConfigCellSystemStatusV1) HasExtraFields() bool {
+    return 13 != s.FieldCount()
 }
             
 
-func (s *ConfigCellSubAccountV1) BasicCapacity() *Uint64 {
+func (s *ConfigCellSystemStatusV1) ApplyRegisterCellType() *ContractStatus {
     start := unpackNumber(s.inner[4:])
     end := unpackNumber(s.inner[8:])
-    return Uint64FromSliceUnchecked(s.inner[start:end])
+    return ContractStatusFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ConfigCellSubAccountV1) PreparedFeeCapacity() *Uint64 {
+func (s *ConfigCellSystemStatusV1) PreAccountCellType() *ContractStatus {
     start := unpackNumber(s.inner[8:])
     end := unpackNumber(s.inner[12:])
-    return Uint64FromSliceUnchecked(s.inner[start:end])
+    return ContractStatusFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ConfigCellSubAccountV1) NewSubAccountPrice() *Uint64 {
+func (s *ConfigCellSystemStatusV1) ProposalCellType() *ContractStatus {
     start := unpackNumber(s.inner[12:])
     end := unpackNumber(s.inner[16:])
-    return Uint64FromSliceUnchecked(s.inner[start:end])
+    return ContractStatusFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ConfigCellSubAccountV1) RenewSubAccountPrice() *Uint64 {
+func (s *ConfigCellSystemStatusV1) ConfigCellType() *ContractStatus {
     start := unpackNumber(s.inner[16:])
     end := unpackNumber(s.inner[20:])
-    return Uint64FromSliceUnchecked(s.inner[start:end])
+    return ContractStatusFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ConfigCellSubAccountV1) CommonFee() *Uint64 {
+func (s *ConfigCellSystemStatusV1) AccountCellType() *ContractStatus {
     start := unpackNumber(s.inner[20:])
     end := unpackNumber(s.inner[24:])
-    return Uint64FromSliceUnchecked(s.inner[start:end])
+    return ContractStatusFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ConfigCellSubAccountV1) CreateFee() *Uint64 {
+func (s *ConfigCellSystemStatusV1) AccountSaleCellType() *ContractStatus {
     start := unpackNumber(s.inner[24:])
     end := unpackNumber(s.inner[28:])
-    return Uint64FromSliceUnchecked(s.inner[start:end])
+    return ContractStatusFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ConfigCellSubAccountV1) EditFee() *Uint64 {
+func (s *ConfigCellSystemStatusV1) SubAccountCellType() *ContractStatus {
     start := unpackNumber(s.inner[28:])
     end := unpackNumber(s.inner[32:])
-    return Uint64FromSliceUnchecked(s.inner[start:end])
+    return ContractStatusFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ConfigCellSubAccountV1) RenewFee() *Uint64 {
+func (s *ConfigCellSystemStatusV1) OfferCellType() *ContractStatus {
     start := unpackNumber(s.inner[32:])
     end := unpackNumber(s.inner[36:])
-    return Uint64FromSliceUnchecked(s.inner[start:end])
+    return ContractStatusFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *ConfigCellSubAccountV1) RecycleFee() *Uint64 {
-    var ret *Uint64
+func (s *ConfigCellSystemStatusV1) BalanceCellType() *ContractStatus {
     start := unpackNumber(s.inner[36:])
+    end := unpackNumber(s.inner[40:])
+    return ContractStatusFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellSystemStatusV1) IncomeCellType() *ContractStatus {
+    start := unpackNumber(s.inner[40:])
+    end := unpackNumber(s.inner[44:])
+    return ContractStatusFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellSystemStatusV1) ReverseRecordCellType() *ContractStatus {
+    start := unpackNumber(s.inner[44:])
+    end := unpackNumber(s.inner[48:])
+    return ContractStatusFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellSystemStatusV1) ReverseRecordRootCellType() *ContractStatus {
+    start := unpackNumber(s.inner[48:])
+    end := unpackNumber(s.inner[52:])
+    return ContractStatusFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *ConfigCellSystemStatusV1) Eip712Lib() *ContractStatus {
+    var ret *ContractStatus
+    start := unpackNumber(s.inner[52:])
     if s.HasExtraFields() {
-        end := unpackNumber(s.inner[40:])
-        ret = Uint64FromSliceUnchecked(s.inner[start:end])
+        end := unpackNumber(s.inner[56:])
+        ret = ContractStatusFromSliceUnchecked(s.inner[start:end])
     } else {
-        ret = Uint64FromSliceUnchecked(s.inner[start:])
+        ret = ContractStatusFromSliceUnchecked(s.inner[start:])
     }
     return ret
 }
                         
 
-func (s *ConfigCellSubAccountV1) AsBuilder() ConfigCellSubAccountV1Builder {
-    ret := NewConfigCellSubAccountV1Builder().BasicCapacity(*s.BasicCapacity()).PreparedFeeCapacity(*s.PreparedFeeCapacity()).NewSubAccountPrice(*s.NewSubAccountPrice()).RenewSubAccountPrice(*s.RenewSubAccountPrice()).CommonFee(*s.CommonFee()).CreateFee(*s.CreateFee()).EditFee(*s.EditFee()).RenewFee(*s.RenewFee()).RecycleFee(*s.RecycleFee())
+func (s *ConfigCellSystemStatusV1) AsBuilder() ConfigCellSystemStatusV1Builder {
+    ret := NewConfigCellSystemStatusV1Builder().ApplyRegisterCellType(*s.ApplyRegisterCellType()).PreAccountCellType(*s.PreAccountCellType()).ProposalCellType(*s.ProposalCellType()).ConfigCellType(*s.ConfigCellType()).AccountCellType(*s.AccountCellType()).AccountSaleCellType(*s.AccountSaleCellType()).SubAccountCellType(*s.SubAccountCellType()).OfferCellType(*s.OfferCellType()).BalanceCellType(*s.BalanceCellType()).IncomeCellType(*s.IncomeCellType()).ReverseRecordCellType(*s.ReverseRecordCellType()).ReverseRecordRootCellType(*s.ReverseRecordRootCellType()).Eip712Lib(*s.Eip712Lib())
     return *ret
 }
         
@@ -35,6 +35,8 @@ export interface ScriptType {
 
 export type ScriptOptType = ScriptType | undefined;
 
+export type ScriptsType = ScriptType[];
+
 export interface OutPointType {
   tx_hash: HashType;
   index: Uint32Type;
@@ -137,6 +139,14 @@ export class ScriptOpt {
   hasValue(): boolean;
 }
 
+export function SerializeScripts(value: Array<ScriptType>): ArrayBuffer;
+export class Scripts {
+  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
+  validate(compatible?: boolean): void;
+  indexAt(i: number): Script;
+  length(): number;
+}
+
 export function SerializeOutPoint(value: OutPointType): ArrayBuffer;
 export class OutPoint {
   constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
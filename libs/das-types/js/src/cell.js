@@ -193,6 +193,7 @@ export class TypeIdTable {
     new Hash(this.view.buffer.slice(offsets[11], offsets[12]), { validate: false }).validate();
     new Hash(this.view.buffer.slice(offsets[12], offsets[13]), { validate: false }).validate();
     new Hash(this.view.buffer.slice(offsets[13], offsets[14]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[14], offsets[15]), { validate: false }).validate();
   }
 
   getAccountCell() {
@@ -289,6 +290,13 @@ export class TypeIdTable {
   getKeyListConfigCell() {
     const start = 56;
     const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getDpointCell() {
+    const start = 60;
+    const offset = this.view.getUint32(start, true);
     const offset_end = this.view.byteLength;
     return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
@@ -310,6 +318,7 @@ export function SerializeTypeIdTable(value) {
   buffers.push(SerializeHash(value.eip712_lib));
   buffers.push(SerializeHash(value.reverse_record_root_cell));
   buffers.push(SerializeHash(value.key_list_config_cell));
+  buffers.push(SerializeHash(value.dpoint_cell));
   return serializeTable(buffers);
 }
 
@@ -499,6 +508,9 @@ export class ConfigCellAccount {
     new Uint32(this.view.buffer.slice(offsets[11], offsets[12]), { validate: false }).validate();
     new Uint32(this.view.buffer.slice(offsets[12], offsets[13]), { validate: false }).validate();
     new Uint32(this.view.buffer.slice(offsets[13], offsets[14]), { validate: false }).validate();
+    new Uint32(this.view.buffer.slice(offsets[14], offsets[15]), { validate: false }).validate();
+    new Uint32(this.view.buffer.slice(offsets[15], offsets[16]), { validate: false }).validate();
+    new Uint32(this.view.buffer.slice(offsets[16], offsets[17]), { validate: false }).validate();
   }
 
   getMaxLength() {
@@ -595,6 +607,27 @@ export class ConfigCellAccount {
   getCommonThrottle() {
     const start = 56;
     const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getExpirationAuctionPeriod() {
+    const start = 60;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getExpirationDeliverPeriod() {
+    const start = 64;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getExpirationAuctionStartPremiums() {
+    const start = 68;
+    const offset = this.view.getUint32(start, true);
     const offset_end = this.view.byteLength;
     return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
@@ -616,6 +649,9 @@ export function SerializeConfigCellAccount(value) {
   buffers.push(SerializeUint32(value.edit_manager_throttle));
   buffers.push(SerializeUint32(value.edit_records_throttle));
   buffers.push(SerializeUint32(value.common_throttle));
+  buffers.push(SerializeUint32(value.expiration_auction_period));
+  buffers.push(SerializeUint32(value.expiration_deliver_period));
+  buffers.push(SerializeUint32(value.expiration_auction_start_premiums));
   return serializeTable(buffers);
 }
 
@@ -1456,6 +1492,8 @@ export class ConfigCellSystemStatus {
     new ContractStatus(this.view.buffer.slice(offsets[10], offsets[11]), { validate: false }).validate();
     new ContractStatus(this.view.buffer.slice(offsets[11], offsets[12]), { validate: false }).validate();
     new ContractStatus(this.view.buffer.slice(offsets[12], offsets[13]), { validate: false }).validate();
+    new ContractStatus(this.view.buffer.slice(offsets[13], offsets[14]), { validate: false }).validate();
+    new ContractStatus(this.view.buffer.slice(offsets[14], offsets[15]), { validate: false }).validate();
   }
 
   getApplyRegisterCellType() {
@@ -1545,6 +1583,20 @@ export class ConfigCellSystemStatus {
   getEip712Lib() {
     const start = 52;
     const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new ContractStatus(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getKeyListConfigCellType() {
+    const start = 56;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new ContractStatus(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getDpointCellType() {
+    const start = 60;
+    const offset = this.view.getUint32(start, true);
     const offset_end = this.view.byteLength;
     return new ContractStatus(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
@@ -1565,6 +1617,8 @@ export function SerializeConfigCellSystemStatus(value) {
   buffers.push(SerializeContractStatus(value.reverse_record_cell_type));
   buffers.push(SerializeContractStatus(value.reverse_record_root_cell_type));
   buffers.push(SerializeContractStatus(value.eip712_lib));
+  buffers.push(SerializeContractStatus(value.key_list_config_cell_type));
+  buffers.push(SerializeContractStatus(value.dpoint_cell_type));
   return serializeTable(buffers);
 }
 
@@ -1608,6 +1662,60 @@ export function SerializeContractStatus(value) {
   return serializeTable(buffers);
 }
 
+export class ConfigCellDPoint {
+  constructor(reader, { validate = true } = {}) {
+    this.view = new DataView(assertArrayBuffer(reader));
+    if (validate) {
+      this.validate();
+    }
+  }
+
+  validate(compatible = false) {
+    const offsets = verifyAndExtractOffsets(this.view, 0, true);
+    new Uint64(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
+    new Uint64(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
+    new Scripts(this.view.buffer.slice(offsets[2], offsets[3]), { validate: false }).validate();
+    new Scripts(this.view.buffer.slice(offsets[3], offsets[4]), { validate: false }).validate();
+  }
+
+  getBasicCapacity() {
+    const start = 4;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getPreparedFeeCapacity() {
+    const start = 8;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getTransferWhitelist() {
+    const start = 12;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Scripts(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getCapacityRecycleWhitelist() {
+    const start = 16;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.byteLength;
+    return new Scripts(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+}
+
+export function SerializeConfigCellDPoint(value) {
+  const buffers = [];
+  buffers.push(SerializeUint64(value.basic_capacity));
+  buffers.push(SerializeUint64(value.prepared_fee_capacity));
+  buffers.push(SerializeScripts(value.transfer_whitelist));
+  buffers.push(SerializeScripts(value.capacity_recycle_whitelist));
+  return serializeTable(buffers);
+}
+
 export class ProposalCellData {
   constructor(reader, { validate = true } = {}) {
     this.view = new DataView(assertArrayBuffer(reader));
@@ -1884,96 +1992,6 @@ export function SerializeIncomeRecord(value) {
   return serializeTable(buffers);
 }
 
-export class AccountCellDataV2 {
-  constructor(reader, { validate = true } = {}) {
-    this.view = new DataView(assertArrayBuffer(reader));
-    if (validate) {
-      this.validate();
-    }
-  }
-
-  validate(compatible = false) {
-    const offsets = verifyAndExtractOffsets(this.view, 0, true);
-    new AccountId(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
-    new AccountChars(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[2], offsets[3]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[3], offsets[4]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[4], offsets[5]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[5], offsets[6]), { validate: false }).validate();
-    new Uint8(this.view.buffer.slice(offsets[6], offsets[7]), { validate: false }).validate();
-    new Records(this.view.buffer.slice(offsets[7], offsets[8]), { validate: false }).validate();
-  }
-
-  getId() {
-    const start = 4;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new AccountId(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getAccount() {
-    const start = 8;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new AccountChars(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getRegisteredAt() {
-    const start = 12;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getLastTransferAccountAt() {
-    const start = 16;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getLastEditManagerAt() {
-    const start = 20;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getLastEditRecordsAt() {
-    const start = 24;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getStatus() {
-    const start = 28;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint8(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getRecords() {
-    const start = 32;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.byteLength;
-    return new Records(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-}
-
-export function SerializeAccountCellDataV2(value) {
-  const buffers = [];
-  buffers.push(SerializeAccountId(value.id));
-  buffers.push(SerializeAccountChars(value.account));
-  buffers.push(SerializeUint64(value.registered_at));
-  buffers.push(SerializeUint64(value.last_transfer_account_at));
-  buffers.push(SerializeUint64(value.last_edit_manager_at));
-  buffers.push(SerializeUint64(value.last_edit_records_at));
-  buffers.push(SerializeUint8(value.status));
-  buffers.push(SerializeRecords(value.records));
-  return serializeTable(buffers);
-}
-
 export class AccountCellData {
   constructor(reader, { validate = true } = {}) {
     this.view = new DataView(assertArrayBuffer(reader));
@@ -1994,6 +2012,7 @@ export class AccountCellData {
     new Records(this.view.buffer.slice(offsets[7], offsets[8]), { validate: false }).validate();
     new Uint8(this.view.buffer.slice(offsets[8], offsets[9]), { validate: false }).validate();
     new Uint64(this.view.buffer.slice(offsets[9], offsets[10]), { validate: false }).validate();
+    new AccountApproval(this.view.buffer.slice(offsets[10], offsets[11]), { validate: false }).validate();
   }
 
   getId() {
@@ -2062,9 +2081,16 @@ export class AccountCellData {
   getRenewSubAccountPrice() {
     const start = 40;
     const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.byteLength;
+    const offset_end = this.view.getUint32(start + 4, true);
     return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
+
+  getApproval() {
+    const start = 44;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.byteLength;
+    return new AccountApproval(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
 }
 
 export function SerializeAccountCellData(value) {
@@ -2079,6 +2105,7 @@ export function SerializeAccountCellData(value) {
   buffers.push(SerializeRecords(value.records));
   buffers.push(SerializeUint8(value.enable_sub_account));
   buffers.push(SerializeUint64(value.renew_sub_account_price));
+  buffers.push(SerializeAccountApproval(value.approval));
   return serializeTable(buffers);
 }
 
@@ -2214,7 +2241,7 @@ export function SerializeRecords(value) {
   return serializeTable(value.map(item => SerializeRecord(item)));
 }
 
-export class AccountSaleCellDataV1 {
+export class AccountApproval {
   constructor(reader, { validate = true } = {}) {
     this.view = new DataView(assertArrayBuffer(reader));
     if (validate) {
@@ -2224,60 +2251,96 @@ export class AccountSaleCellDataV1 {
 
   validate(compatible = false) {
     const offsets = verifyAndExtractOffsets(this.view, 0, true);
-    new AccountId(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
+    new Bytes(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
     new Bytes(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
+  }
+
+  getAction() {
+    const start = 4;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getParams() {
+    const start = 8;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.byteLength;
+    return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+}
+
+export function SerializeAccountApproval(value) {
+  const buffers = [];
+  buffers.push(SerializeBytes(value.action));
+  buffers.push(SerializeBytes(value.params));
+  return serializeTable(buffers);
+}
+
+export class AccountApprovalTransfer {
+  constructor(reader, { validate = true } = {}) {
+    this.view = new DataView(assertArrayBuffer(reader));
+    if (validate) {
+      this.validate();
+    }
+  }
+
+  validate(compatible = false) {
+    const offsets = verifyAndExtractOffsets(this.view, 0, true);
+    new Script(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
+    new Uint64(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
     new Uint64(this.view.buffer.slice(offsets[2], offsets[3]), { validate: false }).validate();
-    new Bytes(this.view.buffer.slice(offsets[3], offsets[4]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[4], offsets[5]), { validate: false }).validate();
+    new Uint8(this.view.buffer.slice(offsets[3], offsets[4]), { validate: false }).validate();
+    new Script(this.view.buffer.slice(offsets[4], offsets[5]), { validate: false }).validate();
   }
 
-  getAccountId() {
+  getPlatformLock() {
     const start = 4;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new AccountId(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Script(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getAccount() {
+  getProtectedUntil() {
     const start = 8;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getPrice() {
+  getSealedUntil() {
     const start = 12;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
     return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getDescription() {
+  getDelayCountRemain() {
     const start = 16;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint8(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getStartedAt() {
+  getToLock() {
     const start = 20;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.byteLength;
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Script(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 }
 
-export function SerializeAccountSaleCellDataV1(value) {
+export function SerializeAccountApprovalTransfer(value) {
   const buffers = [];
-  buffers.push(SerializeAccountId(value.account_id));
-  buffers.push(SerializeBytes(value.account));
-  buffers.push(SerializeUint64(value.price));
-  buffers.push(SerializeBytes(value.description));
-  buffers.push(SerializeUint64(value.started_at));
+  buffers.push(SerializeScript(value.platform_lock));
+  buffers.push(SerializeUint64(value.protected_until));
+  buffers.push(SerializeUint64(value.sealed_until));
+  buffers.push(SerializeUint8(value.delay_count_remain));
+  buffers.push(SerializeScript(value.to_lock));
   return serializeTable(buffers);
 }
 
-export class AccountSaleCellData {
+export class AccountSaleCellDataV1 {
   constructor(reader, { validate = true } = {}) {
     this.view = new DataView(assertArrayBuffer(reader));
     if (validate) {
@@ -2292,7 +2355,6 @@ export class AccountSaleCellData {
     new Uint64(this.view.buffer.slice(offsets[2], offsets[3]), { validate: false }).validate();
     new Bytes(this.view.buffer.slice(offsets[3], offsets[4]), { validate: false }).validate();
     new Uint64(this.view.buffer.slice(offsets[4], offsets[5]), { validate: false }).validate();
-    new Uint32(this.view.buffer.slice(offsets[5], offsets[6]), { validate: false }).validate();
   }
 
   getAccountId() {
@@ -2326,30 +2388,22 @@ export class AccountSaleCellData {
   getStartedAt() {
     const start = 20;
     const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getBuyerInviterProfitRate() {
-    const start = 24;
-    const offset = this.view.getUint32(start, true);
     const offset_end = this.view.byteLength;
-    return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 }
 
-export function SerializeAccountSaleCellData(value) {
+export function SerializeAccountSaleCellDataV1(value) {
   const buffers = [];
   buffers.push(SerializeAccountId(value.account_id));
   buffers.push(SerializeBytes(value.account));
   buffers.push(SerializeUint64(value.price));
   buffers.push(SerializeBytes(value.description));
   buffers.push(SerializeUint64(value.started_at));
-  buffers.push(SerializeUint32(value.buyer_inviter_profit_rate));
   return serializeTable(buffers);
 }
 
-export class AccountAuctionCellData {
+export class AccountSaleCellData {
   constructor(reader, { validate = true } = {}) {
     this.view = new DataView(assertArrayBuffer(reader));
     if (validate) {
@@ -2361,14 +2415,10 @@ export class AccountAuctionCellData {
     const offsets = verifyAndExtractOffsets(this.view, 0, true);
     new AccountId(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
     new Bytes(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
-    new Bytes(this.view.buffer.slice(offsets[2], offsets[3]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[3], offsets[4]), { validate: false }).validate();
-    new Uint32(this.view.buffer.slice(offsets[4], offsets[5]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[5], offsets[6]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[6], offsets[7]), { validate: false }).validate();
-    new Script(this.view.buffer.slice(offsets[7], offsets[8]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[8], offsets[9]), { validate: false }).validate();
-    new Uint32(this.view.buffer.slice(offsets[9], offsets[10]), { validate: false }).validate();
+    new Uint64(this.view.buffer.slice(offsets[2], offsets[3]), { validate: false }).validate();
+    new Bytes(this.view.buffer.slice(offsets[3], offsets[4]), { validate: false }).validate();
+    new Uint64(this.view.buffer.slice(offsets[4], offsets[5]), { validate: false }).validate();
+    new Uint32(this.view.buffer.slice(offsets[5], offsets[6]), { validate: false }).validate();
   }
 
   getAccountId() {
@@ -2385,79 +2435,47 @@ export class AccountAuctionCellData {
     return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getDescription() {
+  getPrice() {
     const start = 12;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getOpeningPrice() {
-    const start = 16;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
     return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getIncrementRateEachBid() {
-    const start = 20;
+  getDescription() {
+    const start = 16;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
   getStartedAt() {
-    const start = 24;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getEndedAt() {
-    const start = 28;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getCurrentBidderLock() {
-    const start = 32;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Script(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getCurrentBidPrice() {
-    const start = 36;
+    const start = 20;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
     return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getPrevBidderProfitRate() {
-    const start = 40;
+  getBuyerInviterProfitRate() {
+    const start = 24;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.byteLength;
     return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 }
 
-export function SerializeAccountAuctionCellData(value) {
+export function SerializeAccountSaleCellData(value) {
   const buffers = [];
   buffers.push(SerializeAccountId(value.account_id));
   buffers.push(SerializeBytes(value.account));
+  buffers.push(SerializeUint64(value.price));
   buffers.push(SerializeBytes(value.description));
-  buffers.push(SerializeUint64(value.opening_price));
-  buffers.push(SerializeUint32(value.increment_rate_each_bid));
   buffers.push(SerializeUint64(value.started_at));
-  buffers.push(SerializeUint64(value.ended_at));
-  buffers.push(SerializeScript(value.current_bidder_lock));
-  buffers.push(SerializeUint64(value.current_bid_price));
-  buffers.push(SerializeUint32(value.prev_bidder_profit_rate));
+  buffers.push(SerializeUint32(value.buyer_inviter_profit_rate));
   return serializeTable(buffers);
 }
 
-export class PreAccountCellDataV1 {
+export class AccountAuctionCellData {
   constructor(reader, { validate = true } = {}) {
     this.view = new DataView(assertArrayBuffer(reader));
     if (validate) {
@@ -2467,218 +2485,101 @@ export class PreAccountCellDataV1 {
 
   validate(compatible = false) {
     const offsets = verifyAndExtractOffsets(this.view, 0, true);
-    new AccountChars(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
-    new Script(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
+    new AccountId(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
+    new Bytes(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
     new Bytes(this.view.buffer.slice(offsets[2], offsets[3]), { validate: false }).validate();
-    new Bytes(this.view.buffer.slice(offsets[3], offsets[4]), { validate: false }).validate();
-    new ScriptOpt(this.view.buffer.slice(offsets[4], offsets[5]), { validate: false }).validate();
-    new ScriptOpt(this.view.buffer.slice(offsets[5], offsets[6]), { validate: false }).validate();
-    new PriceConfig(this.view.buffer.slice(offsets[6], offsets[7]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[7], offsets[8]), { validate: false }).validate();
-    new Uint32(this.view.buffer.slice(offsets[8], offsets[9]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[9], offsets[10]), { validate: false }).validate();
+    new Uint64(this.view.buffer.slice(offsets[3], offsets[4]), { validate: false }).validate();
+    new Uint32(this.view.buffer.slice(offsets[4], offsets[5]), { validate: false }).validate();
+    new Uint64(this.view.buffer.slice(offsets[5], offsets[6]), { validate: false }).validate();
+    new Uint64(this.view.buffer.slice(offsets[6], offsets[7]), { validate: false }).validate();
+    new Script(this.view.buffer.slice(offsets[7], offsets[8]), { validate: false }).validate();
+    new Uint64(this.view.buffer.slice(offsets[8], offsets[9]), { validate: false }).validate();
+    new Uint32(this.view.buffer.slice(offsets[9], offsets[10]), { validate: false }).validate();
   }
 
-  getAccount() {
+  getAccountId() {
     const start = 4;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new AccountChars(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getRefundLock() {
-    const start = 8;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Script(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getOwnerLockArgs() {
-    const start = 12;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getInviterId() {
-    const start = 16;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getInviterLock() {
-    const start = 20;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new ScriptOpt(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getChannelLock() {
-    const start = 24;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new ScriptOpt(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getPrice() {
-    const start = 28;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new PriceConfig(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getQuote() {
-    const start = 32;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getInvitedDiscount() {
-    const start = 36;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getCreatedAt() {
-    const start = 40;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.byteLength;
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-}
-
-export function SerializePreAccountCellDataV1(value) {
-  const buffers = [];
-  buffers.push(SerializeAccountChars(value.account));
-  buffers.push(SerializeScript(value.refund_lock));
-  buffers.push(SerializeBytes(value.owner_lock_args));
-  buffers.push(SerializeBytes(value.inviter_id));
-  buffers.push(SerializeScriptOpt(value.inviter_lock));
-  buffers.push(SerializeScriptOpt(value.channel_lock));
-  buffers.push(SerializePriceConfig(value.price));
-  buffers.push(SerializeUint64(value.quote));
-  buffers.push(SerializeUint32(value.invited_discount));
-  buffers.push(SerializeUint64(value.created_at));
-  return serializeTable(buffers);
-}
-
-export class PreAccountCellDataV2 {
-  constructor(reader, { validate = true } = {}) {
-    this.view = new DataView(assertArrayBuffer(reader));
-    if (validate) {
-      this.validate();
-    }
-  }
-
-  validate(compatible = false) {
-    const offsets = verifyAndExtractOffsets(this.view, 0, true);
-    new AccountChars(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
-    new Script(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
-    new Bytes(this.view.buffer.slice(offsets[2], offsets[3]), { validate: false }).validate();
-    new Bytes(this.view.buffer.slice(offsets[3], offsets[4]), { validate: false }).validate();
-    new ScriptOpt(this.view.buffer.slice(offsets[4], offsets[5]), { validate: false }).validate();
-    new ScriptOpt(this.view.buffer.slice(offsets[5], offsets[6]), { validate: false }).validate();
-    new PriceConfig(this.view.buffer.slice(offsets[6], offsets[7]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[7], offsets[8]), { validate: false }).validate();
-    new Uint32(this.view.buffer.slice(offsets[8], offsets[9]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[9], offsets[10]), { validate: false }).validate();
-    new Records(this.view.buffer.slice(offsets[10], offsets[11]), { validate: false }).validate();
+    return new AccountId(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
   getAccount() {
-    const start = 4;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new AccountChars(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getRefundLock() {
     const start = 8;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Script(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getOwnerLockArgs() {
+  getDescription() {
     const start = 12;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
     return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getInviterId() {
+  getOpeningPrice() {
     const start = 16;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getInviterLock() {
+  getIncrementRateEachBid() {
     const start = 20;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new ScriptOpt(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getChannelLock() {
+  getStartedAt() {
     const start = 24;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new ScriptOpt(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getPrice() {
+  getEndedAt() {
     const start = 28;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new PriceConfig(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getQuote() {
+  getCurrentBidderLock() {
     const start = 32;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Script(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getInvitedDiscount() {
+  getCurrentBidPrice() {
     const start = 36;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getCreatedAt() {
-    const start = 40;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
     return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getInitialRecords() {
-    const start = 44;
+  getPrevBidderProfitRate() {
+    const start = 40;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.byteLength;
-    return new Records(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 }
 
-export function SerializePreAccountCellDataV2(value) {
+export function SerializeAccountAuctionCellData(value) {
   const buffers = [];
-  buffers.push(SerializeAccountChars(value.account));
-  buffers.push(SerializeScript(value.refund_lock));
-  buffers.push(SerializeBytes(value.owner_lock_args));
-  buffers.push(SerializeBytes(value.inviter_id));
-  buffers.push(SerializeScriptOpt(value.inviter_lock));
-  buffers.push(SerializeScriptOpt(value.channel_lock));
-  buffers.push(SerializePriceConfig(value.price));
-  buffers.push(SerializeUint64(value.quote));
-  buffers.push(SerializeUint32(value.invited_discount));
-  buffers.push(SerializeUint64(value.created_at));
-  buffers.push(SerializeRecords(value.initial_records));
+  buffers.push(SerializeAccountId(value.account_id));
+  buffers.push(SerializeBytes(value.account));
+  buffers.push(SerializeBytes(value.description));
+  buffers.push(SerializeUint64(value.opening_price));
+  buffers.push(SerializeUint32(value.increment_rate_each_bid));
+  buffers.push(SerializeUint64(value.started_at));
+  buffers.push(SerializeUint64(value.ended_at));
+  buffers.push(SerializeScript(value.current_bidder_lock));
+  buffers.push(SerializeUint64(value.current_bid_price));
+  buffers.push(SerializeUint32(value.prev_bidder_profit_rate));
   return serializeTable(buffers);
 }
 
@@ -3011,6 +2912,7 @@ export class SubAccount {
     new Uint64(this.view.buffer.slice(offsets[8], offsets[9]), { validate: false }).validate();
     new Uint8(this.view.buffer.slice(offsets[9], offsets[10]), { validate: false }).validate();
     new Uint64(this.view.buffer.slice(offsets[10], offsets[11]), { validate: false }).validate();
+    new AccountApproval(this.view.buffer.slice(offsets[11], offsets[12]), { validate: false }).validate();
   }
 
   getLock() {
@@ -3086,9 +2988,16 @@ export class SubAccount {
   getRenewSubAccountPrice() {
     const start = 44;
     const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.byteLength;
+    const offset_end = this.view.getUint32(start + 4, true);
     return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
+
+  getApproval() {
+    const start = 48;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.byteLength;
+    return new AccountApproval(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
 }
 
 export function SerializeSubAccount(value) {
@@ -3104,6 +3013,7 @@ export function SerializeSubAccount(value) {
   buffers.push(SerializeUint64(value.nonce));
   buffers.push(SerializeUint8(value.enable_sub_account));
   buffers.push(SerializeUint64(value.renew_sub_account_price));
+  buffers.push(SerializeAccountApproval(value.approval));
   return serializeTable(buffers);
 }
 
@@ -3567,6 +3477,143 @@ export function SerializeDeviceKeyListCellData(value) {
   return serializeTable(buffers);
 }
 
+export class MapEntry {
+  constructor(reader, { validate = true } = {}) {
+    this.view = new DataView(assertArrayBuffer(reader));
+    if (validate) {
+      this.validate();
+    }
+  }
+
+  validate(compatible = false) {
+    const offsets = verifyAndExtractOffsets(this.view, 0, true);
+    new Bytes(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
+    new Bytes(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
+  }
+
+  getKey() {
+    const start = 4;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getValue() {
+    const start = 8;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.byteLength;
+    return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+}
+
+export function SerializeMapEntry(value) {
+  const buffers = [];
+  buffers.push(SerializeBytes(value.key));
+  buffers.push(SerializeBytes(value.value));
+  return serializeTable(buffers);
+}
+
+export class ConfigList {
+  constructor(reader, { validate = true } = {}) {
+    this.view = new DataView(assertArrayBuffer(reader));
+    if (validate) {
+      this.validate();
+    }
+  }
+
+  validate(compatible = false) {
+    const offsets = verifyAndExtractOffsets(this.view, 0, true);
+    for (let i = 0; i < offsets.length - 1; i++) {
+      new MapEntry(this.view.buffer.slice(offsets[i], offsets[i + 1]), { validate: false }).validate();
+    }
+  }
+
+  length() {
+    if (this.view.byteLength < 8) {
+      return 0;
+    } else {
+      return this.view.getUint32(4, true) / 4 - 1;
+    }
+  }
+
+  indexAt(i) {
+    const start = 4 + i * 4;
+    const offset = this.view.getUint32(start, true);
+    let offset_end = this.view.byteLength;
+    if (i + 1 < this.length()) {
+      offset_end = this.view.getUint32(start + 4, true);
+    }
+    return new MapEntry(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+}
+
+export function SerializeConfigList(value) {
+  return serializeTable(value.map(item => SerializeMapEntry(item)));
+}
+
+export class Config {
+  constructor(reader, { validate = true } = {}) {
+    this.view = new DataView(assertArrayBuffer(reader));
+    if (validate) {
+      this.validate();
+    }
+  }
+
+  validate(compatible = false) {
+    const offsets = verifyAndExtractOffsets(this.view, 0, true);
+    new Uint8(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
+    new ConfigList(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
+  }
+
+  getStatus() {
+    const start = 4;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Uint8(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getConfigs() {
+    const start = 8;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.byteLength;
+    return new ConfigList(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+}
+
+export function SerializeConfig(value) {
+  const buffers = [];
+  buffers.push(SerializeUint8(value.status));
+  buffers.push(SerializeConfigList(value.configs));
+  return serializeTable(buffers);
+}
+
+export class OrderInfo {
+  constructor(reader, { validate = true } = {}) {
+    this.view = new DataView(assertArrayBuffer(reader));
+    if (validate) {
+      this.validate();
+    }
+  }
+
+  validate(compatible = false) {
+    const offsets = verifyAndExtractOffsets(this.view, 0, true);
+    new Bytes(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
+  }
+
+  getMemo() {
+    const start = 4;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.byteLength;
+    return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+}
+
+export function SerializeOrderInfo(value) {
+  const buffers = [];
+  buffers.push(SerializeBytes(value.memo));
+  return serializeTable(buffers);
+}
+
 export class Uint8 {
   constructor(reader, { validate = true } = {}) {
     this.view = new DataView(assertArrayBuffer(reader));
@@ -3893,6 +3940,44 @@ export function SerializeScriptOpt(value) {
   }
 }
 
+export class Scripts {
+  constructor(reader, { validate = true } = {}) {
+    this.view = new DataView(assertArrayBuffer(reader));
+    if (validate) {
+      this.validate();
+    }
+  }
+
+  validate(compatible = false) {
+    const offsets = verifyAndExtractOffsets(this.view, 0, true);
+    for (let i = 0; i < offsets.length - 1; i++) {
+      new Script(this.view.buffer.slice(offsets[i], offsets[i + 1]), { validate: false }).validate();
+    }
+  }
+
+  length() {
+    if (this.view.byteLength < 8) {
+      return 0;
+    } else {
+      return this.view.getUint32(4, true) / 4 - 1;
+    }
+  }
+
+  indexAt(i) {
+    const start = 4 + i * 4;
+    const offset = this.view.getUint32(start, true);
+    let offset_end = this.view.byteLength;
+    if (i + 1 < this.length()) {
+      offset_end = this.view.getUint32(start + 4, true);
+    }
+    return new Script(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+}
+
+export function SerializeScripts(value) {
+  return serializeTable(value.map(item => SerializeScript(item)));
+}
+
 export class OutPoint {
   constructor(reader, { validate = true } = {}) {
     this.view = new DataView(assertArrayBuffer(reader));
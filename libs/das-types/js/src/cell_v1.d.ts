@@ -22,6 +22,33 @@ export interface AccountCellDataV1Type {
   records: RecordsType;
 }
 
+export interface PreAccountCellDataV1Type {
+  account: AccountCharsType;
+  refund_lock: ScriptType;
+  owner_lock_args: BytesType;
+  inviter_id: BytesType;
+  inviter_lock?: ScriptType;
+  channel_lock?: ScriptType;
+  price: PriceConfigType;
+  quote: Uint64Type;
+  invited_discount: Uint32Type;
+  created_at: Uint64Type;
+}
+
+export interface SubAccountV1Type {
+  lock: ScriptType;
+  id: AccountIdType;
+  account: AccountCharsType;
+  suffix: BytesType;
+  registered_at: Uint64Type;
+  expired_at: Uint64Type;
+  status: Uint8Type;
+  records: RecordsType;
+  nonce: Uint64Type;
+  enable_sub_account: Uint8Type;
+  renew_sub_account_price: Uint64Type;
+}
+
 export type Uint8Type = CanCastToArrayBuffer;
 
 export type Uint32Type = CanCastToArrayBuffer;
@@ -44,6 +71,8 @@ export interface ScriptType {
 
 export type ScriptOptType = ScriptType | undefined;
 
+export type ScriptsType = ScriptType[];
+
 export interface OutPointType {
   tx_hash: HashType;
   index: Uint32Type;
@@ -90,6 +119,7 @@ export interface TypeIdTableType {
   eip712_lib: HashType;
   reverse_record_root_cell: HashType;
   key_list_config_cell: HashType;
+  dpoint_cell: HashType;
 }
 
 export interface DasLockOutPointTableType {
@@ -127,6 +157,9 @@ export interface ConfigCellAccountType {
   edit_manager_throttle: Uint32Type;
   edit_records_throttle: Uint32Type;
   common_throttle: Uint32Type;
+  expiration_auction_period: Uint32Type;
+  expiration_deliver_period: Uint32Type;
+  expiration_auction_start_premiums: Uint32Type;
 }
 
 export interface ConfigCellApplyType {
@@ -240,6 +273,8 @@ export interface ConfigCellSystemStatusType {
   reverse_record_cell_type: ContractStatusType;
   reverse_record_root_cell_type: ContractStatusType;
   eip712_lib: ContractStatusType;
+  key_list_config_cell_type: ContractStatusType;
+  dpoint_cell_type: ContractStatusType;
 }
 
 export interface ContractStatusType {
@@ -247,6 +282,13 @@ export interface ContractStatusType {
   version: BytesType;
 }
 
+export interface ConfigCellDPointType {
+  basic_capacity: Uint64Type;
+  prepared_fee_capacity: Uint64Type;
+  transfer_whitelist: ScriptsType;
+  capacity_recycle_whitelist: ScriptsType;
+}
+
 export interface ProposalCellDataType {
   proposer_lock: ScriptType;
   created_at_height: Uint64Type;
@@ -275,17 +317,6 @@ export interface IncomeRecordType {
   capacity: Uint64Type;
 }
 
-export interface AccountCellDataV2Type {
-  id: AccountIdType;
-  account: AccountCharsType;
-  registered_at: Uint64Type;
-  last_transfer_account_at: Uint64Type;
-  last_edit_manager_at: Uint64Type;
-  last_edit_records_at: Uint64Type;
-  status: Uint8Type;
-  records: RecordsType;
-}
-
 export interface AccountCellDataType {
   id: AccountIdType;
   account: AccountCharsType;
@@ -297,6 +328,7 @@ export interface AccountCellDataType {
   records: RecordsType;
   enable_sub_account: Uint8Type;
   renew_sub_account_price: Uint64Type;
+  approval: AccountApprovalType;
 }
 
 export type AccountIdType = CanCastToArrayBuffer;
@@ -311,6 +343,19 @@ export interface RecordType {
 
 export type RecordsType = RecordType[];
 
+export interface AccountApprovalType {
+  action: BytesType;
+  params: BytesType;
+}
+
+export interface AccountApprovalTransferType {
+  platform_lock: ScriptType;
+  protected_until: Uint64Type;
+  sealed_until: Uint64Type;
+  delay_count_remain: Uint8Type;
+  to_lock: ScriptType;
+}
+
 export interface AccountSaleCellDataV1Type {
   account_id: AccountIdType;
   account: BytesType;
@@ -341,33 +386,6 @@ export interface AccountAuctionCellDataType {
   prev_bidder_profit_rate: Uint32Type;
 }
 
-export interface PreAccountCellDataV1Type {
-  account: AccountCharsType;
-  refund_lock: ScriptType;
-  owner_lock_args: BytesType;
-  inviter_id: BytesType;
-  inviter_lock?: ScriptType;
-  channel_lock?: ScriptType;
-  price: PriceConfigType;
-  quote: Uint64Type;
-  invited_discount: Uint32Type;
-  created_at: Uint64Type;
-}
-
-export interface PreAccountCellDataV2Type {
-  account: AccountCharsType;
-  refund_lock: ScriptType;
-  owner_lock_args: BytesType;
-  inviter_id: BytesType;
-  inviter_lock?: ScriptType;
-  channel_lock?: ScriptType;
-  price: PriceConfigType;
-  quote: Uint64Type;
-  invited_discount: Uint32Type;
-  created_at: Uint64Type;
-  initial_records: RecordsType;
-}
-
 export interface PreAccountCellDataType {
   account: AccountCharsType;
   refund_lock: ScriptType;
@@ -416,6 +434,7 @@ export interface SubAccountType {
   nonce: Uint64Type;
   enable_sub_account: Uint8Type;
   renew_sub_account_price: Uint64Type;
+  approval: AccountApprovalType;
 }
 
 export interface SubAccountRuleType {
@@ -469,6 +488,22 @@ export interface DeviceKeyListCellDataType {
   refund_lock: ScriptType;
 }
 
+export interface MapEntryType {
+  key: BytesType;
+  value: BytesType;
+}
+
+export type ConfigListType = MapEntryType[];
+
+export interface ConfigType {
+  status: Uint8Type;
+  configs: ConfigListType;
+}
+
+export interface OrderInfoType {
+  memo: BytesType;
+}
+
 export function SerializeAccountCellDataV1(value: AccountCellDataV1Type): ArrayBuffer;
 export class AccountCellDataV1 {
   constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
@@ -481,6 +516,39 @@ export class AccountCellDataV1 {
   getRecords(): Records;
 }
 
+export function SerializePreAccountCellDataV1(value: PreAccountCellDataV1Type): ArrayBuffer;
+export class PreAccountCellDataV1 {
+  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
+  validate(compatible?: boolean): void;
+  getAccount(): AccountChars;
+  getRefundLock(): Script;
+  getOwnerLockArgs(): Bytes;
+  getInviterId(): Bytes;
+  getInviterLock(): ScriptOpt;
+  getChannelLock(): ScriptOpt;
+  getPrice(): PriceConfig;
+  getQuote(): Uint64;
+  getInvitedDiscount(): Uint32;
+  getCreatedAt(): Uint64;
+}
+
+export function SerializeSubAccountV1(value: SubAccountV1Type): ArrayBuffer;
+export class SubAccountV1 {
+  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
+  validate(compatible?: boolean): void;
+  getLock(): Script;
+  getId(): AccountId;
+  getAccount(): AccountChars;
+  getSuffix(): Bytes;
+  getRegisteredAt(): Uint64;
+  getExpiredAt(): Uint64;
+  getStatus(): Uint8;
+  getRecords(): Records;
+  getNonce(): Uint64;
+  getEnableSubAccount(): Uint8;
+  getRenewSubAccountPrice(): Uint64;
+}
+
 export function SerializeUint8(value: CanCastToArrayBuffer): ArrayBuffer;
 export class Uint8 {
   constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
@@ -564,6 +632,14 @@ export class ScriptOpt {
   hasValue(): boolean;
 }
 
+export function SerializeScripts(value: Array<ScriptType>): ArrayBuffer;
+export class Scripts {
+  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
+  validate(compatible?: boolean): void;
+  indexAt(i: number): Script;
+  length(): number;
+}
+
 export function SerializeOutPoint(value: OutPointType): ArrayBuffer;
 export class OutPoint {
   constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
@@ -635,6 +711,7 @@ export class TypeIdTable {
   getEip712Lib(): Hash;
   getReverseRecordRootCell(): Hash;
   getKeyListConfigCell(): Hash;
+  getDpointCell(): Hash;
 }
 
 export function SerializeDasLockOutPointTable(value: DasLockOutPointTableType): ArrayBuffer;
@@ -681,6 +758,9 @@ export class ConfigCellAccount {
   getEditManagerThrottle(): Uint32;
   getEditRecordsThrottle(): Uint32;
   getCommonThrottle(): Uint32;
+  getExpirationAuctionPeriod(): Uint32;
+  getExpirationDeliverPeriod(): Uint32;
+  getExpirationAuctionStartPremiums(): Uint32;
 }
 
 export function SerializeConfigCellApply(value: ConfigCellApplyType): ArrayBuffer;
@@ -842,6 +922,8 @@ export class ConfigCellSystemStatus {
   getReverseRecordCellType(): ContractStatus;
   getReverseRecordRootCellType(): ContractStatus;
   getEip712Lib(): ContractStatus;
+  getKeyListConfigCellType(): ContractStatus;
+  getDpointCellType(): ContractStatus;
 }
 
 export function SerializeContractStatus(value: ContractStatusType): ArrayBuffer;
@@ -852,6 +934,16 @@ export class ContractStatus {
   getVersion(): Bytes;
 }
 
+export function SerializeConfigCellDPoint(value: ConfigCellDPointType): ArrayBuffer;
+export class ConfigCellDPoint {
+  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
+  validate(compatible?: boolean): void;
+  getBasicCapacity(): Uint64;
+  getPreparedFeeCapacity(): Uint64;
+  getTransferWhitelist(): Scripts;
+  getCapacityRecycleWhitelist(): Scripts;
+}
+
 export function SerializeProposalCellData(value: ProposalCellDataType): ArrayBuffer;
 export class ProposalCellData {
   constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
@@ -910,20 +1002,6 @@ export class IncomeRecord {
   getCapacity(): Uint64;
 }
 
-export function SerializeAccountCellDataV2(value: AccountCellDataV2Type): ArrayBuffer;
-export class AccountCellDataV2 {
-  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
-  validate(compatible?: boolean): void;
-  getId(): AccountId;
-  getAccount(): AccountChars;
-  getRegisteredAt(): Uint64;
-  getLastTransferAccountAt(): Uint64;
-  getLastEditManagerAt(): Uint64;
-  getLastEditRecordsAt(): Uint64;
-  getStatus(): Uint8;
-  getRecords(): Records;
-}
-
 export function SerializeAccountCellData(value: AccountCellDataType): ArrayBuffer;
 export class AccountCellData {
   constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
@@ -938,6 +1016,7 @@ export class AccountCellData {
   getRecords(): Records;
   getEnableSubAccount(): Uint8;
   getRenewSubAccountPrice(): Uint64;
+  getApproval(): AccountApproval;
 }
 
 export function SerializeAccountId(value: CanCastToArrayBuffer): ArrayBuffer;
@@ -968,6 +1047,25 @@ export class Records {
   length(): number;
 }
 
+export function SerializeAccountApproval(value: AccountApprovalType): ArrayBuffer;
+export class AccountApproval {
+  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
+  validate(compatible?: boolean): void;
+  getAction(): Bytes;
+  getParams(): Bytes;
+}
+
+export function SerializeAccountApprovalTransfer(value: AccountApprovalTransferType): ArrayBuffer;
+export class AccountApprovalTransfer {
+  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
+  validate(compatible?: boolean): void;
+  getPlatformLock(): Script;
+  getProtectedUntil(): Uint64;
+  getSealedUntil(): Uint64;
+  getDelayCountRemain(): Uint8;
+  getToLock(): Script;
+}
+
 export function SerializeAccountSaleCellDataV1(value: AccountSaleCellDataV1Type): ArrayBuffer;
 export class AccountSaleCellDataV1 {
   constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
@@ -1007,39 +1105,6 @@ export class AccountAuctionCellData {
   getPrevBidderProfitRate(): Uint32;
 }
 
-export function SerializePreAccountCellDataV1(value: PreAccountCellDataV1Type): ArrayBuffer;
-export class PreAccountCellDataV1 {
-  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
-  validate(compatible?: boolean): void;
-  getAccount(): AccountChars;
-  getRefundLock(): Script;
-  getOwnerLockArgs(): Bytes;
-  getInviterId(): Bytes;
-  getInviterLock(): ScriptOpt;
-  getChannelLock(): ScriptOpt;
-  getPrice(): PriceConfig;
-  getQuote(): Uint64;
-  getInvitedDiscount(): Uint32;
-  getCreatedAt(): Uint64;
-}
-
-export function SerializePreAccountCellDataV2(value: PreAccountCellDataV2Type): ArrayBuffer;
-export class PreAccountCellDataV2 {
-  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
-  validate(compatible?: boolean): void;
-  getAccount(): AccountChars;
-  getRefundLock(): Script;
-  getOwnerLockArgs(): Bytes;
-  getInviterId(): Bytes;
-  getInviterLock(): ScriptOpt;
-  getChannelLock(): ScriptOpt;
-  getPrice(): PriceConfig;
-  getQuote(): Uint64;
-  getInvitedDiscount(): Uint32;
-  getCreatedAt(): Uint64;
-  getInitialRecords(): Records;
-}
-
 export function SerializePreAccountCellData(value: PreAccountCellDataType): ArrayBuffer;
 export class PreAccountCellData {
   constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
@@ -1109,6 +1174,7 @@ export class SubAccount {
   getNonce(): Uint64;
   getEnableSubAccount(): Uint8;
   getRenewSubAccountPrice(): Uint64;
+  getApproval(): AccountApproval;
 }
 
 export function SerializeSubAccountRule(value: SubAccountRuleType): ArrayBuffer;
@@ -1205,3 +1271,34 @@ export class DeviceKeyListCellData {
   getRefundLock(): Script;
 }
 
+export function SerializeMapEntry(value: MapEntryType): ArrayBuffer;
+export class MapEntry {
+  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
+  validate(compatible?: boolean): void;
+  getKey(): Bytes;
+  getValue(): Bytes;
+}
+
+export function SerializeConfigList(value: Array<MapEntryType>): ArrayBuffer;
+export class ConfigList {
+  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
+  validate(compatible?: boolean): void;
+  indexAt(i: number): MapEntry;
+  length(): number;
+}
+
+export function SerializeConfig(value: ConfigType): ArrayBuffer;
+export class Config {
+  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
+  validate(compatible?: boolean): void;
+  getStatus(): Uint8;
+  getConfigs(): ConfigList;
+}
+
+export function SerializeOrderInfo(value: OrderInfoType): ArrayBuffer;
+export class OrderInfo {
+  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
+  validate(compatible?: boolean): void;
+  getMemo(): Bytes;
+}
+
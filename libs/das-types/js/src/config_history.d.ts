@@ -120,6 +120,12 @@ export interface ConfigCellMainV7Type {
   das_lock_out_point_table: DasLockOutPointTableType;
 }
 
+export interface ConfigCellMainV8Type {
+  status: Uint8Type;
+  type_id_table: TypeIdTableV6Type;
+  das_lock_out_point_table: DasLockOutPointTableType;
+}
+
 export interface TypeIdTableV6Type {
   account_cell: HashType;
   apply_register_cell: HashType;
@@ -135,6 +141,59 @@ export interface TypeIdTableV6Type {
   eip712_lib: HashType;
 }
 
+export interface ConfigCellMainV9Type {
+  status: Uint8Type;
+  type_id_table: TypeIdTableV7Type;
+  das_lock_out_point_table: DasLockOutPointTableType;
+}
+
+export interface TypeIdTableV7Type {
+  account_cell: HashType;
+  apply_register_cell: HashType;
+  balance_cell: HashType;
+  income_cell: HashType;
+  pre_account_cell: HashType;
+  proposal_cell: HashType;
+  account_sale_cell: HashType;
+  account_auction_cell: HashType;
+  offer_cell: HashType;
+  reverse_record_cell: HashType;
+  sub_account_cell: HashType;
+  eip712_lib: HashType;
+  reverse_record_root_cell: HashType;
+}
+
+export interface ConfigCellMainV10Type {
+  status: Uint8Type;
+  type_id_table: TypeIdTableV7Type;
+  das_lock_out_point_table: DasLockOutPointTableType;
+  das_lock_type_id_table: DasLockTypeIdTableType;
+}
+
+export interface ConfigCellMainV11Type {
+  status: Uint8Type;
+  type_id_table: TypeIdTableV8Type;
+  das_lock_out_point_table: DasLockOutPointTableType;
+  das_lock_type_id_table: DasLockTypeIdTableType;
+}
+
+export interface TypeIdTableV8Type {
+  account_cell: HashType;
+  apply_register_cell: HashType;
+  balance_cell: HashType;
+  income_cell: HashType;
+  pre_account_cell: HashType;
+  proposal_cell: HashType;
+  account_sale_cell: HashType;
+  account_auction_cell: HashType;
+  offer_cell: HashType;
+  reverse_record_cell: HashType;
+  sub_account_cell: HashType;
+  eip712_lib: HashType;
+  reverse_record_root_cell: HashType;
+  key_list_config_cell: HashType;
+}
+
 export interface ConfigCellAccountV1Type {
   max_length: Uint32Type;
   basic_capacity: Uint64Type;
@@ -150,6 +209,22 @@ export interface ConfigCellAccountV1Type {
   edit_records_throttle: Uint32Type;
 }
 
+export interface ConfigCellAccountV2Type {
+  max_length: Uint32Type;
+  basic_capacity: Uint64Type;
+  prepared_fee_capacity: Uint64Type;
+  expiration_grace_period: Uint32Type;
+  record_min_ttl: Uint32Type;
+  record_size_limit: Uint32Type;
+  transfer_account_fee: Uint64Type;
+  edit_manager_fee: Uint64Type;
+  edit_records_fee: Uint64Type;
+  transfer_account_throttle: Uint32Type;
+  edit_manager_throttle: Uint32Type;
+  edit_records_throttle: Uint32Type;
+  common_throttle: Uint32Type;
+}
+
 export interface ConfigCellProfitRateV1Type {
   inviter: Uint32Type;
   channel: Uint32Type;
@@ -198,6 +273,22 @@ export interface ConfigCellSubAccountV1Type {
   recycle_fee: Uint64Type;
 }
 
+export interface ConfigCellSystemStatusV1Type {
+  apply_register_cell_type: ContractStatusType;
+  pre_account_cell_type: ContractStatusType;
+  proposal_cell_type: ContractStatusType;
+  config_cell_type: ContractStatusType;
+  account_cell_type: ContractStatusType;
+  account_sale_cell_type: ContractStatusType;
+  sub_account_cell_type: ContractStatusType;
+  offer_cell_type: ContractStatusType;
+  balance_cell_type: ContractStatusType;
+  income_cell_type: ContractStatusType;
+  reverse_record_cell_type: ContractStatusType;
+  reverse_record_root_cell_type: ContractStatusType;
+  eip712_lib: ContractStatusType;
+}
+
 export type Uint8Type = CanCastToArrayBuffer;
 
 export type Uint32Type = CanCastToArrayBuffer;
@@ -220,6 +311,8 @@ export interface ScriptType {
 
 export type ScriptOptType = ScriptType | undefined;
 
+export type ScriptsType = ScriptType[];
+
 export interface OutPointType {
   tx_hash: HashType;
   index: Uint32Type;
@@ -266,6 +359,7 @@ export interface TypeIdTableType {
   eip712_lib: HashType;
   reverse_record_root_cell: HashType;
   key_list_config_cell: HashType;
+  dpoint_cell: HashType;
 }
 
 export interface DasLockOutPointTableType {
@@ -303,6 +397,9 @@ export interface ConfigCellAccountType {
   edit_manager_throttle: Uint32Type;
   edit_records_throttle: Uint32Type;
   common_throttle: Uint32Type;
+  expiration_auction_period: Uint32Type;
+  expiration_deliver_period: Uint32Type;
+  expiration_auction_start_premiums: Uint32Type;
 }
 
 export interface ConfigCellApplyType {
@@ -416,6 +513,8 @@ export interface ConfigCellSystemStatusType {
   reverse_record_cell_type: ContractStatusType;
   reverse_record_root_cell_type: ContractStatusType;
   eip712_lib: ContractStatusType;
+  key_list_config_cell_type: ContractStatusType;
+  dpoint_cell_type: ContractStatusType;
 }
 
 export interface ContractStatusType {
@@ -423,6 +522,13 @@ export interface ContractStatusType {
   version: BytesType;
 }
 
+export interface ConfigCellDPointType {
+  basic_capacity: Uint64Type;
+  prepared_fee_capacity: Uint64Type;
+  transfer_whitelist: ScriptsType;
+  capacity_recycle_whitelist: ScriptsType;
+}
+
 export interface ProposalCellDataType {
   proposer_lock: ScriptType;
   created_at_height: Uint64Type;
@@ -451,17 +557,6 @@ export interface IncomeRecordType {
   capacity: Uint64Type;
 }
 
-export interface AccountCellDataV2Type {
-  id: AccountIdType;
-  account: AccountCharsType;
-  registered_at: Uint64Type;
-  last_transfer_account_at: Uint64Type;
-  last_edit_manager_at: Uint64Type;
-  last_edit_records_at: Uint64Type;
-  status: Uint8Type;
-  records: RecordsType;
-}
-
 export interface AccountCellDataType {
   id: AccountIdType;
   account: AccountCharsType;
@@ -473,6 +568,7 @@ export interface AccountCellDataType {
   records: RecordsType;
   enable_sub_account: Uint8Type;
   renew_sub_account_price: Uint64Type;
+  approval: AccountApprovalType;
 }
 
 export type AccountIdType = CanCastToArrayBuffer;
@@ -487,6 +583,19 @@ export interface RecordType {
 
 export type RecordsType = RecordType[];
 
+export interface AccountApprovalType {
+  action: BytesType;
+  params: BytesType;
+}
+
+export interface AccountApprovalTransferType {
+  platform_lock: ScriptType;
+  protected_until: Uint64Type;
+  sealed_until: Uint64Type;
+  delay_count_remain: Uint8Type;
+  to_lock: ScriptType;
+}
+
 export interface AccountSaleCellDataV1Type {
   account_id: AccountIdType;
   account: BytesType;
@@ -517,33 +626,6 @@ export interface AccountAuctionCellDataType {
   prev_bidder_profit_rate: Uint32Type;
 }
 
-export interface PreAccountCellDataV1Type {
-  account: AccountCharsType;
-  refund_lock: ScriptType;
-  owner_lock_args: BytesType;
-  inviter_id: BytesType;
-  inviter_lock?: ScriptType;
-  channel_lock?: ScriptType;
-  price: PriceConfigType;
-  quote: Uint64Type;
-  invited_discount: Uint32Type;
-  created_at: Uint64Type;
-}
-
-export interface PreAccountCellDataV2Type {
-  account: AccountCharsType;
-  refund_lock: ScriptType;
-  owner_lock_args: BytesType;
-  inviter_id: BytesType;
-  inviter_lock?: ScriptType;
-  channel_lock?: ScriptType;
-  price: PriceConfigType;
-  quote: Uint64Type;
-  invited_discount: Uint32Type;
-  created_at: Uint64Type;
-  initial_records: RecordsType;
-}
-
 export interface PreAccountCellDataType {
   account: AccountCharsType;
   refund_lock: ScriptType;
@@ -592,6 +674,7 @@ export interface SubAccountType {
   nonce: Uint64Type;
   enable_sub_account: Uint8Type;
   renew_sub_account_price: Uint64Type;
+  approval: AccountApprovalType;
 }
 
 export interface SubAccountRuleType {
@@ -645,6 +728,22 @@ export interface DeviceKeyListCellDataType {
   refund_lock: ScriptType;
 }
 
+export interface MapEntryType {
+  key: BytesType;
+  value: BytesType;
+}
+
+export type ConfigListType = MapEntryType[];
+
+export interface ConfigType {
+  status: Uint8Type;
+  configs: ConfigListType;
+}
+
+export interface OrderInfoType {
+  memo: BytesType;
+}
+
 export function SerializeConfigCellMainV1(value: ConfigCellMainV1Type): ArrayBuffer;
 export class ConfigCellMainV1 {
   constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
@@ -791,6 +890,15 @@ export class ConfigCellMainV7 {
   getDasLockOutPointTable(): DasLockOutPointTable;
 }
 
+export function SerializeConfigCellMainV8(value: ConfigCellMainV8Type): ArrayBuffer;
+export class ConfigCellMainV8 {
+  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
+  validate(compatible?: boolean): void;
+  getStatus(): Uint8;
+  getTypeIdTable(): TypeIdTableV6;
+  getDasLockOutPointTable(): DasLockOutPointTable;
+}
+
 export function SerializeTypeIdTableV6(value: TypeIdTableV6Type): ArrayBuffer;
 export class TypeIdTableV6 {
   constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
@@ -809,6 +917,74 @@ export class TypeIdTableV6 {
   getEip712Lib(): Hash;
 }
 
+export function SerializeConfigCellMainV9(value: ConfigCellMainV9Type): ArrayBuffer;
+export class ConfigCellMainV9 {
+  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
+  validate(compatible?: boolean): void;
+  getStatus(): Uint8;
+  getTypeIdTable(): TypeIdTableV7;
+  getDasLockOutPointTable(): DasLockOutPointTable;
+}
+
+export function SerializeTypeIdTableV7(value: TypeIdTableV7Type): ArrayBuffer;
+export class TypeIdTableV7 {
+  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
+  validate(compatible?: boolean): void;
+  getAccountCell(): Hash;
+  getApplyRegisterCell(): Hash;
+  getBalanceCell(): Hash;
+  getIncomeCell(): Hash;
+  getPreAccountCell(): Hash;
+  getProposalCell(): Hash;
+  getAccountSaleCell(): Hash;
+  getAccountAuctionCell(): Hash;
+  getOfferCell(): Hash;
+  getReverseRecordCell(): Hash;
+  getSubAccountCell(): Hash;
+  getEip712Lib(): Hash;
+  getReverseRecordRootCell(): Hash;
+}
+
+export function SerializeConfigCellMainV10(value: ConfigCellMainV10Type): ArrayBuffer;
+export class ConfigCellMainV10 {
+  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
+  validate(compatible?: boolean): void;
+  getStatus(): Uint8;
+  getTypeIdTable(): TypeIdTableV7;
+  getDasLockOutPointTable(): DasLockOutPointTable;
+  getDasLockTypeIdTable(): DasLockTypeIdTable;
+}
+
+export function SerializeConfigCellMainV11(value: ConfigCellMainV11Type): ArrayBuffer;
+export class ConfigCellMainV11 {
+  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
+  validate(compatible?: boolean): void;
+  getStatus(): Uint8;
+  getTypeIdTable(): TypeIdTableV8;
+  getDasLockOutPointTable(): DasLockOutPointTable;
+  getDasLockTypeIdTable(): DasLockTypeIdTable;
+}
+
+export function SerializeTypeIdTableV8(value: TypeIdTableV8Type): ArrayBuffer;
+export class TypeIdTableV8 {
+  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
+  validate(compatible?: boolean): void;
+  getAccountCell(): Hash;
+  getApplyRegisterCell(): Hash;
+  getBalanceCell(): Hash;
+  getIncomeCell(): Hash;
+  getPreAccountCell(): Hash;
+  getProposalCell(): Hash;
+  getAccountSaleCell(): Hash;
+  getAccountAuctionCell(): Hash;
+  getOfferCell(): Hash;
+  getReverseRecordCell(): Hash;
+  getSubAccountCell(): Hash;
+  getEip712Lib(): Hash;
+  getReverseRecordRootCell(): Hash;
+  getKeyListConfigCell(): Hash;
+}
+
 export function SerializeConfigCellAccountV1(value: ConfigCellAccountV1Type): ArrayBuffer;
 export class ConfigCellAccountV1 {
   constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
@@ -827,6 +1003,25 @@ export class ConfigCellAccountV1 {
   getEditRecordsThrottle(): Uint32;
 }
 
+export function SerializeConfigCellAccountV2(value: ConfigCellAccountV2Type): ArrayBuffer;
+export class ConfigCellAccountV2 {
+  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
+  validate(compatible?: boolean): void;
+  getMaxLength(): Uint32;
+  getBasicCapacity(): Uint64;
+  getPreparedFeeCapacity(): Uint64;
+  getExpirationGracePeriod(): Uint32;
+  getRecordMinTtl(): Uint32;
+  getRecordSizeLimit(): Uint32;
+  getTransferAccountFee(): Uint64;
+  getEditManagerFee(): Uint64;
+  getEditRecordsFee(): Uint64;
+  getTransferAccountThrottle(): Uint32;
+  getEditManagerThrottle(): Uint32;
+  getEditRecordsThrottle(): Uint32;
+  getCommonThrottle(): Uint32;
+}
+
 export function SerializeConfigCellProfitRateV1(value: ConfigCellProfitRateV1Type): ArrayBuffer;
 export class ConfigCellProfitRateV1 {
   constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
@@ -890,6 +1085,25 @@ export class ConfigCellSubAccountV1 {
   getRecycleFee(): Uint64;
 }
 
+export function SerializeConfigCellSystemStatusV1(value: ConfigCellSystemStatusV1Type): ArrayBuffer;
+export class ConfigCellSystemStatusV1 {
+  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
+  validate(compatible?: boolean): void;
+  getApplyRegisterCellType(): ContractStatus;
+  getPreAccountCellType(): ContractStatus;
+  getProposalCellType(): ContractStatus;
+  getConfigCellType(): ContractStatus;
+  getAccountCellType(): ContractStatus;
+  getAccountSaleCellType(): ContractStatus;
+  getSubAccountCellType(): ContractStatus;
+  getOfferCellType(): ContractStatus;
+  getBalanceCellType(): ContractStatus;
+  getIncomeCellType(): ContractStatus;
+  getReverseRecordCellType(): ContractStatus;
+  getReverseRecordRootCellType(): ContractStatus;
+  getEip712Lib(): ContractStatus;
+}
+
 export function SerializeUint8(value: CanCastToArrayBuffer): ArrayBuffer;
 export class Uint8 {
   constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
@@ -973,6 +1187,14 @@ export class ScriptOpt {
   hasValue(): boolean;
 }
 
+export function SerializeScripts(value: Array<ScriptType>): ArrayBuffer;
+export class Scripts {
+  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
+  validate(compatible?: boolean): void;
+  indexAt(i: number): Script;
+  length(): number;
+}
+
 export function SerializeOutPoint(value: OutPointType): ArrayBuffer;
 export class OutPoint {
   constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
@@ -1044,6 +1266,7 @@ export class TypeIdTable {
   getEip712Lib(): Hash;
   getReverseRecordRootCell(): Hash;
   getKeyListConfigCell(): Hash;
+  getDpointCell(): Hash;
 }
 
 export function SerializeDasLockOutPointTable(value: DasLockOutPointTableType): ArrayBuffer;
@@ -1090,6 +1313,9 @@ export class ConfigCellAccount {
   getEditManagerThrottle(): Uint32;
   getEditRecordsThrottle(): Uint32;
   getCommonThrottle(): Uint32;
+  getExpirationAuctionPeriod(): Uint32;
+  getExpirationDeliverPeriod(): Uint32;
+  getExpirationAuctionStartPremiums(): Uint32;
 }
 
 export function SerializeConfigCellApply(value: ConfigCellApplyType): ArrayBuffer;
@@ -1251,6 +1477,8 @@ export class ConfigCellSystemStatus {
   getReverseRecordCellType(): ContractStatus;
   getReverseRecordRootCellType(): ContractStatus;
   getEip712Lib(): ContractStatus;
+  getKeyListConfigCellType(): ContractStatus;
+  getDpointCellType(): ContractStatus;
 }
 
 export function SerializeContractStatus(value: ContractStatusType): ArrayBuffer;
@@ -1261,6 +1489,16 @@ export class ContractStatus {
   getVersion(): Bytes;
 }
 
+export function SerializeConfigCellDPoint(value: ConfigCellDPointType): ArrayBuffer;
+export class ConfigCellDPoint {
+  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
+  validate(compatible?: boolean): void;
+  getBasicCapacity(): Uint64;
+  getPreparedFeeCapacity(): Uint64;
+  getTransferWhitelist(): Scripts;
+  getCapacityRecycleWhitelist(): Scripts;
+}
+
 export function SerializeProposalCellData(value: ProposalCellDataType): ArrayBuffer;
 export class ProposalCellData {
   constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
@@ -1319,20 +1557,6 @@ export class IncomeRecord {
   getCapacity(): Uint64;
 }
 
-export function SerializeAccountCellDataV2(value: AccountCellDataV2Type): ArrayBuffer;
-export class AccountCellDataV2 {
-  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
-  validate(compatible?: boolean): void;
-  getId(): AccountId;
-  getAccount(): AccountChars;
-  getRegisteredAt(): Uint64;
-  getLastTransferAccountAt(): Uint64;
-  getLastEditManagerAt(): Uint64;
-  getLastEditRecordsAt(): Uint64;
-  getStatus(): Uint8;
-  getRecords(): Records;
-}
-
 export function SerializeAccountCellData(value: AccountCellDataType): ArrayBuffer;
 export class AccountCellData {
   constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
@@ -1347,6 +1571,7 @@ export class AccountCellData {
   getRecords(): Records;
   getEnableSubAccount(): Uint8;
   getRenewSubAccountPrice(): Uint64;
+  getApproval(): AccountApproval;
 }
 
 export function SerializeAccountId(value: CanCastToArrayBuffer): ArrayBuffer;
@@ -1377,6 +1602,25 @@ export class Records {
   length(): number;
 }
 
+export function SerializeAccountApproval(value: AccountApprovalType): ArrayBuffer;
+export class AccountApproval {
+  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
+  validate(compatible?: boolean): void;
+  getAction(): Bytes;
+  getParams(): Bytes;
+}
+
+export function SerializeAccountApprovalTransfer(value: AccountApprovalTransferType): ArrayBuffer;
+export class AccountApprovalTransfer {
+  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
+  validate(compatible?: boolean): void;
+  getPlatformLock(): Script;
+  getProtectedUntil(): Uint64;
+  getSealedUntil(): Uint64;
+  getDelayCountRemain(): Uint8;
+  getToLock(): Script;
+}
+
 export function SerializeAccountSaleCellDataV1(value: AccountSaleCellDataV1Type): ArrayBuffer;
 export class AccountSaleCellDataV1 {
   constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
@@ -1416,39 +1660,6 @@ export class AccountAuctionCellData {
   getPrevBidderProfitRate(): Uint32;
 }
 
-export function SerializePreAccountCellDataV1(value: PreAccountCellDataV1Type): ArrayBuffer;
-export class PreAccountCellDataV1 {
-  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
-  validate(compatible?: boolean): void;
-  getAccount(): AccountChars;
-  getRefundLock(): Script;
-  getOwnerLockArgs(): Bytes;
-  getInviterId(): Bytes;
-  getInviterLock(): ScriptOpt;
-  getChannelLock(): ScriptOpt;
-  getPrice(): PriceConfig;
-  getQuote(): Uint64;
-  getInvitedDiscount(): Uint32;
-  getCreatedAt(): Uint64;
-}
-
-export function SerializePreAccountCellDataV2(value: PreAccountCellDataV2Type): ArrayBuffer;
-export class PreAccountCellDataV2 {
-  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
-  validate(compatible?: boolean): void;
-  getAccount(): AccountChars;
-  getRefundLock(): Script;
-  getOwnerLockArgs(): Bytes;
-  getInviterId(): Bytes;
-  getInviterLock(): ScriptOpt;
-  getChannelLock(): ScriptOpt;
-  getPrice(): PriceConfig;
-  getQuote(): Uint64;
-  getInvitedDiscount(): Uint32;
-  getCreatedAt(): Uint64;
-  getInitialRecords(): Records;
-}
-
 export function SerializePreAccountCellData(value: PreAccountCellDataType): ArrayBuffer;
 export class PreAccountCellData {
   constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
@@ -1518,6 +1729,7 @@ export class SubAccount {
   getNonce(): Uint64;
   getEnableSubAccount(): Uint8;
   getRenewSubAccountPrice(): Uint64;
+  getApproval(): AccountApproval;
 }
 
 export function SerializeSubAccountRule(value: SubAccountRuleType): ArrayBuffer;
@@ -1614,3 +1826,34 @@ export class DeviceKeyListCellData {
   getRefundLock(): Script;
 }
 
+export function SerializeMapEntry(value: MapEntryType): ArrayBuffer;
+export class MapEntry {
+  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
+  validate(compatible?: boolean): void;
+  getKey(): Bytes;
+  getValue(): Bytes;
+}
+
+export function SerializeConfigList(value: Array<MapEntryType>): ArrayBuffer;
+export class ConfigList {
+  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
+  validate(compatible?: boolean): void;
+  indexAt(i: number): MapEntry;
+  length(): number;
+}
+
+export function SerializeConfig(value: ConfigType): ArrayBuffer;
+export class Config {
+  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
+  validate(compatible?: boolean): void;
+  getStatus(): Uint8;
+  getConfigs(): ConfigList;
+}
+
+export function SerializeOrderInfo(value: OrderInfoType): ArrayBuffer;
+export class OrderInfo {
+  constructor(reader: CanCastToArrayBuffer, options?: CreateOptions);
+  validate(compatible?: boolean): void;
+  getMemo(): Bytes;
+}
+
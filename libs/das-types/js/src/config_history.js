@@ -925,6 +925,51 @@ export function SerializeConfigCellMainV7(value) {
   return serializeTable(buffers);
 }
 
+export class ConfigCellMainV8 {
+  constructor(reader, { validate = true } = {}) {
+    this.view = new DataView(assertArrayBuffer(reader));
+    if (validate) {
+      this.validate();
+    }
+  }
+
+  validate(compatible = false) {
+    const offsets = verifyAndExtractOffsets(this.view, 0, true);
+    new Uint8(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
+    new TypeIdTableV6(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
+    new DasLockOutPointTable(this.view.buffer.slice(offsets[2], offsets[3]), { validate: false }).validate();
+  }
+
+  getStatus() {
+    const start = 4;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Uint8(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getTypeIdTable() {
+    const start = 8;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new TypeIdTableV6(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getDasLockOutPointTable() {
+    const start = 12;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.byteLength;
+    return new DasLockOutPointTable(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+}
+
+export function SerializeConfigCellMainV8(value) {
+  const buffers = [];
+  buffers.push(SerializeUint8(value.status));
+  buffers.push(SerializeTypeIdTableV6(value.type_id_table));
+  buffers.push(SerializeDasLockOutPointTable(value.das_lock_out_point_table));
+  return serializeTable(buffers);
+}
+
 export class TypeIdTableV6 {
   constructor(reader, { validate = true } = {}) {
     this.view = new DataView(assertArrayBuffer(reader));
@@ -935,123 +980,681 @@ export class TypeIdTableV6 {
 
   validate(compatible = false) {
     const offsets = verifyAndExtractOffsets(this.view, 0, true);
-    new Hash(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
-    new Hash(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
-    new Hash(this.view.buffer.slice(offsets[2], offsets[3]), { validate: false }).validate();
-    new Hash(this.view.buffer.slice(offsets[3], offsets[4]), { validate: false }).validate();
-    new Hash(this.view.buffer.slice(offsets[4], offsets[5]), { validate: false }).validate();
-    new Hash(this.view.buffer.slice(offsets[5], offsets[6]), { validate: false }).validate();
-    new Hash(this.view.buffer.slice(offsets[6], offsets[7]), { validate: false }).validate();
-    new Hash(this.view.buffer.slice(offsets[7], offsets[8]), { validate: false }).validate();
-    new Hash(this.view.buffer.slice(offsets[8], offsets[9]), { validate: false }).validate();
-    new Hash(this.view.buffer.slice(offsets[9], offsets[10]), { validate: false }).validate();
-    new Hash(this.view.buffer.slice(offsets[10], offsets[11]), { validate: false }).validate();
-    new Hash(this.view.buffer.slice(offsets[11], offsets[12]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[2], offsets[3]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[3], offsets[4]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[4], offsets[5]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[5], offsets[6]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[6], offsets[7]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[7], offsets[8]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[8], offsets[9]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[9], offsets[10]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[10], offsets[11]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[11], offsets[12]), { validate: false }).validate();
+  }
+
+  getAccountCell() {
+    const start = 4;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getApplyRegisterCell() {
+    const start = 8;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getBalanceCell() {
+    const start = 12;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getIncomeCell() {
+    const start = 16;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getPreAccountCell() {
+    const start = 20;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getProposalCell() {
+    const start = 24;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getAccountSaleCell() {
+    const start = 28;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getAccountAuctionCell() {
+    const start = 32;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getOfferCell() {
+    const start = 36;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getReverseRecordCell() {
+    const start = 40;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getSubAccountCell() {
+    const start = 44;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getEip712Lib() {
+    const start = 48;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.byteLength;
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+}
+
+export function SerializeTypeIdTableV6(value) {
+  const buffers = [];
+  buffers.push(SerializeHash(value.account_cell));
+  buffers.push(SerializeHash(value.apply_register_cell));
+  buffers.push(SerializeHash(value.balance_cell));
+  buffers.push(SerializeHash(value.income_cell));
+  buffers.push(SerializeHash(value.pre_account_cell));
+  buffers.push(SerializeHash(value.proposal_cell));
+  buffers.push(SerializeHash(value.account_sale_cell));
+  buffers.push(SerializeHash(value.account_auction_cell));
+  buffers.push(SerializeHash(value.offer_cell));
+  buffers.push(SerializeHash(value.reverse_record_cell));
+  buffers.push(SerializeHash(value.sub_account_cell));
+  buffers.push(SerializeHash(value.eip712_lib));
+  return serializeTable(buffers);
+}
+
+export class ConfigCellMainV9 {
+  constructor(reader, { validate = true } = {}) {
+    this.view = new DataView(assertArrayBuffer(reader));
+    if (validate) {
+      this.validate();
+    }
+  }
+
+  validate(compatible = false) {
+    const offsets = verifyAndExtractOffsets(this.view, 0, true);
+    new Uint8(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
+    new TypeIdTableV7(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
+    new DasLockOutPointTable(this.view.buffer.slice(offsets[2], offsets[3]), { validate: false }).validate();
+  }
+
+  getStatus() {
+    const start = 4;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Uint8(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getTypeIdTable() {
+    const start = 8;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new TypeIdTableV7(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getDasLockOutPointTable() {
+    const start = 12;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.byteLength;
+    return new DasLockOutPointTable(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+}
+
+export function SerializeConfigCellMainV9(value) {
+  const buffers = [];
+  buffers.push(SerializeUint8(value.status));
+  buffers.push(SerializeTypeIdTableV7(value.type_id_table));
+  buffers.push(SerializeDasLockOutPointTable(value.das_lock_out_point_table));
+  return serializeTable(buffers);
+}
+
+export class TypeIdTableV7 {
+  constructor(reader, { validate = true } = {}) {
+    this.view = new DataView(assertArrayBuffer(reader));
+    if (validate) {
+      this.validate();
+    }
+  }
+
+  validate(compatible = false) {
+    const offsets = verifyAndExtractOffsets(this.view, 0, true);
+    new Hash(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[2], offsets[3]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[3], offsets[4]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[4], offsets[5]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[5], offsets[6]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[6], offsets[7]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[7], offsets[8]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[8], offsets[9]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[9], offsets[10]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[10], offsets[11]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[11], offsets[12]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[12], offsets[13]), { validate: false }).validate();
+  }
+
+  getAccountCell() {
+    const start = 4;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getApplyRegisterCell() {
+    const start = 8;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getBalanceCell() {
+    const start = 12;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getIncomeCell() {
+    const start = 16;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getPreAccountCell() {
+    const start = 20;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getProposalCell() {
+    const start = 24;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getAccountSaleCell() {
+    const start = 28;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getAccountAuctionCell() {
+    const start = 32;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getOfferCell() {
+    const start = 36;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getReverseRecordCell() {
+    const start = 40;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getSubAccountCell() {
+    const start = 44;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getEip712Lib() {
+    const start = 48;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getReverseRecordRootCell() {
+    const start = 52;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.byteLength;
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+}
+
+export function SerializeTypeIdTableV7(value) {
+  const buffers = [];
+  buffers.push(SerializeHash(value.account_cell));
+  buffers.push(SerializeHash(value.apply_register_cell));
+  buffers.push(SerializeHash(value.balance_cell));
+  buffers.push(SerializeHash(value.income_cell));
+  buffers.push(SerializeHash(value.pre_account_cell));
+  buffers.push(SerializeHash(value.proposal_cell));
+  buffers.push(SerializeHash(value.account_sale_cell));
+  buffers.push(SerializeHash(value.account_auction_cell));
+  buffers.push(SerializeHash(value.offer_cell));
+  buffers.push(SerializeHash(value.reverse_record_cell));
+  buffers.push(SerializeHash(value.sub_account_cell));
+  buffers.push(SerializeHash(value.eip712_lib));
+  buffers.push(SerializeHash(value.reverse_record_root_cell));
+  return serializeTable(buffers);
+}
+
+export class ConfigCellMainV10 {
+  constructor(reader, { validate = true } = {}) {
+    this.view = new DataView(assertArrayBuffer(reader));
+    if (validate) {
+      this.validate();
+    }
+  }
+
+  validate(compatible = false) {
+    const offsets = verifyAndExtractOffsets(this.view, 0, true);
+    new Uint8(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
+    new TypeIdTableV7(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
+    new DasLockOutPointTable(this.view.buffer.slice(offsets[2], offsets[3]), { validate: false }).validate();
+    new DasLockTypeIdTable(this.view.buffer.slice(offsets[3], offsets[4]), { validate: false }).validate();
+  }
+
+  getStatus() {
+    const start = 4;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Uint8(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getTypeIdTable() {
+    const start = 8;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new TypeIdTableV7(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getDasLockOutPointTable() {
+    const start = 12;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new DasLockOutPointTable(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getDasLockTypeIdTable() {
+    const start = 16;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.byteLength;
+    return new DasLockTypeIdTable(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+}
+
+export function SerializeConfigCellMainV10(value) {
+  const buffers = [];
+  buffers.push(SerializeUint8(value.status));
+  buffers.push(SerializeTypeIdTableV7(value.type_id_table));
+  buffers.push(SerializeDasLockOutPointTable(value.das_lock_out_point_table));
+  buffers.push(SerializeDasLockTypeIdTable(value.das_lock_type_id_table));
+  return serializeTable(buffers);
+}
+
+export class ConfigCellMainV11 {
+  constructor(reader, { validate = true } = {}) {
+    this.view = new DataView(assertArrayBuffer(reader));
+    if (validate) {
+      this.validate();
+    }
+  }
+
+  validate(compatible = false) {
+    const offsets = verifyAndExtractOffsets(this.view, 0, true);
+    new Uint8(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
+    new TypeIdTableV8(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
+    new DasLockOutPointTable(this.view.buffer.slice(offsets[2], offsets[3]), { validate: false }).validate();
+    new DasLockTypeIdTable(this.view.buffer.slice(offsets[3], offsets[4]), { validate: false }).validate();
+  }
+
+  getStatus() {
+    const start = 4;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Uint8(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getTypeIdTable() {
+    const start = 8;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new TypeIdTableV8(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getDasLockOutPointTable() {
+    const start = 12;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new DasLockOutPointTable(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getDasLockTypeIdTable() {
+    const start = 16;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.byteLength;
+    return new DasLockTypeIdTable(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+}
+
+export function SerializeConfigCellMainV11(value) {
+  const buffers = [];
+  buffers.push(SerializeUint8(value.status));
+  buffers.push(SerializeTypeIdTableV8(value.type_id_table));
+  buffers.push(SerializeDasLockOutPointTable(value.das_lock_out_point_table));
+  buffers.push(SerializeDasLockTypeIdTable(value.das_lock_type_id_table));
+  return serializeTable(buffers);
+}
+
+export class TypeIdTableV8 {
+  constructor(reader, { validate = true } = {}) {
+    this.view = new DataView(assertArrayBuffer(reader));
+    if (validate) {
+      this.validate();
+    }
+  }
+
+  validate(compatible = false) {
+    const offsets = verifyAndExtractOffsets(this.view, 0, true);
+    new Hash(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[2], offsets[3]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[3], offsets[4]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[4], offsets[5]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[5], offsets[6]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[6], offsets[7]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[7], offsets[8]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[8], offsets[9]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[9], offsets[10]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[10], offsets[11]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[11], offsets[12]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[12], offsets[13]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[13], offsets[14]), { validate: false }).validate();
+  }
+
+  getAccountCell() {
+    const start = 4;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getApplyRegisterCell() {
+    const start = 8;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getBalanceCell() {
+    const start = 12;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getIncomeCell() {
+    const start = 16;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getPreAccountCell() {
+    const start = 20;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getProposalCell() {
+    const start = 24;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getAccountSaleCell() {
+    const start = 28;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getAccountAuctionCell() {
+    const start = 32;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getOfferCell() {
+    const start = 36;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getReverseRecordCell() {
+    const start = 40;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getSubAccountCell() {
+    const start = 44;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getEip712Lib() {
+    const start = 48;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getReverseRecordRootCell() {
+    const start = 52;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getKeyListConfigCell() {
+    const start = 56;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.byteLength;
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+}
+
+export function SerializeTypeIdTableV8(value) {
+  const buffers = [];
+  buffers.push(SerializeHash(value.account_cell));
+  buffers.push(SerializeHash(value.apply_register_cell));
+  buffers.push(SerializeHash(value.balance_cell));
+  buffers.push(SerializeHash(value.income_cell));
+  buffers.push(SerializeHash(value.pre_account_cell));
+  buffers.push(SerializeHash(value.proposal_cell));
+  buffers.push(SerializeHash(value.account_sale_cell));
+  buffers.push(SerializeHash(value.account_auction_cell));
+  buffers.push(SerializeHash(value.offer_cell));
+  buffers.push(SerializeHash(value.reverse_record_cell));
+  buffers.push(SerializeHash(value.sub_account_cell));
+  buffers.push(SerializeHash(value.eip712_lib));
+  buffers.push(SerializeHash(value.reverse_record_root_cell));
+  buffers.push(SerializeHash(value.key_list_config_cell));
+  return serializeTable(buffers);
+}
+
+export class ConfigCellAccountV1 {
+  constructor(reader, { validate = true } = {}) {
+    this.view = new DataView(assertArrayBuffer(reader));
+    if (validate) {
+      this.validate();
+    }
+  }
+
+  validate(compatible = false) {
+    const offsets = verifyAndExtractOffsets(this.view, 0, true);
+    new Uint32(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
+    new Uint64(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
+    new Uint64(this.view.buffer.slice(offsets[2], offsets[3]), { validate: false }).validate();
+    new Uint32(this.view.buffer.slice(offsets[3], offsets[4]), { validate: false }).validate();
+    new Uint32(this.view.buffer.slice(offsets[4], offsets[5]), { validate: false }).validate();
+    new Uint32(this.view.buffer.slice(offsets[5], offsets[6]), { validate: false }).validate();
+    new Uint64(this.view.buffer.slice(offsets[6], offsets[7]), { validate: false }).validate();
+    new Uint64(this.view.buffer.slice(offsets[7], offsets[8]), { validate: false }).validate();
+    new Uint64(this.view.buffer.slice(offsets[8], offsets[9]), { validate: false }).validate();
+    new Uint32(this.view.buffer.slice(offsets[9], offsets[10]), { validate: false }).validate();
+    new Uint32(this.view.buffer.slice(offsets[10], offsets[11]), { validate: false }).validate();
+    new Uint32(this.view.buffer.slice(offsets[11], offsets[12]), { validate: false }).validate();
   }
 
-  getAccountCell() {
+  getMaxLength() {
     const start = 4;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getApplyRegisterCell() {
+  getBasicCapacity() {
     const start = 8;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getBalanceCell() {
+  getPreparedFeeCapacity() {
     const start = 12;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getIncomeCell() {
+  getExpirationGracePeriod() {
     const start = 16;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getPreAccountCell() {
+  getRecordMinTtl() {
     const start = 20;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getProposalCell() {
+  getRecordSizeLimit() {
     const start = 24;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getAccountSaleCell() {
+  getTransferAccountFee() {
     const start = 28;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getAccountAuctionCell() {
+  getEditManagerFee() {
     const start = 32;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getOfferCell() {
+  getEditRecordsFee() {
     const start = 36;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getReverseRecordCell() {
+  getTransferAccountThrottle() {
     const start = 40;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getSubAccountCell() {
+  getEditManagerThrottle() {
     const start = 44;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getEip712Lib() {
+  getEditRecordsThrottle() {
     const start = 48;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.byteLength;
-    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 }
 
-export function SerializeTypeIdTableV6(value) {
+export function SerializeConfigCellAccountV1(value) {
   const buffers = [];
-  buffers.push(SerializeHash(value.account_cell));
-  buffers.push(SerializeHash(value.apply_register_cell));
-  buffers.push(SerializeHash(value.balance_cell));
-  buffers.push(SerializeHash(value.income_cell));
-  buffers.push(SerializeHash(value.pre_account_cell));
-  buffers.push(SerializeHash(value.proposal_cell));
-  buffers.push(SerializeHash(value.account_sale_cell));
-  buffers.push(SerializeHash(value.account_auction_cell));
-  buffers.push(SerializeHash(value.offer_cell));
-  buffers.push(SerializeHash(value.reverse_record_cell));
-  buffers.push(SerializeHash(value.sub_account_cell));
-  buffers.push(SerializeHash(value.eip712_lib));
+  buffers.push(SerializeUint32(value.max_length));
+  buffers.push(SerializeUint64(value.basic_capacity));
+  buffers.push(SerializeUint64(value.prepared_fee_capacity));
+  buffers.push(SerializeUint32(value.expiration_grace_period));
+  buffers.push(SerializeUint32(value.record_min_ttl));
+  buffers.push(SerializeUint32(value.record_size_limit));
+  buffers.push(SerializeUint64(value.transfer_account_fee));
+  buffers.push(SerializeUint64(value.edit_manager_fee));
+  buffers.push(SerializeUint64(value.edit_records_fee));
+  buffers.push(SerializeUint32(value.transfer_account_throttle));
+  buffers.push(SerializeUint32(value.edit_manager_throttle));
+  buffers.push(SerializeUint32(value.edit_records_throttle));
   return serializeTable(buffers);
 }
 
-export class ConfigCellAccountV1 {
+export class ConfigCellAccountV2 {
   constructor(reader, { validate = true } = {}) {
     this.view = new DataView(assertArrayBuffer(reader));
     if (validate) {
@@ -1073,6 +1676,7 @@ export class ConfigCellAccountV1 {
     new Uint32(this.view.buffer.slice(offsets[9], offsets[10]), { validate: false }).validate();
     new Uint32(this.view.buffer.slice(offsets[10], offsets[11]), { validate: false }).validate();
     new Uint32(this.view.buffer.slice(offsets[11], offsets[12]), { validate: false }).validate();
+    new Uint32(this.view.buffer.slice(offsets[12], offsets[13]), { validate: false }).validate();
   }
 
   getMaxLength() {
@@ -1155,12 +1759,19 @@ export class ConfigCellAccountV1 {
   getEditRecordsThrottle() {
     const start = 48;
     const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getCommonThrottle() {
+    const start = 52;
+    const offset = this.view.getUint32(start, true);
     const offset_end = this.view.byteLength;
     return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 }
 
-export function SerializeConfigCellAccountV1(value) {
+export function SerializeConfigCellAccountV2(value) {
   const buffers = [];
   buffers.push(SerializeUint32(value.max_length));
   buffers.push(SerializeUint64(value.basic_capacity));
@@ -1174,6 +1785,7 @@ export function SerializeConfigCellAccountV1(value) {
   buffers.push(SerializeUint32(value.transfer_account_throttle));
   buffers.push(SerializeUint32(value.edit_manager_throttle));
   buffers.push(SerializeUint32(value.edit_records_throttle));
+  buffers.push(SerializeUint32(value.common_throttle));
   return serializeTable(buffers);
 }
 
@@ -1518,49 +2130,184 @@ export class ConfigCellSubAccountV1 {
     const start = 20;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getCreateFee() {
+    const start = 24;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getEditFee() {
+    const start = 28;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getRenewFee() {
+    const start = 32;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getRecycleFee() {
+    const start = 36;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.byteLength;
+    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+}
+
+export function SerializeConfigCellSubAccountV1(value) {
+  const buffers = [];
+  buffers.push(SerializeUint64(value.basic_capacity));
+  buffers.push(SerializeUint64(value.prepared_fee_capacity));
+  buffers.push(SerializeUint64(value.new_sub_account_price));
+  buffers.push(SerializeUint64(value.renew_sub_account_price));
+  buffers.push(SerializeUint64(value.common_fee));
+  buffers.push(SerializeUint64(value.create_fee));
+  buffers.push(SerializeUint64(value.edit_fee));
+  buffers.push(SerializeUint64(value.renew_fee));
+  buffers.push(SerializeUint64(value.recycle_fee));
+  return serializeTable(buffers);
+}
+
+export class ConfigCellSystemStatusV1 {
+  constructor(reader, { validate = true } = {}) {
+    this.view = new DataView(assertArrayBuffer(reader));
+    if (validate) {
+      this.validate();
+    }
+  }
+
+  validate(compatible = false) {
+    const offsets = verifyAndExtractOffsets(this.view, 0, true);
+    new ContractStatus(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
+    new ContractStatus(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
+    new ContractStatus(this.view.buffer.slice(offsets[2], offsets[3]), { validate: false }).validate();
+    new ContractStatus(this.view.buffer.slice(offsets[3], offsets[4]), { validate: false }).validate();
+    new ContractStatus(this.view.buffer.slice(offsets[4], offsets[5]), { validate: false }).validate();
+    new ContractStatus(this.view.buffer.slice(offsets[5], offsets[6]), { validate: false }).validate();
+    new ContractStatus(this.view.buffer.slice(offsets[6], offsets[7]), { validate: false }).validate();
+    new ContractStatus(this.view.buffer.slice(offsets[7], offsets[8]), { validate: false }).validate();
+    new ContractStatus(this.view.buffer.slice(offsets[8], offsets[9]), { validate: false }).validate();
+    new ContractStatus(this.view.buffer.slice(offsets[9], offsets[10]), { validate: false }).validate();
+    new ContractStatus(this.view.buffer.slice(offsets[10], offsets[11]), { validate: false }).validate();
+    new ContractStatus(this.view.buffer.slice(offsets[11], offsets[12]), { validate: false }).validate();
+    new ContractStatus(this.view.buffer.slice(offsets[12], offsets[13]), { validate: false }).validate();
+  }
+
+  getApplyRegisterCellType() {
+    const start = 4;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new ContractStatus(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getPreAccountCellType() {
+    const start = 8;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new ContractStatus(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getProposalCellType() {
+    const start = 12;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new ContractStatus(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getConfigCellType() {
+    const start = 16;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new ContractStatus(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getAccountCellType() {
+    const start = 20;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new ContractStatus(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getAccountSaleCellType() {
+    const start = 24;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new ContractStatus(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getSubAccountCellType() {
+    const start = 28;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new ContractStatus(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getOfferCellType() {
+    const start = 32;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new ContractStatus(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getBalanceCellType() {
+    const start = 36;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new ContractStatus(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getCreateFee() {
-    const start = 24;
+  getIncomeCellType() {
+    const start = 40;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new ContractStatus(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getEditFee() {
-    const start = 28;
+  getReverseRecordCellType() {
+    const start = 44;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new ContractStatus(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getRenewFee() {
-    const start = 32;
+  getReverseRecordRootCellType() {
+    const start = 48;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new ContractStatus(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getRecycleFee() {
-    const start = 36;
+  getEip712Lib() {
+    const start = 52;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.byteLength;
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new ContractStatus(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 }
 
-export function SerializeConfigCellSubAccountV1(value) {
+export function SerializeConfigCellSystemStatusV1(value) {
   const buffers = [];
-  buffers.push(SerializeUint64(value.basic_capacity));
-  buffers.push(SerializeUint64(value.prepared_fee_capacity));
-  buffers.push(SerializeUint64(value.new_sub_account_price));
-  buffers.push(SerializeUint64(value.renew_sub_account_price));
-  buffers.push(SerializeUint64(value.common_fee));
-  buffers.push(SerializeUint64(value.create_fee));
-  buffers.push(SerializeUint64(value.edit_fee));
-  buffers.push(SerializeUint64(value.renew_fee));
-  buffers.push(SerializeUint64(value.recycle_fee));
+  buffers.push(SerializeContractStatus(value.apply_register_cell_type));
+  buffers.push(SerializeContractStatus(value.pre_account_cell_type));
+  buffers.push(SerializeContractStatus(value.proposal_cell_type));
+  buffers.push(SerializeContractStatus(value.config_cell_type));
+  buffers.push(SerializeContractStatus(value.account_cell_type));
+  buffers.push(SerializeContractStatus(value.account_sale_cell_type));
+  buffers.push(SerializeContractStatus(value.sub_account_cell_type));
+  buffers.push(SerializeContractStatus(value.offer_cell_type));
+  buffers.push(SerializeContractStatus(value.balance_cell_type));
+  buffers.push(SerializeContractStatus(value.income_cell_type));
+  buffers.push(SerializeContractStatus(value.reverse_record_cell_type));
+  buffers.push(SerializeContractStatus(value.reverse_record_root_cell_type));
+  buffers.push(SerializeContractStatus(value.eip712_lib));
   return serializeTable(buffers);
 }
 
@@ -1890,6 +2637,44 @@ export function SerializeScriptOpt(value) {
   }
 }
 
+export class Scripts {
+  constructor(reader, { validate = true } = {}) {
+    this.view = new DataView(assertArrayBuffer(reader));
+    if (validate) {
+      this.validate();
+    }
+  }
+
+  validate(compatible = false) {
+    const offsets = verifyAndExtractOffsets(this.view, 0, true);
+    for (let i = 0; i < offsets.length - 1; i++) {
+      new Script(this.view.buffer.slice(offsets[i], offsets[i + 1]), { validate: false }).validate();
+    }
+  }
+
+  length() {
+    if (this.view.byteLength < 8) {
+      return 0;
+    } else {
+      return this.view.getUint32(4, true) / 4 - 1;
+    }
+  }
+
+  indexAt(i) {
+    const start = 4 + i * 4;
+    const offset = this.view.getUint32(start, true);
+    let offset_end = this.view.byteLength;
+    if (i + 1 < this.length()) {
+      offset_end = this.view.getUint32(start + 4, true);
+    }
+    return new Script(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+}
+
+export function SerializeScripts(value) {
+  return serializeTable(value.map(item => SerializeScript(item)));
+}
+
 export class OutPoint {
   constructor(reader, { validate = true } = {}) {
     this.view = new DataView(assertArrayBuffer(reader));
@@ -2159,6 +2944,7 @@ export class TypeIdTable {
     new Hash(this.view.buffer.slice(offsets[11], offsets[12]), { validate: false }).validate();
     new Hash(this.view.buffer.slice(offsets[12], offsets[13]), { validate: false }).validate();
     new Hash(this.view.buffer.slice(offsets[13], offsets[14]), { validate: false }).validate();
+    new Hash(this.view.buffer.slice(offsets[14], offsets[15]), { validate: false }).validate();
   }
 
   getAccountCell() {
@@ -2255,6 +3041,13 @@ export class TypeIdTable {
   getKeyListConfigCell() {
     const start = 56;
     const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getDpointCell() {
+    const start = 60;
+    const offset = this.view.getUint32(start, true);
     const offset_end = this.view.byteLength;
     return new Hash(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
@@ -2276,6 +3069,7 @@ export function SerializeTypeIdTable(value) {
   buffers.push(SerializeHash(value.eip712_lib));
   buffers.push(SerializeHash(value.reverse_record_root_cell));
   buffers.push(SerializeHash(value.key_list_config_cell));
+  buffers.push(SerializeHash(value.dpoint_cell));
   return serializeTable(buffers);
 }
 
@@ -2465,6 +3259,9 @@ export class ConfigCellAccount {
     new Uint32(this.view.buffer.slice(offsets[11], offsets[12]), { validate: false }).validate();
     new Uint32(this.view.buffer.slice(offsets[12], offsets[13]), { validate: false }).validate();
     new Uint32(this.view.buffer.slice(offsets[13], offsets[14]), { validate: false }).validate();
+    new Uint32(this.view.buffer.slice(offsets[14], offsets[15]), { validate: false }).validate();
+    new Uint32(this.view.buffer.slice(offsets[15], offsets[16]), { validate: false }).validate();
+    new Uint32(this.view.buffer.slice(offsets[16], offsets[17]), { validate: false }).validate();
   }
 
   getMaxLength() {
@@ -2561,6 +3358,27 @@ export class ConfigCellAccount {
   getCommonThrottle() {
     const start = 56;
     const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getExpirationAuctionPeriod() {
+    const start = 60;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getExpirationDeliverPeriod() {
+    const start = 64;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getExpirationAuctionStartPremiums() {
+    const start = 68;
+    const offset = this.view.getUint32(start, true);
     const offset_end = this.view.byteLength;
     return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
@@ -2582,6 +3400,9 @@ export function SerializeConfigCellAccount(value) {
   buffers.push(SerializeUint32(value.edit_manager_throttle));
   buffers.push(SerializeUint32(value.edit_records_throttle));
   buffers.push(SerializeUint32(value.common_throttle));
+  buffers.push(SerializeUint32(value.expiration_auction_period));
+  buffers.push(SerializeUint32(value.expiration_deliver_period));
+  buffers.push(SerializeUint32(value.expiration_auction_start_premiums));
   return serializeTable(buffers);
 }
 
@@ -3422,6 +4243,8 @@ export class ConfigCellSystemStatus {
     new ContractStatus(this.view.buffer.slice(offsets[10], offsets[11]), { validate: false }).validate();
     new ContractStatus(this.view.buffer.slice(offsets[11], offsets[12]), { validate: false }).validate();
     new ContractStatus(this.view.buffer.slice(offsets[12], offsets[13]), { validate: false }).validate();
+    new ContractStatus(this.view.buffer.slice(offsets[13], offsets[14]), { validate: false }).validate();
+    new ContractStatus(this.view.buffer.slice(offsets[14], offsets[15]), { validate: false }).validate();
   }
 
   getApplyRegisterCellType() {
@@ -3511,6 +4334,20 @@ export class ConfigCellSystemStatus {
   getEip712Lib() {
     const start = 52;
     const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new ContractStatus(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getKeyListConfigCellType() {
+    const start = 56;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new ContractStatus(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getDpointCellType() {
+    const start = 60;
+    const offset = this.view.getUint32(start, true);
     const offset_end = this.view.byteLength;
     return new ContractStatus(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
@@ -3531,6 +4368,8 @@ export function SerializeConfigCellSystemStatus(value) {
   buffers.push(SerializeContractStatus(value.reverse_record_cell_type));
   buffers.push(SerializeContractStatus(value.reverse_record_root_cell_type));
   buffers.push(SerializeContractStatus(value.eip712_lib));
+  buffers.push(SerializeContractStatus(value.key_list_config_cell_type));
+  buffers.push(SerializeContractStatus(value.dpoint_cell_type));
   return serializeTable(buffers);
 }
 
@@ -3574,6 +4413,60 @@ export function SerializeContractStatus(value) {
   return serializeTable(buffers);
 }
 
+export class ConfigCellDPoint {
+  constructor(reader, { validate = true } = {}) {
+    this.view = new DataView(assertArrayBuffer(reader));
+    if (validate) {
+      this.validate();
+    }
+  }
+
+  validate(compatible = false) {
+    const offsets = verifyAndExtractOffsets(this.view, 0, true);
+    new Uint64(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
+    new Uint64(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
+    new Scripts(this.view.buffer.slice(offsets[2], offsets[3]), { validate: false }).validate();
+    new Scripts(this.view.buffer.slice(offsets[3], offsets[4]), { validate: false }).validate();
+  }
+
+  getBasicCapacity() {
+    const start = 4;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getPreparedFeeCapacity() {
+    const start = 8;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getTransferWhitelist() {
+    const start = 12;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Scripts(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getCapacityRecycleWhitelist() {
+    const start = 16;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.byteLength;
+    return new Scripts(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+}
+
+export function SerializeConfigCellDPoint(value) {
+  const buffers = [];
+  buffers.push(SerializeUint64(value.basic_capacity));
+  buffers.push(SerializeUint64(value.prepared_fee_capacity));
+  buffers.push(SerializeScripts(value.transfer_whitelist));
+  buffers.push(SerializeScripts(value.capacity_recycle_whitelist));
+  return serializeTable(buffers);
+}
+
 export class ProposalCellData {
   constructor(reader, { validate = true } = {}) {
     this.view = new DataView(assertArrayBuffer(reader));
@@ -3804,139 +4697,49 @@ export class IncomeRecords {
     const offset = this.view.getUint32(start, true);
     let offset_end = this.view.byteLength;
     if (i + 1 < this.length()) {
-      offset_end = this.view.getUint32(start + 4, true);
-    }
-    return new IncomeRecord(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-}
-
-export function SerializeIncomeRecords(value) {
-  return serializeTable(value.map(item => SerializeIncomeRecord(item)));
-}
-
-export class IncomeRecord {
-  constructor(reader, { validate = true } = {}) {
-    this.view = new DataView(assertArrayBuffer(reader));
-    if (validate) {
-      this.validate();
-    }
-  }
-
-  validate(compatible = false) {
-    const offsets = verifyAndExtractOffsets(this.view, 0, true);
-    new Script(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
-  }
-
-  getBelongTo() {
-    const start = 4;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Script(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getCapacity() {
-    const start = 8;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.byteLength;
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-}
-
-export function SerializeIncomeRecord(value) {
-  const buffers = [];
-  buffers.push(SerializeScript(value.belong_to));
-  buffers.push(SerializeUint64(value.capacity));
-  return serializeTable(buffers);
-}
-
-export class AccountCellDataV2 {
-  constructor(reader, { validate = true } = {}) {
-    this.view = new DataView(assertArrayBuffer(reader));
-    if (validate) {
-      this.validate();
-    }
-  }
-
-  validate(compatible = false) {
-    const offsets = verifyAndExtractOffsets(this.view, 0, true);
-    new AccountId(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
-    new AccountChars(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[2], offsets[3]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[3], offsets[4]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[4], offsets[5]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[5], offsets[6]), { validate: false }).validate();
-    new Uint8(this.view.buffer.slice(offsets[6], offsets[7]), { validate: false }).validate();
-    new Records(this.view.buffer.slice(offsets[7], offsets[8]), { validate: false }).validate();
-  }
-
-  getId() {
-    const start = 4;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new AccountId(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getAccount() {
-    const start = 8;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new AccountChars(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getRegisteredAt() {
-    const start = 12;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getLastTransferAccountAt() {
-    const start = 16;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
+      offset_end = this.view.getUint32(start + 4, true);
+    }
+    return new IncomeRecord(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
+}
 
-  getLastEditManagerAt() {
-    const start = 20;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
+export function SerializeIncomeRecords(value) {
+  return serializeTable(value.map(item => SerializeIncomeRecord(item)));
+}
+
+export class IncomeRecord {
+  constructor(reader, { validate = true } = {}) {
+    this.view = new DataView(assertArrayBuffer(reader));
+    if (validate) {
+      this.validate();
+    }
   }
 
-  getLastEditRecordsAt() {
-    const start = 24;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
+  validate(compatible = false) {
+    const offsets = verifyAndExtractOffsets(this.view, 0, true);
+    new Script(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
+    new Uint64(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
   }
 
-  getStatus() {
-    const start = 28;
+  getBelongTo() {
+    const start = 4;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint8(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Script(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getRecords() {
-    const start = 32;
+  getCapacity() {
+    const start = 8;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.byteLength;
-    return new Records(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 }
 
-export function SerializeAccountCellDataV2(value) {
+export function SerializeIncomeRecord(value) {
   const buffers = [];
-  buffers.push(SerializeAccountId(value.id));
-  buffers.push(SerializeAccountChars(value.account));
-  buffers.push(SerializeUint64(value.registered_at));
-  buffers.push(SerializeUint64(value.last_transfer_account_at));
-  buffers.push(SerializeUint64(value.last_edit_manager_at));
-  buffers.push(SerializeUint64(value.last_edit_records_at));
-  buffers.push(SerializeUint8(value.status));
-  buffers.push(SerializeRecords(value.records));
+  buffers.push(SerializeScript(value.belong_to));
+  buffers.push(SerializeUint64(value.capacity));
   return serializeTable(buffers);
 }
 
@@ -3960,6 +4763,7 @@ export class AccountCellData {
     new Records(this.view.buffer.slice(offsets[7], offsets[8]), { validate: false }).validate();
     new Uint8(this.view.buffer.slice(offsets[8], offsets[9]), { validate: false }).validate();
     new Uint64(this.view.buffer.slice(offsets[9], offsets[10]), { validate: false }).validate();
+    new AccountApproval(this.view.buffer.slice(offsets[10], offsets[11]), { validate: false }).validate();
   }
 
   getId() {
@@ -4028,9 +4832,16 @@ export class AccountCellData {
   getRenewSubAccountPrice() {
     const start = 40;
     const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.byteLength;
+    const offset_end = this.view.getUint32(start + 4, true);
     return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
+
+  getApproval() {
+    const start = 44;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.byteLength;
+    return new AccountApproval(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
 }
 
 export function SerializeAccountCellData(value) {
@@ -4045,6 +4856,7 @@ export function SerializeAccountCellData(value) {
   buffers.push(SerializeRecords(value.records));
   buffers.push(SerializeUint8(value.enable_sub_account));
   buffers.push(SerializeUint64(value.renew_sub_account_price));
+  buffers.push(SerializeAccountApproval(value.approval));
   return serializeTable(buffers);
 }
 
@@ -4180,7 +4992,7 @@ export function SerializeRecords(value) {
   return serializeTable(value.map(item => SerializeRecord(item)));
 }
 
-export class AccountSaleCellDataV1 {
+export class AccountApproval {
   constructor(reader, { validate = true } = {}) {
     this.view = new DataView(assertArrayBuffer(reader));
     if (validate) {
@@ -4190,60 +5002,33 @@ export class AccountSaleCellDataV1 {
 
   validate(compatible = false) {
     const offsets = verifyAndExtractOffsets(this.view, 0, true);
-    new AccountId(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
+    new Bytes(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
     new Bytes(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[2], offsets[3]), { validate: false }).validate();
-    new Bytes(this.view.buffer.slice(offsets[3], offsets[4]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[4], offsets[5]), { validate: false }).validate();
   }
 
-  getAccountId() {
+  getAction() {
     const start = 4;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new AccountId(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getAccount() {
-    const start = 8;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getPrice() {
-    const start = 12;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getDescription() {
-    const start = 16;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
     return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getStartedAt() {
-    const start = 20;
+  getParams() {
+    const start = 8;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.byteLength;
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 }
 
-export function SerializeAccountSaleCellDataV1(value) {
+export function SerializeAccountApproval(value) {
   const buffers = [];
-  buffers.push(SerializeAccountId(value.account_id));
-  buffers.push(SerializeBytes(value.account));
-  buffers.push(SerializeUint64(value.price));
-  buffers.push(SerializeBytes(value.description));
-  buffers.push(SerializeUint64(value.started_at));
+  buffers.push(SerializeBytes(value.action));
+  buffers.push(SerializeBytes(value.params));
   return serializeTable(buffers);
 }
 
-export class AccountSaleCellData {
+export class AccountApprovalTransfer {
   constructor(reader, { validate = true } = {}) {
     this.view = new DataView(assertArrayBuffer(reader));
     if (validate) {
@@ -4253,69 +5038,60 @@ export class AccountSaleCellData {
 
   validate(compatible = false) {
     const offsets = verifyAndExtractOffsets(this.view, 0, true);
-    new AccountId(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
-    new Bytes(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
+    new Script(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
+    new Uint64(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
     new Uint64(this.view.buffer.slice(offsets[2], offsets[3]), { validate: false }).validate();
-    new Bytes(this.view.buffer.slice(offsets[3], offsets[4]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[4], offsets[5]), { validate: false }).validate();
-    new Uint32(this.view.buffer.slice(offsets[5], offsets[6]), { validate: false }).validate();
+    new Uint8(this.view.buffer.slice(offsets[3], offsets[4]), { validate: false }).validate();
+    new Script(this.view.buffer.slice(offsets[4], offsets[5]), { validate: false }).validate();
   }
 
-  getAccountId() {
+  getPlatformLock() {
     const start = 4;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new AccountId(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Script(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getAccount() {
+  getProtectedUntil() {
     const start = 8;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getPrice() {
+  getSealedUntil() {
     const start = 12;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
     return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getDescription() {
+  getDelayCountRemain() {
     const start = 16;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint8(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getStartedAt() {
+  getToLock() {
     const start = 20;
     const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getBuyerInviterProfitRate() {
-    const start = 24;
-    const offset = this.view.getUint32(start, true);
     const offset_end = this.view.byteLength;
-    return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Script(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 }
 
-export function SerializeAccountSaleCellData(value) {
+export function SerializeAccountApprovalTransfer(value) {
   const buffers = [];
-  buffers.push(SerializeAccountId(value.account_id));
-  buffers.push(SerializeBytes(value.account));
-  buffers.push(SerializeUint64(value.price));
-  buffers.push(SerializeBytes(value.description));
-  buffers.push(SerializeUint64(value.started_at));
-  buffers.push(SerializeUint32(value.buyer_inviter_profit_rate));
+  buffers.push(SerializeScript(value.platform_lock));
+  buffers.push(SerializeUint64(value.protected_until));
+  buffers.push(SerializeUint64(value.sealed_until));
+  buffers.push(SerializeUint8(value.delay_count_remain));
+  buffers.push(SerializeScript(value.to_lock));
   return serializeTable(buffers);
 }
 
-export class AccountAuctionCellData {
+export class AccountSaleCellDataV1 {
   constructor(reader, { validate = true } = {}) {
     this.view = new DataView(assertArrayBuffer(reader));
     if (validate) {
@@ -4327,14 +5103,9 @@ export class AccountAuctionCellData {
     const offsets = verifyAndExtractOffsets(this.view, 0, true);
     new AccountId(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
     new Bytes(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
-    new Bytes(this.view.buffer.slice(offsets[2], offsets[3]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[3], offsets[4]), { validate: false }).validate();
-    new Uint32(this.view.buffer.slice(offsets[4], offsets[5]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[5], offsets[6]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[6], offsets[7]), { validate: false }).validate();
-    new Script(this.view.buffer.slice(offsets[7], offsets[8]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[8], offsets[9]), { validate: false }).validate();
-    new Uint32(this.view.buffer.slice(offsets[9], offsets[10]), { validate: false }).validate();
+    new Uint64(this.view.buffer.slice(offsets[2], offsets[3]), { validate: false }).validate();
+    new Bytes(this.view.buffer.slice(offsets[3], offsets[4]), { validate: false }).validate();
+    new Uint64(this.view.buffer.slice(offsets[4], offsets[5]), { validate: false }).validate();
   }
 
   getAccountId() {
@@ -4351,79 +5122,39 @@ export class AccountAuctionCellData {
     return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getDescription() {
+  getPrice() {
     const start = 12;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getOpeningPrice() {
-    const start = 16;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
     return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getIncrementRateEachBid() {
-    const start = 20;
+  getDescription() {
+    const start = 16;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
   getStartedAt() {
-    const start = 24;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getEndedAt() {
-    const start = 28;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getCurrentBidderLock() {
-    const start = 32;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Script(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getCurrentBidPrice() {
-    const start = 36;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getPrevBidderProfitRate() {
-    const start = 40;
+    const start = 20;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.byteLength;
-    return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 }
 
-export function SerializeAccountAuctionCellData(value) {
+export function SerializeAccountSaleCellDataV1(value) {
   const buffers = [];
   buffers.push(SerializeAccountId(value.account_id));
   buffers.push(SerializeBytes(value.account));
+  buffers.push(SerializeUint64(value.price));
   buffers.push(SerializeBytes(value.description));
-  buffers.push(SerializeUint64(value.opening_price));
-  buffers.push(SerializeUint32(value.increment_rate_each_bid));
   buffers.push(SerializeUint64(value.started_at));
-  buffers.push(SerializeUint64(value.ended_at));
-  buffers.push(SerializeScript(value.current_bidder_lock));
-  buffers.push(SerializeUint64(value.current_bid_price));
-  buffers.push(SerializeUint32(value.prev_bidder_profit_rate));
   return serializeTable(buffers);
 }
 
-export class PreAccountCellDataV1 {
+export class AccountSaleCellData {
   constructor(reader, { validate = true } = {}) {
     this.view = new DataView(assertArrayBuffer(reader));
     if (validate) {
@@ -4433,105 +5164,69 @@ export class PreAccountCellDataV1 {
 
   validate(compatible = false) {
     const offsets = verifyAndExtractOffsets(this.view, 0, true);
-    new AccountChars(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
-    new Script(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
-    new Bytes(this.view.buffer.slice(offsets[2], offsets[3]), { validate: false }).validate();
+    new AccountId(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
+    new Bytes(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
+    new Uint64(this.view.buffer.slice(offsets[2], offsets[3]), { validate: false }).validate();
     new Bytes(this.view.buffer.slice(offsets[3], offsets[4]), { validate: false }).validate();
-    new ScriptOpt(this.view.buffer.slice(offsets[4], offsets[5]), { validate: false }).validate();
-    new ScriptOpt(this.view.buffer.slice(offsets[5], offsets[6]), { validate: false }).validate();
-    new PriceConfig(this.view.buffer.slice(offsets[6], offsets[7]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[7], offsets[8]), { validate: false }).validate();
-    new Uint32(this.view.buffer.slice(offsets[8], offsets[9]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[9], offsets[10]), { validate: false }).validate();
+    new Uint64(this.view.buffer.slice(offsets[4], offsets[5]), { validate: false }).validate();
+    new Uint32(this.view.buffer.slice(offsets[5], offsets[6]), { validate: false }).validate();
   }
 
-  getAccount() {
+  getAccountId() {
     const start = 4;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new AccountChars(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getRefundLock() {
-    const start = 8;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Script(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getOwnerLockArgs() {
-    const start = 12;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getInviterId() {
-    const start = 16;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getInviterLock() {
-    const start = 20;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
-    return new ScriptOpt(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new AccountId(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
-
-  getChannelLock() {
-    const start = 24;
+
+  getAccount() {
+    const start = 8;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new ScriptOpt(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
   getPrice() {
-    const start = 28;
+    const start = 12;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new PriceConfig(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getQuote() {
-    const start = 32;
+  getDescription() {
+    const start = 16;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getInvitedDiscount() {
-    const start = 36;
+  getStartedAt() {
+    const start = 20;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getCreatedAt() {
-    const start = 40;
+  getBuyerInviterProfitRate() {
+    const start = 24;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.byteLength;
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 }
 
-export function SerializePreAccountCellDataV1(value) {
+export function SerializeAccountSaleCellData(value) {
   const buffers = [];
-  buffers.push(SerializeAccountChars(value.account));
-  buffers.push(SerializeScript(value.refund_lock));
-  buffers.push(SerializeBytes(value.owner_lock_args));
-  buffers.push(SerializeBytes(value.inviter_id));
-  buffers.push(SerializeScriptOpt(value.inviter_lock));
-  buffers.push(SerializeScriptOpt(value.channel_lock));
-  buffers.push(SerializePriceConfig(value.price));
-  buffers.push(SerializeUint64(value.quote));
-  buffers.push(SerializeUint32(value.invited_discount));
-  buffers.push(SerializeUint64(value.created_at));
+  buffers.push(SerializeAccountId(value.account_id));
+  buffers.push(SerializeBytes(value.account));
+  buffers.push(SerializeUint64(value.price));
+  buffers.push(SerializeBytes(value.description));
+  buffers.push(SerializeUint64(value.started_at));
+  buffers.push(SerializeUint32(value.buyer_inviter_profit_rate));
   return serializeTable(buffers);
 }
 
-export class PreAccountCellDataV2 {
+export class AccountAuctionCellData {
   constructor(reader, { validate = true } = {}) {
     this.view = new DataView(assertArrayBuffer(reader));
     if (validate) {
@@ -4541,110 +5236,101 @@ export class PreAccountCellDataV2 {
 
   validate(compatible = false) {
     const offsets = verifyAndExtractOffsets(this.view, 0, true);
-    new AccountChars(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
-    new Script(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
+    new AccountId(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
+    new Bytes(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
     new Bytes(this.view.buffer.slice(offsets[2], offsets[3]), { validate: false }).validate();
-    new Bytes(this.view.buffer.slice(offsets[3], offsets[4]), { validate: false }).validate();
-    new ScriptOpt(this.view.buffer.slice(offsets[4], offsets[5]), { validate: false }).validate();
-    new ScriptOpt(this.view.buffer.slice(offsets[5], offsets[6]), { validate: false }).validate();
-    new PriceConfig(this.view.buffer.slice(offsets[6], offsets[7]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[7], offsets[8]), { validate: false }).validate();
-    new Uint32(this.view.buffer.slice(offsets[8], offsets[9]), { validate: false }).validate();
-    new Uint64(this.view.buffer.slice(offsets[9], offsets[10]), { validate: false }).validate();
-    new Records(this.view.buffer.slice(offsets[10], offsets[11]), { validate: false }).validate();
+    new Uint64(this.view.buffer.slice(offsets[3], offsets[4]), { validate: false }).validate();
+    new Uint32(this.view.buffer.slice(offsets[4], offsets[5]), { validate: false }).validate();
+    new Uint64(this.view.buffer.slice(offsets[5], offsets[6]), { validate: false }).validate();
+    new Uint64(this.view.buffer.slice(offsets[6], offsets[7]), { validate: false }).validate();
+    new Script(this.view.buffer.slice(offsets[7], offsets[8]), { validate: false }).validate();
+    new Uint64(this.view.buffer.slice(offsets[8], offsets[9]), { validate: false }).validate();
+    new Uint32(this.view.buffer.slice(offsets[9], offsets[10]), { validate: false }).validate();
   }
 
-  getAccount() {
+  getAccountId() {
     const start = 4;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new AccountChars(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new AccountId(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getRefundLock() {
+  getAccount() {
     const start = 8;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Script(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getOwnerLockArgs() {
+  getDescription() {
     const start = 12;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
     return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getInviterId() {
+  getOpeningPrice() {
     const start = 16;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getInviterLock() {
+  getIncrementRateEachBid() {
     const start = 20;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new ScriptOpt(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getChannelLock() {
+  getStartedAt() {
     const start = 24;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new ScriptOpt(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getPrice() {
+  getEndedAt() {
     const start = 28;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new PriceConfig(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getQuote() {
+  getCurrentBidderLock() {
     const start = 32;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Script(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getInvitedDiscount() {
+  getCurrentBidPrice() {
     const start = 36;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.getUint32(start + 4, true);
-    return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
-  }
-
-  getCreatedAt() {
-    const start = 40;
-    const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.getUint32(start + 4, true);
     return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 
-  getInitialRecords() {
-    const start = 44;
+  getPrevBidderProfitRate() {
+    const start = 40;
     const offset = this.view.getUint32(start, true);
     const offset_end = this.view.byteLength;
-    return new Records(this.view.buffer.slice(offset, offset_end), { validate: false });
+    return new Uint32(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
 }
 
-export function SerializePreAccountCellDataV2(value) {
+export function SerializeAccountAuctionCellData(value) {
   const buffers = [];
-  buffers.push(SerializeAccountChars(value.account));
-  buffers.push(SerializeScript(value.refund_lock));
-  buffers.push(SerializeBytes(value.owner_lock_args));
-  buffers.push(SerializeBytes(value.inviter_id));
-  buffers.push(SerializeScriptOpt(value.inviter_lock));
-  buffers.push(SerializeScriptOpt(value.channel_lock));
-  buffers.push(SerializePriceConfig(value.price));
-  buffers.push(SerializeUint64(value.quote));
-  buffers.push(SerializeUint32(value.invited_discount));
-  buffers.push(SerializeUint64(value.created_at));
-  buffers.push(SerializeRecords(value.initial_records));
+  buffers.push(SerializeAccountId(value.account_id));
+  buffers.push(SerializeBytes(value.account));
+  buffers.push(SerializeBytes(value.description));
+  buffers.push(SerializeUint64(value.opening_price));
+  buffers.push(SerializeUint32(value.increment_rate_each_bid));
+  buffers.push(SerializeUint64(value.started_at));
+  buffers.push(SerializeUint64(value.ended_at));
+  buffers.push(SerializeScript(value.current_bidder_lock));
+  buffers.push(SerializeUint64(value.current_bid_price));
+  buffers.push(SerializeUint32(value.prev_bidder_profit_rate));
   return serializeTable(buffers);
 }
 
@@ -4977,6 +5663,7 @@ export class SubAccount {
     new Uint64(this.view.buffer.slice(offsets[8], offsets[9]), { validate: false }).validate();
     new Uint8(this.view.buffer.slice(offsets[9], offsets[10]), { validate: false }).validate();
     new Uint64(this.view.buffer.slice(offsets[10], offsets[11]), { validate: false }).validate();
+    new AccountApproval(this.view.buffer.slice(offsets[11], offsets[12]), { validate: false }).validate();
   }
 
   getLock() {
@@ -5052,9 +5739,16 @@ export class SubAccount {
   getRenewSubAccountPrice() {
     const start = 44;
     const offset = this.view.getUint32(start, true);
-    const offset_end = this.view.byteLength;
+    const offset_end = this.view.getUint32(start + 4, true);
     return new Uint64(this.view.buffer.slice(offset, offset_end), { validate: false });
   }
+
+  getApproval() {
+    const start = 48;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.byteLength;
+    return new AccountApproval(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
 }
 
 export function SerializeSubAccount(value) {
@@ -5070,6 +5764,7 @@ export function SerializeSubAccount(value) {
   buffers.push(SerializeUint64(value.nonce));
   buffers.push(SerializeUint8(value.enable_sub_account));
   buffers.push(SerializeUint64(value.renew_sub_account_price));
+  buffers.push(SerializeAccountApproval(value.approval));
   return serializeTable(buffers);
 }
 
@@ -5533,3 +6228,140 @@ export function SerializeDeviceKeyListCellData(value) {
   return serializeTable(buffers);
 }
 
+export class MapEntry {
+  constructor(reader, { validate = true } = {}) {
+    this.view = new DataView(assertArrayBuffer(reader));
+    if (validate) {
+      this.validate();
+    }
+  }
+
+  validate(compatible = false) {
+    const offsets = verifyAndExtractOffsets(this.view, 0, true);
+    new Bytes(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
+    new Bytes(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
+  }
+
+  getKey() {
+    const start = 4;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getValue() {
+    const start = 8;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.byteLength;
+    return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+}
+
+export function SerializeMapEntry(value) {
+  const buffers = [];
+  buffers.push(SerializeBytes(value.key));
+  buffers.push(SerializeBytes(value.value));
+  return serializeTable(buffers);
+}
+
+export class ConfigList {
+  constructor(reader, { validate = true } = {}) {
+    this.view = new DataView(assertArrayBuffer(reader));
+    if (validate) {
+      this.validate();
+    }
+  }
+
+  validate(compatible = false) {
+    const offsets = verifyAndExtractOffsets(this.view, 0, true);
+    for (let i = 0; i < offsets.length - 1; i++) {
+      new MapEntry(this.view.buffer.slice(offsets[i], offsets[i + 1]), { validate: false }).validate();
+    }
+  }
+
+  length() {
+    if (this.view.byteLength < 8) {
+      return 0;
+    } else {
+      return this.view.getUint32(4, true) / 4 - 1;
+    }
+  }
+
+  indexAt(i) {
+    const start = 4 + i * 4;
+    const offset = this.view.getUint32(start, true);
+    let offset_end = this.view.byteLength;
+    if (i + 1 < this.length()) {
+      offset_end = this.view.getUint32(start + 4, true);
+    }
+    return new MapEntry(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+}
+
+export function SerializeConfigList(value) {
+  return serializeTable(value.map(item => SerializeMapEntry(item)));
+}
+
+export class Config {
+  constructor(reader, { validate = true } = {}) {
+    this.view = new DataView(assertArrayBuffer(reader));
+    if (validate) {
+      this.validate();
+    }
+  }
+
+  validate(compatible = false) {
+    const offsets = verifyAndExtractOffsets(this.view, 0, true);
+    new Uint8(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
+    new ConfigList(this.view.buffer.slice(offsets[1], offsets[2]), { validate: false }).validate();
+  }
+
+  getStatus() {
+    const start = 4;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.getUint32(start + 4, true);
+    return new Uint8(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+
+  getConfigs() {
+    const start = 8;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.byteLength;
+    return new ConfigList(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+}
+
+export function SerializeConfig(value) {
+  const buffers = [];
+  buffers.push(SerializeUint8(value.status));
+  buffers.push(SerializeConfigList(value.configs));
+  return serializeTable(buffers);
+}
+
+export class OrderInfo {
+  constructor(reader, { validate = true } = {}) {
+    this.view = new DataView(assertArrayBuffer(reader));
+    if (validate) {
+      this.validate();
+    }
+  }
+
+  validate(compatible = false) {
+    const offsets = verifyAndExtractOffsets(this.view, 0, true);
+    new Bytes(this.view.buffer.slice(offsets[0], offsets[1]), { validate: false }).validate();
+  }
+
+  getMemo() {
+    const start = 4;
+    const offset = this.view.getUint32(start, true);
+    const offset_end = this.view.byteLength;
+    return new Bytes(this.view.buffer.slice(offset, offset_end), { validate: false });
+  }
+}
+
+export function SerializeOrderInfo(value) {
+  const buffers = [];
+  buffers.push(SerializeBytes(value.memo));
+  return serializeTable(buffers);
+}
+
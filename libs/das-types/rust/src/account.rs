@@ -0,0 +1,199 @@
+//! Normalize account names and derive the IDs from them, with the same rules as the contracts.
+//!
+//! DAS only supports one level of sub-accounts, so a name is either `xxx.bit` or `yyy.xxx.bit`, the suffix `.bit` can
+//! be omitted when parsing. The `AccountId` is the first 20 bytes of the blake2b hash of the whole name, and the key of
+//! a sub-account in the SMT of `sub-account-cell-type` is its `AccountId` padded with 0 to 32 bytes.
+
+#[cfg(feature = "no_std")]
+use alloc::string::String;
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::constants::{ACCOUNT_ID_LENGTH, ACCOUNT_SUFFIX};
+use super::util::blake2b_256;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountNameError {
+    Empty,
+    EmptyLabel,
+    TooManyLevels,
+}
+
+impl fmt::Display for AccountNameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AccountNameError::Empty => write!(f, "the account name is empty"),
+            AccountNameError::EmptyLabel => write!(f, "the account name contains an empty label"),
+            AccountNameError::TooManyLevels => write!(f, "only one level of sub-accounts is supported"),
+        }
+    }
+}
+
+/// A normalized account name, it always ends with `.bit` and contains no uppercase ASCII letters. The other chars are
+/// kept as they are, because the char sets are defined by code points and the contracts compare the raw bytes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AccountName {
+    name: String,
+    // The end of the first label, the rest of the name is the suffix.
+    label_end: usize,
+    sub_account: bool,
+}
+
+impl AccountName {
+    pub fn parse(name: &str) -> Result<Self, AccountNameError> {
+        let mut name = name.trim().to_ascii_lowercase();
+        if name.is_empty() || name == ACCOUNT_SUFFIX {
+            return Err(AccountNameError::Empty);
+        }
+        if !name.ends_with(ACCOUNT_SUFFIX) {
+            name.push_str(ACCOUNT_SUFFIX);
+        }
+
+        let labels = name[..name.len() - ACCOUNT_SUFFIX.len()].split('.').collect::<Vec<_>>();
+        if labels.iter().any(|label| label.is_empty()) {
+            return Err(AccountNameError::EmptyLabel);
+        }
+        if labels.len() > 2 {
+            return Err(AccountNameError::TooManyLevels);
+        }
+
+        Ok(AccountName {
+            label_end: labels[0].len(),
+            sub_account: labels.len() == 2,
+            name,
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.name
+    }
+
+    pub fn is_sub_account(&self) -> bool {
+        self.sub_account
+    }
+
+    /// The first label, it is the part stored as `AccountChars` in cells.
+    pub fn label(&self) -> &str {
+        &self.name[..self.label_end]
+    }
+
+    /// The rest of the name after the label, like `.bit` or `.xxx.bit`, it is the `suffix` field of `SubAccount`.
+    pub fn suffix(&self) -> &str {
+        &self.name[self.label_end..]
+    }
+
+    /// Check if this is a sub-account of the `parent`, it is the same as the contracts checking the `suffix` field.
+    pub fn is_sub_account_of(&self, parent: &AccountName) -> bool {
+        self.sub_account && is_suffix_of_parent(self.suffix().as_bytes(), parent.as_str().as_bytes())
+    }
+
+    pub fn parent(&self) -> Option<AccountName> {
+        if !self.sub_account {
+            return None;
+        }
+
+        let name = String::from(&self.name[self.label_end + 1..]);
+        Some(AccountName {
+            label_end: name.len() - ACCOUNT_SUFFIX.len(),
+            sub_account: false,
+            name,
+        })
+    }
+
+    pub fn id(&self) -> [u8; ACCOUNT_ID_LENGTH] {
+        account_id(self.name.as_bytes())
+    }
+
+    pub fn smt_key(&self) -> [u8; 32] {
+        smt_key_from_account_id(&self.id())
+    }
+}
+
+impl fmt::Display for AccountName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+/// Derive the `AccountId` from the raw bytes of a whole account name, the name is not normalized here.
+pub fn account_id(account: &[u8]) -> [u8; ACCOUNT_ID_LENGTH] {
+    let hash = blake2b_256(account);
+    let mut account_id = [0u8; ACCOUNT_ID_LENGTH];
+    account_id.copy_from_slice(&hash[..ACCOUNT_ID_LENGTH]);
+
+    account_id
+}
+
+/// Check if the `suffix` of a sub-account, like `.xxx.bit`, is the parent account `xxx.bit` with a leading dot.
+pub fn is_suffix_of_parent(suffix: &[u8], parent_account: &[u8]) -> bool {
+    suffix.first() == Some(&b'.') && &suffix[1..] == parent_account
+}
+
+pub fn smt_key_from_account_id(account_id: &[u8]) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    key[..ACCOUNT_ID_LENGTH].copy_from_slice(&account_id[..ACCOUNT_ID_LENGTH]);
+
+    key
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_account_name() {
+        let account = AccountName::parse(" Das0.bit ").unwrap();
+        assert_eq!("das0.bit", account.as_str());
+        assert_eq!("das0", account.label());
+        assert_eq!(".bit", account.suffix());
+        assert!(!account.is_sub_account());
+        assert_eq!(None, account.parent());
+        assert_eq!(account, AccountName::parse("das0").unwrap());
+
+        let sub_account = AccountName::parse("Sub.das0").unwrap();
+        assert_eq!("sub.das0.bit", sub_account.as_str());
+        assert_eq!("sub", sub_account.label());
+        assert_eq!(".das0.bit", sub_account.suffix());
+        assert!(sub_account.is_sub_account());
+        assert_eq!(Some(account.clone()), sub_account.parent());
+
+        assert!(sub_account.is_sub_account_of(sub_account.parent().as_ref().unwrap()));
+        assert!(!sub_account.is_sub_account_of(&AccountName::parse("das1").unwrap()));
+        assert!(!sub_account.is_sub_account_of(&sub_account));
+        assert!(!account.is_sub_account_of(&account));
+
+        // Only the ASCII letters are lowercased, the others are rejected later by the char sets.
+        assert_eq!("straße.bit", AccountName::parse("STRAßE").unwrap().as_str());
+        assert_eq!("ÀÉ.bit", AccountName::parse("ÀÉ").unwrap().as_str());
+
+        assert_eq!(Err(AccountNameError::Empty), AccountName::parse(".bit"));
+        assert_eq!(Err(AccountNameError::EmptyLabel), AccountName::parse("sub..bit"));
+        assert_eq!(Err(AccountNameError::EmptyLabel), AccountName::parse(".das0.bit"));
+        assert_eq!(
+            Err(AccountNameError::TooManyLevels),
+            AccountName::parse("a.sub.das0.bit")
+        );
+    }
+
+    #[test]
+    fn test_is_suffix_of_parent() {
+        assert!(is_suffix_of_parent(b".das0.bit", b"das0.bit"));
+        assert!(!is_suffix_of_parent(b"das0.bit", b"das0.bit"));
+        assert!(!is_suffix_of_parent(b"xdas0.bit", b"das0.bit"));
+        assert!(!is_suffix_of_parent(b".das0.bit", b"das1.bit"));
+        assert!(!is_suffix_of_parent(b"", b""));
+    }
+
+    #[test]
+    fn test_account_id_and_smt_key() {
+        let account = AccountName::parse("xxxxx.bit").unwrap();
+        let id = account.id();
+        assert_eq!(&blake2b_256(b"xxxxx.bit")[..ACCOUNT_ID_LENGTH], &id[..]);
+        assert_eq!(account_id(b"xxxxx.bit"), id);
+
+        let key = account.smt_key();
+        assert_eq!(&id[..], &key[..ACCOUNT_ID_LENGTH]);
+        assert_eq!([0u8; 12], key[ACCOUNT_ID_LENGTH..]);
+    }
+}
@@ -0,0 +1,130 @@
+//! The premium of the dutch auction of expired accounts, it halves every day since the auction started:
+//!
+//! ```text
+//! premium = start_premium * 2 ^ -(elapsed / 86400)
+//! ```
+//!
+//! Only integers are used, so the contracts and the clients always get the same premium to the last digit. The whole
+//! days are applied by shifting, and the rest of the day is applied with its 17 bits binary fraction, which is enough
+//! to tell every second of a day apart, by multiplying the factors `2 ^ -(2 ^ -i)` with 18 decimals.
+//!
+//! The premium never increases with `elapsed`:
+//!
+//! - in a day, the factor of a larger fraction is smaller by at least 5e-6 while the flooring of the 17 multiplications
+//!   loses less than 1e-16, so the factor strictly decreases;
+//! - at the end of a day the factor is still above 1/2, so `floor(base * factor) >= floor(base / 2)` which is the
+//!   premium at the start of the next day.
+//!
+//! This replaces the floating point premium of the `bid_expired_account_dutch_auction` action, which is a change of
+//! consensus: the floating point premium was raised to the `TABLE_LOWEST_PRICE` of every half a day and then lowered by
+//! the `TABLE_ALLOWED_PRECISION` of every 5 days, which tolerated bids up to 10 USD below it. Both tables are gone,
+//! the bid must pay the premium here to the last digit, so some bids accepted by the tolerance are rejected now.
+
+pub const PREMIUM_HALF_LIFE: u64 = 86400;
+
+const ONE_USD: u128 = 1_000_000;
+const PRECISION: u128 = 1_000_000_000_000_000_000;
+const FRACTION_BITS: u32 = 17;
+// HALVING_FACTORS[i] = floor(2 ^ -(2 ^ -(i + 1)) * PRECISION)
+const HALVING_FACTORS: [u128; FRACTION_BITS as usize] = [
+    707_106_781_186_547_524,
+    840_896_415_253_714_543,
+    917_004_043_204_671_231,
+    957_603_280_698_573_646,
+    978_572_062_087_700_134,
+    989_228_013_193_975_484,
+    994_599_423_483_633_175,
+    997_296_056_085_470_126,
+    998_647_112_890_970_173,
+    999_323_327_502_650_752,
+    999_661_606_496_243_683,
+    999_830_788_931_929_063,
+    999_915_390_886_613_497,
+    999_957_694_548_431_132,
+    999_978_847_050_491_929,
+    999_989_423_469_314_464,
+    999_994_711_720_674_283,
+];
+
+/// The factor of the rest of a day in `PRECISION`.
+fn fraction_factor(seconds: u64) -> u128 {
+    let fraction = ((seconds as u128) << FRACTION_BITS) / PREMIUM_HALF_LIFE as u128;
+
+    let mut factor = PRECISION;
+    for (i, halving_factor) in HALVING_FACTORS.iter().enumerate() {
+        if fraction & (1 << (FRACTION_BITS - 1 - i as u32)) != 0 {
+            factor = factor * halving_factor / PRECISION;
+        }
+    }
+
+    factor
+}
+
+/// Calculate the premium at `elapsed` seconds since the auction started, the `start_premium` is in USD like
+/// `ConfigCellAccount.expiration_auction_start_premiums` and the premium is in USD with 6 decimals.
+pub fn dutch_auction_premium(elapsed: u64, start_premium: u32) -> u64 {
+    let days = elapsed / PREMIUM_HALF_LIFE;
+    if days >= 64 {
+        return 0;
+    }
+
+    let base = (start_premium as u128 * ONE_USD) >> days;
+    let premium = base * fraction_factor(elapsed % PREMIUM_HALF_LIFE) / PRECISION;
+
+    // The premium is never larger than u32::MAX * ONE_USD, so it always fits in u64.
+    premium as u64
+}
+
+/// Generate the `(elapsed, premium)` of every `step` seconds in `[0, auction_period]`.
+pub fn dutch_auction_schedule(start_premium: u32, auction_period: u64, step: u64) -> impl Iterator<Item = (u64, u64)> {
+    (0..=auction_period)
+        .step_by(step.max(1) as usize)
+        .map(move |elapsed| (elapsed, dutch_auction_premium(elapsed, start_premium)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_dutch_auction_premium() {
+        let start_premium = 100_000_000;
+        assert_eq!(100_000_000_000_000, dutch_auction_premium(0, start_premium));
+        assert_eq!(
+            70_710_678_118_654,
+            dutch_auction_premium(PREMIUM_HALF_LIFE / 2, start_premium)
+        );
+        assert_eq!(
+            50_000_000_000_000,
+            dutch_auction_premium(PREMIUM_HALF_LIFE, start_premium)
+        );
+        assert_eq!(
+            50_000_000_000_000 >> 26,
+            dutch_auction_premium(27 * PREMIUM_HALF_LIFE, start_premium)
+        );
+        assert_eq!(0, dutch_auction_premium(64 * PREMIUM_HALF_LIFE, start_premium));
+        assert_eq!(u32::MAX as u64 * 1_000_000, dutch_auction_premium(0, u32::MAX));
+        assert_eq!(0, dutch_auction_premium(u64::MAX, u32::MAX));
+    }
+
+    #[test]
+    fn test_dutch_auction_premium_is_monotonic() {
+        let mut last = PRECISION + 1;
+        for seconds in 0..PREMIUM_HALF_LIFE {
+            let factor = fraction_factor(seconds);
+            assert!(
+                factor < last && factor > PRECISION / 2,
+                "The factor at {}s is invalid.",
+                seconds
+            );
+            last = factor;
+        }
+
+        let mut schedule = dutch_auction_schedule(u32::MAX, 28 * PREMIUM_HALF_LIFE, 1);
+        let (_, mut last) = schedule.next().unwrap();
+        for (elapsed, premium) in schedule {
+            assert!(premium <= last, "The premium at {}s increased.", elapsed);
+            last = premium;
+        }
+    }
+}
@@ -0,0 +1,409 @@
+//! Split account names into `AccountChars` by the char sets, with the same rules as the contracts.
+//!
+//! The char sets have the same content as `ConfigCellCharSet*` cells, and the contracts require that:
+//!
+//! - every char of an account is contained by the char set it is marked with;
+//! - an account uses at most one non-global char set, the global ones like emoji and digit can be mixed with any.
+//!
+//! A char may be contained by multiple char sets, like the chars shared by ZhHans and ZhHant, so the non-global char
+//! set is chosen from the ones which contain all the non-global chars of the account.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::{fmt, fs};
+
+use super::constants::{CharSetType, CHAR_SET_LENGTH, WITNESS_LENGTH_BYTES};
+use super::types::{AccountChar, AccountChars};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CharSetError {
+    /// The witness of `ConfigCellCharSet*` can not be parsed.
+    ConfigInvalid(CharSetType),
+    /// The grapheme is not contained by any char set, the offset is the index of its first byte in the name.
+    Undefined { offset: usize, grapheme: String },
+    /// The grapheme is only contained by the non-global char sets which can not be used with the previous ones.
+    Conflict {
+        offset: usize,
+        grapheme: String,
+        char_set_types: Vec<CharSetType>,
+        used: Vec<CharSetType>,
+    },
+}
+
+impl fmt::Display for CharSetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CharSetError::ConfigInvalid(char_set_type) => {
+                write!(f, "the config of CharSet[{}] is invalid", char_set_type)
+            }
+            CharSetError::Undefined { offset, grapheme } => {
+                write!(
+                    f,
+                    "the char {} at {} is not contained by any char set",
+                    grapheme, offset
+                )
+            }
+            CharSetError::Conflict {
+                offset,
+                grapheme,
+                char_set_types,
+                used,
+            } => write!(
+                f,
+                "the char {} at {} is in {:?}, which can not be used together with {:?}",
+                grapheme, offset, char_set_types, used
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CharSetTable {
+    /// The char sets containing every char, in the order of `CharSetType`.
+    chars: HashMap<String, Vec<CharSetType>>,
+    global: [bool; CHAR_SET_LENGTH],
+    max_char_length: usize,
+}
+
+impl CharSetTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load the lists from the directory which contains files named like `char_set_en.txt`, the digit list is named
+    /// `char_set_digit_and_symbol.txt`. The emoji and digit char sets are global like the ones on chain.
+    pub fn from_dir(dir: impl AsRef<Path>) -> std::io::Result<Self> {
+        let mut table = Self::new();
+        for i in 0..CHAR_SET_LENGTH as u32 {
+            let char_set_type = CharSetType::try_from(i).unwrap();
+            let name = match char_set_type {
+                CharSetType::Digit => String::from("digit_and_symbol"),
+                _ => file_name(char_set_type),
+            };
+            let path = dir.as_ref().join(format!("char_set_{}.txt", name));
+            if path.exists() {
+                let global = matches!(char_set_type, CharSetType::Emoji | CharSetType::Digit);
+                table.add_list(char_set_type, global, &fs::read_to_string(path)?);
+            }
+        }
+
+        Ok(table)
+    }
+
+    /// Add a list with one char per line.
+    pub fn add_list(&mut self, char_set_type: CharSetType, global: bool, content: &str) {
+        let chars = content.lines().map(|line| line.trim());
+        self.add_chars(char_set_type, global, chars);
+    }
+
+    /// Add the payload of a `ConfigCellCharSet*` witness, which is LE uint32 length + global flag + chars ending with
+    /// 0x00.
+    pub fn add_config(&mut self, char_set_type: CharSetType, raw: &[u8]) -> Result<(), CharSetError> {
+        let invalid = || CharSetError::ConfigInvalid(char_set_type);
+        let length = raw
+            .get(..WITNESS_LENGTH_BYTES)
+            .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()) as usize)
+            .ok_or_else(invalid)?;
+        if length != raw.len() {
+            return Err(invalid());
+        }
+
+        let global = raw.get(WITNESS_LENGTH_BYTES).ok_or_else(invalid)? == &1;
+        let data = &raw[(WITNESS_LENGTH_BYTES + 1)..];
+        let mut chars = vec![];
+        for bytes in data
+            .split(|byte| *byte == 0)
+            .take(data.iter().filter(|byte| **byte == 0).count())
+        {
+            chars.push(std::str::from_utf8(bytes).map_err(|_| invalid())?);
+        }
+        self.add_chars(char_set_type, global, chars);
+
+        Ok(())
+    }
+
+    fn add_chars<'a>(&mut self, char_set_type: CharSetType, global: bool, chars: impl IntoIterator<Item = &'a str>) {
+        self.global[char_set_type as usize] = global;
+        for char in chars.into_iter().filter(|char| !char.is_empty()) {
+            self.max_char_length = self.max_char_length.max(char.chars().count());
+            let char_set_types = self.chars.entry(char.to_string()).or_default();
+            if !char_set_types.contains(&char_set_type) {
+                char_set_types.push(char_set_type);
+                char_set_types.sort_by_key(|val| *val as u32);
+            }
+        }
+    }
+
+    pub fn is_global(&self, char_set_type: CharSetType) -> bool {
+        self.global[char_set_type as usize]
+    }
+
+    /// Split the name into `AccountChars` by matching the longest char in the char sets, and mark every char with a
+    /// char set which makes the account valid.
+    ///
+    /// A longer char is skipped when the rest of the name can not be split after it, for example `abc` is split into
+    /// `a` and `bc` when the char sets contain `a`, `ab` and `bc`. The char sets do not take part in the backtracking,
+    /// so a name is rejected with `Conflict` if the chars of the longest split have no char set in common.
+    pub fn classify(&self, name: &str) -> Result<AccountChars, CharSetError> {
+        // The ends of the chars in the char sets which may start from the offset.
+        let ends = |offset: usize| -> Vec<usize> {
+            let rest = &name[offset..];
+            rest.char_indices()
+                .skip(1)
+                .map(|(i, _)| i)
+                .chain([rest.len()])
+                .take(self.max_char_length)
+                .filter(|end| self.chars.contains_key(&rest[..*end]))
+                .collect()
+        };
+
+        // Find out the offsets from which the rest of the name can be split, starting from the end of the name.
+        let mut splittable = vec![false; name.len() + 1];
+        splittable[name.len()] = true;
+        for (offset, _) in name.char_indices().rev() {
+            splittable[offset] = ends(offset).iter().any(|end| splittable[offset + end]);
+        }
+        // If the name can not be split at all, the longest match is used to find out the undefined char.
+        let backtracking = splittable[0];
+
+        // Split the name first, every char comes with the char sets containing it.
+        let mut chars = vec![];
+        let mut offset = 0;
+        while offset < name.len() {
+            let rest = &name[offset..];
+            let (end, char_set_types) = ends(offset)
+                .iter()
+                .rev()
+                .find(|end| !backtracking || splittable[offset + **end])
+                .map(|end| (*end, &self.chars[&rest[..*end]]))
+                .ok_or_else(|| {
+                    // When the undefined code point extends the previous one, the grapheme starts from the previous char.
+                    let extending =
+                        rest.chars().next().map(is_extend).unwrap_or(false) || name[..offset].ends_with('\u{200D}');
+                    let offset = match chars.last() {
+                        Some((prev_offset, _, _)) if extending => *prev_offset,
+                        _ => offset,
+                    };
+                    CharSetError::Undefined {
+                        offset,
+                        grapheme: next_grapheme(&name[offset..]).to_string(),
+                    }
+                })?;
+            chars.push((offset, &rest[..end], char_set_types));
+            offset += end;
+        }
+
+        // Then narrow down the non-global char sets which can be used by all the chars.
+        let mut candidates: Option<Vec<CharSetType>> = None;
+        for (offset, char, char_set_types) in chars.iter() {
+            if char_set_types.iter().any(|val| self.is_global(*val)) {
+                continue;
+            }
+            let narrowed: Vec<CharSetType> = match candidates.as_ref() {
+                Some(used) => used
+                    .iter()
+                    .filter(|val| char_set_types.contains(val))
+                    .copied()
+                    .collect(),
+                None => char_set_types.to_vec(),
+            };
+            if narrowed.is_empty() {
+                return Err(CharSetError::Conflict {
+                    offset: *offset,
+                    grapheme: char.to_string(),
+                    char_set_types: char_set_types.to_vec(),
+                    used: candidates.unwrap_or_default(),
+                });
+            }
+            candidates = Some(narrowed);
+        }
+
+        let non_global = candidates.and_then(|val| val.first().copied());
+        let ret = chars
+            .into_iter()
+            .map(|(_, char, char_set_types)| {
+                let char_set_type = char_set_types
+                    .iter()
+                    .find(|val| self.is_global(**val))
+                    .copied()
+                    .or(non_global)
+                    .unwrap();
+                AccountChar {
+                    char_set_type,
+                    char: char.to_string(),
+                }
+            })
+            .collect();
+
+        Ok(ret)
+    }
+}
+
+fn file_name(char_set_type: CharSetType) -> String {
+    let mut ret = String::new();
+    for (i, c) in char_set_type.to_string().chars().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            ret.push('_');
+        }
+        ret.push(c.to_ascii_lowercase());
+    }
+    ret
+}
+
+/// The common chars extending the previous one into a grapheme, like combining marks, variation selectors, emoji
+/// modifiers and ZWJ.
+fn is_extend(c: char) -> bool {
+    matches!(c as u32,
+        0x0300..=0x036F
+        | 0x0E31
+        | 0x0E34..=0x0E3A
+        | 0x0E47..=0x0E4E
+        | 0x1AB0..=0x1AFF
+        | 0x1DC0..=0x1DFF
+        | 0x200D
+        | 0x20D0..=0x20FF
+        | 0xFE00..=0xFE0F
+        | 0xFE20..=0xFE2F
+        | 0x1F3FB..=0x1F3FF
+        | 0xE0020..=0xE007F)
+}
+
+/// Find the grapheme at the start of the text, it is only used for reporting errors, so the rules are simplified.
+fn next_grapheme(text: &str) -> &str {
+    let is_regional_indicator = |c: char| (0x1F1E6..=0x1F1FF).contains(&(c as u32));
+
+    let mut chars = text.char_indices();
+    let first = match chars.next() {
+        Some((_, c)) => c,
+        None => return text,
+    };
+    let mut end = first.len_utf8();
+    let mut prev = first;
+    for (i, c) in chars {
+        let joined = prev == '\u{200D}';
+        let paired = is_regional_indicator(first) && is_regional_indicator(c) && i == first.len_utf8();
+        if !(is_extend(c) || joined || paired) {
+            break;
+        }
+        end = i + c.len_utf8();
+        prev = c;
+    }
+
+    &text[..end]
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn load_table() -> CharSetTable {
+        CharSetTable::from_dir(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../tests/data")).unwrap()
+    }
+
+    fn char_set_types(chars: &AccountChars) -> Vec<CharSetType> {
+        chars.iter().map(|char| char.char_set_type).collect()
+    }
+
+    #[test]
+    fn test_classify() {
+        let table = load_table();
+
+        let chars = table.classify("a1🌈").unwrap();
+        assert_eq!(
+            vec![CharSetType::En, CharSetType::Digit, CharSetType::Emoji],
+            char_set_types(&chars)
+        );
+        assert_eq!("🌈", chars[2].char);
+
+        // The chars shared by ZhHans and ZhHant are marked with the one used by the other chars.
+        let chars = table.classify("中文").unwrap();
+        assert_eq!(vec![CharSetType::ZhHans, CharSetType::ZhHans], char_set_types(&chars));
+        let chars = table.classify("中國").unwrap();
+        assert_eq!(vec![CharSetType::ZhHant, CharSetType::ZhHant], char_set_types(&chars));
+
+        assert_eq!(Ok(vec![]), table.classify(""));
+    }
+
+    #[test]
+    fn test_classify_errors() {
+        let table = load_table();
+
+        assert_eq!(
+            Err(CharSetError::Undefined {
+                offset: 1,
+                grapheme: String::from(".")
+            }),
+            table.classify("a.b")
+        );
+        // The whole grapheme is reported even if it has multiple code points.
+        assert_eq!(
+            Err(CharSetError::Undefined {
+                offset: 1,
+                grapheme: String::from("e\u{301}")
+            }),
+            table.classify("ae\u{301}")
+        );
+
+        match table.classify("ab中") {
+            Err(CharSetError::Conflict {
+                offset, grapheme, used, ..
+            }) => {
+                assert_eq!(2, offset);
+                assert_eq!("中", grapheme);
+                assert_eq!(vec![CharSetType::En, CharSetType::Tr, CharSetType::Vi], used);
+            }
+            ret => panic!("The classification should fail with conflict, but {:?} returned.", ret),
+        }
+    }
+
+    #[test]
+    fn test_add_config() {
+        let mut raw = vec![0u8; 4];
+        raw.push(1);
+        for char in ["👨‍👩‍👧", "👨"] {
+            raw.extend(char.as_bytes());
+            raw.push(0);
+        }
+        let length = raw.len() as u32;
+        raw[..4].copy_from_slice(&length.to_le_bytes());
+
+        let mut table = CharSetTable::new();
+        table.add_config(CharSetType::Emoji, &raw).unwrap();
+        table.add_list(CharSetType::En, false, "a");
+        assert!(table.is_global(CharSetType::Emoji));
+
+        // The longest char is matched first, like emoji with multiple code points.
+        let chars = table.classify("👨‍👩‍👧👨a").unwrap();
+        assert_eq!(
+            vec!["👨‍👩‍👧", "👨", "a"],
+            chars.iter().map(|char| char.char.as_str()).collect::<Vec<_>>()
+        );
+
+        // A shorter char is used when the longest one leaves the rest of the name unsplittable.
+        table.add_list(CharSetType::En, false, "ab\nbc");
+        let chars = table.classify("abc").unwrap();
+        assert_eq!(
+            vec!["a", "bc"],
+            chars.iter().map(|char| char.char.as_str()).collect::<Vec<_>>()
+        );
+        let chars = table.classify("abab").unwrap();
+        assert_eq!(
+            vec!["ab", "ab"],
+            chars.iter().map(|char| char.char.as_str()).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Err(CharSetError::Undefined {
+                offset: 2,
+                grapheme: String::from("c")
+            }),
+            table.classify("abcc")
+        );
+
+        assert_eq!(
+            Err(CharSetError::ConfigInvalid(CharSetType::Ru)),
+            table.add_config(CharSetType::Ru, &raw[1..])
+        );
+    }
+}
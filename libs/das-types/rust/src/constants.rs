@@ -36,6 +36,7 @@ pub enum SystemStatus {
 }
 
 pub const ACCOUNT_ID_LENGTH: usize = 20;
+pub const ACCOUNT_SUFFIX: &str = ".bit";
 pub const PRESERVED_ACCOUNT_CELL_COUNT: u8 = 20;
 
 pub const CKB_HASH_DIGEST: usize = 32;
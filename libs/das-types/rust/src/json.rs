@@ -0,0 +1,1015 @@
+//! Serde (de)serialization of the molecule types, so the cells and witnesses can be converted from/to JSON without
+//! hand-written encoders.
+//!
+//! The JSON follows the schemas one to one:
+//!
+//! - `Uint8` and `Uint32` are numbers, `Uint64` is always a decimal string since JavaScript can not hold all of its
+//!   values, both forms are accepted when deserializing;
+//! - fields of type `byte` are numbers;
+//! - `Bytes` and fixed-size byte arrays like `Hash` and `AccountId` are 0x-prefixed hex strings;
+//! - vectors are arrays, options are `null` or the inner value;
+//! - tables and structs are objects with the field names in the schemas, unknown and missing fields are rejected.
+//!
+//! For example `serde_json::from_value::<packed::AccountCellData>(json)` builds the same entity as the builder does.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use molecule::prelude::*;
+use serde::de::{self, Deserialize, Deserializer, MapAccess, Unexpected, Visitor};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+use super::schemas::packed::*;
+
+struct UintVisitor<T>(PhantomData<T>);
+
+impl<'de, T: TryFrom<u64> + FromStr> Visitor<'de> for UintVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "an unsigned integer or a decimal string of {} bytes",
+            core::mem::size_of::<T>()
+        )
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<T, E> {
+        T::try_from(v).map_err(|_| E::invalid_value(Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        // The `parse` accepts a leading `+` which is not a decimal string.
+        Some(v)
+            .filter(|v| !v.is_empty() && v.bytes().all(|val| val.is_ascii_digit()))
+            .and_then(|v| v.parse::<T>().ok())
+            .ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+struct HexVisitor;
+
+impl<'de> Visitor<'de> for HexVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a 0x-prefixed hex string")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Vec<u8>, E> {
+        v.strip_prefix("0x")
+            .and_then(|v| hex::decode(v).ok())
+            .ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+fn serialize_hex<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&format!("0x{}", hex::encode(bytes)))
+}
+
+macro_rules! impl_serde_uint {
+    ($mol_type:ident, $uint_type:ty) => {
+        impl Serialize for $mol_type {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_u64(u64::from(<$uint_type>::from(self.as_reader())))
+            }
+        }
+
+        impl_serde_uint!(@deserialize $mol_type, $uint_type);
+    };
+    ($mol_type:ident, $uint_type:ty, string) => {
+        impl Serialize for $mol_type {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&<$uint_type>::from(self.as_reader()).to_string())
+            }
+        }
+
+        impl_serde_uint!(@deserialize $mol_type, $uint_type);
+    };
+    (@deserialize $mol_type:ident, $uint_type:ty) => {
+        impl<'de> Deserialize<'de> for $mol_type {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer
+                    .deserialize_any(UintVisitor::<$uint_type>(PhantomData))
+                    .map(Self::from)
+            }
+        }
+    };
+}
+
+/// Implement for the fixed-size byte arrays, the length of the hex string is checked when deserializing.
+macro_rules! impl_serde_byte_array {
+    ($($name:ident),+) => {$(
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize_hex(self.as_slice(), serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let bytes = deserializer.deserialize_str(HexVisitor)?;
+                Self::from_slice(&bytes).map_err(|_| {
+                    de::Error::invalid_length(bytes.len(), &concat!("the bytes length of ", stringify!($name)))
+                })
+            }
+        }
+    )+};
+}
+
+macro_rules! impl_serde_vector {
+    ($($name:ident),+) => {$(
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_seq(self.clone().into_iter())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                Ok(Self::new_builder().set(Deserialize::deserialize(deserializer)?).build())
+            }
+        }
+    )+};
+}
+
+macro_rules! impl_serde_option {
+    ($($name:ident),+) => {$(
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.to_opt().serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                Ok(Self::new_builder().set(Deserialize::deserialize(deserializer)?).build())
+            }
+        }
+    )+};
+}
+
+/// Implement for tables and structs, the fields of type `byte` should be marked with `: byte` because `Byte` is
+/// defined in molecule.
+macro_rules! impl_serde_table {
+    ($name:ident { $($field:ident $(: $kind:ident)?),+ $(,)? }) => {
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let fields = [$(stringify!($field)),+];
+                let mut state = serializer.serialize_struct(stringify!($name), fields.len())?;
+                $(state.serialize_field(stringify!($field), &impl_serde_table!(@get self.$field() $(, $kind)?))?;)+
+                state.end()
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                const FIELDS: &[&str] = &[$(stringify!($field)),+];
+
+                struct TableVisitor;
+
+                impl<'de> Visitor<'de> for TableVisitor {
+                    type Value = $name;
+
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        write!(f, "struct {}", stringify!($name))
+                    }
+
+                    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<$name, A::Error> {
+                        let mut builder = $name::new_builder();
+                        let mut found = [false; FIELDS.len()];
+                        while let Some(key) = map.next_key::<String>()? {
+                            let i = FIELDS
+                                .iter()
+                                .position(|field| *field == key)
+                                .ok_or_else(|| de::Error::unknown_field(&key, FIELDS))?;
+                            if found[i] {
+                                return Err(de::Error::duplicate_field(FIELDS[i]));
+                            }
+                            found[i] = true;
+
+                            builder = match FIELDS[i] {
+                                $(stringify!($field) => builder.$field(impl_serde_table!(@next map $(, $kind)?)),)+
+                                _ => unreachable!(),
+                            };
+                        }
+                        if let Some(i) = found.iter().position(|found| !found) {
+                            return Err(de::Error::missing_field(FIELDS[i]));
+                        }
+
+                        Ok(builder.build())
+                    }
+                }
+
+                deserializer.deserialize_struct(stringify!($name), FIELDS, TableVisitor)
+            }
+        }
+    };
+    (@get $value:expr) => {
+        $value
+    };
+    (@get $value:expr, byte) => {
+        $value.as_slice()[0]
+    };
+    (@next $map:ident) => {
+        $map.next_value()?
+    };
+    (@next $map:ident, byte) => {
+        Byte::new($map.next_value::<u8>()?)
+    };
+}
+
+impl_serde_uint!(Uint8, u8);
+impl_serde_uint!(Uint32, u32);
+impl_serde_uint!(Uint64, u64, string);
+
+impl_serde_byte_array!(Byte10, Hash, AccountId);
+
+impl Serialize for Bytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_hex(&self.raw_data(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Bytes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(HexVisitor).map(Self::from)
+    }
+}
+
+// basic.mol
+impl_serde_table!(Script {
+    code_hash,
+    hash_type: byte,
+    args
+});
+impl_serde_table!(OutPoint { tx_hash, index });
+impl_serde_table!(Data { dep, old, new });
+impl_serde_table!(DataEntity { index, version, entity });
+impl_serde_vector!(BytesVec, Scripts);
+impl_serde_option!(ScriptOpt, DataEntityOpt);
+// cell.mol
+impl_serde_table!(ActionData { action, params });
+impl_serde_table!(ConfigCellMain {
+    status,
+    type_id_table,
+    das_lock_out_point_table,
+    das_lock_type_id_table
+});
+impl_serde_table!(TypeIdTable {
+    account_cell,
+    apply_register_cell,
+    balance_cell,
+    income_cell,
+    pre_account_cell,
+    proposal_cell,
+    account_sale_cell,
+    account_auction_cell,
+    offer_cell,
+    reverse_record_cell,
+    sub_account_cell,
+    eip712_lib,
+    reverse_record_root_cell,
+    key_list_config_cell,
+    dpoint_cell,
+});
+impl_serde_table!(DasLockOutPointTable {
+    ckb_signall,
+    ckb_multisign,
+    ckb_anyone_can_pay,
+    eth,
+    tron,
+    ed25519,
+    web_authn,
+});
+impl_serde_table!(DasLockTypeIdTable {
+    ckb_signhash,
+    ckb_multisig,
+    ed25519,
+    eth,
+    tron,
+    doge,
+    web_authn
+});
+impl_serde_table!(ConfigCellAccount {
+    max_length,
+    basic_capacity,
+    prepared_fee_capacity,
+    expiration_grace_period,
+    record_min_ttl,
+    record_size_limit,
+    transfer_account_fee,
+    edit_manager_fee,
+    edit_records_fee,
+    common_fee,
+    transfer_account_throttle,
+    edit_manager_throttle,
+    edit_records_throttle,
+    common_throttle,
+    expiration_auction_period,
+    expiration_deliver_period,
+    expiration_auction_start_premiums,
+});
+impl_serde_table!(ConfigCellApply {
+    apply_min_waiting_block_number,
+    apply_max_waiting_block_number
+});
+impl_serde_table!(ConfigCellPrice { discount, prices });
+impl_serde_table!(DiscountConfig { invited_discount });
+impl_serde_table!(PriceConfig { length, new, renew });
+impl_serde_table!(ConfigCellProposal {
+    proposal_min_confirm_interval,
+    proposal_min_extend_interval,
+    proposal_min_recycle_interval,
+    proposal_max_account_affect,
+    proposal_max_pre_account_contain,
+});
+impl_serde_table!(ConfigCellProfitRate {
+    inviter,
+    channel,
+    proposal_create,
+    proposal_confirm,
+    income_consolidate,
+    sale_buyer_inviter,
+    sale_buyer_channel,
+    sale_das,
+    auction_bidder_inviter,
+    auction_bidder_channel,
+    auction_das,
+    auction_prev_bidder,
+});
+impl_serde_table!(ConfigCellIncome {
+    basic_capacity,
+    max_records,
+    min_transfer_capacity
+});
+impl_serde_table!(ConfigCellRelease { lucky_number });
+impl_serde_table!(ConfigCellSecondaryMarket {
+    common_fee,
+    sale_min_price,
+    sale_expiration_limit,
+    sale_description_bytes_limit,
+    sale_cell_basic_capacity,
+    sale_cell_prepared_fee_capacity,
+    auction_max_extendable_duration,
+    auction_duration_increment_each_bid,
+    auction_min_opening_price,
+    auction_min_increment_rate_each_bid,
+    auction_description_bytes_limit,
+    auction_cell_basic_capacity,
+    auction_cell_prepared_fee_capacity,
+    offer_min_price,
+    offer_cell_basic_capacity,
+    offer_cell_prepared_fee_capacity,
+    offer_message_bytes_limit,
+});
+impl_serde_table!(ConfigCellReverseResolution {
+    record_basic_capacity,
+    record_prepared_fee_capacity,
+    common_fee
+});
+impl_serde_table!(ConfigCellSubAccount {
+    basic_capacity,
+    prepared_fee_capacity,
+    new_sub_account_price,
+    renew_sub_account_price,
+    common_fee,
+    create_fee,
+    edit_fee,
+    renew_fee,
+    recycle_fee,
+    new_sub_account_custom_price_das_profit_rate,
+    renew_sub_account_custom_price_das_profit_rate,
+});
+impl_serde_table!(ConfigCellSystemStatus {
+    apply_register_cell_type,
+    pre_account_cell_type,
+    proposal_cell_type,
+    config_cell_type,
+    account_cell_type,
+    account_sale_cell_type,
+    sub_account_cell_type,
+    offer_cell_type,
+    balance_cell_type,
+    income_cell_type,
+    reverse_record_cell_type,
+    reverse_record_root_cell_type,
+    eip712_lib,
+    key_list_config_cell_type,
+    dpoint_cell_type,
+});
+impl_serde_table!(ContractStatus { status: byte, version });
+impl_serde_table!(ConfigCellDPoint {
+    basic_capacity,
+    prepared_fee_capacity,
+    transfer_whitelist,
+    capacity_recycle_whitelist,
+});
+impl_serde_table!(ProposalCellData {
+    proposer_lock,
+    created_at_height,
+    slices
+});
+impl_serde_table!(ProposalItem {
+    account_id,
+    item_type,
+    next
+});
+impl_serde_table!(IncomeCellData { creator, records });
+impl_serde_table!(IncomeRecord { belong_to, capacity });
+impl_serde_table!(AccountCellData {
+    id,
+    account,
+    registered_at,
+    last_transfer_account_at,
+    last_edit_manager_at,
+    last_edit_records_at,
+    status,
+    records,
+    enable_sub_account,
+    renew_sub_account_price,
+    approval,
+});
+impl_serde_table!(Record {
+    record_type,
+    record_key,
+    record_label,
+    record_value,
+    record_ttl
+});
+impl_serde_table!(AccountApproval { action, params });
+impl_serde_table!(AccountApprovalTransfer {
+    platform_lock,
+    protected_until,
+    sealed_until,
+    delay_count_remain,
+    to_lock,
+});
+impl_serde_table!(AccountSaleCellDataV1 {
+    account_id,
+    account,
+    price,
+    description,
+    started_at
+});
+impl_serde_table!(AccountSaleCellData {
+    account_id,
+    account,
+    price,
+    description,
+    started_at,
+    buyer_inviter_profit_rate,
+});
+impl_serde_table!(AccountAuctionCellData {
+    account_id,
+    account,
+    description,
+    opening_price,
+    increment_rate_each_bid,
+    started_at,
+    ended_at,
+    current_bidder_lock,
+    current_bid_price,
+    prev_bidder_profit_rate,
+});
+impl_serde_table!(PreAccountCellData {
+    account,
+    refund_lock,
+    owner_lock_args,
+    inviter_id,
+    inviter_lock,
+    channel_lock,
+    price,
+    quote,
+    invited_discount,
+    created_at,
+    initial_records,
+    initial_cross_chain,
+});
+impl_serde_table!(ChainId {
+    checked,
+    coin_type,
+    chain_id
+});
+impl_serde_table!(AccountChar { char_set_name, bytes });
+impl_serde_table!(OfferCellData {
+    account,
+    price,
+    message,
+    inviter_lock,
+    channel_lock
+});
+impl_serde_table!(SubAccount {
+    lock,
+    id,
+    account,
+    suffix,
+    registered_at,
+    expired_at,
+    status,
+    records,
+    nonce,
+    enable_sub_account,
+    renew_sub_account_price,
+    approval,
+});
+impl_serde_table!(SubAccountRule {
+    index,
+    name,
+    note,
+    price,
+    ast,
+    status
+});
+impl_serde_table!(ASTExpression {
+    expression_type: byte,
+    expression
+});
+impl_serde_table!(ASTOperator {
+    symbol: byte,
+    expressions
+});
+impl_serde_table!(ASTFunction { name: byte, arguments });
+impl_serde_table!(ASTVariable { name: byte });
+impl_serde_table!(ASTValue {
+    value_type: byte,
+    value
+});
+impl_serde_table!(DeviceKey {
+    main_alg_id,
+    sub_alg_id,
+    cid,
+    pubkey
+});
+impl_serde_table!(DeviceKeyListCellData { keys, refund_lock });
+impl_serde_table!(MapEntry { key, value });
+impl_serde_table!(Config { status, configs });
+impl_serde_table!(OrderInfo { memo });
+impl_serde_vector!(
+    Chars,
+    PriceConfigList,
+    SliceList,
+    SL,
+    IncomeRecords,
+    Records,
+    AccountChars,
+    SubAccountRules,
+    ASTExpressions,
+    DeviceKeyList,
+    ConfigList
+);
+// cell_v1.mol
+impl_serde_table!(AccountCellDataV1 {
+    id,
+    account,
+    registered_at,
+    updated_at,
+    status,
+    records
+});
+impl_serde_table!(PreAccountCellDataV1 {
+    account,
+    refund_lock,
+    owner_lock_args,
+    inviter_id,
+    inviter_lock,
+    channel_lock,
+    price,
+    quote,
+    invited_discount,
+    created_at,
+});
+impl_serde_table!(SubAccountV1 {
+    lock,
+    id,
+    account,
+    suffix,
+    registered_at,
+    expired_at,
+    status,
+    records,
+    nonce,
+    enable_sub_account,
+    renew_sub_account_price,
+});
+// cell_v2.mol
+impl_serde_table!(AccountCellDataV2 {
+    id,
+    account,
+    registered_at,
+    last_transfer_account_at,
+    last_edit_manager_at,
+    last_edit_records_at,
+    status,
+    records,
+});
+impl_serde_table!(PreAccountCellDataV2 {
+    account,
+    refund_lock,
+    owner_lock_args,
+    inviter_id,
+    inviter_lock,
+    channel_lock,
+    price,
+    quote,
+    invited_discount,
+    created_at,
+    initial_records,
+});
+// cell_v3.mol
+impl_serde_table!(AccountCellDataV3 {
+    id,
+    account,
+    registered_at,
+    last_transfer_account_at,
+    last_edit_manager_at,
+    last_edit_records_at,
+    status,
+    records,
+    enable_sub_account,
+    renew_sub_account_price,
+});
+// config_history.mol
+impl_serde_table!(ConfigCellMainV1 {
+    status,
+    type_id_table,
+    das_lock_out_point_table
+});
+impl_serde_table!(TypeIdTableV1 {
+    account_cell,
+    apply_register_cell,
+    bidding_cell,
+    income_cell,
+    on_sale_cell,
+    pre_account_cell,
+    proposal_cell,
+});
+impl_serde_table!(DasLockOutPointTableV1 {
+    ckb_signall,
+    ckb_multisign,
+    ckb_anyone_can_pay,
+    eth,
+    tron
+});
+impl_serde_table!(ConfigCellMainV2 {
+    status,
+    type_id_table,
+    das_lock_out_point_table
+});
+impl_serde_table!(TypeIdTableV2 {
+    account_cell,
+    apply_register_cell,
+    balance_cell,
+    income_cell,
+    pre_account_cell,
+    proposal_cell,
+});
+impl_serde_table!(ConfigCellMainV3 {
+    status,
+    type_id_table,
+    das_lock_out_point_table
+});
+impl_serde_table!(TypeIdTableV3 {
+    account_cell,
+    apply_register_cell,
+    balance_cell,
+    income_cell,
+    pre_account_cell,
+    proposal_cell,
+    account_sale_cell,
+    account_auction_cell,
+});
+impl_serde_table!(ConfigCellMainV4 {
+    status,
+    type_id_table,
+    das_lock_out_point_table
+});
+impl_serde_table!(ConfigCellMainV5 {
+    status,
+    type_id_table,
+    das_lock_out_point_table
+});
+impl_serde_table!(TypeIdTableV4 {
+    account_cell,
+    apply_register_cell,
+    balance_cell,
+    income_cell,
+    pre_account_cell,
+    proposal_cell,
+    account_sale_cell,
+    account_auction_cell,
+    offer_cell,
+    reverse_record_cell,
+});
+impl_serde_table!(ConfigCellMainV6 {
+    status,
+    type_id_table,
+    das_lock_out_point_table
+});
+impl_serde_table!(TypeIdTableV5 {
+    account_cell,
+    apply_register_cell,
+    balance_cell,
+    income_cell,
+    pre_account_cell,
+    proposal_cell,
+    account_sale_cell,
+    account_auction_cell,
+    offer_cell,
+    reverse_record_cell,
+    sub_account_cell,
+});
+impl_serde_table!(ConfigCellMainV7 {
+    status,
+    type_id_table,
+    das_lock_out_point_table
+});
+impl_serde_table!(ConfigCellMainV8 {
+    status,
+    type_id_table,
+    das_lock_out_point_table
+});
+impl_serde_table!(TypeIdTableV6 {
+    account_cell,
+    apply_register_cell,
+    balance_cell,
+    income_cell,
+    pre_account_cell,
+    proposal_cell,
+    account_sale_cell,
+    account_auction_cell,
+    offer_cell,
+    reverse_record_cell,
+    sub_account_cell,
+    eip712_lib,
+});
+impl_serde_table!(ConfigCellMainV9 {
+    status,
+    type_id_table,
+    das_lock_out_point_table
+});
+impl_serde_table!(TypeIdTableV7 {
+    account_cell,
+    apply_register_cell,
+    balance_cell,
+    income_cell,
+    pre_account_cell,
+    proposal_cell,
+    account_sale_cell,
+    account_auction_cell,
+    offer_cell,
+    reverse_record_cell,
+    sub_account_cell,
+    eip712_lib,
+    reverse_record_root_cell,
+});
+impl_serde_table!(ConfigCellMainV10 {
+    status,
+    type_id_table,
+    das_lock_out_point_table,
+    das_lock_type_id_table
+});
+impl_serde_table!(ConfigCellMainV11 {
+    status,
+    type_id_table,
+    das_lock_out_point_table,
+    das_lock_type_id_table
+});
+impl_serde_table!(TypeIdTableV8 {
+    account_cell,
+    apply_register_cell,
+    balance_cell,
+    income_cell,
+    pre_account_cell,
+    proposal_cell,
+    account_sale_cell,
+    account_auction_cell,
+    offer_cell,
+    reverse_record_cell,
+    sub_account_cell,
+    eip712_lib,
+    reverse_record_root_cell,
+    key_list_config_cell,
+});
+impl_serde_table!(ConfigCellAccountV1 {
+    max_length,
+    basic_capacity,
+    prepared_fee_capacity,
+    expiration_grace_period,
+    record_min_ttl,
+    record_size_limit,
+    transfer_account_fee,
+    edit_manager_fee,
+    edit_records_fee,
+    transfer_account_throttle,
+    edit_manager_throttle,
+    edit_records_throttle,
+});
+impl_serde_table!(ConfigCellAccountV2 {
+    max_length,
+    basic_capacity,
+    prepared_fee_capacity,
+    expiration_grace_period,
+    record_min_ttl,
+    record_size_limit,
+    transfer_account_fee,
+    edit_manager_fee,
+    edit_records_fee,
+    transfer_account_throttle,
+    edit_manager_throttle,
+    edit_records_throttle,
+    common_throttle,
+});
+impl_serde_table!(ConfigCellProfitRateV1 {
+    inviter,
+    channel,
+    proposal_create,
+    proposal_confirm,
+    income_consolidate
+});
+impl_serde_table!(ConfigCellProfitRateV2 {
+    inviter,
+    channel,
+    proposal_create,
+    proposal_confirm,
+    income_consolidate,
+    sale_inviter,
+    sale_channel,
+    sale_das,
+});
+impl_serde_table!(ConfigCellSecondaryMarketV1 {
+    min_sale_price,
+    sale_expiration_limit,
+    sale_description_bytes_limit
+});
+impl_serde_table!(ConfigCellSecondaryMarketV2 {
+    sale_min_price,
+    sale_expiration_limit,
+    sale_description_bytes_limit,
+    auction_max_extendable_duration,
+    auction_duration_increment_each_bid,
+    auction_min_opening_price,
+    auction_min_increment_rate_each_bid,
+    auction_description_bytes_limit,
+});
+impl_serde_table!(ConfigCellSubAccountV1 {
+    basic_capacity,
+    prepared_fee_capacity,
+    new_sub_account_price,
+    renew_sub_account_price,
+    common_fee,
+    create_fee,
+    edit_fee,
+    renew_fee,
+    recycle_fee,
+});
+impl_serde_table!(ConfigCellSystemStatusV1 {
+    apply_register_cell_type,
+    pre_account_cell_type,
+    proposal_cell_type,
+    config_cell_type,
+    account_cell_type,
+    account_sale_cell_type,
+    sub_account_cell_type,
+    offer_cell_type,
+    balance_cell_type,
+    income_cell_type,
+    reverse_record_cell_type,
+    reverse_record_root_cell_type,
+    eip712_lib,
+});
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    fn script(args: &str) -> serde_json::Value {
+        json!({
+            "code_hash": format!("0x{}", "01".repeat(32)),
+            "hash_type": 1,
+            "args": args,
+        })
+    }
+
+    #[test]
+    fn test_account_cell_data_round_trip() {
+        let value = json!({
+            "id": "0x0000000000000000000000000000000000000001",
+            "account": [
+                { "char_set_name": 2, "bytes": "0x61" },
+                { "char_set_name": 1, "bytes": "0x31" },
+            ],
+            "registered_at": "1611200000",
+            "last_transfer_account_at": "0",
+            "last_edit_manager_at": "0",
+            "last_edit_records_at": "0",
+            "status": 0,
+            "records": [{
+                "record_type": "0x61646472657373",
+                "record_key": "0x3630",
+                "record_label": "0x",
+                "record_value": "0xabcdef",
+                "record_ttl": 300,
+            }],
+            "enable_sub_account": 1,
+            "renew_sub_account_price": "100000000000",
+            "approval": { "action": "0x", "params": "0x" },
+        });
+
+        let data: AccountCellData = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(2, data.account().len());
+        assert_eq!(100_000_000_000u64, u64::from(data.renew_sub_account_price()));
+        assert_eq!(
+            b"address",
+            data.records().get(0).unwrap().record_type().raw_data().as_ref()
+        );
+        assert_eq!(value, serde_json::to_value(&data).unwrap());
+
+        // The result should be the same as the molecule encoding.
+        let decoded = AccountCellData::from_slice(data.as_slice()).unwrap();
+        assert_eq!(value, serde_json::to_value(&decoded).unwrap());
+    }
+
+    #[test]
+    fn test_options_and_byte_fields() {
+        let proposal: ProposalCellData = serde_json::from_value(json!({
+            "proposer_lock": script("0x"),
+            "created_at_height": 10,
+            "slices": [[{
+                "account_id": format!("0x{}", "00".repeat(20)),
+                "item_type": 1,
+                "next": format!("0x{}", "ff".repeat(20)),
+            }]],
+        }))
+        .unwrap();
+        assert_eq!(Byte::new(1), proposal.proposer_lock().hash_type());
+        assert_eq!(1, proposal.slices().get(0).unwrap().len());
+
+        let income: IncomeCellData = serde_json::from_value(json!({
+            "creator": script("0x01"),
+            "records": [{ "belong_to": script("0x02"), "capacity": 20_000_000_000u64 }],
+        }))
+        .unwrap();
+        assert_eq!(
+            json!("20000000000"),
+            serde_json::to_value(&income).unwrap()["records"][0]["capacity"]
+        );
+
+        let opt: ScriptOpt = serde_json::from_value(json!(null)).unwrap();
+        assert!(opt.is_none());
+        let opt: ScriptOpt = serde_json::from_value(script("0x")).unwrap();
+        assert_eq!(script("0x"), serde_json::to_value(&opt).unwrap());
+    }
+
+    #[test]
+    fn test_versioned_config_cells() {
+        let hash = format!("0x{}", "00".repeat(32));
+        let out_point = json!({ "tx_hash": hash, "index": 0 });
+        let value = json!({
+            "status": 1,
+            "type_id_table": {
+                "account_cell": hash,
+                "apply_register_cell": hash,
+                "bidding_cell": hash,
+                "income_cell": hash,
+                "on_sale_cell": hash,
+                "pre_account_cell": hash,
+                "proposal_cell": hash,
+            },
+            "das_lock_out_point_table": {
+                "ckb_signall": out_point,
+                "ckb_multisign": out_point,
+                "ckb_anyone_can_pay": out_point,
+                "eth": out_point,
+                "tron": out_point,
+            },
+        });
+
+        let config: ConfigCellMainV1 = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(value, serde_json::to_value(&config).unwrap());
+
+        // The tables are changed in the latest version, so the JSON of the V1 is rejected.
+        assert!(serde_json::from_value::<ConfigCellMain>(value).is_err());
+    }
+
+    #[test]
+    fn test_invalid_values() {
+        let err = serde_json::from_value::<Uint8>(json!(256)).unwrap_err();
+        assert!(err.to_string().contains("invalid value"), "{}", err);
+        assert_eq!(255u8, u8::from(serde_json::from_value::<Uint8>(json!("255")).unwrap()));
+        assert_eq!(json!(255), serde_json::to_value(Uint8::from(255u8)).unwrap());
+        assert_eq!(json!("1"), serde_json::to_value(Uint64::from(1u64)).unwrap());
+        assert!(serde_json::from_value::<Uint64>(json!("+1")).is_err());
+
+        let err = serde_json::from_value::<AccountId>(json!("0x0011")).unwrap_err();
+        assert!(err.to_string().contains("invalid length 2"), "{}", err);
+        assert!(serde_json::from_value::<Bytes>(json!("0011")).is_err());
+        assert!(serde_json::from_value::<Bytes>(json!("0x0g")).is_err());
+
+        let err = serde_json::from_value::<ActionData>(json!({ "action": "0x" })).unwrap_err();
+        assert!(err.to_string().contains("missing field `params`"), "{}", err);
+    }
+}
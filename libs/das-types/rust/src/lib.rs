@@ -2,13 +2,28 @@
 #[cfg(feature = "no_std")]
 extern crate alloc;
 
+pub mod account;
+pub mod auction;
+#[cfg(feature = "std")]
+pub mod charset;
 pub mod constants;
 pub mod convert;
 pub mod data_parser;
+#[cfg(feature = "std")]
+pub mod json;
 pub mod mixer;
+#[cfg(feature = "std")]
+pub mod network;
 pub mod prettier;
+#[cfg(feature = "std")]
+pub mod prettier_json;
+#[cfg(feature = "std")]
+pub mod schema;
 pub mod types;
+pub mod upgrade;
 pub mod util;
+#[cfg(not(feature = "no_std"))]
+pub mod witness;
 
 mod schemas;
 
@@ -0,0 +1,422 @@
+//! Deployment constants which can be loaded at runtime.
+//!
+//! The functions like `constants::das_lock` read the `.env` file at compile time, so one build targets exactly one
+//! network. The off-chain services can use `NetworkProfile` instead, it contains the same values and can be loaded from
+//! a TOML, JSON or `.env` file at runtime, or from the presets which are the `.env.*` files beside the `.env` file, they
+//! are embedded by the build script.
+//!
+//! The TOML looks like:
+//!
+//! ```toml
+//! network = "mainnet"
+//! config_cell_type_id = "0x903bff0221b72b2f5d549236b631234b294f10f53e6cc7328af07776e32a6640"
+//! # ... the other type IDs
+//!
+//! [super_lock]
+//! code_hash = "0x5c5069eb0857efc65e1bca0c07df34c31663b3622fd3876c876320fc9634e2a8"
+//! args = "0xc126635ece567c71c50f7482c5db80603852c306"
+//! # ... the other special locks
+//! ```
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
+use std::{fmt, fs};
+
+use ckb_types::core::ScriptHashType;
+use ckb_types::packed::Byte;
+use molecule::prelude::{Builder, Entity};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+
+use super::schemas::packed::{Bytes, Hash, Script};
+
+include!(concat!(env!("OUT_DIR"), "/presets.rs"));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumString, Display, Serialize, Deserialize)]
+#[strum(serialize_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum Network {
+    Mainnet,
+    Testnet2,
+    Testnet3,
+    Dev,
+    Custom,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetworkProfileError {
+    Io(String),
+    Syntax(String),
+    FieldMissing(&'static str),
+    FieldInvalid(&'static str),
+    PresetMissing(Network),
+}
+
+impl fmt::Display for NetworkProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NetworkProfileError::Io(reason) => write!(f, "reading the profile failed: {}", reason),
+            NetworkProfileError::Syntax(reason) => write!(f, "parsing the profile failed: {}", reason),
+            NetworkProfileError::FieldMissing(field) => write!(f, "the field {} is missing", field),
+            NetworkProfileError::FieldInvalid(field) => write!(f, "the field {} is invalid", field),
+            NetworkProfileError::PresetMissing(network) => write!(f, "the preset of {} is missing", network),
+        }
+    }
+}
+
+/// A lock script whose hash type is always `type`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LockConfig {
+    #[serde(with = "hex_array")]
+    pub code_hash: [u8; 32],
+    #[serde(with = "hex_vec")]
+    pub args: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NetworkProfile {
+    pub network: Network,
+    #[serde(with = "hex_array")]
+    pub config_cell_type_id: [u8; 32],
+    #[serde(with = "hex_array")]
+    pub oracle_cell_type_id: [u8; 32],
+    #[serde(with = "hex_array")]
+    pub das_lock_type_id: [u8; 32],
+    #[serde(with = "hex_array")]
+    pub always_success_lock_type_id: [u8; 32],
+    #[serde(with = "hex_array")]
+    pub signhash_lock_type_id: [u8; 32],
+    #[serde(with = "hex_array")]
+    pub multisig_lock_type_id: [u8; 32],
+    pub super_lock: LockConfig,
+    pub wallet_lock: LockConfig,
+    pub cross_chain_lock: LockConfig,
+    pub profit_manager_lock: LockConfig,
+}
+
+impl NetworkProfile {
+    /// The profile this crate is compiled with, it is the same as the functions in `constants`.
+    pub fn compiled() -> Result<Self, NetworkProfileError> {
+        let vars = [
+            ("NETWORK", env!("NETWORK")),
+            ("CONFIG_CELL_TYPE_ID", env!("CONFIG_CELL_TYPE_ID")),
+            ("ORACLE_CELL_TYPE_ID", env!("ORACLE_CELL_TYPE_ID")),
+            ("DAS_LOCK_TYPE_ID", env!("DAS_LOCK_TYPE_ID")),
+            ("ALWAYS_SUCCESS_LOCK_TYPE_ID", env!("ALWAYS_SUCCESS_LOCK_TYPE_ID")),
+            ("SIGNHASH_LOCK_TYPE_ID", env!("SIGNHASH_LOCK_TYPE_ID")),
+            ("MULTISIG_LOCK_TYPE_ID", env!("MULTISIG_LOCK_TYPE_ID")),
+            ("SUPER_CODE_HASH", env!("SUPER_CODE_HASH")),
+            ("SUPER_ARGS", env!("SUPER_ARGS")),
+            ("WALLET_CODE_HASH", env!("WALLET_CODE_HASH")),
+            ("WALLET_ARGS", env!("WALLET_ARGS")),
+            ("CROSS_CHAIN_CODE_HASH", env!("CROSS_CHAIN_CODE_HASH")),
+            ("CROSS_CHAIN_ARGS", env!("CROSS_CHAIN_ARGS")),
+            ("PROFIT_MANAGER_CODE_HASH", env!("PROFIT_MANAGER_CODE_HASH")),
+            ("PROFIT_MANAGER_ARGS", env!("PROFIT_MANAGER_ARGS")),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect();
+
+        Self::from_env_vars(&vars)
+    }
+
+    /// The profiles shipped with this repo, `Network::Custom` has no preset, and the others are missing if their
+    /// `.env.*` files are not found when building this crate.
+    pub fn preset(network: Network) -> Result<Self, NetworkProfileError> {
+        let text = match network {
+            Network::Mainnet => PRESET_MAINNET,
+            Network::Testnet2 => PRESET_TESTNET2,
+            Network::Testnet3 => PRESET_TESTNET3,
+            Network::Dev => PRESET_DEV,
+            Network::Custom => None,
+        };
+
+        Self::from_env_str(text.ok_or(NetworkProfileError::PresetMissing(network))?)
+    }
+
+    pub fn from_toml_str(text: &str) -> Result<Self, NetworkProfileError> {
+        toml::from_str(text).map_err(|err| NetworkProfileError::Syntax(err.to_string()))
+    }
+
+    pub fn from_json_str(text: &str) -> Result<Self, NetworkProfileError> {
+        serde_json::from_str(text).map_err(|err| NetworkProfileError::Syntax(err.to_string()))
+    }
+
+    /// Parse the `.env` format used by the build script, `${VAR}` can refer to the variables defined before it.
+    pub fn from_env_str(text: &str) -> Result<Self, NetworkProfileError> {
+        let mut vars = HashMap::new();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| NetworkProfileError::Syntax(format!("line {} is not KEY=VALUE", i + 1)))?;
+            let value = value.trim().trim_matches('"');
+            let value = match value.strip_prefix("${").and_then(|val| val.strip_suffix('}')) {
+                Some(name) => vars.get(name).cloned().ok_or_else(|| {
+                    NetworkProfileError::Syntax(format!("line {} refers to undefined {}", i + 1, name))
+                })?,
+                None => value.to_string(),
+            };
+            vars.insert(key.trim().to_string(), value);
+        }
+
+        Self::from_env_vars(&vars)
+    }
+
+    /// Load the profile by the extension of the file, `.toml`, `.json` or `.env*` are supported.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, NetworkProfileError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|err| NetworkProfileError::Io(err.to_string()))?;
+        let name = path.file_name().and_then(|val| val.to_str()).unwrap_or_default();
+
+        match path.extension().and_then(|val| val.to_str()) {
+            Some("toml") => Self::from_toml_str(&text),
+            Some("json") => Self::from_json_str(&text),
+            _ if name.starts_with(".env") => Self::from_env_str(&text),
+            _ => Err(NetworkProfileError::Io(format!(
+                "the format of {} is unknown",
+                path.display()
+            ))),
+        }
+    }
+
+    fn from_env_vars(vars: &HashMap<String, String>) -> Result<Self, NetworkProfileError> {
+        fn get<'a>(vars: &'a HashMap<String, String>, key: &'static str) -> Result<&'a str, NetworkProfileError> {
+            vars.get(key)
+                .map(|val| val.as_str())
+                .ok_or(NetworkProfileError::FieldMissing(key))
+        }
+        fn hash(vars: &HashMap<String, String>, key: &'static str) -> Result<[u8; 32], NetworkProfileError> {
+            hex_array::decode(get(vars, key)?).ok_or(NetworkProfileError::FieldInvalid(key))
+        }
+        fn lock(
+            vars: &HashMap<String, String>,
+            code_hash_key: &'static str,
+            args_key: &'static str,
+        ) -> Result<LockConfig, NetworkProfileError> {
+            Ok(LockConfig {
+                code_hash: hash(vars, code_hash_key)?,
+                args: hex_vec::decode(get(vars, args_key)?).ok_or(NetworkProfileError::FieldInvalid(args_key))?,
+            })
+        }
+
+        // The networks without presets, like the ones for local tests, are all custom.
+        let network = Network::from_str(get(vars, "NETWORK")?).unwrap_or(Network::Custom);
+
+        Ok(NetworkProfile {
+            network,
+            config_cell_type_id: hash(vars, "CONFIG_CELL_TYPE_ID")?,
+            oracle_cell_type_id: hash(vars, "ORACLE_CELL_TYPE_ID")?,
+            das_lock_type_id: hash(vars, "DAS_LOCK_TYPE_ID")?,
+            always_success_lock_type_id: hash(vars, "ALWAYS_SUCCESS_LOCK_TYPE_ID")?,
+            signhash_lock_type_id: hash(vars, "SIGNHASH_LOCK_TYPE_ID")?,
+            multisig_lock_type_id: hash(vars, "MULTISIG_LOCK_TYPE_ID")?,
+            super_lock: lock(vars, "SUPER_CODE_HASH", "SUPER_ARGS")?,
+            wallet_lock: lock(vars, "WALLET_CODE_HASH", "WALLET_ARGS")?,
+            cross_chain_lock: lock(vars, "CROSS_CHAIN_CODE_HASH", "CROSS_CHAIN_ARGS")?,
+            profit_manager_lock: lock(vars, "PROFIT_MANAGER_CODE_HASH", "PROFIT_MANAGER_ARGS")?,
+        })
+    }
+
+    pub fn super_lock(&self) -> Script {
+        build_script(&self.super_lock.code_hash, &self.super_lock.args)
+    }
+
+    pub fn wallet_lock(&self) -> Script {
+        build_script(&self.wallet_lock.code_hash, &self.wallet_lock.args)
+    }
+
+    pub fn cross_chain_lock(&self) -> Script {
+        build_script(&self.cross_chain_lock.code_hash, &self.cross_chain_lock.args)
+    }
+
+    pub fn profit_manager_lock(&self) -> Script {
+        build_script(&self.profit_manager_lock.code_hash, &self.profit_manager_lock.args)
+    }
+
+    pub fn das_lock(&self) -> Script {
+        build_script(&self.das_lock_type_id, &[])
+    }
+
+    pub fn always_success_lock(&self) -> Script {
+        build_script(&self.always_success_lock_type_id, &[])
+    }
+
+    pub fn signhash_lock(&self) -> Script {
+        build_script(&self.signhash_lock_type_id, &[])
+    }
+
+    pub fn multisign_lock(&self) -> Script {
+        build_script(&self.multisig_lock_type_id, &[])
+    }
+
+    pub fn config_cell_type(&self) -> Script {
+        build_script(&self.config_cell_type_id, &[])
+    }
+
+    pub fn quote_cell_type(&self) -> Script {
+        build_script(&self.oracle_cell_type_id, &[0])
+    }
+
+    pub fn time_cell_type(&self) -> Script {
+        build_script(&self.oracle_cell_type_id, &[1])
+    }
+
+    pub fn height_cell_type(&self) -> Script {
+        build_script(&self.oracle_cell_type_id, &[2])
+    }
+}
+
+fn build_script(code_hash: &[u8; 32], args: &[u8]) -> Script {
+    Script::new_builder()
+        .code_hash(Hash::from(*code_hash))
+        .hash_type(Byte::new(ScriptHashType::Type.into()))
+        .args(Bytes::from(args))
+        .build()
+}
+
+mod hex_vec {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn decode(value: &str) -> Option<Vec<u8>> {
+        hex::decode(value.strip_prefix("0x")?).ok()
+    }
+
+    pub fn serialize<S: Serializer>(value: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("0x{}", hex::encode(value)))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        decode(&value).ok_or_else(|| serde::de::Error::custom("expected a 0x-prefixed hex string"))
+    }
+}
+
+mod hex_array {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn decode(value: &str) -> Option<[u8; 32]> {
+        super::hex_vec::decode(value)?.try_into().ok()
+    }
+
+    pub fn serialize<S: Serializer>(value: &[u8; 32], serializer: S) -> Result<S::Ok, S::Error> {
+        super::hex_vec::serialize(value, serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<[u8; 32], D::Error> {
+        let value = String::deserialize(deserializer)?;
+        decode(&value).ok_or_else(|| serde::de::Error::custom("expected a 0x-prefixed hex string of 32 bytes"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::constants;
+
+    #[test]
+    fn test_compiled_profile() {
+        let profile = NetworkProfile::compiled().unwrap();
+        assert_eq!(constants::super_lock().as_slice(), profile.super_lock().as_slice());
+        assert_eq!(constants::wallet_lock().as_slice(), profile.wallet_lock().as_slice());
+        assert_eq!(
+            constants::cross_chain_lock().as_slice(),
+            profile.cross_chain_lock().as_slice()
+        );
+        assert_eq!(
+            constants::profit_manager_lock().as_slice(),
+            profile.profit_manager_lock().as_slice()
+        );
+        assert_eq!(constants::das_lock().as_slice(), profile.das_lock().as_slice());
+        assert_eq!(
+            constants::always_success_lock().as_slice(),
+            profile.always_success_lock().as_slice()
+        );
+        assert_eq!(
+            constants::signhash_lock().as_slice(),
+            profile.signhash_lock().as_slice()
+        );
+        assert_eq!(
+            constants::multisign_lock().as_slice(),
+            profile.multisign_lock().as_slice()
+        );
+        assert_eq!(
+            constants::config_cell_type().as_slice(),
+            profile.config_cell_type().as_slice()
+        );
+        assert_eq!(
+            constants::quote_cell_type().as_slice(),
+            profile.quote_cell_type().as_slice()
+        );
+        assert_eq!(
+            constants::time_cell_type().as_slice(),
+            profile.time_cell_type().as_slice()
+        );
+        assert_eq!(
+            constants::height_cell_type().as_slice(),
+            profile.height_cell_type().as_slice()
+        );
+    }
+
+    #[test]
+    fn test_presets_and_formats() {
+        let mainnet = NetworkProfile::preset(Network::Mainnet).unwrap();
+        assert_eq!(Network::Mainnet, mainnet.network);
+        // The ${MULTISIG_LOCK_TYPE_ID} in .env.mainnet should be resolved.
+        assert_eq!(mainnet.multisig_lock_type_id, mainnet.super_lock.code_hash);
+        assert_eq!(
+            Network::Testnet3,
+            NetworkProfile::preset(Network::Testnet3).unwrap().network
+        );
+        assert_eq!(
+            Err(NetworkProfileError::PresetMissing(Network::Custom)),
+            NetworkProfile::preset(Network::Custom)
+        );
+
+        let text = toml::to_string(&mainnet).unwrap();
+        assert_eq!(mainnet, NetworkProfile::from_toml_str(&text).unwrap());
+        let text = serde_json::to_string(&mainnet).unwrap();
+        assert_eq!(mainnet, NetworkProfile::from_json_str(&text).unwrap());
+
+        let mut value = serde_json::to_value(&mainnet).unwrap();
+        value["network"] = serde_json::json!("custom");
+        value["super_lock"]["args"] = serde_json::json!("0x01");
+        let custom = NetworkProfile::from_json_str(&value.to_string()).unwrap();
+        assert_eq!(Network::Custom, custom.network);
+        assert_eq!(&[1u8][..], custom.super_lock().args().raw_data().as_ref());
+        assert_eq!(mainnet.das_lock().as_slice(), custom.das_lock().as_slice());
+    }
+
+    #[test]
+    fn test_invalid_profiles() {
+        assert_eq!(
+            Err(NetworkProfileError::FieldMissing("NETWORK")),
+            NetworkProfile::from_env_str("# empty")
+        );
+        assert!(matches!(
+            NetworkProfile::from_env_str("NETWORK=dev\nSUPER_CODE_HASH=${UNDEFINED}"),
+            Err(NetworkProfileError::Syntax(_))
+        ));
+
+        let text = PRESET_DEV.unwrap().replace(
+            "DAS_LOCK_TYPE_ID=0xebd2ca43797df1eae21f5a0d20a09a3851beab063ca06d7b86a1e1e8ef9c7698",
+            "DAS_LOCK_TYPE_ID=0xebd2",
+        );
+        assert_eq!(
+            Err(NetworkProfileError::FieldInvalid("DAS_LOCK_TYPE_ID")),
+            NetworkProfile::from_env_str(&text)
+        );
+        assert!(matches!(
+            NetworkProfile::from_toml_str("network = \"mainnet\""),
+            Err(NetworkProfileError::Syntax(_))
+        ));
+    }
+}
@@ -28,6 +28,7 @@ macro_rules! print_fields {
     }
 }
 
+/// Format the entities as human readable strings, see `prettier_json::PrettierJson` for the structured output.
 pub trait Prettier {
     fn as_prettier(&self) -> String;
 }
@@ -0,0 +1,329 @@
+//! The structured alternative of `Prettier`, it outputs JSON which can be parsed and diffed by machines.
+//!
+//! The JSON is the one from the serde codec in `json.rs` with some fields decoded in place:
+//!
+//! - scripts get the `hash_type` name and the `name` of the script if it is a well-known one in the `NetworkProfile`,
+//!   the args of das-lock are split into `owner` and `manager`;
+//! - `owner_lock_args` which are das-lock args are split in the same way;
+//! - the fields ending with `_at` become `{ "timestamp": "0", "utc": "1970-01-01T00:00:00Z" }`;
+//! - the capacities, which are `capacity`, `price` and the fields ending with `_capacity` or `_fee`, become
+//!   `{ "shannon": "100000000", "ckb": "1.00000000" }`.
+//!
+//! The `timestamp` and `shannon` keep the value of the serde codec, so a `Uint64` is still a decimal string.
+//!
+//! `to_field_paths` flattens the JSON into `records[0].record_key` like paths, so two dumps can be diffed line by line.
+
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::sync::OnceLock;
+
+use molecule::prelude::Entity;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+use super::constants::DasLockType;
+use super::data_parser::das_lock_args;
+use super::network::NetworkProfile;
+
+const ONE_CKB: u64 = 100_000_000;
+
+pub trait PrettierJson {
+    /// Decode the well-known scripts with the profile this crate is compiled with, the scripts are left without names
+    /// if the profile is invalid.
+    fn as_prettier_json(&self) -> Value;
+
+    fn as_prettier_json_with(&self, profile: &NetworkProfile) -> Value;
+}
+
+impl<T: Entity + Serialize> PrettierJson for T {
+    fn as_prettier_json(&self) -> Value {
+        static PROFILE: OnceLock<Option<NetworkProfile>> = OnceLock::new();
+        to_prettier_json(self, PROFILE.get_or_init(|| NetworkProfile::compiled().ok()).as_ref())
+    }
+
+    fn as_prettier_json_with(&self, profile: &NetworkProfile) -> Value {
+        to_prettier_json(self, Some(profile))
+    }
+}
+
+fn to_prettier_json<T: Serialize>(entity: &T, profile: Option<&NetworkProfile>) -> Value {
+    let mut value = serde_json::to_value(entity).expect("The entities should always be serializable.");
+    decode_value(&mut value, None, profile);
+    value
+}
+
+/// Flatten the JSON into a map from the field paths to the leaf values.
+pub fn to_field_paths(value: &Value) -> BTreeMap<String, Value> {
+    fn walk(path: String, value: &Value, paths: &mut BTreeMap<String, Value>) {
+        match value {
+            Value::Object(map) if !map.is_empty() => {
+                for (key, item) in map {
+                    let path = if path.is_empty() {
+                        key.to_owned()
+                    } else {
+                        format!("{}.{}", path, key)
+                    };
+                    walk(path, item, paths);
+                }
+            }
+            Value::Array(items) if !items.is_empty() => {
+                for (i, item) in items.iter().enumerate() {
+                    walk(format!("{}[{}]", path, i), item, paths);
+                }
+            }
+            _ => {
+                paths.insert(path, value.to_owned());
+            }
+        }
+    }
+
+    let mut paths = BTreeMap::new();
+    walk(String::new(), value, &mut paths);
+    paths
+}
+
+fn decode_value(value: &mut Value, field: Option<&str>, profile: Option<&NetworkProfile>) {
+    match value {
+        Value::Object(map) => {
+            if is_script(map) {
+                decode_script(map, profile);
+            }
+            for (key, item) in map.iter_mut() {
+                decode_value(item, Some(key), profile);
+            }
+        }
+        Value::Array(items) => {
+            // The items of a vector share the name of the field.
+            for item in items.iter_mut() {
+                decode_value(item, field, profile);
+            }
+        }
+        _ => {
+            let field = match field {
+                Some(field) => field,
+                None => return,
+            };
+            if let Some(number) = as_u64(value) {
+                if field.ends_with("_at") {
+                    *value = json!({ "timestamp": value.take(), "utc": format_utc(number) });
+                } else if field == "capacity"
+                    || field == "price"
+                    || field.ends_with("_capacity")
+                    || field.ends_with("_fee")
+                {
+                    *value = json!({ "shannon": value.take(), "ckb": format_ckb(number) });
+                }
+            } else if field == "owner_lock_args" {
+                if let Some(decoded) = value
+                    .as_str()
+                    .and_then(decode_hex)
+                    .and_then(|args| decode_das_lock_args(&args))
+                {
+                    let raw = value.take();
+                    *value = decoded;
+                    value["raw"] = raw;
+                }
+            }
+        }
+    }
+}
+
+fn is_script(map: &Map<String, Value>) -> bool {
+    map.len() == 3 && map.contains_key("code_hash") && map.contains_key("hash_type") && map.contains_key("args")
+}
+
+fn decode_script(map: &mut Map<String, Value>, profile: Option<&NetworkProfile>) {
+    let hash_type = map["hash_type"].as_u64();
+    let code_hash = map["code_hash"].as_str().and_then(decode_hex);
+    let args = map["args"].as_str().and_then(decode_hex).unwrap_or_default();
+
+    let name = match hash_type_name(hash_type) {
+        Some(name) => name,
+        None => return,
+    };
+    map.insert(String::from("hash_type"), Value::from(name));
+    if name != "type" {
+        return;
+    }
+
+    let (code_hash, profile) = match (code_hash, profile) {
+        (Some(code_hash), Some(profile)) => (code_hash, profile),
+        _ => return,
+    };
+    let script_name = if code_hash == profile.das_lock_type_id {
+        "das-lock"
+    } else if code_hash == profile.always_success_lock_type_id {
+        "always-success"
+    } else if code_hash == profile.signhash_lock_type_id {
+        "ckb-signhash"
+    } else if code_hash == profile.multisig_lock_type_id {
+        "ckb-multisign"
+    } else if code_hash == profile.config_cell_type_id {
+        "config-cell-type"
+    } else if code_hash == profile.oracle_cell_type_id {
+        match args.as_slice() {
+            [0] => "quote-cell-type",
+            [1] => "time-cell-type",
+            [2] => "height-cell-type",
+            _ => "oracle-cell-type",
+        }
+    } else {
+        return;
+    };
+    map.insert(String::from("name"), Value::from(script_name));
+
+    if script_name == "das-lock" {
+        if let Some(Value::Object(decoded)) = decode_das_lock_args(&args) {
+            map.extend(decoded);
+        }
+    }
+}
+
+fn hash_type_name(hash_type: Option<u64>) -> Option<&'static str> {
+    match hash_type? {
+        0 => Some("data"),
+        1 => Some("type"),
+        2 => Some("data1"),
+        4 => Some("data2"),
+        _ => None,
+    }
+}
+
+fn decode_das_lock_args(args: &[u8]) -> Option<Value> {
+    if args.is_empty() {
+        return None;
+    }
+
+    let role = |lock_type: u8, lock_args: &[u8]| {
+        let algorithm = DasLockType::try_from(lock_type)
+            .map(|val| val.to_string())
+            .unwrap_or_else(|_| lock_type.to_string());
+        json!({ "algorithm": algorithm, "args": format!("0x{}", hex::encode(lock_args)) })
+    };
+
+    let owner_type = das_lock_args::get_owner_type(args)?;
+    let owner_args = das_lock_args::get_owner_lock_args(args)?;
+    let manager_type = das_lock_args::get_manager_type(args)?;
+    let manager_args = das_lock_args::get_manager_lock_args(args)?;
+
+    Some(json!({
+        "owner": role(owner_type, owner_args),
+        "manager": role(manager_type, manager_args),
+    }))
+}
+
+fn decode_hex(value: &str) -> Option<Vec<u8>> {
+    hex::decode(value.strip_prefix("0x")?).ok()
+}
+
+fn as_u64(value: &Value) -> Option<u64> {
+    match value {
+        Value::Number(number) => number.as_u64(),
+        // Uint8 and Uint32 are numbers in the serde codec, Uint64 is always a decimal string.
+        Value::String(text) if !text.is_empty() && text.bytes().all(|val| val.is_ascii_digit()) => text.parse().ok(),
+        _ => None,
+    }
+}
+
+fn format_ckb(shannon: u64) -> String {
+    format!("{}.{:08}", shannon / ONE_CKB, shannon % ONE_CKB)
+}
+
+fn format_utc(timestamp: u64) -> String {
+    let days = (timestamp / 86400) as i64;
+    let seconds = timestamp % 86400;
+
+    // Convert the days since 1970-01-01 to the civil date, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::network::Network;
+    use crate::packed::*;
+
+    #[test]
+    fn test_format() {
+        assert_eq!("1970-01-01T00:00:00Z", format_utc(0));
+        assert_eq!("2021-01-21T03:33:20Z", format_utc(1_611_200_000));
+        assert_eq!("2024-02-29T23:59:59Z", format_utc(1_709_251_199));
+        assert_eq!("0.00000001", format_ckb(1));
+        assert_eq!("20000.50000000", format_ckb(2_000_050_000_000));
+    }
+
+    #[test]
+    fn test_prettier_json() {
+        let profile = NetworkProfile::preset(Network::Mainnet).unwrap();
+        let das_lock_args = "0x03ffffffffffffffffffffffffffffffffffffffff03eeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee";
+        let sale: AccountSaleCellData = serde_json::from_value(json!({
+            "account_id": "0x9d6e5f2b56ed9e1b4e86d0dbd9c6b0b9d2e5a7f1",
+            "account": "0x646173302e626974",
+            "price": 20_000_000_000_000u64,
+            "description": "0x",
+            "started_at": 1_611_200_000u64,
+            "buyer_inviter_profit_rate": 100,
+        }))
+        .unwrap();
+        let value = sale.as_prettier_json_with(&profile);
+        assert_eq!(
+            json!({ "shannon": "20000000000000", "ckb": "200000.00000000" }),
+            value["price"]
+        );
+        assert_eq!(
+            json!({ "timestamp": "1611200000", "utc": "2021-01-21T03:33:20Z" }),
+            value["started_at"]
+        );
+        assert_eq!(json!(100), value["buyer_inviter_profit_rate"]);
+
+        let income: IncomeCellData = serde_json::from_value(json!({
+            "creator": {
+                "code_hash": format!("0x{}", hex::encode(profile.das_lock_type_id)),
+                "hash_type": 1,
+                "args": das_lock_args,
+            },
+            "records": [{
+                "belong_to": {
+                    "code_hash": "0x9376c3b5811942960a846691e16e477cf43d7c7fa654067c9948dfcd09a32137",
+                    "hash_type": 0,
+                    "args": "0x",
+                },
+                "capacity": 100_000_000,
+            }],
+        }))
+        .unwrap();
+        let value = income.as_prettier_json_with(&profile);
+        assert_eq!("das-lock", value["creator"]["name"]);
+        assert_eq!("type", value["creator"]["hash_type"]);
+        assert_eq!(
+            json!({ "algorithm": "ETH", "args": "0xffffffffffffffffffffffffffffffffffffffff" }),
+            value["creator"]["owner"]
+        );
+        assert_eq!("data", value["records"][0]["belong_to"]["hash_type"]);
+        assert!(value["records"][0]["belong_to"].get("name").is_none());
+
+        let paths = to_field_paths(&value);
+        assert_eq!(Some(&json!("1.00000000")), paths.get("records[0].capacity.ckb"));
+        assert_eq!(Some(&json!("100000000")), paths.get("records[0].capacity.shannon"));
+        assert_eq!(Some(&json!("ETH")), paths.get("creator.manager.algorithm"));
+        assert_eq!(Some(&json!(das_lock_args)), paths.get("creator.args"));
+    }
+}
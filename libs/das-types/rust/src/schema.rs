@@ -0,0 +1,684 @@
+//! Check the evolution of the molecule schemas, so changes which break the readers of older data can be found before
+//! they are shipped.
+//!
+//! Two kinds of checks are provided:
+//!
+//! - `check_compatibility` compares two versions of the schemas and reports the changes which make the new schemas
+//!   unable to read the data encoded by the old ones, appending fields to the end of a table is the only compatible way
+//!   to change a type;
+//! - `check_mixers` verifies that every `*Mixer` trait in `mixer.rs` and its `*ReaderMixer` trait are implemented for
+//!   every version of the type, like `AccountCellDataV2`, `AccountCellDataV3` and `AccountCellData`.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::{fmt, fs};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    pub name: String,
+    pub type_: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Declaration {
+    Array { item: String, length: usize },
+    Struct { fields: Vec<Field> },
+    Vector { item: String },
+    Option { item: String },
+    Union { items: Vec<String> },
+    Table { fields: Vec<Field> },
+}
+
+impl Declaration {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Declaration::Array { .. } => "array",
+            Declaration::Struct { .. } => "struct",
+            Declaration::Vector { .. } => "vector",
+            Declaration::Option { .. } => "option",
+            Declaration::Union { .. } => "union",
+            Declaration::Table { .. } => "table",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaError {
+    pub file: String,
+    pub line: usize,
+    pub reason: String,
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{} {}", self.file, self.line, self.reason)
+    }
+}
+
+/// All the types declared in a set of .mol files, the imports are ignored since the files are merged.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Schemas {
+    pub types: BTreeMap<String, Declaration>,
+}
+
+impl Schemas {
+    /// Parse and merge all the .mol files in the directory.
+    pub fn from_dir(dir: impl AsRef<Path>) -> Result<Self, SchemaError> {
+        let io_error = |err: std::io::Error| SchemaError {
+            file: dir.as_ref().display().to_string(),
+            line: 0,
+            reason: err.to_string(),
+        };
+
+        let mut paths = vec![];
+        for entry in fs::read_dir(dir.as_ref()).map_err(io_error)? {
+            let path = entry.map_err(io_error)?.path();
+            if path.extension().map(|ext| ext == "mol").unwrap_or(false) {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        let mut schemas = Schemas::default();
+        for path in paths {
+            let text = fs::read_to_string(&path).map_err(io_error)?;
+            schemas.parse(&path.display().to_string(), &text)?;
+        }
+
+        Ok(schemas)
+    }
+
+    /// Parse the text of a .mol file and add its types, the file name is only used in errors.
+    pub fn parse(&mut self, file: &str, text: &str) -> Result<(), SchemaError> {
+        let tokens = tokenize(text);
+        let mut parser = Parser { file, tokens, pos: 0 };
+
+        while let Some((line, keyword)) = parser.next() {
+            if keyword == "import" {
+                parser.ident()?;
+                parser.expect(";")?;
+                continue;
+            }
+
+            let name = parser.ident()?;
+            let declaration = match keyword.as_str() {
+                "array" => {
+                    parser.expect("[")?;
+                    let item = parser.ident()?;
+                    parser.expect(";")?;
+                    let length = parser.number()?;
+                    parser.expect("]")?;
+                    parser.expect(";")?;
+                    Declaration::Array { item, length }
+                }
+                "vector" => {
+                    parser.expect("<")?;
+                    let item = parser.ident()?;
+                    parser.expect(">")?;
+                    parser.expect(";")?;
+                    Declaration::Vector { item }
+                }
+                "option" => {
+                    parser.expect("(")?;
+                    let item = parser.ident()?;
+                    parser.expect(")")?;
+                    parser.expect(";")?;
+                    Declaration::Option { item }
+                }
+                "union" => {
+                    parser.expect("{")?;
+                    let mut items = vec![];
+                    while !parser.eat("}") {
+                        items.push(parser.ident()?);
+                        if !parser.eat(",") {
+                            parser.expect("}")?;
+                            break;
+                        }
+                    }
+                    Declaration::Union { items }
+                }
+                "struct" | "table" => {
+                    parser.expect("{")?;
+                    let mut fields = vec![];
+                    while !parser.eat("}") {
+                        let name = parser.ident()?;
+                        parser.expect(":")?;
+                        let type_ = parser.ident()?;
+                        fields.push(Field { name, type_ });
+                        if !parser.eat(",") {
+                            parser.expect("}")?;
+                            break;
+                        }
+                    }
+                    if keyword == "struct" {
+                        Declaration::Struct { fields }
+                    } else {
+                        Declaration::Table { fields }
+                    }
+                }
+                _ => return Err(parser.error(line, format!("unknown keyword `{}`", keyword))),
+            };
+
+            if self.types.insert(name.clone(), declaration).is_some() {
+                return Err(parser.error(line, format!("type `{}` is declared more than once", name)));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn tokenize(text: &str) -> Vec<(usize, String)> {
+    let mut tokens = vec![];
+    for (i, line) in text.lines().enumerate() {
+        let line = line.split("//").next().unwrap_or_default();
+        let mut word = String::new();
+        for c in line.chars() {
+            if c.is_alphanumeric() || c == '_' {
+                word.push(c);
+                continue;
+            }
+            if !word.is_empty() {
+                tokens.push((i + 1, std::mem::take(&mut word)));
+            }
+            if !c.is_whitespace() {
+                tokens.push((i + 1, c.to_string()));
+            }
+        }
+        if !word.is_empty() {
+            tokens.push((i + 1, word));
+        }
+    }
+    tokens
+}
+
+struct Parser<'a> {
+    file: &'a str,
+    tokens: Vec<(usize, String)>,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, line: usize, reason: String) -> SchemaError {
+        SchemaError {
+            file: self.file.to_string(),
+            line,
+            reason,
+        }
+    }
+
+    fn next(&mut self) -> Option<(usize, String)> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn next_or_error(&mut self, expected: &str) -> Result<(usize, String), SchemaError> {
+        let line = self.tokens.last().map(|(line, _)| *line).unwrap_or_default();
+        self.next()
+            .ok_or_else(|| self.error(line, format!("expected {}, but the file ends", expected)))
+    }
+
+    fn ident(&mut self) -> Result<String, SchemaError> {
+        let (line, token) = self.next_or_error("an identifier")?;
+        if token.starts_with(|c: char| c.is_alphabetic() || c == '_') {
+            Ok(token)
+        } else {
+            Err(self.error(line, format!("expected an identifier, but `{}` found", token)))
+        }
+    }
+
+    fn number(&mut self) -> Result<usize, SchemaError> {
+        let (line, token) = self.next_or_error("a number")?;
+        token
+            .parse()
+            .map_err(|_| self.error(line, format!("expected a number, but `{}` found", token)))
+    }
+
+    fn expect(&mut self, expected: &str) -> Result<(), SchemaError> {
+        let (line, token) = self.next_or_error(&format!("`{}`", expected))?;
+        if token == expected {
+            Ok(())
+        } else {
+            Err(self.error(line, format!("expected `{}`, but `{}` found", expected, token)))
+        }
+    }
+
+    fn eat(&mut self, expected: &str) -> bool {
+        if self
+            .tokens
+            .get(self.pos)
+            .map(|(_, token)| token == expected)
+            .unwrap_or(false)
+        {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// A change which makes the new schemas unable to read the data encoded by the old ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Incompatibility {
+    TypeRemoved {
+        name: String,
+    },
+    KindChanged {
+        name: String,
+        old: &'static str,
+        new: &'static str,
+    },
+    FieldRemoved {
+        name: String,
+        field: String,
+    },
+    FieldReordered {
+        name: String,
+        field: String,
+        old_index: usize,
+        new_index: usize,
+    },
+    FieldTypeChanged {
+        name: String,
+        field: String,
+        old: String,
+        new: String,
+    },
+    /// A struct has a fixed size, so even appending a field breaks it.
+    StructFieldAdded {
+        name: String,
+        field: String,
+    },
+    /// An array has a fixed size, so both shrinking and growing break it.
+    ArrayLengthChanged {
+        name: String,
+        old: usize,
+        new: usize,
+    },
+    ItemTypeChanged {
+        name: String,
+        old: String,
+        new: String,
+    },
+    /// The items of a union are identified by their indexes, so only appending is allowed.
+    UnionItemChanged {
+        name: String,
+        index: usize,
+        old: String,
+        new: Option<String>,
+    },
+}
+
+impl fmt::Display for Incompatibility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Incompatibility::TypeRemoved { name } => write!(f, "{} is removed", name),
+            Incompatibility::KindChanged { name, old, new } => write!(f, "{} is changed from {} to {}", name, old, new),
+            Incompatibility::FieldRemoved { name, field } => write!(f, "{}.{} is removed", name, field),
+            Incompatibility::FieldReordered {
+                name,
+                field,
+                old_index,
+                new_index,
+            } => write!(f, "{}.{} is moved from {} to {}", name, field, old_index, new_index),
+            Incompatibility::FieldTypeChanged { name, field, old, new } => {
+                write!(f, "{}.{} is changed from {} to {}", name, field, old, new)
+            }
+            Incompatibility::StructFieldAdded { name, field } => write!(f, "{}.{} is added to a struct", name, field),
+            Incompatibility::ArrayLengthChanged { name, old, new } => {
+                write!(f, "the length of {} is changed from {} to {}", name, old, new)
+            }
+            Incompatibility::ItemTypeChanged { name, old, new } => {
+                write!(f, "the item of {} is changed from {} to {}", name, old, new)
+            }
+            Incompatibility::UnionItemChanged { name, index, old, new } => match new {
+                Some(new) => write!(f, "the item {} of {} is changed from {} to {}", index, name, old, new),
+                None => write!(f, "the item {} of {} is removed, it was {}", index, name, old),
+            },
+        }
+    }
+}
+
+/// Compare the new schemas with the old ones, the types only existing in the new schemas are always compatible.
+pub fn check_compatibility(old: &Schemas, new: &Schemas) -> Vec<Incompatibility> {
+    let mut ret = vec![];
+    for (name, old_declaration) in old.types.iter() {
+        let new_declaration = match new.types.get(name) {
+            Some(val) => val,
+            None => {
+                ret.push(Incompatibility::TypeRemoved { name: name.clone() });
+                continue;
+            }
+        };
+
+        match (old_declaration, new_declaration) {
+            (
+                Declaration::Array {
+                    item: old_item,
+                    length: old_length,
+                },
+                Declaration::Array {
+                    item: new_item,
+                    length: new_length,
+                },
+            ) => {
+                check_item(&mut ret, name, old_item, new_item);
+                if old_length != new_length {
+                    ret.push(Incompatibility::ArrayLengthChanged {
+                        name: name.clone(),
+                        old: *old_length,
+                        new: *new_length,
+                    });
+                }
+            }
+            (Declaration::Vector { item: old_item }, Declaration::Vector { item: new_item })
+            | (Declaration::Option { item: old_item }, Declaration::Option { item: new_item }) => {
+                check_item(&mut ret, name, old_item, new_item)
+            }
+            (Declaration::Union { items: old_items }, Declaration::Union { items: new_items }) => {
+                for (index, old_item) in old_items.iter().enumerate() {
+                    let new_item = new_items.get(index);
+                    if new_item != Some(old_item) {
+                        ret.push(Incompatibility::UnionItemChanged {
+                            name: name.clone(),
+                            index,
+                            old: old_item.clone(),
+                            new: new_item.cloned(),
+                        });
+                    }
+                }
+            }
+            (Declaration::Struct { fields: old_fields }, Declaration::Struct { fields: new_fields }) => {
+                check_fields(&mut ret, name, old_fields, new_fields);
+                for field in new_fields.iter().skip(old_fields.len()) {
+                    ret.push(Incompatibility::StructFieldAdded {
+                        name: name.clone(),
+                        field: field.name.clone(),
+                    });
+                }
+            }
+            (Declaration::Table { fields: old_fields }, Declaration::Table { fields: new_fields }) => {
+                check_fields(&mut ret, name, old_fields, new_fields)
+            }
+            _ => ret.push(Incompatibility::KindChanged {
+                name: name.clone(),
+                old: old_declaration.kind(),
+                new: new_declaration.kind(),
+            }),
+        }
+    }
+
+    ret
+}
+
+fn check_item(ret: &mut Vec<Incompatibility>, name: &str, old_item: &str, new_item: &str) {
+    if old_item != new_item {
+        ret.push(Incompatibility::ItemTypeChanged {
+            name: name.to_string(),
+            old: old_item.to_string(),
+            new: new_item.to_string(),
+        });
+    }
+}
+
+fn check_fields(ret: &mut Vec<Incompatibility>, name: &str, old_fields: &[Field], new_fields: &[Field]) {
+    for (old_index, old_field) in old_fields.iter().enumerate() {
+        let (new_index, new_field) = match new_fields
+            .iter()
+            .enumerate()
+            .find(|(_, field)| field.name == old_field.name)
+        {
+            Some(val) => val,
+            None => {
+                ret.push(Incompatibility::FieldRemoved {
+                    name: name.to_string(),
+                    field: old_field.name.clone(),
+                });
+                continue;
+            }
+        };
+
+        if old_index != new_index {
+            ret.push(Incompatibility::FieldReordered {
+                name: name.to_string(),
+                field: old_field.name.clone(),
+                old_index,
+                new_index,
+            });
+        }
+        if old_field.type_ != new_field.type_ {
+            ret.push(Incompatibility::FieldTypeChanged {
+                name: name.to_string(),
+                field: old_field.name.clone(),
+                old: old_field.type_.clone(),
+                new: new_field.type_.clone(),
+            });
+        }
+    }
+}
+
+/// A version of a type which is not covered by a mixer trait.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingMixerImpl {
+    pub trait_name: String,
+    pub type_name: String,
+}
+
+impl fmt::Display for MissingMixerImpl {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} is not implemented for {}", self.trait_name, self.type_name)
+    }
+}
+
+/// The versions which are not covered by the mixer traits on purpose, the AccountCellDataV1 has no last_*_at fields,
+/// so it can not be mixed with the later versions.
+pub const IGNORED_MIXER_TYPES: [&str; 1] = ["AccountCellDataV1"];
+
+/// Check the source of `mixer.rs`, every version of a type like `SubAccountV1` and `SubAccount` should implement both
+/// `SubAccountMixer` and `SubAccountReaderMixer`, the types in `ignored` are skipped.
+pub fn check_mixers(schemas: &Schemas, mixer_source: &str, ignored: &[&str]) -> Vec<MissingMixerImpl> {
+    let mut traits = vec![];
+    let mut impls = vec![];
+    for line in mixer_source.lines().map(str::trim) {
+        if let Some(rest) = line.strip_prefix("pub trait ") {
+            let name = leading_ident(rest);
+            if name.ends_with("Mixer") && !name.ends_with("ReaderMixer") {
+                traits.push(name.to_string());
+            }
+        } else if let Some(rest) = line.strip_prefix("impl") {
+            let rest = rest.strip_prefix("<'r>").unwrap_or(rest).trim_start();
+            if let Some((trait_name, type_name)) = rest.split_once(" for ") {
+                impls.push((
+                    leading_ident(trait_name).to_string(),
+                    leading_ident(type_name).to_string(),
+                ));
+            }
+        }
+    }
+
+    let mut ret = vec![];
+    for trait_name in traits {
+        let base = trait_name.trim_end_matches("Mixer");
+        let versions = schemas.types.keys().filter(|name| {
+            name.strip_prefix(base)
+                .map(|version| version.is_empty() || is_version_suffix(version))
+                .unwrap_or(false)
+        });
+        for type_name in versions.filter(|name| !ignored.contains(&name.as_str())) {
+            let expected = [
+                (trait_name.clone(), type_name.clone()),
+                (format!("{}ReaderMixer", base), format!("{}Reader", type_name)),
+            ];
+            for (trait_name, type_name) in expected {
+                if !impls.contains(&(trait_name.clone(), type_name.clone())) {
+                    ret.push(MissingMixerImpl { trait_name, type_name });
+                }
+            }
+        }
+    }
+
+    ret
+}
+
+fn leading_ident(text: &str) -> &str {
+    let end = text
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(text.len());
+    &text[..end]
+}
+
+fn is_version_suffix(text: &str) -> bool {
+    text.strip_prefix('V')
+        .map(|number| !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()))
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(text: &str) -> Schemas {
+        let mut schemas = Schemas::default();
+        schemas.parse("test.mol", text).unwrap();
+        schemas
+    }
+
+    #[test]
+    fn test_parse_schemas() {
+        let schemas = parse(
+            r#"
+            import basic;
+
+            array Hash [byte; 32];
+            vector Bytes <byte>; // comment
+            option BytesOpt (Bytes);
+            union Action { Hash, Bytes }
+            struct OutPoint {
+                tx_hash: Hash,
+                index: Uint32,
+            }
+            table Data { hash: Hash, bytes: Bytes }
+            "#,
+        );
+
+        assert_eq!(
+            Some(&Declaration::Array {
+                item: String::from("byte"),
+                length: 32
+            }),
+            schemas.types.get("Hash")
+        );
+        assert_eq!(
+            Some(&Declaration::Union {
+                items: vec![String::from("Hash"), String::from("Bytes")]
+            }),
+            schemas.types.get("Action")
+        );
+        assert!(matches!(schemas.types.get("OutPoint"), Some(Declaration::Struct { fields }) if fields.len() == 2));
+        assert!(matches!(schemas.types.get("Data"), Some(Declaration::Table { fields }) if fields[1].type_ == "Bytes"));
+
+        let err = Schemas::default()
+            .parse("test.mol", "table Data {\n  a: ,\n}")
+            .unwrap_err();
+        assert_eq!(2, err.line);
+        let err = Schemas::default()
+            .parse("test.mol", "vector A <byte>;\nvector A <byte>;")
+            .unwrap_err();
+        assert_eq!(2, err.line);
+    }
+
+    #[test]
+    fn test_check_compatibility() {
+        let old = parse(
+            r#"
+            array Id [byte; 20];
+            vector Ids <Id>;
+            struct Pair { a: Id, b: Id }
+            table Data { id: Id, ids: Ids, extra: Ids }
+            union Action { Id, Ids }
+            table Removed { id: Id }
+            "#,
+        );
+
+        // Appending fields to tables and items to unions is compatible.
+        let new = parse(
+            r#"
+            array Id [byte; 20];
+            vector Ids <Id>;
+            struct Pair { a: Id, b: Id }
+            table Data { id: Id, ids: Ids, extra: Ids, new_field: Id }
+            union Action { Id, Ids, Pair }
+            table Removed { id: Id }
+            table Added { id: Id }
+            "#,
+        );
+        assert!(check_compatibility(&old, &new).is_empty());
+
+        let new = parse(
+            r#"
+            array Id [byte; 10];
+            array Ids [byte; 10];
+            struct Pair { a: Id, b: Id, c: Id }
+            table Data { ids: Ids, id: Ids }
+            union Action { Ids }
+            "#,
+        );
+        let errors = check_compatibility(&old, &new);
+        assert_eq!(
+            vec![
+                "the item 0 of Action is changed from Id to Ids",
+                "the item 1 of Action is removed, it was Ids",
+                "Data.id is moved from 0 to 1",
+                "Data.id is changed from Id to Ids",
+                "Data.ids is moved from 1 to 0",
+                "Data.extra is removed",
+                "the length of Id is changed from 20 to 10",
+                "Ids is changed from vector to array",
+                "Pair.c is added to a struct",
+                "Removed is removed",
+            ],
+            errors.iter().map(|err| err.to_string()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_check_mixers() {
+        let schemas = parse(
+            "table DataV1 { a: byte }\ntable DataV2 { a: byte }\ntable Data { a: byte }\ntable DataX { a: byte }",
+        );
+        let source = r#"
+            pub trait DataMixer {}
+            impl DataMixer for DataV1 {}
+            impl DataMixer for Data {}
+            pub trait DataReaderMixer<'r> {}
+            impl<'r> DataReaderMixer<'r> for DataV1Reader<'r> {}
+            impl<'r> DataReaderMixer<'r> for DataV2Reader<'r> {}
+        "#;
+
+        assert_eq!(
+            vec![
+                "DataReaderMixer is not implemented for DataReader",
+                "DataMixer is not implemented for DataV2",
+            ],
+            check_mixers(&schemas, source, &[])
+                .iter()
+                .map(|err| err.to_string())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(1, check_mixers(&schemas, source, &["DataV2"]).len());
+    }
+
+    #[test]
+    fn test_check_repo_schemas() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let schemas = Schemas::from_dir(root.join("schemas")).unwrap();
+        assert!(schemas.types.contains_key("AccountCellDataV1"));
+        assert!(check_compatibility(&schemas, &schemas).is_empty());
+
+        let mixer_source = fs::read_to_string(root.join("rust/src/mixer.rs")).unwrap();
+        let missing = check_mixers(&schemas, &mixer_source, &IGNORED_MIXER_TYPES);
+        assert!(missing.is_empty(), "{:?}", missing);
+    }
+}
@@ -0,0 +1,355 @@
+//! Upgrade the old versions of cell data to the newer ones.
+//!
+//! Every version is upgraded to the next one by `Upgrade::upgrade`, the fields existing in both versions are copied
+//! and the new fields are filled with the defaults documented on each implementation. The defaults are the values the
+//! contracts treat as "not set", so an upgraded entity behaves the same as the old one.
+
+use molecule::error::VerificationResult;
+use molecule::prelude::*;
+
+use super::mixer::{AccountCellDataMixer, AccountSaleCellDataMixer, PreAccountCellDataMixer, SubAccountMixer};
+use super::schemas::packed::*;
+
+pub trait Upgrade: Sized {
+    /// The next version of this entity.
+    type Output;
+    /// The latest version of this entity.
+    type Latest;
+
+    fn upgrade(self) -> Self::Output;
+
+    /// Upgrade through all the versions after this one.
+    fn upgrade_to_latest(self) -> Self::Latest;
+}
+
+/// The `updated_at` is dropped because it can not tell which kind of update happened, so `last_transfer_account_at`,
+/// `last_edit_manager_at` and `last_edit_records_at` are all 0, which means no throttle is applied.
+impl Upgrade for AccountCellDataV1 {
+    type Output = AccountCellDataV2;
+    type Latest = AccountCellData;
+
+    fn upgrade(self) -> Self::Output {
+        AccountCellDataV2::new_builder()
+            .id(self.id())
+            .account(self.account())
+            .registered_at(self.registered_at())
+            .last_transfer_account_at(Uint64::from(0))
+            .last_edit_manager_at(Uint64::from(0))
+            .last_edit_records_at(Uint64::from(0))
+            .status(self.status())
+            .records(self.records())
+            .build()
+    }
+
+    fn upgrade_to_latest(self) -> Self::Latest {
+        self.upgrade().upgrade_to_latest()
+    }
+}
+
+/// The `enable_sub_account` is 0 which means sub-account is disabled, and the `renew_sub_account_price` is 0.
+impl Upgrade for AccountCellDataV2 {
+    type Output = AccountCellDataV3;
+    type Latest = AccountCellData;
+
+    fn upgrade(self) -> Self::Output {
+        AccountCellDataV3::new_builder()
+            .id(self.id())
+            .account(self.account())
+            .registered_at(self.registered_at())
+            .last_transfer_account_at(self.last_transfer_account_at())
+            .last_edit_manager_at(self.last_edit_manager_at())
+            .last_edit_records_at(self.last_edit_records_at())
+            .status(self.status())
+            .records(self.records())
+            .enable_sub_account(Uint8::from(0))
+            .renew_sub_account_price(Uint64::from(0))
+            .build()
+    }
+
+    fn upgrade_to_latest(self) -> Self::Latest {
+        self.upgrade().upgrade_to_latest()
+    }
+}
+
+/// The `approval` is `AccountApproval::default()` which means no approval.
+impl Upgrade for AccountCellDataV3 {
+    type Output = AccountCellData;
+    type Latest = AccountCellData;
+
+    fn upgrade(self) -> Self::Output {
+        AccountCellData::new_builder()
+            .id(self.id())
+            .account(self.account())
+            .registered_at(self.registered_at())
+            .last_transfer_account_at(self.last_transfer_account_at())
+            .last_edit_manager_at(self.last_edit_manager_at())
+            .last_edit_records_at(self.last_edit_records_at())
+            .status(self.status())
+            .records(self.records())
+            .enable_sub_account(self.enable_sub_account())
+            .renew_sub_account_price(self.renew_sub_account_price())
+            .approval(AccountApproval::default())
+            .build()
+    }
+
+    fn upgrade_to_latest(self) -> Self::Latest {
+        self.upgrade()
+    }
+}
+
+/// The `initial_records` is empty.
+impl Upgrade for PreAccountCellDataV1 {
+    type Output = PreAccountCellDataV2;
+    type Latest = PreAccountCellData;
+
+    fn upgrade(self) -> Self::Output {
+        PreAccountCellDataV2::new_builder()
+            .account(self.account())
+            .refund_lock(self.refund_lock())
+            .owner_lock_args(self.owner_lock_args())
+            .inviter_id(self.inviter_id())
+            .inviter_lock(self.inviter_lock())
+            .channel_lock(self.channel_lock())
+            .price(self.price())
+            .quote(self.quote())
+            .invited_discount(self.invited_discount())
+            .created_at(self.created_at())
+            .initial_records(Records::default())
+            .build()
+    }
+
+    fn upgrade_to_latest(self) -> Self::Latest {
+        self.upgrade().upgrade_to_latest()
+    }
+}
+
+/// The `initial_cross_chain` is `ChainId::default()` whose `checked` is 0, which means the account is not locked for
+/// cross chain.
+impl Upgrade for PreAccountCellDataV2 {
+    type Output = PreAccountCellData;
+    type Latest = PreAccountCellData;
+
+    fn upgrade(self) -> Self::Output {
+        PreAccountCellData::new_builder()
+            .account(self.account())
+            .refund_lock(self.refund_lock())
+            .owner_lock_args(self.owner_lock_args())
+            .inviter_id(self.inviter_id())
+            .inviter_lock(self.inviter_lock())
+            .channel_lock(self.channel_lock())
+            .price(self.price())
+            .quote(self.quote())
+            .invited_discount(self.invited_discount())
+            .created_at(self.created_at())
+            .initial_records(self.initial_records())
+            .initial_cross_chain(ChainId::default())
+            .build()
+    }
+
+    fn upgrade_to_latest(self) -> Self::Latest {
+        self.upgrade()
+    }
+}
+
+/// The `approval` is `AccountApproval::default()` which means no approval.
+impl Upgrade for SubAccountV1 {
+    type Output = SubAccount;
+    type Latest = SubAccount;
+
+    fn upgrade(self) -> Self::Output {
+        SubAccount::new_builder()
+            .lock(self.lock())
+            .id(self.id())
+            .account(self.account())
+            .suffix(self.suffix())
+            .registered_at(self.registered_at())
+            .expired_at(self.expired_at())
+            .status(self.status())
+            .records(self.records())
+            .nonce(self.nonce())
+            .enable_sub_account(self.enable_sub_account())
+            .renew_sub_account_price(self.renew_sub_account_price())
+            .approval(AccountApproval::default())
+            .build()
+    }
+
+    fn upgrade_to_latest(self) -> Self::Latest {
+        self.upgrade()
+    }
+}
+
+/// The `buyer_inviter_profit_rate` is 0. Note that account-sale-cell-type pays the inviter of buyer by
+/// `ConfigCellProfitRate.sale_buyer_inviter` for the AccountSaleCells in version 1, so the profit of an upgraded entity
+/// should still be calculated by its original version.
+impl Upgrade for AccountSaleCellDataV1 {
+    type Output = AccountSaleCellData;
+    type Latest = AccountSaleCellData;
+
+    fn upgrade(self) -> Self::Output {
+        AccountSaleCellData::new_builder()
+            .account_id(self.account_id())
+            .account(self.account())
+            .price(self.price())
+            .description(self.description())
+            .started_at(self.started_at())
+            .buyer_inviter_profit_rate(Uint32::default())
+            .build()
+    }
+
+    fn upgrade_to_latest(self) -> Self::Latest {
+        self.upgrade()
+    }
+}
+
+/// Upgrade any version of `AccountCellData` decoded by its version to the latest.
+pub fn account_cell_data_to_latest(data: &dyn AccountCellDataMixer) -> VerificationResult<AccountCellData> {
+    let reader = data.as_reader();
+    match reader.version() {
+        2 => Ok(reader.try_into_v2()?.to_entity().upgrade_to_latest()),
+        3 => Ok(reader.try_into_v3()?.to_entity().upgrade_to_latest()),
+        _ => Ok(reader.try_into_latest()?.to_entity()),
+    }
+}
+
+/// Upgrade any version of `PreAccountCellData` decoded by its version to the latest.
+pub fn pre_account_cell_data_to_latest(data: &dyn PreAccountCellDataMixer) -> VerificationResult<PreAccountCellData> {
+    let reader = data.as_reader();
+    match reader.version() {
+        1 => Ok(reader.try_into_v1()?.to_entity().upgrade_to_latest()),
+        2 => Ok(reader.try_into_v2()?.to_entity().upgrade_to_latest()),
+        _ => Ok(reader.try_into_latest()?.to_entity()),
+    }
+}
+
+/// Upgrade any version of `SubAccount` decoded by its version to the latest.
+pub fn sub_account_to_latest(data: &dyn SubAccountMixer) -> VerificationResult<SubAccount> {
+    match data.version() {
+        1 => Ok(data.try_into_v1()?.upgrade_to_latest()),
+        _ => data.try_into_latest(),
+    }
+}
+
+/// Upgrade any version of `AccountSaleCellData` decoded by its version to the latest.
+pub fn account_sale_cell_data_to_latest(
+    data: &dyn AccountSaleCellDataMixer,
+) -> VerificationResult<AccountSaleCellData> {
+    let reader = data.as_reader();
+    match reader.version() {
+        1 => Ok(reader.try_into_v1()?.to_entity().upgrade_to_latest()),
+        _ => Ok(reader.try_into_latest()?.to_entity()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn gen_records() -> Records {
+        Records::new_builder()
+            .push(
+                Record::new_builder()
+                    .record_type(Bytes::from(b"address".to_vec()))
+                    .record_ttl(Uint32::from(300))
+                    .build(),
+            )
+            .build()
+    }
+
+    #[test]
+    fn test_upgrade_account_cell_data() {
+        let v1 = AccountCellDataV1::new_builder()
+            .id(AccountId::try_from(vec![1u8; 20]).unwrap())
+            .registered_at(Uint64::from(100))
+            .updated_at(Uint64::from(200))
+            .status(Uint8::from(1))
+            .records(gen_records())
+            .build();
+
+        let v2 = v1.clone().upgrade();
+        assert_eq!(v1.id().as_slice(), v2.id().as_slice());
+        assert_eq!(100, u64::from(v2.registered_at()));
+        assert_eq!(0, u64::from(v2.last_transfer_account_at()));
+        assert_eq!(0, u64::from(v2.last_edit_records_at()));
+        assert_eq!(1, u8::from(v2.status()));
+
+        let v3 = v2.clone().upgrade();
+        assert_eq!(0, u8::from(v3.enable_sub_account()));
+        assert_eq!(0, u64::from(v3.renew_sub_account_price()));
+
+        let latest = v1.upgrade_to_latest();
+        assert_eq!(v3.clone().upgrade().as_slice(), latest.as_slice());
+        assert_eq!(gen_records().as_slice(), latest.records().as_slice());
+        assert_eq!(AccountApproval::default().as_slice(), latest.approval().as_slice());
+
+        // The upgraded entity should be the same as the one decoded from the version of mixer.
+        assert_eq!(latest.as_slice(), account_cell_data_to_latest(&v2).unwrap().as_slice());
+        assert_eq!(latest.as_slice(), account_cell_data_to_latest(&v3).unwrap().as_slice());
+        assert_eq!(
+            latest.as_slice(),
+            account_cell_data_to_latest(&latest).unwrap().as_slice()
+        );
+    }
+
+    #[test]
+    fn test_upgrade_pre_account_cell_data() {
+        let v1 = PreAccountCellDataV1::new_builder()
+            .quote(Uint64::from(1000))
+            .created_at(Uint64::from(100))
+            .build();
+
+        let v2 = v1.clone().upgrade();
+        assert_eq!(1000, u64::from(v2.quote()));
+        assert!(v2.initial_records().is_empty());
+
+        let latest = v2.upgrade();
+        assert_eq!(100, u64::from(latest.created_at()));
+        assert_eq!(0, u8::from(latest.initial_cross_chain().checked()));
+        assert_eq!(
+            latest.as_slice(),
+            pre_account_cell_data_to_latest(&v1).unwrap().as_slice()
+        );
+    }
+
+    #[test]
+    fn test_upgrade_sub_account() {
+        let v1 = SubAccountV1::new_builder()
+            .suffix(Bytes::from(b".bit".to_vec()))
+            .expired_at(Uint64::from(200))
+            .nonce(Uint64::from(3))
+            .enable_sub_account(Uint8::from(1))
+            .build();
+
+        let latest = Clone::clone(&v1).upgrade();
+        assert_eq!(b".bit", latest.suffix().raw_data().as_ref());
+        assert_eq!(3, u64::from(latest.nonce()));
+        assert_eq!(1, u8::from(latest.enable_sub_account()));
+        assert_eq!(AccountApproval::default().as_slice(), latest.approval().as_slice());
+        assert_eq!(
+            Entity::as_slice(&latest),
+            Entity::as_slice(&sub_account_to_latest(&v1).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_upgrade_account_sale_cell_data() {
+        let v1 = AccountSaleCellDataV1::new_builder()
+            .account(Bytes::from(b"das00001.bit".to_vec()))
+            .price(Uint64::from(20_000_000_000))
+            .started_at(Uint64::from(100))
+            .build();
+
+        let latest = v1.clone().upgrade();
+        assert_eq!(v1.account().as_slice(), latest.account().as_slice());
+        assert_eq!(20_000_000_000, u64::from(latest.price()));
+        assert_eq!(100, u64::from(latest.started_at()));
+        assert_eq!(0, u32::from(latest.buyer_inviter_profit_rate()));
+        assert_eq!(
+            latest.as_slice(),
+            account_sale_cell_data_to_latest(&v1).unwrap().as_slice()
+        );
+        assert_eq!(
+            latest.as_slice(),
+            account_sale_cell_data_to_latest(&latest).unwrap().as_slice()
+        );
+    }
+}
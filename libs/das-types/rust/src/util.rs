@@ -197,6 +197,7 @@ pub enum EntityWrapper {
     PreAccountCellData(PreAccountCellData),
     ProposalCellData(ProposalCellData),
     AccountCellData(AccountCellData),
+    AccountCellDataV1(AccountCellDataV1),
     AccountCellDataV2(AccountCellDataV2),
     AccountCellDataV3(AccountCellDataV3),
     AccountSaleCellData(AccountSaleCellData),
@@ -206,6 +207,7 @@ pub enum EntityWrapper {
     OfferCellData(OfferCellData),
     SubAccountV1(SubAccountV1),
     SubAccount(SubAccount),
+    DeviceKeyListCellData(DeviceKeyListCellData),
     ConfigCellAccount(ConfigCellAccount),
     ConfigCellApply(ConfigCellApply),
     ConfigCellIncome(ConfigCellIncome),
@@ -299,6 +301,7 @@ pub fn wrap_data_entity_v3(version: u32, index: usize, entity: EntityWrapper) ->
         EntityWrapper::PreAccountCellData(entity) => wrap_data_entity(version, index, entity),
         EntityWrapper::ProposalCellData(entity) => wrap_data_entity(version, index, entity),
         EntityWrapper::AccountCellData(entity) => wrap_data_entity(version, index, entity),
+        EntityWrapper::AccountCellDataV1(entity) => wrap_data_entity(version, index, entity),
         EntityWrapper::AccountCellDataV2(entity) => wrap_data_entity(version, index, entity),
         EntityWrapper::AccountCellDataV3(entity) => wrap_data_entity(version, index, entity),
         EntityWrapper::AccountSaleCellData(entity) => wrap_data_entity(version, index, entity),
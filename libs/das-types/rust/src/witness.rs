@@ -0,0 +1,758 @@
+//! Decode any DAS witness without knowing its type in advance.
+//!
+//! Binary format: 'das'(3) + DATA_TYPE(4) + payload, the payload is decoded by the DATA_TYPE:
+//!
+//! - ActionData and the molecule config cells are the molecule entities;
+//! - the cell data witnesses are wrapped by `Data`, and the entities are decoded by their versions;
+//! - the raw config cells like preserved accounts and char sets are LE uint32 length + bytes;
+//! - the sub-account and reverse record witnesses are a list of fields, every field is LE uint32 length + bytes.
+
+use std::fmt;
+use std::str::FromStr;
+
+use molecule::error::VerificationError;
+use molecule::prelude::*;
+
+use super::constants::*;
+use super::mixer::SubAccountMixer;
+use super::schemas::packed::*;
+use super::util::EntityWrapper;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WitnessDecodingError {
+    HeaderInvalid,
+    DataTypeUnknown(u32),
+    FieldMissing(&'static str),
+    FieldInvalid(&'static str),
+    VersionUnsupported { data_type: DataType, version: u32 },
+    EntityInvalid { name: &'static str, reason: String },
+}
+
+impl fmt::Display for WitnessDecodingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WitnessDecodingError::HeaderInvalid => write!(f, "the witness is not started with 'das'"),
+            WitnessDecodingError::DataTypeUnknown(val) => write!(f, "the data type {} is unknown", val),
+            WitnessDecodingError::FieldMissing(field) => write!(f, "the field {} is missing", field),
+            WitnessDecodingError::FieldInvalid(field) => write!(f, "the field {} is invalid", field),
+            WitnessDecodingError::VersionUnsupported { data_type, version } => {
+                write!(f, "the version {} of {:?} is unsupported", version, data_type)
+            }
+            WitnessDecodingError::EntityInvalid { name, reason } => write!(f, "decoding {} failed: {}", name, reason),
+        }
+    }
+}
+
+/// An entity in the `Data` of cell data witnesses.
+pub struct DataEntityWitness {
+    pub index: u32,
+    pub version: u32,
+    pub entity: EntityWrapper,
+}
+
+pub struct SubAccountWitness {
+    pub version: u32,
+    pub action: SubAccountAction,
+    pub signature: Vec<u8>,
+    pub sign_role: Option<LockRole>,
+    pub sign_expired_at: Option<u64>,
+    pub new_root: Vec<u8>,
+    pub proof: Vec<u8>,
+    pub old_sub_account_version: u32,
+    pub new_sub_account_version: u32,
+    pub sub_account: Box<dyn SubAccountMixer>,
+    pub edit_key: Vec<u8>,
+    pub edit_value: Vec<u8>,
+}
+
+/// The witness of SubAccountMintSign and SubAccountRenewSign.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubAccountSignWitness {
+    pub version: u32,
+    pub signature: Vec<u8>,
+    pub sign_role: Option<LockRole>,
+    pub expired_at: u64,
+    pub account_list_smt_root: Vec<u8>,
+}
+
+/// The witness of SubAccountPriceRule and SubAccountPreservedRule.
+#[derive(Debug, Clone)]
+pub struct SubAccountRuleWitness {
+    pub version: u32,
+    pub rules: SubAccountRules,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReverseRecordWitness {
+    pub version: u32,
+    pub action: ReverseRecordAction,
+    pub signature: Vec<u8>,
+    pub sign_type: DasLockType,
+    pub address_payload: Vec<u8>,
+    pub proof: Vec<u8>,
+    pub prev_nonce: Option<u32>,
+    pub prev_account: String,
+    pub next_root: [u8; 32],
+    pub next_account: String,
+}
+
+pub enum Witness {
+    ActionData(ActionData),
+    Data {
+        data_type: DataType,
+        dep: Option<DataEntityWitness>,
+        old: Option<DataEntityWitness>,
+        new: Option<DataEntityWitness>,
+    },
+    /// The config cells encoded by molecule.
+    Config(DataType, EntityWrapper),
+    /// The config cells not encoded by molecule, the data is the bytes after the length.
+    RawConfig(DataType, Vec<u8>),
+    SubAccount(SubAccountWitness),
+    SubAccountMintSign(SubAccountSignWitness),
+    SubAccountRenewSign(SubAccountSignWitness),
+    SubAccountPriceRule(SubAccountRuleWitness),
+    SubAccountPreservedRule(SubAccountRuleWitness),
+    ReverseRecord(ReverseRecordWitness),
+    DeviceKeyListCellData(DeviceKeyListCellData),
+    OrderInfo(OrderInfo),
+}
+
+impl Witness {
+    pub fn data_type(&self) -> DataType {
+        match self {
+            Witness::ActionData(_) => DataType::ActionData,
+            Witness::Data { data_type, .. } => *data_type,
+            Witness::Config(data_type, _) => *data_type,
+            Witness::RawConfig(data_type, _) => *data_type,
+            Witness::SubAccount(_) => DataType::SubAccount,
+            Witness::SubAccountMintSign(_) => DataType::SubAccountMintSign,
+            Witness::SubAccountRenewSign(_) => DataType::SubAccountRenewSign,
+            Witness::SubAccountPriceRule(_) => DataType::SubAccountPriceRule,
+            Witness::SubAccountPreservedRule(_) => DataType::SubAccountPreservedRule,
+            Witness::ReverseRecord(_) => DataType::ReverseRecord,
+            Witness::DeviceKeyListCellData(_) => DataType::DeviceKeyListCellData,
+            Witness::OrderInfo(_) => DataType::OrderInfo,
+        }
+    }
+}
+
+/// Decode a whole witness including the 'das' header and the DATA_TYPE.
+pub fn decode_witness(witness: &[u8]) -> Result<Witness, WitnessDecodingError> {
+    match witness.get(..WITNESS_HEADER_BYTES) {
+        Some(header) if header == WITNESS_HEADER => {}
+        _ => return Err(WitnessDecodingError::HeaderInvalid),
+    }
+
+    let data_type_bytes = witness
+        .get(WITNESS_HEADER_BYTES..(WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES))
+        .ok_or(WitnessDecodingError::FieldMissing("data_type"))?;
+    let data_type_in_int = u32::from_le_bytes(data_type_bytes.try_into().unwrap());
+    let data_type =
+        DataType::try_from(data_type_in_int).map_err(|_| WitnessDecodingError::DataTypeUnknown(data_type_in_int))?;
+
+    decode_payload(data_type, &witness[(WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES)..])
+}
+
+/// Decode the payload after the DATA_TYPE.
+pub fn decode_payload(data_type: DataType, payload: &[u8]) -> Result<Witness, WitnessDecodingError> {
+    let ret = match data_type {
+        DataType::ActionData => Witness::ActionData(decode_entity("ActionData", payload)?),
+        DataType::AccountCellData
+        | DataType::AccountSaleCellData
+        | DataType::AccountAuctionCellData
+        | DataType::ProposalCellData
+        | DataType::PreAccountCellData
+        | DataType::IncomeCellData
+        | DataType::OfferCellData
+        | DataType::DeviceKeyListEntityData => {
+            // The Data may be followed by redundant bytes, trim it to its total size like the contracts do.
+            let length = payload
+                .get(..4)
+                .map(|raw| u32::from_le_bytes(raw.try_into().unwrap()) as usize)
+                .ok_or(WitnessDecodingError::FieldMissing("Data.total_size"))?;
+            let raw = payload
+                .get(..length)
+                .ok_or(WitnessDecodingError::FieldInvalid("Data.total_size"))?;
+            let data: Data = decode_entity("Data", raw)?;
+            let decode = |opt: DataEntityOpt| -> Result<Option<DataEntityWitness>, WitnessDecodingError> {
+                match opt.to_opt() {
+                    Some(entity) => decode_data_entity(data_type, entity).map(Some),
+                    None => Ok(None),
+                }
+            };
+
+            Witness::Data {
+                data_type,
+                dep: decode(data.dep())?,
+                old: decode(data.old())?,
+                new: decode(data.new())?,
+            }
+        }
+        DataType::SubAccount => Witness::SubAccount(decode_sub_account_witness(payload)?),
+        DataType::SubAccountMintSign => Witness::SubAccountMintSign(decode_sign_witness(payload)?),
+        DataType::SubAccountRenewSign => Witness::SubAccountRenewSign(decode_sign_witness(payload)?),
+        DataType::SubAccountPriceRule => Witness::SubAccountPriceRule(decode_rule_witness(data_type, payload)?),
+        DataType::SubAccountPreservedRule => Witness::SubAccountPreservedRule(decode_rule_witness(data_type, payload)?),
+        DataType::ReverseRecord => Witness::ReverseRecord(decode_reverse_record_witness(payload)?),
+        DataType::DeviceKeyListCellData => {
+            Witness::DeviceKeyListCellData(decode_entity("DeviceKeyListCellData", payload)?)
+        }
+        DataType::OrderInfo => Witness::OrderInfo(decode_entity("OrderInfo", payload)?),
+        DataType::ConfigCellAccount => Witness::Config(
+            data_type,
+            EntityWrapper::ConfigCellAccount(decode_entity("ConfigCellAccount", payload)?),
+        ),
+        DataType::ConfigCellApply => Witness::Config(
+            data_type,
+            EntityWrapper::ConfigCellApply(decode_entity("ConfigCellApply", payload)?),
+        ),
+        DataType::ConfigCellIncome => Witness::Config(
+            data_type,
+            EntityWrapper::ConfigCellIncome(decode_entity("ConfigCellIncome", payload)?),
+        ),
+        DataType::ConfigCellMain => Witness::Config(
+            data_type,
+            EntityWrapper::ConfigCellMain(decode_entity("ConfigCellMain", payload)?),
+        ),
+        DataType::ConfigCellPrice => Witness::Config(
+            data_type,
+            EntityWrapper::ConfigCellPrice(decode_entity("ConfigCellPrice", payload)?),
+        ),
+        DataType::ConfigCellProposal => Witness::Config(
+            data_type,
+            EntityWrapper::ConfigCellProposal(decode_entity("ConfigCellProposal", payload)?),
+        ),
+        DataType::ConfigCellProfitRate => Witness::Config(
+            data_type,
+            EntityWrapper::ConfigCellProfitRate(decode_entity("ConfigCellProfitRate", payload)?),
+        ),
+        DataType::ConfigCellRelease => Witness::Config(
+            data_type,
+            EntityWrapper::ConfigCellRelease(decode_entity("ConfigCellRelease", payload)?),
+        ),
+        DataType::ConfigCellSecondaryMarket => Witness::Config(
+            data_type,
+            EntityWrapper::ConfigCellSecondaryMarket(decode_entity("ConfigCellSecondaryMarket", payload)?),
+        ),
+        DataType::ConfigCellReverseResolution => Witness::Config(
+            data_type,
+            EntityWrapper::ConfigCellReverseResolution(decode_entity("ConfigCellReverseResolution", payload)?),
+        ),
+        DataType::ConfigCellSubAccount => Witness::Config(
+            data_type,
+            EntityWrapper::ConfigCellSubAccount(decode_entity("ConfigCellSubAccount", payload)?),
+        ),
+        DataType::ConfigCellSystemStatus => Witness::Config(
+            data_type,
+            EntityWrapper::ConfigCellSytemStatus(decode_entity("ConfigCellSystemStatus", payload)?),
+        ),
+        DataType::ConfigCellDPoint => Witness::Config(
+            data_type,
+            EntityWrapper::ConfigCellDPoint(decode_entity("ConfigCellDPoint", payload)?),
+        ),
+        // The rest are the raw config cells, like ConfigCellRecordKeyNamespace, ConfigCellPreservedAccountXX and
+        // ConfigCellCharSetXX, they all start with the LE uint32 length of the whole payload.
+        _ => {
+            let length_bytes = payload
+                .get(..WITNESS_LENGTH_BYTES)
+                .ok_or(WitnessDecodingError::FieldMissing("length"))?;
+            let length = u32::from_le_bytes(length_bytes.try_into().unwrap()) as usize;
+            if length != payload.len() {
+                return Err(WitnessDecodingError::FieldInvalid("length"));
+            }
+
+            Witness::RawConfig(data_type, payload[WITNESS_LENGTH_BYTES..].to_vec())
+        }
+    };
+
+    Ok(ret)
+}
+
+fn decode_entity<T: Entity>(name: &'static str, bytes: &[u8]) -> Result<T, WitnessDecodingError> {
+    T::from_compatible_slice(bytes).map_err(|err: VerificationError| WitnessDecodingError::EntityInvalid {
+        name,
+        reason: err.to_string(),
+    })
+}
+
+fn decode_data_entity(data_type: DataType, data_entity: DataEntity) -> Result<DataEntityWitness, WitnessDecodingError> {
+    let version = u32::from(data_entity.version());
+    let index = u32::from(data_entity.index());
+    let raw = data_entity.entity();
+    let bytes = raw.as_reader().raw_data();
+
+    let entity = match (data_type, version) {
+        (DataType::AccountCellData, 1) => EntityWrapper::AccountCellDataV1(decode_entity("AccountCellDataV1", bytes)?),
+        (DataType::AccountCellData, 2) => EntityWrapper::AccountCellDataV2(decode_entity("AccountCellDataV2", bytes)?),
+        (DataType::AccountCellData, 3) => EntityWrapper::AccountCellDataV3(decode_entity("AccountCellDataV3", bytes)?),
+        (DataType::AccountCellData, 4) => EntityWrapper::AccountCellData(decode_entity("AccountCellData", bytes)?),
+        (DataType::PreAccountCellData, 1) => {
+            EntityWrapper::PreAccountCellDataV1(decode_entity("PreAccountCellDataV1", bytes)?)
+        }
+        (DataType::PreAccountCellData, 2) => {
+            EntityWrapper::PreAccountCellDataV2(decode_entity("PreAccountCellDataV2", bytes)?)
+        }
+        (DataType::PreAccountCellData, 3) => {
+            EntityWrapper::PreAccountCellData(decode_entity("PreAccountCellData", bytes)?)
+        }
+        (DataType::AccountSaleCellData, 1) => {
+            EntityWrapper::AccountSaleCellDataV1(decode_entity("AccountSaleCellDataV1", bytes)?)
+        }
+        (DataType::AccountSaleCellData, 2) => {
+            EntityWrapper::AccountSaleCellData(decode_entity("AccountSaleCellData", bytes)?)
+        }
+        // The following types have only one version for now.
+        (DataType::AccountAuctionCellData, _) => {
+            EntityWrapper::AccountAuctionCellData(decode_entity("AccountAuctionCellData", bytes)?)
+        }
+        (DataType::ProposalCellData, _) => EntityWrapper::ProposalCellData(decode_entity("ProposalCellData", bytes)?),
+        (DataType::IncomeCellData, _) => EntityWrapper::IncomeCellData(decode_entity("IncomeCellData", bytes)?),
+        (DataType::OfferCellData, _) => EntityWrapper::OfferCellData(decode_entity("OfferCellData", bytes)?),
+        (DataType::DeviceKeyListEntityData, _) => {
+            EntityWrapper::DeviceKeyListCellData(decode_entity("DeviceKeyListCellData", bytes)?)
+        }
+        _ => return Err(WitnessDecodingError::VersionUnsupported { data_type, version }),
+    };
+
+    Ok(DataEntityWitness { index, version, entity })
+}
+
+/// Every field is start with 4 bytes of LE uint32 as its length.
+fn parse_field<'a>(
+    field: &'static str,
+    bytes: &'a [u8],
+    start: usize,
+) -> Result<(usize, &'a [u8]), WitnessDecodingError> {
+    let length_bytes = bytes
+        .get(start..(start + WITNESS_LENGTH_BYTES))
+        .ok_or(WitnessDecodingError::FieldMissing(field))?;
+    let from = start + WITNESS_LENGTH_BYTES;
+    let to = from + u32::from_le_bytes(length_bytes.try_into().unwrap()) as usize;
+    let field_bytes = bytes.get(from..to).ok_or(WitnessDecodingError::FieldMissing(field))?;
+
+    Ok((to, field_bytes))
+}
+
+fn parse_u32(field: &'static str, bytes: &[u8]) -> Result<u32, WitnessDecodingError> {
+    bytes
+        .try_into()
+        .map(u32::from_le_bytes)
+        .map_err(|_| WitnessDecodingError::FieldInvalid(field))
+}
+
+fn parse_u64(field: &'static str, bytes: &[u8]) -> Result<u64, WitnessDecodingError> {
+    bytes
+        .try_into()
+        .map(u64::from_le_bytes)
+        .map_err(|_| WitnessDecodingError::FieldInvalid(field))
+}
+
+/// The sign_role is empty when the witness need no signature.
+fn parse_sign_role(bytes: &[u8]) -> Result<Option<LockRole>, WitnessDecodingError> {
+    match bytes {
+        [] => Ok(None),
+        [val] => LockRole::try_from(*val)
+            .map(Some)
+            .map_err(|_| WitnessDecodingError::FieldInvalid("sign_role")),
+        _ => Err(WitnessDecodingError::FieldInvalid("sign_role")),
+    }
+}
+
+fn parse_string(field: &'static str, bytes: &[u8]) -> Result<String, WitnessDecodingError> {
+    String::from_utf8(bytes.to_vec()).map_err(|_| WitnessDecodingError::FieldInvalid(field))
+}
+
+fn decode_sub_account_witness(payload: &[u8]) -> Result<SubAccountWitness, WitnessDecodingError> {
+    let (start, version_bytes) = parse_field("version", payload, 0)?;
+    let (start, action_bytes) = parse_field("action", payload, start)?;
+    let (start, signature) = parse_field("signature", payload, start)?;
+    let (start, sign_role_bytes) = parse_field("sign_role", payload, start)?;
+    let (start, sign_expired_at_bytes) = parse_field("sign_expired_at", payload, start)?;
+    let (start, new_root) = parse_field("new_root", payload, start)?;
+    let (start, proof) = parse_field("proof", payload, start)?;
+
+    let version = parse_u32("version", version_bytes)?;
+    let (start, old_sub_account_version, new_sub_account_version) = if version == 3 {
+        let (start, old_bytes) = parse_field("old_sub_account_version", payload, start)?;
+        let (start, new_bytes) = parse_field("new_sub_account_version", payload, start)?;
+        (
+            start,
+            parse_u32("old_sub_account_version", old_bytes)?,
+            parse_u32("new_sub_account_version", new_bytes)?,
+        )
+    } else {
+        (start, 1, 1)
+    };
+
+    let (start, sub_account_bytes) = parse_field("sub_account", payload, start)?;
+    let (start, edit_key) = parse_field("edit_key", payload, start)?;
+    let (_, edit_value) = parse_field("edit_value", payload, start)?;
+
+    let action = SubAccountAction::from_str(&parse_string("action", action_bytes)?)
+        .map_err(|_| WitnessDecodingError::FieldInvalid("action"))?;
+    let sign_expired_at = match sign_expired_at_bytes {
+        [] => None,
+        bytes => Some(parse_u64("sign_expired_at", bytes)?),
+    };
+
+    // The new created SubAccount should always be the latest version.
+    let sub_account: Box<dyn SubAccountMixer> = match (action, old_sub_account_version) {
+        (SubAccountAction::Create, _) | (_, 2) => {
+            Box::new(decode_entity::<SubAccount>("SubAccount", sub_account_bytes)?)
+        }
+        (_, 1) => Box::new(decode_entity::<SubAccountV1>("SubAccountV1", sub_account_bytes)?),
+        (_, version) => {
+            return Err(WitnessDecodingError::VersionUnsupported {
+                data_type: DataType::SubAccount,
+                version,
+            })
+        }
+    };
+
+    Ok(SubAccountWitness {
+        version,
+        action,
+        signature: signature.to_vec(),
+        sign_role: parse_sign_role(sign_role_bytes)?,
+        sign_expired_at,
+        new_root: new_root.to_vec(),
+        proof: proof.to_vec(),
+        old_sub_account_version,
+        new_sub_account_version,
+        sub_account,
+        edit_key: edit_key.to_vec(),
+        edit_value: edit_value.to_vec(),
+    })
+}
+
+fn decode_sign_witness(payload: &[u8]) -> Result<SubAccountSignWitness, WitnessDecodingError> {
+    let (start, version_bytes) = parse_field("version", payload, 0)?;
+    let (start, signature) = parse_field("signature", payload, start)?;
+    let (start, sign_role_bytes) = parse_field("sign_role", payload, start)?;
+    let (start, expired_at_bytes) = parse_field("expired_at", payload, start)?;
+    let (_, account_list_smt_root) = parse_field("account_list_smt_root", payload, start)?;
+
+    Ok(SubAccountSignWitness {
+        version: parse_u32("version", version_bytes)?,
+        signature: signature.to_vec(),
+        sign_role: parse_sign_role(sign_role_bytes)?,
+        expired_at: parse_u64("expired_at", expired_at_bytes)?,
+        account_list_smt_root: account_list_smt_root.to_vec(),
+    })
+}
+
+fn decode_rule_witness(data_type: DataType, payload: &[u8]) -> Result<SubAccountRuleWitness, WitnessDecodingError> {
+    let (start, version_bytes) = parse_field("version", payload, 0)?;
+    let (_, rules_bytes) = parse_field("rules", payload, start)?;
+
+    let version = parse_u32("version", version_bytes)?;
+    if version != 1 {
+        return Err(WitnessDecodingError::VersionUnsupported { data_type, version });
+    }
+
+    Ok(SubAccountRuleWitness {
+        version,
+        rules: decode_entity("SubAccountRules", rules_bytes)?,
+    })
+}
+
+fn decode_reverse_record_witness(payload: &[u8]) -> Result<ReverseRecordWitness, WitnessDecodingError> {
+    let (start, version_bytes) = parse_field("version", payload, 0)?;
+    let (start, action_bytes) = parse_field("action", payload, start)?;
+    let (start, signature) = parse_field("signature", payload, start)?;
+    let (start, sign_type_bytes) = parse_field("sign_type", payload, start)?;
+    let (start, address_payload) = parse_field("address_payload", payload, start)?;
+    let (start, proof) = parse_field("proof", payload, start)?;
+    let (start, prev_nonce_bytes) = parse_field("prev_nonce", payload, start)?;
+    let (start, prev_account) = parse_field("prev_account", payload, start)?;
+    let (start, next_root) = parse_field("next_root", payload, start)?;
+    let (_, next_account) = parse_field("next_account", payload, start)?;
+
+    let version = parse_u32("version", version_bytes)?;
+    if version != 1 {
+        return Err(WitnessDecodingError::VersionUnsupported {
+            data_type: DataType::ReverseRecord,
+            version,
+        });
+    }
+
+    let action = ReverseRecordAction::from_str(&parse_string("action", action_bytes)?)
+        .map_err(|_| WitnessDecodingError::FieldInvalid("action"))?;
+    let sign_type = match sign_type_bytes {
+        [val] => DasLockType::try_from(*val).map_err(|_| WitnessDecodingError::FieldInvalid("sign_type"))?,
+        _ => return Err(WitnessDecodingError::FieldInvalid("sign_type")),
+    };
+    let prev_nonce = match prev_nonce_bytes {
+        [] => None,
+        bytes => Some(parse_u32("prev_nonce", bytes)?),
+    };
+
+    Ok(ReverseRecordWitness {
+        version,
+        action,
+        signature: signature.to_vec(),
+        sign_type,
+        address_payload: address_payload.to_vec(),
+        proof: proof.to_vec(),
+        prev_nonce,
+        prev_account: parse_string("prev_account", prev_account)?,
+        next_root: next_root
+            .try_into()
+            .map_err(|_| WitnessDecodingError::FieldInvalid("next_root"))?,
+        next_account: parse_string("next_account", next_account)?,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::upgrade::Upgrade;
+    use crate::util::{wrap_action_witness_v3, wrap_data_witness_v4, wrap_entity_witness_v2, wrap_sub_account_witness};
+
+    fn lv(fields: &[&[u8]]) -> Vec<u8> {
+        let mut ret = vec![];
+        for field in fields {
+            ret.extend((field.len() as u32).to_le_bytes());
+            ret.extend(*field);
+        }
+        ret
+    }
+
+    #[test]
+    fn test_decode_entity_witnesses() {
+        let witness = wrap_action_witness_v3("transfer_account", Some(vec![0]));
+        match decode_witness(&witness).unwrap() {
+            Witness::ActionData(action) => assert_eq!(b"transfer_account", action.action().raw_data().as_ref()),
+            _ => panic!("The witness should be decoded to ActionData."),
+        }
+
+        let account_cell_data = AccountCellDataV3::new_builder()
+            .registered_at(Uint64::from(100))
+            .build();
+        let witness = wrap_data_witness_v4(
+            DataType::AccountCellData,
+            3,
+            1,
+            EntityWrapper::AccountCellDataV3(account_cell_data.clone()),
+            Source::Output,
+        );
+        match decode_witness(&witness).unwrap() {
+            Witness::Data {
+                data_type,
+                dep: None,
+                old: None,
+                new: Some(new),
+            } => {
+                assert_eq!(DataType::AccountCellData, data_type);
+                assert_eq!((1, 3), (new.index, new.version));
+                assert!(
+                    matches!(new.entity, EntityWrapper::AccountCellDataV3(entity) if entity.as_slice() == account_cell_data.as_slice())
+                );
+            }
+            _ => panic!("The witness should be decoded to Data with a new entity."),
+        }
+
+        // The version 5 of AccountCellData does not exist.
+        let witness = wrap_data_witness_v4(
+            DataType::AccountCellData,
+            5,
+            0,
+            EntityWrapper::AccountCellData(AccountCellData::default()),
+            Source::Input,
+        );
+        assert!(matches!(
+            decode_witness(&witness),
+            Err(WitnessDecodingError::VersionUnsupported { version: 5, .. })
+        ));
+
+        let witness = wrap_entity_witness_v2(DataType::ConfigCellMain, ConfigCellMain::default());
+        assert!(matches!(
+            decode_witness(&witness),
+            Ok(Witness::Config(
+                DataType::ConfigCellMain,
+                EntityWrapper::ConfigCellMain(_)
+            ))
+        ));
+
+        let mut payload = 9u32.to_le_bytes().to_vec();
+        payload.extend([1, 0x61, 0x62, 0x63, 0x64]);
+        let witness = wrap_sub_account_witness(DataType::ConfigCellCharSetEn, payload);
+        match decode_witness(&witness).unwrap() {
+            Witness::RawConfig(DataType::ConfigCellCharSetEn, data) => {
+                assert_eq!(vec![1, 0x61, 0x62, 0x63, 0x64], data)
+            }
+            _ => panic!("The witness should be decoded to RawConfig."),
+        }
+    }
+
+    #[test]
+    fn test_decode_lv_witnesses() {
+        let sub_account = SubAccountV1::new_builder().nonce(Uint64::from(1)).build();
+        let payload = lv(&[
+            &3u32.to_le_bytes(),
+            b"edit",
+            &[1; 65],
+            &[0],
+            &100u64.to_le_bytes(),
+            &[2; 32],
+            &[3; 10],
+            &1u32.to_le_bytes(),
+            &2u32.to_le_bytes(),
+            Entity::as_slice(&sub_account),
+            b"records",
+            &[],
+        ]);
+        let witness = wrap_sub_account_witness(DataType::SubAccount, payload);
+        match decode_witness(&witness).unwrap() {
+            Witness::SubAccount(witness) => {
+                assert_eq!(SubAccountAction::Edit, witness.action);
+                assert_eq!(Some(LockRole::Owner), witness.sign_role);
+                assert_eq!(Some(100), witness.sign_expired_at);
+                assert_eq!(
+                    (1, 2),
+                    (witness.old_sub_account_version, witness.new_sub_account_version)
+                );
+                assert_eq!(1, witness.sub_account.version());
+                assert_eq!(b"records", witness.edit_key.as_slice());
+            }
+            _ => panic!("The witness should be decoded to SubAccount."),
+        }
+
+        let payload = lv(&[
+            &1u32.to_le_bytes(),
+            b"update",
+            &[1; 65],
+            &[DasLockType::ETH as u8],
+            &[4; 20],
+            &[],
+            &[],
+            &[],
+            &[5; 32],
+            b"xxxxx.bit",
+        ]);
+        let witness = wrap_sub_account_witness(DataType::ReverseRecord, payload.clone());
+        match decode_witness(&witness).unwrap() {
+            Witness::ReverseRecord(witness) => {
+                assert_eq!(ReverseRecordAction::Update, witness.action);
+                assert_eq!(DasLockType::ETH, witness.sign_type);
+                assert_eq!(None, witness.prev_nonce);
+                assert_eq!("xxxxx.bit", witness.next_account);
+            }
+            _ => panic!("The witness should be decoded to ReverseRecord."),
+        }
+
+        // Every field is required even if it is empty.
+        let witness = wrap_sub_account_witness(DataType::ReverseRecord, payload[..payload.len() - 13].to_vec());
+        assert!(matches!(
+            decode_witness(&witness),
+            Err(WitnessDecodingError::FieldMissing("next_account"))
+        ));
+
+        let payload = lv(&[&1u32.to_le_bytes(), SubAccountRules::default().as_slice()]);
+        let witness = wrap_sub_account_witness(DataType::SubAccountPriceRule, payload);
+        assert!(matches!(
+            decode_witness(&witness),
+            Ok(Witness::SubAccountPriceRule(SubAccountRuleWitness { version: 1, .. }))
+        ));
+    }
+
+    #[test]
+    fn test_decode_invalid_witnesses() {
+        assert!(matches!(
+            decode_witness(b"abc"),
+            Err(WitnessDecodingError::HeaderInvalid)
+        ));
+        assert!(matches!(
+            decode_witness(b"das\xff\xff\xff\xff"),
+            Err(WitnessDecodingError::DataTypeUnknown(u32::MAX))
+        ));
+        assert!(matches!(
+            decode_witness(&wrap_sub_account_witness(DataType::ActionData, vec![1, 2, 3])),
+            Err(WitnessDecodingError::EntityInvalid { name: "ActionData", .. })
+        ));
+        assert!(matches!(
+            decode_witness(&wrap_sub_account_witness(DataType::AccountCellData, vec![1, 2])),
+            Err(WitnessDecodingError::FieldMissing("Data.total_size"))
+        ));
+        assert!(matches!(
+            decode_witness(&wrap_sub_account_witness(DataType::AccountCellData, vec![100, 0, 0, 0])),
+            Err(WitnessDecodingError::FieldInvalid("Data.total_size"))
+        ));
+    }
+
+    #[test]
+    fn test_decode_data_witness_with_redundant_bytes() {
+        let mut witness = wrap_data_witness_v4(
+            DataType::AccountCellData,
+            4,
+            0,
+            EntityWrapper::AccountCellData(AccountCellData::default()),
+            Source::Output,
+        );
+        witness.extend([0; 8]);
+        assert!(matches!(
+            decode_witness(&witness),
+            Ok(Witness::Data {
+                new: Some(DataEntityWitness { version: 4, .. }),
+                ..
+            })
+        ));
+    }
+
+    /// The vectors are generated by `examples/gen_vectors.rs` for the bindings in other languages, they should always
+    /// be decoded by the current version of this crate.
+    #[test]
+    fn test_conformance_vectors() {
+        fn to_json<T: serde::Serialize>(val: &T) -> serde_json::Value {
+            serde_json::to_value(val).unwrap()
+        }
+
+        let vectors: serde_json::Value = serde_json::from_str(include_str!("../../vectors/cell_data.json")).unwrap();
+        for vector in vectors.as_array().unwrap() {
+            let name = vector["name"].as_str().unwrap();
+            let witness = hex::decode(vector["witness"].as_str().unwrap().trim_start_matches("0x")).unwrap();
+            let (version, entity) = match decode_witness(&witness).unwrap() {
+                Witness::Data { new: Some(val), .. } => (val.version, val.entity),
+                Witness::SubAccount(val) => match val.sub_account.version() {
+                    1 => (1, EntityWrapper::SubAccountV1(val.sub_account.try_into_v1().unwrap())),
+                    _ => (2, EntityWrapper::SubAccount(val.sub_account.try_into_latest().unwrap())),
+                },
+                _ => panic!("The witness of {} should be decoded to Data.new or SubAccount.", name),
+            };
+            assert_eq!(vector["version"], version, "{}", name);
+
+            let (schema, decoded, latest) = match entity {
+                EntityWrapper::AccountCellDataV1(val) => {
+                    ("AccountCellDataV1", to_json(&val), to_json(&val.upgrade_to_latest()))
+                }
+                EntityWrapper::AccountCellDataV2(val) => {
+                    ("AccountCellDataV2", to_json(&val), to_json(&val.upgrade_to_latest()))
+                }
+                EntityWrapper::AccountCellDataV3(val) => {
+                    ("AccountCellDataV3", to_json(&val), to_json(&val.upgrade_to_latest()))
+                }
+                EntityWrapper::AccountCellData(val) => ("AccountCellData", to_json(&val), to_json(&val)),
+                EntityWrapper::PreAccountCellDataV1(val) => {
+                    ("PreAccountCellDataV1", to_json(&val), to_json(&val.upgrade_to_latest()))
+                }
+                EntityWrapper::PreAccountCellDataV2(val) => {
+                    ("PreAccountCellDataV2", to_json(&val), to_json(&val.upgrade_to_latest()))
+                }
+                EntityWrapper::PreAccountCellData(val) => ("PreAccountCellData", to_json(&val), to_json(&val)),
+                EntityWrapper::SubAccountV1(val) => ("SubAccountV1", to_json(&val), to_json(&val.upgrade_to_latest())),
+                EntityWrapper::SubAccount(val) => ("SubAccount", to_json(&val), to_json(&val)),
+                EntityWrapper::AccountSaleCellDataV1(val) => (
+                    "AccountSaleCellDataV1",
+                    to_json(&val),
+                    to_json(&val.upgrade_to_latest()),
+                ),
+                EntityWrapper::AccountSaleCellData(val) => ("AccountSaleCellData", to_json(&val), to_json(&val)),
+                _ => panic!("The entity of {} is not covered.", name),
+            };
+            assert_eq!(vector["schema"], schema, "{}", name);
+            assert_eq!(vector["decoded"], decoded, "{}", name);
+            assert_eq!(vector["latest"], latest, "{}", name);
+        }
+    }
+}
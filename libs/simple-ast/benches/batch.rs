@@ -0,0 +1,93 @@
+//! Compare `evaluate_batch` with matching the names one by one with freshly built molecule entities.
+//!
+//! Run with `cargo bench -p simple-ast --bench batch`.
+
+use std::path::PathBuf;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use das_types::packed;
+use simple_ast::batch::{evaluate_batch, CharSetTable};
+use simple_ast::dsl;
+use simple_ast::executor::match_price_rule_with_account_chars;
+use simple_ast::types::{RuleContext, SubAccountRule, SubAccountRuleStatus};
+
+const NAMES: usize = 10_000;
+
+fn gen_rule(index: u32, price: u64, text: &str) -> SubAccountRule {
+    SubAccountRule {
+        index,
+        name: String::new(),
+        note: String::new(),
+        price,
+        status: SubAccountRuleStatus::On,
+        ast: dsl::parse(text).unwrap(),
+    }
+}
+
+fn gen_names() -> Vec<String> {
+    let alphabet: Vec<char> = "abcdefghijklmnopqrstuvwxyz0123456789".chars().collect();
+    let mut seed = 0x2545_f491_u64;
+    (0..NAMES)
+        .map(|_| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            let length = 1 + (seed % 10) as usize;
+            (0..length)
+                .map(|i| alphabet[((seed >> (i * 5)) % alphabet.len() as u64) as usize])
+                .collect()
+        })
+        .collect()
+}
+
+fn bench_batch(c: &mut Criterion) {
+    let table = CharSetTable::from_dir(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../tests/data")).unwrap();
+    let rules = vec![
+        gen_rule(0, 0, r#"starts_with(account, ["vip"])"#),
+        gen_rule(
+            1,
+            100,
+            "account_length <= 2 and only_include_charset(account_chars, digit)",
+        ),
+        gen_rule(2, 0, "select_by_length(account_length, 50u64, 20u64, 10u64, 5u64)"),
+    ];
+    let names = gen_names();
+    let context = RuleContext::default();
+
+    let mut group = c.benchmark_group("batch");
+    group.sample_size(10);
+    group.bench_function("evaluate_batch", |b| {
+        b.iter(|| {
+            evaluate_batch(
+                &rules,
+                true,
+                &table,
+                names.iter().map(|name| name.as_str()),
+                ".parent.bit",
+                &context,
+            )
+        })
+    });
+    // Splitting the names by the char sets is the lower bound of both.
+    group.bench_function("classify_only", |b| {
+        b.iter(|| names.iter().filter(|name| table.classify(name).is_ok()).count())
+    });
+    group.bench_function("one_by_one", |b| {
+        b.iter(|| {
+            names
+                .iter()
+                .filter_map(|name| {
+                    let account_chars = packed::AccountChars::from(table.classify(name).ok()?);
+                    let account = format!("{}.parent.bit", name);
+                    match_price_rule_with_account_chars(&rules, account_chars.as_reader(), &account, &context)
+                        .unwrap()
+                        .map(|(rule, price)| (rule.index, price))
+                })
+                .count()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_batch);
+criterion_main!(benches);
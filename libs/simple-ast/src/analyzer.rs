@@ -0,0 +1,681 @@
+//! Static analysis of sub-account rules, it is designed to reject bad rules before any transaction is built.
+//!
+//! The analyzer checks the types of all expressions without an account, finds the rules which can never be reached
+//! and estimates the worst-case cycles of `executor::match_rule_with_account_chars`.
+
+use crate::error::ASTError;
+use crate::pattern::{self, MAX_STEPS};
+use crate::types::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum UnreachableReason {
+    /// The rule is shadowed by an earlier rule which always matches, the value is the index of that rule.
+    ShadowedBy(u32),
+    /// The AST of the rule always returns false.
+    NeverMatches,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnreachableRule {
+    pub key: String,
+    pub index: u32,
+    pub reason: UnreachableReason,
+}
+
+#[derive(Debug, Default)]
+pub struct RulesReport {
+    pub errors: Vec<ASTError>,
+    pub unreachable: Vec<UnreachableRule>,
+    pub estimated_cycles: u64,
+}
+
+impl RulesReport {
+    pub fn is_ok(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// The cost of every kind of work in the executor, the defaults are rough upper bounds and they can be tuned with the
+/// real cycles from ckb-vm.
+#[derive(Debug, Clone)]
+pub struct CycleCostModel {
+    /// The cost of matching a rule, including checking its status and its AST type.
+    pub rule: u64,
+    /// The cost of decoding an expression from molecule and dispatching it.
+    pub expression: u64,
+    /// The cost of processing one byte of the account or the arguments.
+    pub byte: u64,
+    /// The cost of calculating the account ID with blake2b.
+    pub blake2b: u64,
+    /// The cost of one step of the pattern matching.
+    pub pattern_step: u64,
+}
+
+impl Default for CycleCostModel {
+    fn default() -> Self {
+        CycleCostModel {
+            rule: 2_000,
+            expression: 5_000,
+            byte: 50,
+            blake2b: 20_000,
+            pattern_step: 100,
+        }
+    }
+}
+
+/// The max length of accounts in chars, it is used for estimating cycles.
+pub const DEFAULT_MAX_ACCOUNT_LENGTH: usize = 42;
+/// The max bytes of a char in UTF-8.
+const MAX_CHAR_BYTES: u64 = 4;
+
+pub fn analyze_rules(rules: &[SubAccountRule], is_price_rules: bool) -> RulesReport {
+    analyze_rules_with_model(
+        rules,
+        is_price_rules,
+        &CycleCostModel::default(),
+        DEFAULT_MAX_ACCOUNT_LENGTH,
+    )
+}
+
+pub fn analyze_rules_with_model(
+    rules: &[SubAccountRule],
+    is_price_rules: bool,
+    model: &CycleCostModel,
+    max_account_length: usize,
+) -> RulesReport {
+    let mut report = RulesReport::default();
+    let mut always_matched_by: Option<u32> = None;
+
+    for (i, rule) in rules.iter().enumerate() {
+        if rule.status == SubAccountRuleStatus::Off {
+            continue;
+        }
+
+        let key = format!("rules[{}]", i);
+        report.estimated_cycles += model.rule + estimate_expression_cycles(&rule.ast, model, max_account_length);
+
+        if let Err(err) = check_rule(&key, rule, is_price_rules) {
+            report.errors.push(err);
+            continue;
+        }
+
+        if let Some(index) = always_matched_by {
+            report.unreachable.push(UnreachableRule {
+                key,
+                index: rule.index,
+                reason: UnreachableReason::ShadowedBy(index),
+            });
+            continue;
+        }
+
+        match const_eval(&rule.ast) {
+            Some(Value::Bool(false)) => report.unreachable.push(UnreachableRule {
+                key,
+                index: rule.index,
+                reason: UnreachableReason::NeverMatches,
+            }),
+            // A rule calculating price always matches, just like a rule always returning true.
+            Some(Value::Bool(true)) => always_matched_by = Some(rule.index),
+            _ => {
+                if is_numeric(infer_type(&format!("{}.ast", key), &rule.ast).ok()) {
+                    always_matched_by = Some(rule.index)
+                }
+            }
+        }
+    }
+
+    report
+}
+
+pub fn check_rule(key: &str, rule: &SubAccountRule, is_price_rules: bool) -> Result<ValueType, ASTError> {
+    let key = format!("{}.ast", key);
+    match rule.ast {
+        Expression::Function(_) | Expression::Operator(_) => {}
+        _ => return Err(ASTError::FunctionOrOperatorRequired { key }),
+    }
+
+    let ret = infer_type(&key, &rule.ast)?;
+    if ret == ValueType::Bool || (is_price_rules && is_numeric(Some(ret))) {
+        Ok(ret)
+    } else {
+        Err(ASTError::ReturnTypeError {
+            key,
+            types: if is_price_rules {
+                String::from("Bool, Uint8, Uint32, Uint64")
+            } else {
+                ValueType::Bool.to_string()
+            },
+        })
+    }
+}
+
+fn is_numeric(value_type: Option<ValueType>) -> bool {
+    matches!(
+        value_type,
+        Some(ValueType::Uint8) | Some(ValueType::Uint32) | Some(ValueType::Uint64)
+    )
+}
+
+fn assert_numeric(key: String, value_type: ValueType) -> Result<ValueType, ASTError> {
+    if is_numeric(Some(value_type)) {
+        Ok(value_type)
+    } else {
+        Err(ASTError::ParamTypeError {
+            key,
+            types: String::from("Uint8, Uint32, Uint64"),
+        })
+    }
+}
+
+fn wider(left: ValueType, right: ValueType) -> ValueType {
+    let width = |value_type| match value_type {
+        ValueType::Uint8 => 1,
+        ValueType::Uint32 => 4,
+        _ => 8,
+    };
+    if width(left) >= width(right) {
+        left
+    } else {
+        right
+    }
+}
+
+fn assert_length(key: String, length: usize, expected: usize, allow_more: bool) -> Result<(), ASTError> {
+    if length == expected || (allow_more && length > expected) {
+        Ok(())
+    } else {
+        Err(ASTError::ParamLengthError {
+            key,
+            expected_length: if allow_more {
+                format!(">= {}", expected)
+            } else {
+                expected.to_string()
+            },
+            length: format!("it is {}", length),
+        })
+    }
+}
+
+/// Infer the return type of the expression with the same rules as the executor, but without any account.
+pub fn infer_type(key: &str, expression: &Expression) -> Result<ValueType, ASTError> {
+    match expression {
+        Expression::Operator(operator) => infer_operator_type(key, operator),
+        Expression::Function(function) => infer_function_type(key, function),
+        Expression::Variable(variable) => Ok(match variable.name {
+            VarName::Account => ValueType::String,
+            VarName::AccountChars => ValueType::StringVec,
+            VarName::AccountLength => ValueType::Uint32,
+            VarName::Timestamp => ValueType::Uint64,
+            VarName::Years => ValueType::Uint32,
+            VarName::ParentAccount => ValueType::String,
+            VarName::MintedCount => ValueType::Uint32,
+        }),
+        Expression::Value(value) => {
+            if value.value_type != value.value.get_type() {
+                return Err(ASTError::ParamTypeMismatch {
+                    key: format!("{}.value", key),
+                    types: value.value.get_type().to_string(),
+                });
+            }
+            Ok(value.value_type)
+        }
+    }
+}
+
+fn infer_operator_type(key: &str, operator: &OperatorExpression) -> Result<ValueType, ASTError> {
+    let length_key = format!("{}.expressions", key);
+    let length = operator.expressions.len();
+    match operator.symbol {
+        SymbolType::Not => assert_length(length_key, length, 1, false)?,
+        SymbolType::And | SymbolType::Or | SymbolType::Add | SymbolType::Mul | SymbolType::Min | SymbolType::Max => {
+            assert_length(length_key, length, 2, true)?
+        }
+        _ => assert_length(length_key, length, 2, false)?,
+    }
+
+    let mut types = vec![];
+    for (i, expression) in operator.expressions.iter().enumerate() {
+        types.push(infer_type(&format!("{}.expressions[{}]", key, i), expression)?);
+    }
+
+    match operator.symbol {
+        SymbolType::Not | SymbolType::And | SymbolType::Or => {
+            for (i, type_) in types.iter().enumerate() {
+                if *type_ != ValueType::Bool {
+                    return Err(ASTError::ParamTypeError {
+                        key: format!("{}.expressions[{}]", key, i),
+                        types: ValueType::Bool.to_string(),
+                    });
+                }
+            }
+            Ok(ValueType::Bool)
+        }
+        SymbolType::Gt | SymbolType::Gte | SymbolType::Lt | SymbolType::Lte | SymbolType::Equal => {
+            // Strings are compared lexicographically, so both sides must be strings.
+            if types.iter().all(|type_| *type_ == ValueType::String) {
+                return Ok(ValueType::Bool);
+            }
+            for (i, type_) in types.iter().enumerate() {
+                assert_numeric(format!("{}.expressions[{}]", key, i), *type_)?;
+            }
+            Ok(ValueType::Bool)
+        }
+        _ => {
+            let mut ret = ValueType::Uint8;
+            for (i, type_) in types.iter().enumerate() {
+                ret = wider(ret, assert_numeric(format!("{}.expressions[{}]", key, i), *type_)?);
+            }
+            Ok(ret)
+        }
+    }
+}
+
+fn infer_function_type(key: &str, function: &FunctionExpression) -> Result<ValueType, ASTError> {
+    let args = &function.arguments;
+    let length_key = format!("{}.arguments", key);
+    match function.name {
+        FnName::SelectByLength => assert_length(length_key, args.len(), 2, true)?,
+        FnName::ByteLength | FnName::CharCount => assert_length(length_key, args.len(), 1, false)?,
+        FnName::IncludesAt => assert_length(length_key, args.len(), 3, false)?,
+        _ => assert_length(length_key, args.len(), 2, false)?,
+    }
+
+    let arg_key = |i: usize| format!("{}.arguments[{}]", key, i);
+    let expect_variable = |names: &[VarName]| match &args[0] {
+        Expression::Variable(variable) if names.contains(&variable.name) => Ok(()),
+        _ => Err(ASTError::ParamTypeError {
+            key: arg_key(0),
+            types: format!(
+                "variable {}",
+                names
+                    .iter()
+                    .map(|name| format!("{:?}", name))
+                    .collect::<Vec<_>>()
+                    .join(" or ")
+            ),
+        }),
+    };
+    let expect_value = |types: &str, f: fn(&Value) -> bool| match &args[1] {
+        Expression::Value(ValueExpression { value, .. }) if f(value) => Ok(value),
+        _ => Err(ASTError::ParamTypeError {
+            key: arg_key(1),
+            types: String::from(types),
+        }),
+    };
+
+    match function.name {
+        FnName::IncludeChars | FnName::IncludeWords | FnName::StartsWith | FnName::EndsWith => {
+            expect_variable(&[VarName::Account])?;
+            expect_value("string[]", |value| matches!(value, Value::StringVec(_)))?;
+            Ok(ValueType::Bool)
+        }
+        FnName::InList => {
+            expect_variable(&[VarName::Account, VarName::ParentAccount])?;
+            expect_value("binary[]", |value| matches!(value, Value::BinaryVec(_)))?;
+            Ok(ValueType::Bool)
+        }
+        FnName::OnlyIncludeCharset | FnName::IncludeCharset => {
+            expect_variable(&[VarName::AccountChars])?;
+            expect_value("charset_type", |value| matches!(value, Value::CharsetType(_)))?;
+            Ok(ValueType::Bool)
+        }
+        FnName::MatchesPattern => {
+            expect_variable(&[VarName::Account, VarName::ParentAccount])?;
+            if let Value::String(text) = expect_value("string", |value| matches!(value, Value::String(_)))? {
+                pattern::Pattern::parse(&arg_key(1), text)?;
+            }
+            Ok(ValueType::Bool)
+        }
+        FnName::SelectByLength => {
+            expect_variable(&[VarName::AccountLength])?;
+            let mut ret = ValueType::Uint8;
+            for (i, arg) in args.iter().enumerate().skip(1) {
+                ret = wider(ret, assert_numeric(arg_key(i), infer_type(&arg_key(i), arg)?)?);
+            }
+            Ok(ret)
+        }
+        FnName::ByteLength | FnName::CharCount => {
+            expect_variable(&[VarName::Account, VarName::ParentAccount])?;
+            Ok(ValueType::Uint32)
+        }
+        FnName::IncludesAt => {
+            expect_variable(&[VarName::Account, VarName::ParentAccount])?;
+            expect_value("string[]", |value| matches!(value, Value::StringVec(_)))?;
+            assert_numeric(arg_key(2), infer_type(&arg_key(2), &args[2])?)?;
+            Ok(ValueType::Bool)
+        }
+        FnName::CountCharset => {
+            expect_variable(&[VarName::AccountChars])?;
+            expect_value("charset_type", |value| matches!(value, Value::CharsetType(_)))?;
+            Ok(ValueType::Uint32)
+        }
+    }
+}
+
+/// Evaluate the expression if its value does not depend on the account.
+///
+/// Besides constants, the fact that an account always has at least one char is used, so expressions like
+/// `account_length >= 1` are treated as always true.
+pub fn const_eval(expression: &Expression) -> Option<Value> {
+    match expression {
+        Expression::Value(value) => Some(value.value.clone()),
+        Expression::Variable(_) | Expression::Function(_) => None,
+        Expression::Operator(operator) => {
+            let values: Vec<Option<Value>> = operator.expressions.iter().map(const_eval).collect();
+            match operator.symbol {
+                SymbolType::And | SymbolType::Or => {
+                    let short_circuit = operator.symbol == SymbolType::Or;
+                    if values.iter().any(|value| value == &Some(Value::Bool(short_circuit))) {
+                        Some(Value::Bool(short_circuit))
+                    } else if values.iter().all(|value| value == &Some(Value::Bool(!short_circuit))) {
+                        Some(Value::Bool(!short_circuit))
+                    } else {
+                        None
+                    }
+                }
+                SymbolType::Not => match values.as_slice() {
+                    [Some(Value::Bool(val))] => Some(Value::Bool(!val)),
+                    _ => None,
+                },
+                SymbolType::Gt | SymbolType::Gte | SymbolType::Lt | SymbolType::Lte | SymbolType::Equal => {
+                    match values.as_slice() {
+                        [Some(left), Some(right)] => left.compare(right, operator.symbol).ok().map(Value::Bool),
+                        [None, Some(right)] if is_account_length(&operator.expressions[0]) => {
+                            const_compare_account_length(operator.symbol, right.get_u64().ok()?)
+                        }
+                        _ => None,
+                    }
+                }
+                _ => {
+                    let mut values = values.into_iter();
+                    let mut ret = values.next()??;
+                    for value in values {
+                        ret = ret.calculate(&value?, operator.symbol).ok()?;
+                    }
+                    Some(ret)
+                }
+            }
+        }
+    }
+}
+
+fn is_account_length(expression: &Expression) -> bool {
+    matches!(
+        expression,
+        Expression::Variable(VariableExpression {
+            name: VarName::AccountLength
+        })
+    )
+}
+
+fn const_compare_account_length(symbol: SymbolType, right: u64) -> Option<Value> {
+    match symbol {
+        SymbolType::Gte if right <= 1 => Some(Value::Bool(true)),
+        SymbolType::Gt if right == 0 => Some(Value::Bool(true)),
+        SymbolType::Lt if right <= 1 => Some(Value::Bool(false)),
+        SymbolType::Lte | SymbolType::Equal if right == 0 => Some(Value::Bool(false)),
+        _ => None,
+    }
+}
+
+/// Estimate the worst-case cycles of the expression, note that the executor never short-circuits `and` and `or`, so
+/// every sub-expression is counted.
+pub fn estimate_expression_cycles(expression: &Expression, model: &CycleCostModel, max_account_length: usize) -> u64 {
+    let account_bytes = max_account_length as u64 * MAX_CHAR_BYTES;
+    let cost = match expression {
+        Expression::Value(value) => model.byte * value_size(&value.value),
+        Expression::Variable(variable) => match variable.name {
+            VarName::Account => model.byte * account_bytes,
+            VarName::AccountChars => model.byte * account_bytes + model.expression * max_account_length as u64,
+            VarName::ParentAccount => model.byte * account_bytes,
+            VarName::AccountLength | VarName::Timestamp | VarName::Years | VarName::MintedCount => 0,
+        },
+        Expression::Operator(operator) => operator
+            .expressions
+            .iter()
+            .map(|expr| estimate_expression_cycles(expr, model, max_account_length))
+            .sum(),
+        Expression::Function(function) => {
+            let args = &function.arguments;
+            let args_cost: u64 = args
+                .iter()
+                .map(|expr| estimate_expression_cycles(expr, model, max_account_length))
+                .sum();
+            let arg_size = args.get(1).map(|arg| match arg {
+                Expression::Value(value) => value_size(&value.value),
+                _ => 0,
+            });
+            let body_cost = match function.name {
+                FnName::IncludeChars | FnName::IncludeWords | FnName::StartsWith | FnName::EndsWith => {
+                    model.byte * account_bytes * arg_size.unwrap_or(0)
+                }
+                FnName::OnlyIncludeCharset | FnName::IncludeCharset | FnName::CountCharset => {
+                    model.expression * max_account_length as u64
+                }
+                FnName::InList => model.blake2b + model.byte * arg_size.unwrap_or(0),
+                FnName::MatchesPattern => model.pattern_step * MAX_STEPS as u64,
+                FnName::SelectByLength => 0,
+                FnName::ByteLength | FnName::CharCount => model.byte * account_bytes,
+                FnName::IncludesAt => model.byte * (account_bytes + arg_size.unwrap_or(0)),
+            };
+            args_cost + body_cost
+        }
+    };
+
+    model.expression + cost
+}
+
+fn value_size(value: &Value) -> u64 {
+    (match value {
+        Value::Bool(_) | Value::Uint8(_) => 1,
+        Value::Uint32(_) | Value::CharsetType(_) => 4,
+        Value::Uint64(_) => 8,
+        Value::Binary(val) => val.len(),
+        Value::BinaryVec(val) => val.iter().map(|item| item.len()).sum(),
+        Value::String(val) => val.len(),
+        Value::StringVec(val) => val.iter().map(|item| item.len()).sum(),
+    }) as u64
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+    use crate::util;
+
+    fn gen_rules(asts: Vec<serde_json::Value>) -> Vec<SubAccountRule> {
+        let rules_json: Vec<serde_json::Value> = asts
+            .into_iter()
+            .enumerate()
+            .map(|(i, ast)| {
+                json!({
+                    "index": i,
+                    "name": "",
+                    "note": "",
+                    "price": 100_000_000,
+                    "status": 1,
+                    "ast": ast,
+                })
+            })
+            .collect();
+        util::json_to_sub_account_rules(String::new(), &serde_json::Value::Array(rules_json)).unwrap()
+    }
+
+    fn length_equal(length: u32) -> serde_json::Value {
+        json!({
+            "type": "operator",
+            "symbol": "==",
+            "expressions": [
+                { "type": "variable", "name": "account_length" },
+                { "type": "value", "value_type": "uint32", "value": length },
+            ],
+        })
+    }
+
+    #[test]
+    fn test_analyze_type_errors() {
+        let rules = gen_rules(vec![
+            length_equal(1),
+            json!({
+                "type": "operator",
+                "symbol": "and",
+                "expressions": [
+                    length_equal(1),
+                    { "type": "value", "value_type": "uint8", "value": 1 },
+                ],
+            }),
+            json!({
+                "type": "function",
+                "name": "include_chars",
+                "arguments": [
+                    { "type": "variable", "name": "account_chars" },
+                    { "type": "value", "value_type": "string[]", "value": ["a"] },
+                ],
+            }),
+            json!({
+                "type": "function",
+                "name": "matches_pattern",
+                "arguments": [
+                    { "type": "variable", "name": "account" },
+                    { "type": "value", "value_type": "string", "value": "(a" },
+                ],
+            }),
+            json!({ "type": "variable", "name": "account_length" }),
+        ]);
+
+        let report = analyze_rules(&rules, false);
+        assert!(!report.is_ok());
+        assert_eq!(4, report.errors.len());
+        assert!(
+            matches!(&report.errors[0], ASTError::ParamTypeError { key, .. } if key == "rules[1].ast.expressions[1]")
+        );
+        assert!(
+            matches!(&report.errors[1], ASTError::ParamTypeError { key, .. } if key == "rules[2].ast.arguments[0]")
+        );
+        assert!(
+            matches!(&report.errors[2], ASTError::PatternInvalid { key, .. } if key == "rules[3].ast.arguments[1]")
+        );
+        assert!(matches!(&report.errors[3], ASTError::FunctionOrOperatorRequired { key } if key == "rules[4].ast"));
+    }
+
+    #[test]
+    fn test_infer_string_and_length_functions() {
+        let infer = |text: &str| infer_type("", &crate::dsl::parse(text).unwrap());
+
+        assert_eq!(
+            ValueType::Bool,
+            infer("byte_length(account) > char_count(parent_account)").unwrap()
+        );
+        assert_eq!(ValueType::Uint32, infer("count_charset(account_chars, digit)").unwrap());
+        assert_eq!(
+            ValueType::Bool,
+            infer(r#"includes_at(account, ["88"], account_length - 2) and parent_account < "m""#).unwrap()
+        );
+        assert!(matches!(
+            infer(r#"account < 1"#),
+            Err(ASTError::ParamTypeError { key, .. }) if key == ".expressions[0]"
+        ));
+        assert!(matches!(
+            infer(r#"includes_at(account, ["88"], "1")"#),
+            Err(ASTError::ParamTypeError { key, .. }) if key == ".arguments[2]"
+        ));
+        assert!(matches!(
+            infer("char_count(account, account)"),
+            Err(ASTError::ParamLengthError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_analyze_price_expression() {
+        let ast = json!({
+            "type": "operator",
+            "symbol": "*",
+            "expressions": [
+                { "type": "value", "value_type": "uint64", "value": 1_000_000 },
+                { "type": "variable", "name": "account_length" },
+            ],
+        });
+
+        let report = analyze_rules(&gen_rules(vec![ast.clone()]), false);
+        assert!(matches!(report.errors.as_slice(), [ASTError::ReturnTypeError { .. }]));
+
+        let report = analyze_rules(&gen_rules(vec![ast, length_equal(1)]), true);
+        assert!(report.is_ok());
+        assert_eq!(
+            vec![UnreachableRule {
+                key: String::from("rules[1]"),
+                index: 1,
+                reason: UnreachableReason::ShadowedBy(0),
+            }],
+            report.unreachable
+        );
+    }
+
+    #[test]
+    fn test_analyze_unreachable_rules() {
+        let rules = gen_rules(vec![
+            length_equal(0),
+            length_equal(1),
+            json!({
+                "type": "operator",
+                "symbol": "or",
+                "expressions": [
+                    length_equal(2),
+                    {
+                        "type": "operator",
+                        "symbol": ">=",
+                        "expressions": [
+                            { "type": "variable", "name": "account_length" },
+                            { "type": "value", "value_type": "uint8", "value": 1 },
+                        ],
+                    },
+                ],
+            }),
+            length_equal(3),
+        ]);
+
+        let report = analyze_rules(&rules, false);
+        assert!(report.is_ok());
+        assert_eq!(
+            vec![
+                UnreachableRule {
+                    key: String::from("rules[0]"),
+                    index: 0,
+                    reason: UnreachableReason::NeverMatches,
+                },
+                UnreachableRule {
+                    key: String::from("rules[3]"),
+                    index: 3,
+                    reason: UnreachableReason::ShadowedBy(2),
+                },
+            ],
+            report.unreachable
+        );
+    }
+
+    #[test]
+    fn test_estimate_cycles() {
+        let model = CycleCostModel::default();
+        let one_rule = analyze_rules(&gen_rules(vec![length_equal(1)]), false);
+        let two_rules = analyze_rules(&gen_rules(vec![length_equal(1), length_equal(2)]), false);
+        assert_eq!(
+            model.rule + model.expression * 3 + model.byte * 4,
+            one_rule.estimated_cycles
+        );
+        assert_eq!(one_rule.estimated_cycles * 2, two_rules.estimated_cycles);
+
+        let pattern_rule = analyze_rules(
+            &gen_rules(vec![json!({
+                "type": "function",
+                "name": "matches_pattern",
+                "arguments": [
+                    { "type": "variable", "name": "account" },
+                    { "type": "value", "value_type": "string", "value": "^a+$" },
+                ],
+            })]),
+            false,
+        );
+        assert!(pattern_rule.estimated_cycles > model.pattern_step * MAX_STEPS as u64);
+    }
+}
@@ -0,0 +1,338 @@
+//! Evaluate a set of rules against a corpus of candidate names, so owners can preview their rules over tens of
+//! thousands of names.
+//!
+//! Names are split into `AccountChars` by `CharSetTable` with the same rules as the contracts, and encoded into one
+//! reused buffer instead of building molecule entities. Rules which do not read the content of the account only depend
+//! on the length and the charsets of it, so their results are cached by these two properties.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+pub use das_types::charset::CharSetTable;
+use das_types::packed;
+use das_types::prelude::Reader;
+use das_types::types::AccountChars;
+
+use crate::error::ASTError;
+use crate::executor::execute_rule;
+use crate::types::*;
+
+#[derive(Debug, Clone)]
+pub enum BatchOutcome {
+    Matched {
+        rule: u32,
+        price: u64,
+    },
+    Unmatched,
+    /// The name can not be split by the charset lists, or it uses conflicting charsets.
+    InvalidChars,
+    Failed(ASTError),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct BatchReport {
+    /// The outcome of every name in the same order as the names.
+    pub outcomes: Vec<(String, BatchOutcome)>,
+    /// The count of names matched by every rule, keyed by the index of the rule.
+    pub matched_per_rule: BTreeMap<u32, usize>,
+    pub unmatched: usize,
+    pub invalid: usize,
+    pub failed: usize,
+}
+
+/// Find the matched rule and the price of every name, the same as calling `match_rule_with_account_chars` or
+/// `match_price_rule_with_account_chars` with each name, the account is the name with the suffix like `.xxxxx.bit`.
+pub fn evaluate_batch<'a>(
+    rules: &[SubAccountRule],
+    is_price_rules: bool,
+    table: &CharSetTable,
+    names: impl IntoIterator<Item = &'a str>,
+    suffix: &str,
+    context: &RuleContext,
+) -> BatchReport {
+    let cacheable: Vec<bool> = rules.iter().map(|rule| !reads_account_content(&rule.ast)).collect();
+    let mut cache: HashMap<(usize, usize, u32), Result<Option<u64>, ASTError>> = HashMap::new();
+    let mut report = BatchReport::default();
+    let mut buf = Vec::new();
+    let mut account = String::new();
+
+    for name in names {
+        let outcome = match table.classify(name) {
+            Ok(account_chars) => {
+                let charsets = account_chars
+                    .iter()
+                    .fold(0u32, |mask, char| mask | 1 << char.char_set_type as u32);
+                let length = account_chars.len();
+                encode_account_chars(&mut buf, &account_chars);
+                let account_chars = packed::AccountCharsReader::new_unchecked(&buf);
+                account.clear();
+                account.push_str(name);
+                account.push_str(suffix);
+
+                let mut outcome = BatchOutcome::Unmatched;
+                for (i, rule) in rules.iter().enumerate() {
+                    if rule.status == SubAccountRuleStatus::Off {
+                        continue;
+                    }
+
+                    let execute = || execute_rule(i, rule, account_chars, &account, context, is_price_rules);
+                    let ret = if cacheable[i] {
+                        cache.entry((i, length, charsets)).or_insert_with(execute).clone()
+                    } else {
+                        execute()
+                    };
+                    match ret {
+                        Ok(Some(price)) => {
+                            outcome = BatchOutcome::Matched {
+                                rule: rule.index,
+                                price,
+                            };
+                            break;
+                        }
+                        Ok(None) => {}
+                        Err(err) => {
+                            outcome = BatchOutcome::Failed(err);
+                            break;
+                        }
+                    }
+                }
+                outcome
+            }
+            Err(_) => BatchOutcome::InvalidChars,
+        };
+
+        match &outcome {
+            BatchOutcome::Matched { rule, .. } => *report.matched_per_rule.entry(*rule).or_default() += 1,
+            BatchOutcome::Unmatched => report.unmatched += 1,
+            BatchOutcome::InvalidChars => report.invalid += 1,
+            BatchOutcome::Failed(_) => report.failed += 1,
+        }
+        report.outcomes.push((name.to_string(), outcome));
+    }
+
+    report
+}
+
+/// The same as `evaluate_batch` with the names read from lines, blank lines and lines starting with `#` are skipped.
+pub fn evaluate_batch_reader(
+    rules: &[SubAccountRule],
+    is_price_rules: bool,
+    table: &CharSetTable,
+    reader: impl BufRead,
+    suffix: &str,
+    context: &RuleContext,
+) -> io::Result<BatchReport> {
+    let mut names = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        let name = line.trim();
+        if !name.is_empty() && !name.starts_with('#') {
+            names.push(name.to_string());
+        }
+    }
+
+    Ok(evaluate_batch(
+        rules,
+        is_price_rules,
+        table,
+        names.iter().map(|name| name.as_str()),
+        suffix,
+        context,
+    ))
+}
+
+/// The same as `evaluate_batch_reader` with the names read from a file with one name per line.
+pub fn evaluate_batch_file(
+    rules: &[SubAccountRule],
+    is_price_rules: bool,
+    table: &CharSetTable,
+    path: impl AsRef<Path>,
+    suffix: &str,
+    context: &RuleContext,
+) -> io::Result<BatchReport> {
+    let file = File::open(path)?;
+    evaluate_batch_reader(rules, is_price_rules, table, BufReader::new(file), suffix, context)
+}
+
+/// Encode the chars into `buf` in the molecule format of `AccountChars`, the previous content of `buf` is dropped.
+fn encode_account_chars(buf: &mut Vec<u8>, account_chars: &AccountChars) {
+    const U32: usize = 4;
+
+    buf.clear();
+    buf.resize(U32 * (1 + account_chars.len()), 0);
+    for (i, char) in account_chars.iter().enumerate() {
+        let offset = buf.len() as u32;
+        buf[U32 * (i + 1)..U32 * (i + 2)].copy_from_slice(&offset.to_le_bytes());
+
+        // AccountChar is a table of char_set_name: Uint32 and bytes: Bytes.
+        let bytes = char.char.as_bytes();
+        let header_size = U32 * 3;
+        let total_size = header_size + U32 + U32 + bytes.len();
+        buf.extend_from_slice(&(total_size as u32).to_le_bytes());
+        buf.extend_from_slice(&(header_size as u32).to_le_bytes());
+        buf.extend_from_slice(&((header_size + U32) as u32).to_le_bytes());
+        buf.extend_from_slice(&(char.char_set_type as u32).to_le_bytes());
+        buf.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+        buf.extend_from_slice(bytes);
+    }
+
+    let total_size = buf.len() as u32;
+    buf[..U32].copy_from_slice(&total_size.to_le_bytes());
+}
+
+/// Return true if the result of the expression may depend on more than the length and the charsets of the account.
+fn reads_account_content(expression: &Expression) -> bool {
+    match expression {
+        Expression::Operator(operator) => operator.expressions.iter().any(reads_account_content),
+        // These functions only read the charsets of the account chars.
+        Expression::Function(FunctionExpression {
+            name: FnName::OnlyIncludeCharset | FnName::IncludeCharset,
+            arguments,
+        }) => arguments.iter().skip(1).any(reads_account_content),
+        Expression::Function(function) => function.arguments.iter().any(reads_account_content),
+        Expression::Variable(variable) => matches!(variable.name, VarName::Account | VarName::AccountChars),
+        Expression::Value(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use das_types::prelude::Entity;
+
+    use super::*;
+    use crate::dsl;
+    use crate::executor::match_price_rule_with_account_chars;
+
+    fn gen_rule(index: u32, price: u64, text: &str) -> SubAccountRule {
+        SubAccountRule {
+            index,
+            name: String::new(),
+            note: String::new(),
+            price,
+            status: SubAccountRuleStatus::On,
+            ast: dsl::parse(text).unwrap(),
+        }
+    }
+
+    fn load_table() -> CharSetTable {
+        CharSetTable::from_dir(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../tests/data")).unwrap()
+    }
+
+    #[test]
+    fn test_evaluate_batch() {
+        let table = load_table();
+        let rules = vec![
+            gen_rule(
+                0,
+                0,
+                r#"in_list(account, [0x0000000000000000000000000000000000000000])"#,
+            ),
+            gen_rule(
+                1,
+                100,
+                "account_length <= 2 and only_include_charset(account_chars, digit)",
+            ),
+            gen_rule(2, 300, r#"starts_with(account, ["vip"])"#),
+            gen_rule(3, 0, "select_by_length(account_length, 50u64, 20u64, 10u64)"),
+        ];
+        let names = ["1", "12", "123", "ab", "abcd", "a.b", "中文", "vip1"];
+        let context = RuleContext::default();
+
+        let report = evaluate_batch(&rules, true, &table, names.iter().copied(), ".parent.bit", &context);
+        let prices: Vec<Option<u64>> = report
+            .outcomes
+            .iter()
+            .map(|(_, outcome)| match outcome {
+                BatchOutcome::Matched { price, .. } => Some(*price),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            vec![
+                Some(100),
+                Some(100),
+                Some(10),
+                Some(20),
+                Some(10),
+                None,
+                Some(20),
+                Some(300)
+            ],
+            prices
+        );
+        assert_eq!(Some(&2), report.matched_per_rule.get(&1));
+        assert_eq!(Some(&1), report.matched_per_rule.get(&2));
+        assert_eq!(Some(&4), report.matched_per_rule.get(&3));
+        assert_eq!(1, report.invalid);
+        assert_eq!(0, report.unmatched);
+
+        // The result should be the same as matching names one by one.
+        for (name, outcome) in report.outcomes.iter() {
+            let account_chars = match table.classify(name) {
+                Ok(chars) => packed::AccountChars::from(chars),
+                Err(_) => continue,
+            };
+            let expected = match_price_rule_with_account_chars(
+                &rules,
+                account_chars.as_reader(),
+                &format!("{}.parent.bit", name),
+                &context,
+            )
+            .unwrap()
+            .map(|(rule, price)| (rule.index, price));
+            let actual = match outcome {
+                BatchOutcome::Matched { rule, price } => Some((*rule, *price)),
+                _ => None,
+            };
+            assert_eq!(expected, actual, "{} is matched differently", name);
+        }
+    }
+
+    #[test]
+    fn test_evaluate_batch_reader() {
+        let table = load_table();
+        let rules = vec![gen_rule(0, 0, "select_by_length(account_length, 50u64, 20u64, 10u64)")];
+        let content = "# preview\n1\n\n  ab  \na.b\n";
+
+        let report = evaluate_batch_reader(
+            &rules,
+            true,
+            &table,
+            content.as_bytes(),
+            ".parent.bit",
+            &RuleContext::default(),
+        )
+        .unwrap();
+        let names: Vec<&str> = report.outcomes.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(vec!["1", "ab", "a.b"], names);
+        assert_eq!(Some(&2), report.matched_per_rule.get(&0));
+        assert_eq!(1, report.invalid);
+    }
+
+    #[test]
+    fn test_encode_account_chars() {
+        let table = load_table();
+        let mut buf = vec![0xff; 3];
+        for name in ["", "a", "a1🌈", "中文"] {
+            let account_chars = table.classify(name).unwrap();
+            encode_account_chars(&mut buf, &account_chars);
+            assert_eq!(packed::AccountChars::from(account_chars).as_slice(), buf.as_slice());
+            assert!(packed::AccountCharsReader::verify(&buf, false).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_reads_account_content() {
+        let check = |text: &str| reads_account_content(&dsl::parse(text).unwrap());
+        assert!(!check("account_length > 1 and include_charset(account_chars, digit)"));
+        assert!(!check("select_by_length(account_length, 1, 2) * years"));
+        assert!(check(r#"starts_with(account, ["a"])"#));
+        assert!(check(r#"include_chars(account, ["a"]) or account_length > 1"#));
+        // The count of a charset is not determined by the set of charsets.
+        assert!(check("count_charset(account_chars, digit) > 1"));
+    }
+}
@@ -0,0 +1,1541 @@
+//! Compile sub-account rules to a compact bytecode and evaluate it with a stack machine, so that large rule sets can be
+//! matched on-chain without decoding every `ASTExpression` into an `Expression` tree first.
+//!
+//! The compiler checks the arity, the literal arguments and the types of all expressions ahead of time, and fails with
+//! the same `ASTError` as `executor` would fail at that expression. It is stricter than `executor`, which only reports
+//! the errors of the expressions it actually evaluates, like an ill-typed rule after the matched one or an unselected
+//! argument of `select_by_length`. For every program which compiles, matching returns the same result as `executor`, the
+//! keys of errors are located by program counters like `rules[1].ast@12` and can be resolved by `SourceMap`.
+//!
+//! All integers in the program are little-endian:
+//!
+//! ```text
+//! program = version: u8, count: u32, rule * count
+//! rule    = price: u64, status: u8, length: u32, code: [u8; length]
+//! ```
+//!
+//! The code of a rule is in postfix order, operands are evaluated from the first to the last like `executor` does, and
+//! `and`/`or` do not short-circuit. The code of a rule whose status is off is always empty.
+
+#[cfg(feature = "no_std")]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "no_std")]
+use alloc::format;
+#[cfg(feature = "no_std")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+#[cfg(not(feature = "no_std"))]
+use std::collections::BTreeMap;
+
+#[cfg(feature = "no_std")]
+use das_types::{constants::*, packed, prelude::*};
+#[cfg(not(feature = "no_std"))]
+use das_types::{constants::*, packed, prelude::*};
+
+use crate::error::ASTError;
+use crate::executor::{assert_param_length, assert_param_length_gte};
+use crate::pattern::{self, Pattern};
+use crate::types::*;
+use crate::util::blake2b_256;
+
+pub const VERSION: u8 = 1;
+
+const ACCOUNT_ID_LENGTH: usize = 20;
+
+/// The opcodes, the operands which follow the opcode in the code are listed after it.
+mod op {
+    /// value: u8, push a bool.
+    pub const BOOL: u8 = 0;
+    /// value: u8, push a uint8.
+    pub const UINT8: u8 = 1;
+    /// value: u32, push a uint32.
+    pub const UINT32: u8 = 2;
+    /// value: u64, push a uint64.
+    pub const UINT64: u8 = 3;
+    /// length: u32, bytes: [u8; length], push a string.
+    pub const STRING: u8 = 4;
+    /// name: u8, push the value of a variable, `account_chars` is never pushed.
+    pub const VARIABLE: u8 = 5;
+    /// count: u32, pop `count` bools and push the result.
+    pub const AND: u8 = 6;
+    /// count: u32, pop `count` bools and push the result.
+    pub const OR: u8 = 7;
+    /// Pop a bool and push the negation.
+    pub const NOT: u8 = 8;
+    /// symbol: u8, pop two uints or two strings and push the result.
+    pub const COMPARE: u8 = 9;
+    /// symbol: u8, pop two uints and push the result.
+    pub const CALCULATE: u8 = 10;
+    /// name: u8, followed by the literal arguments of the function, see `Compiler::function`.
+    pub const FUNCTION: u8 = 11;
+}
+
+/// Map the program counters of the operations which may fail at runtime to the keys of their expressions.
+#[derive(Debug, Clone, Default)]
+pub struct SourceMap {
+    keys: BTreeMap<(usize, usize), String>,
+}
+
+impl SourceMap {
+    /// Replace the key of an error returned by matching a program with the key of the expression, like
+    /// `rules[1].ast@12` to `rules[1].ast.expressions[0]`, the other errors are returned as they are.
+    pub fn resolve(&self, mut err: ASTError) -> ASTError {
+        let key = match &mut err {
+            ASTError::CalculationFailed { key, .. }
+            | ASTError::PatternInvalid { key, .. }
+            | ASTError::PatternTooComplex { key } => key,
+            _ => return err,
+        };
+
+        let location = key
+            .strip_prefix("rules[")
+            .and_then(|rest| rest.split_once("].ast@"))
+            .and_then(|(rule, pc)| Some((rule.parse::<usize>().ok()?, pc.parse::<usize>().ok()?)));
+        if let Some(expression_key) = location.and_then(|location| self.keys.get(&location)) {
+            *key = expression_key.clone();
+        }
+
+        err
+    }
+}
+
+/// Compile the rules to a program, the position of rules in the program is the same as in `rules`.
+pub fn compile_rules(rules: &[SubAccountRule]) -> Result<Vec<u8>, ASTError> {
+    compile_rules_with_source_map(rules).map(|(program, _)| program)
+}
+
+pub fn compile_rules_with_source_map(rules: &[SubAccountRule]) -> Result<(Vec<u8>, SourceMap), ASTError> {
+    let mut program = vec![VERSION];
+    program.extend((rules.len() as u32).to_le_bytes());
+    let mut source_map = SourceMap::default();
+
+    for (i, rule) in rules.iter().enumerate() {
+        let mut compiler = Compiler {
+            rule: i,
+            code: Vec::new(),
+            source_map: &mut source_map,
+        };
+
+        if rule.status == SubAccountRuleStatus::On {
+            let key = format!("rules[{}].ast", i);
+            match rule.ast {
+                Expression::Function(_) | Expression::Operator(_) => {}
+                _ => return Err(ASTError::FunctionOrOperatorRequired { key }),
+            }
+            compiler.expression(&key, &rule.ast)?;
+        }
+
+        program.extend(rule.price.to_le_bytes());
+        program.push(rule.status as u8);
+        program.extend((compiler.code.len() as u32).to_le_bytes());
+        program.extend(compiler.code);
+    }
+
+    Ok((program, source_map))
+}
+
+fn is_uint(value_type: ValueType) -> bool {
+    matches!(value_type, ValueType::Uint8 | ValueType::Uint32 | ValueType::Uint64)
+}
+
+fn wider(left: ValueType, right: ValueType) -> ValueType {
+    let width = |value_type| match value_type {
+        ValueType::Uint8 => 1,
+        ValueType::Uint32 => 4,
+        _ => 8,
+    };
+
+    if width(left) >= width(right) {
+        left
+    } else {
+        right
+    }
+}
+
+fn assert_uint(key: String, value_type: ValueType) -> Result<(), ASTError> {
+    if !is_uint(value_type) {
+        return Err(ASTError::ParamTypeError {
+            key,
+            types: String::from("Uint8, Uint32, Uint64"),
+        });
+    }
+
+    Ok(())
+}
+
+fn assert_variable(key: String, argument: &Expression, name: VarName, msg_types: &str) -> Result<(), ASTError> {
+    match argument {
+        Expression::Variable(variable) if variable.name == name => Ok(()),
+        _ => Err(ASTError::ParamTypeError {
+            key,
+            types: String::from(msg_types),
+        }),
+    }
+}
+
+fn get_literal<'b>(key: String, argument: &'b Expression, msg_types: &str) -> Result<&'b Value, ASTError> {
+    match argument {
+        Expression::Value(ValueExpression { value, .. }) => Ok(value),
+        _ => Err(ASTError::ParamTypeError {
+            key,
+            types: String::from(msg_types),
+        }),
+    }
+}
+
+/// Get the variable which can be either the `account` or the `parent_account`, like `executor::get_account_argument`.
+fn get_account_variable(key: String, argument: &Expression) -> Result<VarName, ASTError> {
+    match argument {
+        Expression::Variable(VariableExpression {
+            name: name @ (VarName::Account | VarName::ParentAccount),
+        }) => Ok(*name),
+        _ => Err(ASTError::ParamTypeError {
+            key,
+            types: String::from("variable Account or ParentAccount"),
+        }),
+    }
+}
+
+struct Compiler<'a> {
+    rule: usize,
+    code: Vec<u8>,
+    source_map: &'a mut SourceMap,
+}
+
+impl Compiler<'_> {
+    fn push_u32(&mut self, val: u32) {
+        self.code.extend(val.to_le_bytes());
+    }
+
+    fn push_bytes(&mut self, bytes: &[u8]) {
+        self.push_u32(bytes.len() as u32);
+        self.code.extend(bytes);
+    }
+
+    /// Push a list with its length in bytes, so that it can be skipped once an item is matched.
+    fn push_list<T: AsRef<[u8]>>(&mut self, items: &[T]) {
+        let length = items.iter().map(|item| 4 + item.as_ref().len()).sum::<usize>();
+        self.push_u32(length as u32);
+        for item in items {
+            self.push_bytes(item.as_ref());
+        }
+    }
+
+    fn locate(&mut self, key: String) {
+        self.source_map.keys.insert((self.rule, self.code.len()), key);
+    }
+
+    /// Compile the expression and return its type, the type of a uint expression is the widest type it may return.
+    fn expression(&mut self, key: &str, expression: &Expression) -> Result<ValueType, ASTError> {
+        match expression {
+            Expression::Operator(operator) => self.operator(key, operator),
+            Expression::Function(function) => self.function(key, function),
+            Expression::Variable(variable) => Ok(self.variable(variable.name)),
+            Expression::Value(ValueExpression { value, .. }) => {
+                match value {
+                    Value::Bool(val) => self.code.extend([op::BOOL, *val as u8]),
+                    Value::Uint8(val) => self.code.extend([op::UINT8, *val]),
+                    Value::Uint32(val) => {
+                        self.code.push(op::UINT32);
+                        self.push_u32(*val);
+                    }
+                    Value::Uint64(val) => {
+                        self.code.push(op::UINT64);
+                        self.code.extend(val.to_le_bytes());
+                    }
+                    Value::String(val) => {
+                        self.code.push(op::STRING);
+                        self.push_bytes(val.as_bytes());
+                    }
+                    // The other values are only accepted as literal arguments of functions, so the caller always fails
+                    // with their types.
+                    _ => {}
+                }
+
+                Ok(value.get_type())
+            }
+        }
+    }
+
+    fn variable(&mut self, name: VarName) -> ValueType {
+        match name {
+            VarName::Account | VarName::ParentAccount => {
+                self.code.extend([op::VARIABLE, name as u8]);
+                ValueType::String
+            }
+            VarName::AccountLength | VarName::Years | VarName::MintedCount => {
+                self.code.extend([op::VARIABLE, name as u8]);
+                ValueType::Uint32
+            }
+            VarName::Timestamp => {
+                self.code.extend([op::VARIABLE, name as u8]);
+                ValueType::Uint64
+            }
+            // The account_chars is only accepted as the argument of functions, so the caller always fails with its type.
+            VarName::AccountChars => ValueType::StringVec,
+        }
+    }
+
+    fn operator(&mut self, key: &str, operator: &OperatorExpression) -> Result<ValueType, ASTError> {
+        let expressions = &operator.expressions;
+        let expression_key = |i: usize| format!("{}.expressions[{}]", key, i);
+
+        match operator.symbol {
+            SymbolType::And | SymbolType::Or => {
+                assert_param_length_gte(format!("{}.expressions", key), expressions.len(), 2)?;
+                for (i, expression) in expressions.iter().enumerate() {
+                    if self.expression(&expression_key(i), expression)? != ValueType::Bool {
+                        return Err(ASTError::ParamTypeError {
+                            key: expression_key(i),
+                            types: ValueType::Bool.to_string(),
+                        });
+                    }
+                }
+
+                self.code.push(if operator.symbol == SymbolType::And {
+                    op::AND
+                } else {
+                    op::OR
+                });
+                self.push_u32(expressions.len() as u32);
+                Ok(ValueType::Bool)
+            }
+            SymbolType::Not => {
+                assert_param_length(format!("{}.expressions", key), expressions.len(), 1)?;
+                if self.expression(&expression_key(0), &expressions[0])? != ValueType::Bool {
+                    return Err(ASTError::ParamTypeError {
+                        key: expression_key(0),
+                        types: ValueType::Bool.to_string(),
+                    });
+                }
+
+                self.code.push(op::NOT);
+                Ok(ValueType::Bool)
+            }
+            SymbolType::Equal | SymbolType::Gt | SymbolType::Gte | SymbolType::Lt | SymbolType::Lte => {
+                assert_param_length(format!("{}.expressions", key), expressions.len(), 2)?;
+                let left = self.expression(&expression_key(0), &expressions[0])?;
+                let right = self.expression(&expression_key(1), &expressions[1])?;
+
+                if !is_uint(left) && left != ValueType::String {
+                    return Err(ASTError::ParamTypeError {
+                        key: expression_key(0),
+                        types: String::from("Uint8, Uint32, Uint64, String"),
+                    });
+                }
+                if is_uint(left) != is_uint(right) || (left == ValueType::String) != (right == ValueType::String) {
+                    return Err(ASTError::ParamTypeError {
+                        key: key.to_string(),
+                        types: ASTError::ValueOperatorUnsupported.to_string(),
+                    });
+                }
+
+                self.code.extend([op::COMPARE, operator.symbol as u8]);
+                Ok(ValueType::Bool)
+            }
+            SymbolType::Add
+            | SymbolType::Sub
+            | SymbolType::Mul
+            | SymbolType::Div
+            | SymbolType::Mod
+            | SymbolType::Min
+            | SymbolType::Max => {
+                match operator.symbol {
+                    SymbolType::Add | SymbolType::Mul | SymbolType::Min | SymbolType::Max => {
+                        assert_param_length_gte(format!("{}.expressions", key), expressions.len(), 2)?
+                    }
+                    _ => assert_param_length(format!("{}.expressions", key), expressions.len(), 2)?,
+                }
+
+                let mut ret: Option<ValueType> = None;
+                for (i, expression) in expressions.iter().enumerate() {
+                    let value_type = self.expression(&expression_key(i), expression)?;
+                    assert_uint(expression_key(i), value_type)?;
+
+                    ret = match ret {
+                        None => Some(value_type),
+                        Some(left) => {
+                            self.locate(key.to_string());
+                            self.code.extend([op::CALCULATE, operator.symbol as u8]);
+                            Some(wider(left, value_type))
+                        }
+                    };
+                }
+
+                // The length of params has been checked above, so the ret must be Some.
+                Ok(ret.unwrap())
+            }
+        }
+    }
+
+    /// Compile a function, the arguments which are not literals are compiled before it. The literal arguments follow
+    /// the name of the function:
+    ///
+    /// - `include_chars`, `include_words`, `starts_with`, `ends_with`: strings as a list;
+    /// - `includes_at`: the account variable: u8, strings as a list, the position is popped;
+    /// - `matches_pattern`: the account variable: u8, length: u32, pattern: [u8; length];
+    /// - `only_include_charset`, `include_charset`, `count_charset`: charset: u32;
+    /// - `in_list`: the account variable: u8, count: u32, sorted account IDs: [[u8; 20]; count];
+    /// - `byte_length`, `char_count`: the account variable: u8;
+    /// - `select_by_length`: count: u32, length of candidates: [u32; count], code of candidates.
+    ///
+    /// A list is its length in bytes: u32, followed by items of length: u32, bytes: [u8; length].
+    fn function(&mut self, key: &str, function: &FunctionExpression) -> Result<ValueType, ASTError> {
+        let arguments = &function.arguments;
+        let arguments_key = format!("{}.arguments", key);
+        let argument_key = |i: usize| format!("{}.arguments[{}]", key, i);
+
+        match function.name {
+            FnName::IncludeChars | FnName::IncludeWords | FnName::StartsWith | FnName::EndsWith => {
+                assert_param_length(arguments_key, arguments.len(), 2)?;
+                assert_variable(
+                    argument_key(0),
+                    &arguments[0],
+                    VarName::Account,
+                    "variable AccountChars",
+                )?;
+                let strings = match get_literal(argument_key(1), &arguments[1], "string[]")? {
+                    Value::StringVec(strings) => strings,
+                    _ => {
+                        return Err(ASTError::ParamTypeError {
+                            key: argument_key(1),
+                            types: String::from("string[]"),
+                        })
+                    }
+                };
+
+                self.code.extend([op::FUNCTION, function.name as u8]);
+                self.push_list(strings);
+                Ok(ValueType::Bool)
+            }
+            FnName::IncludesAt => {
+                assert_param_length(arguments_key, arguments.len(), 3)?;
+                let target = get_account_variable(argument_key(0), &arguments[0])?;
+                let strings = match get_literal(argument_key(1), &arguments[1], "string[]")? {
+                    Value::StringVec(strings) => strings,
+                    _ => {
+                        return Err(ASTError::ParamTypeError {
+                            key: argument_key(1),
+                            types: String::from("string[]"),
+                        })
+                    }
+                };
+                let position = self.expression(&argument_key(2), &arguments[2])?;
+                assert_uint(argument_key(2), position)?;
+
+                self.code.extend([op::FUNCTION, function.name as u8, target as u8]);
+                self.push_list(strings);
+                Ok(ValueType::Bool)
+            }
+            FnName::MatchesPattern => {
+                assert_param_length(arguments_key, arguments.len(), 2)?;
+                let target = get_account_variable(argument_key(0), &arguments[0])?;
+                let pattern = match get_literal(argument_key(1), &arguments[1], "string")? {
+                    Value::String(pattern) => pattern,
+                    _ => {
+                        return Err(ASTError::ParamTypeError {
+                            key: argument_key(1),
+                            types: String::from("string"),
+                        })
+                    }
+                };
+                Pattern::parse(&argument_key(1), pattern)?;
+
+                self.locate(argument_key(1));
+                self.code.extend([op::FUNCTION, function.name as u8, target as u8]);
+                self.push_bytes(pattern.as_bytes());
+                Ok(ValueType::Bool)
+            }
+            FnName::OnlyIncludeCharset | FnName::IncludeCharset | FnName::CountCharset => {
+                assert_param_length(arguments_key, arguments.len(), 2)?;
+                assert_variable(
+                    argument_key(0),
+                    &arguments[0],
+                    VarName::AccountChars,
+                    "variable AccountChars",
+                )?;
+                let charset = match get_literal(argument_key(1), &arguments[1], "charset_type")? {
+                    Value::CharsetType(charset) => *charset,
+                    _ => {
+                        return Err(ASTError::ParamTypeError {
+                            key: argument_key(1),
+                            types: String::from("charset_type"),
+                        })
+                    }
+                };
+
+                self.code.extend([op::FUNCTION, function.name as u8]);
+                self.push_u32(charset as u32);
+                if function.name == FnName::CountCharset {
+                    Ok(ValueType::Uint32)
+                } else {
+                    Ok(ValueType::Bool)
+                }
+            }
+            FnName::InList => {
+                assert_param_length(arguments_key, arguments.len(), 2)?;
+                let target = get_account_variable(argument_key(0), &arguments[0])?;
+                let account_list = match get_literal(argument_key(1), &arguments[1], "binary[]")? {
+                    Value::BinaryVec(account_list) => account_list,
+                    _ => {
+                        return Err(ASTError::ParamTypeError {
+                            key: argument_key(1),
+                            types: String::from("binary[]"),
+                        })
+                    }
+                };
+
+                // An account ID is 20 bytes, so the other items never match and the rest are sorted for a binary
+                // search.
+                let mut account_ids: Vec<&[u8]> = account_list
+                    .iter()
+                    .map(|account_id| account_id.as_slice())
+                    .filter(|account_id| account_id.len() == ACCOUNT_ID_LENGTH)
+                    .collect();
+                account_ids.sort_unstable();
+                account_ids.dedup();
+
+                self.code.extend([op::FUNCTION, function.name as u8, target as u8]);
+                self.push_u32(account_ids.len() as u32);
+                for account_id in account_ids {
+                    self.code.extend_from_slice(account_id);
+                }
+                Ok(ValueType::Bool)
+            }
+            FnName::ByteLength | FnName::CharCount => {
+                assert_param_length(arguments_key, arguments.len(), 1)?;
+                let target = get_account_variable(argument_key(0), &arguments[0])?;
+
+                self.code.extend([op::FUNCTION, function.name as u8, target as u8]);
+                Ok(ValueType::Uint32)
+            }
+            FnName::SelectByLength => {
+                assert_param_length_gte(arguments_key, arguments.len(), 2)?;
+                assert_variable(
+                    argument_key(0),
+                    &arguments[0],
+                    VarName::AccountLength,
+                    "variable AccountLength",
+                )?;
+
+                let candidates = &arguments[1..];
+                self.code.extend([op::FUNCTION, function.name as u8]);
+                self.push_u32(candidates.len() as u32);
+                let table = self.code.len();
+                self.code.resize(table + 4 * candidates.len(), 0);
+
+                let mut ret = ValueType::Uint8;
+                for (i, candidate) in candidates.iter().enumerate() {
+                    let start = self.code.len();
+                    let value_type = self.expression(&argument_key(i + 1), candidate)?;
+                    assert_uint(argument_key(i + 1), value_type)?;
+                    ret = wider(ret, value_type);
+
+                    let length = (self.code.len() - start) as u32;
+                    self.code[table + 4 * i..table + 4 * (i + 1)].copy_from_slice(&length.to_le_bytes());
+                }
+
+                Ok(ret)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Operand<'a> {
+    Bool(bool),
+    Uint8(u8),
+    Uint32(u32),
+    Uint64(u64),
+    String(&'a [u8]),
+}
+
+impl Operand<'_> {
+    fn to_value(self) -> Option<Value> {
+        match self {
+            Operand::Uint8(val) => Some(Value::Uint8(val)),
+            Operand::Uint32(val) => Some(Value::Uint32(val)),
+            Operand::Uint64(val) => Some(Value::Uint64(val)),
+            _ => None,
+        }
+    }
+
+    fn get_u64(self) -> Option<u64> {
+        match self {
+            Operand::Uint8(val) => Some(val as u64),
+            Operand::Uint32(val) => Some(val as u64),
+            Operand::Uint64(val) => Some(val),
+            _ => None,
+        }
+    }
+}
+
+struct Cursor<'a> {
+    data: &'a [u8],
+    pos: usize,
+    rule: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn malformed(&self) -> ASTError {
+        ASTError::BytesToEntityFailed {
+            key: format!("rules[{}].code", self.rule),
+        }
+    }
+
+    fn take(&mut self, length: usize) -> Result<&'a [u8], ASTError> {
+        let ret = self
+            .pos
+            .checked_add(length)
+            .and_then(|end| self.data.get(self.pos..end))
+            .ok_or_else(|| self.malformed())?;
+        self.pos += length;
+        Ok(ret)
+    }
+
+    fn u8(&mut self) -> Result<u8, ASTError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, ASTError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, ASTError> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn bytes(&mut self) -> Result<&'a [u8], ASTError> {
+        let length = self.u32()? as usize;
+        self.take(length)
+    }
+
+    /// Take a list and return a cursor of its items.
+    fn list(&mut self) -> Result<Cursor<'a>, ASTError> {
+        Ok(Cursor {
+            data: self.bytes()?,
+            pos: 0,
+            rule: self.rule,
+        })
+    }
+
+    /// Check if any item of the list matches, the rest items are skipped once an item is matched.
+    fn any(mut self, mut f: impl FnMut(&[u8]) -> bool) -> Result<bool, ASTError> {
+        while self.pos < self.data.len() {
+            if f(self.bytes()?) {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+}
+
+fn bytes_contains(haystack: &[u8], needle: &[u8]) -> bool {
+    needle.is_empty() || haystack.windows(needle.len()).any(|window| window == needle)
+}
+
+/// Binary search the account IDs which are concatenated in ascending order.
+fn sorted_ids_contains(account_ids: &[u8], account_id: &[u8]) -> bool {
+    let (mut low, mut high) = (0, account_ids.len() / ACCOUNT_ID_LENGTH);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        match account_ids[mid * ACCOUNT_ID_LENGTH..(mid + 1) * ACCOUNT_ID_LENGTH].cmp(account_id) {
+            Ordering::Equal => return true,
+            Ordering::Less => low = mid + 1,
+            Ordering::Greater => high = mid,
+        }
+    }
+
+    false
+}
+
+fn get_account_without_suffix(account: &str) -> &str {
+    account.split('.').next().unwrap_or_default()
+}
+
+struct Machine<'a> {
+    code: &'a [u8],
+    rule: usize,
+    account_chars: packed::AccountCharsReader<'a>,
+    account: &'a str,
+    context: &'a RuleContext<'a>,
+    stack: Vec<Operand<'a>>,
+}
+
+impl<'a> Machine<'a> {
+    fn malformed(&self) -> ASTError {
+        ASTError::BytesToEntityFailed {
+            key: format!("rules[{}].code", self.rule),
+        }
+    }
+
+    /// The key of the operation at the program counter, it is only formatted when an error is returned.
+    fn location(&self, pc: usize) -> String {
+        format!("rules[{}].ast@{}", self.rule, pc)
+    }
+
+    fn pop(&mut self) -> Result<Operand<'a>, ASTError> {
+        self.stack.pop().ok_or_else(|| self.malformed())
+    }
+
+    fn pop_bool(&mut self) -> Result<bool, ASTError> {
+        match self.pop()? {
+            Operand::Bool(val) => Ok(val),
+            _ => Err(self.malformed()),
+        }
+    }
+
+    fn pop_uint(&mut self) -> Result<Operand<'a>, ASTError> {
+        match self.pop()? {
+            operand @ (Operand::Uint8(_) | Operand::Uint32(_) | Operand::Uint64(_)) => Ok(operand),
+            _ => Err(self.malformed()),
+        }
+    }
+
+    fn get_account_variable(&self, cursor: &mut Cursor<'a>) -> Result<&'a str, ASTError> {
+        match VarName::try_from(cursor.u8()?) {
+            Ok(VarName::Account) => Ok(self.account),
+            Ok(VarName::ParentAccount) => Ok(self.context.parent_account),
+            _ => Err(self.malformed()),
+        }
+    }
+
+    fn get_charset(&self, cursor: &mut Cursor<'a>, pc: usize) -> Result<CharSetType, ASTError> {
+        let charset_index = cursor.u32()?;
+        CharSetType::try_from(charset_index).map_err(|_| ASTError::UndefinedCharSetType {
+            key: self.location(pc),
+            type_: charset_index,
+        })
+    }
+
+    /// Iterate the charsets of the account chars, like `executor` it fails when an undefined charset is reached.
+    fn any_charset(&self, mut f: impl FnMut(CharSetType) -> bool) -> Result<bool, ASTError> {
+        for item in self.account_chars.iter() {
+            let charset_index = u32::from(item.char_set_name());
+            let charset = CharSetType::try_from(charset_index).map_err(|_| ASTError::UndefinedCharSetType {
+                key: "".to_string(),
+                type_: charset_index,
+            })?;
+
+            if f(charset) {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Evaluate the code in `start..end`, the program counters are always the offsets in the code of the rule.
+    fn eval(&mut self, start: usize, end: usize) -> Result<(), ASTError> {
+        let mut cursor = Cursor {
+            data: self.code.get(..end).ok_or_else(|| self.malformed())?,
+            pos: start,
+            rule: self.rule,
+        };
+
+        while cursor.pos < end {
+            let pc = cursor.pos;
+            let operand = match cursor.u8()? {
+                op::BOOL => Operand::Bool(cursor.u8()? != 0),
+                op::UINT8 => Operand::Uint8(cursor.u8()?),
+                op::UINT32 => Operand::Uint32(cursor.u32()?),
+                op::UINT64 => Operand::Uint64(cursor.u64()?),
+                op::STRING => Operand::String(cursor.bytes()?),
+                op::VARIABLE => {
+                    let name = cursor.u8()?;
+                    match VarName::try_from(name) {
+                        Ok(VarName::Account) => Operand::String(self.account.as_bytes()),
+                        Ok(VarName::ParentAccount) => Operand::String(self.context.parent_account.as_bytes()),
+                        Ok(VarName::AccountLength) => Operand::Uint32(self.account_chars.len() as u32),
+                        Ok(VarName::Timestamp) => Operand::Uint64(self.context.timestamp),
+                        Ok(VarName::Years) => Operand::Uint32(self.context.years),
+                        Ok(VarName::MintedCount) => Operand::Uint32(self.context.minted_count),
+                        _ => {
+                            return Err(ASTError::UndefinedVariableType {
+                                key: self.location(pc),
+                                type_: name,
+                            })
+                        }
+                    }
+                }
+                op @ (op::AND | op::OR) => {
+                    let count = cursor.u32()? as usize;
+                    if count > self.stack.len() {
+                        return Err(self.malformed());
+                    }
+
+                    let mut ret = op == op::AND;
+                    for _ in 0..count {
+                        let val = self.pop_bool()?;
+                        if op == op::AND {
+                            ret &= val;
+                        } else {
+                            ret |= val;
+                        }
+                    }
+                    Operand::Bool(ret)
+                }
+                op::NOT => Operand::Bool(!self.pop_bool()?),
+                op::COMPARE => {
+                    let symbol = cursor.u8()?;
+                    let right = self.pop()?;
+                    let left = self.pop()?;
+                    let ordering = match (left, right) {
+                        (Operand::String(left), Operand::String(right)) => left.cmp(right),
+                        _ => match (left.get_u64(), right.get_u64()) {
+                            (Some(left), Some(right)) => left.cmp(&right),
+                            _ => return Err(self.malformed()),
+                        },
+                    };
+
+                    Operand::Bool(match SymbolType::try_from(symbol) {
+                        Ok(SymbolType::Gt) => ordering.is_gt(),
+                        Ok(SymbolType::Gte) => ordering.is_ge(),
+                        Ok(SymbolType::Lt) => ordering.is_lt(),
+                        Ok(SymbolType::Lte) => ordering.is_le(),
+                        Ok(SymbolType::Equal) => ordering.is_eq(),
+                        _ => {
+                            return Err(ASTError::UndefinedOperator {
+                                key: self.location(pc),
+                                type_: symbol,
+                            })
+                        }
+                    })
+                }
+                op::CALCULATE => {
+                    let symbol = cursor.u8()?;
+                    let symbol = SymbolType::try_from(symbol).map_err(|_| ASTError::UndefinedOperator {
+                        key: self.location(pc),
+                        type_: symbol,
+                    })?;
+                    let right = self.pop_uint()?.to_value().unwrap();
+                    let left = self.pop_uint()?.to_value().unwrap();
+
+                    match left.calculate(&right, symbol) {
+                        Ok(Value::Uint8(val)) => Operand::Uint8(val),
+                        Ok(Value::Uint32(val)) => Operand::Uint32(val),
+                        Ok(Value::Uint64(val)) => Operand::Uint64(val),
+                        Ok(_) => return Err(self.malformed()),
+                        Err(err) => {
+                            return Err(ASTError::CalculationFailed {
+                                key: self.location(pc),
+                                reason: err.to_string(),
+                            })
+                        }
+                    }
+                }
+                op::FUNCTION => self.function(&mut cursor, pc)?,
+                type_ => {
+                    return Err(ASTError::UndefinedExpression {
+                        key: self.location(pc),
+                        type_,
+                    })
+                }
+            };
+
+            self.stack.push(operand);
+        }
+
+        Ok(())
+    }
+
+    fn function(&mut self, cursor: &mut Cursor<'a>, pc: usize) -> Result<Operand<'a>, ASTError> {
+        let name = cursor.u8()?;
+        let name = FnName::try_from(name).map_err(|_| ASTError::UndefinedFunction {
+            key: self.location(pc),
+            type_: name,
+        })?;
+
+        let ret = match name {
+            FnName::IncludeChars | FnName::IncludeWords | FnName::StartsWith | FnName::EndsWith => {
+                let account = get_account_without_suffix(self.account).as_bytes();
+                let list = cursor.list()?;
+                Operand::Bool(match name {
+                    FnName::StartsWith => list.any(|string| account.starts_with(string))?,
+                    FnName::EndsWith => list.any(|string| account.ends_with(string))?,
+                    _ => list.any(|string| bytes_contains(account, string))?,
+                })
+            }
+            FnName::IncludesAt => {
+                let target = get_account_without_suffix(self.get_account_variable(cursor)?);
+                let list = cursor.list()?;
+                let position = self.pop_uint()?.get_u64().unwrap();
+
+                let rest = target
+                    .char_indices()
+                    .map(|(i, _)| i)
+                    .chain([target.len()])
+                    .nth(position as usize)
+                    .map(|i| &target.as_bytes()[i..]);
+                Operand::Bool(match rest {
+                    Some(rest) => list.any(|string| rest.starts_with(string))?,
+                    None => false,
+                })
+            }
+            FnName::MatchesPattern => {
+                let target = get_account_without_suffix(self.get_account_variable(cursor)?);
+                let pattern = core::str::from_utf8(cursor.bytes()?).map_err(|_| self.malformed())?;
+                let ret = pattern::is_match("", pattern, target).map_err(|err| match err {
+                    ASTError::PatternInvalid { reason, .. } => ASTError::PatternInvalid {
+                        key: self.location(pc),
+                        reason,
+                    },
+                    ASTError::PatternTooComplex { .. } => ASTError::PatternTooComplex { key: self.location(pc) },
+                    err => err,
+                })?;
+                Operand::Bool(ret)
+            }
+            FnName::OnlyIncludeCharset => {
+                let expected = self.get_charset(cursor, pc)?;
+                Operand::Bool(!self.any_charset(|charset| charset != expected)?)
+            }
+            FnName::IncludeCharset => {
+                let expected = self.get_charset(cursor, pc)?;
+                Operand::Bool(self.any_charset(|charset| charset == expected)?)
+            }
+            FnName::CountCharset => {
+                let expected = self.get_charset(cursor, pc)?;
+                let mut count = 0u32;
+                self.any_charset(|charset| {
+                    if charset == expected {
+                        count += 1;
+                    }
+                    false
+                })?;
+                Operand::Uint32(count)
+            }
+            FnName::InList => {
+                let account = self.get_account_variable(cursor)?;
+                let count = cursor.u32()? as usize;
+                let account_ids = cursor.take(count.checked_mul(ACCOUNT_ID_LENGTH).ok_or_else(|| self.malformed())?)?;
+                let hash = blake2b_256(account);
+                let account_id = &hash[0..ACCOUNT_ID_LENGTH];
+                Operand::Bool(sorted_ids_contains(account_ids, account_id))
+            }
+            FnName::ByteLength => {
+                let account = get_account_without_suffix(self.get_account_variable(cursor)?);
+                Operand::Uint32(account.len() as u32)
+            }
+            FnName::CharCount => {
+                let account = get_account_without_suffix(self.get_account_variable(cursor)?);
+                Operand::Uint32(account.chars().count() as u32)
+            }
+            FnName::SelectByLength => {
+                let count = cursor.u32()? as usize;
+                if count == 0 {
+                    return Err(self.malformed());
+                }
+                let length = self.account_chars.len();
+                let i = if length == 0 {
+                    0
+                } else {
+                    core::cmp::min(length, count) - 1
+                };
+
+                let table = cursor.take(4 * count)?;
+                let mut end = cursor.pos;
+                let mut selected = (end, end);
+                for (j, length) in table.chunks(4).enumerate() {
+                    let length = u32::from_le_bytes(length.try_into().unwrap()) as usize;
+                    let next = end.checked_add(length).ok_or_else(|| self.malformed())?;
+                    if j == i {
+                        selected = (end, next);
+                    }
+                    end = next;
+                }
+                if end > cursor.data.len() {
+                    return Err(self.malformed());
+                }
+
+                let depth = self.stack.len();
+                self.eval(selected.0, selected.1)?;
+                if self.stack.len() != depth + 1 {
+                    return Err(self.malformed());
+                }
+                cursor.pos = end;
+                self.pop_uint()?
+            }
+        };
+
+        Ok(ret)
+    }
+}
+
+/// Find the first matched rule like `executor::match_rule_with_account_chars`, return the position of it in the
+/// compiled rules.
+pub fn match_rule_with_account_chars(
+    program: &[u8],
+    account_chars: packed::AccountCharsReader,
+    account: &str,
+    context: &RuleContext,
+) -> Result<Option<usize>, ASTError> {
+    Ok(match_rules(program, account_chars, account, context, false)?.map(|(i, _)| i))
+}
+
+/// Find the first matched price rule like `executor::match_price_rule_with_account_chars`, return the position of it in
+/// the compiled rules and the price of the account.
+pub fn match_price_rule_with_account_chars(
+    program: &[u8],
+    account_chars: packed::AccountCharsReader,
+    account: &str,
+    context: &RuleContext,
+) -> Result<Option<(usize, u64)>, ASTError> {
+    match_rules(program, account_chars, account, context, true)
+}
+
+fn match_rules(
+    program: &[u8],
+    account_chars: packed::AccountCharsReader,
+    account: &str,
+    context: &RuleContext,
+    is_price_rules: bool,
+) -> Result<Option<(usize, u64)>, ASTError> {
+    let mut cursor = Cursor {
+        data: program,
+        pos: 0,
+        rule: 0,
+    };
+    if cursor.u8().ok() != Some(VERSION) {
+        return Err(ASTError::BytesToEntityFailed {
+            key: String::from("version"),
+        });
+    }
+    let count = cursor.u32()?;
+
+    let mut stack = Vec::with_capacity(16);
+    for i in 0..count as usize {
+        cursor.rule = i;
+        let price = cursor.u64()?;
+        let status = cursor.u8()?;
+        let status = SubAccountRuleStatus::try_from(status).map_err(|_| ASTError::UndefinedRuleStatus {
+            key: format!("rules[{}].status", i),
+            type_: status,
+        })?;
+        let code = cursor.bytes()?;
+        if status == SubAccountRuleStatus::Off {
+            continue;
+        }
+
+        let mut machine = Machine {
+            code,
+            rule: i,
+            account_chars,
+            account,
+            context,
+            stack,
+        };
+        machine.eval(0, code.len())?;
+        let value = machine.pop()?;
+        if !machine.stack.is_empty() {
+            return Err(machine.malformed());
+        }
+        stack = machine.stack;
+
+        match value {
+            Operand::Bool(true) => return Ok(Some((i, price))),
+            Operand::Bool(false) => {}
+            Operand::Uint8(_) | Operand::Uint32(_) | Operand::Uint64(_) if is_price_rules => {
+                return Ok(Some((i, value.get_u64().unwrap())))
+            }
+            _ if is_price_rules => {
+                return Err(ASTError::ReturnTypeError {
+                    key: format!("rules[{}]", i),
+                    types: String::from("Bool, Uint8, Uint32, Uint64"),
+                })
+            }
+            _ => {
+                return Err(ASTError::ReturnTypeError {
+                    key: format!("rules[{}]", i),
+                    types: ValueType::Bool.to_string(),
+                })
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use das_types::charset::CharSetTable;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{dsl, executor, util};
+
+    fn gen_rule(index: u32, price: u64, text: &str) -> SubAccountRule {
+        SubAccountRule {
+            index,
+            name: String::new(),
+            note: String::new(),
+            price,
+            status: SubAccountRuleStatus::On,
+            ast: dsl::parse(text).unwrap(),
+        }
+    }
+
+    fn gen_rules(texts: &[&str]) -> Vec<SubAccountRule> {
+        texts
+            .iter()
+            .enumerate()
+            .map(|(i, text)| gen_rule(i as u32, 100 + i as u64, text))
+            .collect()
+    }
+
+    fn gen_account_id(account: &str) -> String {
+        format!("0x{}", hex::encode(&util::blake2b_256(account)[0..20]))
+    }
+
+    /// The names of different lengths and charsets, they are matched with the suffix `.parent.bit`.
+    const NAMES: [&str; 16] = [
+        "1",
+        "12",
+        "123",
+        "a",
+        "ab",
+        "abcd",
+        "abcde",
+        "test1",
+        "xtest2y",
+        "vip1",
+        "88888",
+        "中文",
+        "中88",
+        "🌈",
+        "✨🌈",
+        "a1b2c3d4e5f6",
+    ];
+
+    fn gen_corpus() -> Vec<(packed::AccountChars, String)> {
+        let table = CharSetTable::from_dir(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../tests/data")).unwrap();
+        let mut corpus: Vec<(packed::AccountChars, String)> = NAMES
+            .iter()
+            .map(|name| {
+                let account_chars = table.classify(name).unwrap();
+                (account_chars.into(), format!("{}.parent.bit", name))
+            })
+            .collect();
+
+        // An account char with an undefined charset makes the charset functions fail.
+        let account_chars = packed::AccountChars::new_builder()
+            .push(
+                packed::AccountChar::new_builder()
+                    .char_set_name(packed::Uint32::from(99))
+                    .bytes(packed::Bytes::from("x".as_bytes()))
+                    .build(),
+            )
+            .build();
+        corpus.push((account_chars, String::from("x.parent.bit")));
+
+        corpus
+    }
+
+    fn gen_contexts() -> Vec<RuleContext<'static>> {
+        vec![
+            RuleContext::default(),
+            RuleContext {
+                timestamp: 1_700_000_000,
+                years: 3,
+                parent_account: "parent.bit",
+                minted_count: 10,
+            },
+        ]
+    }
+
+    /// Match every account in the corpus by `executor` and by the compiled program, the results and errors should be
+    /// the same.
+    fn assert_same_results(rules: &[SubAccountRule]) {
+        let (program, source_map) = compile_rules_with_source_map(rules).unwrap();
+        let position = |rule: &SubAccountRule| rules.iter().position(|item| core::ptr::eq(item, rule)).unwrap();
+
+        for (account_chars, account) in gen_corpus().iter() {
+            for context in gen_contexts().iter() {
+                let account_chars = account_chars.as_reader();
+
+                let expected = executor::match_price_rule_with_account_chars(rules, account_chars, account, context)
+                    .map(|ret| ret.map(|(rule, price)| (position(rule), price)));
+                let actual = match_price_rule_with_account_chars(&program, account_chars, account, context)
+                    .map_err(|err| source_map.resolve(err));
+                assert_eq!(
+                    format!("{:?}", expected),
+                    format!("{:?}", actual),
+                    "{} is matched differently by price rules",
+                    account
+                );
+
+                let expected = executor::match_rule_with_account_chars(rules, account_chars, account, context)
+                    .map(|ret| ret.map(position));
+                let actual = match_rule_with_account_chars(&program, account_chars, account, context)
+                    .map_err(|err| source_map.resolve(err));
+                assert_eq!(
+                    format!("{:?}", expected),
+                    format!("{:?}", actual),
+                    "{} is matched differently by rules",
+                    account
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_same_results_with_fixture_rules() {
+        // The preserved rules and the price rules of the sub-account-cell-type tests.
+        let preserved_rules_json = json!([
+            {
+                "index": 0,
+                "name": "No emoji accounts",
+                "note": "",
+                "price": 0,
+                "status": 1,
+                "ast": {
+                    "type": "function",
+                    "name": "only_include_charset",
+                    "arguments": [
+                        { "type": "variable", "name": "account_chars" },
+                        { "type": "value", "value_type": "charset_type", "value": "Emoji" }
+                    ],
+                }
+            },
+            {
+                "index": 1,
+                "name": "No preserved accounts",
+                "note": "",
+                "price": 0,
+                "status": 1,
+                "ast": {
+                    "type": "function",
+                    "name": "in_list",
+                    "arguments": [
+                        { "type": "variable", "name": "account" },
+                        { "type": "value", "value_type": "binary[]", "value": [gen_account_id("abcd.parent.bit")] }
+                    ],
+                }
+            }
+        ]);
+        let price_rules_json = json!([
+            {
+                "index": 0,
+                "name": "4 charactor account",
+                "note": "",
+                "price": 20_000_000,
+                "status": 0,
+                "ast": {
+                    "type": "operator",
+                    "symbol": "==",
+                    "expressions": [
+                        { "type": "variable", "name": "account_length" },
+                        { "type": "value", "value_type": "uint32", "value": 4 },
+                    ],
+                }
+            },
+            {
+                "index": 1,
+                "name": "5 or more charactor account",
+                "note": "",
+                "price": 5_000_000,
+                "status": 1,
+                "ast": {
+                    "type": "operator",
+                    "symbol": ">=",
+                    "expressions": [
+                        { "type": "variable", "name": "account_length" },
+                        { "type": "value", "value_type": "uint32", "value": 5 },
+                    ],
+                }
+            },
+            {
+                "index": 2,
+                "name": "special account",
+                "note": "",
+                "price": 10_000_000,
+                "status": 1,
+                "ast": {
+                    "type": "function",
+                    "name": "include_chars",
+                    "arguments": [
+                        { "type": "variable", "name": "account" },
+                        { "type": "value", "value_type": "string[]", "value": ["✨", "🌈"] },
+                    ],
+                }
+            }
+        ]);
+
+        for rules_json in [preserved_rules_json, price_rules_json] {
+            assert_same_results(&util::json_to_sub_account_rules(String::new(), &rules_json).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_same_results_with_perf_rules() {
+        // The rules of perf_create_with_custom_rules, a long in_list and 50 rules which are never matched.
+        let mut account_list = vec![String::from("0x0000000000000000000000000000000000000000"); 1000];
+        account_list.push(gen_account_id("vip1.parent.bit"));
+        let mut rules_json = vec![json!({
+            "index": 0,
+            "name": "Dummy rule 0",
+            "note": "",
+            "price": 0,
+            "status": 1,
+            "ast": {
+                "type": "operator",
+                "symbol": "and",
+                "expressions": [
+                    { "type": "value", "value_type": "bool", "value": true },
+                    {
+                        "type": "function",
+                        "name": "in_list",
+                        "arguments": [
+                            { "type": "variable", "name": "account" },
+                            { "type": "value", "value_type": "binary[]", "value": account_list }
+                        ]
+                    }
+                ]
+            }
+        })];
+        for i in 1..=50 {
+            rules_json.push(json!({
+                "index": i,
+                "name": format!("Dummy rule {}", i),
+                "note": "",
+                "price": 0,
+                "status": 1,
+                "ast": {
+                    "type": "operator",
+                    "symbol": "and",
+                    "expressions": [
+                        {
+                            "type": "operator",
+                            "symbol": "==",
+                            "expressions": [
+                                { "type": "variable", "name": "account_length" },
+                                { "type": "value", "value_type": "uint32", "value": 147 }
+                            ]
+                        },
+                        {
+                            "type": "function",
+                            "name": "only_include_charset",
+                            "arguments": [
+                                { "type": "variable", "name": "account_chars" },
+                                { "type": "value", "value_type": "charset_type", "value": "En" }
+                            ]
+                        },
+                        {
+                            "type": "function",
+                            "name": "include_words",
+                            "arguments": [
+                                { "type": "variable", "name": "account" },
+                                { "type": "value", "value_type": "string[]", "value": ["test1", "test2", "test3"] }
+                            ]
+                        }
+                    ]
+                }
+            }));
+        }
+        rules_json.push(json!({
+            "index": 51,
+            "name": "Dummy rule last",
+            "note": "",
+            "price": 5_000_000,
+            "status": 1,
+            "ast": {
+                "type": "operator",
+                "symbol": ">",
+                "expressions": [
+                    { "type": "variable", "name": "account_length" },
+                    { "type": "value", "value_type": "uint8", "value": 4 }
+                ]
+            }
+        }));
+
+        assert_same_results(&util::json_to_sub_account_rules(String::new(), &json!(rules_json)).unwrap());
+    }
+
+    #[test]
+    fn test_same_results_with_every_expression() {
+        let rule_sets = [
+            // The rules of executor::test_match_price_rule.
+            vec![
+                "account_length == 1",
+                "1_000_000u64 * select_by_length(account_length, 100u8, 10u8, 1u8)",
+            ],
+            vec![
+                r#"account_length > 3 and starts_with(account, ["a"])"#,
+                "10u64 * account_length",
+                "account_length == 2",
+            ],
+            vec![
+                "select_by_length(account_length, 100u64) * (10 - min(years, 2)) / 10 > 50",
+                "timestamp < 1_800_000_000u64 and years >= 3 and minted_count >= 10",
+                r#"matches_pattern(parent_account, "^par") and not matches_pattern(account, "^(a|b)+\\d?$")"#,
+            ],
+            vec![
+                r#"includes_at(account, ["88"], 1) or includes_at(account, ["x", "2y"], account_length - 2)"#,
+                "byte_length(account) > 6 or char_count(parent_account) == 6",
+                "only_include_charset(account_chars, digit) or count_charset(account_chars, digit) * 2 >= account_length",
+            ],
+            vec![
+                r#"ends_with(account, ["1", "文"]) or include_chars(account, ["es"]) or include_words(account, ["vip"])"#,
+                r#"parent_account < "parent.bitx" and account == "ab.parent.bit""#,
+                "include_charset(account_chars, zh_hans)",
+                "max(account_length, 3u8, years) % 4 == 1 and 255u8 + account_length > 255",
+                "account_length <= 2 and only_include_charset(account_chars, digit)",
+                "select_by_length(account_length, 50u64, 20u64 + years, 10u64)",
+            ],
+            // The calculations overflow or are divided by zero for some accounts.
+            vec![
+                "account_length >= 5",
+                "100u8 * account_length - 200 > 0",
+                "10 / (account_length - 2) == 10 % (3 - account_length)",
+                "select_by_length(account_length, 250u8 + 3u8 * account_length, 1u8) > 1",
+            ],
+            // The pattern is too complex to match any account.
+            vec!["account_length >= 5", r#"matches_pattern(account, "^(((.?){64}){64})$")"#],
+        ];
+
+        // The account IDs are unsorted and duplicated, and some items are not account IDs.
+        let mut account_ids = vec![String::from("0x00"), format!("0x{}", "ff".repeat(21))];
+        for name in NAMES.iter().rev().step_by(2).chain(NAMES.iter().step_by(3)) {
+            account_ids.push(gen_account_id(&format!("{}.parent.bit", name)));
+        }
+        let in_list = format!(
+            "in_list(account, [{}]) or in_list(parent_account, [{}])",
+            account_ids.join(", "),
+            gen_account_id("parent.bit"),
+        );
+        let in_list_texts = ["in_list(account, [0x00]) and account_length == 1", in_list.as_str()];
+
+        for texts in rule_sets
+            .iter()
+            .map(|texts| texts.as_slice())
+            .chain([in_list_texts.as_slice()])
+        {
+            assert_same_results(&gen_rules(texts));
+
+            // The rules whose status is off are never compiled.
+            let mut rules = gen_rules(texts);
+            rules[0].status = SubAccountRuleStatus::Off;
+            rules[0].ast = dsl::parse("account_chars").unwrap();
+            assert_same_results(&rules);
+        }
+    }
+
+    #[test]
+    fn test_compile_errors() {
+        let account_chars: packed::AccountChars = das_types::types::AccountChars::default().into();
+        let context = RuleContext {
+            parent_account: "parent.bit",
+            ..Default::default()
+        };
+
+        for text in [
+            "account_length",
+            "true",
+            "not account_length",
+            "account_length and true",
+            "account_chars > 1",
+            "parent_account > 1",
+            "1 > parent_account",
+            "1 + true > 1",
+            "years - timestamp - parent_account > 1",
+            "char_count(account_chars) > 1",
+            r#"includes_at(account, ["8"], "1")"#,
+            r#"includes_at(account, "8", 1)"#,
+            r#"matches_pattern(account, "(")"#,
+            r#"matches_pattern(account, ["a"])"#,
+            "count_charset(account, digit) > 1",
+            "include_charset(account_chars, 1)",
+            "select_by_length(years, 1, 2) > 1",
+            r#"select_by_length(account_length, "1") > 1"#,
+            r#"starts_with(parent_account, ["a"])"#,
+            r#"ends_with(account, "a")"#,
+            r#"in_list(account, ["a"])"#,
+            "in_list(account_chars, [0x00])",
+            "byte_length(account_chars) > 1",
+        ] {
+            let rules = vec![gen_rule(0, 100, text)];
+            let expected = executor::match_price_rule_with_account_chars(
+                &rules,
+                account_chars.as_reader(),
+                "ab.parent.bit",
+                &context,
+            )
+            .unwrap_err();
+            let actual = compile_rules(&rules).unwrap_err();
+            assert_eq!(format!("{:?}", expected), format!("{:?}", actual), "{}", text);
+        }
+
+        // The compiler is stricter than the executor, which does not evaluate the rules after the matched one.
+        let rules = gen_rules(&["account_length == 0", "not account_length"]);
+        assert!(matches!(
+            executor::match_rule_with_account_chars(&rules, account_chars.as_reader(), "", &context),
+            Ok(Some(_))
+        ));
+        assert!(matches!(
+            compile_rules(&rules),
+            Err(ASTError::ParamTypeError { ref key, .. }) if key == "rules[1].ast.expressions[0]"
+        ));
+    }
+
+    #[test]
+    fn test_malformed_program() {
+        let rules = gen_rules(&[
+            r#"includes_at(account, ["88"], 1) or starts_with(account, ["a"]) or matches_pattern(account, "^a")"#,
+            "select_by_length(account_length, 1u8, 2u8 * years) > count_charset(account_chars, digit)",
+            r#"in_list(parent_account, [0x00]) and parent_account >= "a""#,
+        ]);
+        let program = compile_rules(&rules).unwrap();
+        let account_chars: packed::AccountChars = das_types::types::AccountChars::default().into();
+        let context = RuleContext::default();
+        let run =
+            |program: &[u8]| match_price_rule_with_account_chars(program, account_chars.as_reader(), "a.bit", &context);
+
+        assert!(matches!(run(&program), Ok(Some((0, 100)))));
+        assert!(matches!(run(&[]), Err(ASTError::BytesToEntityFailed { .. })));
+        assert!(matches!(
+            run(&[VERSION + 1, 0, 0, 0, 0]),
+            Err(ASTError::BytesToEntityFailed { .. })
+        ));
+
+        // A truncated or corrupted program should only fail without panic.
+        for i in 0..program.len() {
+            let _ = run(&program[..i]);
+            for byte in [0x00, 0x01, 0x0b, 0xff] {
+                let mut corrupted = program.clone();
+                corrupted[i] = byte;
+                let _ = run(&corrupted);
+            }
+        }
+    }
+
+    #[test]
+    fn test_source_map() {
+        let rules = gen_rules(&["account_length >= 5", "(3 - account_length) * 2 > 1"]);
+        let (program, source_map) = compile_rules_with_source_map(&rules).unwrap();
+        let account_chars: packed::AccountChars = das_types::types::AccountChars::from(vec![
+            das_types::types::AccountChar {
+                char_set_type: CharSetType::En,
+                char: String::from("a"),
+            };
+            4
+        ])
+        .into();
+
+        let err =
+            match_rule_with_account_chars(&program, account_chars.as_reader(), "aaaa.bit", &RuleContext::default())
+                .unwrap_err();
+        let key = match &err {
+            ASTError::CalculationFailed { key, .. } => key.clone(),
+            _ => panic!("{:?}", err),
+        };
+        assert!(key.starts_with("rules[1].ast@"));
+        assert!(matches!(
+            source_map.resolve(err),
+            ASTError::CalculationFailed { ref key, .. } if key == "rules[1].ast.expressions[0].expressions[0]"
+        ));
+    }
+}
@@ -0,0 +1,422 @@
+//! Diff two sets of rules by the `index` of rules, and apply the diff to the old rules as a patch.
+//!
+//! The rules are matched from the first one to the last one, so the order of rules is also a part of the diff.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::error::ASTError;
+use crate::types::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RuleDiff {
+    Removed(SubAccountRule),
+    Changed {
+        index: u32,
+        changes: Vec<RuleChange>,
+    },
+    /// The order of the rules which exist in both sets is changed, it contains the indexes in the new order.
+    Reordered(Vec<u32>),
+    /// The rule is added at the position of the new rules.
+    Added {
+        position: usize,
+        rule: SubAccountRule,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RuleChange {
+    Name {
+        old: String,
+        new: String,
+    },
+    Note {
+        old: String,
+        new: String,
+    },
+    Price {
+        old: u64,
+        new: u64,
+    },
+    Status {
+        old: SubAccountRuleStatus,
+        new: SubAccountRuleStatus,
+    },
+    /// The sub-expression is replaced, the key is relative to the rule like `ast.expressions[1]`.
+    Expression {
+        key: String,
+        old: Expression,
+        new: Expression,
+    },
+}
+
+fn index_rules(key: &str, rules: &[SubAccountRule]) -> Result<BTreeMap<u32, usize>, ASTError> {
+    let mut ret = BTreeMap::new();
+    for (i, rule) in rules.iter().enumerate() {
+        if ret.insert(rule.index, i).is_some() {
+            return Err(ASTError::RuleIndexDuplicated {
+                key: format!("{}[{}].index", key, i),
+                index: rule.index,
+            });
+        }
+    }
+
+    Ok(ret)
+}
+
+/// Diff the old rules with the new rules, the rules are identified by their `index`.
+pub fn diff_rules(old: &[SubAccountRule], new: &[SubAccountRule]) -> Result<Vec<RuleDiff>, ASTError> {
+    let old_indexes = index_rules("old", old)?;
+    let new_indexes = index_rules("new", new)?;
+    let mut ret = vec![];
+
+    for rule in old.iter() {
+        match new_indexes.get(&rule.index) {
+            None => ret.push(RuleDiff::Removed(rule.clone())),
+            Some(&i) => {
+                let changes = diff_rule(rule, &new[i]);
+                if !changes.is_empty() {
+                    ret.push(RuleDiff::Changed {
+                        index: rule.index,
+                        changes,
+                    });
+                }
+            }
+        }
+    }
+
+    let old_order: Vec<u32> = old
+        .iter()
+        .map(|rule| rule.index)
+        .filter(|index| new_indexes.contains_key(index))
+        .collect();
+    let new_order: Vec<u32> = new
+        .iter()
+        .map(|rule| rule.index)
+        .filter(|index| old_indexes.contains_key(index))
+        .collect();
+    if old_order != new_order {
+        ret.push(RuleDiff::Reordered(new_order));
+    }
+
+    for (position, rule) in new.iter().enumerate() {
+        if !old_indexes.contains_key(&rule.index) {
+            ret.push(RuleDiff::Added {
+                position,
+                rule: rule.clone(),
+            });
+        }
+    }
+
+    Ok(ret)
+}
+
+/// Diff two rules with the same index, the AST is compared down to the smallest different sub-expressions.
+pub fn diff_rule(old: &SubAccountRule, new: &SubAccountRule) -> Vec<RuleChange> {
+    let mut ret = vec![];
+    if old.name != new.name {
+        ret.push(RuleChange::Name {
+            old: old.name.clone(),
+            new: new.name.clone(),
+        });
+    }
+    if old.note != new.note {
+        ret.push(RuleChange::Note {
+            old: old.note.clone(),
+            new: new.note.clone(),
+        });
+    }
+    if old.price != new.price {
+        ret.push(RuleChange::Price {
+            old: old.price,
+            new: new.price,
+        });
+    }
+    if old.status != new.status {
+        ret.push(RuleChange::Status {
+            old: old.status,
+            new: new.status,
+        });
+    }
+    diff_expression("ast", &old.ast, &new.ast, &mut ret);
+
+    ret
+}
+
+fn diff_expression(key: &str, old: &Expression, new: &Expression, changes: &mut Vec<RuleChange>) {
+    if old == new {
+        return;
+    }
+
+    match (old, new) {
+        (Expression::Operator(old_operator), Expression::Operator(new_operator))
+            if old_operator.symbol == new_operator.symbol
+                && old_operator.expressions.len() == new_operator.expressions.len() =>
+        {
+            for (i, (old_expr, new_expr)) in old_operator
+                .expressions
+                .iter()
+                .zip(new_operator.expressions.iter())
+                .enumerate()
+            {
+                diff_expression(&format!("{}.expressions[{}]", key, i), old_expr, new_expr, changes);
+            }
+        }
+        (Expression::Function(old_function), Expression::Function(new_function))
+            if old_function.name == new_function.name
+                && old_function.arguments.len() == new_function.arguments.len() =>
+        {
+            for (i, (old_expr, new_expr)) in old_function
+                .arguments
+                .iter()
+                .zip(new_function.arguments.iter())
+                .enumerate()
+            {
+                diff_expression(&format!("{}.arguments[{}]", key, i), old_expr, new_expr, changes);
+            }
+        }
+        _ => changes.push(RuleChange::Expression {
+            key: key.to_string(),
+            old: old.clone(),
+            new: new.clone(),
+        }),
+    }
+}
+
+fn conflict(key: String, reason: &str) -> ASTError {
+    ASTError::PatchConflict {
+        key,
+        reason: reason.to_string(),
+    }
+}
+
+/// Apply the diff to the rules, every change is checked against the old value, so a diff can only be applied to the
+/// same rules it is generated from.
+pub fn apply_diff(rules: &[SubAccountRule], diff: &[RuleDiff]) -> Result<Vec<SubAccountRule>, ASTError> {
+    let mut ret = rules.to_vec();
+    index_rules("rules", &ret)?;
+
+    let find = |rules: &[SubAccountRule], index: u32| rules.iter().position(|rule| rule.index == index);
+    let mut order = None;
+    let mut added = vec![];
+    for (i, item) in diff.iter().enumerate() {
+        let key = format!("diff[{}]", i);
+        match item {
+            RuleDiff::Removed(rule) => match find(&ret, rule.index) {
+                Some(position) if ret[position] == *rule => {
+                    ret.remove(position);
+                }
+                Some(_) => return Err(conflict(key, "the removed rule is different")),
+                None => return Err(conflict(key, "the removed rule does not exist")),
+            },
+            RuleDiff::Changed { index, changes } => match find(&ret, *index) {
+                Some(position) => {
+                    for (j, change) in changes.iter().enumerate() {
+                        apply_change(format!("{}.changes[{}]", key, j), &mut ret[position], change)?;
+                    }
+                }
+                None => return Err(conflict(key, "the changed rule does not exist")),
+            },
+            RuleDiff::Reordered(indexes) => order = Some((key, indexes)),
+            RuleDiff::Added { position, rule } => {
+                if find(&ret, rule.index).is_some() {
+                    return Err(conflict(key, "the added rule already exists"));
+                }
+                added.push((key, *position, rule));
+            }
+        }
+    }
+
+    if let Some((key, indexes)) = order {
+        let current: BTreeSet<u32> = ret.iter().map(|rule| rule.index).collect();
+        let expected: BTreeSet<u32> = indexes.iter().copied().collect();
+        if current != expected || indexes.len() != ret.len() {
+            return Err(conflict(
+                key,
+                "the reordered indexes are different from the existing rules",
+            ));
+        }
+        ret.sort_by_key(|rule| indexes.iter().position(|index| *index == rule.index));
+    }
+
+    // Insert the added rules from the front to the back, so every position is the same as the one in the new rules.
+    added.sort_by_key(|(_, position, _)| *position);
+    for (key, position, rule) in added {
+        if position > ret.len() || find(&ret, rule.index).is_some() {
+            return Err(conflict(key, "the added rule can not be inserted"));
+        }
+        ret.insert(position, rule.clone());
+    }
+
+    Ok(ret)
+}
+
+fn apply_change(key: String, rule: &mut SubAccountRule, change: &RuleChange) -> Result<(), ASTError> {
+    macro_rules! replace_field {
+        ($field: ident, $old: expr, $new: expr) => {{
+            if rule.$field != *$old {
+                return Err(conflict(key, concat!("the ", stringify!($field), " is different")));
+            }
+            rule.$field = $new.clone();
+        }};
+    }
+
+    match change {
+        RuleChange::Name { old, new } => replace_field!(name, old, new),
+        RuleChange::Note { old, new } => replace_field!(note, old, new),
+        RuleChange::Price { old, new } => replace_field!(price, old, new),
+        RuleChange::Status { old, new } => replace_field!(status, old, new),
+        RuleChange::Expression {
+            key: expression_key,
+            old,
+            new,
+        } => match get_expression_mut(&mut rule.ast, expression_key) {
+            Some(expression) if expression == old => *expression = new.clone(),
+            Some(_) => return Err(conflict(key, "the expression is different")),
+            None => return Err(conflict(key, "the expression does not exist")),
+        },
+    }
+
+    Ok(())
+}
+
+/// Find the sub-expression by the key like `ast.expressions[1].arguments[0]`.
+fn get_expression_mut<'a>(ast: &'a mut Expression, key: &str) -> Option<&'a mut Expression> {
+    let mut rest = key.strip_prefix("ast")?;
+    let mut expression = ast;
+    while !rest.is_empty() {
+        let (children, after_name) = match expression {
+            Expression::Operator(operator) => (&mut operator.expressions, rest.strip_prefix(".expressions[")?),
+            Expression::Function(function) => (&mut function.arguments, rest.strip_prefix(".arguments[")?),
+            _ => return None,
+        };
+        let (i, after_index) = after_name.split_once(']')?;
+        expression = children.get_mut(i.parse::<usize>().ok()?)?;
+        rest = after_index;
+    }
+
+    Some(expression)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::dsl;
+
+    fn gen_rule(index: u32, price: u64, text: &str) -> SubAccountRule {
+        SubAccountRule {
+            index,
+            name: format!("rule {}", index),
+            note: String::new(),
+            price,
+            status: SubAccountRuleStatus::On,
+            ast: dsl::parse(text).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_diff_rules() {
+        let old = vec![
+            gen_rule(0, 100, "account_length == 1"),
+            gen_rule(1, 50, "account_length == 2 and include_charset(account_chars, digit)"),
+            gen_rule(2, 10, "account_length >= 3"),
+        ];
+        let mut changed = gen_rule(1, 60, "account_length == 3 and include_charset(account_chars, en)");
+        changed.status = SubAccountRuleStatus::Off;
+        let new = vec![
+            gen_rule(3, 5, "account_length > 10"),
+            gen_rule(2, 10, "account_length >= 3"),
+            changed,
+        ];
+
+        let diff = diff_rules(&old, &new).unwrap();
+        assert_eq!(
+            vec![
+                RuleDiff::Removed(old[0].clone()),
+                RuleDiff::Changed {
+                    index: 1,
+                    changes: vec![
+                        RuleChange::Price { old: 50, new: 60 },
+                        RuleChange::Status {
+                            old: SubAccountRuleStatus::On,
+                            new: SubAccountRuleStatus::Off,
+                        },
+                        RuleChange::Expression {
+                            key: String::from("ast.expressions[0].expressions[1]"),
+                            old: dsl::parse("2").unwrap(),
+                            new: dsl::parse("3").unwrap(),
+                        },
+                        RuleChange::Expression {
+                            key: String::from("ast.expressions[1].arguments[1]"),
+                            old: dsl::parse("digit").unwrap(),
+                            new: dsl::parse("en").unwrap(),
+                        },
+                    ],
+                },
+                RuleDiff::Reordered(vec![2, 1]),
+                RuleDiff::Added {
+                    position: 0,
+                    rule: new[0].clone(),
+                },
+            ],
+            diff
+        );
+
+        assert_eq!(new, apply_diff(&old, &diff).unwrap());
+        assert!(diff_rules(&new, &new).unwrap().is_empty());
+        assert_eq!(old, apply_diff(&old, &diff_rules(&old, &old).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_diff_replaces_different_structures() {
+        let old = vec![gen_rule(0, 100, "account_length == 1 or account_length == 2")];
+        let new = vec![gen_rule(
+            0,
+            100,
+            "account_length == 1 or account_length == 2 or account_length == 3",
+        )];
+
+        let diff = diff_rules(&old, &new).unwrap();
+        assert!(matches!(
+            &diff[..],
+            [RuleDiff::Changed { changes, .. }] if matches!(&changes[..], [RuleChange::Expression { key, .. }] if key == "ast")
+        ));
+        assert_eq!(new, apply_diff(&old, &diff).unwrap());
+    }
+
+    #[test]
+    fn test_apply_diff_conflict() {
+        let old = vec![
+            gen_rule(0, 100, "account_length == 1"),
+            gen_rule(1, 50, "account_length == 2"),
+        ];
+        let mut new = old.clone();
+        new[1] = gen_rule(1, 60, "account_length == 3");
+        let diff = diff_rules(&old, &new).unwrap();
+
+        // The diff can not be applied to rules which have been changed.
+        let mut base = old.clone();
+        base[1].price = 70;
+        assert!(matches!(
+            apply_diff(&base, &diff),
+            Err(ASTError::PatchConflict { key, .. }) if key == "diff[0].changes[0]"
+        ));
+
+        let mut base = old.clone();
+        base[1].ast = dsl::parse("account_length == 4").unwrap();
+        assert!(matches!(
+            apply_diff(&base, &diff),
+            Err(ASTError::PatchConflict { key, .. }) if key == "diff[0].changes[1]"
+        ));
+
+        assert!(matches!(
+            apply_diff(&old[..1], &diff),
+            Err(ASTError::PatchConflict { key, .. }) if key == "diff[0]"
+        ));
+
+        let duplicated = vec![old[0].clone(), old[0].clone()];
+        assert!(matches!(
+            diff_rules(&duplicated, &new),
+            Err(ASTError::RuleIndexDuplicated { key, index: 0 }) if key == "old[1].index"
+        ));
+    }
+}
@@ -0,0 +1,689 @@
+//! A compact text DSL for writing rules by hand, for example:
+//!
+//! ```text
+//! account_length >= 4 and only_include_charset(account_chars, digit)
+//! ```
+//!
+//! The DSL is parsed into `Expression` and `Expression` can be printed back to the DSL, so it round-trips losslessly
+//! with the JSON and molecule encodings. The syntax is:
+//!
+//! - operators from the lowest precedence to the highest: `or`, `and`, `not`, `> >= < <= ==`, `+ -`, `* / %`, the
+//!   `and`, `or`, `+` and `*` accept more than two operands like `a and b and c`, parentheses are needed to nest the
+//!   same operator;
+//! - `min(a, b, ...)` and `max(a, b, ...)` operators and functions like `in_list(account, [0x...])` are called with
+//!   parentheses;
+//! - variables like `account`, `account_chars`, `account_length` and `timestamp`;
+//! - values: `true`/`false`, uint32 like `4` or `1_000`, uint8 and uint64 with suffixes like `4u8` and `100u64`,
+//!   binary like `0x1234`, strings in JSON style like `"abc"`, lists like `["a", "b"]` and `[0x12, 0x34]`, empty lists
+//!   `string[]` and `binary[]`, charset types in snake_case like `digit` and `zh_hans`.
+
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use das_types::constants::*;
+
+use crate::error::ASTError;
+use crate::types::*;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Number { value: u64, value_type: ValueType },
+    Hex(Vec<u8>),
+    Str(String),
+    EmptyVec(ValueType),
+    Symbol(&'static str),
+}
+
+const SYMBOLS: [&str; 14] = [">=", "<=", "==", ">", "<", "+", "-", "*", "/", "%", "(", ")", "[", "]"];
+
+fn syntax_error(position: usize, reason: &str) -> ASTError {
+    ASTError::DslSyntaxError {
+        position,
+        reason: reason.to_string(),
+    }
+}
+
+fn tokenize(text: &str) -> Result<Vec<(usize, Token)>, ASTError> {
+    let mut tokens = vec![];
+    let mut pos = 0;
+    while pos < text.len() {
+        let rest = &text[pos..];
+        let c = rest.chars().next().unwrap();
+        if c.is_whitespace() {
+            pos += c.len_utf8();
+            continue;
+        }
+
+        if c == ',' {
+            tokens.push((pos, Token::Symbol(",")));
+            pos += 1;
+        } else if c == '"' {
+            let mut end = 1;
+            let bytes = rest.as_bytes();
+            while end < bytes.len() && bytes[end] != b'"' {
+                end += if bytes[end] == b'\\' { 2 } else { 1 };
+            }
+            if end >= bytes.len() {
+                return Err(syntax_error(pos, "unclosed string"));
+            }
+            let val: String =
+                serde_json::from_str(&rest[..=end]).map_err(|_| syntax_error(pos, "invalid string literal"))?;
+            tokens.push((pos, Token::Str(val)));
+            pos += end + 1;
+        } else if let Some(digits) = rest.strip_prefix("0x") {
+            let len = digits.chars().take_while(|c| c.is_ascii_hexdigit()).count();
+            let bytes = hex::decode(&digits[..len]).map_err(|_| syntax_error(pos, "invalid hex literal"))?;
+            tokens.push((pos, Token::Hex(bytes)));
+            pos += 2 + len;
+        } else if c.is_ascii_digit() {
+            let len = rest
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+                .count();
+            let literal = rest[..len].replace('_', "");
+            let (number, value_type) = if let Some(number) = literal.strip_suffix("u8") {
+                (number, ValueType::Uint8)
+            } else if let Some(number) = literal.strip_suffix("u32") {
+                (number, ValueType::Uint32)
+            } else if let Some(number) = literal.strip_suffix("u64") {
+                (number, ValueType::Uint64)
+            } else {
+                (literal.as_str(), ValueType::Uint32)
+            };
+            let value = number
+                .parse::<u64>()
+                .map_err(|_| syntax_error(pos, "invalid number literal"))?;
+            tokens.push((pos, Token::Number { value, value_type }));
+            pos += len;
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            let len = rest
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+                .count();
+            let ident = &rest[..len];
+            if rest[len..].starts_with("[]") && (ident == "string" || ident == "binary") {
+                let value_type = if ident == "string" {
+                    ValueType::StringVec
+                } else {
+                    ValueType::BinaryVec
+                };
+                tokens.push((pos, Token::EmptyVec(value_type)));
+                pos += len + 2;
+            } else {
+                tokens.push((pos, Token::Ident(ident.to_string())));
+                pos += len;
+            }
+        } else {
+            match SYMBOLS.iter().find(|symbol| rest.starts_with(*symbol)) {
+                Some(symbol) => {
+                    tokens.push((pos, Token::Symbol(symbol)));
+                    pos += symbol.len();
+                }
+                None => return Err(syntax_error(pos, &format!("unexpected character `{}`", c))),
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn charset_to_ident(charset: CharSetType) -> String {
+    let mut ret = String::new();
+    for (i, c) in charset.to_string().chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                ret.push('_');
+            }
+            ret.push(c.to_ascii_lowercase());
+        } else {
+            ret.push(c);
+        }
+    }
+    ret
+}
+
+fn ident_to_charset(ident: &str) -> Option<CharSetType> {
+    (0..CHAR_SET_LENGTH as u32)
+        .filter_map(|i| CharSetType::try_from(i).ok())
+        .find(|charset| charset_to_ident(*charset) == ident)
+}
+
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    pos: usize,
+    end: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(_, token)| token)
+    }
+
+    fn position(&self) -> usize {
+        self.tokens.get(self.pos).map(|(pos, _)| *pos).unwrap_or(self.end)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.peek().cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn eat_symbol(&mut self, expected: &str) -> bool {
+        if matches!(self.peek(), Some(Token::Symbol(symbol)) if *symbol == expected) {
+            self.pos += 1;
+            return true;
+        }
+        false
+    }
+
+    fn expect_symbol(&mut self, expected: &str) -> Result<(), ASTError> {
+        if self.eat_symbol(expected) {
+            Ok(())
+        } else {
+            Err(syntax_error(self.position(), &format!("`{}` expected", expected)))
+        }
+    }
+
+    fn find_operator(&self, operators: &[(&str, SymbolType)]) -> Option<SymbolType> {
+        let text = match self.peek() {
+            Some(Token::Ident(ident)) => ident.as_str(),
+            Some(Token::Symbol(symbol)) => symbol,
+            _ => return None,
+        };
+        operators
+            .iter()
+            .find(|(expected, _)| *expected == text)
+            .map(|(_, symbol)| *symbol)
+    }
+
+    /// Parse operands joined by operators with the same precedence, like `a + b - c`.
+    fn parse_binary(
+        &mut self,
+        operators: &[(&str, SymbolType)],
+        parse_operand: fn(&mut Self) -> Result<Expression, ASTError>,
+    ) -> Result<Expression, ASTError> {
+        let mut left = parse_operand(self)?;
+        // Only the operands in the same chain are merged, an operand in parentheses is always kept as it is.
+        let mut chained_symbol = None;
+        while let Some(symbol) = self.find_operator(operators) {
+            self.pos += 1;
+            let right = parse_operand(self)?;
+
+            left = match left {
+                Expression::Operator(mut operator) if chained_symbol == Some(symbol) && is_variadic(symbol) => {
+                    operator.expressions.push(right);
+                    Expression::Operator(operator)
+                }
+                _ => Expression::Operator(OperatorExpression {
+                    symbol,
+                    expressions: vec![left, right],
+                }),
+            };
+            chained_symbol = Some(symbol);
+        }
+
+        Ok(left)
+    }
+
+    fn parse_or(&mut self) -> Result<Expression, ASTError> {
+        self.parse_binary(&[("or", SymbolType::Or)], Self::parse_and)
+    }
+
+    fn parse_and(&mut self) -> Result<Expression, ASTError> {
+        self.parse_binary(&[("and", SymbolType::And)], Self::parse_not)
+    }
+
+    fn parse_not(&mut self) -> Result<Expression, ASTError> {
+        if matches!(self.peek(), Some(Token::Ident(ident)) if ident == "not") {
+            self.pos += 1;
+            let expression = self.parse_not()?;
+            return Ok(Expression::Operator(OperatorExpression {
+                symbol: SymbolType::Not,
+                expressions: vec![expression],
+            }));
+        }
+
+        self.parse_compare()
+    }
+
+    fn parse_compare(&mut self) -> Result<Expression, ASTError> {
+        let left = self.parse_additive()?;
+        match self.find_operator(&COMPARE_OPERATORS) {
+            Some(symbol) => {
+                self.pos += 1;
+                let right = self.parse_additive()?;
+                if self.find_operator(&COMPARE_OPERATORS).is_some() {
+                    return Err(syntax_error(
+                        self.position(),
+                        "comparisons can not be chained, use parentheses instead",
+                    ));
+                }
+                Ok(Expression::Operator(OperatorExpression {
+                    symbol,
+                    expressions: vec![left, right],
+                }))
+            }
+            None => Ok(left),
+        }
+    }
+
+    fn parse_additive(&mut self) -> Result<Expression, ASTError> {
+        self.parse_binary(&ADDITIVE_OPERATORS, Self::parse_multiplicative)
+    }
+
+    fn parse_multiplicative(&mut self) -> Result<Expression, ASTError> {
+        self.parse_binary(&MULTIPLICATIVE_OPERATORS, Self::parse_primary)
+    }
+
+    fn parse_arguments(&mut self) -> Result<Vec<Expression>, ASTError> {
+        self.expect_symbol("(")?;
+        let mut arguments = vec![];
+        if !self.eat_symbol(")") {
+            loop {
+                arguments.push(self.parse_or()?);
+                if self.eat_symbol(")") {
+                    break;
+                }
+                self.expect_symbol(",")?;
+            }
+        }
+        Ok(arguments)
+    }
+
+    fn parse_primary(&mut self) -> Result<Expression, ASTError> {
+        let position = self.position();
+        let token = self
+            .next()
+            .ok_or_else(|| syntax_error(position, "unexpected end of the text"))?;
+
+        let value = match token {
+            Token::Symbol("(") => {
+                let expression = self.parse_or()?;
+                self.expect_symbol(")")?;
+                return Ok(expression);
+            }
+            Token::Symbol("[") => self.parse_list(position)?,
+            Token::Number { value, value_type } => {
+                Value::from_u64(value_type, value).map_err(|_| syntax_error(position, "number out of range"))?
+            }
+            Token::Hex(bytes) => Value::Binary(bytes),
+            Token::Str(text) => Value::String(text),
+            Token::EmptyVec(ValueType::StringVec) => Value::StringVec(vec![]),
+            Token::EmptyVec(_) => Value::BinaryVec(vec![]),
+            Token::Ident(ident) => {
+                let is_call = matches!(self.peek(), Some(Token::Symbol("(")));
+                match ident.as_str() {
+                    "true" => Value::Bool(true),
+                    "false" => Value::Bool(false),
+                    "min" | "max" if is_call => {
+                        return Ok(Expression::Operator(OperatorExpression {
+                            symbol: SymbolType::from_str(&ident).unwrap(),
+                            expressions: self.parse_arguments()?,
+                        }));
+                    }
+                    _ if is_call => {
+                        let name = FnName::from_str(&ident)
+                            .map_err(|_| syntax_error(position, &format!("undefined function `{}`", ident)))?;
+                        return Ok(Expression::Function(FunctionExpression {
+                            name,
+                            arguments: self.parse_arguments()?,
+                        }));
+                    }
+                    _ => {
+                        if let Ok(name) = VarName::from_str(&ident) {
+                            return Ok(Expression::Variable(VariableExpression { name }));
+                        }
+                        match ident_to_charset(&ident) {
+                            Some(charset) => Value::CharsetType(charset),
+                            None => {
+                                return Err(syntax_error(position, &format!("undefined identifier `{}`", ident)));
+                            }
+                        }
+                    }
+                }
+            }
+            Token::Symbol(symbol) => return Err(syntax_error(position, &format!("unexpected `{}`", symbol))),
+        };
+
+        Ok(Expression::Value(ValueExpression {
+            value_type: value.get_type(),
+            value,
+        }))
+    }
+
+    fn parse_list(&mut self, position: usize) -> Result<Value, ASTError> {
+        let mut strings = vec![];
+        let mut binaries = vec![];
+        if matches!(self.peek(), Some(Token::Symbol("]"))) {
+            return Err(syntax_error(position, "use string[] or binary[] for empty lists"));
+        }
+        loop {
+            let item_position = self.position();
+            match self.next() {
+                Some(Token::Str(text)) if binaries.is_empty() => strings.push(text),
+                Some(Token::Hex(bytes)) if strings.is_empty() => binaries.push(bytes),
+                _ => {
+                    return Err(syntax_error(
+                        item_position,
+                        "the items of a list should be all strings or all binaries",
+                    ))
+                }
+            }
+            if self.eat_symbol("]") {
+                break;
+            }
+            self.expect_symbol(",")?;
+        }
+
+        if strings.is_empty() {
+            Ok(Value::BinaryVec(binaries))
+        } else {
+            Ok(Value::StringVec(strings))
+        }
+    }
+}
+
+const COMPARE_OPERATORS: [(&str, SymbolType); 5] = [
+    (">=", SymbolType::Gte),
+    ("<=", SymbolType::Lte),
+    ("==", SymbolType::Equal),
+    (">", SymbolType::Gt),
+    ("<", SymbolType::Lt),
+];
+const ADDITIVE_OPERATORS: [(&str, SymbolType); 2] = [("+", SymbolType::Add), ("-", SymbolType::Sub)];
+const MULTIPLICATIVE_OPERATORS: [(&str, SymbolType); 3] =
+    [("*", SymbolType::Mul), ("/", SymbolType::Div), ("%", SymbolType::Mod)];
+
+fn is_variadic(symbol: SymbolType) -> bool {
+    matches!(
+        symbol,
+        SymbolType::And | SymbolType::Or | SymbolType::Add | SymbolType::Mul
+    )
+}
+
+/// Parse the DSL text into an expression.
+pub fn parse(text: &str) -> Result<Expression, ASTError> {
+    let mut parser = Parser {
+        tokens: tokenize(text)?,
+        pos: 0,
+        end: text.len(),
+    };
+    let expression = parser.parse_or()?;
+    if parser.pos < parser.tokens.len() {
+        return Err(syntax_error(parser.position(), "unexpected token"));
+    }
+
+    Ok(expression)
+}
+
+fn precedence(expression: &Expression) -> u8 {
+    match expression {
+        Expression::Operator(operator) => match operator.symbol {
+            SymbolType::Or => 1,
+            SymbolType::And => 2,
+            SymbolType::Not => 3,
+            SymbolType::Gt | SymbolType::Gte | SymbolType::Lt | SymbolType::Lte | SymbolType::Equal => 4,
+            SymbolType::Add | SymbolType::Sub => 5,
+            SymbolType::Mul | SymbolType::Div | SymbolType::Mod => 6,
+            SymbolType::Min | SymbolType::Max => 7,
+        },
+        _ => 7,
+    }
+}
+
+fn print_list(key: &str, expressions: &[Expression]) -> Result<String, ASTError> {
+    let mut items = vec![];
+    for (i, expression) in expressions.iter().enumerate() {
+        items.push(print_expression(&format!("{}[{}]", key, i), expression)?);
+    }
+    Ok(items.join(", "))
+}
+
+fn print_operator(key: &str, operator: &OperatorExpression) -> Result<String, ASTError> {
+    let parent_precedence = precedence(&Expression::Operator(operator.clone()));
+    let length = operator.expressions.len();
+    let expressions_key = format!("{}.expressions", key);
+    let valid_length = match operator.symbol {
+        SymbolType::Min | SymbolType::Max => true,
+        SymbolType::Not => length == 1,
+        symbol if is_variadic(symbol) => length >= 2,
+        _ => length == 2,
+    };
+    if !valid_length {
+        return Err(ASTError::ParamLengthError {
+            key: expressions_key,
+            expected_length: String::from("a length which can be written in the DSL"),
+            length: format!("it is {}", length),
+        });
+    }
+
+    match operator.symbol {
+        SymbolType::Min | SymbolType::Max => Ok(format!(
+            "{}({})",
+            operator.symbol,
+            print_list(&expressions_key, &operator.expressions)?
+        )),
+        _ => {
+            let mut items = vec![];
+            for (i, expression) in operator.expressions.iter().enumerate() {
+                let text = print_expression(&format!("{}[{}]", expressions_key, i), expression)?;
+                let need_parentheses = match precedence(expression).cmp(&parent_precedence) {
+                    Ordering::Less => true,
+                    Ordering::Greater => false,
+                    // `not` is a prefix operator, so it can be nested without parentheses.
+                    Ordering::Equal if operator.symbol == SymbolType::Not => false,
+                    // The left operand is parsed first, so it needs parentheses only when it would be merged into
+                    // the same chain or it is a comparison which can not be chained.
+                    Ordering::Equal if i == 0 => {
+                        parent_precedence == 4
+                            || matches!(expression, Expression::Operator(child) if child.symbol == operator.symbol)
+                    }
+                    Ordering::Equal => true,
+                };
+                items.push(if need_parentheses { format!("({})", text) } else { text });
+            }
+
+            if operator.symbol == SymbolType::Not {
+                Ok(format!("not {}", items[0]))
+            } else {
+                Ok(items.join(&format!(" {} ", operator.symbol)))
+            }
+        }
+    }
+}
+
+fn print_value(value: &Value) -> String {
+    let quote = |text: &String| serde_json::to_string(text).unwrap();
+    match value {
+        Value::Bool(val) => val.to_string(),
+        Value::Uint8(val) => format!("{}u8", val),
+        Value::Uint32(val) => val.to_string(),
+        Value::Uint64(val) => format!("{}u64", val),
+        Value::Binary(val) => format!("0x{}", hex::encode(val)),
+        Value::BinaryVec(val) if val.is_empty() => String::from("binary[]"),
+        Value::BinaryVec(val) => format!(
+            "[{}]",
+            val.iter()
+                .map(|item| format!("0x{}", hex::encode(item)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Value::String(val) => quote(val),
+        Value::StringVec(val) if val.is_empty() => String::from("string[]"),
+        Value::StringVec(val) => format!("[{}]", val.iter().map(quote).collect::<Vec<_>>().join(", ")),
+        Value::CharsetType(val) => charset_to_ident(*val),
+    }
+}
+
+fn print_expression(key: &str, expression: &Expression) -> Result<String, ASTError> {
+    match expression {
+        Expression::Operator(operator) => print_operator(key, operator),
+        Expression::Function(function) => Ok(format!(
+            "{}({})",
+            function.name,
+            print_list(&format!("{}.arguments", key), &function.arguments)?
+        )),
+        Expression::Variable(variable) => Ok(variable.name.to_string()),
+        Expression::Value(value) => {
+            if value.value_type != value.value.get_type() {
+                return Err(ASTError::ParamTypeMismatch {
+                    key: format!("{}.value", key),
+                    types: value.value.get_type().to_string(),
+                });
+            }
+            Ok(print_value(&value.value))
+        }
+    }
+}
+
+/// Print the expression in the DSL, it fails only when the expression can not be written in the DSL, like an `and`
+/// operator with only one operand.
+pub fn to_string(expression: &Expression) -> Result<String, ASTError> {
+    print_expression("", expression)
+}
+
+#[cfg(test)]
+mod test {
+    use das_types::packed;
+    use das_types::prelude::*;
+    use serde_json::json;
+
+    use super::*;
+    use crate::util;
+
+    fn assert_round_trip(text: &str) -> Expression {
+        let expression = parse(text).unwrap_or_else(|err| panic!("parse {} failed: {}", text, err));
+        let printed = to_string(&expression).unwrap();
+        let reparsed = parse(&printed).unwrap();
+
+        // Compare with both the JSON and the molecule encodings.
+        assert_eq!(
+            serde_json::to_value(&expression).unwrap(),
+            serde_json::to_value(&reparsed).unwrap(),
+            "{} is printed as {}",
+            text,
+            printed
+        );
+        let mol: packed::ASTExpression = expression.clone().into();
+        let reparsed_mol: packed::ASTExpression = reparsed.into();
+        assert_eq!(mol.as_slice(), reparsed_mol.as_slice());
+
+        let from_mol = util::mol_reader_to_expression(String::new(), mol.as_reader()).unwrap();
+        assert_eq!(printed, to_string(&from_mol).unwrap());
+
+        expression
+    }
+
+    #[test]
+    fn test_dsl_to_json() {
+        let expression = assert_round_trip("account_length >= 4 and only_include_charset(account_chars, digit)");
+        let expected = json!({
+            "type": "operator",
+            "symbol": "and",
+            "expressions": [
+                {
+                    "type": "operator",
+                    "symbol": ">=",
+                    "expressions": [
+                        { "type": "variable", "name": "account_length" },
+                        { "type": "value", "value_type": "uint32", "value": 4 },
+                    ],
+                },
+                {
+                    "type": "function",
+                    "name": "only_include_charset",
+                    "arguments": [
+                        { "type": "variable", "name": "account_chars" },
+                        { "type": "value", "value_type": "charset_type", "value": "Digit" },
+                    ],
+                },
+            ],
+        });
+        assert_eq!(expected, serde_json::to_value(&expression).unwrap());
+
+        let from_json = util::json_to_expression(String::new(), &expected).unwrap();
+        assert_eq!(
+            "account_length >= 4 and only_include_charset(account_chars, digit)",
+            to_string(&from_json).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_dsl_round_trip() {
+        assert_round_trip("account");
+        assert_round_trip("true or false and not true");
+        assert_round_trip("not (account_length > 1 or account_length == 5u8)");
+        assert_round_trip("(true and false) and true");
+        assert_round_trip("true and false and true");
+        assert_round_trip("1_000_000u64 * select_by_length(account_length, 100u8, 10u8, 1u8)");
+        assert_round_trip("(1 + 2) * 3 - 4 / 2 % 3 > min(1, 2, 3) - max(1u64, 2)");
+        assert_round_trip("10 - (2 - 1) == 9");
+        assert_round_trip(r#"include_chars(account, ["🌈", "\"quoted\""]) or starts_with(account, string[])"#);
+        assert_round_trip(
+            "in_list(account, [0x0000000000000000000000000000000000000000, 0x01]) or in_list(account, binary[])",
+        );
+        assert_round_trip(r#"matches_pattern(account, "^(.)\\1\\1$") and include_charset(account_chars, zh_hans)"#);
+    }
+
+    #[test]
+    fn test_dsl_flattening() {
+        let expression = parse("true and false and true").unwrap();
+        assert!(matches!(expression, Expression::Operator(OperatorExpression {
+            symbol: SymbolType::And,
+            expressions,
+        }) if expressions.len() == 3));
+
+        let expression = parse("(true and false) and true").unwrap();
+        assert!(matches!(expression, Expression::Operator(OperatorExpression {
+            symbol: SymbolType::And,
+            expressions,
+        }) if expressions.len() == 2));
+
+        assert_eq!(
+            "(true and false) and true",
+            to_string(&parse("(true and false) and true").unwrap()).unwrap()
+        );
+        assert_eq!("1 + 2 - 3", to_string(&parse("1 + 2 - 3").unwrap()).unwrap());
+        assert_eq!("1 - (2 - 3)", to_string(&parse("1 - (2 - 3)").unwrap()).unwrap());
+        assert_eq!("1 + (2 + 3)", to_string(&parse("1 + (2 + 3)").unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_dsl_syntax_error() {
+        for (text, position) in [
+            ("account_length >=", 17),
+            ("account_length > 1 > 2", 19),
+            ("(true", 5),
+            ("unknown_var", 0),
+            ("unknown_fn(account)", 0),
+            ("[]", 0),
+            (r#"["a", 0x01]"#, 6),
+            ("256u8", 0),
+            ("true true", 5),
+            ("account_length # 1", 15),
+        ] {
+            let ret = parse(text);
+            assert!(
+                matches!(ret, Err(ASTError::DslSyntaxError { position: pos, .. }) if pos == position),
+                "{} should be failed at {}, but got {:?}",
+                text,
+                position,
+                ret
+            );
+        }
+    }
+
+    #[test]
+    fn test_dsl_unprintable() {
+        let expression = Expression::Operator(OperatorExpression {
+            symbol: SymbolType::And,
+            expressions: vec![Expression::Value(ValueExpression {
+                value_type: ValueType::Bool,
+                value: Value::Bool(true),
+            })],
+        });
+        assert!(matches!(to_string(&expression), Err(ASTError::ParamLengthError { .. })));
+    }
+}
@@ -1,14 +1,14 @@
 #[cfg(feature = "no_std")]
 use alloc::string::String;
 
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 use thiserror::Error;
 #[cfg(feature = "no_std")]
 use thiserror_no_std::Error;
 
 use crate::types::*;
 
-#[derive(Error, Debug)]
+#[derive(Error, Debug, Clone)]
 pub enum ASTError {
     #[error("[{key}] Parsing hex string failed")]
     ParseHexFailed { key: String },
@@ -56,6 +56,22 @@ pub enum ASTError {
     ValueTypeMismatch,
     #[error("The value do not support this operator")]
     ValueOperatorUnsupported,
+    #[error("The result of the calculation is overflow")]
+    ValueOverflow,
+    #[error("The divisor can not be zero")]
+    ValueDividedByZero,
+    #[error("[{key}] The calculation failed: {reason}")]
+    CalculationFailed { key: String, reason: String },
     #[error("[{key}] The expression must be a function or operator")]
     FunctionOrOperatorRequired { key: String },
+    #[error("[{key}] The pattern is invalid: {reason}")]
+    PatternInvalid { key: String, reason: String },
+    #[error("[{key}] The pattern is too complex to match")]
+    PatternTooComplex { key: String },
+    #[error("[{position}] The DSL has a syntax error: {reason}")]
+    DslSyntaxError { position: usize, reason: String },
+    #[error("[{key}] The rule index {index} is duplicated")]
+    RuleIndexDuplicated { key: String, index: u32 },
+    #[error("[{key}] The patch can not be applied: {reason}")]
+    PatchConflict { key: String, reason: String },
 }
@@ -4,17 +4,20 @@ use alloc::format;
 use alloc::string::String;
 #[cfg(feature = "no_std")]
 use alloc::string::ToString;
+#[cfg(not(feature = "no_std"))]
+use std::collections::BTreeMap;
 
 #[cfg(feature = "no_std")]
 use das_types::{constants::*, packed, prelude::*};
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 use das_types::{constants::*, packed, prelude::*};
 
 use crate::error::ASTError;
+use crate::pattern;
 use crate::types::*;
 use crate::util::*;
 
-fn assert_param_length(key: String, length: usize, expected_length: usize) -> Result<(), ASTError> {
+pub(crate) fn assert_param_length(key: String, length: usize, expected_length: usize) -> Result<(), ASTError> {
     if length != expected_length {
         return Err(ASTError::ParamLengthError {
             key,
@@ -26,7 +29,7 @@ fn assert_param_length(key: String, length: usize, expected_length: usize) -> Re
     Ok(())
 }
 
-fn assert_param_length_gte(key: String, length: usize, expected_length: usize) -> Result<(), ASTError> {
+pub(crate) fn assert_param_length_gte(key: String, length: usize, expected_length: usize) -> Result<(), ASTError> {
     if length < expected_length {
         return Err(ASTError::ParamLengthError {
             key,
@@ -67,6 +70,7 @@ pub fn match_rule_with_account_chars<'a>(
     rules: &'a [SubAccountRule],
     account_chars: packed::AccountCharsReader,
     account: &str,
+    context: &RuleContext,
 ) -> Result<Option<&'a SubAccountRule>, ASTError> {
     for (i, rule) in rules.iter().enumerate() {
         if rule.status == SubAccountRuleStatus::Off {
@@ -82,7 +86,7 @@ pub fn match_rule_with_account_chars<'a>(
             }
         }
 
-        let value = handle_expression(&format!("rules[{}].ast", i), &rule.ast, account_chars, account)?;
+        let value = handle_expression(&format!("rules[{}].ast", i), &rule.ast, account_chars, account, context)?;
         let ret = assert_and_get_return!(format!("rules[{}]", i), value, Bool);
 
         if ret {
@@ -93,16 +97,207 @@ pub fn match_rule_with_account_chars<'a>(
     Ok(None)
 }
 
+/// Find the first matched price rule and return it with the price of the account.
+///
+/// The AST of a price rule may return either a bool or a uint:
+/// - when it returns a bool, the rule is matched if the value is true and the price is `rule.price`;
+/// - when it returns a uint, the rule is always matched and the price is the returned value, so that one rule can
+///   calculate prices like "base price × length multiplier" by itself.
+pub fn match_price_rule_with_account_chars<'a>(
+    rules: &'a [SubAccountRule],
+    account_chars: packed::AccountCharsReader,
+    account: &str,
+    context: &RuleContext,
+) -> Result<Option<(&'a SubAccountRule, u64)>, ASTError> {
+    for (i, rule) in rules.iter().enumerate() {
+        if rule.status == SubAccountRuleStatus::Off {
+            continue;
+        }
+
+        if let Some(price) = execute_rule(i, rule, account_chars, account, context, true)? {
+            return Ok(Some((rule, price)));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Execute the rule at the index of the rules, return its price if it is matched.
+pub(crate) fn execute_rule(
+    index: usize,
+    rule: &SubAccountRule,
+    account_chars: packed::AccountCharsReader,
+    account: &str,
+    context: &RuleContext,
+    is_price_rule: bool,
+) -> Result<Option<u64>, ASTError> {
+    match rule.ast {
+        Expression::Function(_) | Expression::Operator(_) => {}
+        _ => {
+            return Err(ASTError::FunctionOrOperatorRequired {
+                key: format!("rules[{}].ast", index),
+            })
+        }
+    }
+
+    let value = handle_expression(
+        &format!("rules[{}].ast", index),
+        &rule.ast,
+        account_chars,
+        account,
+        context,
+    )?;
+    get_rule_price(index, rule, value, is_price_rule)
+}
+
+/// Get the price of the rule from the value of its AST, return None if the rule is not matched.
+fn get_rule_price(
+    index: usize,
+    rule: &SubAccountRule,
+    value: Value,
+    is_price_rule: bool,
+) -> Result<Option<u64>, ASTError> {
+    match value {
+        Value::Bool(true) => Ok(Some(rule.price)),
+        Value::Bool(false) => Ok(None),
+        Value::Uint8(_) | Value::Uint32(_) | Value::Uint64(_) if is_price_rule => Ok(Some(value.get_u64()?)),
+        _ if is_price_rule => Err(ASTError::ReturnTypeError {
+            key: format!("rules[{}]", index),
+            types: String::from("Bool, Uint8, Uint32, Uint64"),
+        }),
+        _ => Err(ASTError::ReturnTypeError {
+            key: format!("rules[{}]", index),
+            types: ValueType::Bool.to_string(),
+        }),
+    }
+}
+
+/// The trace of one rule, it contains the value of every sub-expression keyed by the same path used by `ASTError`,
+/// like `rules[2].ast.expressions[1]`.
+#[cfg(not(feature = "no_std"))]
+#[derive(Debug)]
+pub struct RuleTrace {
+    pub index: usize,
+    /// The rule is skipped because its status is off.
+    pub skipped: bool,
+    /// The price of the rule if it is matched.
+    pub price: Option<u64>,
+    pub values: BTreeMap<String, Result<Value, ASTError>>,
+}
+
+/// The trace of matching rules, it contains every rule evaluated until the matched one.
+#[cfg(not(feature = "no_std"))]
+#[derive(Debug)]
+pub struct RulesTrace {
+    pub rules: Vec<RuleTrace>,
+    /// The same result as `match_rule_with_account_chars` or `match_price_rule_with_account_chars` but with the index
+    /// of the matched rule.
+    pub result: Result<Option<(usize, u64)>, ASTError>,
+}
+
+/// Match the rules like `match_rule_with_account_chars` or `match_price_rule_with_account_chars` and record the value of
+/// every sub-expression, so that it is possible to explain why a rule is matched or not.
+///
+/// The sub-expressions are evaluated one by one, so it is much slower than matching and it should only be used off-chain.
+#[cfg(not(feature = "no_std"))]
+pub fn trace_rules_with_account_chars(
+    rules: &[SubAccountRule],
+    account_chars: packed::AccountCharsReader,
+    account: &str,
+    context: &RuleContext,
+    is_price_rules: bool,
+) -> RulesTrace {
+    let mut traces = vec![];
+    for (i, rule) in rules.iter().enumerate() {
+        let mut trace = RuleTrace {
+            index: i,
+            skipped: rule.status == SubAccountRuleStatus::Off,
+            price: None,
+            values: BTreeMap::new(),
+        };
+        if trace.skipped {
+            traces.push(trace);
+            continue;
+        }
+
+        let key = format!("rules[{}].ast", i);
+        trace_expression(&key, &rule.ast, account_chars, account, context, &mut trace.values);
+
+        let result = match rule.ast {
+            Expression::Function(_) | Expression::Operator(_) => match &trace.values[&key] {
+                Ok(value) => get_rule_price(i, rule, value.clone(), is_price_rules),
+                Err(err) => Err(err.clone()),
+            },
+            _ => Err(ASTError::FunctionOrOperatorRequired { key }),
+        };
+        trace.price = result.as_ref().ok().copied().flatten();
+        traces.push(trace);
+
+        match result {
+            Ok(Some(price)) => {
+                return RulesTrace {
+                    rules: traces,
+                    result: Ok(Some((i, price))),
+                }
+            }
+            Ok(None) => {}
+            Err(err) => {
+                return RulesTrace {
+                    rules: traces,
+                    result: Err(err),
+                }
+            }
+        }
+    }
+
+    RulesTrace {
+        rules: traces,
+        result: Ok(None),
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+fn trace_expression(
+    key: &str,
+    ast: &Expression,
+    account_chars: packed::AccountCharsReader,
+    account: &str,
+    context: &RuleContext,
+    values: &mut BTreeMap<String, Result<Value, ASTError>>,
+) {
+    values.insert(
+        key.to_string(),
+        handle_expression(key, ast, account_chars, account, context),
+    );
+
+    match ast {
+        Expression::Operator(operator) => {
+            for (i, expression) in operator.expressions.iter().enumerate() {
+                let key = format!("{}.expressions[{}]", key, i);
+                trace_expression(&key, expression, account_chars, account, context, values);
+            }
+        }
+        Expression::Function(function) => {
+            for (i, argument) in function.arguments.iter().enumerate() {
+                let key = format!("{}.arguments[{}]", key, i);
+                trace_expression(&key, argument, account_chars, account, context, values);
+            }
+        }
+        _ => {}
+    }
+}
+
 fn handle_expression(
     key: &str,
     ast: &Expression,
     account_chars: packed::AccountCharsReader,
     account: &str,
+    context: &RuleContext,
 ) -> Result<Value, ASTError> {
     Ok(match ast {
-        Expression::Operator(operator) => handle_operator(key, operator, account_chars, account)?,
-        Expression::Function(function) => handle_function(key, function, account_chars, account)?,
-        Expression::Variable(variable) => handle_variable(key, variable, account_chars, account)?,
+        Expression::Operator(operator) => handle_operator(key, operator, account_chars, account, context)?,
+        Expression::Function(function) => handle_function(key, function, account_chars, account, context)?,
+        Expression::Variable(variable) => handle_variable(key, variable, account_chars, account, context)?,
         Expression::Value(value) => value.value.clone(),
         // _ => todo!()
     })
@@ -113,15 +308,24 @@ fn handle_operator(
     operator: &OperatorExpression,
     account_chars: packed::AccountCharsReader,
     account: &str,
+    context: &RuleContext,
 ) -> Result<Value, ASTError> {
-    Ok(Value::Bool(match operator.symbol {
-        SymbolType::And => operator_and_or(&key, operator, account_chars, account, true)?,
-        SymbolType::Or => operator_and_or(&key, operator, account_chars, account, false)?,
-        SymbolType::Not => operator_not(&key, operator, account_chars, account)?,
-        SymbolType::Equal | SymbolType::Gt | SymbolType::Gte | SymbolType::Lt | SymbolType::Lte => {
-            operator_compare(&key, operator, account_chars, account, operator.symbol)?
-        } // _ => todo!(),
-    }))
+    Ok(match operator.symbol {
+        SymbolType::And => Value::Bool(operator_and_or(key, operator, account_chars, account, context, true)?),
+        SymbolType::Or => Value::Bool(operator_and_or(key, operator, account_chars, account, context, false)?),
+        SymbolType::Not => Value::Bool(operator_not(key, operator, account_chars, account, context)?),
+        SymbolType::Equal | SymbolType::Gt | SymbolType::Gte | SymbolType::Lt | SymbolType::Lte => Value::Bool(
+            operator_compare(key, operator, account_chars, account, context, operator.symbol)?,
+        ),
+        SymbolType::Add
+        | SymbolType::Sub
+        | SymbolType::Mul
+        | SymbolType::Div
+        | SymbolType::Mod
+        | SymbolType::Min
+        | SymbolType::Max => operator_calculate(key, operator, account_chars, account, context, operator.symbol)?,
+        // _ => todo!(),
+    })
 }
 
 fn operator_and_or(
@@ -129,6 +333,7 @@ fn operator_and_or(
     operator: &OperatorExpression,
     account_chars: packed::AccountCharsReader,
     account: &str,
+    context: &RuleContext,
     is_and: bool,
 ) -> Result<bool, ASTError> {
     assert_param_length_gte(format!("{}.expressions", key), operator.expressions.len(), 2)?;
@@ -140,6 +345,7 @@ fn operator_and_or(
             expression,
             account_chars,
             account,
+            context,
         )?;
         match value {
             Value::Bool(val) => {
@@ -170,6 +376,7 @@ fn operator_not(
     operator: &OperatorExpression,
     account_chars: packed::AccountCharsReader,
     account: &str,
+    context: &RuleContext,
 ) -> Result<bool, ASTError> {
     assert_param_length(format!("{}.expressions", key), operator.expressions.len(), 1)?;
 
@@ -178,6 +385,7 @@ fn operator_not(
         &operator.expressions[0],
         account_chars,
         account,
+        context,
     )?;
     match value {
         Value::Bool(val) => Ok(!val),
@@ -195,6 +403,7 @@ fn operator_compare(
     operator: &OperatorExpression,
     account_chars: packed::AccountCharsReader,
     account: &str,
+    context: &RuleContext,
     symbol_type: SymbolType,
 ) -> Result<bool, ASTError> {
     assert_param_length(format!("{}.expressions", key), operator.expressions.len(), 2)?;
@@ -204,18 +413,27 @@ fn operator_compare(
         &operator.expressions[0],
         account_chars,
         account,
+        context,
     )?;
     let right = handle_expression(
         &format!("{}.expressions[1]", key),
         &operator.expressions[1],
         account_chars,
         account,
+        context,
     )?;
 
-    if ![ValueType::Uint8, ValueType::Uint32, ValueType::Uint64].contains(&left.get_type()) {
+    if ![
+        ValueType::Uint8,
+        ValueType::Uint32,
+        ValueType::Uint64,
+        ValueType::String,
+    ]
+    .contains(&left.get_type())
+    {
         return Err(ASTError::ParamTypeError {
             key: format!("{}.expressions[0]", key),
-            types: format!("Uint8, Uint32, Uint64"),
+            types: String::from("Uint8, Uint32, Uint64, String"),
         });
     }
 
@@ -226,11 +444,61 @@ fn operator_compare(
         })
 }
 
+fn operator_calculate(
+    key: &str,
+    operator: &OperatorExpression,
+    account_chars: packed::AccountCharsReader,
+    account: &str,
+    context: &RuleContext,
+    symbol_type: SymbolType,
+) -> Result<Value, ASTError> {
+    match symbol_type {
+        // These operators are associative, so they can accept more than two params.
+        SymbolType::Add | SymbolType::Mul | SymbolType::Min | SymbolType::Max => {
+            assert_param_length_gte(format!("{}.expressions", key), operator.expressions.len(), 2)?
+        }
+        _ => assert_param_length(format!("{}.expressions", key), operator.expressions.len(), 2)?,
+    }
+
+    let mut ret: Option<Value> = None;
+    for (i, expression) in operator.expressions.iter().enumerate() {
+        let value = handle_expression(
+            &format!("{}.expressions[{}]", key, i),
+            expression,
+            account_chars,
+            account,
+            context,
+        )?;
+
+        if ![ValueType::Uint8, ValueType::Uint32, ValueType::Uint64].contains(&value.get_type()) {
+            return Err(ASTError::ParamTypeError {
+                key: format!("{}.expressions[{}]", key, i),
+                types: String::from("Uint8, Uint32, Uint64"),
+            });
+        }
+
+        ret = match ret {
+            None => Some(value),
+            Some(left) => Some(
+                left.calculate(&value, symbol_type)
+                    .map_err(|err| ASTError::CalculationFailed {
+                        key: key.to_string(),
+                        reason: err.to_string(),
+                    })?,
+            ),
+        };
+    }
+
+    // The length of params has been checked above, so the ret must be Some.
+    Ok(ret.unwrap())
+}
+
 fn handle_function(
     key: &str,
     function: &FunctionExpression,
     account_chars: packed::AccountCharsReader,
     account: &str,
+    context: &RuleContext,
 ) -> Result<Value, ASTError> {
     macro_rules! call_fn {
         ($fn_name: ident, $arg_len: expr) => {{
@@ -239,7 +507,7 @@ fn handle_function(
                 function.arguments.len(),
                 $arg_len.to_owned(),
             )?;
-            $fn_name(key, &function.arguments, account_chars, account)
+            $fn_name(key, &function.arguments, account_chars, account, context)
         }};
     }
 
@@ -250,13 +518,34 @@ fn handle_function(
         FnName::IncludeCharset => call_fn!(include_charset, 2),
         FnName::StartsWith => call_fn!(starts_with, 2),
         FnName::EndsWith => call_fn!(ends_with, 2),
+        FnName::MatchesPattern => call_fn!(matches_pattern, 2),
+        FnName::SelectByLength => {
+            assert_param_length_gte(format!("{}.arguments", key), function.arguments.len(), 2)?;
+            select_by_length(key, &function.arguments, account_chars, account, context)
+        }
+        FnName::ByteLength => call_fn!(byte_length, 1),
+        FnName::CharCount => call_fn!(char_count, 1),
+        FnName::IncludesAt => call_fn!(includes_at, 3),
+        FnName::CountCharset => call_fn!(count_charset, 2),
     }?;
 
-    if ret.get_type() != ValueType::Bool {
-        return Err(ASTError::ReturnTypeError {
-            key: key.to_string(),
-            types: ValueType::Bool.to_string(),
-        });
+    match function.name {
+        FnName::SelectByLength | FnName::ByteLength | FnName::CharCount | FnName::CountCharset => {
+            if ![ValueType::Uint8, ValueType::Uint32, ValueType::Uint64].contains(&ret.get_type()) {
+                return Err(ASTError::ReturnTypeError {
+                    key: key.to_string(),
+                    types: String::from("Uint8, Uint32, Uint64"),
+                });
+            }
+        }
+        _ => {
+            if ret.get_type() != ValueType::Bool {
+                return Err(ASTError::ReturnTypeError {
+                    key: key.to_string(),
+                    types: ValueType::Bool.to_string(),
+                });
+            }
+        }
     }
 
     Ok(ret)
@@ -267,6 +556,7 @@ fn handle_variable(
     variable: &VariableExpression,
     account_chars: packed::AccountCharsReader,
     account: &str,
+    context: &RuleContext,
 ) -> Result<Value, ASTError> {
     let ret = match variable.name {
         VarName::Account => Value::String(account.to_string()),
@@ -283,17 +573,42 @@ fn handle_variable(
 
             Value::StringVec(string_vec)
         }
-        VarName::AccountLength => Value::Uint32(account_chars.len() as u32), // _ => todo!(),
+        VarName::AccountLength => Value::Uint32(account_chars.len() as u32),
+        VarName::Timestamp => Value::Uint64(context.timestamp),
+        VarName::Years => Value::Uint32(context.years),
+        VarName::ParentAccount => Value::String(context.parent_account.to_string()),
+        VarName::MintedCount => Value::Uint32(context.minted_count),
+        // _ => todo!(),
     };
 
     Ok(ret)
 }
 
+/// Get the account from the argument which can be either the `account` or the `parent_account` variable.
+fn get_account_argument<'b>(
+    key: String,
+    argument: &Expression,
+    account: &'b str,
+    context: &'b RuleContext,
+) -> Result<&'b str, ASTError> {
+    match argument {
+        Expression::Variable(VariableExpression { name: VarName::Account }) => Ok(account),
+        Expression::Variable(VariableExpression {
+            name: VarName::ParentAccount,
+        }) => Ok(context.parent_account),
+        _ => Err(ASTError::ParamTypeError {
+            key,
+            types: String::from("variable Account or ParentAccount"),
+        }),
+    }
+}
+
 fn include_chars(
     key: &str,
     arguments: &[Expression],
     _account_chars: packed::AccountCharsReader,
     account: &str,
+    _context: &RuleContext,
 ) -> Result<Value, ASTError> {
     assert_param_expression!(
         format!("{}.arguments[0]", key),
@@ -329,6 +644,7 @@ fn starts_with(
     arguments: &[Expression],
     _account_chars: packed::AccountCharsReader,
     account: &str,
+    _context: &RuleContext,
 ) -> Result<Value, ASTError> {
     assert_param_expression!(
         format!("{}.arguments[0]", key),
@@ -364,6 +680,7 @@ fn ends_with(
     arguments: &[Expression],
     _account_chars: packed::AccountCharsReader,
     account: &str,
+    _context: &RuleContext,
 ) -> Result<Value, ASTError> {
     assert_param_expression!(
         format!("{}.arguments[0]", key),
@@ -394,11 +711,37 @@ fn ends_with(
     }
 }
 
+fn matches_pattern(
+    key: &str,
+    arguments: &[Expression],
+    _account_chars: packed::AccountCharsReader,
+    account: &str,
+    context: &RuleContext,
+) -> Result<Value, ASTError> {
+    let account = get_account_argument(format!("{}.arguments[0]", key), &arguments[0], account, context)?;
+    let account_without_suffix = get_account_without_suffix(account);
+
+    match &arguments[1] {
+        Expression::Value(ValueExpression {
+            value_type: _,
+            value: Value::String(pattern),
+        }) => {
+            let ret = pattern::is_match(&format!("{}.arguments[1]", key), pattern, &account_without_suffix)?;
+            Ok(Value::Bool(ret))
+        }
+        _ => Err(ASTError::ParamTypeError {
+            key: format!("{}.arguments[1]", key),
+            types: String::from("string"),
+        }),
+    }
+}
+
 fn only_include_charset(
     key: &str,
     arguments: &[Expression],
     account_chars: packed::AccountCharsReader,
     _account: &str,
+    _context: &RuleContext,
 ) -> Result<Value, ASTError> {
     assert_param_expression!(
         format!("{}.arguments[0]", key),
@@ -442,6 +785,7 @@ fn include_charset(
     arguments: &[Expression],
     account_chars: packed::AccountCharsReader,
     _account: &str,
+    _context: &RuleContext,
 ) -> Result<Value, ASTError> {
     assert_param_expression!(
         format!("{}.arguments[0]", key),
@@ -485,13 +829,9 @@ fn in_list(
     arguments: &[Expression],
     _account_chars: packed::AccountCharsReader,
     account: &str,
+    context: &RuleContext,
 ) -> Result<Value, ASTError> {
-    assert_param_expression!(
-        format!("{}.arguments[0]", key),
-        arguments[0],
-        Expression::Variable(VariableExpression { name: VarName::Account }),
-        format!("variable AccountChars")
-    );
+    let account = get_account_argument(format!("{}.arguments[0]", key), &arguments[0], account, context)?;
 
     match &arguments[1] {
         Expression::Value(ValueExpression {
@@ -510,6 +850,175 @@ fn in_list(
     }
 }
 
+/// Count the bytes of the account without suffix in UTF-8.
+fn byte_length(
+    key: &str,
+    arguments: &[Expression],
+    _account_chars: packed::AccountCharsReader,
+    account: &str,
+    context: &RuleContext,
+) -> Result<Value, ASTError> {
+    let account = get_account_argument(format!("{}.arguments[0]", key), &arguments[0], account, context)?;
+    let account_without_suffix = get_account_without_suffix(account);
+
+    Ok(Value::Uint32(account_without_suffix.len() as u32))
+}
+
+/// Count the unicode scalar values of the account without suffix, unlike `account_length` an emoji built from
+/// multiple code points is counted more than once.
+fn char_count(
+    key: &str,
+    arguments: &[Expression],
+    _account_chars: packed::AccountCharsReader,
+    account: &str,
+    context: &RuleContext,
+) -> Result<Value, ASTError> {
+    let account = get_account_argument(format!("{}.arguments[0]", key), &arguments[0], account, context)?;
+    let account_without_suffix = get_account_without_suffix(account);
+
+    Ok(Value::Uint32(account_without_suffix.chars().count() as u32))
+}
+
+/// Check if any of the strings appears in the account without suffix at the position, the position is counted in
+/// unicode scalar values from 0, for example `includes_at(account, ["88"], 2)` matches `ab88c`.
+fn includes_at(
+    key: &str,
+    arguments: &[Expression],
+    account_chars: packed::AccountCharsReader,
+    account: &str,
+    context: &RuleContext,
+) -> Result<Value, ASTError> {
+    let target = get_account_argument(format!("{}.arguments[0]", key), &arguments[0], account, context)?;
+    let target_without_suffix = get_account_without_suffix(target);
+
+    let strings = match &arguments[1] {
+        Expression::Value(ValueExpression {
+            value_type: _,
+            value: Value::StringVec(strings),
+        }) => strings,
+        _ => {
+            return Err(ASTError::ParamTypeError {
+                key: format!("{}.arguments[1]", key),
+                types: String::from("string[]"),
+            })
+        }
+    };
+
+    let position = handle_expression(
+        &format!("{}.arguments[2]", key),
+        &arguments[2],
+        account_chars,
+        account,
+        context,
+    )?
+    .get_u64()
+    .map_err(|_| ASTError::ParamTypeError {
+        key: format!("{}.arguments[2]", key),
+        types: String::from("Uint8, Uint32, Uint64"),
+    })?;
+
+    let rest = target_without_suffix
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain([target_without_suffix.len()])
+        .nth(position as usize)
+        .map(|i| &target_without_suffix[i..]);
+    let ret = match rest {
+        Some(rest) => strings.iter().any(|string| rest.starts_with(string.as_str())),
+        None => false,
+    };
+
+    Ok(Value::Bool(ret))
+}
+
+/// Count the account chars which belong to the charset, for example the number of digits in the account.
+fn count_charset(
+    key: &str,
+    arguments: &[Expression],
+    account_chars: packed::AccountCharsReader,
+    _account: &str,
+    _context: &RuleContext,
+) -> Result<Value, ASTError> {
+    assert_param_expression!(
+        format!("{}.arguments[0]", key),
+        arguments[0],
+        Expression::Variable(VariableExpression {
+            name: VarName::AccountChars
+        }),
+        format!("variable AccountChars")
+    );
+
+    let expected_charset = match &arguments[1] {
+        Expression::Value(ValueExpression {
+            value_type: _,
+            value: Value::CharsetType(charset),
+        }) => charset,
+        _ => {
+            return Err(ASTError::ParamTypeError {
+                key: format!("{}.arguments[1]", key),
+                types: String::from("charset_type"),
+            })
+        }
+    };
+
+    let mut count = 0u32;
+    for item in account_chars.iter() {
+        let charset_index = u32::from(item.char_set_name());
+        let charset = CharSetType::try_from(charset_index).map_err(|_| ASTError::UndefinedCharSetType {
+            key: "".to_string(),
+            type_: charset_index,
+        })?;
+
+        if expected_charset == &charset {
+            count += 1;
+        }
+    }
+
+    Ok(Value::Uint32(count))
+}
+
+/// Select a value from the arguments by the length of the account, the last argument is used for all the longer
+/// accounts, for example `select_by_length(account_length, 1-char price, 2-char price, 3+-char price)`.
+fn select_by_length(
+    key: &str,
+    arguments: &[Expression],
+    account_chars: packed::AccountCharsReader,
+    account: &str,
+    context: &RuleContext,
+) -> Result<Value, ASTError> {
+    assert_param_expression!(
+        format!("{}.arguments[0]", key),
+        arguments[0],
+        Expression::Variable(VariableExpression {
+            name: VarName::AccountLength
+        }),
+        format!("variable AccountLength")
+    );
+
+    let length = account_chars.len();
+    let candidates = &arguments[1..];
+    let i = if length == 0 {
+        0
+    } else {
+        core::cmp::min(length, candidates.len()) - 1
+    };
+
+    let value = handle_expression(
+        &format!("{}.arguments[{}]", key, i + 1),
+        &candidates[i],
+        account_chars,
+        account,
+        context,
+    )?;
+    match value {
+        Value::Uint8(_) | Value::Uint32(_) | Value::Uint64(_) => Ok(value),
+        _ => Err(ASTError::ParamTypeError {
+            key: format!("{}.arguments[{}]", key, i + 1),
+            types: String::from("Uint8, Uint32, Uint64"),
+        }),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use das_types::types;
@@ -573,7 +1082,12 @@ mod test {
         let dummy_account_chars = dummy_account_chars_builder.build();
         let dummy_account = "";
 
-        let ret = match_rule_with_account_chars(&rules, dummy_account_chars.as_reader(), dummy_account);
+        let ret = match_rule_with_account_chars(
+            &rules,
+            dummy_account_chars.as_reader(),
+            dummy_account,
+            &RuleContext::default(),
+        );
         println!("return: {:?}", ret);
         if let Err(err) = ret.as_ref() {
             println!("error msg: {:?}\n", err.to_string());
@@ -596,7 +1110,12 @@ mod test {
             }),
         }];
 
-        let ret = match_rule_with_account_chars(&rules, packed::AccountChars::default().as_reader(), "");
+        let ret = match_rule_with_account_chars(
+            &rules,
+            packed::AccountChars::default().as_reader(),
+            "",
+            &RuleContext::default(),
+        );
         assert!(ret.is_err());
         assert!(matches!(ret.unwrap_err(), ASTError::FunctionOrOperatorRequired { .. }));
     }
@@ -646,7 +1165,13 @@ mod test {
             },
         ];
 
-        let ret = match_rule_with_account_chars(&rules, packed::AccountChars::default().as_reader(), "").unwrap();
+        let ret = match_rule_with_account_chars(
+            &rules,
+            packed::AccountChars::default().as_reader(),
+            "",
+            &RuleContext::default(),
+        )
+        .unwrap();
         assert!(ret.is_some());
 
         // rules[0] is disabled, so the matched rule should be rules[1]
@@ -659,7 +1184,14 @@ mod test {
         let account_chars = packed::AccountChars::default();
         let account = "";
 
-        handle_expression(key, &expression, account_chars.as_reader(), account).unwrap()
+        handle_expression(
+            key,
+            &expression,
+            account_chars.as_reader(),
+            account,
+            &RuleContext::default(),
+        )
+        .unwrap()
     }
 
     fn test_err_operator_expression(expression: Expression) -> Result<Value, ASTError> {
@@ -667,7 +1199,13 @@ mod test {
         let account_chars = packed::AccountChars::default();
         let account = "";
 
-        handle_expression(key, &expression, account_chars.as_reader(), account)
+        handle_expression(
+            key,
+            &expression,
+            account_chars.as_reader(),
+            account,
+            &RuleContext::default(),
+        )
     }
 
     #[test]
@@ -1032,7 +1570,13 @@ mod test {
         let key = ".";
         let account_chars: packed::AccountChars = account_chars.into();
 
-        match handle_expression(key, &expression, account_chars.as_reader(), account) {
+        match handle_expression(
+            key,
+            &expression,
+            account_chars.as_reader(),
+            account,
+            &RuleContext::default(),
+        ) {
             Ok(ret) => ret,
             Err(err) => {
                 panic!("handle expression failed: {:?}", err)
@@ -1048,7 +1592,13 @@ mod test {
         let key = ".";
         let account_chars: packed::AccountChars = account_chars.into();
 
-        handle_expression(key, &expression, account_chars.as_reader(), account)
+        handle_expression(
+            key,
+            &expression,
+            account_chars.as_reader(),
+            account,
+            &RuleContext::default(),
+        )
     }
 
     #[test]
@@ -1135,6 +1685,39 @@ mod test {
         assert!(matches!(ret, Value::Bool(true)));
     }
 
+    #[test]
+    fn test_function_matches_pattern() {
+        fn inner(pattern: &str, account: &str) -> Result<Value, ASTError> {
+            test_err_function_expression(
+                Expression::Function(FunctionExpression {
+                    name: FnName::MatchesPattern,
+                    arguments: vec![
+                        Expression::Variable(VariableExpression { name: VarName::Account }),
+                        Expression::Value(ValueExpression {
+                            value_type: ValueType::String,
+                            value: Value::String(pattern.to_string()),
+                        }),
+                    ],
+                }),
+                vec![],
+                account,
+            )
+        }
+
+        let ret = inner("^(.)\\1\\1$", "888.ast.bit");
+        assert!(matches!(ret, Ok(Value::Bool(true))));
+
+        // The suffix should be ignored.
+        let ret = inner("^(.)\\1\\1$", "88.ast.bit");
+        assert!(matches!(ret, Ok(Value::Bool(false))));
+
+        let ret = inner("^\\d{2}[a-z]+$", "22abc.ast.bit");
+        assert!(matches!(ret, Ok(Value::Bool(true))));
+
+        let ret = inner("^(ab", "22abc.ast.bit");
+        assert!(matches!(ret, Err(ASTError::PatternInvalid { key: _, reason: _ })));
+    }
+
     #[test]
     fn test_function_include_chars_param_error() {
         let ret = test_err_function_expression(
@@ -1601,4 +2184,521 @@ mod test {
             })
         ));
     }
+
+    fn uint_value(value: Value) -> Expression {
+        Expression::Value(ValueExpression {
+            value_type: value.get_type(),
+            value,
+        })
+    }
+
+    #[test]
+    fn test_operator_calculate() {
+        fn inner(symbol: SymbolType, values: Vec<Value>) -> Value {
+            test_operator_expression(Expression::Operator(OperatorExpression {
+                symbol,
+                expressions: values.into_iter().map(uint_value).collect(),
+            }))
+        }
+
+        let ret = inner(SymbolType::Add, vec![Value::Uint8(1), Value::Uint8(2), Value::Uint8(3)]);
+        assert!(matches!(ret, Value::Uint8(6)));
+
+        // The type of the result should be the wider one.
+        let ret = inner(SymbolType::Add, vec![Value::Uint8(1), Value::Uint64(2)]);
+        assert!(matches!(ret, Value::Uint64(3)));
+
+        let ret = inner(SymbolType::Sub, vec![Value::Uint32(10), Value::Uint8(3)]);
+        assert!(matches!(ret, Value::Uint32(7)));
+
+        let ret = inner(SymbolType::Mul, vec![Value::Uint64(5_000_000), Value::Uint32(3)]);
+        assert!(matches!(ret, Value::Uint64(15_000_000)));
+
+        let ret = inner(SymbolType::Div, vec![Value::Uint32(10), Value::Uint32(3)]);
+        assert!(matches!(ret, Value::Uint32(3)));
+
+        let ret = inner(SymbolType::Mod, vec![Value::Uint32(10), Value::Uint32(3)]);
+        assert!(matches!(ret, Value::Uint32(1)));
+
+        let ret = inner(
+            SymbolType::Min,
+            vec![Value::Uint32(10), Value::Uint32(3), Value::Uint32(5)],
+        );
+        assert!(matches!(ret, Value::Uint32(3)));
+
+        let ret = inner(
+            SymbolType::Max,
+            vec![Value::Uint32(10), Value::Uint32(3), Value::Uint32(5)],
+        );
+        assert!(matches!(ret, Value::Uint32(10)));
+
+        // The result of calculation can be used in comparison.
+        let ret = test_operator_expression(Expression::Operator(OperatorExpression {
+            symbol: SymbolType::Gt,
+            expressions: vec![
+                Expression::Operator(OperatorExpression {
+                    symbol: SymbolType::Mul,
+                    expressions: vec![uint_value(Value::Uint32(2)), uint_value(Value::Uint32(3))],
+                }),
+                uint_value(Value::Uint32(5)),
+            ],
+        }));
+        assert!(matches!(ret, Value::Bool(true)));
+    }
+
+    #[test]
+    fn test_operator_calculate_error() {
+        fn inner(symbol: SymbolType, values: Vec<Value>) -> Result<Value, ASTError> {
+            test_err_operator_expression(Expression::Operator(OperatorExpression {
+                symbol,
+                expressions: values.into_iter().map(uint_value).collect(),
+            }))
+        }
+
+        let ret = inner(SymbolType::Add, vec![Value::Uint8(u8::MAX), Value::Uint8(1)]);
+        assert!(matches!(ret, Err(ASTError::CalculationFailed { key: _, reason: _ })));
+
+        let ret = inner(SymbolType::Sub, vec![Value::Uint64(0), Value::Uint64(1)]);
+        assert!(matches!(ret, Err(ASTError::CalculationFailed { key: _, reason: _ })));
+
+        let ret = inner(SymbolType::Div, vec![Value::Uint64(1), Value::Uint64(0)]);
+        assert!(matches!(ret, Err(ASTError::CalculationFailed { key: _, reason: _ })));
+
+        let ret = inner(SymbolType::Mod, vec![Value::Uint64(1), Value::Uint64(0)]);
+        assert!(matches!(ret, Err(ASTError::CalculationFailed { key: _, reason: _ })));
+
+        let ret = inner(
+            SymbolType::Sub,
+            vec![Value::Uint64(3), Value::Uint64(2), Value::Uint64(1)],
+        );
+        assert!(matches!(
+            ret,
+            Err(ASTError::ParamLengthError {
+                key: _,
+                expected_length: _,
+                length: _
+            })
+        ));
+
+        let ret = inner(SymbolType::Add, vec![Value::Uint64(1), Value::Bool(true)]);
+        assert!(matches!(ret, Err(ASTError::ParamTypeError { key: _, types: _ })));
+    }
+
+    #[test]
+    fn test_function_select_by_length() {
+        fn inner(length: usize) -> Value {
+            let account_chars = (0..length)
+                .map(|_| types::AccountChar {
+                    char_set_type: CharSetType::Digit,
+                    char: String::from("1"),
+                })
+                .collect();
+
+            test_function_expression(
+                Expression::Function(FunctionExpression {
+                    name: FnName::SelectByLength,
+                    arguments: vec![
+                        Expression::Variable(VariableExpression {
+                            name: VarName::AccountLength,
+                        }),
+                        uint_value(Value::Uint64(300)),
+                        uint_value(Value::Uint64(200)),
+                        uint_value(Value::Uint64(100)),
+                    ],
+                }),
+                account_chars,
+                "",
+            )
+        }
+
+        assert!(matches!(inner(1), Value::Uint64(300)));
+        assert!(matches!(inner(2), Value::Uint64(200)));
+        assert!(matches!(inner(3), Value::Uint64(100)));
+        assert!(matches!(inner(10), Value::Uint64(100)));
+    }
+
+    #[test]
+    fn test_function_select_by_length_param_error() {
+        let ret = test_err_function_expression(
+            Expression::Function(FunctionExpression {
+                name: FnName::SelectByLength,
+                arguments: vec![
+                    Expression::Variable(VariableExpression { name: VarName::Account }),
+                    uint_value(Value::Uint64(300)),
+                ],
+            }),
+            vec![],
+            "",
+        );
+        assert!(matches!(ret, Err(ASTError::ParamTypeError { key: _, types: _ })));
+
+        let ret = test_err_function_expression(
+            Expression::Function(FunctionExpression {
+                name: FnName::SelectByLength,
+                arguments: vec![
+                    Expression::Variable(VariableExpression {
+                        name: VarName::AccountLength,
+                    }),
+                    Expression::Value(ValueExpression {
+                        value_type: ValueType::Bool,
+                        value: Value::Bool(true),
+                    }),
+                ],
+            }),
+            vec![],
+            "",
+        );
+        assert!(matches!(ret, Err(ASTError::ParamTypeError { key: _, types: _ })));
+    }
+
+    #[test]
+    fn test_match_price_rule() {
+        let rules_json = json!([
+            {
+                "index": 0,
+                "name": "Fixed price of 1 character DID",
+                "note": "",
+                "price": 100_000_000,
+                "status": 1,
+                "ast": {
+                    "type": "operator",
+                    "symbol": "==",
+                    "expressions": [
+                        {
+                            "type": "variable",
+                            "name": "account_length",
+                        },
+                        {
+                            "type": "value",
+                            "value_type": "uint32",
+                            "value": 1,
+                        },
+                    ],
+                }
+            },
+            {
+                "index": 1,
+                "name": "Base price × length multiplier",
+                "note": "",
+                "price": 0,
+                "status": 1,
+                "ast": {
+                    "type": "operator",
+                    "symbol": "*",
+                    "expressions": [
+                        {
+                            "type": "value",
+                            "value_type": "uint64",
+                            "value": 1_000_000,
+                        },
+                        {
+                            "type": "function",
+                            "name": "select_by_length",
+                            "arguments": [
+                                {
+                                    "type": "variable",
+                                    "name": "account_length",
+                                },
+                                {
+                                    "type": "value",
+                                    "value_type": "uint8",
+                                    "value": 100,
+                                },
+                                {
+                                    "type": "value",
+                                    "value_type": "uint8",
+                                    "value": 10,
+                                },
+                                {
+                                    "type": "value",
+                                    "value_type": "uint8",
+                                    "value": 1,
+                                },
+                            ],
+                        },
+                    ],
+                }
+            }
+        ]);
+        let rules = util::json_to_sub_account_rules(String::new(), &rules_json).unwrap();
+
+        fn gen_account_chars(length: usize) -> packed::AccountChars {
+            let account_chars: types::AccountChars = (0..length)
+                .map(|_| types::AccountChar {
+                    char_set_type: CharSetType::Digit,
+                    char: String::from("1"),
+                })
+                .collect();
+            account_chars.into()
+        }
+
+        let (rule, price) =
+            match_price_rule_with_account_chars(&rules, gen_account_chars(1).as_reader(), "", &RuleContext::default())
+                .unwrap()
+                .unwrap();
+        assert_eq!(0, rule.index);
+        assert_eq!(100_000_000, price);
+
+        let (rule, price) =
+            match_price_rule_with_account_chars(&rules, gen_account_chars(2).as_reader(), "", &RuleContext::default())
+                .unwrap()
+                .unwrap();
+        assert_eq!(1, rule.index);
+        assert_eq!(10_000_000, price);
+
+        let (rule, price) =
+            match_price_rule_with_account_chars(&rules, gen_account_chars(5).as_reader(), "", &RuleContext::default())
+                .unwrap()
+                .unwrap();
+        assert_eq!(1, rule.index);
+        assert_eq!(1_000_000, price);
+    }
+
+    #[test]
+    fn test_trace_rules() {
+        let gen_rule = |index: u32, status: SubAccountRuleStatus, text: &str| SubAccountRule {
+            index,
+            name: String::new(),
+            note: String::new(),
+            price: 100,
+            status,
+            ast: crate::dsl::parse(text).unwrap(),
+        };
+        let rules = vec![
+            gen_rule(
+                0,
+                SubAccountRuleStatus::On,
+                "account_length > 3 and starts_with(account, [\"a\"])",
+            ),
+            gen_rule(1, SubAccountRuleStatus::Off, "account_length == 2"),
+            gen_rule(2, SubAccountRuleStatus::On, "10u64 * account_length"),
+            gen_rule(3, SubAccountRuleStatus::On, "account_length == 2"),
+        ];
+        let account_chars: types::AccountChars = "ab"
+            .chars()
+            .map(|char| types::AccountChar {
+                char_set_type: CharSetType::En,
+                char: char.to_string(),
+            })
+            .collect();
+        let account_chars: packed::AccountChars = account_chars.into();
+
+        let trace = trace_rules_with_account_chars(
+            &rules,
+            account_chars.as_reader(),
+            "ab.bit",
+            &RuleContext::default(),
+            true,
+        );
+        assert!(matches!(trace.result, Ok(Some((2, 20)))));
+        assert_eq!(
+            3,
+            trace.rules.len(),
+            "The rules after the matched one should not be evaluated."
+        );
+
+        let rule = &trace.rules[0];
+        assert_eq!(None, rule.price);
+        assert_eq!(Value::Bool(false), *rule.values["rules[0].ast"].as_ref().unwrap());
+        assert_eq!(
+            Value::Bool(false),
+            *rule.values["rules[0].ast.expressions[0]"].as_ref().unwrap()
+        );
+        assert_eq!(
+            Value::Uint32(2),
+            *rule.values["rules[0].ast.expressions[0].expressions[0]"]
+                .as_ref()
+                .unwrap()
+        );
+        assert_eq!(
+            Value::Bool(true),
+            *rule.values["rules[0].ast.expressions[1]"].as_ref().unwrap()
+        );
+        assert_eq!(
+            Value::String(String::from("ab.bit")),
+            *rule.values["rules[0].ast.expressions[1].arguments[0]"]
+                .as_ref()
+                .unwrap()
+        );
+        assert_eq!(7, rule.values.len());
+
+        assert!(trace.rules[1].skipped);
+        assert!(trace.rules[1].values.is_empty());
+
+        assert_eq!(Some(20), trace.rules[2].price);
+
+        // The trace of a bool rule fails with the same error as matching.
+        let trace = trace_rules_with_account_chars(
+            &rules,
+            account_chars.as_reader(),
+            "ab.bit",
+            &RuleContext::default(),
+            false,
+        );
+        assert!(matches!(trace.result, Err(ASTError::ReturnTypeError { ref key, .. }) if key == "rules[2]"));
+        assert!(
+            match_rule_with_account_chars(&rules, account_chars.as_reader(), "ab.bit", &RuleContext::default())
+                .is_err()
+        );
+        assert_eq!(
+            Value::Uint64(20),
+            *trace.rules[2].values["rules[2].ast"].as_ref().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_context_variables() {
+        let account_chars: packed::AccountChars = types::AccountChars::default().into();
+        let context = RuleContext {
+            timestamp: 1_700_000_000,
+            years: 3,
+            parent_account: "parent.bit",
+            minted_count: 10,
+        };
+        let eval = |text: &str| {
+            handle_expression(
+                "",
+                &crate::dsl::parse(text).unwrap(),
+                account_chars.as_reader(),
+                "sub.parent.bit",
+                &context,
+            )
+        };
+
+        assert_eq!(Value::Uint64(1_700_000_000), eval("timestamp").unwrap());
+        assert_eq!(Value::Uint32(3), eval("years").unwrap());
+        assert_eq!(
+            Value::String(String::from("parent.bit")),
+            eval("parent_account").unwrap()
+        );
+        assert_eq!(Value::Uint32(10), eval("minted_count").unwrap());
+
+        // A time-limited promotion with a multi-year discount.
+        assert_eq!(
+            Value::Uint64(80),
+            eval("select_by_length(account_length, 100u64) * (10 - min(years, 2)) / 10").unwrap()
+        );
+        assert_eq!(
+            Value::Bool(true),
+            eval("timestamp < 1_800_000_000u64 and years >= 3 and minted_count >= 10").unwrap()
+        );
+        assert_eq!(
+            Value::Bool(true),
+            eval(r#"matches_pattern(parent_account, "^par") and not matches_pattern(account, "^par")"#).unwrap()
+        );
+        assert!(matches!(
+            eval(r#"matches_pattern(account_chars, "^par")"#),
+            Err(ASTError::ParamTypeError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_string_and_length_functions() {
+        let account_chars: packed::AccountChars = vec![
+            types::AccountChar {
+                char_set_type: CharSetType::ZhHans,
+                char: String::from("中"),
+            },
+            types::AccountChar {
+                char_set_type: CharSetType::Digit,
+                char: String::from("8"),
+            },
+            types::AccountChar {
+                char_set_type: CharSetType::Digit,
+                char: String::from("8"),
+            },
+            types::AccountChar {
+                char_set_type: CharSetType::Emoji,
+                char: String::from("👨‍👩‍👧"),
+            },
+        ]
+        .into();
+        let context = RuleContext {
+            parent_account: "parent.bit",
+            ..Default::default()
+        };
+        let eval = |text: &str| {
+            handle_expression(
+                "",
+                &crate::dsl::parse(text).unwrap(),
+                account_chars.as_reader(),
+                "中88👨‍👩‍👧.parent.bit",
+                &context,
+            )
+        };
+
+        assert_eq!(Value::Uint32(23), eval("byte_length(account)").unwrap());
+        assert_eq!(Value::Uint32(6), eval("byte_length(parent_account)").unwrap());
+        assert_eq!(Value::Uint32(8), eval("char_count(account)").unwrap());
+        assert_eq!(Value::Uint32(2), eval("count_charset(account_chars, digit)").unwrap());
+        assert_eq!(Value::Uint32(0), eval("count_charset(account_chars, en)").unwrap());
+
+        assert_eq!(Value::Bool(true), eval(r#"includes_at(account, ["88"], 1)"#).unwrap());
+        assert_eq!(Value::Bool(false), eval(r#"includes_at(account, ["88"], 0)"#).unwrap());
+        assert_eq!(
+            Value::Bool(true),
+            eval(r#"includes_at(account, ["x", "8👨"], account_length - 2)"#).unwrap()
+        );
+        assert_eq!(Value::Bool(false), eval(r#"includes_at(account, ["8"], 100)"#).unwrap());
+
+        // Price a mixed-script name by the number of digits in it.
+        assert_eq!(
+            Value::Bool(true),
+            eval("only_include_charset(account_chars, digit) or count_charset(account_chars, digit) * 2 >= account_length").unwrap()
+        );
+
+        assert_eq!(Value::Bool(true), eval(r#"parent_account < "parent.bitx""#).unwrap());
+        assert_eq!(Value::Bool(true), eval(r#"parent_account == "parent.bit""#).unwrap());
+        assert_eq!(Value::Bool(false), eval(r#"parent_account >= "q""#).unwrap());
+        assert!(matches!(
+            eval(r#"parent_account > 1"#),
+            Err(ASTError::ParamTypeError { .. })
+        ));
+        assert!(matches!(
+            eval(r#"char_count(account_chars)"#),
+            Err(ASTError::ParamTypeError { .. })
+        ));
+        assert!(matches!(
+            eval(r#"includes_at(account, ["8"], "1")"#),
+            Err(ASTError::ParamTypeError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_function_includes_at_with_parent_account() {
+        let account_chars: packed::AccountChars = vec![
+            types::AccountChar {
+                char_set_type: CharSetType::En,
+                char: String::from("a"),
+            },
+            types::AccountChar {
+                char_set_type: CharSetType::En,
+                char: String::from("b"),
+            },
+        ]
+        .into();
+        let context = RuleContext {
+            parent_account: "parent.bit",
+            ..Default::default()
+        };
+        let eval = |text: &str| {
+            handle_expression(
+                "",
+                &crate::dsl::parse(text).unwrap(),
+                account_chars.as_reader(),
+                "ab.parent.bit",
+                &context,
+            )
+        };
+
+        // The position is always evaluated against the account itself, not the string being searched.
+        assert_eq!(
+            Value::Bool(false),
+            eval(r#"includes_at(parent_account, ["t"], char_count(account) - 1)"#).unwrap()
+        );
+        assert_eq!(
+            Value::Bool(true),
+            eval(r#"includes_at(parent_account, ["t"], char_count(parent_account) - 1)"#).unwrap()
+        );
+    }
 }
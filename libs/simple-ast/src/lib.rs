@@ -1,10 +1,20 @@
-#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "no_std", no_std)]
 
-#[cfg(not(feature = "std"))]
+#[cfg(feature = "no_std")]
 extern crate alloc;
 
+#[cfg(not(feature = "no_std"))]
+pub mod analyzer;
+#[cfg(not(feature = "no_std"))]
+pub mod batch;
+pub mod bytecode;
+#[cfg(not(feature = "no_std"))]
+pub mod diff;
+#[cfg(not(feature = "no_std"))]
+pub mod dsl;
 pub mod error;
 pub mod executor;
+pub mod pattern;
 #[cfg(feature = "size_util")]
 pub mod size_util;
 pub mod types;
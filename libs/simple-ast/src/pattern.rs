@@ -0,0 +1,515 @@
+//! A bounded regular-expression-lite matcher which is safe to run in ckb-vm.
+//!
+//! Supported syntax:
+//! - `^` and `$` anchors at the start and the end of the pattern;
+//! - `.` for any character, literal characters and `\` escaped characters;
+//! - character classes like `[abc]`, `[a-z0-9]` and negated `[^0-9]`, `\d` for digits and `\w` for ascii letters or
+//!   digits;
+//! - groups like `(ab)`, and backreferences from `\1` to `\9` which refer to the text captured by the groups;
+//! - quantifiers `*`, `+`, `?`, `{n}`, `{n,}` and `{n,m}`, they are all greedy.
+//!
+//! For example, `^(.)\1\1$` matches "AAA"-style accounts, `^\d{2}[a-z]+$` matches accounts starting with two digits
+//! followed by letters, and `^(.)(.)\2\1$` matches 4-character palindromes.
+//!
+//! All the matching is based on unicode characters instead of bytes, and the length of the pattern, the count of
+//! repetitions and the steps of backtracking are limited, so that a malicious pattern can not exhaust the cycles.
+
+#[cfg(feature = "no_std")]
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+use crate::error::ASTError;
+
+pub const MAX_PATTERN_LENGTH: usize = 128;
+pub const MAX_REPETITION: u32 = 64;
+pub const MAX_GROUPS: usize = 9;
+pub const MAX_STEPS: usize = 100_000;
+/// The matcher is recursive, every atom matched on the current path takes a few stack frames, so the depth is limited
+/// separately from the steps to keep the stack from overflowing.
+pub const MAX_DEPTH: usize = 512;
+
+#[derive(Debug, Clone, PartialEq)]
+enum ClassItem {
+    Char(char),
+    Range(char, char),
+    Digit,
+    Word,
+}
+
+impl ClassItem {
+    fn contains(&self, c: char) -> bool {
+        match self {
+            ClassItem::Char(expected) => *expected == c,
+            ClassItem::Range(start, end) => *start <= c && c <= *end,
+            ClassItem::Digit => c.is_ascii_digit(),
+            ClassItem::Word => c.is_ascii_alphanumeric(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Atom {
+    Any,
+    Char(char),
+    Class { negated: bool, items: Vec<ClassItem> },
+    Group { index: usize, pieces: Vec<Piece> },
+    BackRef(usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Piece {
+    atom: Atom,
+    min: u32,
+    max: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pattern {
+    pieces: Vec<Piece>,
+    anchored_start: bool,
+    anchored_end: bool,
+    groups: usize,
+}
+
+struct Parser<'a> {
+    key: &'a str,
+    chars: Vec<char>,
+    pos: usize,
+    groups: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, reason: &str) -> ASTError {
+        ASTError::PatternInvalid {
+            key: self.key.to_string(),
+            reason: format!("{} at position {}", reason, self.pos),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek();
+        if c.is_some() {
+            self.pos += 1;
+        }
+        c
+    }
+
+    fn parse_sequence(&mut self, in_group: bool) -> Result<Vec<Piece>, ASTError> {
+        let mut pieces = Vec::new();
+        while let Some(c) = self.peek() {
+            match c {
+                ')' if in_group => break,
+                ')' => return Err(self.error("unmatched `)`")),
+                '$' if self.pos == self.chars.len() - 1 && !in_group => break,
+                _ => {}
+            }
+
+            let atom = self.parse_atom()?;
+            let (min, max) = self.parse_quantifier()?;
+            pieces.push(Piece { atom, min, max });
+        }
+
+        Ok(pieces)
+    }
+
+    fn parse_atom(&mut self) -> Result<Atom, ASTError> {
+        let c = self.next().ok_or_else(|| self.error("unexpected end"))?;
+        match c {
+            '.' => Ok(Atom::Any),
+            '[' => self.parse_class(),
+            '(' => {
+                if self.groups >= MAX_GROUPS {
+                    return Err(self.error("too many groups"));
+                }
+                self.groups += 1;
+                let index = self.groups;
+                let pieces = self.parse_sequence(true)?;
+                if self.next() != Some(')') {
+                    return Err(self.error("unclosed `(`"));
+                }
+                Ok(Atom::Group { index, pieces })
+            }
+            '\\' => {
+                let escaped = self.next().ok_or_else(|| self.error("unexpected end after `\\`"))?;
+                match escaped {
+                    'd' => Ok(Atom::Class {
+                        negated: false,
+                        items: vec![ClassItem::Digit],
+                    }),
+                    'w' => Ok(Atom::Class {
+                        negated: false,
+                        items: vec![ClassItem::Word],
+                    }),
+                    '1'..='9' => {
+                        let index = escaped as usize - '0' as usize;
+                        if index > self.groups {
+                            return Err(self.error("backreference to an undefined group"));
+                        }
+                        Ok(Atom::BackRef(index))
+                    }
+                    _ => Ok(Atom::Char(escaped)),
+                }
+            }
+            '*' | '+' | '?' | '{' => Err(self.error("quantifier without a target")),
+            '^' => Err(self.error("`^` is only allowed at the start")),
+            '$' => Err(self.error("`$` is only allowed at the end")),
+            _ => Ok(Atom::Char(c)),
+        }
+    }
+
+    fn parse_class(&mut self) -> Result<Atom, ASTError> {
+        let mut negated = false;
+        if self.peek() == Some('^') {
+            negated = true;
+            self.pos += 1;
+        }
+
+        let mut items = Vec::new();
+        loop {
+            let c = self.next().ok_or_else(|| self.error("unclosed `[`"))?;
+            let start = match c {
+                ']' if !items.is_empty() => break,
+                '\\' => {
+                    let escaped = self.next().ok_or_else(|| self.error("unexpected end after `\\`"))?;
+                    match escaped {
+                        'd' => {
+                            items.push(ClassItem::Digit);
+                            continue;
+                        }
+                        'w' => {
+                            items.push(ClassItem::Word);
+                            continue;
+                        }
+                        _ => escaped,
+                    }
+                }
+                _ => c,
+            };
+
+            if self.peek() == Some('-') && self.chars.get(self.pos + 1).is_some_and(|c| *c != ']') {
+                self.pos += 1;
+                let end = self.next().ok_or_else(|| self.error("unclosed `[`"))?;
+                if end < start {
+                    return Err(self.error("invalid range in `[]`"));
+                }
+                items.push(ClassItem::Range(start, end));
+            } else {
+                items.push(ClassItem::Char(start));
+            }
+        }
+
+        Ok(Atom::Class { negated, items })
+    }
+
+    fn parse_number(&mut self) -> Result<Option<u32>, ASTError> {
+        let mut ret: Option<u32> = None;
+        while let Some(c) = self.peek() {
+            match c.to_digit(10) {
+                Some(digit) => {
+                    let val = ret.unwrap_or(0) * 10 + digit;
+                    if val > MAX_REPETITION {
+                        return Err(self.error("the count of repetitions is too large"));
+                    }
+                    ret = Some(val);
+                    self.pos += 1;
+                }
+                None => break,
+            }
+        }
+
+        Ok(ret)
+    }
+
+    fn parse_quantifier(&mut self) -> Result<(u32, u32), ASTError> {
+        let ret = match self.peek() {
+            Some('*') => (0, MAX_REPETITION),
+            Some('+') => (1, MAX_REPETITION),
+            Some('?') => (0, 1),
+            Some('{') => {
+                self.pos += 1;
+                let min = self
+                    .parse_number()?
+                    .ok_or_else(|| self.error("number required in `{}`"))?;
+                let max = if self.peek() == Some(',') {
+                    self.pos += 1;
+                    self.parse_number()?.unwrap_or(MAX_REPETITION)
+                } else {
+                    min
+                };
+                if self.peek() != Some('}') {
+                    return Err(self.error("unclosed `{`"));
+                }
+                if max < min {
+                    return Err(self.error("invalid range in `{}`"));
+                }
+                (min, max)
+            }
+            _ => return Ok((1, 1)),
+        };
+        self.pos += 1;
+
+        if matches!(self.peek(), Some('*') | Some('+') | Some('?') | Some('{')) {
+            return Err(self.error("nested quantifiers are not supported"));
+        }
+
+        Ok(ret)
+    }
+}
+
+impl Pattern {
+    pub fn parse(key: &str, pattern: &str) -> Result<Self, ASTError> {
+        let mut chars: Vec<char> = pattern.chars().collect();
+        if chars.len() > MAX_PATTERN_LENGTH {
+            return Err(ASTError::PatternInvalid {
+                key: key.to_string(),
+                reason: format!("the pattern should be no more than {} characters", MAX_PATTERN_LENGTH),
+            });
+        }
+
+        let anchored_start = chars.first() == Some(&'^');
+        if anchored_start {
+            chars.remove(0);
+        }
+
+        let mut parser = Parser {
+            key,
+            chars,
+            pos: 0,
+            groups: 0,
+        };
+        let pieces = parser.parse_sequence(false)?;
+        let anchored_end = match parser.next() {
+            Some('$') => true,
+            None => false,
+            Some(_) => return Err(parser.error("unexpected character")),
+        };
+
+        Ok(Pattern {
+            pieces,
+            anchored_start,
+            anchored_end,
+            groups: parser.groups,
+        })
+    }
+
+    pub fn is_match(&self, key: &str, text: &str) -> Result<bool, ASTError> {
+        let mut matcher = Matcher {
+            text: text.chars().collect(),
+            captures: vec![None; self.groups + 1],
+            steps: 0,
+            depth: 0,
+        };
+        let anchored_end = self.anchored_end;
+        let last_start = if self.anchored_start { 0 } else { matcher.text.len() };
+
+        for start in 0..=last_start {
+            let len = matcher.text.len();
+            if matcher.match_sequence(&self.pieces, start, &mut |_, pos| !anchored_end || pos == len) {
+                return Ok(true);
+            }
+            if matcher.steps > MAX_STEPS {
+                return Err(ASTError::PatternTooComplex { key: key.to_string() });
+            }
+        }
+
+        Ok(false)
+    }
+}
+
+type Continuation<'c> = dyn FnMut(&mut Matcher, usize) -> bool + 'c;
+
+struct Matcher {
+    text: Vec<char>,
+    captures: Vec<Option<(usize, usize)>>,
+    steps: usize,
+    depth: usize,
+}
+
+impl Matcher {
+    fn match_sequence(&mut self, pieces: &[Piece], pos: usize, cont: &mut Continuation) -> bool {
+        match pieces.split_first() {
+            None => cont(self, pos),
+            Some((piece, rest)) => self.match_repeat(piece, rest, 0, pos, cont),
+        }
+    }
+
+    fn match_repeat(&mut self, piece: &Piece, rest: &[Piece], count: u32, pos: usize, cont: &mut Continuation) -> bool {
+        self.steps += 1;
+        if self.depth >= MAX_DEPTH {
+            // Too deep is treated as too many steps, so the whole matching is aborted.
+            self.steps = MAX_STEPS + 1;
+        }
+        if self.steps > MAX_STEPS {
+            return false;
+        }
+
+        self.depth += 1;
+        let ret = self.match_repeat_once(piece, rest, count, pos, cont);
+        self.depth -= 1;
+
+        ret
+    }
+
+    fn match_repeat_once(
+        &mut self,
+        piece: &Piece,
+        rest: &[Piece],
+        count: u32,
+        pos: usize,
+        cont: &mut Continuation,
+    ) -> bool {
+        if count < piece.max {
+            let matched = self.match_atom(&piece.atom, pos, &mut |matcher, next_pos| {
+                // Stop repeating an atom which matches nothing, otherwise it will loop forever.
+                if next_pos == pos && count >= piece.min {
+                    return false;
+                }
+                matcher.match_repeat(piece, rest, count + 1, next_pos, cont)
+            });
+            if matched {
+                return true;
+            }
+        }
+
+        count >= piece.min && self.match_sequence(rest, pos, cont)
+    }
+
+    fn match_atom(&mut self, atom: &Atom, pos: usize, cont: &mut Continuation) -> bool {
+        match atom {
+            Atom::Any => pos < self.text.len() && cont(self, pos + 1),
+            Atom::Char(expected) => self.text.get(pos) == Some(expected) && cont(self, pos + 1),
+            Atom::Class { negated, items } => match self.text.get(pos) {
+                Some(c) => items.iter().any(|item| item.contains(*c)) != *negated && cont(self, pos + 1),
+                None => false,
+            },
+            Atom::Group { index, pieces } => {
+                let index = *index;
+                let start = pos;
+                self.match_sequence(pieces, pos, &mut |matcher, end| {
+                    let saved = matcher.captures[index];
+                    matcher.captures[index] = Some((start, end));
+                    if cont(matcher, end) {
+                        true
+                    } else {
+                        matcher.captures[index] = saved;
+                        false
+                    }
+                })
+            }
+            Atom::BackRef(index) => match self.captures[*index] {
+                Some((start, end)) => {
+                    let len = end - start;
+                    pos + len <= self.text.len()
+                        && self.text[start..end] == self.text[pos..pos + len]
+                        && cont(self, pos + len)
+                }
+                None => false,
+            },
+        }
+    }
+}
+
+pub fn is_match(key: &str, pattern: &str, text: &str) -> Result<bool, ASTError> {
+    Pattern::parse(key, pattern)?.is_match(key, text)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_match(pattern: &str, text: &str, expected: bool) {
+        let ret = is_match(".", pattern, text).unwrap();
+        assert_eq!(
+            expected,
+            ret,
+            "pattern {} should {}match {}",
+            pattern,
+            if expected { "" } else { "not " },
+            text
+        );
+    }
+
+    #[test]
+    fn test_pattern_literal_and_anchors() {
+        assert_match("abc", "xxabcxx", true);
+        assert_match("^abc", "xxabc", false);
+        assert_match("^abc", "abcxx", true);
+        assert_match("abc$", "abcxx", false);
+        assert_match("abc$", "xxabc", true);
+        assert_match("^abc$", "abc", true);
+        assert_match("^$", "", true);
+        assert_match("a\\.b", "a.b", true);
+        assert_match("a\\.b", "axb", false);
+        assert_match("🌈.✨", "🌈x✨", true);
+    }
+
+    #[test]
+    fn test_pattern_class() {
+        assert_match("^\\d{2}[a-z]+$", "88abc", true);
+        assert_match("^\\d{2}[a-z]+$", "8abc", false);
+        assert_match("^\\d{2}[a-z]+$", "88ab1", false);
+        assert_match("^[^0-9]+$", "abc", true);
+        assert_match("^[^0-9]+$", "ab1", false);
+        assert_match("^[\\w-]+$", "a-1", true);
+        assert_match("^[a-]+$", "a-a", true);
+    }
+
+    #[test]
+    fn test_pattern_quantifier() {
+        assert_match("^a*$", "", true);
+        assert_match("^a+$", "", false);
+        assert_match("^ab?c$", "ac", true);
+        assert_match("^ab?c$", "abc", true);
+        assert_match("^a{2,3}$", "a", false);
+        assert_match("^a{2,3}$", "aaa", true);
+        assert_match("^a{2,3}$", "aaaa", false);
+        assert_match("^a{2,}$", "aaaaaa", true);
+        assert_match("^(ab)+$", "ababab", true);
+        assert_match("^(ab)+$", "ababa", false);
+    }
+
+    #[test]
+    fn test_pattern_repeated_chars() {
+        assert_match("^(.)\\1\\1$", "888", true);
+        assert_match("^(.)\\1\\1$", "AAA", true);
+        assert_match("^(.)\\1\\1$", "889", false);
+        assert_match("(.)\\1{2}", "x666y", true);
+        assert_match("^(.)(.)\\2\\1$", "abba", true);
+        assert_match("^(.)(.)\\2\\1$", "abab", false);
+        assert_match("^(.)(.).\\2\\1$", "12321", true);
+    }
+
+    #[test]
+    fn test_pattern_invalid() {
+        for pattern in [
+            "(ab", "ab)", "[ab", "a{2", "a{3,2}", "*a", "a**", "\\1(a)", "a^", "a$b", "[z-a]",
+        ] {
+            let ret = Pattern::parse(".", pattern);
+            assert!(
+                matches!(ret, Err(ASTError::PatternInvalid { .. })),
+                "pattern {} should be invalid",
+                pattern
+            );
+        }
+
+        let ret = Pattern::parse(".", &"a".repeat(MAX_PATTERN_LENGTH + 1));
+        assert!(matches!(ret, Err(ASTError::PatternInvalid { .. })));
+        let ret = Pattern::parse(".", "a{65}");
+        assert!(matches!(ret, Err(ASTError::PatternInvalid { .. })));
+    }
+
+    #[test]
+    fn test_pattern_too_complex() {
+        let ret = is_match(".", "^(a*)*(a*)*(a*)*(a*)*b$", &"a".repeat(40));
+        assert!(matches!(ret, Err(ASTError::PatternTooComplex { .. })));
+
+        // The nested empty groups must be repeated 64 * 64 * 64 times, it used to overflow the stack.
+        let ret = is_match(".", "^((((()){64}){64}){64})$", "");
+        assert!(matches!(ret, Err(ASTError::PatternTooComplex { .. })));
+        let ret = is_match(".", "^(((.?){64}){64})$", &"a".repeat(64));
+        assert!(matches!(ret, Err(ASTError::PatternTooComplex { .. })));
+    }
+}
@@ -1,3 +1,8 @@
+#[cfg(feature = "no_std")]
+use alloc::format;
+#[cfg(feature = "no_std")]
+use alloc::string::String;
+
 use log::debug;
 
 use crate::types::*;
@@ -3,18 +3,18 @@ use alloc::string::String;
 
 #[cfg(feature = "no_std")]
 use das_types::{constants::*, packed, prelude::*};
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 use das_types::{constants::*, packed, prelude::*};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 use serde::ser::{SerializeSeq, SerializeStruct};
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 use serde::{Deserialize, Serialize, Serializer};
 use strum::{Display, EnumString};
 
 use crate::error::ASTError;
 
-#[cfg_attr(feature = "std", derive(Deserialize))]
+#[cfg_attr(not(feature = "no_std"), derive(Deserialize))]
 #[derive(Debug, Copy, Clone, Eq, PartialEq, IntoPrimitive, TryFromPrimitive, Display)]
 #[repr(u8)]
 pub enum SubAccountRuleStatus {
@@ -22,8 +22,8 @@ pub enum SubAccountRuleStatus {
     On,
 }
 
-#[cfg_attr(feature = "std", derive(Deserialize))]
-#[derive(Debug, Clone)]
+#[cfg_attr(not(feature = "no_std"), derive(Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SubAccountRule {
     pub index: u32,
     pub name: String,
@@ -46,7 +46,7 @@ impl Into<packed::SubAccountRule> for SubAccountRule {
     }
 }
 
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 impl Serialize for SubAccountRule {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -70,7 +70,7 @@ impl Serialize for SubAccountRule {
     }
 }
 
-#[derive(Debug, Eq, PartialEq, IntoPrimitive, TryFromPrimitive, EnumString, Display)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, IntoPrimitive, TryFromPrimitive, EnumString, Display)]
 #[strum(serialize_all = "snake_case")]
 #[repr(u8)]
 pub enum ExpressionType {
@@ -86,8 +86,8 @@ impl Into<packed::Byte> for ExpressionType {
     }
 }
 
-#[cfg_attr(feature = "std", derive(Deserialize))]
-#[derive(Debug, Clone)]
+#[cfg_attr(not(feature = "no_std"), derive(Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Expression {
     Operator(OperatorExpression),
     Function(FunctionExpression),
@@ -123,7 +123,7 @@ impl Into<packed::ASTExpression> for Expression {
     }
 }
 
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 impl Serialize for Expression {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -138,34 +138,70 @@ impl Serialize for Expression {
     }
 }
 
-#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(not(feature = "no_std"), derive(Serialize, Deserialize))]
 #[derive(Debug, Copy, Clone, Eq, PartialEq, IntoPrimitive, TryFromPrimitive, EnumString, Display)]
 #[repr(u8)]
 pub enum SymbolType {
-    #[cfg_attr(feature = "std", serde(rename(serialize = "not", deserialize = "not")))]
+    #[cfg_attr(not(feature = "no_std"), serde(rename(serialize = "not", deserialize = "not")))]
     #[strum(serialize = "not")]
     Not,
-    #[cfg_attr(feature = "std", serde(rename(serialize = "and", deserialize = "and")))]
+    #[cfg_attr(not(feature = "no_std"), serde(rename(serialize = "and", deserialize = "and")))]
     #[strum(serialize = "and")]
     And,
-    #[cfg_attr(feature = "std", serde(rename(serialize = "or", deserialize = "or")))]
+    #[cfg_attr(not(feature = "no_std"), serde(rename(serialize = "or", deserialize = "or")))]
     #[strum(serialize = "or")]
     Or,
-    #[cfg_attr(feature = "std", serde(rename(serialize = ">", deserialize = ">")))]
+    #[cfg_attr(not(feature = "no_std"), serde(rename(serialize = ">", deserialize = ">")))]
     #[strum(serialize = ">")]
     Gt,
-    #[cfg_attr(feature = "std", serde(rename(serialize = ">=", deserialize = ">=")))]
+    #[cfg_attr(not(feature = "no_std"), serde(rename(serialize = ">=", deserialize = ">=")))]
     #[strum(serialize = ">=")]
     Gte,
-    #[cfg_attr(feature = "std", serde(rename(serialize = "<", deserialize = "<")))]
+    #[cfg_attr(not(feature = "no_std"), serde(rename(serialize = "<", deserialize = "<")))]
     #[strum(serialize = "<")]
     Lt,
-    #[cfg_attr(feature = "std", serde(rename(serialize = "<=", deserialize = "<=")))]
+    #[cfg_attr(not(feature = "no_std"), serde(rename(serialize = "<=", deserialize = "<=")))]
     #[strum(serialize = "<=")]
     Lte,
-    #[cfg_attr(feature = "std", serde(rename(serialize = "==", deserialize = "==")))]
+    #[cfg_attr(not(feature = "no_std"), serde(rename(serialize = "==", deserialize = "==")))]
     #[strum(serialize = "==")]
     Equal,
+    #[cfg_attr(not(feature = "no_std"), serde(rename(serialize = "+", deserialize = "+")))]
+    #[strum(serialize = "+")]
+    Add,
+    #[cfg_attr(not(feature = "no_std"), serde(rename(serialize = "-", deserialize = "-")))]
+    #[strum(serialize = "-")]
+    Sub,
+    #[cfg_attr(not(feature = "no_std"), serde(rename(serialize = "*", deserialize = "*")))]
+    #[strum(serialize = "*")]
+    Mul,
+    #[cfg_attr(not(feature = "no_std"), serde(rename(serialize = "/", deserialize = "/")))]
+    #[strum(serialize = "/")]
+    Div,
+    #[cfg_attr(not(feature = "no_std"), serde(rename(serialize = "%", deserialize = "%")))]
+    #[strum(serialize = "%")]
+    Mod,
+    #[cfg_attr(not(feature = "no_std"), serde(rename(serialize = "min", deserialize = "min")))]
+    #[strum(serialize = "min")]
+    Min,
+    #[cfg_attr(not(feature = "no_std"), serde(rename(serialize = "max", deserialize = "max")))]
+    #[strum(serialize = "max")]
+    Max,
+}
+
+impl SymbolType {
+    pub fn is_arithmetic(&self) -> bool {
+        matches!(
+            self,
+            SymbolType::Add
+                | SymbolType::Sub
+                | SymbolType::Mul
+                | SymbolType::Div
+                | SymbolType::Mod
+                | SymbolType::Min
+                | SymbolType::Max
+        )
+    }
 }
 
 impl Into<packed::Byte> for SymbolType {
@@ -174,8 +210,8 @@ impl Into<packed::Byte> for SymbolType {
     }
 }
 
-#[cfg_attr(feature = "std", derive(Deserialize))]
-#[derive(Debug, Clone)]
+#[cfg_attr(not(feature = "no_std"), derive(Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
 pub struct OperatorExpression {
     pub symbol: SymbolType,
     pub expressions: Vec<Expression>,
@@ -194,13 +230,13 @@ impl Into<packed::ASTOperator> for OperatorExpression {
     }
 }
 
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 impl Serialize for OperatorExpression {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        #[cfg(feature = "std")]
+        #[cfg(not(feature = "no_std"))]
         {
             let mut state = serializer.serialize_struct("OperatorExpression", 3)?;
             state.serialize_field("type", "operator")?;
@@ -211,9 +247,9 @@ impl Serialize for OperatorExpression {
     }
 }
 
-#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(not(feature = "no_std"), derive(Serialize, Deserialize))]
 #[derive(Debug, Copy, Clone, Eq, PartialEq, IntoPrimitive, TryFromPrimitive, EnumString, Display)]
-#[cfg_attr(feature = "std", serde(rename_all = "snake_case"))]
+#[cfg_attr(not(feature = "no_std"), serde(rename_all = "snake_case"))]
 #[strum(serialize_all = "snake_case")]
 #[repr(u8)]
 pub enum FnName {
@@ -224,6 +260,12 @@ pub enum FnName {
     IncludeCharset,
     StartsWith,
     EndsWith,
+    SelectByLength,
+    MatchesPattern,
+    ByteLength,
+    CharCount,
+    IncludesAt,
+    CountCharset,
 }
 
 impl Into<packed::Byte> for FnName {
@@ -232,8 +274,8 @@ impl Into<packed::Byte> for FnName {
     }
 }
 
-#[cfg_attr(feature = "std", derive(Deserialize))]
-#[derive(Debug, Clone)]
+#[cfg_attr(not(feature = "no_std"), derive(Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
 pub struct FunctionExpression {
     pub name: FnName,
     pub arguments: Vec<Expression>,
@@ -252,7 +294,7 @@ impl Into<packed::ASTFunction> for FunctionExpression {
     }
 }
 
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 impl Serialize for FunctionExpression {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -266,15 +308,32 @@ impl Serialize for FunctionExpression {
     }
 }
 
-#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(not(feature = "no_std"), derive(Serialize, Deserialize))]
 #[derive(Debug, Copy, Clone, Eq, PartialEq, IntoPrimitive, TryFromPrimitive, EnumString, Display)]
-#[cfg_attr(feature = "std", serde(rename_all = "snake_case"))]
+#[cfg_attr(not(feature = "no_std"), serde(rename_all = "snake_case"))]
 #[strum(serialize_all = "snake_case")]
 #[repr(u8)]
 pub enum VarName {
     Account,
     AccountChars,
     AccountLength,
+    Timestamp,
+    Years,
+    ParentAccount,
+    MintedCount,
+}
+
+/// The context of the transaction which can be accessed by the variables of rules.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RuleContext<'a> {
+    /// The current timestamp from the TimeCell, in seconds.
+    pub timestamp: u64,
+    /// The years of registration or renewal which is requested.
+    pub years: u32,
+    /// The parent account with suffix, like `xxxxx.bit`.
+    pub parent_account: &'a str,
+    /// The count of sub-accounts minted in the transaction.
+    pub minted_count: u32,
 }
 
 impl Into<packed::Byte> for VarName {
@@ -283,8 +342,8 @@ impl Into<packed::Byte> for VarName {
     }
 }
 
-#[cfg_attr(feature = "std", derive(Deserialize))]
-#[derive(Debug, Clone)]
+#[cfg_attr(not(feature = "no_std"), derive(Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
 pub struct VariableExpression {
     pub name: VarName,
 }
@@ -295,7 +354,7 @@ impl Into<packed::ASTVariable> for VariableExpression {
     }
 }
 
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 impl Serialize for VariableExpression {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -308,9 +367,9 @@ impl Serialize for VariableExpression {
     }
 }
 
-#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(not(feature = "no_std"), derive(Serialize, Deserialize))]
 #[derive(Debug, Copy, Clone, PartialEq, IntoPrimitive, TryFromPrimitive, Display, EnumString)]
-#[cfg_attr(feature = "std", serde(rename_all = "snake_case"))]
+#[cfg_attr(not(feature = "no_std"), serde(rename_all = "snake_case"))]
 #[repr(u8)]
 #[strum(serialize_all = "snake_case")]
 pub enum ValueType {
@@ -319,11 +378,11 @@ pub enum ValueType {
     Uint32,
     Uint64,
     Binary,
-    #[cfg_attr(feature = "std", serde(rename(serialize = "binary[]", deserialize = "binary[]")))]
+    #[cfg_attr(not(feature = "no_std"), serde(rename(serialize = "binary[]", deserialize = "binary[]")))]
     #[strum(serialize = "binary[]")]
     BinaryVec,
     String,
-    #[cfg_attr(feature = "std", serde(rename(serialize = "string[]", deserialize = "string[]")))]
+    #[cfg_attr(not(feature = "no_std"), serde(rename(serialize = "string[]", deserialize = "string[]")))]
     #[strum(serialize = "string[]")]
     StringVec,
     CharsetType,
@@ -335,8 +394,8 @@ impl Into<packed::Byte> for ValueType {
     }
 }
 
-#[cfg_attr(feature = "std", derive(Deserialize))]
-#[derive(Debug, Clone)]
+#[cfg_attr(not(feature = "no_std"), derive(Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ValueExpression {
     pub value_type: ValueType,
     pub value: Value,
@@ -351,7 +410,7 @@ impl Into<packed::ASTValue> for ValueExpression {
     }
 }
 
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 impl Serialize for ValueExpression {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -367,7 +426,7 @@ impl Serialize for ValueExpression {
 
 pub type Binary = Vec<u8>;
 
-#[cfg_attr(feature = "std", derive(Deserialize))]
+#[cfg_attr(not(feature = "no_std"), derive(Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub enum Value {
     Bool(bool),
@@ -415,24 +474,68 @@ impl Value {
         }
     }
 
+    /// Compare two uint values by number or two string values lexicographically by bytes.
     pub fn compare(&self, right: &Value, symbol_type: SymbolType) -> Result<bool, ASTError> {
-        let left = self.get_u64();
-        let right = right.get_u64();
-
-        match (left, right) {
-            (Ok(left), Ok(right)) => match symbol_type {
-                SymbolType::Gt => Ok(left > right),
-                SymbolType::Gte => Ok(left >= right),
-                SymbolType::Lt => Ok(left < right),
-                SymbolType::Lte => Ok(left <= right),
-                SymbolType::Equal => Ok(left == right),
-                _ => Err(ASTError::ValueOperatorUnsupported),
+        let ordering = match (self, right) {
+            (Value::String(left), Value::String(right)) => left.as_bytes().cmp(right.as_bytes()),
+            _ => match (self.get_u64(), right.get_u64()) {
+                (Ok(left), Ok(right)) => left.cmp(&right),
+                _ => return Err(ASTError::ValueOperatorUnsupported),
             },
+        };
+
+        match symbol_type {
+            SymbolType::Gt => Ok(ordering.is_gt()),
+            SymbolType::Gte => Ok(ordering.is_ge()),
+            SymbolType::Lt => Ok(ordering.is_lt()),
+            SymbolType::Lte => Ok(ordering.is_le()),
+            SymbolType::Equal => Ok(ordering.is_eq()),
             _ => Err(ASTError::ValueOperatorUnsupported),
         }
     }
 
-    fn get_u64(&self) -> Result<u64, ASTError> {
+    /// Calculate with two uint values, the type of the result is the wider one of the two operands.
+    pub fn calculate(&self, right: &Value, symbol_type: SymbolType) -> Result<Value, ASTError> {
+        let ret_type = match (self.get_uint_width()?, right.get_uint_width()?) {
+            (left_width, right_width) if left_width >= right_width => self.get_type(),
+            _ => right.get_type(),
+        };
+        let left = self.get_u64()?;
+        let right = right.get_u64()?;
+
+        let ret = match symbol_type {
+            SymbolType::Add => left.checked_add(right).ok_or(ASTError::ValueOverflow)?,
+            SymbolType::Sub => left.checked_sub(right).ok_or(ASTError::ValueOverflow)?,
+            SymbolType::Mul => left.checked_mul(right).ok_or(ASTError::ValueOverflow)?,
+            SymbolType::Div => left.checked_div(right).ok_or(ASTError::ValueDividedByZero)?,
+            SymbolType::Mod => left.checked_rem(right).ok_or(ASTError::ValueDividedByZero)?,
+            SymbolType::Min => left.min(right),
+            SymbolType::Max => left.max(right),
+            _ => return Err(ASTError::ValueOperatorUnsupported),
+        };
+
+        Value::from_u64(ret_type, ret)
+    }
+
+    pub fn from_u64(value_type: ValueType, val: u64) -> Result<Value, ASTError> {
+        match value_type {
+            ValueType::Uint8 => Ok(Value::Uint8(u8::try_from(val).map_err(|_| ASTError::ValueOverflow)?)),
+            ValueType::Uint32 => Ok(Value::Uint32(u32::try_from(val).map_err(|_| ASTError::ValueOverflow)?)),
+            ValueType::Uint64 => Ok(Value::Uint64(val)),
+            _ => Err(ASTError::ValueOperatorUnsupported),
+        }
+    }
+
+    fn get_uint_width(&self) -> Result<u8, ASTError> {
+        match self {
+            Value::Uint8(_) => Ok(1),
+            Value::Uint32(_) => Ok(4),
+            Value::Uint64(_) => Ok(8),
+            _ => Err(ASTError::ValueOperatorUnsupported),
+        }
+    }
+
+    pub fn get_u64(&self) -> Result<u64, ASTError> {
         match self {
             Value::Uint8(val) => Ok(*val as u64),
             Value::Uint32(val) => Ok(*val as u64),
@@ -471,7 +574,7 @@ impl Into<packed::Bytes> for Value {
     }
 }
 
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 impl Serialize for Value {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -939,6 +1042,16 @@ mod test {
         assert!(mid.compare(&right, SymbolType::Lt).unwrap());
         assert!(mid.compare(&right, SymbolType::Lte).unwrap());
         assert!(mid.compare(&mid, SymbolType::Lte).unwrap());
+
+        let left = Value::String(String::from("abc"));
+        let right = Value::String(String::from("abd"));
+        assert!(left.compare(&right, SymbolType::Lt).unwrap());
+        assert!(right.compare(&left, SymbolType::Gt).unwrap());
+        assert!(left.compare(&left, SymbolType::Equal).unwrap());
+        assert!(Value::String(String::from("ab"))
+            .compare(&left, SymbolType::Lt)
+            .unwrap());
+        assert!(left.compare(&mid, SymbolType::Gt).is_err());
     }
 
     #[test]
@@ -1,17 +1,17 @@
 #[cfg(feature = "no_std")]
 use alloc::{format, string::String, string::ToString};
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 use std::str::FromStr;
 
 #[cfg(feature = "no_std")]
 pub use blake2b_ref::{Blake2b, Blake2bBuilder};
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 pub use blake2b_rs::{Blake2b, Blake2bBuilder};
 #[cfg(feature = "no_std")]
 use das_types::{constants::*, packed, prelude::*};
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 use das_types::{constants::*, packed, prelude::*};
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 use serde_json;
 
 use crate::error::ASTError;
@@ -19,7 +19,7 @@ use crate::types::*;
 
 macro_rules! gen_json_to_uint_fn {
     ($name:ident, $u_type:ty) => {
-        #[cfg(feature = "std")]
+        #[cfg(not(feature = "no_std"))]
         pub fn $name(key: String, obj: &serde_json::Value) -> Result<$u_type, ASTError> {
             if let Some(val) = obj.as_u64() {
                 if val > <$u_type>::MAX as u64 {
@@ -101,7 +101,7 @@ gen_json_to_uint_fn!(json_to_u8, u8);
 gen_json_to_uint_fn!(json_to_u32, u32);
 gen_json_to_uint_fn!(json_to_u64, u64);
 
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 pub fn json_to_string(key: String, obj: &serde_json::Value) -> Result<String, ASTError> {
     match obj.as_str() {
         Some(str) => Ok(str.to_string()),
@@ -120,7 +120,7 @@ pub fn byte_reader_to_symbol(key: String, reader: packed::ByteReader) -> Result<
     byte_to_symbol(key, reader.as_slice()[0])
 }
 
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 pub fn json_to_symbol(key: String, obj: &serde_json::Value) -> Result<SymbolType, ASTError> {
     match obj.as_str() {
         Some(str) => {
@@ -148,7 +148,7 @@ pub fn mol_reader_to_operator(key: String, reader: packed::ASTOperatorReader) ->
     Ok(OperatorExpression { symbol, expressions })
 }
 
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 pub fn json_to_operator(key: String, obj: &serde_json::Value) -> Result<OperatorExpression, ASTError> {
     if obj["type"].as_str() != Some("operator") {
         return Err(ASTError::JsonValueError {
@@ -183,7 +183,7 @@ pub fn byte_reader_to_function_name(key: String, reader: packed::ByteReader) ->
     byte_to_function_name(key, reader.as_slice()[0])
 }
 
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 pub fn json_to_function_name(key: String, obj: &serde_json::Value) -> Result<FnName, ASTError> {
     match obj.as_str() {
         Some(str) => {
@@ -211,7 +211,7 @@ pub fn mol_reader_to_function(key: String, reader: packed::ASTFunctionReader) ->
     Ok(FunctionExpression { name, arguments })
 }
 
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 pub fn json_to_function(key: String, obj: &serde_json::Value) -> Result<FunctionExpression, ASTError> {
     if obj["type"].as_str() != Some("function") {
         return Err(ASTError::JsonValueError {
@@ -250,7 +250,7 @@ pub fn byte_reader_to_variable_name(key: String, reader: packed::ByteReader) ->
     byte_to_variable_name(key, reader.as_slice()[0])
 }
 
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 pub fn json_to_variable_name(key: String, obj: &serde_json::Value) -> Result<VarName, ASTError> {
     match obj.as_str() {
         Some(str) => {
@@ -272,7 +272,7 @@ pub fn mol_reader_to_variable(key: String, reader: packed::ASTVariableReader) ->
     Ok(VariableExpression { name })
 }
 
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 pub fn json_to_variable(key: String, obj: &serde_json::Value) -> Result<VariableExpression, ASTError> {
     if obj["type"].as_str() != Some("variable") {
         return Err(ASTError::JsonValueError {
@@ -293,7 +293,7 @@ pub fn byte_reader_to_value_type(key: String, reader: packed::ByteReader) -> Res
     byte_to_value_type(key, reader.as_slice()[0])
 }
 
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 pub fn json_to_value_type(key: String, obj: &serde_json::Value) -> Result<ValueType, ASTError> {
     match obj.as_str() {
         Some(str) => Ok(ValueType::from_str(str).map_err(|_| ASTError::JsonValueIsUndefined {
@@ -391,7 +391,7 @@ pub fn mol_reader_to_value(key: String, reader: packed::ASTValueReader) -> Resul
     Ok(ValueExpression { value_type, value })
 }
 
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 pub fn json_to_value(key: String, obj: &serde_json::Value) -> Result<ValueExpression, ASTError> {
     if obj["type"].as_str() != Some("value") {
         return Err(ASTError::JsonValueError {
@@ -513,7 +513,7 @@ pub fn byte_reader_to_expression_type(key: String, reader: packed::ByteReader) -
     byte_to_expression_type(key, reader.as_slice()[0])
 }
 
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 pub fn json_to_expression_type(key: String, obj: &serde_json::Value) -> Result<ExpressionType, ASTError> {
     match obj.as_str() {
         Some(str) => Ok(
@@ -562,7 +562,7 @@ pub fn mol_reader_to_expression(key: String, reader: packed::ASTExpressionReader
     }
 }
 
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 pub fn json_to_expression(key: String, obj: &serde_json::Value) -> Result<Expression, ASTError> {
     let type_ = json_to_expression_type(key.clone() + ".type", &obj["type"])?;
 
@@ -620,7 +620,7 @@ pub fn sub_account_rules_to_mol_entity(rules: Vec<SubAccountRule>) -> Result<pac
     Ok(packed::SubAccountRules::new_builder().set(tmp).build())
 }
 
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 pub fn json_to_sub_account_rule(key: String, obj: &serde_json::Value) -> Result<SubAccountRule, ASTError> {
     let index = json_to_u32(key.clone() + ".index", &obj["index"])?;
     let name = json_to_string(key.clone() + ".name", &obj["name"])?;
@@ -644,7 +644,7 @@ pub fn json_to_sub_account_rule(key: String, obj: &serde_json::Value) -> Result<
     })
 }
 
-#[cfg(feature = "std")]
+#[cfg(not(feature = "no_std"))]
 pub fn json_to_sub_account_rules(key: String, obj: &serde_json::Value) -> Result<Vec<SubAccountRule>, ASTError> {
     match obj.as_array() {
         Some(arr) => {
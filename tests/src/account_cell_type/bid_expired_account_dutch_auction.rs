@@ -1,10 +1,12 @@
+use das_types::auction;
 use das_types::constants::{DataType, Source};
 use serde_json::json;
 
 use crate::util;
 use crate::util::accounts::{DP_TRANSFER_WHITELIST_1, SENDER};
 use crate::util::constants::{
-    OracleCellType, ACCOUNT_EXPIRATION_AUCTION_PERIOD, ACCOUNT_EXPIRATION_GRACE_PERIOD, TIMESTAMP,
+    OracleCellType, ACCOUNT_EXPIRATION_AUCTION_PERIOD, ACCOUNT_EXPIRATION_AUCTION_START_PREMIUMS,
+    ACCOUNT_EXPIRATION_GRACE_PERIOD, ACCOUNT_PRICE_5_CHAR, TIMESTAMP,
 };
 use crate::util::error::{AccountCellErrorCode, DPointCellErrorCode, ErrorCode};
 use crate::util::template_common_cell::{
@@ -205,6 +207,14 @@ fn test_bid_expired_success_four_letters_account() {
 }
 
 fn common_when_auction_have_started(account_expired_at: u64, premium: u64) -> TemplateGenerator {
+    let basic_price_five_letters_account = 5814420;
+    common_bid_with_paid(
+        account_expired_at,
+        premium * DECIMAL_PRECISION + basic_price_five_letters_account,
+    )
+}
+
+fn common_bid_with_paid(account_expired_at: u64, paid: u64) -> TemplateGenerator {
     let mut template = init("bid_expired_account_dutch_auction");
 
     let account_expired_at = account_expired_at;
@@ -213,9 +223,8 @@ fn common_when_auction_have_started(account_expired_at: u64, premium: u64) -> Te
     let last_edit_manager_at = registered_at + 124 * SECONDS_ONE_DAY;
     let last_edit_records_at = registered_at + 125 * SECONDS_ONE_DAY;
 
-    let basic_price_five_letters_account = 5814420;
     let outputs_user_dp_amount = 1 * DECIMAL_PRECISION;
-    let outputs_das_dp_amount = premium * DECIMAL_PRECISION + basic_price_five_letters_account;
+    let outputs_das_dp_amount = paid;
     let inputs_user_dp_amount = outputs_das_dp_amount + outputs_user_dp_amount;
     //push inputs
     push_input_account_cell(
@@ -313,6 +322,41 @@ fn challenge_bid_expired_failed_when_auction_has_not_started() {
     );
     //test_tx(template.as_json());
 }
+/// The lowest bid of a five letters account, the storage is paid in USD with the quote 3788 and the premium is the one
+/// calculated by the contract.
+fn bid_price_of_five_letters_account(duration_in_auction: u64) -> u64 {
+    let storage_price = util::gen_account_cell_capacity(5) * 3788 / SHANNON;
+    let premium = auction::dutch_auction_premium(duration_in_auction, ACCOUNT_EXPIRATION_AUCTION_START_PREMIUMS as u32);
+
+    storage_price + ACCOUNT_PRICE_5_CHAR + premium
+}
+
+#[test]
+fn test_bid_expired_success_with_exact_premium() {
+    // Choose a time in the middle of a day, so the fraction of the day takes effect.
+    let duration_in_auction = 20 * SECONDS_ONE_DAY + 12345;
+    let account_expired_at = TIMESTAMP - ACCOUNT_EXPIRATION_GRACE_PERIOD - duration_in_auction;
+    let template = common_bid_with_paid(
+        account_expired_at,
+        bid_price_of_five_letters_account(duration_in_auction),
+    );
+
+    test_tx(template.as_json());
+}
+
+#[test]
+fn challenge_bid_expired_failed_with_one_unit_below_premium() {
+    // The old floating point premium tolerated bids up to 10 USD below it, now the premium must be paid exactly.
+    let duration_in_auction = 20 * SECONDS_ONE_DAY + 12345;
+    let account_expired_at = TIMESTAMP - ACCOUNT_EXPIRATION_GRACE_PERIOD - duration_in_auction;
+    let template = common_bid_with_paid(
+        account_expired_at,
+        bid_price_of_five_letters_account(duration_in_auction) - 1,
+    );
+
+    challenge_tx(template.as_json(), AccountCellErrorCode::AccountCellBidPriceTooLow);
+}
+
 // the premium will not decrease to 0 in 27 days
 // #[test]
 // fn test_bid_expired_success_when_auction_started_27_days_00_00() {
@@ -0,0 +1,114 @@
+use std::fs;
+use std::path::PathBuf;
+
+// The catalogue of das-core copies the error enums of dpoint-cell-type and device-key-list-cell-type by hand, because
+// the contracts can not depend on das-core. These tests compare the copies with the enums.
+
+/// List all the variants of an error enum, the exhaustive match breaks the build when a variant is added.
+macro_rules! variants {
+    ($enum:path { $($variant:ident,)* }) => {{
+        use $enum as ErrorEnum;
+
+        #[allow(dead_code)]
+        fn exhaustive(code: ErrorEnum) {
+            match code {
+                $(ErrorEnum::$variant => (),)*
+            }
+        }
+
+        vec![$((ErrorEnum::$variant as i8, stringify!($variant)),)*]
+    }};
+}
+
+/// Parse the `code Variant => ...` lines of a table in the catalogue.
+fn catalogue_table(table: &str) -> Vec<(i8, String)> {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../libs/das-core/src/error_catalogue.rs");
+    let source = fs::read_to_string(&path).expect("The error catalogue of das-core should exist.");
+
+    let start = source
+        .find(&format!("catalogue!({},", table))
+        .expect("The table should exist in the catalogue.");
+    let end = start + source[start..].find("\n});").expect("The table should be closed.");
+
+    source[start..end]
+        .lines()
+        .filter_map(|line| {
+            let (entry, _) = line.trim().split_once(" => ")?;
+            let (code, name) = entry.split_once(' ')?;
+            Some((code.parse().ok()?, name.to_string()))
+        })
+        .collect()
+}
+
+fn assert_table_eq(table: &str, mut expected: Vec<(i8, &str)>) {
+    let mut current = catalogue_table(table);
+    expected.sort();
+    current.sort();
+
+    assert_eq!(
+        expected
+            .into_iter()
+            .map(|(code, name)| (code, name.to_string()))
+            .collect::<Vec<_>>(),
+        current,
+        "The {} in the catalogue is out of sync with the error enum.",
+        table
+    );
+}
+
+#[test]
+fn test_dpoint_cell_errors_are_in_sync() {
+    assert_table_eq(
+        "DPOINT_CELL_ERRORS",
+        variants!(dpoint_cell_type::error::ErrorCode {
+            IndexOutOfBound,
+            ItemMissing,
+            LengthNotEnough,
+            Encoding,
+            InvalidTransactionStructure,
+            ActionNotSupported,
+            SuperLockIsRequired,
+            WhitelistLockIsRequired,
+            InitialCapacityError,
+            InitialDataError,
+            InitialOwnerError,
+            TheValueFieldIsMissing,
+            TheTotalDPointCanNotChange,
+            TheDPointShouldDecreased,
+            OnlyOneUserIsAllowed,
+            CanNotSpendAnyFee,
+            ThereShouldBeSomeDPointCellInInput,
+            CapacityRecycleError,
+        }),
+    );
+}
+
+#[test]
+fn test_device_key_list_cell_errors_are_in_sync() {
+    assert_table_eq(
+        "DEVICE_KEY_LIST_CELL_ERRORS",
+        variants!(device_key_list_cell_type::error::ErrorCode {
+            IndexOutOfBound,
+            ItemMissing,
+            LengthNotEnough,
+            Encoding,
+            FoundKeyListInInput,
+            WitnessArgsInvalid,
+            NoKeyListInOutput,
+            LockArgLengthIncorrect,
+            InvalidLock,
+            KeyListParseError,
+            InvalidTransactionStructure,
+            KeyListNumberIncorrect,
+            UpdateParamsInvalid,
+            DestroyParamsInvalid,
+            CapacityNotEnough,
+            MustUseDasLock,
+            InconsistentBalanceCellLocks,
+            CapacityReduceTooMuch,
+            DuplicatedKeys,
+            ActionNotSupported,
+            VerificationError,
+        }),
+    );
+}
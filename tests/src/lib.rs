@@ -12,6 +12,7 @@ mod config;
 mod config_cell_type;
 mod device_key_list_cell_type;
 mod dpoint_cell_type;
+mod error_catalogue;
 mod income_cell_type;
 mod offer_cell_type;
 mod playground;
@@ -19,6 +20,7 @@ mod pre_account_cell_type;
 mod proposal_cell_type;
 mod reverse_record_cell_type;
 mod reverse_record_root_cell_type;
+mod simple_ast;
 mod sub_account_cell_type;
 mod uint;
 mod witness_parser;
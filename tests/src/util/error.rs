@@ -235,6 +235,7 @@ pub enum AccountCellErrorCode {
     ApprovalNotRevoked,
     ApprovalInProtectionPeriod,
     ApprovalFulfillError,
+    AccountCellBidPriceTooLow,
 }
 
 impl Into<i8> for AccountCellErrorCode {
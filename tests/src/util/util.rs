@@ -107,11 +107,7 @@ pub fn blake2b_smt<T: AsRef<[u8]>>(s: T) -> [u8; 32] {
 }
 
 pub fn gen_smt_key_from_account(account: &str) -> [u8; 32] {
-    let account_id = account_to_id(account);
-    let mut key = [0u8; 32];
-    let key_pre = [account_id, vec![0u8; 12]].concat();
-    key.copy_from_slice(&key_pre);
-    key
+    das_types::account::smt_key_from_account_id(&account_to_id(account))
 }
 
 pub fn gen_smt_value_for_reverse_record_smt(nonce: u32, account: &[u8]) -> H256 {
@@ -128,8 +124,7 @@ pub fn get_type_id_bytes(name: &str) -> Vec<u8> {
 }
 
 pub fn account_to_id(account: &str) -> Vec<u8> {
-    let hash = blake2b_256(account);
-    hash.get(..ACCOUNT_ID_LENGTH).unwrap().to_vec()
+    das_types::account::account_id(account.as_bytes()).to_vec()
 }
 
 pub fn account_to_id_hex(account: &str) -> String {